@@ -127,6 +127,7 @@ pub trait Benchmarkable {
                         None,
                         IggyExpiry::NeverExpire,
                         max_topic_size,
+                        TopicOptions::default(),
                     )
                     .await?;
             }
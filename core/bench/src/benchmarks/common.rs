@@ -84,6 +84,7 @@ pub async fn init_consumer_groups(
                 &topic_id.try_into().unwrap(),
                 &consumer_group_name,
                 Some(consumer_group_id),
+                ConsumerGroupAssignmentStrategy::default(),
             )
             .await
         {
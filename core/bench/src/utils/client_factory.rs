@@ -31,6 +31,7 @@ pub fn create_client_factory(args: &IggyBenchArgs) -> Arc<dyn ClientFactory> {
         Transport::Tcp => Arc::new(TcpClientFactory {
             server_addr: args.server_address().to_owned(),
             nodelay: args.nodelay(),
+            ..Default::default()
         }),
         Transport::Quic => Arc::new(QuicClientFactory {
             server_addr: args.server_address().to_owned(),
@@ -20,8 +20,8 @@ use crate::Client;
 use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
-use iggy_common::Identifier;
 use iggy_common::create_consumer_group::CreateConsumerGroup;
+use iggy_common::{ConsumerGroupAssignmentStrategy, Identifier};
 use tracing::{Level, event};
 
 pub struct CreateConsumerGroupCmd {
@@ -34,6 +34,7 @@ impl CreateConsumerGroupCmd {
         topic_id: Identifier,
         name: String,
         group_id: Option<u32>,
+        assignment_strategy: ConsumerGroupAssignmentStrategy,
     ) -> Self {
         Self {
             create_consumer_group: CreateConsumerGroup {
@@ -41,6 +42,7 @@ impl CreateConsumerGroupCmd {
                 topic_id,
                 name,
                 group_id,
+                assignment_strategy,
             },
         }
     }
@@ -57,9 +59,10 @@ impl CreateConsumerGroupCmd {
 impl CliCommand for CreateConsumerGroupCmd {
     fn explain(&self) -> String {
         format!(
-            "create consumer group: {}, name: {} for topic with ID: {} and stream with ID: {}",
+            "create consumer group: {}, name: {}, assignment strategy: {} for topic with ID: {} and stream with ID: {}",
             self.get_group_id_info(),
             self.create_consumer_group.name,
+            self.create_consumer_group.assignment_strategy,
             self.create_consumer_group.topic_id,
             self.create_consumer_group.stream_id,
         )
@@ -67,7 +70,7 @@ impl CliCommand for CreateConsumerGroupCmd {
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
         client
-            .create_consumer_group(&self.create_consumer_group.stream_id, &self.create_consumer_group.topic_id, &self.create_consumer_group.name, self.create_consumer_group.group_id)
+            .create_consumer_group(&self.create_consumer_group.stream_id, &self.create_consumer_group.topic_id, &self.create_consumer_group.name, self.create_consumer_group.group_id, self.create_consumer_group.assignment_strategy)
             .await
             .with_context(|| {
                 format!(
@@ -77,9 +80,10 @@ impl CliCommand for CreateConsumerGroupCmd {
             })?;
 
         event!(target: PRINT_TARGET, Level::INFO,
-            "Consumer group: {}, name: {} created for topic with ID: {} and stream with ID: {}",
+            "Consumer group: {}, name: {}, assignment strategy: {} created for topic with ID: {} and stream with ID: {}",
             self.get_group_id_info(),
             self.create_consumer_group.name,
+            self.create_consumer_group.assignment_strategy,
             self.create_consumer_group.topic_id,
             self.create_consumer_group.stream_id,
         );
@@ -20,3 +20,4 @@ pub mod create_consumer_group;
 pub mod delete_consumer_group;
 pub mod get_consumer_group;
 pub mod get_consumer_groups;
+pub mod reset_consumer_group_offsets;
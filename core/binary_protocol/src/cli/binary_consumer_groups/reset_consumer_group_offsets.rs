@@ -0,0 +1,118 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Client;
+use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use iggy_common::reset_consumer_group_offsets::ResetConsumerGroupOffsets;
+use iggy_common::{ConsumerOffsetsResetTarget, Identifier};
+use tracing::{Level, event};
+
+pub struct ResetConsumerGroupOffsetsCmd {
+    reset_consumer_group_offsets: ResetConsumerGroupOffsets,
+}
+
+impl ResetConsumerGroupOffsetsCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        group_id: Identifier,
+        target: ConsumerOffsetsResetTarget,
+        dry_run: bool,
+        force: bool,
+    ) -> Self {
+        Self {
+            reset_consumer_group_offsets: ResetConsumerGroupOffsets {
+                stream_id,
+                topic_id,
+                group_id,
+                target,
+                dry_run,
+                force,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for ResetConsumerGroupOffsetsCmd {
+    fn explain(&self) -> String {
+        format!(
+            "reset offsets of consumer group with ID: {} for topic with ID: {} and stream with ID: {} to {}{}",
+            self.reset_consumer_group_offsets.group_id,
+            self.reset_consumer_group_offsets.topic_id,
+            self.reset_consumer_group_offsets.stream_id,
+            self.reset_consumer_group_offsets.target,
+            if self.reset_consumer_group_offsets.dry_run {
+                " (dry run)"
+            } else {
+                ""
+            },
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let resets = client
+            .reset_consumer_group_offsets(
+                &self.reset_consumer_group_offsets.stream_id,
+                &self.reset_consumer_group_offsets.topic_id,
+                &self.reset_consumer_group_offsets.group_id,
+                &self.reset_consumer_group_offsets.target,
+                self.reset_consumer_group_offsets.dry_run,
+                self.reset_consumer_group_offsets.force,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem resetting offsets of consumer group with ID: {} for topic with ID: {} and stream with ID: {}",
+                    self.reset_consumer_group_offsets.group_id, self.reset_consumer_group_offsets.topic_id, self.reset_consumer_group_offsets.stream_id
+                )
+            })?;
+
+        let mut table = Table::new();
+        table.set_header(vec![
+            "Partition ID",
+            "Current offset",
+            "Old offset",
+            "New offset",
+        ]);
+        for reset in resets {
+            table.add_row(vec![
+                format!("{}", reset.partition_id),
+                format!("{}", reset.current_offset),
+                reset
+                    .old_offset
+                    .map_or("-".to_string(), |offset| offset.to_string()),
+                reset
+                    .new_offset
+                    .map_or("-".to_string(), |offset| offset.to_string()),
+            ]);
+        }
+
+        if self.reset_consumer_group_offsets.dry_run {
+            event!(target: PRINT_TARGET, Level::INFO, "Dry run, the offsets of consumer group with ID: {} were not changed", self.reset_consumer_group_offsets.group_id);
+        } else {
+            event!(target: PRINT_TARGET, Level::INFO, "Offsets of consumer group with ID: {} were reset", self.reset_consumer_group_offsets.group_id);
+        }
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
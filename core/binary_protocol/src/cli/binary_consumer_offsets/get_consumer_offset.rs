@@ -115,6 +115,17 @@ impl CliCommand for GetConsumerOffsetCmd {
             "Stored offset",
             format!("{}", consumer_offset.stored_offset).as_str(),
         ]);
+        table.add_row(vec![
+            "Metadata",
+            consumer_offset.metadata.as_deref().unwrap_or_default(),
+        ]);
+        table.add_row(vec![
+            "Committed at",
+            consumer_offset
+                .committed_at
+                .to_local_string("%Y-%m-%d %H:%M:%S")
+                .as_str(),
+        ]);
 
         event!(target: PRINT_TARGET, Level::INFO, "{table}");
 
@@ -36,6 +36,7 @@ impl SetConsumerOffsetCmd {
         topic_id: Identifier,
         partition_id: u32,
         offset: u64,
+        metadata: Option<String>,
     ) -> Self {
         Self {
             set_consumer_offset: StoreConsumerOffset {
@@ -47,6 +48,7 @@ impl SetConsumerOffsetCmd {
                 topic_id,
                 partition_id: Some(partition_id),
                 offset,
+                metadata,
             },
         }
     }
@@ -67,7 +69,7 @@ impl CliCommand for SetConsumerOffsetCmd {
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
         client
-            .store_consumer_offset(&self.set_consumer_offset.consumer, &self.set_consumer_offset.stream_id, &self.set_consumer_offset.topic_id, self.set_consumer_offset.partition_id, self.set_consumer_offset.offset)
+            .store_consumer_offset(&self.set_consumer_offset.consumer, &self.set_consumer_offset.stream_id, &self.set_consumer_offset.topic_id, self.set_consumer_offset.partition_id, self.set_consumer_offset.offset, self.set_consumer_offset.metadata.as_deref())
             .await
             .with_context(|| {
                 format!(
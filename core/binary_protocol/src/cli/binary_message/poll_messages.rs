@@ -23,7 +23,8 @@ use async_trait::async_trait;
 use comfy_table::{Cell, CellAlignment, Row, Table};
 use iggy_common::{
     BytesSerializable, Consumer, HeaderKey, HeaderKind, HeaderValue, Identifier, IggyByteSize,
-    IggyDuration, IggyMessage, IggyTimestamp, PollMessages, PollingStrategy, Sizeable,
+    IggyDuration, IggyMessage, IggyTimestamp, IsolationLevel, PollMessages, PollingStrategy,
+    Sizeable,
 };
 use std::collections::{HashMap, HashSet};
 use tokio::io::AsyncWriteExt;
@@ -67,6 +68,10 @@ impl PollMessagesCmd {
                 strategy,
                 count: message_count,
                 auto_commit,
+                filter: None,
+                isolation_level: IsolationLevel::default(),
+                max_wait: IggyDuration::default(),
+                min_bytes: 0,
             },
             show_headers,
             output_file,
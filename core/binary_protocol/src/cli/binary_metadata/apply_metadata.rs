@@ -0,0 +1,283 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Client;
+use crate::cli::binary_metadata::MetadataFormat;
+use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy_common::{Identifier, MetadataOperation, TopicOptions};
+use std::path::PathBuf;
+use tracing::{Level, event};
+
+pub struct ApplyMetadataCmd {
+    file: PathBuf,
+    format: Option<MetadataFormat>,
+    dry_run: bool,
+    prune: bool,
+}
+
+impl ApplyMetadataCmd {
+    pub fn new(file: PathBuf, format: Option<MetadataFormat>, dry_run: bool, prune: bool) -> Self {
+        Self {
+            file,
+            format,
+            dry_run,
+            prune,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for ApplyMetadataCmd {
+    fn explain(&self) -> String {
+        match self.dry_run {
+            true => format!("plan metadata from file: {}", self.file.display()),
+            false => format!("apply metadata from file: {}", self.file.display()),
+        }
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let format = self
+            .format
+            .or_else(|| MetadataFormat::from_path(&self.file))
+            .with_context(|| {
+                format!(
+                    "Unknown format of file: {}, use toml, json or yaml extension or set the format",
+                    self.file.display()
+                )
+            })?;
+        let content = tokio::fs::read_to_string(&self.file)
+            .await
+            .with_context(|| format!("Problem reading file: {}", self.file.display()))?;
+        let desired = format.deserialize(&content)?;
+        let current = client
+            .export_metadata(false)
+            .await
+            .with_context(|| "Problem exporting current metadata".to_owned())?;
+        let plan = current
+            .plan(&desired, self.prune)
+            .with_context(|| "Problem planning metadata changes".to_owned())?;
+        if !self.prune {
+            let pruned = current
+                .plan(&desired, true)
+                .with_context(|| "Problem planning metadata changes".to_owned())?;
+            let skipped = pruned.len() - plan.len();
+            if skipped > 0 {
+                event!(target: PRINT_TARGET, Level::INFO, "Skipped {skipped} deletion(s) of the resources not present in the file, use --prune to apply them");
+            }
+        }
+
+        if plan.is_empty() {
+            event!(target: PRINT_TARGET, Level::INFO, "Metadata is up to date, nothing to apply");
+            return Ok(());
+        }
+
+        event!(target: PRINT_TARGET, Level::INFO, "Plan with {} operation(s):", plan.len());
+        for operation in &plan {
+            event!(target: PRINT_TARGET, Level::INFO, "  {operation}");
+        }
+
+        if self.dry_run {
+            return Ok(());
+        }
+
+        for operation in &plan {
+            apply(client, operation)
+                .await
+                .with_context(|| format!("Problem applying operation: {operation}"))?;
+        }
+
+        event!(target: PRINT_TARGET, Level::INFO, "Applied {} operation(s)", plan.len());
+
+        Ok(())
+    }
+}
+
+async fn apply(client: &dyn Client, operation: &MetadataOperation) -> anyhow::Result<()> {
+    match operation {
+        MetadataOperation::CreateRole(role) => {
+            client.create_role(&role.name, role.rules.clone()).await?
+        }
+        MetadataOperation::UpdateRole(role) => {
+            client.update_role(&role.name, role.rules.clone()).await?
+        }
+        MetadataOperation::DeleteRole { name } => client.delete_role(name).await?,
+        MetadataOperation::CreateStream { id, name } => {
+            client.create_stream(name, Some(*id)).await?;
+        }
+        MetadataOperation::DeleteStream { name } => {
+            client.delete_stream(&Identifier::named(name)?).await?
+        }
+        MetadataOperation::CreateTopic { stream, topic } => {
+            client
+                .create_topic(
+                    &Identifier::named(stream)?,
+                    &topic.name,
+                    topic.partitions_count,
+                    topic.compression_algorithm,
+                    topic.replication_factor,
+                    Some(topic.id),
+                    topic.message_expiry,
+                    topic.max_topic_size,
+                    TopicOptions {
+                        cleanup_policy: topic.cleanup_policy,
+                        partitioning_hash: topic.partitioning_hash,
+                        segment_max_age: topic.segment_max_age,
+                        max_partition_size: topic.max_partition_size,
+                    },
+                )
+                .await?;
+        }
+        MetadataOperation::UpdateTopic { stream, topic } => {
+            client
+                .update_topic(
+                    &Identifier::named(stream)?,
+                    &Identifier::named(&topic.name)?,
+                    &topic.name,
+                    topic.compression_algorithm,
+                    topic.replication_factor,
+                    topic.message_expiry,
+                    topic.max_topic_size,
+                    TopicOptions {
+                        cleanup_policy: topic.cleanup_policy,
+                        partitioning_hash: topic.partitioning_hash,
+                        segment_max_age: topic.segment_max_age,
+                        max_partition_size: topic.max_partition_size,
+                    },
+                )
+                .await?
+        }
+        MetadataOperation::DeleteTopic { stream, name } => {
+            client
+                .delete_topic(&Identifier::named(stream)?, &Identifier::named(name)?)
+                .await?
+        }
+        MetadataOperation::CreatePartitions {
+            stream,
+            topic,
+            count,
+        } => {
+            client
+                .create_partitions(
+                    &Identifier::named(stream)?,
+                    &Identifier::named(topic)?,
+                    *count,
+                )
+                .await?
+        }
+        MetadataOperation::DeletePartitions {
+            stream,
+            topic,
+            count,
+        } => {
+            client
+                .delete_partitions(
+                    &Identifier::named(stream)?,
+                    &Identifier::named(topic)?,
+                    *count,
+                )
+                .await?
+        }
+        MetadataOperation::CreateConsumerGroup {
+            stream,
+            topic,
+            group,
+        } => {
+            client
+                .create_consumer_group(
+                    &Identifier::named(stream)?,
+                    &Identifier::named(topic)?,
+                    &group.name,
+                    Some(group.id),
+                    group.assignment_strategy,
+                )
+                .await?;
+        }
+        MetadataOperation::DeleteConsumerGroup {
+            stream,
+            topic,
+            name,
+        } => {
+            client
+                .delete_consumer_group(
+                    &Identifier::named(stream)?,
+                    &Identifier::named(topic)?,
+                    &Identifier::named(name)?,
+                )
+                .await?
+        }
+        MetadataOperation::CreateUser(user) => match (&user.password, &user.password_hash) {
+            (None, Some(password_hash)) => {
+                client
+                    .import_user(
+                        &user.username,
+                        password_hash,
+                        user.status,
+                        user.permissions.clone(),
+                        user.quotas,
+                    )
+                    .await?;
+            }
+            (password, _) => {
+                client
+                    .create_user(
+                        &user.username,
+                        password.as_deref().unwrap_or_default(),
+                        user.status,
+                        user.permissions.clone(),
+                        user.quotas,
+                    )
+                    .await?;
+            }
+        },
+        MetadataOperation::UpdateUser {
+            username,
+            status,
+            quotas,
+        } => {
+            // The missing quotas leave the current ones unchanged, so the unlimited quotas are sent instead.
+            client
+                .update_user(
+                    &Identifier::named(username)?,
+                    None,
+                    Some(*status),
+                    Some(quotas.unwrap_or_default()),
+                )
+                .await?
+        }
+        MetadataOperation::UpdatePermissions {
+            username,
+            permissions,
+        } => {
+            client
+                .update_permissions(&Identifier::named(username)?, permissions.clone())
+                .await?
+        }
+        MetadataOperation::AssignRoles { username, roles } => {
+            client
+                .assign_roles(&Identifier::named(username)?, roles.clone())
+                .await?
+        }
+        MetadataOperation::DeleteUser { username } => {
+            client.delete_user(&Identifier::named(username)?).await?
+        }
+    }
+
+    Ok(())
+}
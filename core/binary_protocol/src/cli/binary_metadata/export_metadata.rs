@@ -0,0 +1,80 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Client;
+use crate::cli::binary_metadata::MetadataFormat;
+use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use std::path::PathBuf;
+use tracing::{Level, event};
+
+pub struct ExportMetadataCmd {
+    format: MetadataFormat,
+    include_password_hashes: bool,
+    output: Option<PathBuf>,
+}
+
+impl ExportMetadataCmd {
+    pub fn new(
+        format: Option<MetadataFormat>,
+        include_password_hashes: bool,
+        output: Option<PathBuf>,
+    ) -> Self {
+        let format = format
+            .or_else(|| output.as_deref().and_then(MetadataFormat::from_path))
+            .unwrap_or(MetadataFormat::Toml);
+        Self {
+            format,
+            include_password_hashes,
+            output,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for ExportMetadataCmd {
+    fn explain(&self) -> String {
+        format!("export metadata in format: {}", self.format)
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let metadata = client
+            .export_metadata(self.include_password_hashes)
+            .await
+            .with_context(|| "Problem exporting metadata".to_owned())?;
+        let content = self.format.serialize(&metadata)?;
+
+        match &self.output {
+            Some(path) => {
+                tokio::fs::write(path, content).await.with_context(|| {
+                    format!("Problem writing metadata to file: {}", path.display())
+                })?;
+                event!(target: PRINT_TARGET, Level::INFO,
+                    "Metadata exported to file: {}",
+                    path.display()
+                );
+            }
+            None => {
+                event!(target: PRINT_TARGET, Level::INFO, "{content}");
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use anyhow::Context;
+use iggy_common::ServerMetadata;
+use serde_json::Value;
+use std::fmt::Display;
+use std::path::Path;
+
+pub mod apply_metadata;
+pub mod export_metadata;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum MetadataFormat {
+    Toml,
+    Json,
+    Yaml,
+}
+
+impl MetadataFormat {
+    /// Returns the format matching the extension of the file, if any.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_lowercase().as_str() {
+            "toml" => Some(MetadataFormat::Toml),
+            "json" => Some(MetadataFormat::Json),
+            "yaml" | "yml" => Some(MetadataFormat::Yaml),
+            _ => None,
+        }
+    }
+
+    pub fn serialize(&self, metadata: &ServerMetadata) -> anyhow::Result<String> {
+        match self {
+            MetadataFormat::Toml => {
+                // TOML has no null values, so the missing optional fields are skipped instead.
+                let mut value = serde_json::to_value(metadata)
+                    .with_context(|| "Problem converting metadata".to_owned())?;
+                remove_nulls(&mut value);
+                toml::to_string_pretty(&value)
+                    .with_context(|| "Problem serializing metadata to TOML".to_owned())
+            }
+            MetadataFormat::Json => serde_json::to_string_pretty(metadata)
+                .with_context(|| "Problem serializing metadata to JSON".to_owned()),
+            MetadataFormat::Yaml => serde_yaml::to_string(metadata)
+                .with_context(|| "Problem serializing metadata to YAML".to_owned()),
+        }
+    }
+
+    pub fn deserialize(&self, content: &str) -> anyhow::Result<ServerMetadata> {
+        match self {
+            MetadataFormat::Toml => {
+                // The numeric keys, e.g. of the stream permissions, are always strings in TOML.
+                let value = toml::from_str::<Value>(content)
+                    .with_context(|| "Problem parsing TOML metadata".to_owned())?;
+                serde_json::from_value(value)
+                    .with_context(|| "Problem deserializing TOML metadata".to_owned())
+            }
+            MetadataFormat::Json => serde_json::from_str(content)
+                .with_context(|| "Problem deserializing JSON metadata".to_owned()),
+            MetadataFormat::Yaml => serde_yaml::from_str(content)
+                .with_context(|| "Problem deserializing YAML metadata".to_owned()),
+        }
+    }
+}
+
+impl Display for MetadataFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MetadataFormat::Toml => write!(f, "toml"),
+            MetadataFormat::Json => write!(f, "json"),
+            MetadataFormat::Yaml => write!(f, "yaml"),
+        }
+    }
+}
+
+fn remove_nulls(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.retain(|_, value| !value.is_null());
+            map.values_mut().for_each(remove_nulls);
+        }
+        Value::Array(values) => values.iter_mut().for_each(remove_nulls),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy_common::{
+        IggyExpiry, Permissions, StreamMetadata, StreamPermissions, TopicMetadata, UserMetadata,
+    };
+
+    fn metadata() -> ServerMetadata {
+        ServerMetadata {
+            streams: vec![StreamMetadata {
+                id: 1,
+                name: "shop".to_string(),
+                topics: vec![TopicMetadata {
+                    id: 1,
+                    name: "orders".to_string(),
+                    partitions_count: 3,
+                    compression_algorithm: Default::default(),
+                    message_expiry: IggyExpiry::NeverExpire,
+                    max_topic_size: Default::default(),
+                    replication_factor: None,
+                    cleanup_policy: Default::default(),
+                    partitioning_hash: Default::default(),
+                    segment_max_age: Default::default(),
+                    max_partition_size: Default::default(),
+                    consumer_groups: vec![],
+                }],
+            }],
+            roles: vec![],
+            users: vec![UserMetadata {
+                id: 2,
+                username: "shop".to_string(),
+                status: Default::default(),
+                permissions: Some(Permissions {
+                    global: Default::default(),
+                    streams: Some([(1, StreamPermissions::default())].into()),
+                }),
+                quotas: None,
+                roles: vec![],
+                password_hash: None,
+                password: None,
+                personal_access_tokens: vec![],
+            }],
+        }
+    }
+
+    #[test]
+    fn metadata_should_be_serialized_and_deserialized_in_all_formats() {
+        let metadata = metadata();
+        for format in [
+            MetadataFormat::Toml,
+            MetadataFormat::Json,
+            MetadataFormat::Yaml,
+        ] {
+            let content = format.serialize(&metadata).unwrap();
+            let deserialized = format.deserialize(&content).unwrap();
+            assert_eq!(deserialized, metadata, "format: {format}");
+        }
+    }
+
+    #[test]
+    fn format_should_be_detected_from_file_extension() {
+        assert_eq!(
+            MetadataFormat::from_path(Path::new("metadata.yml")),
+            Some(MetadataFormat::Yaml)
+        );
+        assert_eq!(
+            MetadataFormat::from_path(Path::new("metadata.TOML")),
+            Some(MetadataFormat::Toml)
+        );
+        assert_eq!(MetadataFormat::from_path(Path::new("metadata")), None);
+    }
+}
@@ -0,0 +1,90 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Client;
+use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy_common::Identifier;
+use iggy_common::move_partition::MovePartition;
+use tracing::{Level, event};
+
+pub struct MovePartitionCmd {
+    move_partition: MovePartition,
+}
+
+impl MovePartitionCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partition_id: u32,
+        data_directory: String,
+    ) -> Self {
+        Self {
+            move_partition: MovePartition {
+                stream_id,
+                topic_id,
+                partition_id,
+                data_directory,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for MovePartitionCmd {
+    fn explain(&self) -> String {
+        format!(
+            "move partition with ID: {} for topic with ID: {} and stream with ID: {} to data directory: {}",
+            self.move_partition.partition_id,
+            self.move_partition.topic_id,
+            self.move_partition.stream_id,
+            self.move_partition.data_directory
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .move_partition(
+                &self.move_partition.stream_id,
+                &self.move_partition.topic_id,
+                self.move_partition.partition_id,
+                &self.move_partition.data_directory,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem moving partition with ID: {} for topic with ID: {} and stream with ID: {} to data directory: {}",
+                    self.move_partition.partition_id,
+                    self.move_partition.topic_id,
+                    self.move_partition.stream_id,
+                    self.move_partition.data_directory
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Moved partition with ID: {} for topic with ID: {} and stream with ID: {} to data directory: {}",
+            self.move_partition.partition_id,
+            self.move_partition.topic_id,
+            self.move_partition.stream_id,
+            self.move_partition.data_directory,
+        );
+
+        Ok(())
+    }
+}
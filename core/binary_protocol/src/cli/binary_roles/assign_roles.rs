@@ -0,0 +1,68 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Client;
+use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy_common::Identifier;
+use iggy_common::assign_roles::AssignRoles;
+use tracing::{Level, event};
+
+pub struct AssignRolesCmd {
+    assign_roles: AssignRoles,
+}
+
+impl AssignRolesCmd {
+    pub fn new(user_id: Identifier, roles: Vec<String>) -> Self {
+        Self {
+            assign_roles: AssignRoles { user_id, roles },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for AssignRolesCmd {
+    fn explain(&self) -> String {
+        format!(
+            "assign roles: [{}] to user with ID: {}",
+            self.assign_roles.roles.join(", "),
+            self.assign_roles.user_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .assign_roles(&self.assign_roles.user_id, self.assign_roles.roles.clone())
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem assigning roles to user with ID: {}",
+                    self.assign_roles.user_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Roles: [{}] assigned to user with ID: {}",
+            self.assign_roles.roles.join(", "),
+            self.assign_roles.user_id
+        );
+
+        Ok(())
+    }
+}
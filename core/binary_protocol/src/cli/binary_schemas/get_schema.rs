@@ -0,0 +1,91 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Client;
+use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use iggy_common::get_schema::GetSchema;
+use tracing::{Level, event};
+
+pub struct GetSchemaCmd {
+    get_schema: GetSchema,
+}
+
+impl GetSchemaCmd {
+    pub fn new(schema_id: u32) -> Self {
+        Self {
+            get_schema: GetSchema { schema_id },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetSchemaCmd {
+    fn explain(&self) -> String {
+        format!("get schema with ID: {}", self.get_schema.schema_id)
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let schema = client
+            .get_schema(self.get_schema.schema_id)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting schema with ID: {}",
+                    self.get_schema.schema_id
+                )
+            })?;
+
+        let Some(schema) = schema else {
+            event!(target: PRINT_TARGET, Level::INFO, "Schema with ID: {} was not found", self.get_schema.schema_id);
+            return Ok(());
+        };
+
+        let mut table = Table::new();
+
+        table.set_header(vec!["Property", "Value"]);
+        table.add_row(vec!["Schema ID", format!("{}", schema.id).as_str()]);
+        table.add_row(vec!["Stream ID", format!("{}", schema.stream_id).as_str()]);
+        table.add_row(vec!["Topic ID", format!("{}", schema.topic_id).as_str()]);
+        table.add_row(vec!["Version", format!("{}", schema.version).as_str()]);
+        table.add_row(vec!["Type", format!("{}", schema.schema_type).as_str()]);
+        table.add_row(vec![
+            "Compatibility",
+            format!("{}", schema.compatibility).as_str(),
+        ]);
+        table.add_row(vec![
+            "Created",
+            schema
+                .created_at
+                .to_local_string("%Y-%m-%d %H:%M:%S")
+                .as_str(),
+        ]);
+        // Protobuf descriptors are binary, so only the size is printed for them.
+        let definition = match std::str::from_utf8(&schema.definition) {
+            Ok(definition) => definition.to_string(),
+            Err(_) => format!("<{} bytes>", schema.definition.len()),
+        };
+        table.add_row(vec!["Definition", definition.as_str()]);
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Client;
+use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use iggy_common::Identifier;
+use iggy_common::get_schemas::GetSchemas;
+use tracing::{Level, event};
+
+pub enum GetSchemasOutput {
+    Table,
+    List,
+}
+
+pub struct GetSchemasCmd {
+    get_schemas: GetSchemas,
+    output: GetSchemasOutput,
+}
+
+impl GetSchemasCmd {
+    pub fn new(stream_id: Identifier, topic_id: Identifier, output: GetSchemasOutput) -> Self {
+        Self {
+            get_schemas: GetSchemas {
+                stream_id,
+                topic_id,
+            },
+            output,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetSchemasCmd {
+    fn explain(&self) -> String {
+        let mode = match self.output {
+            GetSchemasOutput::Table => "table",
+            GetSchemasOutput::List => "list",
+        };
+        format!(
+            "list schemas for topic with ID: {} and stream with ID: {} in {mode} mode",
+            self.get_schemas.topic_id, self.get_schemas.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let schemas = client
+            .get_schemas(&self.get_schemas.stream_id, &self.get_schemas.topic_id)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting list of schemas for topic with ID: {} and stream with ID: {}",
+                    self.get_schemas.topic_id, self.get_schemas.stream_id
+                )
+            })?;
+
+        if schemas.is_empty() {
+            event!(target: PRINT_TARGET, Level::INFO, "No schemas found!");
+            return Ok(());
+        }
+
+        match self.output {
+            GetSchemasOutput::Table => {
+                let mut table = Table::new();
+
+                table.set_header(vec![
+                    "ID",
+                    "Version",
+                    "Type",
+                    "Compatibility",
+                    "Size",
+                    "Created",
+                ]);
+
+                schemas.iter().for_each(|schema| {
+                    table.add_row(vec![
+                        format!("{}", schema.id),
+                        format!("{}", schema.version),
+                        format!("{}", schema.schema_type),
+                        format!("{}", schema.compatibility),
+                        format!("{}", schema.definition.len()),
+                        schema.created_at.to_local_string("%Y-%m-%d %H:%M:%S"),
+                    ]);
+                });
+
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            }
+            GetSchemasOutput::List => {
+                schemas.iter().for_each(|schema| {
+                    event!(target: PRINT_TARGET, Level::INFO,
+                        "{}|{}|{}|{}|{}|{}",
+                        schema.id,
+                        schema.version,
+                        schema.schema_type,
+                        schema.compatibility,
+                        schema.definition.len(),
+                        schema.created_at.to_local_string("%Y-%m-%d %H:%M:%S")
+                    );
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Client;
+use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use bytes::Bytes;
+use iggy_common::{Identifier, SchemaCompatibility, SchemaType};
+use std::path::PathBuf;
+use tracing::{Level, event};
+
+pub struct RegisterSchemaCmd {
+    stream_id: Identifier,
+    topic_id: Identifier,
+    schema_type: SchemaType,
+    compatibility: SchemaCompatibility,
+    file: PathBuf,
+}
+
+impl RegisterSchemaCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        schema_type: SchemaType,
+        compatibility: SchemaCompatibility,
+        file: PathBuf,
+    ) -> Self {
+        Self {
+            stream_id,
+            topic_id,
+            schema_type,
+            compatibility,
+            file,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for RegisterSchemaCmd {
+    fn explain(&self) -> String {
+        format!(
+            "register {} schema from file: {} with {} compatibility for topic with ID: {} and stream with ID: {}",
+            self.schema_type,
+            self.file.display(),
+            self.compatibility,
+            self.topic_id,
+            self.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let definition = tokio::fs::read(&self.file)
+            .await
+            .with_context(|| format!("Problem reading file: {}", self.file.display()))?;
+        let schema = client
+            .register_schema(
+                &self.stream_id,
+                &self.topic_id,
+                self.schema_type,
+                self.compatibility,
+                Bytes::from(definition),
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem registering schema from file: {} for topic with ID: {} and stream with ID: {}",
+                    self.file.display(),
+                    self.topic_id,
+                    self.stream_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Schema with ID: {} and version: {} registered for topic with ID: {} and stream with ID: {}",
+            schema.id, schema.version, self.topic_id, self.stream_id
+        );
+
+        Ok(())
+    }
+}
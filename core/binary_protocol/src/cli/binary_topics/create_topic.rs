@@ -22,7 +22,10 @@ use anyhow::Context;
 use async_trait::async_trait;
 use core::fmt;
 use iggy_common::create_topic::CreateTopic;
-use iggy_common::{CompressionAlgorithm, Identifier, IggyExpiry, MaxTopicSize};
+use iggy_common::{
+    CleanupPolicy, CompressionAlgorithm, Identifier, IggyExpiry, MaxTopicSize, PartitioningHash,
+    TopicOptions,
+};
 use tracing::{Level, event};
 
 pub struct CreateTopicCmd {
@@ -43,6 +46,10 @@ impl CreateTopicCmd {
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
         replication_factor: u8,
+        cleanup_policy: CleanupPolicy,
+        partitioning_hash: PartitioningHash,
+        segment_max_age: IggyExpiry,
+        max_partition_size: MaxTopicSize,
     ) -> Self {
         Self {
             create_topic: CreateTopic {
@@ -54,6 +61,10 @@ impl CreateTopicCmd {
                 message_expiry,
                 max_topic_size,
                 replication_factor: Some(replication_factor),
+                cleanup_policy,
+                partitioning_hash,
+                segment_max_age,
+                max_partition_size,
             },
             message_expiry,
             max_topic_size,
@@ -77,7 +88,22 @@ impl CliCommand for CreateTopicCmd {
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
         client
-            .create_topic(&self.create_topic.stream_id, &self.create_topic.name, self.create_topic.partitions_count, self.create_topic.compression_algorithm, self.create_topic.replication_factor, self.create_topic.topic_id, self.create_topic.message_expiry, self.create_topic.max_topic_size)
+            .create_topic(
+                &self.create_topic.stream_id,
+                &self.create_topic.name,
+                self.create_topic.partitions_count,
+                self.create_topic.compression_algorithm,
+                self.create_topic.replication_factor,
+                self.create_topic.topic_id,
+                self.create_topic.message_expiry,
+                self.create_topic.max_topic_size,
+                TopicOptions {
+                    cleanup_policy: self.create_topic.cleanup_policy,
+                    partitioning_hash: self.create_topic.partitioning_hash,
+                    segment_max_age: self.create_topic.segment_max_age,
+                    max_partition_size: self.create_topic.max_partition_size,
+                },
+            )
             .await
             .with_context(|| {
                 format!(
@@ -87,7 +113,7 @@ impl CliCommand for CreateTopicCmd {
             })?;
 
         event!(target: PRINT_TARGET, Level::INFO,
-            "Topic with name: {}, {}, partitions count: {}, compression algorithm: {}, message expiry: {}, max topic size: {}, replication factor: {} created in stream with ID: {}",
+            "Topic with name: {}, {}, partitions count: {}, compression algorithm: {}, message expiry: {}, max topic size: {}, replication factor: {}, cleanup policy: {}, partitioning hash: {}, segment max age: {}, max partition size: {} created in stream with ID: {}",
             self.create_topic.name,
             self.get_topic_id_info(),
             self.create_topic.partitions_count,
@@ -95,6 +121,10 @@ impl CliCommand for CreateTopicCmd {
             self.message_expiry,
             self.max_topic_size,
             self.replication_factor,
+            self.create_topic.cleanup_policy,
+            self.create_topic.partitioning_hash,
+            self.create_topic.segment_max_age,
+            self.create_topic.max_partition_size,
             self.create_topic.stream_id,
         );
 
@@ -110,12 +140,17 @@ impl fmt::Display for CreateTopicCmd {
         let message_expiry = &self.message_expiry;
         let max_topic_size = &self.max_topic_size;
         let replication_factor = self.replication_factor;
+        let cleanup_policy = &self.create_topic.cleanup_policy;
+        let partitioning_hash = &self.create_topic.partitioning_hash;
+        let segment_max_age = &self.create_topic.segment_max_age;
+        let max_partition_size = &self.create_topic.max_partition_size;
         let stream_id = &self.create_topic.stream_id;
 
         write!(
             f,
             "create topic with name: {topic_name}, {topic_id}, message expiry: {message_expiry}, compression algorithm: {compression_algorithm}, \
-            max topic size: {max_topic_size}, replication factor: {replication_factor} in stream with ID: {stream_id}",
+            max topic size: {max_topic_size}, replication factor: {replication_factor}, cleanup policy: {cleanup_policy}, partitioning hash: {partitioning_hash}, \
+            segment max age: {segment_max_age}, max partition size: {max_partition_size} in stream with ID: {stream_id}",
         )
     }
 }
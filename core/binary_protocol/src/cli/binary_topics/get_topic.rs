@@ -94,6 +94,27 @@ impl CliCommand for GetTopicCmd {
             "Max topic size",
             format!("{}", topic.max_topic_size).as_str(),
         ]);
+        table.add_row(vec![
+            "Cleanup policy",
+            topic.cleanup_policy.to_string().as_str(),
+        ]);
+        table.add_row(vec![
+            "Partitioning hash",
+            topic.partitioning_hash.to_string().as_str(),
+        ]);
+        table.add_row(vec![
+            "Segment max age",
+            match topic.segment_max_age {
+                IggyExpiry::NeverExpire => String::from("unlimited"),
+                IggyExpiry::ServerDefault => String::from("server_default"),
+                IggyExpiry::ExpireDuration(value) => format!("{}", value),
+            }
+            .as_str(),
+        ]);
+        table.add_row(vec![
+            "Max partition size",
+            format!("{}", topic.max_partition_size).as_str(),
+        ]);
         table.add_row(vec![
             "Topic message count",
             format!("{}", topic.messages_count).as_str(),
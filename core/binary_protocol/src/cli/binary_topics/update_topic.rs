@@ -22,7 +22,10 @@ use anyhow::Context;
 use async_trait::async_trait;
 use core::fmt;
 use iggy_common::update_topic::UpdateTopic;
-use iggy_common::{CompressionAlgorithm, Identifier, IggyExpiry, MaxTopicSize};
+use iggy_common::{
+    CleanupPolicy, CompressionAlgorithm, Identifier, IggyExpiry, MaxTopicSize, PartitioningHash,
+    TopicOptions,
+};
 use tracing::{Level, event};
 
 pub struct UpdateTopicCmd {
@@ -33,6 +36,7 @@ pub struct UpdateTopicCmd {
 }
 
 impl UpdateTopicCmd {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         stream_id: Identifier,
         topic_id: Identifier,
@@ -41,6 +45,10 @@ impl UpdateTopicCmd {
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
         replication_factor: u8,
+        cleanup_policy: CleanupPolicy,
+        partitioning_hash: PartitioningHash,
+        segment_max_age: IggyExpiry,
+        max_partition_size: MaxTopicSize,
     ) -> Self {
         Self {
             update_topic: UpdateTopic {
@@ -51,6 +59,10 @@ impl UpdateTopicCmd {
                 message_expiry,
                 max_topic_size,
                 replication_factor: Some(replication_factor),
+                cleanup_policy,
+                partitioning_hash,
+                segment_max_age,
+                max_partition_size,
             },
             message_expiry,
             max_topic_size,
@@ -67,7 +79,21 @@ impl CliCommand for UpdateTopicCmd {
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
         client
-            .update_topic(&self.update_topic.stream_id, &self.update_topic.topic_id, &self.update_topic.name, self.update_topic.compression_algorithm, self.replication_factor.into(), self.message_expiry, self.max_topic_size)
+            .update_topic(
+                &self.update_topic.stream_id,
+                &self.update_topic.topic_id,
+                &self.update_topic.name,
+                self.update_topic.compression_algorithm,
+                self.replication_factor.into(),
+                self.message_expiry,
+                self.max_topic_size,
+                TopicOptions {
+                    cleanup_policy: self.update_topic.cleanup_policy,
+                    partitioning_hash: self.update_topic.partitioning_hash,
+                    segment_max_age: self.update_topic.segment_max_age,
+                    max_partition_size: self.update_topic.max_partition_size,
+                },
+            )
             .await
             .with_context(|| {
                 format!(
@@ -80,13 +106,17 @@ impl CliCommand for UpdateTopicCmd {
             })?;
 
         event!(target: PRINT_TARGET, Level::INFO,
-            "Topic with ID: {} updated name: {}, updated message expiry: {}, updated compression algorithm: {}, updated max topic size: {}, updated replication factor: {} in stream with ID: {}",
+            "Topic with ID: {} updated name: {}, updated message expiry: {}, updated compression algorithm: {}, updated max topic size: {}, updated replication factor: {}, updated cleanup policy: {}, updated partitioning hash: {}, updated segment max age: {}, updated max partition size: {} in stream with ID: {}",
             self.update_topic.topic_id,
             self.update_topic.name,
             self.message_expiry,
             self.update_topic.compression_algorithm,
             self.max_topic_size,
             self.replication_factor,
+            self.update_topic.cleanup_policy,
+            self.update_topic.partitioning_hash,
+            self.update_topic.segment_max_age,
+            self.update_topic.max_partition_size,
             self.update_topic.stream_id,
         );
 
@@ -102,13 +132,18 @@ impl fmt::Display for UpdateTopicCmd {
         let message_expiry = &self.message_expiry;
         let max_topic_size = &self.max_topic_size;
         let replication_factor = self.replication_factor;
+        let cleanup_policy = &self.update_topic.cleanup_policy;
+        let partitioning_hash = &self.update_topic.partitioning_hash;
+        let segment_max_age = &self.update_topic.segment_max_age;
+        let max_partition_size = &self.update_topic.max_partition_size;
         let stream_id = &self.update_topic.stream_id;
 
         write!(
             f,
             "update topic with ID: {topic_id}, name: {topic_name}, message expiry: \
             {message_expiry}, compression algorithm: {compression_algorithm}, max topic size: {max_topic_size}, replication \
-            factor: {replication_factor}, in stream with ID: {stream_id}",
+            factor: {replication_factor}, cleanup policy: {cleanup_policy}, partitioning hash: {partitioning_hash}, \
+            segment max age: {segment_max_age}, max partition size: {max_partition_size}, in stream with ID: {stream_id}",
         )
     }
 }
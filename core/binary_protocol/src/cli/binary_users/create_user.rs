@@ -21,6 +21,7 @@ use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use iggy_common::Permissions;
+use iggy_common::UserQuotas;
 use iggy_common::UserStatus;
 use iggy_common::create_user::CreateUser;
 use tracing::{Level, event};
@@ -35,6 +36,7 @@ impl CreateUserCmd {
         password: String,
         status: UserStatus,
         permissions: Option<Permissions>,
+        quotas: Option<UserQuotas>,
     ) -> Self {
         Self {
             create_user: CreateUser {
@@ -42,6 +44,7 @@ impl CreateUserCmd {
                 password,
                 status,
                 permissions,
+                quotas,
             },
         }
     }
@@ -63,6 +66,7 @@ impl CliCommand for CreateUserCmd {
                 &self.create_user.password,
                 self.create_user.status,
                 self.create_user.permissions.clone(),
+                self.create_user.quotas,
             )
             .await
             .with_context(|| {
@@ -88,6 +88,14 @@ impl CliCommand for GetUserCmd {
             }
         };
 
+        if let Some(quotas) = user.quotas {
+            table.add_row(vec!["Quotas", format!("{}", quotas).as_str()]);
+        }
+
+        if !user.roles.is_empty() {
+            table.add_row(vec!["Roles", user.roles.join(", ").as_str()]);
+        }
+
         event!(target: PRINT_TARGET, Level::INFO, "{table}");
 
         Ok(())
@@ -21,6 +21,7 @@ use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use iggy_common::Identifier;
+use iggy_common::UserQuotas;
 use iggy_common::UserStatus;
 use iggy_common::update_user::UpdateUser;
 use tracing::{Level, event};
@@ -29,6 +30,7 @@ use tracing::{Level, event};
 pub enum UpdateUserType {
     Name(String),
     Status(UserStatus),
+    Quotas(UserQuotas),
 }
 
 pub struct UpdateUserCmd {
@@ -38,9 +40,10 @@ pub struct UpdateUserCmd {
 
 impl UpdateUserCmd {
     pub fn new(user_id: Identifier, update_type: UpdateUserType) -> Self {
-        let (username, status) = match update_type.clone() {
-            UpdateUserType::Name(username) => (Some(username), None),
-            UpdateUserType::Status(status) => (None, Some(status)),
+        let (username, status, quotas) = match update_type.clone() {
+            UpdateUserType::Name(username) => (Some(username), None, None),
+            UpdateUserType::Status(status) => (None, Some(status), None),
+            UpdateUserType::Quotas(quotas) => (None, None, Some(quotas)),
         };
 
         UpdateUserCmd {
@@ -49,6 +52,7 @@ impl UpdateUserCmd {
                 user_id,
                 username,
                 status,
+                quotas,
             },
         }
     }
@@ -57,6 +61,7 @@ impl UpdateUserCmd {
         match &self.update_type {
             UpdateUserType::Name(username) => format!("username: {}", username),
             UpdateUserType::Status(status) => format!("status: {}", status),
+            UpdateUserType::Quotas(quotas) => format!("quotas: {}", quotas),
         }
     }
 }
@@ -77,6 +82,7 @@ impl CliCommand for UpdateUserCmd {
                 &self.update_user.user_id,
                 self.update_user.username.as_deref(),
                 self.update_user.status,
+                self.update_user.quotas,
             )
             .await
             .with_context(|| {
@@ -21,8 +21,11 @@ pub mod binary_consumer_groups;
 pub mod binary_consumer_offsets;
 pub mod binary_context;
 pub mod binary_message;
+pub mod binary_metadata;
 pub mod binary_partitions;
 pub mod binary_personal_access_tokens;
+pub mod binary_roles;
+pub mod binary_schemas;
 pub mod binary_segments;
 pub mod binary_streams;
 pub mod binary_system;
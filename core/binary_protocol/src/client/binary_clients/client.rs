@@ -18,7 +18,8 @@
 
 use crate::{
     ConsumerGroupClient, ConsumerOffsetClient, MessageClient, PartitionClient,
-    PersonalAccessTokenClient, SegmentClient, StreamClient, SystemClient, TopicClient, UserClient,
+    PersonalAccessTokenClient, ProducerClient, RoleClient, SchemaClient, SegmentClient,
+    StreamClient, SystemClient, TopicClient, TransactionClient, UserClient,
 };
 use async_broadcast::Receiver;
 use async_trait::async_trait;
@@ -32,6 +33,7 @@ use std::fmt::Debug;
 pub trait Client:
     SystemClient
     + UserClient
+    + RoleClient
     + PersonalAccessTokenClient
     + StreamClient
     + TopicClient
@@ -40,6 +42,9 @@ pub trait Client:
     + MessageClient
     + ConsumerOffsetClient
     + ConsumerGroupClient
+    + TransactionClient
+    + ProducerClient
+    + SchemaClient
     + Sync
     + Send
     + Debug
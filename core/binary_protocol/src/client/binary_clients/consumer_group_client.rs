@@ -17,7 +17,10 @@
  */
 
 use async_trait::async_trait;
-use iggy_common::{ConsumerGroup, ConsumerGroupDetails, Identifier, IggyError};
+use iggy_common::{
+    ConsumerGroup, ConsumerGroupAssignment, ConsumerGroupAssignmentStrategy, ConsumerGroupDetails,
+    ConsumerGroupOffsetReset, ConsumerOffsetsResetTarget, Identifier, IggyError,
+};
 
 /// This trait defines the methods to interact with the consumer group module.
 #[async_trait]
@@ -48,6 +51,7 @@ pub trait ConsumerGroupClient {
         topic_id: &Identifier,
         name: &str,
         group_id: Option<u32>,
+        assignment_strategy: ConsumerGroupAssignmentStrategy,
     ) -> Result<ConsumerGroupDetails, IggyError>;
     /// Delete a consumer group by unique ID or name for the given stream and topic by unique IDs or names.
     ///
@@ -76,4 +80,43 @@ pub trait ConsumerGroupClient {
         topic_id: &Identifier,
         group_id: &Identifier,
     ) -> Result<(), IggyError>;
+    /// Get the partitions assigned to the consumer group member of the current client, along with the partitions
+    /// assigned and revoked by the rebalances since the last acknowledgement.
+    /// Providing the generation of the received assignment acknowledges the rebalance, which allows the server
+    /// to hand over the revoked partitions to the other members for the cooperative assignment strategy.
+    ///
+    /// Authentication is required, and the permission to read the streams or topics.
+    async fn sync_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        generation: u32,
+    ) -> Result<ConsumerGroupAssignment, IggyError>;
+    /// Record the failed delivery of the message with the given offset in the partition for the consumer group,
+    /// and return the number of the failed delivery attempts of the message, including this one.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn record_delivery_failure(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+    ) -> Result<u32, IggyError>;
+    /// Reset the offsets of the consumer group for the partitions of the topic to the target
+    /// and return the old and new offset of each partition. Nothing is stored on the dry run.
+    /// The offsets cannot be reset while the group has active members, unless the reset is forced.
+    ///
+    /// Authentication is required, and the permission to store the consumer offsets.
+    async fn reset_consumer_group_offsets(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        target: &ConsumerOffsetsResetTarget,
+        dry_run: bool,
+        force: bool,
+    ) -> Result<Vec<ConsumerGroupOffsetReset>, IggyError>;
 }
@@ -17,12 +17,13 @@
  */
 
 use async_trait::async_trait;
-use iggy_common::{Consumer, ConsumerOffsetInfo, Identifier, IggyError};
+use iggy_common::{Consumer, ConsumerOffsetInfo, ConsumerPartitionOffset, Identifier, IggyError};
 
 /// This trait defines the methods to interact with the consumer offset module.
 #[async_trait]
 pub trait ConsumerOffsetClient {
     /// Store the consumer offset for a specific consumer or consumer group for the given stream and topic by unique IDs or names.
+    /// The optional metadata (e.g. a processing checkpoint) is stored along with the offset.
     ///
     /// Authentication is required, and the permission to poll the messages.
     async fn store_consumer_offset(
@@ -32,6 +33,18 @@ pub trait ConsumerOffsetClient {
         topic_id: &Identifier,
         partition_id: Option<u32>,
         offset: u64,
+        metadata: Option<&str>,
+    ) -> Result<(), IggyError>;
+    /// Atomically store the consumer offsets for many partitions of the given stream and topic by unique IDs or names.
+    /// Either all the offsets are stored, or none of them.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn store_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        offsets: &[ConsumerPartitionOffset],
     ) -> Result<(), IggyError>;
     /// Get the consumer offset for a specific consumer or consumer group for the given stream and topic by unique IDs or names.
     ///
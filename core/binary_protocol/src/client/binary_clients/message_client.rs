@@ -17,7 +17,8 @@
  */
 use async_trait::async_trait;
 use iggy_common::{
-    Consumer, Identifier, IggyError, IggyMessage, Partitioning, PolledMessages, PollingStrategy,
+    Consumer, Identifier, IggyDuration, IggyError, IggyMessage, MessageFilter, Partitioning,
+    PolledMessages, PollingStrategy,
 };
 
 /// This trait defines the methods to interact with the messaging module.
@@ -38,6 +39,43 @@ pub trait MessageClient {
         auto_commit: bool,
     ) -> Result<PolledMessages, IggyError>;
 
+    /// Poll given amount of messages matching the filter expression over the user headers using the specified consumer and strategy from the specified stream and topic by unique IDs or names.
+    /// The messages are filtered on the server, and the returned `next_offset` should be used to continue polling, as the non-matching messages are skipped.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    #[allow(clippy::too_many_arguments)]
+    async fn poll_messages_with_filter(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        filter: &MessageFilter,
+    ) -> Result<PolledMessages, IggyError>;
+
+    /// Poll given amount of messages (optionally matching the filter expression over the user headers) using the specified consumer and strategy from the specified stream and topic by unique IDs or names.
+    /// If there are fewer than `min_bytes` of the messages available, the server waits up to `max_wait` for the new messages to be appended before responding (long polling).
+    /// The server may cap the `max_wait`, and the empty response is returned if no messages were appended in the meantime.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    #[allow(clippy::too_many_arguments)]
+    async fn long_poll_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        filter: Option<&MessageFilter>,
+        max_wait: IggyDuration,
+        min_bytes: u32,
+    ) -> Result<PolledMessages, IggyError>;
+
     /// Send messages using specified partitioning strategy to the given stream and topic by unique IDs or names.
     ///
     /// Authentication is required, and the permission to send the messages.
@@ -22,10 +22,14 @@ pub(crate) mod consumer_offset_client;
 pub(crate) mod message_client;
 pub(crate) mod partition_client;
 pub(crate) mod personal_access_token_client;
+pub(crate) mod producer_client;
+pub(crate) mod role_client;
+pub(crate) mod schema_client;
 pub(crate) mod segment_client;
 pub(crate) mod stream_client;
 pub(crate) mod system_client;
 pub(crate) mod topic_client;
+pub(crate) mod transaction_client;
 pub(crate) mod user_client;
 
 pub use crate::client::binary_clients::binary_client::BinaryClient;
@@ -35,8 +39,12 @@ pub use crate::client::binary_clients::consumer_offset_client::ConsumerOffsetCli
 pub use crate::client::binary_clients::message_client::MessageClient;
 pub use crate::client::binary_clients::partition_client::PartitionClient;
 pub use crate::client::binary_clients::personal_access_token_client::PersonalAccessTokenClient;
+pub use crate::client::binary_clients::producer_client::ProducerClient;
+pub use crate::client::binary_clients::role_client::RoleClient;
+pub use crate::client::binary_clients::schema_client::SchemaClient;
 pub use crate::client::binary_clients::segment_client::SegmentClient;
 pub use crate::client::binary_clients::stream_client::StreamClient;
 pub use crate::client::binary_clients::system_client::SystemClient;
 pub use crate::client::binary_clients::topic_client::TopicClient;
+pub use crate::client::binary_clients::transaction_client::TransactionClient;
 pub use crate::client::binary_clients::user_client::UserClient;
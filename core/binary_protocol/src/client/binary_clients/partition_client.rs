@@ -44,4 +44,16 @@ pub trait PartitionClient {
         topic_id: &Identifier,
         partitions_count: u32,
     ) -> Result<(), IggyError>;
+    /// Move the partition with all its segments to another data directory configured on the server.
+    ///
+    /// The closed segments are copied while the partition stays available, then the appends are paused briefly to copy the open segment and switch over.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn move_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        data_directory: &str,
+    ) -> Result<(), IggyError>;
 }
@@ -0,0 +1,46 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use async_trait::async_trait;
+use iggy_common::{
+    Identifier, IggyError, IggyMessage, Partitioning, ProducerInfo, ProducerSequence,
+};
+
+/// This trait defines the methods to interact with the idempotent producers module.
+#[async_trait]
+pub trait ProducerClient {
+    /// Initialize the idempotent producer by unique ID returned by the previous initialization, or register a new one if no ID is provided.
+    /// Each initialization bumps the epoch of the producer, so that the messages sent by its previous instance are rejected.
+    ///
+    /// Authentication is required.
+    async fn init_producer(&self, producer_id: Option<u64>) -> Result<ProducerInfo, IggyError>;
+
+    /// Send messages as the idempotent producer using specified partitioning strategy to the given stream and topic by unique IDs or names.
+    /// The batch whose sequence number has already been appended to the partition is ignored, and the out of order one is rejected,
+    /// thus the batch can be safely retried with the same sequence number. The balanced partitioning is not supported.
+    ///
+    /// Authentication is required, and the permission to send the messages.
+    async fn send_idempotent_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        producer: &ProducerSequence,
+        messages: &mut [IggyMessage],
+    ) -> Result<(), IggyError>;
+}
@@ -0,0 +1,51 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use async_trait::async_trait;
+use iggy_common::{Identifier, IggyError, Role, RoleRule};
+
+/// This trait defines the methods to interact with the roles module.
+/// The roles consist of the rules allowing or denying the actions on the streams and topics matched by name patterns.
+#[async_trait]
+pub trait RoleClient {
+    /// Get the info about all the roles.
+    ///
+    /// Authentication is required, and the permission to read the users.
+    async fn get_roles(&self) -> Result<Vec<Role>, IggyError>;
+
+    /// Create a new role with the given unique name and rules.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn create_role(&self, name: &str, rules: Vec<RoleRule>) -> Result<(), IggyError>;
+
+    /// Replace the rules of the role by unique name.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn update_role(&self, name: &str, rules: Vec<RoleRule>) -> Result<(), IggyError>;
+
+    /// Delete the role by unique name, it's also unassigned from all the users.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn delete_role(&self, name: &str) -> Result<(), IggyError>;
+
+    /// Replace the roles assigned to the user by unique ID or username, the empty list unassigns all the roles.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn assign_roles(&self, user_id: &Identifier, roles: Vec<String>)
+    -> Result<(), IggyError>;
+}
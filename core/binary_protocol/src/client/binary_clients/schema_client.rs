@@ -0,0 +1,58 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use iggy_common::{Identifier, IggyError, Schema, SchemaCompatibility, SchemaType};
+
+/// This trait defines the methods to interact with the schema registry module.
+/// The schemas are registered per topic and the messages referencing them by ID in the `iggy-schema-id` header are validated on send.
+#[async_trait]
+pub trait SchemaClient {
+    /// Get the info about a specific schema by unique ID.
+    ///
+    /// Authentication is required, and the permission to read the topic the schema belongs to.
+    async fn get_schema(&self, schema_id: u32) -> Result<Option<Schema>, IggyError>;
+
+    /// Get the info about all the versions of the schemas registered for the topic by unique IDs or names.
+    ///
+    /// Authentication is required, and the permission to read the topic.
+    async fn get_schemas(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<Schema>, IggyError>;
+
+    /// Register a new version of the schema for the topic by unique IDs or names.
+    /// The definition is checked against the latest registered version using the given compatibility mode.
+    ///
+    /// Authentication is required, and the permission to manage the topic.
+    async fn register_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        schema_type: SchemaType,
+        compatibility: SchemaCompatibility,
+        definition: Bytes,
+    ) -> Result<Schema, IggyError>;
+
+    /// Delete the schema by unique ID.
+    ///
+    /// Authentication is required, and the permission to manage the topic the schema belongs to.
+    async fn delete_schema(&self, schema_id: u32) -> Result<(), IggyError>;
+}
@@ -18,8 +18,8 @@
 
 use async_trait::async_trait;
 use iggy_common::{
-    ClientInfo, ClientInfoDetails, IggyDuration, IggyError, Snapshot, SnapshotCompression, Stats,
-    SystemSnapshotType,
+    AuditRecord, ClientInfo, ClientInfoDetails, IggyDuration, IggyError, ServerMetadata, Snapshot,
+    SnapshotCompression, Stats, SystemSnapshotType,
 };
 
 /// This trait defines the methods to interact with the system module.
@@ -52,4 +52,19 @@ pub trait SystemClient {
         compression: SnapshotCompression,
         snapshot_types: Vec<SystemSnapshotType>,
     ) -> Result<Snapshot, IggyError>;
+    /// Get the records of the audit log, starting from the record with the given ID.
+    ///
+    /// Authentication is required, and the permission to read the server info.
+    async fn get_audit_log(&self, start_id: u64, count: u32)
+    -> Result<Vec<AuditRecord>, IggyError>;
+
+    /// Export the metadata of the streams, topics, consumer groups, users and roles.
+    /// The password hashes of the users are included only if requested.
+    ///
+    /// Authentication is required, and the permission to read the server info, streams and users,
+    /// or to manage the users if the password hashes are requested.
+    async fn export_metadata(
+        &self,
+        include_password_hashes: bool,
+    ) -> Result<ServerMetadata, IggyError>;
 }
@@ -19,6 +19,7 @@
 use async_trait::async_trait;
 use iggy_common::{
     CompressionAlgorithm, Identifier, IggyError, IggyExpiry, MaxTopicSize, Topic, TopicDetails,
+    TopicOptions,
 };
 
 /// This trait defines the methods to interact with the topic module.
@@ -38,6 +39,7 @@ pub trait TopicClient {
     /// Authentication is required, and the permission to read the topics.
     async fn get_topics(&self, stream_id: &Identifier) -> Result<Vec<Topic>, IggyError>;
     /// Create a new topic.
+    /// The optional settings are provided in `options`, `TopicOptions::default()` keeps their defaults.
     ///
     /// Authentication is required, and the permission to manage the topics.
     #[allow(clippy::too_many_arguments)]
@@ -51,8 +53,10 @@ pub trait TopicClient {
         topic_id: Option<u32>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+        options: TopicOptions,
     ) -> Result<TopicDetails, IggyError>;
     /// Update a topic by unique ID or name.
+    /// The optional settings are replaced with the ones provided in `options`.
     ///
     /// Authentication is required, and the permission to manage the topics.
     async fn update_topic(
@@ -64,6 +68,7 @@ pub trait TopicClient {
         replication_factor: Option<u8>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+        options: TopicOptions,
     ) -> Result<(), IggyError>;
     /// Delete a topic by unique ID or name.
     ///
@@ -0,0 +1,72 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use async_trait::async_trait;
+use iggy_common::{
+    Consumer, Identifier, IggyError, IggyMessage, Partitioning, PolledMessages, PollingStrategy,
+    TransactionInfo,
+};
+
+/// This trait defines the methods to interact with the transactions module.
+#[async_trait]
+pub trait TransactionClient {
+    /// Begin a new transaction, within which the messages can be sent to the multiple partitions and topics, and then committed or aborted atomically.
+    /// The transaction which is neither committed nor aborted within the configured timeout is aborted by the server.
+    ///
+    /// Authentication is required.
+    async fn begin_transaction(&self) -> Result<TransactionInfo, IggyError>;
+
+    /// Send messages within the transaction using specified partitioning strategy to the given stream and topic by unique IDs or names.
+    /// The messages are visible to the consumers polling with the `read_committed` isolation level only once the transaction has been committed.
+    ///
+    /// Authentication is required, and the permission to send the messages.
+    async fn send_transaction_messages(
+        &self,
+        transaction_id: u64,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [IggyMessage],
+    ) -> Result<(), IggyError>;
+
+    /// Commit the transaction by unique ID, so that all the messages sent within it become visible to the consumers polling with the `read_committed` isolation level.
+    ///
+    /// Authentication is required, and only the user who has begun the transaction can commit it.
+    async fn commit_transaction(&self, transaction_id: u64) -> Result<(), IggyError>;
+
+    /// Abort the transaction by unique ID, so that none of the messages sent within it are visible to the consumers polling with the `read_committed` isolation level.
+    ///
+    /// Authentication is required, and only the user who has begun the transaction can abort it.
+    async fn abort_transaction(&self, transaction_id: u64) -> Result<(), IggyError>;
+
+    /// Poll given amount of messages using the `read_committed` isolation level, the specified consumer and strategy from the specified stream and topic by unique IDs or names.
+    /// The messages of the open or aborted transactions are skipped, and the returned `next_offset` should be used to continue polling.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    #[allow(clippy::too_many_arguments)]
+    async fn poll_committed_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+    ) -> Result<PolledMessages, IggyError>;
+}
@@ -18,7 +18,8 @@
 
 use async_trait::async_trait;
 use iggy_common::{
-    Identifier, IdentityInfo, IggyError, Permissions, UserInfo, UserInfoDetails, UserStatus,
+    Identifier, IdentityInfo, IggyError, Permissions, UserInfo, UserInfoDetails, UserQuotas,
+    UserStatus,
 };
 
 /// This trait defines the methods to interact with the user module.
@@ -41,6 +42,18 @@ pub trait UserClient {
         password: &str,
         status: UserStatus,
         permissions: Option<Permissions>,
+        quotas: Option<UserQuotas>,
+    ) -> Result<UserInfoDetails, IggyError>;
+    /// Create a new user with the password hash exported from another server, e.g. along with the metadata.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn import_user(
+        &self,
+        username: &str,
+        password_hash: &str,
+        status: UserStatus,
+        permissions: Option<Permissions>,
+        quotas: Option<UserQuotas>,
     ) -> Result<UserInfoDetails, IggyError>;
     /// Delete a user by unique ID or username.
     ///
@@ -54,6 +67,7 @@ pub trait UserClient {
         user_id: &Identifier,
         username: Option<&str>,
         status: Option<UserStatus>,
+        quotas: Option<UserQuotas>,
     ) -> Result<(), IggyError>;
     /// Update the permissions of a user by unique ID or username.
     ///
@@ -74,6 +88,8 @@ pub trait UserClient {
     ) -> Result<(), IggyError>;
     /// Login a user by username and password.
     async fn login_user(&self, username: &str, password: &str) -> Result<IdentityInfo, IggyError>;
+    /// Login a user with the JSON Web Token issued by one of the external issuers configured on the server.
+    async fn login_with_token(&self, token: &str) -> Result<IdentityInfo, IggyError>;
     /// Logout the currently authenticated user.
     async fn logout_user(&self) -> Result<(), IggyError>;
 }
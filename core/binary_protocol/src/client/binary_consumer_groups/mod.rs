@@ -26,7 +26,13 @@ use iggy_common::get_consumer_group::GetConsumerGroup;
 use iggy_common::get_consumer_groups::GetConsumerGroups;
 use iggy_common::join_consumer_group::JoinConsumerGroup;
 use iggy_common::leave_consumer_group::LeaveConsumerGroup;
-use iggy_common::{ConsumerGroup, ConsumerGroupDetails, Identifier, IggyError};
+use iggy_common::record_delivery_failure::RecordDeliveryFailure;
+use iggy_common::reset_consumer_group_offsets::ResetConsumerGroupOffsets;
+use iggy_common::sync_consumer_group::SyncConsumerGroup;
+use iggy_common::{
+    ConsumerGroup, ConsumerGroupAssignment, ConsumerGroupAssignmentStrategy, ConsumerGroupDetails,
+    ConsumerGroupOffsetReset, ConsumerOffsetsResetTarget, Identifier, IggyError,
+};
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> ConsumerGroupClient for B {
@@ -72,6 +78,7 @@ impl<B: BinaryClient> ConsumerGroupClient for B {
         topic_id: &Identifier,
         name: &str,
         group_id: Option<u32>,
+        assignment_strategy: ConsumerGroupAssignmentStrategy,
     ) -> Result<ConsumerGroupDetails, IggyError> {
         fail_if_not_authenticated(self).await?;
         let response = self
@@ -80,6 +87,7 @@ impl<B: BinaryClient> ConsumerGroupClient for B {
                 topic_id: topic_id.clone(),
                 name: name.to_string(),
                 group_id,
+                assignment_strategy,
             })
             .await?;
         mapper::map_consumer_group(response)
@@ -132,4 +140,66 @@ impl<B: BinaryClient> ConsumerGroupClient for B {
         .await?;
         Ok(())
     }
+
+    async fn sync_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        generation: u32,
+    ) -> Result<ConsumerGroupAssignment, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&SyncConsumerGroup {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                group_id: group_id.clone(),
+                generation,
+            })
+            .await?;
+        mapper::map_consumer_group_assignment(response)
+    }
+
+    async fn record_delivery_failure(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+    ) -> Result<u32, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&RecordDeliveryFailure {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                group_id: group_id.clone(),
+                partition_id,
+                offset,
+            })
+            .await?;
+        mapper::map_delivery_attempts(response)
+    }
+    async fn reset_consumer_group_offsets(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        target: &ConsumerOffsetsResetTarget,
+        dry_run: bool,
+        force: bool,
+    ) -> Result<Vec<ConsumerGroupOffsetReset>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&ResetConsumerGroupOffsets {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                group_id: group_id.clone(),
+                target: target.clone(),
+                dry_run,
+                force,
+            })
+            .await?;
+        mapper::map_consumer_group_offset_resets(response)
+    }
 }
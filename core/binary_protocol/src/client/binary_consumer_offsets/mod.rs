@@ -22,7 +22,8 @@ use crate::utils::mapper;
 use iggy_common::delete_consumer_offset::DeleteConsumerOffset;
 use iggy_common::get_consumer_offset::GetConsumerOffset;
 use iggy_common::store_consumer_offset::StoreConsumerOffset;
-use iggy_common::{Consumer, ConsumerOffsetInfo, Identifier, IggyError};
+use iggy_common::store_consumer_offsets::StoreConsumerOffsets;
+use iggy_common::{Consumer, ConsumerOffsetInfo, ConsumerPartitionOffset, Identifier, IggyError};
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> ConsumerOffsetClient for B {
@@ -33,6 +34,7 @@ impl<B: BinaryClient> ConsumerOffsetClient for B {
         topic_id: &Identifier,
         partition_id: Option<u32>,
         offset: u64,
+        metadata: Option<&str>,
     ) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         self.send_with_response(&StoreConsumerOffset {
@@ -41,6 +43,25 @@ impl<B: BinaryClient> ConsumerOffsetClient for B {
             topic_id: topic_id.clone(),
             partition_id,
             offset,
+            metadata: metadata.map(|metadata| metadata.to_string()),
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn store_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        offsets: &[ConsumerPartitionOffset],
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&StoreConsumerOffsets {
+            consumer: consumer.clone(),
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            offsets: offsets.to_vec(),
         })
         .await?;
         Ok(())
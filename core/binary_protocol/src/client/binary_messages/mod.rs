@@ -18,9 +18,9 @@
 use crate::utils::auth::fail_if_not_authenticated;
 use crate::{BinaryClient, MessageClient};
 use iggy_common::{
-    BytesSerializable, Consumer, FlushUnsavedBuffer, Identifier, IggyError, IggyMessage,
-    POLL_MESSAGES_CODE, Partitioning, PollMessages, PolledMessages, PollingStrategy,
-    SEND_MESSAGES_CODE, SendMessages,
+    BytesSerializable, Consumer, FlushUnsavedBuffer, Identifier, IggyDuration, IggyError,
+    IggyMessage, IsolationLevel, MessageFilter, POLL_MESSAGES_CODE, Partitioning, PollMessages,
+    PolledMessages, PollingStrategy, SEND_MESSAGES_CODE, SendMessages,
 };
 
 #[async_trait::async_trait]
@@ -47,12 +47,88 @@ impl<B: BinaryClient> MessageClient for B {
                     strategy,
                     count,
                     auto_commit,
+                    None,
+                    IsolationLevel::ReadUncommitted,
+                    IggyDuration::default(),
+                    0,
                 ),
             )
             .await?;
         PolledMessages::from_bytes(response)
     }
 
+    async fn poll_messages_with_filter(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        filter: &MessageFilter,
+    ) -> Result<PolledMessages, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_raw_with_response(
+                POLL_MESSAGES_CODE,
+                PollMessages::bytes(
+                    stream_id,
+                    topic_id,
+                    partition_id,
+                    consumer,
+                    strategy,
+                    count,
+                    auto_commit,
+                    Some(filter),
+                    IsolationLevel::ReadUncommitted,
+                    IggyDuration::default(),
+                    0,
+                ),
+            )
+            .await?;
+        PolledMessages::from_filtered_bytes(response)
+    }
+
+    async fn long_poll_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        filter: Option<&MessageFilter>,
+        max_wait: IggyDuration,
+        min_bytes: u32,
+    ) -> Result<PolledMessages, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_raw_with_response(
+                POLL_MESSAGES_CODE,
+                PollMessages::bytes(
+                    stream_id,
+                    topic_id,
+                    partition_id,
+                    consumer,
+                    strategy,
+                    count,
+                    auto_commit,
+                    filter,
+                    IsolationLevel::ReadUncommitted,
+                    max_wait,
+                    min_bytes,
+                ),
+            )
+            .await?;
+        if filter.is_some() {
+            PolledMessages::from_filtered_bytes(response)
+        } else {
+            PolledMessages::from_bytes(response)
+        }
+    }
+
     async fn send_messages(
         &self,
         stream_id: &Identifier,
@@ -63,7 +139,7 @@ impl<B: BinaryClient> MessageClient for B {
         fail_if_not_authenticated(self).await?;
         self.send_raw_with_response(
             SEND_MESSAGES_CODE,
-            SendMessages::bytes(stream_id, topic_id, partitioning, messages),
+            SendMessages::bytes(stream_id, topic_id, partitioning, None, messages),
         )
         .await?;
         Ok(())
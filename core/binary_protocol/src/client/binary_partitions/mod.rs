@@ -20,6 +20,7 @@ use crate::utils::auth::fail_if_not_authenticated;
 use crate::{BinaryClient, PartitionClient};
 use iggy_common::create_partitions::CreatePartitions;
 use iggy_common::delete_partitions::DeletePartitions;
+use iggy_common::move_partition::MovePartition;
 use iggy_common::{Identifier, IggyError};
 
 #[async_trait::async_trait]
@@ -55,4 +56,22 @@ impl<B: BinaryClient> PartitionClient for B {
         .await?;
         Ok(())
     }
+
+    async fn move_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        data_directory: &str,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&MovePartition {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partition_id,
+            data_directory: data_directory.to_string(),
+        })
+        .await?;
+        Ok(())
+    }
 }
@@ -0,0 +1,56 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::utils::auth::fail_if_not_authenticated;
+use crate::utils::mapper;
+use crate::{BinaryClient, ProducerClient};
+use iggy_common::init_producer::InitProducer;
+use iggy_common::{
+    Identifier, IggyError, IggyMessage, Partitioning, ProducerInfo, ProducerSequence,
+    SEND_MESSAGES_CODE, SendMessages,
+};
+
+#[async_trait::async_trait]
+impl<B: BinaryClient> ProducerClient for B {
+    async fn init_producer(&self, producer_id: Option<u64>) -> Result<ProducerInfo, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&InitProducer {
+                producer_id: producer_id.unwrap_or_default(),
+            })
+            .await?;
+        mapper::map_producer_info(response)
+    }
+
+    async fn send_idempotent_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        producer: &ProducerSequence,
+        messages: &mut [IggyMessage],
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_raw_with_response(
+            SEND_MESSAGES_CODE,
+            SendMessages::bytes(stream_id, topic_id, partitioning, Some(producer), messages),
+        )
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::utils::auth::fail_if_not_authenticated;
+use crate::utils::mapper;
+use crate::{BinaryClient, RoleClient};
+use iggy_common::assign_roles::AssignRoles;
+use iggy_common::create_role::CreateRole;
+use iggy_common::delete_role::DeleteRole;
+use iggy_common::get_roles::GetRoles;
+use iggy_common::update_role::UpdateRole;
+use iggy_common::{Identifier, IggyError, Role, RoleRule};
+
+#[async_trait::async_trait]
+impl<B: BinaryClient> RoleClient for B {
+    async fn get_roles(&self) -> Result<Vec<Role>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&GetRoles {}).await?;
+        mapper::map_roles(response)
+    }
+
+    async fn create_role(&self, name: &str, rules: Vec<RoleRule>) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&CreateRole {
+            name: name.to_string(),
+            rules,
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn update_role(&self, name: &str, rules: Vec<RoleRule>) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&UpdateRole {
+            name: name.to_string(),
+            rules,
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_role(&self, name: &str) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&DeleteRole {
+            name: name.to_string(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn assign_roles(
+        &self,
+        user_id: &Identifier,
+        roles: Vec<String>,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&AssignRoles {
+            user_id: user_id.clone(),
+            roles,
+        })
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::utils::auth::fail_if_not_authenticated;
+use crate::utils::mapper;
+use crate::{BinaryClient, SchemaClient};
+use bytes::Bytes;
+use iggy_common::delete_schema::DeleteSchema;
+use iggy_common::get_schema::GetSchema;
+use iggy_common::get_schemas::GetSchemas;
+use iggy_common::register_schema::RegisterSchema;
+use iggy_common::{
+    BytesSerializable, Identifier, IggyError, Schema, SchemaCompatibility, SchemaType,
+};
+
+#[async_trait::async_trait]
+impl<B: BinaryClient> SchemaClient for B {
+    async fn get_schema(&self, schema_id: u32) -> Result<Option<Schema>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&GetSchema { schema_id }).await?;
+        if response.is_empty() {
+            return Ok(None);
+        }
+
+        Schema::from_bytes(response).map(Some)
+    }
+
+    async fn get_schemas(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<Schema>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetSchemas {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+            })
+            .await?;
+        mapper::map_schemas(response)
+    }
+
+    async fn register_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        schema_type: SchemaType,
+        compatibility: SchemaCompatibility,
+        definition: Bytes,
+    ) -> Result<Schema, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&RegisterSchema {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                schema_type,
+                compatibility,
+                definition,
+            })
+            .await?;
+        Schema::from_bytes(response)
+    }
+
+    async fn delete_schema(&self, schema_id: u32) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&DeleteSchema { schema_id }).await?;
+        Ok(())
+    }
+}
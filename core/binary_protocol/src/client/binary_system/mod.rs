@@ -19,6 +19,8 @@
 use crate::utils::auth::fail_if_not_authenticated;
 use crate::utils::mapper;
 use crate::{BinaryClient, SystemClient};
+use iggy_common::export_metadata::ExportMetadata;
+use iggy_common::get_audit_log::GetAuditLog;
 use iggy_common::get_client::GetClient;
 use iggy_common::get_clients::GetClients;
 use iggy_common::get_me::GetMe;
@@ -26,8 +28,8 @@ use iggy_common::get_snapshot::GetSnapshot;
 use iggy_common::get_stats::GetStats;
 use iggy_common::ping::Ping;
 use iggy_common::{
-    ClientInfo, ClientInfoDetails, IggyDuration, IggyError, Snapshot, SnapshotCompression, Stats,
-    SystemSnapshotType,
+    AuditRecord, BytesSerializable, ClientInfo, ClientInfoDetails, IggyDuration, IggyError,
+    ServerMetadata, Snapshot, SnapshotCompression, Stats, SystemSnapshotType,
 };
 
 #[async_trait::async_trait]
@@ -83,4 +85,29 @@ impl<B: BinaryClient> SystemClient for B {
         let snapshot = Snapshot::new(response.to_vec());
         Ok(snapshot)
     }
+
+    async fn get_audit_log(
+        &self,
+        start_id: u64,
+        count: u32,
+    ) -> Result<Vec<AuditRecord>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetAuditLog { start_id, count })
+            .await?;
+        mapper::map_audit_records(response)
+    }
+
+    async fn export_metadata(
+        &self,
+        include_password_hashes: bool,
+    ) -> Result<ServerMetadata, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&ExportMetadata {
+                include_password_hashes,
+            })
+            .await?;
+        ServerMetadata::from_bytes(response)
+    }
 }
@@ -27,6 +27,7 @@ use iggy_common::purge_topic::PurgeTopic;
 use iggy_common::update_topic::UpdateTopic;
 use iggy_common::{
     CompressionAlgorithm, Identifier, IggyError, IggyExpiry, MaxTopicSize, Topic, TopicDetails,
+    TopicOptions,
 };
 
 #[async_trait::async_trait]
@@ -70,6 +71,7 @@ impl<B: BinaryClient> TopicClient for B {
         topic_id: Option<u32>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+        options: TopicOptions,
     ) -> Result<TopicDetails, IggyError> {
         fail_if_not_authenticated(self).await?;
         let response = self
@@ -82,6 +84,10 @@ impl<B: BinaryClient> TopicClient for B {
                 topic_id,
                 message_expiry,
                 max_topic_size,
+                cleanup_policy: options.cleanup_policy,
+                partitioning_hash: options.partitioning_hash,
+                segment_max_age: options.segment_max_age,
+                max_partition_size: options.max_partition_size,
             })
             .await?;
         mapper::map_topic(response)
@@ -96,6 +102,7 @@ impl<B: BinaryClient> TopicClient for B {
         replication_factor: Option<u8>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+        options: TopicOptions,
     ) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         self.send_with_response(&UpdateTopic {
@@ -106,6 +113,10 @@ impl<B: BinaryClient> TopicClient for B {
             replication_factor,
             message_expiry,
             max_topic_size,
+            cleanup_policy: options.cleanup_policy,
+            partitioning_hash: options.partitioning_hash,
+            segment_max_age: options.segment_max_age,
+            max_partition_size: options.max_partition_size,
         })
         .await?;
         Ok(())
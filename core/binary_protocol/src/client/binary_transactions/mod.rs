@@ -0,0 +1,108 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::utils::auth::fail_if_not_authenticated;
+use crate::utils::mapper;
+use crate::{BinaryClient, TransactionClient};
+use iggy_common::abort_transaction::AbortTransaction;
+use iggy_common::begin_transaction::BeginTransaction;
+use iggy_common::commit_transaction::CommitTransaction;
+use iggy_common::send_transaction_messages::SendTransactionMessages;
+use iggy_common::{
+    Consumer, Identifier, IggyDuration, IggyError, IggyMessage, IsolationLevel, POLL_MESSAGES_CODE,
+    Partitioning, PollMessages, PolledMessages, PollingStrategy, SEND_TRANSACTION_MESSAGES_CODE,
+    TransactionInfo,
+};
+
+#[async_trait::async_trait]
+impl<B: BinaryClient> TransactionClient for B {
+    async fn begin_transaction(&self) -> Result<TransactionInfo, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&BeginTransaction {}).await?;
+        mapper::map_transaction_info(response)
+    }
+
+    async fn send_transaction_messages(
+        &self,
+        transaction_id: u64,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [IggyMessage],
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_raw_with_response(
+            SEND_TRANSACTION_MESSAGES_CODE,
+            SendTransactionMessages::bytes(
+                transaction_id,
+                stream_id,
+                topic_id,
+                partitioning,
+                messages,
+            ),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn commit_transaction(&self, transaction_id: u64) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&CommitTransaction { transaction_id })
+            .await?;
+        Ok(())
+    }
+
+    async fn abort_transaction(&self, transaction_id: u64) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&AbortTransaction { transaction_id })
+            .await?;
+        Ok(())
+    }
+
+    async fn poll_committed_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+    ) -> Result<PolledMessages, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_raw_with_response(
+                POLL_MESSAGES_CODE,
+                PollMessages::bytes(
+                    stream_id,
+                    topic_id,
+                    partition_id,
+                    consumer,
+                    strategy,
+                    count,
+                    auto_commit,
+                    None,
+                    IsolationLevel::ReadCommitted,
+                    IggyDuration::default(),
+                    0,
+                ),
+            )
+            .await?;
+        PolledMessages::from_filtered_bytes(response)
+    }
+}
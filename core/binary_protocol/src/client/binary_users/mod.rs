@@ -24,13 +24,15 @@ use iggy_common::create_user::CreateUser;
 use iggy_common::delete_user::DeleteUser;
 use iggy_common::get_user::GetUser;
 use iggy_common::get_users::GetUsers;
+use iggy_common::import_user::ImportUser;
 use iggy_common::login_user::LoginUser;
+use iggy_common::login_with_token::LoginWithToken;
 use iggy_common::logout_user::LogoutUser;
 use iggy_common::update_permissions::UpdatePermissions;
 use iggy_common::update_user::UpdateUser;
 use iggy_common::{
     ClientState, DiagnosticEvent, Identifier, IdentityInfo, IggyError, Permissions, UserInfo,
-    UserInfoDetails, UserStatus,
+    UserInfoDetails, UserQuotas, UserStatus,
 };
 
 #[async_trait::async_trait]
@@ -61,6 +63,7 @@ impl<B: BinaryClient> UserClient for B {
         password: &str,
         status: UserStatus,
         permissions: Option<Permissions>,
+        quotas: Option<UserQuotas>,
     ) -> Result<UserInfoDetails, IggyError> {
         fail_if_not_authenticated(self).await?;
         let response = self
@@ -69,6 +72,28 @@ impl<B: BinaryClient> UserClient for B {
                 password: password.to_string(),
                 status,
                 permissions,
+                quotas,
+            })
+            .await?;
+        mapper::map_user(response)
+    }
+
+    async fn import_user(
+        &self,
+        username: &str,
+        password_hash: &str,
+        status: UserStatus,
+        permissions: Option<Permissions>,
+        quotas: Option<UserQuotas>,
+    ) -> Result<UserInfoDetails, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&ImportUser {
+                username: username.to_string(),
+                password_hash: password_hash.to_string(),
+                status,
+                permissions,
+                quotas,
             })
             .await?;
         mapper::map_user(response)
@@ -88,12 +113,14 @@ impl<B: BinaryClient> UserClient for B {
         user_id: &Identifier,
         username: Option<&str>,
         status: Option<UserStatus>,
+        quotas: Option<UserQuotas>,
     ) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         self.send_with_response(&UpdateUser {
             user_id: user_id.clone(),
             username: username.map(|s| s.to_string()),
             status,
+            quotas,
         })
         .await?;
         Ok(())
@@ -143,6 +170,17 @@ impl<B: BinaryClient> UserClient for B {
         mapper::map_identity_info(response)
     }
 
+    async fn login_with_token(&self, token: &str) -> Result<IdentityInfo, IggyError> {
+        let response = self
+            .send_with_response(&LoginWithToken {
+                token: token.to_string(),
+            })
+            .await?;
+        self.set_state(ClientState::Authenticated).await;
+        self.publish_event(DiagnosticEvent::SignedIn).await;
+        mapper::map_identity_info(response)
+    }
+
     async fn logout_user(&self) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         self.send_with_response(&LogoutUser {}).await?;
@@ -18,11 +18,13 @@
 
 use bytes::Bytes;
 use iggy_common::{
-    BytesSerializable, CacheMetrics, CacheMetricsKey, ClientInfo, ClientInfoDetails,
-    CompressionAlgorithm, ConsumerGroup, ConsumerGroupDetails, ConsumerGroupInfo,
-    ConsumerGroupMember, ConsumerOffsetInfo, IdentityInfo, IggyByteSize, IggyError, IggyExpiry,
-    MaxTopicSize, Partition, Permissions, PersonalAccessTokenInfo, RawPersonalAccessToken, Stats,
-    Stream, StreamDetails, Topic, TopicDetails, UserInfo, UserInfoDetails, UserStatus,
+    AuditRecord, BytesSerializable, CacheMetrics, CacheMetricsKey, CleanupPolicy, ClientInfo,
+    ClientInfoDetails, CompressionAlgorithm, ConsumerGroup, ConsumerGroupAssignment,
+    ConsumerGroupDetails, ConsumerGroupInfo, ConsumerGroupMember, ConsumerGroupOffsetReset,
+    ConsumerOffsetInfo, IdentityInfo, IggyByteSize, IggyError, IggyExpiry, IggyTimestamp,
+    MaxTopicSize, Partition, PartitioningHash, Permissions, PersonalAccessTokenInfo, ProducerInfo,
+    RawPersonalAccessToken, Role, Schema, Stats, Stream, StreamDetails, Topic, TopicDetails,
+    TransactionInfo, UserInfo, UserInfoDetails, UserQuotas, UserStatus,
 };
 use std::collections::HashMap;
 use std::str::from_utf8;
@@ -33,6 +35,7 @@ const EMPTY_CLIENTS: Vec<ClientInfo> = vec![];
 const EMPTY_USERS: Vec<UserInfo> = vec![];
 const EMPTY_PERSONAL_ACCESS_TOKENS: Vec<PersonalAccessTokenInfo> = vec![];
 const EMPTY_CONSUMER_GROUPS: Vec<ConsumerGroup> = vec![];
+const EMPTY_AUDIT_RECORDS: Vec<AuditRecord> = vec![];
 
 pub fn map_stats(payload: Bytes) -> Result<Stats, IggyError> {
     let process_id = u32::from_le_bytes(
@@ -384,15 +387,45 @@ pub fn map_consumer_offset(payload: Bytes) -> Result<ConsumerOffsetInfo, IggyErr
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
     );
+    // The commit time and metadata are missing in the responses of the older servers.
+    let (committed_at, metadata) = if payload.len() >= 30 {
+        let committed_at = u64::from_le_bytes(
+            payload[20..28]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let metadata_length = u16::from_le_bytes(
+            payload[28..30]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        let metadata = if metadata_length > 0 {
+            let metadata = payload
+                .get(30..30 + metadata_length)
+                .ok_or(IggyError::InvalidCommand)?;
+            Some(
+                from_utf8(metadata)
+                    .map_err(|_| IggyError::InvalidUtf8)?
+                    .to_string(),
+            )
+        } else {
+            None
+        };
+        (committed_at.into(), metadata)
+    } else {
+        (IggyTimestamp::zero(), None)
+    };
     Ok(ConsumerOffsetInfo {
         partition_id,
         current_offset,
         stored_offset,
+        metadata,
+        committed_at,
     })
 }
 
 pub fn map_user(payload: Bytes) -> Result<UserInfoDetails, IggyError> {
-    let (user, position) = map_to_user_info(payload.clone(), 0)?;
+    let (user, mut position) = map_to_user_info(payload.clone(), 0)?;
     let has_permissions = payload[position];
     let permissions = if has_permissions == 1 {
         let permissions_length = u32::from_le_bytes(
@@ -401,21 +434,116 @@ pub fn map_user(payload: Bytes) -> Result<UserInfoDetails, IggyError> {
                 .map_err(|_| IggyError::InvalidNumberEncoding)?,
         ) as usize;
         let permissions = payload.slice(position + 5..position + 5 + permissions_length);
+        position += 5 + permissions_length;
         Some(Permissions::from_bytes(permissions)?)
     } else {
+        // The missing permissions are always written as 4 zeroed bytes.
+        position += 4;
         None
     };
 
+    // The quotas are not returned by the older servers.
+    let quotas = match payload.get(position) {
+        Some(1) => {
+            position += 1;
+            if payload.len() < position + UserQuotas::SIZE {
+                return Err(IggyError::InvalidCommand);
+            }
+            let quotas =
+                UserQuotas::from_bytes(payload.slice(position..position + UserQuotas::SIZE))?;
+            position += UserQuotas::SIZE;
+            Some(quotas)
+        }
+        Some(_) => {
+            position += 1;
+            None
+        }
+        None => None,
+    };
+
+    // The roles are not returned by the older servers.
+    let mut roles = Vec::new();
+    if payload.len() >= position + 4 {
+        let roles_count = u32::from_le_bytes(
+            payload[position..position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 4;
+        for _ in 0..roles_count {
+            let name_length = *payload.get(position).ok_or(IggyError::InvalidCommand)? as usize;
+            let name = payload
+                .get(position + 1..position + 1 + name_length)
+                .ok_or(IggyError::InvalidCommand)?;
+            roles.push(
+                from_utf8(name)
+                    .map_err(|_| IggyError::InvalidUtf8)?
+                    .to_string(),
+            );
+            position += 1 + name_length;
+        }
+    }
+
     let user = UserInfoDetails {
         id: user.id,
         created_at: user.created_at,
         status: user.status,
         username: user.username,
         permissions,
+        quotas,
+        roles,
     };
     Ok(user)
 }
 
+pub fn map_roles(payload: Bytes) -> Result<Vec<Role>, IggyError> {
+    let mut roles = Vec::new();
+    let mut position = 0;
+    while position < payload.len() {
+        let role_length = u32::from_le_bytes(
+            payload
+                .get(position..position + 4)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        position += 4;
+        if payload.len() < position + role_length {
+            return Err(IggyError::InvalidCommand);
+        }
+        roles.push(Role::from_bytes(
+            payload.slice(position..position + role_length),
+        )?);
+        position += role_length;
+    }
+    roles.sort_by(|x, y| x.name.cmp(&y.name));
+    Ok(roles)
+}
+
+pub fn map_schemas(payload: Bytes) -> Result<Vec<Schema>, IggyError> {
+    let mut schemas = Vec::new();
+    let mut position = 0;
+    while position < payload.len() {
+        let schema_length = u32::from_le_bytes(
+            payload
+                .get(position..position + 4)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        position += 4;
+        if payload.len() < position + schema_length {
+            return Err(IggyError::InvalidCommand);
+        }
+        schemas.push(Schema::from_bytes(
+            payload.slice(position..position + schema_length),
+        )?);
+        position += schema_length;
+    }
+    schemas.sort_by_key(|schema| schema.version);
+    Ok(schemas)
+}
+
 pub fn map_users(payload: Bytes) -> Result<Vec<UserInfo>, IggyError> {
     if payload.is_empty() {
         return Ok(EMPTY_USERS);
@@ -472,6 +600,33 @@ pub fn map_raw_pat(payload: Bytes) -> Result<RawPersonalAccessToken, IggyError>
     Ok(RawPersonalAccessToken { token })
 }
 
+pub fn map_transaction_info(payload: Bytes) -> Result<TransactionInfo, IggyError> {
+    let id = u64::from_le_bytes(
+        payload[..8]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    Ok(TransactionInfo { id })
+}
+
+pub fn map_producer_info(payload: Bytes) -> Result<ProducerInfo, IggyError> {
+    if payload.len() != 12 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let id = u64::from_le_bytes(
+        payload[..8]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let epoch = u32::from_le_bytes(
+        payload[8..12]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    Ok(ProducerInfo { id, epoch })
+}
+
 pub fn map_client(payload: Bytes) -> Result<ClientInfoDetails, IggyError> {
     let (client, mut position) = map_to_client_info(payload.clone(), 0)?;
     let mut consumer_groups = Vec::new();
@@ -532,6 +687,23 @@ pub fn map_clients(payload: Bytes) -> Result<Vec<ClientInfo>, IggyError> {
     Ok(clients)
 }
 
+pub fn map_audit_records(payload: Bytes) -> Result<Vec<AuditRecord>, IggyError> {
+    if payload.is_empty() {
+        return Ok(EMPTY_AUDIT_RECORDS);
+    }
+
+    let mut records = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let (record, read_bytes) = map_to_audit_record(payload.clone(), position)?;
+        records.push(record);
+        position += read_bytes;
+    }
+    records.sort_by_key(|record| record.id);
+    Ok(records)
+}
+
 pub fn map_streams(payload: Bytes) -> Result<Vec<Stream>, IggyError> {
     if payload.is_empty() {
         return Ok(EMPTY_STREAMS);
@@ -656,6 +828,10 @@ pub fn map_topic(payload: Bytes) -> Result<TopicDetails, IggyError> {
         compression_algorithm: topic.compression_algorithm,
         max_topic_size: topic.max_topic_size,
         replication_factor: topic.replication_factor,
+        cleanup_policy: topic.cleanup_policy,
+        partitioning_hash: topic.partitioning_hash,
+        segment_max_age: topic.segment_max_age,
+        max_partition_size: topic.max_partition_size,
         #[allow(clippy::cast_possible_truncation)]
         partitions_count: partitions.len() as u32,
         partitions,
@@ -696,21 +872,36 @@ fn map_to_topic(payload: Bytes, position: usize) -> Result<(Topic, usize), IggyE
     );
     let max_topic_size: MaxTopicSize = max_topic_size.into();
     let replication_factor = payload[position + 33];
+    let cleanup_policy = CleanupPolicy::from_code(payload[position + 34])?;
+    let partitioning_hash =
+        PartitioningHash::from_code(payload[position + 35], payload[position + 36])?;
+    let segment_max_age: IggyExpiry = u64::from_le_bytes(
+        payload[position + 37..position + 45]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    )
+    .into();
+    let max_partition_size: MaxTopicSize = u64::from_le_bytes(
+        payload[position + 45..position + 53]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    )
+    .into();
     let size_bytes = IggyByteSize::from(u64::from_le_bytes(
-        payload[position + 34..position + 42]
+        payload[position + 53..position + 61]
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
     ));
     let messages_count = u64::from_le_bytes(
-        payload[position + 42..position + 50]
+        payload[position + 61..position + 69]
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
     );
-    let name_length = payload[position + 50];
-    let name = from_utf8(&payload[position + 51..position + 51 + name_length as usize])
+    let name_length = payload[position + 69];
+    let name = from_utf8(&payload[position + 70..position + 70 + name_length as usize])
         .map_err(|_| IggyError::InvalidUtf8)?
         .to_string();
-    let read_bytes = 4 + 8 + 4 + 8 + 8 + 8 + 8 + 1 + 1 + 1 + name_length as usize;
+    let read_bytes = 4 + 8 + 4 + 8 + 8 + 8 + 8 + 1 + 1 + 1 + 1 + 2 + 8 + 8 + name_length as usize;
     Ok((
         Topic {
             id,
@@ -723,6 +914,10 @@ fn map_to_topic(payload: Bytes, position: usize) -> Result<(Topic, usize), IggyE
             compression_algorithm,
             max_topic_size,
             replication_factor,
+            cleanup_policy,
+            partitioning_hash,
+            segment_max_age,
+            max_partition_size,
         },
         read_bytes,
     ))
@@ -812,6 +1007,64 @@ pub fn map_consumer_group(payload: Bytes) -> Result<ConsumerGroupDetails, IggyEr
     Ok(consumer_group_details)
 }
 
+pub fn map_consumer_group_assignment(payload: Bytes) -> Result<ConsumerGroupAssignment, IggyError> {
+    let generation = u32::from_le_bytes(
+        payload
+            .get(..4)
+            .ok_or(IggyError::InvalidCommand)?
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let mut position = 4;
+    let (partitions, read_bytes) = map_to_partition_ids(&payload, position)?;
+    position += read_bytes;
+    let (assigned_partitions, read_bytes) = map_to_partition_ids(&payload, position)?;
+    position += read_bytes;
+    let (revoked_partitions, _) = map_to_partition_ids(&payload, position)?;
+    Ok(ConsumerGroupAssignment {
+        generation,
+        partitions,
+        assigned_partitions,
+        revoked_partitions,
+    })
+}
+
+pub fn map_delivery_attempts(payload: Bytes) -> Result<u32, IggyError> {
+    if payload.len() != 4 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let attempts = u32::from_le_bytes(
+        payload[..4]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    Ok(attempts)
+}
+
+fn map_to_partition_ids(payload: &Bytes, position: usize) -> Result<(Vec<u32>, usize), IggyError> {
+    let count = u32::from_le_bytes(
+        payload
+            .get(position..position + 4)
+            .ok_or(IggyError::InvalidCommand)?
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    ) as usize;
+    let mut partition_ids = Vec::with_capacity(count);
+    for i in 0..count {
+        let start = position + 4 + i * 4;
+        let partition_id = u32::from_le_bytes(
+            payload
+                .get(start..start + 4)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        partition_ids.push(partition_id);
+    }
+    Ok((partition_ids, 4 + count * 4))
+}
+
 fn map_to_consumer_group(
     payload: Bytes,
     position: usize,
@@ -882,6 +1135,85 @@ fn map_to_consumer_group_member(
     ))
 }
 
+fn map_to_audit_record(
+    payload: Bytes,
+    mut position: usize,
+) -> Result<(AuditRecord, usize), IggyError> {
+    let start_position = position;
+    let id = u64::from_le_bytes(
+        payload[position..position + 8]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let timestamp = u64::from_le_bytes(
+        payload[position + 8..position + 16]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    )
+    .into();
+    let user_id = u32::from_le_bytes(
+        payload[position + 16..position + 20]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let user_id = match user_id {
+        0 => None,
+        _ => Some(user_id),
+    };
+    let error_code = u32::from_le_bytes(
+        payload[position + 20..position + 24]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let error_code = match error_code {
+        0 => None,
+        _ => Some(error_code),
+    };
+    let previous_checksum = u32::from_le_bytes(
+        payload[position + 24..position + 28]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let checksum = u32::from_le_bytes(
+        payload[position + 28..position + 32]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    position += 32;
+    let mut values = Vec::with_capacity(4);
+    for _ in 0..4 {
+        let value_length = u32::from_le_bytes(
+            payload[position..position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        let value = from_utf8(&payload[position + 4..position + 4 + value_length])
+            .map_err(|_| IggyError::InvalidUtf8)?
+            .to_string();
+        values.push(value);
+        position += 4 + value_length;
+    }
+    let target = values.pop().unwrap_or_default();
+    let command = values.pop().unwrap_or_default();
+    let transport = values.pop().unwrap_or_default();
+    let address = values.pop().unwrap_or_default();
+    Ok((
+        AuditRecord {
+            id,
+            timestamp,
+            user_id,
+            address,
+            transport,
+            command,
+            target,
+            error_code,
+            previous_checksum,
+            checksum,
+        },
+        position - start_position,
+    ))
+}
+
 fn map_to_client_info(
     payload: Bytes,
     mut position: usize,
@@ -990,3 +1322,43 @@ fn map_to_pat_info(
     let read_bytes = 1 + name_length as usize + 8;
     Ok((PersonalAccessTokenInfo { name, expiry_at }, read_bytes))
 }
+
+pub fn map_consumer_group_offset_resets(
+    payload: Bytes,
+) -> Result<Vec<ConsumerGroupOffsetReset>, IggyError> {
+    if !payload.len().is_multiple_of(30) {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let mut resets = Vec::with_capacity(payload.len() / 30);
+    for chunk in payload.chunks_exact(30) {
+        let partition_id = u32::from_le_bytes(
+            chunk[..4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let current_offset = u64::from_le_bytes(
+            chunk[4..12]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let mut offsets = [None; 2];
+        for (index, offset) in offsets.iter_mut().enumerate() {
+            let position = 12 + index * 9;
+            if chunk[position] == 1 {
+                *offset = Some(u64::from_le_bytes(
+                    chunk[position + 1..position + 9]
+                        .try_into()
+                        .map_err(|_| IggyError::InvalidNumberEncoding)?,
+                ));
+            }
+        }
+        resets.push(ConsumerGroupOffsetReset {
+            partition_id,
+            current_offset,
+            old_offset: offsets[0],
+            new_offset: offsets[1],
+        });
+    }
+    Ok(resets)
+}
@@ -21,6 +21,8 @@ use iggy_binary_protocol::cli::binary_client::get_clients::GetClientsOutput;
 use iggy_binary_protocol::cli::binary_consumer_groups::get_consumer_groups::GetConsumerGroupsOutput;
 use iggy_binary_protocol::cli::binary_context::get_contexts::GetContextsOutput;
 use iggy_binary_protocol::cli::binary_personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokensOutput;
+use iggy_binary_protocol::cli::binary_roles::get_roles::GetRolesOutput;
+use iggy_binary_protocol::cli::binary_schemas::get_schemas::GetSchemasOutput;
 use iggy_binary_protocol::cli::binary_streams::get_streams::GetStreamsOutput;
 use iggy_binary_protocol::cli::binary_system::stats::GetStatsOutput;
 use iggy_binary_protocol::cli::binary_topics::get_topics::GetTopicsOutput;
@@ -59,6 +61,24 @@ impl From<ListMode> for GetPersonalAccessTokensOutput {
     }
 }
 
+impl From<ListMode> for GetRolesOutput {
+    fn from(mode: ListMode) -> Self {
+        match mode {
+            ListMode::Table => GetRolesOutput::Table,
+            ListMode::List => GetRolesOutput::List,
+        }
+    }
+}
+
+impl From<ListMode> for GetSchemasOutput {
+    fn from(mode: ListMode) -> Self {
+        match mode {
+            ListMode::Table => GetSchemasOutput::Table,
+            ListMode::List => GetSchemasOutput::List,
+        }
+    }
+}
+
 impl From<ListMode> for GetUsersOutput {
     fn from(mode: ListMode) -> Self {
         match mode {
@@ -17,8 +17,12 @@
  */
 
 use crate::args::common::ListMode;
-use clap::{Args, Subcommand};
-use iggy::prelude::Identifier;
+use clap::{ArgGroup, Args, Subcommand};
+use iggy::prelude::{
+    ConsumerGroupAssignmentStrategy, ConsumerOffsetsResetTarget, ConsumerPartitionOffset,
+    Identifier,
+};
+use std::fs;
 
 #[derive(Debug, Clone, Subcommand)]
 pub(crate) enum ConsumerGroupAction {
@@ -81,6 +85,21 @@ pub(crate) enum ConsumerGroupAction {
     ///  iggy consumer-group list production sensor -l table
     #[clap(verbatim_doc_comment, visible_alias = "l")]
     List(ConsumerGroupListArgs),
+    /// Reset offsets of consumer group with given ID for given stream ID and topic ID
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    /// Consumer group ID can be specified as a consumer group name or ID
+    /// Offsets cannot be reset while the consumer group has members, unless forced
+    ///
+    /// Examples:
+    ///  iggy consumer-group reset-offsets 1 2 3 --to-earliest
+    ///  iggy consumer-group reset-offsets stream topic group --to-latest --dry-run
+    ///  iggy consumer-group reset-offsets stream topic group --to-timestamp 1735689600000000
+    ///  iggy consumer-group reset-offsets stream topic group --shift-by -100 --force
+    ///  iggy consumer-group reset-offsets stream topic group --from-csv offsets.csv
+    #[clap(verbatim_doc_comment, visible_alias = "r")]
+    ResetOffsets(ConsumerGroupResetOffsetsArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -100,6 +119,9 @@ pub(crate) struct ConsumerGroupCreateArgs {
     pub(crate) group_id: Option<u32>,
     /// Consumer group name to create
     pub(crate) name: String,
+    /// Strategy of assigning the partitions to the members, either "round_robin" or "cooperative_sticky"
+    #[arg(short, long, default_value = "round_robin", value_parser = clap::value_parser!(ConsumerGroupAssignmentStrategy))]
+    pub(crate) assignment_strategy: ConsumerGroupAssignmentStrategy,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -156,3 +178,130 @@ pub(crate) struct ConsumerGroupListArgs {
     #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
     pub(crate) list_mode: ListMode,
 }
+
+#[derive(Debug, Clone, Args)]
+#[command(group = ArgGroup::new("target").required(true))]
+pub(crate) struct ConsumerGroupResetOffsetsArgs {
+    /// Stream ID to reset consumer group offsets
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to reset consumer group offsets
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Consumer group ID to reset offsets
+    ///
+    /// Consumer group ID can be specified as a consumer group name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) group_id: Identifier,
+    /// Reset offsets to the first available message in each partition
+    #[clap(long, default_value_t = false, group = "target")]
+    pub(crate) to_earliest: bool,
+    /// Reset offsets to the end of each partition, skipping all the existing messages
+    #[clap(long, default_value_t = false, group = "target")]
+    pub(crate) to_latest: bool,
+    /// Reset offsets to the first message with given or later timestamp
+    ///
+    /// Timestamp must be specified in microseconds since the Unix epoch
+    #[clap(verbatim_doc_comment)]
+    #[clap(long, group = "target")]
+    pub(crate) to_timestamp: Option<u64>,
+    /// Shift offsets by given number of messages, negative value moves them back
+    #[clap(long, allow_negative_numbers = true, group = "target")]
+    pub(crate) shift_by: Option<i64>,
+    /// Reset offsets to the values from the CSV file
+    ///
+    /// Each line of the file contains the partition ID and the offset,
+    /// e.g. "1,100", the partitions missing in the file are left intact
+    #[clap(verbatim_doc_comment)]
+    #[clap(long, value_parser = parse_offsets_csv_file, group = "target")]
+    pub(crate) from_csv: Option<ConsumerOffsetsCsv>,
+    /// Only print the old and new offsets without storing them
+    #[clap(short, long, default_value_t = false)]
+    pub(crate) dry_run: bool,
+    /// Reset offsets even if the consumer group has members
+    #[clap(short, long, default_value_t = false)]
+    pub(crate) force: bool,
+}
+
+impl ConsumerGroupResetOffsetsArgs {
+    pub(crate) fn target(&self) -> ConsumerOffsetsResetTarget {
+        if let Some(timestamp) = self.to_timestamp {
+            ConsumerOffsetsResetTarget::Timestamp(timestamp)
+        } else if let Some(shift) = self.shift_by {
+            ConsumerOffsetsResetTarget::Shift(shift)
+        } else if let Some(csv) = &self.from_csv {
+            ConsumerOffsetsResetTarget::Offsets(csv.0.clone())
+        } else if self.to_latest {
+            ConsumerOffsetsResetTarget::Latest
+        } else {
+            ConsumerOffsetsResetTarget::Earliest
+        }
+    }
+}
+
+/// Consumer offsets read from the CSV file
+#[derive(Debug, Clone)]
+pub(crate) struct ConsumerOffsetsCsv(Vec<ConsumerPartitionOffset>);
+
+fn parse_offsets_csv_file(path: &str) -> Result<ConsumerOffsetsCsv, String> {
+    let content =
+        fs::read_to_string(path).map_err(|error| format!("cannot read file {path}: {error}"))?;
+    parse_offsets_csv(&content).map(ConsumerOffsetsCsv)
+}
+
+fn parse_offsets_csv(content: &str) -> Result<Vec<ConsumerPartitionOffset>, String> {
+    let mut offsets = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line == "partition_id,offset" {
+            continue;
+        }
+
+        let (partition_id, offset) = line
+            .split_once(',')
+            .ok_or(format!("invalid line {}: {line}", index + 1))?;
+        let partition_id = partition_id
+            .trim()
+            .parse::<u32>()
+            .map_err(|error| format!("invalid partition ID in line {}: {error}", index + 1))?;
+        let offset = offset
+            .trim()
+            .parse::<u64>()
+            .map_err(|error| format!("invalid offset in line {}: {error}", index + 1))?;
+        offsets.push(ConsumerPartitionOffset::new(partition_id, offset, None));
+    }
+
+    if offsets.is_empty() {
+        return Err("no offsets found".to_string());
+    }
+
+    Ok(offsets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_offsets_csv_should_skip_header_comments_and_empty_lines() {
+        let offsets = parse_offsets_csv("partition_id,offset\n# comment\n1,100\n\n 2 , 200 \n");
+        assert_eq!(
+            offsets,
+            Ok(vec![
+                ConsumerPartitionOffset::new(1, 100, None),
+                ConsumerPartitionOffset::new(2, 200, None),
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_offsets_csv_invalid_line_should_return_err() {
+        assert!(parse_offsets_csv("1;100").is_err());
+        assert!(parse_offsets_csv("1,-100").is_err());
+        assert!(parse_offsets_csv("").is_err());
+    }
+}
@@ -102,4 +102,7 @@ pub(crate) struct ConsumerOffsetSetArgs {
     pub(crate) partition_id: u32,
     /// Offset to set
     pub(crate) offset: u64,
+    /// Optional metadata stored along with the offset, e.g. a processing checkpoint
+    #[arg(short, long)]
+    pub(crate) metadata: Option<String>,
 }
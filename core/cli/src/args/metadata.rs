@@ -0,0 +1,109 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use clap::{Args, Subcommand, ValueEnum};
+use iggy_binary_protocol::cli::binary_metadata::MetadataFormat;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Subcommand)]
+pub(crate) enum MetadataAction {
+    /// Export metadata of streams, topics, consumer groups, users and roles
+    ///
+    /// Exported metadata contains topics configuration, partitions counts,
+    /// users permissions, quotas, roles and personal access tokens names.
+    /// Password hashes of the users are exported only when requested.
+    ///
+    /// Examples
+    ///  iggy metadata export
+    ///  iggy metadata export --format yaml
+    ///  iggy metadata export --output metadata.json --include-password-hashes
+    #[clap(verbatim_doc_comment, visible_alias = "e")]
+    Export(MetadataExportArgs),
+    /// Apply metadata from given file to the server
+    ///
+    /// Streams, topics, consumer groups, users and roles are matched by their
+    /// names, the missing ones are created and the changed ones are updated.
+    /// The ones which are not present in the file, as well as the partitions
+    /// above the count from the file, are deleted only with --prune. Plan of
+    /// the operations is printed before applying them. Users can be created
+    /// only if their password or the exported password hash is provided in
+    /// the file, root user is never changed.
+    ///
+    /// The plan is applied by the CLI using the regular commands one by one,
+    /// so it is not atomic and the server has no dedicated apply command.
+    ///
+    /// Examples
+    ///  iggy metadata apply metadata.toml
+    ///  iggy metadata apply metadata.yaml --dry-run
+    ///  iggy metadata apply metadata.json --prune
+    #[clap(verbatim_doc_comment, visible_alias = "a")]
+    Apply(MetadataApplyArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct MetadataExportArgs {
+    /// Format of the exported metadata
+    ///
+    /// If not set, format is detected from the output file extension
+    /// and TOML is used by default.
+    #[arg(short, long, value_enum, verbatim_doc_comment)]
+    pub(crate) format: Option<MetadataFormatArg>,
+    /// Include password hashes of the users
+    #[arg(short, long, default_value_t = false)]
+    pub(crate) include_password_hashes: bool,
+    /// Write exported metadata to given file instead of standard output
+    #[arg(short, long)]
+    pub(crate) output: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct MetadataApplyArgs {
+    /// Path to the file with metadata
+    pub(crate) file: PathBuf,
+    /// Format of the metadata file
+    ///
+    /// If not set, format is detected from the file extension.
+    #[arg(short, long, value_enum, verbatim_doc_comment)]
+    pub(crate) format: Option<MetadataFormatArg>,
+    /// Print plan of the operations without applying them
+    #[arg(short, long, default_value_t = false)]
+    pub(crate) dry_run: bool,
+    /// Delete the resources which are not present in the file
+    ///
+    /// Without this flag the file is merged into the server metadata,
+    /// so a partial file never deletes the existing resources.
+    #[arg(long, default_value_t = false, verbatim_doc_comment)]
+    pub(crate) prune: bool,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub(crate) enum MetadataFormatArg {
+    Toml,
+    Json,
+    Yaml,
+}
+
+impl From<MetadataFormatArg> for MetadataFormat {
+    fn from(format: MetadataFormatArg) -> Self {
+        match format {
+            MetadataFormatArg::Toml => MetadataFormat::Toml,
+            MetadataFormatArg::Json => MetadataFormat::Json,
+            MetadataFormatArg::Yaml => MetadataFormat::Yaml,
+        }
+    }
+}
@@ -34,8 +34,10 @@ use crate::args::{
     consumer_offset::ConsumerOffsetAction,
     context::ContextAction,
     message::MessageAction,
+    metadata::MetadataAction,
     partition::PartitionAction,
     personal_access_token::PersonalAccessTokenAction,
+    schema::SchemaAction,
     stream::StreamAction,
     system::{PingArgs, StatsArgs},
     topic::TopicAction,
@@ -44,6 +46,7 @@ use crate::args::{
 #[cfg(feature = "login-session")]
 use crate::args::system::LoginArgs;
 
+use self::role::RoleAction;
 use self::user::UserAction;
 
 pub(crate) mod client;
@@ -52,9 +55,12 @@ pub(crate) mod consumer_group;
 pub(crate) mod consumer_offset;
 pub(crate) mod context;
 pub(crate) mod message;
+pub(crate) mod metadata;
 pub(crate) mod partition;
 pub(crate) mod permissions;
 pub(crate) mod personal_access_token;
+pub(crate) mod role;
+pub(crate) mod schema;
 pub(crate) mod segment;
 pub(crate) mod stream;
 pub(crate) mod system;
@@ -170,6 +176,9 @@ pub(crate) enum Command {
     /// user operations
     #[command(subcommand, visible_alias = "u")]
     User(UserAction),
+    /// role operations
+    #[command(subcommand, visible_alias = "r")]
+    Role(RoleAction),
     /// client operations
     #[command(subcommand, visible_alias = "c")]
     Client(ClientAction),
@@ -179,12 +188,18 @@ pub(crate) enum Command {
     /// consumer offset operations
     #[command(subcommand, visible_alias = "o")]
     ConsumerOffset(ConsumerOffsetAction),
+    /// schema registry operations
+    #[command(subcommand, visible_alias = "sc")]
+    Schema(SchemaAction),
     /// message operations
     #[command(subcommand, visible_alias = "m")]
     Message(MessageAction),
     /// context operations
     #[command(subcommand, visible_alias = "ctx")]
     Context(ContextAction),
+    /// metadata export and apply operations
+    #[command(subcommand, visible_alias = "md")]
+    Metadata(MetadataAction),
     #[cfg(feature = "login-session")]
     /// login to Iggy server
     ///
@@ -47,6 +47,20 @@ pub(crate) enum PartitionAction {
     ///  iggy partition delete 1 sensor 16
     #[clap(verbatim_doc_comment, visible_alias = "d")]
     Delete(PartitionDeleteArgs),
+    /// Move partition with given ID for the specified topic ID
+    /// and stream ID to another data directory configured on the server.
+    ///
+    /// The partition stays available while its segments are copied,
+    /// only the appends are paused briefly to switch over to the new directory.
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy partition move 1 1 1 /mnt/disk2/iggy
+    ///  iggy partition move prod sensor 2 /mnt/disk3/iggy
+    #[clap(verbatim_doc_comment, visible_alias = "m")]
+    Move(PartitionMoveArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -82,3 +96,24 @@ pub(crate) struct PartitionDeleteArgs {
     #[arg(value_parser = clap::value_parser!(u32).range(1..100_001))]
     pub(crate) partitions_count: u32,
 }
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct PartitionMoveArgs {
+    /// Stream ID of the partition to move
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID of the partition to move
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Partition ID to move
+    #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) partition_id: u32,
+    /// Data directory to move the partition to
+    ///
+    /// Must be either the system path or one of the data directories configured on the server
+    pub(crate) data_directory: String,
+}
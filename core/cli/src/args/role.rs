@@ -0,0 +1,204 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::args::common::ListMode;
+use clap::{Args, Subcommand};
+use iggy::prelude::{Identifier, ResourcePattern, RoleAction as RuleAction, RoleRule};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Subcommand)]
+pub(crate) enum RoleAction {
+    /// Create role with given name and rules
+    ///
+    /// Examples
+    ///  iggy role create tenant --allow stream:tenant-*=read_stream,send_messages
+    ///  iggy role create reader -a topic:*=poll_messages -d stream:internal=poll_messages
+    #[clap(verbatim_doc_comment, visible_alias = "c")]
+    Create(RoleCreateArgs),
+    /// Replace rules of the role with given name
+    ///
+    /// Examples
+    ///  iggy role update tenant --allow stream:tenant-*/topic:orders-*=manage_topic
+    #[clap(verbatim_doc_comment, visible_alias = "u")]
+    Update(RoleUpdateArgs),
+    /// Delete role with given name
+    ///
+    /// The role is also unassigned from all the users.
+    ///
+    /// Examples
+    ///  iggy role delete tenant
+    #[clap(verbatim_doc_comment, visible_alias = "d")]
+    Delete(RoleDeleteArgs),
+    /// List all roles
+    ///
+    /// Examples
+    ///  iggy role list
+    ///  iggy role list --list-mode list
+    #[clap(verbatim_doc_comment, visible_alias = "l")]
+    List(RoleListArgs),
+    /// Assign roles to user with given ID
+    ///
+    /// The user ID can be specified as either a username or an ID.
+    /// Roles assigned to the user earlier are replaced, if no roles are
+    /// provided, all the roles are unassigned from the user.
+    ///
+    /// Examples
+    ///  iggy role assign 2 tenant reader
+    ///  iggy role assign testuser
+    #[clap(verbatim_doc_comment, visible_alias = "a")]
+    Assign(RoleAssignArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct RoleRulesArgs {
+    /// Rule allowing the actions on the matching streams and topics
+    ///
+    /// Rule format consists of resource pattern followed by equals sign (=)
+    /// and list of actions separated by comma (,). Resource pattern is
+    /// stream:STREAM_NAME[/topic:TOPIC_NAME] where names can contain
+    /// * and ? wildcards, topic:TOPIC_NAME matches topics of all streams.
+    ///
+    /// Available actions: manage_stream, read_stream, manage_topic, read_topic,
+    /// poll_messages, send_messages.
+    #[clap(short, long, verbatim_doc_comment)]
+    #[arg(value_parser = clap::value_parser!(RoleRuleArg))]
+    pub(crate) allow: Vec<RoleRuleArg>,
+    /// Rule denying the actions on the matching streams and topics
+    ///
+    /// Uses the same format as the allow rule. Deny rules take precedence
+    /// over allow rules and the regular permissions of the user. Denying
+    /// an action denies also the actions it implies, e.g. denying
+    /// read_stream denies read_topic and poll_messages too.
+    #[clap(short, long, verbatim_doc_comment)]
+    #[arg(value_parser = clap::value_parser!(RoleRuleArg))]
+    pub(crate) deny: Vec<RoleRuleArg>,
+}
+
+impl RoleRulesArgs {
+    pub(crate) fn rules(&self) -> Vec<RoleRule> {
+        let allow = self
+            .allow
+            .iter()
+            .map(|rule| RoleRule::allow(rule.resource.clone(), &rule.actions));
+        let deny = self
+            .deny
+            .iter()
+            .map(|rule| RoleRule::deny(rule.resource.clone(), &rule.actions));
+        allow.chain(deny).collect()
+    }
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct RoleCreateArgs {
+    /// Name of the role
+    ///
+    /// Unique name of the role, must be between 3 and 50 characters long.
+    #[clap(verbatim_doc_comment)]
+    pub(crate) name: String,
+    #[clap(flatten)]
+    pub(crate) rules: RoleRulesArgs,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct RoleUpdateArgs {
+    /// Name of the role to update
+    pub(crate) name: String,
+    #[clap(flatten)]
+    pub(crate) rules: RoleRulesArgs,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct RoleDeleteArgs {
+    /// Name of the role to delete
+    pub(crate) name: String,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct RoleListArgs {
+    /// List mode (table or list)
+    #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
+    pub(crate) list_mode: ListMode,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct RoleAssignArgs {
+    /// User ID to assign the roles to
+    ///
+    /// The user ID can be specified as either a username or an ID
+    pub(crate) user_id: Identifier,
+    /// Names of the roles to assign
+    pub(crate) roles: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct RoleRuleArg {
+    pub(crate) resource: ResourcePattern,
+    pub(crate) actions: Vec<RuleAction>,
+}
+
+impl FromStr for RoleRuleArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (resource, actions) = s
+            .rsplit_once('=')
+            .ok_or(format!("Missing actions for rule: {s}"))?;
+        let resource = resource
+            .parse::<ResourcePattern>()
+            .map_err(|_| format!("Invalid resource pattern: {resource}"))?;
+        let actions = actions
+            .split(',')
+            .map(|action| action.trim().parse::<RuleAction>())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { resource, actions })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_deserialize_rule() {
+        let rule = RoleRuleArg::from_str("stream:tenant-*/topic:orders=read_topic,poll_messages");
+        assert_eq!(
+            rule,
+            Ok(RoleRuleArg {
+                resource: ResourcePattern::topic("tenant-*", "orders"),
+                actions: vec![RuleAction::ReadTopic, RuleAction::PollMessages],
+            })
+        );
+    }
+
+    #[test]
+    fn should_not_deserialize_rule_without_actions() {
+        assert!(RoleRuleArg::from_str("stream:tenant").is_err());
+    }
+
+    #[test]
+    fn should_not_deserialize_rule_with_unknown_action() {
+        let rule = RoleRuleArg::from_str("stream:tenant=read_stream,purge");
+        assert_eq!(rule, Err("Unknown role action: purge".to_string()));
+    }
+
+    #[test]
+    fn should_not_deserialize_rule_with_invalid_resource() {
+        assert!(RoleRuleArg::from_str("queue:tenant=read_stream").is_err());
+    }
+}
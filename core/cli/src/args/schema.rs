@@ -0,0 +1,118 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::args::common::ListMode;
+use clap::{Args, Subcommand};
+use iggy::prelude::{Identifier, SchemaCompatibility, SchemaType};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Subcommand)]
+pub(crate) enum SchemaAction {
+    /// Register next version of the schema for topic with given ID
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// The definition is read from the given file and checked against the latest
+    /// version registered for the topic using the requested compatibility.
+    /// JSON and Avro definitions are JSON documents, protobuf definition is
+    /// a serialized FileDescriptorSet, e.g. produced by protoc --descriptor_set_out.
+    ///
+    /// Examples
+    ///  iggy schema register 1 1 json order.schema.json
+    ///  iggy schema register prod orders avro order.avsc --compatibility full
+    ///  iggy schema register prod events protobuf events.desc -c none
+    #[clap(verbatim_doc_comment, visible_alias = "r")]
+    Register(SchemaRegisterArgs),
+    /// Delete schema with given ID
+    ///
+    /// Messages referencing the deleted schema are no longer accepted,
+    /// its ID is never reused.
+    ///
+    /// Examples
+    ///  iggy schema delete 3
+    #[clap(verbatim_doc_comment, visible_alias = "d")]
+    Delete(SchemaDeleteArgs),
+    /// Get details of the schema with given ID
+    ///
+    /// Examples
+    ///  iggy schema get 3
+    #[clap(verbatim_doc_comment, visible_alias = "g")]
+    Get(SchemaGetArgs),
+    /// List all versions of the schemas registered for topic with given ID
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy schema list 1 1
+    ///  iggy schema list prod orders --list-mode list
+    #[clap(verbatim_doc_comment, visible_alias = "l")]
+    List(SchemaListArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct SchemaRegisterArgs {
+    /// Stream ID to register schema for
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to register schema for
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Type of the schema, either "json", "avro" or "protobuf"
+    #[arg(value_parser = clap::value_parser!(SchemaType))]
+    pub(crate) schema_type: SchemaType,
+    /// Path to the file with the schema definition
+    pub(crate) file: PathBuf,
+    /// Compatibility with the latest version, either "none", "backward", "forward" or "full"
+    #[arg(short, long, default_value = "backward", value_parser = clap::value_parser!(SchemaCompatibility))]
+    pub(crate) compatibility: SchemaCompatibility,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct SchemaDeleteArgs {
+    /// Schema ID to delete
+    pub(crate) schema_id: u32,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct SchemaGetArgs {
+    /// Schema ID to get
+    pub(crate) schema_id: u32,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct SchemaListArgs {
+    /// Stream ID to list schemas of
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to list schemas of
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// List mode (table or list)
+    #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
+    pub(crate) list_mode: ListMode,
+}
@@ -18,7 +18,9 @@
 
 use crate::args::common::ListMode;
 use clap::{Args, Subcommand};
-use iggy::prelude::{CompressionAlgorithm, Identifier, IggyExpiry, MaxTopicSize};
+use iggy::prelude::{
+    CleanupPolicy, CompressionAlgorithm, Identifier, IggyExpiry, MaxTopicSize, PartitioningHash,
+};
 
 #[derive(Debug, Clone, Subcommand)]
 pub(crate) enum TopicAction {
@@ -110,6 +112,8 @@ pub(crate) struct TopicCreateArgs {
     /// Number of partitions inside the topic
     pub(crate) partitions_count: u32,
     /// Compression algorithm for the topic, set to "none" for no compression
+    ///
+    /// Available algorithms: "none", "gzip", "zstd", "lz4" and "snappy"
     #[arg(value_parser = clap::value_parser!(CompressionAlgorithm), verbatim_doc_comment)]
     pub(crate) compression_algorithm: CompressionAlgorithm,
     /// Max topic size in human-readable format like "unlimited" or "15GB"
@@ -121,6 +125,26 @@ pub(crate) struct TopicCreateArgs {
     /// Replication factor for the topic
     #[arg(short, long, default_value = "1")]
     pub(crate) replication_factor: u8,
+    /// Cleanup policy for the topic, either "delete" or "compact"
+    #[arg(short, long, default_value = "delete", value_parser = clap::value_parser!(CleanupPolicy))]
+    pub(crate) cleanup_policy: CleanupPolicy,
+    /// Hashing of the messages key to the partitions in the "algorithm[:mode]" format
+    ///
+    /// Algorithm is one of "xxhash32", "murmur2" (Kafka compatible) or "xxhash3",
+    /// mode is either "modulo" (default) or "jump" (consistent hashing).
+    #[arg(long, default_value = "xxhash32", value_parser = clap::value_parser!(PartitioningHash))]
+    pub(crate) partitioning_hash: PartitioningHash,
+    /// Max age of the open segment after which it is closed and a new one is started, in human-readable format like "none" or "1h"
+    ///
+    /// "server_default" or skipping parameter makes CLI to use server default (from current server config) segment max age
+    #[arg(long, default_value = "server_default", value_parser = clap::value_parser!(IggyExpiry), verbatim_doc_comment)]
+    pub(crate) segment_max_age: IggyExpiry,
+    /// Max size of a single partition in human-readable format like "unlimited" or "5GB"
+    ///
+    /// "server_default" or skipping parameter makes CLI to use server default (from current server config) max partition size
+    /// Can't be lower than segment size in the config.
+    #[arg(long, default_value = "server_default", verbatim_doc_comment)]
+    pub(crate) max_partition_size: MaxTopicSize,
     /// Message expiry time in human-readable format like "unlimited" or "15days 2min 2s"
     ///
     /// "server_default" or skipping parameter makes CLI to use server default (from current server config) expiry time
@@ -157,6 +181,8 @@ pub(crate) struct TopicUpdateArgs {
     /// New name for the topic
     pub(crate) name: String,
     /// Compression algorithm for the topic, set to "none" for no compression
+    ///
+    /// Available algorithms: "none", "gzip", "zstd", "lz4" and "snappy"
     #[arg(value_parser = clap::value_parser!(CompressionAlgorithm), verbatim_doc_comment)]
     pub(crate) compression_algorithm: CompressionAlgorithm,
     /// New max topic size in human-readable format like "unlimited" or "15GB"
@@ -168,6 +194,26 @@ pub(crate) struct TopicUpdateArgs {
     #[arg(short, long, default_value = "1")]
     /// New replication factor for the topic
     pub(crate) replication_factor: u8,
+    /// New cleanup policy for the topic, either "delete" or "compact"
+    #[arg(short, long, default_value = "delete", value_parser = clap::value_parser!(CleanupPolicy))]
+    pub(crate) cleanup_policy: CleanupPolicy,
+    /// New hashing of the messages key to the partitions in the "algorithm[:mode]" format
+    ///
+    /// Algorithm is one of "xxhash32", "murmur2" (Kafka compatible) or "xxhash3",
+    /// mode is either "modulo" (default) or "jump" (consistent hashing).
+    #[arg(long, default_value = "xxhash32", value_parser = clap::value_parser!(PartitioningHash))]
+    pub(crate) partitioning_hash: PartitioningHash,
+    /// New max age of the open segment after which it is closed and a new one is started, in human-readable format like "none" or "1h"
+    ///
+    /// "server_default" or skipping parameter makes CLI to use server default (from current server config) segment max age
+    #[arg(long, default_value = "server_default", value_parser = clap::value_parser!(IggyExpiry), verbatim_doc_comment)]
+    pub(crate) segment_max_age: IggyExpiry,
+    /// New max size of a single partition in human-readable format like "unlimited" or "5GB"
+    ///
+    /// "server_default" or skipping parameter makes CLI to use server default (from current server config) max partition size
+    /// Can't be lower than segment size in the config.
+    #[arg(long, default_value = "server_default", verbatim_doc_comment)]
+    pub(crate) max_partition_size: MaxTopicSize,
     /// New message expiry time in human-readable format like "unlimited" or "15days 2min 2s"
     ///
     /// "server_default" or skipping parameter makes CLI to use server default (from current server config) expiry time
@@ -20,7 +20,7 @@ use crate::args::common::ListMode;
 use crate::args::permissions::UserStatusArg;
 use crate::args::permissions::stream::StreamPermissionsArg;
 use clap::{Args, Subcommand};
-use iggy::prelude::Identifier;
+use iggy::prelude::{Identifier, IggyByteSize, UserQuotas};
 
 use super::permissions::global::GlobalPermissionsArg;
 
@@ -100,6 +100,19 @@ pub(crate) enum UserAction {
     ///  iggy user permissions client
     #[clap(verbatim_doc_comment, visible_alias = "p")]
     Permissions(UserPermissionsArgs),
+    /// Set quotas for user with given ID
+    ///
+    /// The user ID can be specified as either a username or an ID. Quotas
+    /// are configured based on the options provided with this command. If no
+    /// options are set, the default behavior is to remove quotas for the
+    /// specified user.
+    ///
+    /// Examples:
+    ///  iggy user quotas 2
+    ///  iggy user quotas client --max-produced-bytes-per-second 10MB
+    ///  iggy user quotas 3 --max-consumed-bytes-per-second 1GB --max-connections 10
+    #[clap(verbatim_doc_comment, visible_alias = "q")]
+    Quotas(UserQuotasArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -171,6 +184,8 @@ pub(crate) struct UserCreateArgs {
     #[clap(short, long, verbatim_doc_comment)]
     #[arg(value_parser = clap::value_parser!(StreamPermissionsArg))]
     pub(crate) stream_permissions: Option<Vec<StreamPermissionsArg>>,
+    #[clap(flatten)]
+    pub(crate) quotas: QuotasArgs,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -303,3 +318,46 @@ pub(crate) struct UserPermissionsArgs {
     #[arg(value_parser = clap::value_parser!(StreamPermissionsArg))]
     pub(crate) stream_permissions: Option<Vec<StreamPermissionsArg>>,
 }
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct UserQuotasArgs {
+    /// User ID to update
+    ///
+    /// The user ID can be specified as either a username or an ID
+    pub(crate) user_id: Identifier,
+    #[clap(flatten)]
+    pub(crate) quotas: QuotasArgs,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct QuotasArgs {
+    /// Maximum amount of bytes produced per second by all clients of the user
+    #[clap(long)]
+    pub(crate) max_produced_bytes_per_second: Option<IggyByteSize>,
+    /// Maximum number of messages produced per second by all clients of the user
+    #[clap(long)]
+    pub(crate) max_produced_messages_per_second: Option<u64>,
+    /// Maximum amount of bytes consumed per second by all clients of the user
+    #[clap(long)]
+    pub(crate) max_consumed_bytes_per_second: Option<IggyByteSize>,
+    /// Maximum number of concurrent connections of the user
+    #[clap(long)]
+    pub(crate) max_connections: Option<u32>,
+}
+
+impl From<QuotasArgs> for UserQuotas {
+    fn from(args: QuotasArgs) -> Self {
+        let bytes = |size: Option<IggyByteSize>| {
+            size.map(|size| size.as_bytes_u64())
+                .filter(|bytes| *bytes > 0)
+        };
+        UserQuotas {
+            max_produced_bytes_per_second: bytes(args.max_produced_bytes_per_second),
+            max_produced_messages_per_second: args
+                .max_produced_messages_per_second
+                .filter(|value| *value > 0),
+            max_consumed_bytes_per_second: bytes(args.max_consumed_bytes_per_second),
+            max_connections: args.max_connections.filter(|value| *value > 0),
+        }
+    }
+}
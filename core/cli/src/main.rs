@@ -31,14 +31,19 @@ use crate::error::IggyCmdError;
 use crate::logging::Logging;
 use args::context::ContextAction;
 use args::message::MessageAction;
+use args::metadata::MetadataAction;
 use args::partition::PartitionAction;
+use args::role::RoleAction;
+use args::schema::SchemaAction;
 use args::segment::SegmentAction;
 use args::user::UserAction;
 use args::{CliOptions, IggyMergedConsoleArgs};
 use clap::Parser;
 use iggy::client_provider::{self, ClientProviderConfig};
 use iggy::clients::client::IggyClient;
-use iggy::prelude::{Aes256GcmEncryptor, Args, EncryptorKind, PersonalAccessTokenExpiry};
+use iggy::prelude::{
+    Aes256GcmEncryptor, Args, EncryptorKind, PersonalAccessTokenExpiry, UserQuotas,
+};
 use iggy_binary_protocol::cli::binary_context::common::ContextManager;
 use iggy_binary_protocol::cli::binary_context::use_context::UseContextCmd;
 use iggy_binary_protocol::cli::binary_segments::delete_segments::DeleteSegmentsCmd;
@@ -50,6 +55,7 @@ use iggy_binary_protocol::cli::{
         create_consumer_group::CreateConsumerGroupCmd,
         delete_consumer_group::DeleteConsumerGroupCmd, get_consumer_group::GetConsumerGroupCmd,
         get_consumer_groups::GetConsumerGroupsCmd,
+        reset_consumer_group_offsets::ResetConsumerGroupOffsetsCmd,
     },
     binary_consumer_offsets::{
         get_consumer_offset::GetConsumerOffsetCmd, set_consumer_offset::SetConsumerOffsetCmd,
@@ -59,14 +65,24 @@ use iggy_binary_protocol::cli::{
         flush_messages::FlushMessagesCmd, poll_messages::PollMessagesCmd,
         send_messages::SendMessagesCmd,
     },
+    binary_metadata::{apply_metadata::ApplyMetadataCmd, export_metadata::ExportMetadataCmd},
     binary_partitions::{
         create_partitions::CreatePartitionsCmd, delete_partitions::DeletePartitionsCmd,
+        move_partition::MovePartitionCmd,
     },
     binary_personal_access_tokens::{
         create_personal_access_token::CreatePersonalAccessTokenCmd,
         delete_personal_access_tokens::DeletePersonalAccessTokenCmd,
         get_personal_access_tokens::GetPersonalAccessTokensCmd,
     },
+    binary_roles::{
+        assign_roles::AssignRolesCmd, create_role::CreateRoleCmd, delete_role::DeleteRoleCmd,
+        get_roles::GetRolesCmd, update_role::UpdateRoleCmd,
+    },
+    binary_schemas::{
+        delete_schema::DeleteSchemaCmd, get_schema::GetSchemaCmd, get_schemas::GetSchemasCmd,
+        register_schema::RegisterSchemaCmd,
+    },
     binary_streams::{
         create_stream::CreateStreamCmd, delete_stream::DeleteStreamCmd, get_stream::GetStreamCmd,
         get_streams::GetStreamsCmd, purge_stream::PurgeStreamCmd, update_stream::UpdateStreamCmd,
@@ -128,6 +144,10 @@ fn get_command(
                 args.message_expiry.clone().into(),
                 args.max_topic_size,
                 args.replication_factor,
+                args.cleanup_policy,
+                args.partitioning_hash,
+                args.segment_max_age,
+                args.max_partition_size,
             )),
             TopicAction::Delete(args) => Box::new(DeleteTopicCmd::new(
                 args.stream_id.clone(),
@@ -141,6 +161,10 @@ fn get_command(
                 args.message_expiry.clone().into(),
                 args.max_topic_size,
                 args.replication_factor,
+                args.cleanup_policy,
+                args.partitioning_hash,
+                args.segment_max_age,
+                args.max_partition_size,
             )),
             TopicAction::Get(args) => Box::new(GetTopicCmd::new(
                 args.stream_id.clone(),
@@ -166,6 +190,12 @@ fn get_command(
                 args.topic_id.clone(),
                 args.partitions_count,
             )),
+            PartitionAction::Move(args) => Box::new(MovePartitionCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.partition_id,
+                args.data_directory.clone(),
+            )),
         },
         Command::Segment(command) => match command {
             SegmentAction::Delete(args) => Box::new(DeleteSegmentsCmd::new(
@@ -213,6 +243,7 @@ fn get_command(
                     create_args.stream_permissions.clone(),
                 )
                 .into(),
+                Some(UserQuotas::from(create_args.quotas.clone())).filter(|q| !q.is_unlimited()),
             )),
             UserAction::Delete(delete_args) => {
                 Box::new(DeleteUserCmd::new(delete_args.user_id.clone()))
@@ -240,6 +271,28 @@ fn get_command(
                 )
                 .into(),
             )),
+            UserAction::Quotas(quotas_args) => Box::new(UpdateUserCmd::new(
+                quotas_args.user_id.clone(),
+                UpdateUserType::Quotas(quotas_args.quotas.clone().into()),
+            )),
+        },
+        Command::Role(command) => match command {
+            RoleAction::Create(create_args) => Box::new(CreateRoleCmd::new(
+                create_args.name.clone(),
+                create_args.rules.rules(),
+            )),
+            RoleAction::Update(update_args) => Box::new(UpdateRoleCmd::new(
+                update_args.name.clone(),
+                update_args.rules.rules(),
+            )),
+            RoleAction::Delete(delete_args) => {
+                Box::new(DeleteRoleCmd::new(delete_args.name.clone()))
+            }
+            RoleAction::List(list_args) => Box::new(GetRolesCmd::new(list_args.list_mode.into())),
+            RoleAction::Assign(assign_args) => Box::new(AssignRolesCmd::new(
+                assign_args.user_id.clone(),
+                assign_args.roles.clone(),
+            )),
         },
         Command::Client(command) => match command {
             ClientAction::Get(get_args) => Box::new(GetClientCmd::new(get_args.client_id)),
@@ -253,6 +306,7 @@ fn get_command(
                 create_args.topic_id.clone(),
                 create_args.name.clone(),
                 create_args.group_id,
+                create_args.assignment_strategy,
             )),
             ConsumerGroupAction::Delete(delete_args) => Box::new(DeleteConsumerGroupCmd::new(
                 delete_args.stream_id.clone(),
@@ -269,6 +323,16 @@ fn get_command(
                 list_args.topic_id.clone(),
                 list_args.list_mode.into(),
             )),
+            ConsumerGroupAction::ResetOffsets(reset_args) => {
+                Box::new(ResetConsumerGroupOffsetsCmd::new(
+                    reset_args.stream_id.clone(),
+                    reset_args.topic_id.clone(),
+                    reset_args.group_id.clone(),
+                    reset_args.target(),
+                    reset_args.dry_run,
+                    reset_args.force,
+                ))
+            }
         },
         Command::Message(command) => match command {
             MessageAction::Send(send_args) => Box::new(SendMessagesCmd::new(
@@ -314,6 +378,25 @@ fn get_command(
                 set_args.topic_id.clone(),
                 set_args.partition_id,
                 set_args.offset,
+                set_args.metadata.clone(),
+            )),
+        },
+        Command::Schema(command) => match command {
+            SchemaAction::Register(register_args) => Box::new(RegisterSchemaCmd::new(
+                register_args.stream_id.clone(),
+                register_args.topic_id.clone(),
+                register_args.schema_type,
+                register_args.compatibility,
+                register_args.file.clone(),
+            )),
+            SchemaAction::Delete(delete_args) => {
+                Box::new(DeleteSchemaCmd::new(delete_args.schema_id))
+            }
+            SchemaAction::Get(get_args) => Box::new(GetSchemaCmd::new(get_args.schema_id)),
+            SchemaAction::List(list_args) => Box::new(GetSchemasCmd::new(
+                list_args.stream_id.clone(),
+                list_args.topic_id.clone(),
+                list_args.list_mode.into(),
             )),
         },
         Command::Context(command) => match command {
@@ -324,6 +407,19 @@ fn get_command(
                 Box::new(UseContextCmd::new(use_args.context_name.clone()))
             }
         },
+        Command::Metadata(command) => match command {
+            MetadataAction::Export(export_args) => Box::new(ExportMetadataCmd::new(
+                export_args.format.map(Into::into),
+                export_args.include_password_hashes,
+                export_args.output.clone(),
+            )),
+            MetadataAction::Apply(apply_args) => Box::new(ApplyMetadataCmd::new(
+                apply_args.file.clone(),
+                apply_args.format.map(Into::into),
+                apply_args.dry_run,
+                apply_args.prune,
+            )),
+        },
         #[cfg(feature = "login-session")]
         Command::Login(login_args) => Box::new(LoginCmd::new(
             iggy_args.get_server_address().unwrap(),
@@ -18,6 +18,7 @@
 
 use super::MAX_NAME_LENGTH;
 use crate::BytesSerializable;
+use crate::ConsumerGroupAssignmentStrategy;
 use crate::Identifier;
 use crate::Sizeable;
 use crate::Validatable;
@@ -34,6 +35,7 @@ use std::str::from_utf8;
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `group_id` - unique consumer group ID.
 /// - `name` - unique consumer group name, max length is 255 characters.
+/// - `assignment_strategy` - strategy of assigning the partitions to the members, either `round_robin` or `cooperative_sticky`.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct CreateConsumerGroup {
     /// Unique stream ID (numeric or name).
@@ -46,6 +48,9 @@ pub struct CreateConsumerGroup {
     pub group_id: Option<u32>,
     /// Unique consumer group name, max length is 255 characters.
     pub name: String,
+    /// Strategy of assigning the partitions to the members, either `round_robin` or `cooperative_sticky`.
+    #[serde(default)]
+    pub assignment_strategy: ConsumerGroupAssignmentStrategy,
 }
 
 impl Command for CreateConsumerGroup {
@@ -61,6 +66,7 @@ impl Default for CreateConsumerGroup {
             topic_id: Identifier::default(),
             group_id: None,
             name: "consumer_group_1".to_string(),
+            assignment_strategy: ConsumerGroupAssignmentStrategy::default(),
         }
     }
 }
@@ -86,7 +92,7 @@ impl BytesSerializable for CreateConsumerGroup {
         let stream_id_bytes = self.stream_id.to_bytes();
         let topic_id_bytes = self.topic_id.to_bytes();
         let mut bytes = BytesMut::with_capacity(
-            4 + stream_id_bytes.len() + topic_id_bytes.len() + self.name.len(),
+            6 + stream_id_bytes.len() + topic_id_bytes.len() + self.name.len(),
         );
         bytes.put_slice(&stream_id_bytes);
         bytes.put_slice(&topic_id_bytes);
@@ -94,6 +100,7 @@ impl BytesSerializable for CreateConsumerGroup {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.put_slice(self.name.as_bytes());
+        bytes.put_u8(self.assignment_strategy.as_code());
         bytes.freeze()
     }
 
@@ -117,11 +124,17 @@ impl BytesSerializable for CreateConsumerGroup {
         let name = from_utf8(&bytes[position + 5..position + 5 + name_length as usize])
             .map_err(|_| IggyError::InvalidUtf8)?
             .to_string();
+        // The assignment strategy is appended after the name, so the older clients and state entries can still be handled.
+        let assignment_strategy = match bytes.get(position + 5 + name_length as usize) {
+            Some(code) => ConsumerGroupAssignmentStrategy::from_code(*code)?,
+            None => ConsumerGroupAssignmentStrategy::default(),
+        };
         let command = CreateConsumerGroup {
             stream_id,
             topic_id,
             group_id,
             name,
+            assignment_strategy,
         };
         Ok(command)
     }
@@ -131,11 +144,12 @@ impl Display for CreateConsumerGroup {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}",
             self.stream_id,
             self.topic_id,
             self.group_id.unwrap_or(0),
-            self.name
+            self.name,
+            self.assignment_strategy
         )
     }
 }
@@ -151,6 +165,7 @@ mod tests {
             topic_id: Identifier::numeric(2).unwrap(),
             group_id: Some(3),
             name: "test".to_string(),
+            assignment_strategy: ConsumerGroupAssignmentStrategy::CooperativeSticky,
         };
 
         let bytes = command.to_bytes();
@@ -163,11 +178,15 @@ mod tests {
 
         let name_length = bytes[position + 4];
         let name = from_utf8(&bytes[position + 5..position + 5 + name_length as usize]).unwrap();
+        let assignment_strategy =
+            ConsumerGroupAssignmentStrategy::from_code(bytes[position + 5 + name_length as usize])
+                .unwrap();
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(topic_id, command.topic_id);
         assert_eq!(group_id, command.group_id.unwrap());
         assert_eq!(name, command.name);
+        assert_eq!(assignment_strategy, command.assignment_strategy);
     }
 
     #[test]
@@ -194,5 +213,24 @@ mod tests {
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.group_id.unwrap(), group_id);
         assert_eq!(command.name, name);
+        assert_eq!(
+            command.assignment_strategy,
+            ConsumerGroupAssignmentStrategy::RoundRobin
+        );
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_with_assignment_strategy() {
+        let command = CreateConsumerGroup {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            group_id: Some(3),
+            name: "test".to_string(),
+            assignment_strategy: ConsumerGroupAssignmentStrategy::CooperativeSticky,
+        };
+
+        let deserialized = CreateConsumerGroup::from_bytes(command.to_bytes()).unwrap();
+
+        assert_eq!(deserialized, command);
     }
 }
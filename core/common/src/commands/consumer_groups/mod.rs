@@ -22,5 +22,8 @@ pub mod get_consumer_group;
 pub mod get_consumer_groups;
 pub mod join_consumer_group;
 pub mod leave_consumer_group;
+pub mod record_delivery_failure;
+pub mod reset_consumer_group_offsets;
+pub mod sync_consumer_group;
 
 const MAX_NAME_LENGTH: usize = 255;
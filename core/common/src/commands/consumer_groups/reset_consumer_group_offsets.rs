@@ -0,0 +1,255 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::ConsumerOffsetsResetTarget;
+use crate::ConsumerPartitionOffset;
+use crate::Identifier;
+use crate::Sizeable;
+use crate::Validatable;
+use crate::error::IggyError;
+use crate::store_consumer_offsets::MAX_CONSUMER_OFFSETS_COUNT;
+use crate::validate_metadata;
+use crate::{Command, RESET_CONSUMER_GROUP_OFFSETS_CODE};
+use ahash::AHashSet;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `ResetConsumerGroupOffsets` command resolves the new offsets of the consumer group for each partition
+/// of the topic and stores them, unless it's a dry run. Returns the old and new offsets of each partition.
+/// The offsets cannot be reset while the group has active members, unless the reset is forced.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `group_id` - unique consumer group ID (numeric or name).
+/// - `target` - where the offsets are moved to: earliest, latest, timestamp, shift or the explicit offsets.
+/// - `dry_run` - if true, the new offsets are only resolved and returned, but not stored.
+/// - `force` - if true, the offsets are reset even if the group has active members.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct ResetConsumerGroupOffsets {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Unique consumer group ID (numeric or name).
+    #[serde(skip)]
+    pub group_id: Identifier,
+    /// Where the offsets are moved to.
+    pub target: ConsumerOffsetsResetTarget,
+    /// If true, the new offsets are only resolved and returned, but not stored.
+    #[serde(default)]
+    pub dry_run: bool,
+    /// If true, the offsets are reset even if the group has active members.
+    #[serde(default)]
+    pub force: bool,
+}
+
+impl Command for ResetConsumerGroupOffsets {
+    fn code(&self) -> u32 {
+        RESET_CONSUMER_GROUP_OFFSETS_CODE
+    }
+}
+
+impl Validatable<IggyError> for ResetConsumerGroupOffsets {
+    fn validate(&self) -> Result<(), IggyError> {
+        let ConsumerOffsetsResetTarget::Offsets(offsets) = &self.target else {
+            return Ok(());
+        };
+
+        if offsets.is_empty() || offsets.len() > MAX_CONSUMER_OFFSETS_COUNT {
+            return Err(IggyError::InvalidConsumerOffsetsCount);
+        }
+
+        let mut partition_ids = AHashSet::with_capacity(offsets.len());
+        for offset in offsets {
+            if !partition_ids.insert(offset.partition_id) {
+                return Err(IggyError::DuplicatedConsumerOffsetPartition(
+                    offset.partition_id,
+                ));
+            }
+
+            validate_metadata(offset.metadata.as_deref())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for ResetConsumerGroupOffsets {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let group_id_bytes = self.group_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            stream_id_bytes.len() + topic_id_bytes.len() + group_id_bytes.len() + 11,
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_slice(&group_id_bytes);
+        bytes.put_u8(self.dry_run as u8);
+        bytes.put_u8(self.force as u8);
+        bytes.put_u8(self.target.as_code());
+        match &self.target {
+            ConsumerOffsetsResetTarget::Earliest | ConsumerOffsetsResetTarget::Latest => {}
+            ConsumerOffsetsResetTarget::Timestamp(timestamp) => bytes.put_u64_le(*timestamp),
+            ConsumerOffsetsResetTarget::Shift(shift) => bytes.put_i64_le(*shift),
+            ConsumerOffsetsResetTarget::Offsets(offsets) => {
+                bytes.put_u32_le(offsets.len() as u32);
+                for offset in offsets {
+                    bytes.put_slice(&offset.to_bytes());
+                }
+            }
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<ResetConsumerGroupOffsets, IggyError> {
+        if bytes.len() < 12 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let group_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += group_id.get_size_bytes().as_bytes_usize();
+        let flags = bytes
+            .get(position..position + 3)
+            .ok_or(IggyError::InvalidCommand)?;
+        let dry_run = flags[0] == 1;
+        let force = flags[1] == 1;
+        let target_code = flags[2];
+        position += 3;
+        let target = match target_code {
+            1 => ConsumerOffsetsResetTarget::Earliest,
+            2 => ConsumerOffsetsResetTarget::Latest,
+            3 => ConsumerOffsetsResetTarget::Timestamp(u64::from_le_bytes(
+                bytes
+                    .get(position..position + 8)
+                    .ok_or(IggyError::InvalidCommand)?
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            )),
+            4 => ConsumerOffsetsResetTarget::Shift(i64::from_le_bytes(
+                bytes
+                    .get(position..position + 8)
+                    .ok_or(IggyError::InvalidCommand)?
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            )),
+            5 => {
+                let count = u32::from_le_bytes(
+                    bytes
+                        .get(position..position + 4)
+                        .ok_or(IggyError::InvalidCommand)?
+                        .try_into()
+                        .map_err(|_| IggyError::InvalidNumberEncoding)?,
+                ) as usize;
+                position += 4;
+                if count > MAX_CONSUMER_OFFSETS_COUNT {
+                    return Err(IggyError::InvalidConsumerOffsetsCount);
+                }
+
+                let mut offsets = Vec::with_capacity(count);
+                for _ in 0..count {
+                    let (offset, size) = ConsumerPartitionOffset::read(&bytes, position)?;
+                    position += size;
+                    offsets.push(offset);
+                }
+                ConsumerOffsetsResetTarget::Offsets(offsets)
+            }
+            _ => return Err(IggyError::InvalidConsumerOffsetsResetTarget),
+        };
+
+        let command = ResetConsumerGroupOffsets {
+            stream_id,
+            topic_id,
+            group_id,
+            target,
+            dry_run,
+            force,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for ResetConsumerGroupOffsets {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}|{}",
+            self.stream_id, self.topic_id, self.group_id, self.target, self.dry_run, self.force
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes_and_deserialized_back() {
+        let targets = vec![
+            ConsumerOffsetsResetTarget::Earliest,
+            ConsumerOffsetsResetTarget::Latest,
+            ConsumerOffsetsResetTarget::Timestamp(1_000_000),
+            ConsumerOffsetsResetTarget::Shift(-5),
+            ConsumerOffsetsResetTarget::Offsets(vec![
+                ConsumerPartitionOffset::new(1, 10, None),
+                ConsumerPartitionOffset::new(2, 20, Some("checkpoint".to_string())),
+            ]),
+        ];
+
+        for target in targets {
+            let command = ResetConsumerGroupOffsets {
+                stream_id: Identifier::numeric(1).unwrap(),
+                topic_id: Identifier::named("topic").unwrap(),
+                group_id: Identifier::numeric(3).unwrap(),
+                target,
+                dry_run: true,
+                force: false,
+            };
+
+            let bytes = command.to_bytes();
+            let deserialized = ResetConsumerGroupOffsets::from_bytes(bytes).unwrap();
+
+            assert_eq!(deserialized, command);
+        }
+    }
+
+    #[test]
+    fn should_not_be_valid_given_duplicated_partitions() {
+        let command = ResetConsumerGroupOffsets {
+            target: ConsumerOffsetsResetTarget::Offsets(vec![
+                ConsumerPartitionOffset::new(1, 10, None),
+                ConsumerPartitionOffset::new(1, 20, None),
+            ]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            command.validate().unwrap_err().as_code(),
+            IggyError::DuplicatedConsumerOffsetPartition(1).as_code()
+        );
+    }
+}
@@ -19,3 +19,4 @@
 pub mod delete_consumer_offset;
 pub mod get_consumer_offset;
 pub mod store_consumer_offset;
+pub mod store_consumer_offsets;
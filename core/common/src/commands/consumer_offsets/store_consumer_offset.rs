@@ -23,10 +23,14 @@ use crate::Validatable;
 use crate::error::IggyError;
 use crate::{Command, STORE_CONSUMER_OFFSET_CODE};
 use crate::{Consumer, ConsumerKind};
+use crate::{metadata_size, read_metadata, validate_metadata, write_metadata};
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
+/// The maximum length (in bytes) of the metadata stored along with the consumer offset.
+pub const MAX_CONSUMER_OFFSET_METADATA_LENGTH: usize = 1024;
+
 /// `StoreConsumerOffset` command stores the offset of a consumer for a given partition on the server.
 /// It has additional payload:
 /// - `consumer` - the consumer that is storing the offset, either the regular consumer or the consumer group.
@@ -34,6 +38,7 @@ use std::fmt::Display;
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `partition_id` - partition ID on which the offset is stored. Has to be specified for the regular consumer. For consumer group it is ignored (use `None`).
 /// - `offset` - offset to store.
+/// - `metadata` - optional opaque metadata stored along with the offset, e.g. a processing checkpoint or a commit hash.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct StoreConsumerOffset {
     /// The consumer that is storing the offset, either the regular consumer or the consumer group.
@@ -49,6 +54,9 @@ pub struct StoreConsumerOffset {
     pub partition_id: Option<u32>,
     /// Offset to store.
     pub offset: u64,
+    /// Optional opaque metadata stored along with the offset, up to 1024 bytes.
+    #[serde(default)]
+    pub metadata: Option<String>,
 }
 
 impl Default for StoreConsumerOffset {
@@ -59,6 +67,7 @@ impl Default for StoreConsumerOffset {
             topic_id: Identifier::default(),
             partition_id: Some(1),
             offset: 0,
+            metadata: None,
         }
     }
 }
@@ -71,7 +80,7 @@ impl Command for StoreConsumerOffset {
 
 impl Validatable<IggyError> for StoreConsumerOffset {
     fn validate(&self) -> Result<(), IggyError> {
-        Ok(())
+        validate_metadata(self.metadata.as_deref())
     }
 }
 
@@ -81,7 +90,10 @@ impl BytesSerializable for StoreConsumerOffset {
         let stream_id_bytes = self.stream_id.to_bytes();
         let topic_id_bytes = self.topic_id.to_bytes();
         let mut bytes = BytesMut::with_capacity(
-            12 + consumer_bytes.len() + stream_id_bytes.len() + topic_id_bytes.len(),
+            12 + consumer_bytes.len()
+                + stream_id_bytes.len()
+                + topic_id_bytes.len()
+                + metadata_size(self.metadata.as_deref()),
         );
         bytes.put_slice(&consumer_bytes);
         bytes.put_slice(&stream_id_bytes);
@@ -92,6 +104,7 @@ impl BytesSerializable for StoreConsumerOffset {
             bytes.put_u32_le(0);
         }
         bytes.put_u64_le(self.offset);
+        write_metadata(self.metadata.as_deref(), &mut bytes);
         bytes.freeze()
     }
 
@@ -127,12 +140,20 @@ impl BytesSerializable for StoreConsumerOffset {
                 .try_into()
                 .map_err(|_| IggyError::InvalidNumberEncoding)?,
         );
+        position += 12;
+        // The metadata is optional, so that the command sent by the older clients is still valid.
+        let metadata = if bytes.len() > position {
+            read_metadata(&bytes, position)?.0
+        } else {
+            None
+        };
         let command = StoreConsumerOffset {
             consumer,
             stream_id,
             topic_id,
             partition_id,
             offset,
+            metadata,
         };
         Ok(command)
     }
@@ -142,12 +163,13 @@ impl Display for StoreConsumerOffset {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}",
             self.consumer,
             self.stream_id,
             self.topic_id,
             self.partition_id.unwrap_or(0),
-            self.offset
+            self.offset,
+            self.metadata.as_deref().unwrap_or_default()
         )
     }
 }
@@ -164,6 +186,7 @@ mod tests {
             topic_id: Identifier::numeric(3).unwrap(),
             partition_id: Some(4),
             offset: 5,
+            metadata: Some("checkpoint".to_string()),
         };
 
         let bytes = command.to_bytes();
@@ -181,6 +204,7 @@ mod tests {
         position += topic_id.get_size_bytes().as_bytes_usize();
         let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
         let offset = u64::from_le_bytes(bytes[position + 4..position + 12].try_into().unwrap());
+        let (metadata, _) = read_metadata(&bytes, position + 12).unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(consumer, command.consumer);
@@ -188,6 +212,7 @@ mod tests {
         assert_eq!(topic_id, command.topic_id);
         assert_eq!(Some(partition_id), command.partition_id);
         assert_eq!(offset, command.offset);
+        assert_eq!(metadata, command.metadata);
     }
 
     #[test]
@@ -219,5 +244,6 @@ mod tests {
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.partition_id, Some(partition_id));
         assert_eq!(command.offset, offset);
+        assert!(command.metadata.is_none());
     }
 }
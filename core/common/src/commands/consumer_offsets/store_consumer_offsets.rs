@@ -0,0 +1,220 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::ConsumerPartitionOffset;
+use crate::Identifier;
+use crate::Sizeable;
+use crate::Validatable;
+use crate::error::IggyError;
+use crate::validate_metadata;
+use crate::{Command, STORE_CONSUMER_OFFSETS_CODE};
+use crate::{Consumer, ConsumerKind};
+use ahash::AHashSet;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// The maximum number of the partition offsets stored at once.
+pub const MAX_CONSUMER_OFFSETS_COUNT: usize = 1000;
+
+/// `StoreConsumerOffsets` command atomically stores the offsets of a consumer for many partitions of the topic.
+/// Either all the offsets are stored, or none of them if any of the offsets is invalid.
+/// It has additional payload:
+/// - `consumer` - the consumer that is storing the offsets, either the regular consumer or the consumer group.
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `offsets` - offsets to store, at most one for each partition, each with the optional metadata.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct StoreConsumerOffsets {
+    /// The consumer that is storing the offsets, either the regular consumer or the consumer group.
+    #[serde(flatten)]
+    pub consumer: Consumer,
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Offsets to store, at most one for each partition.
+    pub offsets: Vec<ConsumerPartitionOffset>,
+}
+
+impl Default for StoreConsumerOffsets {
+    fn default() -> Self {
+        StoreConsumerOffsets {
+            consumer: Consumer::default(),
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            offsets: vec![ConsumerPartitionOffset::new(1, 0, None)],
+        }
+    }
+}
+
+impl Command for StoreConsumerOffsets {
+    fn code(&self) -> u32 {
+        STORE_CONSUMER_OFFSETS_CODE
+    }
+}
+
+impl Validatable<IggyError> for StoreConsumerOffsets {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.offsets.is_empty() || self.offsets.len() > MAX_CONSUMER_OFFSETS_COUNT {
+            return Err(IggyError::InvalidConsumerOffsetsCount);
+        }
+
+        let mut partition_ids = AHashSet::with_capacity(self.offsets.len());
+        for offset in &self.offsets {
+            if !partition_ids.insert(offset.partition_id) {
+                return Err(IggyError::DuplicatedConsumerOffsetPartition(
+                    offset.partition_id,
+                ));
+            }
+
+            validate_metadata(offset.metadata.as_deref())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for StoreConsumerOffsets {
+    fn to_bytes(&self) -> Bytes {
+        let consumer_bytes = self.consumer.to_bytes();
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let offsets_size = self
+            .offsets
+            .iter()
+            .map(|offset| offset.get_size_bytes())
+            .sum::<usize>();
+        let mut bytes = BytesMut::with_capacity(
+            4 + consumer_bytes.len() + stream_id_bytes.len() + topic_id_bytes.len() + offsets_size,
+        );
+        bytes.put_slice(&consumer_bytes);
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(self.offsets.len() as u32);
+        for offset in &self.offsets {
+            bytes.put_slice(&offset.to_bytes());
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<StoreConsumerOffsets, IggyError> {
+        if bytes.len() < 15 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let consumer_kind = ConsumerKind::from_code(bytes[0])?;
+        let consumer_id = Identifier::from_bytes(bytes.slice(1..))?;
+        position += 1 + consumer_id.get_size_bytes().as_bytes_usize();
+        let consumer = Consumer {
+            kind: consumer_kind,
+            id: consumer_id,
+        };
+        let stream_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() < position + 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let count = u32::from_le_bytes(
+            bytes[position..position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        position += 4;
+        if count > MAX_CONSUMER_OFFSETS_COUNT {
+            return Err(IggyError::InvalidConsumerOffsetsCount);
+        }
+
+        let mut offsets = Vec::with_capacity(count);
+        for _ in 0..count {
+            let (offset, size) = ConsumerPartitionOffset::read(&bytes, position)?;
+            position += size;
+            offsets.push(offset);
+        }
+
+        let command = StoreConsumerOffsets {
+            consumer,
+            stream_id,
+            topic_id,
+            offsets,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for StoreConsumerOffsets {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let offsets = self
+            .offsets
+            .iter()
+            .map(|offset| offset.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(
+            f,
+            "{}|{}|{}|{offsets}",
+            self.consumer, self.stream_id, self.topic_id
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes_and_deserialized_back() {
+        let command = StoreConsumerOffsets {
+            consumer: Consumer::group(Identifier::numeric(1).unwrap()),
+            stream_id: Identifier::numeric(2).unwrap(),
+            topic_id: Identifier::named("topic").unwrap(),
+            offsets: vec![
+                ConsumerPartitionOffset::new(1, 10, None),
+                ConsumerPartitionOffset::new(2, 20, Some("checkpoint".to_string())),
+            ],
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = StoreConsumerOffsets::from_bytes(bytes).unwrap();
+
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_valid_given_duplicated_partitions() {
+        let command = StoreConsumerOffsets {
+            offsets: vec![
+                ConsumerPartitionOffset::new(1, 10, None),
+                ConsumerPartitionOffset::new(1, 20, None),
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            command.validate().unwrap_err().as_code(),
+            IggyError::DuplicatedConsumerOffsetPartition(1).as_code()
+        );
+    }
+}
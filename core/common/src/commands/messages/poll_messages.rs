@@ -17,11 +17,15 @@
  */
 
 use crate::error::IggyError;
-use crate::{BytesSerializable, Identifier, PollingKind, PollingStrategy, Sizeable, Validatable};
+use crate::{
+    BytesSerializable, Identifier, IggyDuration, IsolationLevel, MessageFilter, PollingKind,
+    PollingStrategy, Sizeable, Validatable,
+};
 use crate::{Command, POLL_MESSAGES_CODE};
 use crate::{Consumer, ConsumerKind};
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
+use serde_with::{DisplayFromStr, serde_as};
 use std::fmt::Display;
 
 pub const DEFAULT_PARTITION_ID: u32 = 1;
@@ -36,6 +40,11 @@ pub const DEFAULT_NUMBER_OF_MESSAGES_TO_POLL: u32 = 10;
 /// - `strategy` - polling strategy which specifies from where to start polling messages.
 /// - `count` - number of messages to poll.
 /// - `auto_commit` - whether to commit offset on the server automatically after polling the messages.
+/// - `filter` - optional expression over the user headers, only the matching messages will be returned.
+/// - `isolation_level` - whether the messages of the open or aborted transactions should be returned.
+/// - `max_wait` - maximum time for which the server waits for the new messages (long polling), zero to respond immediately.
+/// - `min_bytes` - minimum size of the messages for which the server responds before `max_wait` elapses.
+#[serde_as]
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct PollMessages {
     /// Consumer which will poll messages. Either regular consumer or consumer group.
@@ -59,9 +68,28 @@ pub struct PollMessages {
     /// Whether to commit offset on the server automatically after polling the messages.
     #[serde(default)]
     pub auto_commit: bool,
+    /// Optional expression over the user headers, only the matching messages will be returned.
+    /// When the filter is set, the response contains the offset from which the next poll should start.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<MessageFilter>,
+    /// Whether the messages of the open or aborted transactions should be returned.
+    /// When the `read_committed` level is set, the response contains the offset from which the next poll should start.
+    #[serde(default)]
+    pub isolation_level: IsolationLevel,
+    /// Maximum time for which the server waits for the new messages to be appended, if there are fewer than `min_bytes` of them (long polling).
+    /// The server responds immediately when it's zero, which is the default.
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(default, skip_serializing_if = "is_no_wait")]
+    pub max_wait: IggyDuration,
+    /// Minimum size of the messages in bytes, for which the server responds before `max_wait` elapses.
+    /// When it's zero, any messages are enough.
+    #[serde(default)]
+    pub min_bytes: u32,
 }
 
 impl PollMessages {
+    #[allow(clippy::too_many_arguments)]
     pub fn bytes(
         stream_id: &Identifier,
         topic_id: &Identifier,
@@ -70,16 +98,28 @@ impl PollMessages {
         strategy: &PollingStrategy,
         count: u32,
         auto_commit: bool,
+        filter: Option<&MessageFilter>,
+        isolation_level: IsolationLevel,
+        max_wait: IggyDuration,
+        min_bytes: u32,
     ) -> Bytes {
         let consumer_bytes = consumer.to_bytes();
         let stream_id_bytes = stream_id.to_bytes();
         let topic_id_bytes = topic_id.to_bytes();
         let strategy_bytes = strategy.to_bytes();
+        let filter_bytes = filter.map(|filter| filter.to_bytes());
+        let has_long_polling = !is_no_wait(&max_wait);
+        let has_isolation_level =
+            has_long_polling || isolation_level != IsolationLevel::ReadUncommitted;
         let mut bytes = BytesMut::with_capacity(
             9 + consumer_bytes.len()
                 + stream_id_bytes.len()
                 + topic_id_bytes.len()
-                + strategy_bytes.len(),
+                + strategy_bytes.len()
+                + 4
+                + filter_bytes.as_ref().map_or(0, |filter| filter.len())
+                + 1
+                + 12,
         );
         bytes.put_slice(&consumer_bytes);
         bytes.put_slice(&stream_id_bytes);
@@ -96,6 +136,19 @@ impl PollMessages {
         } else {
             bytes.put_u8(0);
         }
+        if let Some(filter_bytes) = filter_bytes {
+            bytes.put_u32_le(filter_bytes.len() as u32);
+            bytes.put_slice(&filter_bytes);
+        } else if has_isolation_level {
+            bytes.put_u32_le(0);
+        }
+        if has_isolation_level {
+            bytes.put_u8(isolation_level.as_code());
+        }
+        if has_long_polling {
+            bytes.put_u64_le(max_wait.as_micros());
+            bytes.put_u32_le(min_bytes);
+        }
 
         bytes.freeze()
     }
@@ -119,6 +172,10 @@ impl Default for PollMessages {
             strategy: PollingStrategy::default(),
             count: PollMessages::default_number_of_messages_to_poll(),
             auto_commit: false,
+            filter: None,
+            isolation_level: IsolationLevel::default(),
+            max_wait: IggyDuration::default(),
+            min_bytes: 0,
         }
     }
 }
@@ -131,6 +188,10 @@ impl Command for PollMessages {
 
 impl Validatable<IggyError> for PollMessages {
     fn validate(&self) -> Result<(), IggyError> {
+        if let Some(filter) = &self.filter {
+            filter.validate()?;
+        }
+
         Ok(())
     }
 }
@@ -145,6 +206,10 @@ impl BytesSerializable for PollMessages {
             &self.strategy,
             self.count,
             self.auto_commit,
+            self.filter.as_ref(),
+            self.isolation_level,
+            self.max_wait,
+            self.min_bytes,
         )
     }
 
@@ -192,6 +257,52 @@ impl BytesSerializable for PollMessages {
         );
         let auto_commit = bytes[position + 12];
         let auto_commit = matches!(auto_commit, 1);
+        position += 13;
+        // The filter (empty if not set), the isolation level and the long polling are optional and appended at the end,
+        // so the older clients can still be handled.
+        let mut filter = None;
+        let mut isolation_level = IsolationLevel::default();
+        let mut max_wait = IggyDuration::default();
+        let mut min_bytes = 0;
+        if bytes.len() > position {
+            if bytes.len() < position + 4 {
+                return Err(IggyError::InvalidCommand);
+            }
+            let filter_length = u32::from_le_bytes(
+                bytes[position..position + 4]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ) as usize;
+            position += 4;
+            if bytes.len() < position + filter_length {
+                return Err(IggyError::InvalidCommand);
+            }
+            if filter_length > 0 {
+                filter = Some(MessageFilter::from_bytes(
+                    bytes.slice(position..position + filter_length),
+                )?);
+            }
+            position += filter_length;
+            match bytes.len() - position {
+                0 => {}
+                1 => isolation_level = IsolationLevel::from_code(bytes[position])?,
+                13 => {
+                    isolation_level = IsolationLevel::from_code(bytes[position])?;
+                    max_wait = u64::from_le_bytes(
+                        bytes[position + 1..position + 9]
+                            .try_into()
+                            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+                    )
+                    .into();
+                    min_bytes = u32::from_le_bytes(
+                        bytes[position + 9..position + 13]
+                            .try_into()
+                            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+                    );
+                }
+                _ => return Err(IggyError::InvalidCommand),
+            }
+        }
         let command = PollMessages {
             consumer,
             stream_id,
@@ -200,6 +311,10 @@ impl BytesSerializable for PollMessages {
             strategy,
             count,
             auto_commit,
+            filter,
+            isolation_level,
+            max_wait,
+            min_bytes,
         };
         Ok(command)
     }
@@ -217,10 +332,25 @@ impl Display for PollMessages {
             self.strategy,
             self.count,
             auto_commit_to_string(self.auto_commit)
-        )
+        )?;
+        if let Some(filter) = &self.filter {
+            write!(f, "|{filter}")?;
+        }
+        if self.isolation_level != IsolationLevel::ReadUncommitted {
+            write!(f, "|{}", self.isolation_level)?;
+        }
+        if !is_no_wait(&self.max_wait) {
+            write!(f, "|{}|{}", self.max_wait, self.min_bytes)?;
+        }
+        Ok(())
     }
 }
 
+// The sub-second waits are not zero, unlike for `IggyDuration::is_zero`.
+fn is_no_wait(max_wait: &IggyDuration) -> bool {
+    max_wait.as_micros() == 0
+}
+
 fn auto_commit_to_string(auto_commit: bool) -> &'static str {
     if auto_commit { "a" } else { "n" }
 }
@@ -228,6 +358,8 @@ fn auto_commit_to_string(auto_commit: bool) -> &'static str {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{HeaderKey, HeaderValue};
+    use std::str::FromStr;
 
     #[test]
     fn should_be_serialized_as_bytes() {
@@ -239,6 +371,10 @@ mod tests {
             strategy: PollingStrategy::offset(2),
             count: 3,
             auto_commit: true,
+            filter: None,
+            isolation_level: IsolationLevel::ReadUncommitted,
+            max_wait: IggyDuration::default(),
+            min_bytes: 0,
         };
 
         let bytes = command.to_bytes();
@@ -317,5 +453,78 @@ mod tests {
         assert_eq!(command.strategy, strategy);
         assert_eq!(command.count, count);
         assert_eq!(command.auto_commit, auto_commit);
+        assert_eq!(command.filter, None);
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_filter() {
+        let command = PollMessages {
+            consumer: Consumer::new(Identifier::numeric(1).unwrap()),
+            stream_id: Identifier::numeric(2).unwrap(),
+            topic_id: Identifier::numeric(3).unwrap(),
+            partition_id: Some(4),
+            strategy: PollingStrategy::next(),
+            count: 10,
+            auto_commit: true,
+            filter: Some(MessageFilter::equals(
+                HeaderKey::new("region").unwrap(),
+                HeaderValue::from_str("eu").unwrap(),
+            )),
+            isolation_level: IsolationLevel::ReadUncommitted,
+            max_wait: IggyDuration::default(),
+            min_bytes: 0,
+        };
+
+        let deserialized = PollMessages::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_isolation_level() {
+        let mut command = PollMessages {
+            consumer: Consumer::new(Identifier::numeric(1).unwrap()),
+            stream_id: Identifier::numeric(2).unwrap(),
+            topic_id: Identifier::numeric(3).unwrap(),
+            partition_id: Some(4),
+            strategy: PollingStrategy::next(),
+            count: 10,
+            auto_commit: true,
+            filter: None,
+            isolation_level: IsolationLevel::ReadCommitted,
+            max_wait: IggyDuration::default(),
+            min_bytes: 0,
+        };
+
+        let deserialized = PollMessages::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+
+        command.filter = Some(MessageFilter::exists(HeaderKey::new("region").unwrap()));
+        let deserialized = PollMessages::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_long_polling() {
+        let mut command = PollMessages {
+            consumer: Consumer::new(Identifier::numeric(1).unwrap()),
+            stream_id: Identifier::numeric(2).unwrap(),
+            topic_id: Identifier::numeric(3).unwrap(),
+            partition_id: Some(4),
+            strategy: PollingStrategy::next(),
+            count: 10,
+            auto_commit: true,
+            filter: None,
+            isolation_level: IsolationLevel::ReadUncommitted,
+            max_wait: IggyDuration::from_str("500ms").unwrap(),
+            min_bytes: 1024,
+        };
+
+        let deserialized = PollMessages::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+
+        command.filter = Some(MessageFilter::exists(HeaderKey::new("region").unwrap()));
+        command.isolation_level = IsolationLevel::ReadCommitted;
+        let deserialized = PollMessages::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
     }
 }
@@ -20,6 +20,7 @@ use crate::BytesSerializable;
 use crate::Identifier;
 use crate::IggyMessageView;
 use crate::PartitioningKind;
+use crate::ProducerSequence;
 use crate::Sizeable;
 use crate::Validatable;
 use crate::error::IggyError;
@@ -39,10 +40,11 @@ use std::fmt::{Display, Formatter};
 /// - `stream_id` - unique stream ID (numeric or name).
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `partitioning` - to which partition the messages should be sent - either provided by the client or calculated by the server.
+/// - `producer` - optional producer ID, epoch and sequence number of the batch sent by the idempotent producer.
 /// - `batch` - collection of messages to be sent.
 #[derive(Debug, PartialEq)]
 pub struct SendMessages {
-    /// Length of stream_id, topic_id, partitioning and messages_count (4 bytes)
+    /// Length of stream_id, topic_id, partitioning, messages_count (4 bytes) and optional producer sequence (20 bytes)
     pub metadata_length: u32,
     /// Unique stream ID (numeric or name).
     pub stream_id: Identifier,
@@ -50,6 +52,8 @@ pub struct SendMessages {
     pub topic_id: Identifier,
     /// To which partition the messages should be sent - either provided by the client or calculated by the server.
     pub partitioning: Partitioning,
+    /// Producer ID, epoch and sequence number of the batch, if sent by the idempotent producer.
+    pub producer: Option<ProducerSequence>,
     /// Messages collection
     pub batch: IggyMessagesBatch,
 }
@@ -59,6 +63,7 @@ impl SendMessages {
         stream_id: &Identifier,
         topic_id: &Identifier,
         partitioning: &Partitioning,
+        producer: Option<&ProducerSequence>,
         messages: &[IggyMessage],
     ) -> Bytes {
         let stream_id_field_size = stream_id.get_buffer_size();
@@ -67,10 +72,12 @@ impl SendMessages {
         let metadata_length_field_size = size_of::<u32>();
         let messages_count = messages.len();
         let messages_count_field_size = size_of::<u32>();
+        let producer_field_size = producer.map_or(0, |_| ProducerSequence::SIZE);
         let metadata_length = stream_id_field_size
             + topic_id_field_size
             + partitioning_field_size
-            + messages_count_field_size;
+            + messages_count_field_size
+            + producer_field_size;
         let indexes_size = messages_count * INDEX_SIZE;
         let messages_size = messages
             .iter()
@@ -82,6 +89,7 @@ impl SendMessages {
             + topic_id_field_size
             + partitioning_field_size
             + messages_count_field_size
+            + producer_field_size
             + indexes_size
             + messages_size;
 
@@ -92,6 +100,11 @@ impl SendMessages {
         topic_id.write_to_buffer(&mut bytes);
         partitioning.write_to_buffer(&mut bytes);
         bytes.put_u32_le(messages_count as u32);
+        if let Some(producer) = producer {
+            bytes.put_u64_le(producer.producer_id);
+            bytes.put_u32_le(producer.producer_epoch);
+            bytes.put_u64_le(producer.sequence);
+        }
 
         let mut current_position = bytes.len();
 
@@ -134,6 +147,7 @@ impl Default for SendMessages {
             stream_id: Identifier::default(),
             topic_id: Identifier::default(),
             partitioning: Partitioning::default(),
+            producer: None,
             batch: IggyMessagesBatch::empty(),
         }
     }
@@ -154,6 +168,10 @@ impl Validatable<IggyError> for SendMessages {
             return Err(IggyError::InvalidKeyValueLength);
         }
 
+        if self.producer.is_some() && self.partitioning.kind == PartitioningKind::Balanced {
+            return Err(IggyError::InvalidIdempotentPartitioning);
+        }
+
         self.batch.validate()?;
 
         Ok(())
@@ -210,8 +228,12 @@ impl Serialize for SendMessages {
             })
             .collect();
 
-        let mut state = serializer.serialize_struct("SendMessages", 2)?;
+        let fields_count = if self.producer.is_some() { 3 } else { 2 };
+        let mut state = serializer.serialize_struct("SendMessages", fields_count)?;
         state.serialize_field("partitioning", &self.partitioning)?;
+        if let Some(producer) = &self.producer {
+            state.serialize_field("producer", producer)?;
+        }
         state.serialize_field("messages", &messages)?;
         state.end()
     }
@@ -224,6 +246,7 @@ impl<'de> Deserialize<'de> for SendMessages {
     {
         enum Field {
             Partitioning,
+            Producer,
             Messages,
         }
 
@@ -238,7 +261,7 @@ impl<'de> Deserialize<'de> for SendMessages {
                     type Value = Field;
 
                     fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
-                        formatter.write_str("`partitioning` or `messages`")
+                        formatter.write_str("`partitioning`, `producer` or `messages`")
                     }
 
                     fn visit_str<E>(self, value: &str) -> Result<Field, E>
@@ -247,10 +270,11 @@ impl<'de> Deserialize<'de> for SendMessages {
                     {
                         match value {
                             "partitioning" => Ok(Field::Partitioning),
+                            "producer" => Ok(Field::Producer),
                             "messages" => Ok(Field::Messages),
                             _ => Err(de::Error::unknown_field(
                                 value,
-                                &["partitioning", "messages"],
+                                &["partitioning", "producer", "messages"],
                             )),
                         }
                     }
@@ -274,6 +298,7 @@ impl<'de> Deserialize<'de> for SendMessages {
                 V: MapAccess<'de>,
             {
                 let mut partitioning = None;
+                let mut producer = None;
                 let mut messages = None;
 
                 while let Some(key) = map.next_key()? {
@@ -284,6 +309,12 @@ impl<'de> Deserialize<'de> for SendMessages {
                             }
                             partitioning = Some(map.next_value()?);
                         }
+                        Field::Producer => {
+                            if producer.is_some() {
+                                return Err(de::Error::duplicate_field("producer"));
+                            }
+                            producer = map.next_value()?;
+                        }
                         Field::Messages => {
                             if messages.is_some() {
                                 return Err(de::Error::duplicate_field("messages"));
@@ -358,6 +389,7 @@ impl<'de> Deserialize<'de> for SendMessages {
                     stream_id: Identifier::default(),
                     topic_id: Identifier::default(),
                     partitioning,
+                    producer,
                     batch,
                 })
             }
@@ -365,7 +397,7 @@ impl<'de> Deserialize<'de> for SendMessages {
 
         deserializer.deserialize_struct(
             "SendMessages",
-            &["partitioning", "messages"],
+            &["partitioning", "producer", "messages"],
             SendMessagesVisitor,
         )
     }
@@ -426,4 +458,57 @@ mod tests {
         let key = Partitioning::messages_key_str(&messages_key);
         assert!(key.is_err());
     }
+
+    #[test]
+    fn producer_sequence_should_be_serialized_as_part_of_metadata() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let partitioning = Partitioning::partition_id(3);
+        let producer = ProducerSequence {
+            producer_id: 4,
+            producer_epoch: 5,
+            sequence: 6,
+        };
+        let messages = vec![
+            IggyMessage::builder()
+                .payload("test".into())
+                .build()
+                .unwrap(),
+        ];
+
+        let bytes = SendMessages::bytes(&stream_id, &topic_id, &partitioning, None, &messages);
+        let idempotent_bytes = SendMessages::bytes(
+            &stream_id,
+            &topic_id,
+            &partitioning,
+            Some(&producer),
+            &messages,
+        );
+
+        let metadata_length = u32::from_le_bytes(bytes[..4].try_into().unwrap()) as usize;
+        let idempotent_metadata_length =
+            u32::from_le_bytes(idempotent_bytes[..4].try_into().unwrap()) as usize;
+        assert_eq!(
+            idempotent_metadata_length,
+            metadata_length + ProducerSequence::SIZE
+        );
+        assert_eq!(idempotent_bytes.len(), bytes.len() + ProducerSequence::SIZE);
+
+        let position = 4 + metadata_length;
+        let producer_id =
+            u64::from_le_bytes(idempotent_bytes[position..position + 8].try_into().unwrap());
+        let producer_epoch = u32::from_le_bytes(
+            idempotent_bytes[position + 8..position + 12]
+                .try_into()
+                .unwrap(),
+        );
+        let sequence = u64::from_le_bytes(
+            idempotent_bytes[position + 12..position + 20]
+                .try_into()
+                .unwrap(),
+        );
+        assert_eq!(producer_id, producer.producer_id);
+        assert_eq!(producer_epoch, producer.producer_epoch);
+        assert_eq!(sequence, producer.sequence);
+    }
 }
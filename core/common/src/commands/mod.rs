@@ -20,8 +20,12 @@ pub(crate) mod consumer_offsets;
 pub(crate) mod messages;
 pub(crate) mod partitions;
 pub(crate) mod personal_access_tokens;
+pub(crate) mod producers;
+pub(crate) mod roles;
+pub(crate) mod schemas;
 pub(crate) mod segments;
 pub(crate) mod streams;
 pub(crate) mod system;
 pub(crate) mod topics;
+pub(crate) mod transactions;
 pub(crate) mod users;
@@ -18,5 +18,6 @@
 
 pub mod create_partitions;
 pub mod delete_partitions;
+pub mod move_partition;
 
 const MAX_PARTITIONS_COUNT: u32 = 1000;
@@ -0,0 +1,222 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::Identifier;
+use crate::Sizeable;
+use crate::Validatable;
+use crate::error::IggyError;
+use crate::{Command, MOVE_PARTITION_CODE};
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// The maximum length of the data directory path.
+pub const MAX_DATA_DIRECTORY_LENGTH: usize = 4096;
+
+/// `MovePartition` command is used to move the partition with all its segments to another data directory,
+/// while it stays available for appending and polling the messages.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `partition_id` - unique partition ID.
+/// - `data_directory` - one of the data directories configured on the server.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct MovePartition {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Unique partition ID.
+    #[serde(skip)]
+    pub partition_id: u32,
+    /// One of the data directories configured on the server.
+    pub data_directory: String,
+}
+
+impl Command for MovePartition {
+    fn code(&self) -> u32 {
+        MOVE_PARTITION_CODE
+    }
+}
+
+impl Default for MovePartition {
+    fn default() -> Self {
+        MovePartition {
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            partition_id: 1,
+            data_directory: "local_data".to_string(),
+        }
+    }
+}
+
+impl Validatable<IggyError> for MovePartition {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.data_directory.is_empty() || self.data_directory.len() > MAX_DATA_DIRECTORY_LENGTH {
+            return Err(IggyError::InvalidDataDirectory(self.data_directory.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for MovePartition {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            8 + stream_id_bytes.len() + topic_id_bytes.len() + self.data_directory.len(),
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(self.partition_id);
+        bytes.put_u32_le(self.data_directory.len() as u32);
+        bytes.put_slice(self.data_directory.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<MovePartition, IggyError> {
+        if bytes.len() < 15 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let partition_id = u32::from_le_bytes(
+            bytes
+                .get(position..position + 4)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 4;
+        let data_directory_length = u32::from_le_bytes(
+            bytes
+                .get(position..position + 4)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        position += 4;
+        let data_directory = from_utf8(
+            bytes
+                .get(position..position + data_directory_length)
+                .ok_or(IggyError::InvalidCommand)?,
+        )
+        .map_err(|_| IggyError::InvalidUtf8)?
+        .to_string();
+        let command = MovePartition {
+            stream_id,
+            topic_id,
+            partition_id,
+            data_directory,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for MovePartition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.stream_id, self.topic_id, self.partition_id, self.data_directory
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = MovePartition {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partition_id: 3,
+            data_directory: "/mnt/disk2/iggy".to_string(),
+        };
+
+        let bytes = command.to_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        position += 4;
+        let data_directory_length =
+            u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap()) as usize;
+        position += 4;
+        let data_directory = from_utf8(&bytes[position..position + data_directory_length]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(partition_id, command.partition_id);
+        assert_eq!(data_directory, command.data_directory);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let partition_id = 3u32;
+        let data_directory = "/mnt/disk2/iggy";
+        let stream_id_bytes = stream_id.to_bytes();
+        let topic_id_bytes = topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            8 + stream_id_bytes.len() + topic_id_bytes.len() + data_directory.len(),
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(partition_id);
+        bytes.put_u32_le(data_directory.len() as u32);
+        bytes.put_slice(data_directory.as_bytes());
+        let command = MovePartition::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.partition_id, partition_id);
+        assert_eq!(command.data_directory, data_directory);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let command = MovePartition {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partition_id: 3,
+            data_directory: "/mnt/disk2/iggy".to_string(),
+        };
+
+        let bytes = command.to_bytes();
+        let command = MovePartition::from_bytes(bytes.slice(..bytes.len() - 1));
+        assert!(command.is_err());
+    }
+}
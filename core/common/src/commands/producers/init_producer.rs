@@ -0,0 +1,106 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::Validatable;
+use crate::error::IggyError;
+use crate::{Command, INIT_PRODUCER_CODE};
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `InitProducer` command is used to initialize the idempotent producer, whose messages are sent along with
+/// the per-partition sequence numbers, so that the retried batches are never appended twice.
+/// It has additional payload:
+/// - `producer_id` - unique producer ID returned by the previous initialization, or 0 to register a new producer.
+///
+/// The response contains the producer ID and its epoch, which is bumped on each initialization,
+/// so that the previous instance of the same producer gets fenced.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct InitProducer {
+    /// Unique producer ID, or 0 to register a new producer.
+    #[serde(default)]
+    pub producer_id: u64,
+}
+
+impl Command for InitProducer {
+    fn code(&self) -> u32 {
+        INIT_PRODUCER_CODE
+    }
+}
+
+impl Validatable<IggyError> for InitProducer {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for InitProducer {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(8);
+        bytes.put_u64_le(self.producer_id);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<InitProducer, IggyError> {
+        if bytes.len() != 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let producer_id = u64::from_le_bytes(
+            bytes[..8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let command = InitProducer { producer_id };
+        Ok(command)
+    }
+}
+
+impl Display for InitProducer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.producer_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = InitProducer { producer_id: 1 };
+        let bytes = command.to_bytes();
+        let producer_id = u64::from_le_bytes(bytes[..8].try_into().unwrap());
+
+        assert_eq!(bytes.len(), 8);
+        assert_eq!(producer_id, command.producer_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let producer_id = 1u64;
+        let mut bytes = BytesMut::with_capacity(8);
+        bytes.put_u64_le(producer_id);
+        let command = InitProducer::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.producer_id, producer_id);
+    }
+}
@@ -0,0 +1,147 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::Identifier;
+use crate::Sizeable;
+use crate::Validatable;
+use crate::defaults::*;
+use crate::error::IggyError;
+use crate::{ASSIGN_ROLES_CODE, Command};
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `AssignRoles` command is used to replace the roles assigned to a user.
+/// It has additional payload:
+/// - `user_id` - unique user ID (numeric or name).
+/// - `roles` - names of the roles, if empty, then all the roles will be unassigned from the user.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct AssignRoles {
+    /// Unique user ID (numeric or name).
+    #[serde(skip)]
+    pub user_id: Identifier,
+    /// Names of the roles, if empty, then all the roles will be unassigned from the user.
+    pub roles: Vec<String>,
+}
+
+impl Command for AssignRoles {
+    fn code(&self) -> u32 {
+        ASSIGN_ROLES_CODE
+    }
+}
+
+impl Validatable<IggyError> for AssignRoles {
+    fn validate(&self) -> Result<(), IggyError> {
+        for role in &self.roles {
+            if role.is_empty()
+                || role.len() > MAX_ROLE_NAME_LENGTH
+                || role.len() < MIN_ROLE_NAME_LENGTH
+            {
+                return Err(IggyError::InvalidRoleName);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for AssignRoles {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&self.user_id.to_bytes());
+        bytes.put_u32_le(self.roles.len() as u32);
+        for role in &self.roles {
+            #[allow(clippy::cast_possible_truncation)]
+            bytes.put_u8(role.len() as u8);
+            bytes.put_slice(role.as_bytes());
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<AssignRoles, IggyError> {
+        if bytes.len() < 7 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let user_id = Identifier::from_bytes(bytes.clone())?;
+        let mut position = user_id.get_size_bytes().as_bytes_usize();
+        let count = u32::from_le_bytes(
+            bytes
+                .get(position..position + 4)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 4;
+        let mut roles = Vec::new();
+        for _ in 0..count {
+            let name_length = *bytes.get(position).ok_or(IggyError::InvalidCommand)? as usize;
+            let name = bytes
+                .get(position + 1..position + 1 + name_length)
+                .ok_or(IggyError::InvalidCommand)?;
+            roles.push(
+                from_utf8(name)
+                    .map_err(|_| IggyError::InvalidUtf8)?
+                    .to_string(),
+            );
+            position += 1 + name_length;
+        }
+
+        if position != bytes.len() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(AssignRoles { user_id, roles })
+    }
+}
+
+impl Display for AssignRoles {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.user_id, self.roles.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = AssignRoles {
+            user_id: Identifier::named("tenant-admin").unwrap(),
+            roles: vec!["tenant".to_string(), "auditor".to_string()],
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = AssignRoles::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_without_roles() {
+        let command = AssignRoles {
+            user_id: Identifier::numeric(2).unwrap(),
+            roles: vec![],
+        };
+
+        let deserialized = AssignRoles::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+}
@@ -0,0 +1,135 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::Validatable;
+use crate::error::IggyError;
+use crate::{CREATE_ROLE_CODE, Command, Role, RoleRule};
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `CreateRole` command is used to create a new role.
+/// It has additional payload:
+/// - `name` - unique name of the role, must be between 3 and 50 characters long.
+/// - `rules` - rules allowing or denying the actions on the streams and topics matching the resource patterns.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct CreateRole {
+    /// Unique name of the role, must be between 3 and 50 characters long.
+    pub name: String,
+    /// Rules allowing or denying the actions on the streams and topics matching the resource patterns.
+    pub rules: Vec<RoleRule>,
+}
+
+impl Command for CreateRole {
+    fn code(&self) -> u32 {
+        CREATE_ROLE_CODE
+    }
+}
+
+impl Validatable<IggyError> for CreateRole {
+    fn validate(&self) -> Result<(), IggyError> {
+        Role::validate(&self.name, &self.rules)
+    }
+}
+
+impl BytesSerializable for CreateRole {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::new();
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.name.len() as u8);
+        bytes.put_slice(self.name.as_bytes());
+        RoleRule::write_all(&self.rules, &mut bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<CreateRole, IggyError> {
+        if bytes.len() < 5 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name_length = bytes[0] as usize;
+        let name = from_utf8(
+            bytes
+                .get(1..1 + name_length)
+                .ok_or(IggyError::InvalidCommand)?,
+        )
+        .map_err(|_| IggyError::InvalidUtf8)?
+        .to_string();
+        let rules = RoleRule::read_all(&bytes[1 + name_length..])?;
+        Ok(CreateRole { name, rules })
+    }
+}
+
+impl Display for CreateRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rules = self
+            .rules
+            .iter()
+            .map(|rule| rule.to_string())
+            .collect::<Vec<_>>()
+            .join("; ");
+        write!(f, "{}|{rules}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ResourcePattern, RoleAction};
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = CreateRole {
+            name: "tenant".to_string(),
+            rules: vec![
+                RoleRule::allow(
+                    ResourcePattern::stream("tenant-*"),
+                    &[RoleAction::ManageTopic],
+                ),
+                RoleRule::deny(
+                    ResourcePattern::topic("*", "audit"),
+                    &[RoleAction::SendMessages],
+                ),
+            ],
+        };
+
+        let bytes = command.to_bytes();
+        let name_length = bytes[0] as usize;
+        assert_eq!(&bytes[1..1 + name_length], command.name.as_bytes());
+
+        let deserialized = CreateRole::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_validated_with_too_short_name_or_rule_without_actions() {
+        let command = CreateRole {
+            name: "ab".to_string(),
+            rules: vec![],
+        };
+        assert!(command.validate().is_err());
+
+        let command = CreateRole {
+            name: "tenant".to_string(),
+            rules: vec![RoleRule::allow(ResourcePattern::stream("*"), &[])],
+        };
+        assert!(command.validate().is_err());
+    }
+}
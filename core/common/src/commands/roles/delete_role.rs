@@ -0,0 +1,106 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::Validatable;
+use crate::defaults::*;
+use crate::error::IggyError;
+use crate::{Command, DELETE_ROLE_CODE};
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `DeleteRole` command is used to delete a role, it's also unassigned from all the users.
+/// It has additional payload:
+/// - `name` - unique name of the role.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct DeleteRole {
+    /// Unique name of the role.
+    #[serde(skip)]
+    pub name: String,
+}
+
+impl Command for DeleteRole {
+    fn code(&self) -> u32 {
+        DELETE_ROLE_CODE
+    }
+}
+
+impl Validatable<IggyError> for DeleteRole {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.name.is_empty()
+            || self.name.len() > MAX_ROLE_NAME_LENGTH
+            || self.name.len() < MIN_ROLE_NAME_LENGTH
+        {
+            return Err(IggyError::InvalidRoleName);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for DeleteRole {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(1 + self.name.len());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.name.len() as u8);
+        bytes.put_slice(self.name.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<DeleteRole, IggyError> {
+        if bytes.is_empty() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name_length = bytes[0] as usize;
+        if bytes.len() != 1 + name_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name = from_utf8(&bytes[1..])
+            .map_err(|_| IggyError::InvalidUtf8)?
+            .to_string();
+        Ok(DeleteRole { name })
+    }
+}
+
+impl Display for DeleteRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = DeleteRole {
+            name: "tenant".to_string(),
+        };
+
+        let bytes = command.to_bytes();
+        assert_eq!(bytes[0] as usize, command.name.len());
+
+        let deserialized = DeleteRole::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+}
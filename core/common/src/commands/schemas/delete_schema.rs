@@ -0,0 +1,95 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::Validatable;
+use crate::error::IggyError;
+use crate::{Command, DELETE_SCHEMA_CODE};
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `DeleteSchema` command deletes the schema by its unique ID, the messages referencing it are no longer accepted.
+/// It has additional payload:
+/// - `schema_id` - unique schema ID.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct DeleteSchema {
+    /// Unique schema ID.
+    #[serde(skip)]
+    pub schema_id: u32,
+}
+
+impl Command for DeleteSchema {
+    fn code(&self) -> u32 {
+        DELETE_SCHEMA_CODE
+    }
+}
+
+impl Validatable<IggyError> for DeleteSchema {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for DeleteSchema {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(4);
+        bytes.put_u32_le(self.schema_id);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<DeleteSchema, IggyError> {
+        if bytes.len() != 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let schema_id = u32::from_le_bytes(
+            bytes[..4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        Ok(DeleteSchema { schema_id })
+    }
+}
+
+impl Display for DeleteSchema {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.schema_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = DeleteSchema { schema_id: 3 };
+
+        let bytes = command.to_bytes();
+        assert_eq!(bytes.len(), 4);
+
+        let deserialized = DeleteSchema::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_invalid_bytes() {
+        assert!(DeleteSchema::from_bytes(Bytes::from_static(&[1, 2, 3])).is_err());
+    }
+}
@@ -0,0 +1,188 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::Identifier;
+use crate::Sizeable;
+use crate::Validatable;
+use crate::error::IggyError;
+use crate::{Command, REGISTER_SCHEMA_CODE};
+use crate::{SchemaCompatibility, SchemaType};
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use serde_with::base64::Base64;
+use serde_with::serde_as;
+use std::fmt::Display;
+
+/// The maximum size of the schema definition in bytes.
+pub const MAX_SCHEMA_DEFINITION_SIZE: usize = 1024 * 1024;
+
+/// `RegisterSchema` command registers the next version of the schema for the topic.
+/// The definition is checked against the latest version of the topic using the requested compatibility.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `schema_type` - format of the schema definition.
+/// - `compatibility` - compatibility required with the latest version of the topic.
+/// - `definition` - the schema definition, max size is 1 MB.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct RegisterSchema {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Format of the schema definition.
+    pub schema_type: SchemaType,
+    /// Compatibility required with the latest version of the topic.
+    #[serde(default)]
+    pub compatibility: SchemaCompatibility,
+    /// The schema definition, base64 encoded in JSON.
+    #[serde_as(as = "Base64")]
+    pub definition: Bytes,
+}
+
+impl Command for RegisterSchema {
+    fn code(&self) -> u32 {
+        REGISTER_SCHEMA_CODE
+    }
+}
+
+impl Validatable<IggyError> for RegisterSchema {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.definition.is_empty() {
+            return Err(IggyError::InvalidSchemaDefinition(
+                "definition is empty".to_string(),
+            ));
+        }
+
+        if self.definition.len() > MAX_SCHEMA_DEFINITION_SIZE {
+            return Err(IggyError::InvalidSchemaDefinition(format!(
+                "definition exceeds {MAX_SCHEMA_DEFINITION_SIZE} bytes"
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for RegisterSchema {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            stream_id_bytes.len() + topic_id_bytes.len() + 6 + self.definition.len(),
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u8(self.schema_type.as_code());
+        bytes.put_u8(self.compatibility.as_code());
+        bytes.put_u32_le(self.definition.len() as u32);
+        bytes.put_slice(&self.definition);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<RegisterSchema, IggyError> {
+        if bytes.len() < 12 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() < position + 6 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let schema_type = SchemaType::from_code(bytes[position])?;
+        let compatibility = SchemaCompatibility::from_code(bytes[position + 1])?;
+        position += 2;
+        let definition_length = u32::from_le_bytes(
+            bytes[position..position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        position += 4;
+        if bytes.len() != position + definition_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(RegisterSchema {
+            stream_id,
+            topic_id,
+            schema_type,
+            compatibility,
+            definition: bytes.slice(position..),
+        })
+    }
+}
+
+impl Display for RegisterSchema {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.schema_type,
+            self.compatibility,
+            self.definition.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command() -> RegisterSchema {
+        RegisterSchema {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            schema_type: SchemaType::Json,
+            compatibility: SchemaCompatibility::Full,
+            definition: Bytes::from_static(br#"{"type":"object"}"#),
+        }
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = command();
+        let deserialized = RegisterSchema::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let bytes = command().to_bytes();
+        assert!(RegisterSchema::from_bytes(bytes.slice(..bytes.len() - 1)).is_err());
+    }
+
+    #[test]
+    fn empty_definition_should_be_invalid() {
+        let command = RegisterSchema {
+            definition: Bytes::new(),
+            ..command()
+        };
+        assert!(command.validate().is_err());
+    }
+}
@@ -0,0 +1,105 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::Validatable;
+use crate::error::IggyError;
+use crate::{Command, EXPORT_METADATA_CODE};
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `ExportMetadata` command is used to export the metadata of the streams, topics, consumer groups, users and roles.
+/// It has additional payload:
+/// - `include_password_hashes` - whether the password hashes of the users should be exported.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct ExportMetadata {
+    /// Whether the password hashes of the users should be exported.
+    #[serde(default)]
+    pub include_password_hashes: bool,
+}
+
+impl Command for ExportMetadata {
+    fn code(&self) -> u32 {
+        EXPORT_METADATA_CODE
+    }
+}
+
+impl Validatable<IggyError> for ExportMetadata {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for ExportMetadata {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(1);
+        bytes.put_u8(u8::from(self.include_password_hashes));
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<ExportMetadata, IggyError> {
+        if bytes.len() != 1 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let include_password_hashes = match bytes[0] {
+            0 => false,
+            1 => true,
+            _ => return Err(IggyError::InvalidCommand),
+        };
+        Ok(ExportMetadata {
+            include_password_hashes,
+        })
+    }
+}
+
+impl Display for ExportMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.include_password_hashes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = ExportMetadata {
+            include_password_hashes: true,
+        };
+
+        let bytes = command.to_bytes();
+        assert_eq!(bytes.len(), 1);
+        assert_eq!(bytes[0], 1);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let command = ExportMetadata::from_bytes(Bytes::from_static(&[1]));
+        assert!(command.is_ok());
+        assert!(command.unwrap().include_password_hashes);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_invalid_flag() {
+        let command = ExportMetadata::from_bytes(Bytes::from_static(&[2]));
+        assert!(command.is_err());
+    }
+}
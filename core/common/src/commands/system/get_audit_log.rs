@@ -0,0 +1,130 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::Validatable;
+use crate::error::IggyError;
+use crate::{Command, GET_AUDIT_LOG_CODE};
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `GetAuditLog` command is used to get the records of the audit log.
+/// It has additional payload:
+/// - `start_id` - unique ID of the first record to return.
+/// - `count` - maximum number of the records to return.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct GetAuditLog {
+    /// Unique ID of the first record to return.
+    #[serde(default)]
+    pub start_id: u64,
+    /// Maximum number of the records to return.
+    #[serde(default = "default_count")]
+    pub count: u32,
+}
+
+impl Command for GetAuditLog {
+    fn code(&self) -> u32 {
+        GET_AUDIT_LOG_CODE
+    }
+}
+
+impl Default for GetAuditLog {
+    fn default() -> Self {
+        GetAuditLog {
+            start_id: 0,
+            count: default_count(),
+        }
+    }
+}
+
+fn default_count() -> u32 {
+    100
+}
+
+impl Validatable<IggyError> for GetAuditLog {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetAuditLog {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(12);
+        bytes.put_u64_le(self.start_id);
+        bytes.put_u32_le(self.count);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<GetAuditLog, IggyError> {
+        if bytes.len() != 12 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let start_id = u64::from_le_bytes(
+            bytes[..8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let count = u32::from_le_bytes(
+            bytes[8..12]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        Ok(GetAuditLog { start_id, count })
+    }
+}
+
+impl Display for GetAuditLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.start_id, self.count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetAuditLog {
+            start_id: 10,
+            count: 50,
+        };
+
+        let bytes = command.to_bytes();
+        let start_id = u64::from_le_bytes(bytes[..8].try_into().unwrap());
+        let count = u32::from_le_bytes(bytes[8..12].try_into().unwrap());
+
+        assert_eq!(start_id, command.start_id);
+        assert_eq!(count, command.count);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let mut bytes = BytesMut::with_capacity(12);
+        bytes.put_u64_le(10);
+        bytes.put_u32_le(50);
+        let command = GetAuditLog::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.start_id, 10);
+        assert_eq!(command.count, 50);
+    }
+}
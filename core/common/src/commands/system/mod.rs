@@ -16,6 +16,8 @@
  * under the License.
  */
 
+pub mod export_metadata;
+pub mod get_audit_log;
 pub mod get_client;
 pub mod get_clients;
 pub mod get_me;
@@ -18,8 +18,10 @@
 
 use super::MAX_NAME_LENGTH;
 use crate::BytesSerializable;
+use crate::CleanupPolicy;
 use crate::CompressionAlgorithm;
 use crate::Identifier;
+use crate::PartitioningHash;
 use crate::Sizeable;
 use crate::Validatable;
 use crate::error::IggyError;
@@ -40,6 +42,10 @@ use std::str::from_utf8;
 ///   Can't be lower than segment size in the config.
 /// - `replication_factor` - replication factor for the topic.
 /// - `name` - unique topic name, max length is 255 characters.
+/// - `cleanup_policy` - cleanup policy of the topic, either `delete` or `compact`.
+/// - `partitioning_hash` - hashing of the messages key to the partitions, e.g. `murmur2` or `xxhash3:jump`.
+/// - `segment_max_age` - max age of the open segment, after which a new segment is rolled, if `NeverExpire` then segments are rolled only by size.
+/// - `max_partition_size` - maximum size of each partition, if `Unlimited` then the oldest segments of the partition are never deleted by size.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct UpdateTopic {
     /// Unique stream ID (numeric or name).
@@ -59,6 +65,18 @@ pub struct UpdateTopic {
     pub replication_factor: Option<u8>,
     /// Unique topic name, max length is 255 characters.
     pub name: String,
+    /// Cleanup policy of the topic, either `delete` or `compact`.
+    #[serde(default)]
+    pub cleanup_policy: CleanupPolicy,
+    /// Hashing of the messages key to the partitions, e.g. `murmur2` or `xxhash3:jump`.
+    #[serde(default)]
+    pub partitioning_hash: PartitioningHash,
+    /// Max age of the open segment, after which a new segment is rolled, if `NeverExpire` then segments are rolled only by size.
+    #[serde(default)]
+    pub segment_max_age: IggyExpiry,
+    /// Max partition size, if `Unlimited` then the oldest segments of the partition are never deleted by size.
+    #[serde(default)]
+    pub max_partition_size: MaxTopicSize,
 }
 
 impl Command for UpdateTopic {
@@ -77,6 +95,10 @@ impl Default for UpdateTopic {
             max_topic_size: MaxTopicSize::ServerDefault,
             replication_factor: None,
             name: "topic".to_string(),
+            cleanup_policy: CleanupPolicy::default(),
+            partitioning_hash: PartitioningHash::default(),
+            segment_max_age: IggyExpiry::ServerDefault,
+            max_partition_size: MaxTopicSize::ServerDefault,
         }
     }
 }
@@ -102,7 +124,7 @@ impl BytesSerializable for UpdateTopic {
         let stream_id_bytes = self.stream_id.to_bytes();
         let topic_id_bytes = self.topic_id.to_bytes();
         let mut bytes = BytesMut::with_capacity(
-            19 + stream_id_bytes.len() + topic_id_bytes.len() + self.name.len(),
+            20 + stream_id_bytes.len() + topic_id_bytes.len() + self.name.len(),
         );
         bytes.put_slice(&stream_id_bytes.clone());
         bytes.put_slice(&topic_id_bytes.clone());
@@ -116,6 +138,10 @@ impl BytesSerializable for UpdateTopic {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.put_slice(self.name.as_bytes());
+        bytes.put_u8(self.cleanup_policy.as_code());
+        bytes.put_slice(&self.partitioning_hash.as_code());
+        bytes.put_u64_le(self.segment_max_age.into());
+        bytes.put_u64_le(self.max_partition_size.into());
         bytes.freeze()
     }
 
@@ -153,6 +179,41 @@ impl BytesSerializable for UpdateTopic {
         if name.len() != name_length as usize {
             return Err(IggyError::InvalidCommand);
         }
+        // The cleanup policy is appended after the name, so the older clients and state entries can still be handled.
+        let cleanup_policy = match bytes.get(position + 18 + name_length as usize) {
+            Some(code) => CleanupPolicy::from_code(*code)?,
+            None => CleanupPolicy::default(),
+        };
+        // The partitioning hash follows the cleanup policy, and defaults to the original XXH32 modulo hashing.
+        let partitioning_hash = match bytes
+            .get(position + 19 + name_length as usize..position + 21 + name_length as usize)
+        {
+            Some(codes) => PartitioningHash::from_code(codes[0], codes[1])?,
+            None => PartitioningHash::default(),
+        };
+        // The segment max age and the max partition size follow the partitioning hash, and default to the server config.
+        let segment_max_age: IggyExpiry = match bytes
+            .get(position + 21 + name_length as usize..position + 29 + name_length as usize)
+        {
+            Some(value) => u64::from_le_bytes(
+                value
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            )
+            .into(),
+            None => IggyExpiry::ServerDefault,
+        };
+        let max_partition_size: MaxTopicSize = match bytes
+            .get(position + 29 + name_length as usize..position + 37 + name_length as usize)
+        {
+            Some(value) => u64::from_le_bytes(
+                value
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            )
+            .into(),
+            None => MaxTopicSize::ServerDefault,
+        };
         let command = UpdateTopic {
             stream_id,
             topic_id,
@@ -161,6 +222,10 @@ impl BytesSerializable for UpdateTopic {
             max_topic_size,
             replication_factor,
             name,
+            cleanup_policy,
+            partitioning_hash,
+            segment_max_age,
+            max_partition_size,
         };
         Ok(command)
     }
@@ -170,13 +235,17 @@ impl Display for UpdateTopic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}",
             self.stream_id,
             self.topic_id,
             self.message_expiry,
             self.max_topic_size,
             self.replication_factor.unwrap_or(0),
             self.name,
+            self.cleanup_policy,
+            self.partitioning_hash,
+            self.segment_max_age,
+            self.max_partition_size,
         )
     }
 }
@@ -197,6 +266,10 @@ mod tests {
             max_topic_size: MaxTopicSize::ServerDefault,
             replication_factor: Some(1),
             name: "test".to_string(),
+            cleanup_policy: CleanupPolicy::Compact,
+            partitioning_hash: PartitioningHash::default(),
+            segment_max_age: IggyExpiry::NeverExpire,
+            max_partition_size: MaxTopicSize::ServerDefault,
         };
 
         let bytes = command.to_bytes();
@@ -217,6 +290,8 @@ mod tests {
         let name = from_utf8(&bytes[position + 18..position + 18 + name_length as usize])
             .unwrap()
             .to_string();
+        let cleanup_policy =
+            CleanupPolicy::from_code(bytes[position + 18 + name_length as usize]).unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
@@ -227,6 +302,7 @@ mod tests {
         assert_eq!(replication_factor, command.replication_factor.unwrap());
         assert_eq!(name.len() as u8, command.name.len() as u8);
         assert_eq!(name, command.name);
+        assert_eq!(cleanup_policy, command.cleanup_policy);
     }
 
     #[test]
@@ -265,5 +341,40 @@ mod tests {
         assert_eq!(command.max_topic_size, max_topic_size);
         assert_eq!(command.replication_factor, Some(replication_factor));
         assert_eq!(command.name, name);
+        assert_eq!(command.cleanup_policy, CleanupPolicy::Delete);
+        assert_eq!(command.partitioning_hash, PartitioningHash::default());
+        assert_eq!(command.segment_max_age, IggyExpiry::ServerDefault);
+        assert_eq!(command.max_partition_size, MaxTopicSize::ServerDefault);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_with_partitioning_hash() {
+        let command = UpdateTopic {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            name: "test".to_string(),
+            partitioning_hash: "xxhash3:jump".parse().unwrap(),
+            ..Default::default()
+        };
+
+        let deserialized = UpdateTopic::from_bytes(command.to_bytes()).unwrap();
+
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_with_segment_max_age_and_max_partition_size() {
+        let command = UpdateTopic {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            name: "test".to_string(),
+            segment_max_age: "1h".parse().unwrap(),
+            max_partition_size: "10 GB".parse().unwrap(),
+            ..Default::default()
+        };
+
+        let deserialized = UpdateTopic::from_bytes(command.to_bytes()).unwrap();
+
+        assert_eq!(deserialized, command);
     }
 }
@@ -0,0 +1,106 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::Validatable;
+use crate::error::IggyError;
+use crate::{ABORT_TRANSACTION_CODE, Command};
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `AbortTransaction` command is used to abort the transaction, so that none of the messages sent within it are visible
+/// to the consumers polling with the `read_committed` isolation level.
+/// It has additional payload:
+/// - `transaction_id` - unique transaction ID.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct AbortTransaction {
+    /// Unique transaction ID.
+    pub transaction_id: u64,
+}
+
+impl Command for AbortTransaction {
+    fn code(&self) -> u32 {
+        ABORT_TRANSACTION_CODE
+    }
+}
+
+impl Validatable<IggyError> for AbortTransaction {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.transaction_id == 0 {
+            return Err(IggyError::InvalidTransactionId);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for AbortTransaction {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(8);
+        bytes.put_u64_le(self.transaction_id);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<AbortTransaction, IggyError> {
+        if bytes.len() != 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let transaction_id = u64::from_le_bytes(
+            bytes[..8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let command = AbortTransaction { transaction_id };
+        Ok(command)
+    }
+}
+
+impl Display for AbortTransaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.transaction_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = AbortTransaction { transaction_id: 1 };
+        let bytes = command.to_bytes();
+        let transaction_id = u64::from_le_bytes(bytes[..8].try_into().unwrap());
+
+        assert_eq!(bytes.len(), 8);
+        assert_eq!(transaction_id, command.transaction_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let transaction_id = 1u64;
+        let mut bytes = BytesMut::with_capacity(8);
+        bytes.put_u64_le(transaction_id);
+        let command = AbortTransaction::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.transaction_id, transaction_id);
+    }
+}
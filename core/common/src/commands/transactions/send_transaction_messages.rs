@@ -0,0 +1,89 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::Identifier;
+use crate::IggyMessage;
+use crate::Validatable;
+use crate::error::IggyError;
+use crate::types::message::partitioning::Partitioning;
+use crate::{Command, SEND_TRANSACTION_MESSAGES_CODE, SendMessages};
+use bytes::{BufMut, Bytes, BytesMut};
+use std::fmt::{Display, Formatter};
+
+/// `SendTransactionMessages` command is used to send messages to a topic in a stream within the transaction.
+/// The messages are appended to the partition right away, but they're visible to the consumers polling
+/// with the `read_committed` isolation level only once the transaction has been committed.
+/// It has additional payload:
+/// - `transaction_id` - unique transaction ID.
+/// - `messages` - the same payload as for the `SendMessages` command.
+#[derive(Debug, Default, PartialEq)]
+pub struct SendTransactionMessages {
+    /// Unique transaction ID.
+    pub transaction_id: u64,
+    /// Stream ID, topic ID, partitioning and the messages to be sent.
+    pub messages: SendMessages,
+}
+
+impl SendTransactionMessages {
+    pub fn bytes(
+        transaction_id: u64,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &[IggyMessage],
+    ) -> Bytes {
+        let messages_bytes = SendMessages::bytes(stream_id, topic_id, partitioning, None, messages);
+        let mut bytes = BytesMut::with_capacity(8 + messages_bytes.len());
+        bytes.put_u64_le(transaction_id);
+        bytes.put_slice(&messages_bytes);
+        bytes.freeze()
+    }
+}
+
+impl Command for SendTransactionMessages {
+    fn code(&self) -> u32 {
+        SEND_TRANSACTION_MESSAGES_CODE
+    }
+}
+
+impl Validatable<IggyError> for SendTransactionMessages {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.transaction_id == 0 {
+            return Err(IggyError::InvalidTransactionId);
+        }
+
+        self.messages.validate()
+    }
+}
+
+impl BytesSerializable for SendTransactionMessages {
+    fn to_bytes(&self) -> Bytes {
+        panic!("should not be used")
+    }
+
+    fn from_bytes(_bytes: Bytes) -> Result<SendTransactionMessages, IggyError> {
+        panic!("should not be used")
+    }
+}
+
+impl Display for SendTransactionMessages {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.transaction_id, self.messages)
+    }
+}
@@ -19,6 +19,7 @@
 use super::defaults::*;
 use crate::BytesSerializable;
 use crate::Permissions;
+use crate::UserQuotas;
 use crate::UserStatus;
 use crate::Validatable;
 use crate::error::IggyError;
@@ -34,6 +35,7 @@ use std::str::from_utf8;
 /// - `password` - password of the user, must be between 3 and 100 characters long.
 /// - `status` - status of the user, can be either `active` or `inactive`.
 /// - `permissions` - optional permissions of the user. If not provided, user will have no permissions.
+/// - `quotas` - optional quotas of the user. If not provided, user will have no limits.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct CreateUser {
     /// Unique name of the user, must be between 3 and 50 characters long.
@@ -44,6 +46,9 @@ pub struct CreateUser {
     pub status: UserStatus,
     /// Optional permissions of the user. If not provided, user will have no permissions.
     pub permissions: Option<Permissions>,
+    /// Optional quotas of the user. If not provided, user will have no limits.
+    #[serde(default)]
+    pub quotas: Option<UserQuotas>,
 }
 
 impl Command for CreateUser {
@@ -59,6 +64,7 @@ impl Default for CreateUser {
             password: "secret".to_string(),
             status: UserStatus::Active,
             permissions: None,
+            quotas: None,
         }
     }
 }
@@ -102,6 +108,12 @@ impl BytesSerializable for CreateUser {
         } else {
             bytes.put_u8(0);
         }
+        if let Some(quotas) = &self.quotas {
+            bytes.put_u8(1);
+            bytes.put_slice(&quotas.to_bytes());
+        } else {
+            bytes.put_u8(0);
+        }
         bytes.freeze()
     }
 
@@ -144,18 +156,36 @@ impl BytesSerializable for CreateUser {
                     .map_err(|_| IggyError::InvalidNumberEncoding)?,
             );
             position += 4;
-            Some(Permissions::from_bytes(
+            let permissions = Permissions::from_bytes(
                 bytes.slice(position..position + permissions_length as usize),
-            )?)
+            )?;
+            position += permissions_length as usize;
+            Some(permissions)
         } else {
             None
         };
 
+        // The quotas are optional, so that the command sent by the older clients is still valid.
+        let quotas = match bytes.get(position) {
+            Some(1) => {
+                position += 1;
+                if bytes.len() < position + UserQuotas::SIZE {
+                    return Err(IggyError::InvalidCommand);
+                }
+                Some(UserQuotas::from_bytes(
+                    bytes.slice(position..position + UserQuotas::SIZE),
+                )?)
+            }
+            Some(0) | None => None,
+            Some(_) => return Err(IggyError::InvalidCommand),
+        };
+
         let command = CreateUser {
             username,
             password,
             status,
             permissions,
+            quotas,
         };
         Ok(command)
     }
@@ -168,10 +198,15 @@ impl Display for CreateUser {
         } else {
             "no_permissions".to_string()
         };
+        let quotas = if let Some(quotas) = &self.quotas {
+            quotas.to_string()
+        } else {
+            "no_quotas".to_string()
+        };
         write!(
             f,
-            "{}|******|{}|{}",
-            self.username, self.status, permissions
+            "{}|******|{}|{}|{}",
+            self.username, self.status, permissions, quotas
         )
     }
 }
@@ -202,6 +237,11 @@ mod tests {
                 },
                 streams: None,
             }),
+            quotas: Some(UserQuotas {
+                max_produced_bytes_per_second: Some(1_000_000),
+                max_connections: Some(10),
+                ..Default::default()
+            }),
         };
 
         let bytes = command.to_bytes();
@@ -223,6 +263,11 @@ mod tests {
         let permissions =
             Permissions::from_bytes(bytes.slice(position..position + permissions_length as usize))
                 .unwrap();
+        position += permissions_length as usize;
+        let has_quotas = bytes[position];
+        position += 1;
+        let quotas =
+            UserQuotas::from_bytes(bytes.slice(position..position + UserQuotas::SIZE)).unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(username, command.username);
@@ -230,6 +275,8 @@ mod tests {
         assert_eq!(status, command.status);
         assert_eq!(has_permissions, 1);
         assert_eq!(permissions, command.permissions.unwrap());
+        assert_eq!(has_quotas, 1);
+        assert_eq!(quotas, command.quotas.unwrap());
     }
 
     #[test]
@@ -276,5 +323,6 @@ mod tests {
         assert_eq!(command.status, status);
         assert!(command.permissions.is_some());
         assert_eq!(command.permissions.unwrap(), permissions);
+        assert!(command.quotas.is_none());
     }
 }
@@ -21,8 +21,11 @@ pub const MIN_USERNAME_LENGTH: usize = 3;
 pub const MAX_PASSWORD_LENGTH: usize = 100;
 pub const MIN_PASSWORD_LENGTH: usize = 3;
 pub const MAX_PAT_LENGTH: usize = 100;
+pub const MAX_ACCESS_TOKEN_LENGTH: usize = 16384;
 pub const MAX_PERSONAL_ACCESS_TOKEN_NAME_LENGTH: usize = 30;
 pub const MIN_PERSONAL_ACCESS_TOKEN_NAME_LENGTH: usize = 3;
 pub const DEFAULT_ROOT_USER_ID: u32 = 1;
 pub const DEFAULT_ROOT_USERNAME: &str = "iggy";
 pub const DEFAULT_ROOT_PASSWORD: &str = "iggy";
+pub const MAX_ROLE_NAME_LENGTH: usize = 50;
+pub const MIN_ROLE_NAME_LENGTH: usize = 3;
@@ -0,0 +1,167 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::defaults::*;
+use crate::BytesSerializable;
+use crate::Permissions;
+use crate::UserQuotas;
+use crate::UserStatus;
+use crate::Validatable;
+use crate::create_user::CreateUser;
+use crate::error::IggyError;
+use crate::{Command, IMPORT_USER_CODE};
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `ImportUser` command is used to create a new user with the password hash exported from another server,
+/// so that the user can log in with the same password without it being known to the importer.
+/// It has the same payload as `CreateUser`, except for the password:
+/// - `username` - unique name of the user, must be between 3 and 50 characters long.
+/// - `password_hash` - bcrypt hash of the password, as exported along with the server metadata.
+/// - `status` - status of the user, can be either `active` or `inactive`.
+/// - `permissions` - optional permissions of the user. If not provided, user will have no permissions.
+/// - `quotas` - optional quotas of the user. If not provided, user will have no limits.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ImportUser {
+    /// Unique name of the user, must be between 3 and 50 characters long.
+    pub username: String,
+    /// Bcrypt hash of the password, as exported along with the server metadata.
+    pub password_hash: String,
+    /// Status of the user, can be either `active` or `inactive`.
+    pub status: UserStatus,
+    /// Optional permissions of the user. If not provided, user will have no permissions.
+    pub permissions: Option<Permissions>,
+    /// Optional quotas of the user. If not provided, user will have no limits.
+    #[serde(default)]
+    pub quotas: Option<UserQuotas>,
+}
+
+impl Command for ImportUser {
+    fn code(&self) -> u32 {
+        IMPORT_USER_CODE
+    }
+}
+
+impl Default for ImportUser {
+    fn default() -> Self {
+        ImportUser {
+            username: "user".to_string(),
+            password_hash: "$2b$04$ddm6xRqMwVbg3B6ZbEsC3.GYFIfNLxmYHbKs7ZWmGK5JbkxL8N0Ly"
+                .to_string(),
+            status: UserStatus::Active,
+            permissions: None,
+            quotas: None,
+        }
+    }
+}
+
+impl Validatable<IggyError> for ImportUser {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.username.is_empty()
+            || self.username.len() > MAX_USERNAME_LENGTH
+            || self.username.len() < MIN_USERNAME_LENGTH
+        {
+            return Err(IggyError::InvalidUsername);
+        }
+
+        if self.password_hash.is_empty() || self.password_hash.len() > u8::MAX as usize {
+            return Err(IggyError::InvalidPassword);
+        }
+
+        Ok(())
+    }
+}
+
+/// The binary format is the same as the one of `CreateUser`, with the password hash in place of the password.
+impl BytesSerializable for ImportUser {
+    fn to_bytes(&self) -> Bytes {
+        CreateUser {
+            username: self.username.clone(),
+            password: self.password_hash.clone(),
+            status: self.status,
+            permissions: self.permissions.clone(),
+            quotas: self.quotas,
+        }
+        .to_bytes()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<ImportUser, IggyError> {
+        let command = CreateUser::from_bytes(bytes)?;
+        Ok(ImportUser {
+            username: command.username,
+            password_hash: command.password,
+            status: command.status,
+            permissions: command.permissions,
+            quotas: command.quotas,
+        })
+    }
+}
+
+impl Display for ImportUser {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let permissions = if let Some(permissions) = &self.permissions {
+            permissions.to_string()
+        } else {
+            "no_permissions".to_string()
+        };
+        let quotas = if let Some(quotas) = &self.quotas {
+            quotas.to_string()
+        } else {
+            "no_quotas".to_string()
+        };
+        write!(
+            f,
+            "{}|******|{}|{}|{}",
+            self.username, self.status, permissions, quotas
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_as_bytes() {
+        let command = ImportUser {
+            quotas: Some(UserQuotas {
+                max_connections: Some(10),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let deserialized_command = ImportUser::from_bytes(command.to_bytes()).unwrap();
+
+        assert_eq!(deserialized_command, command);
+    }
+
+    #[test]
+    fn should_not_be_valid_given_empty_password_hash() {
+        let command = ImportUser {
+            password_hash: String::new(),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            command.validate(),
+            Err(IggyError::InvalidPassword)
+        ));
+    }
+}
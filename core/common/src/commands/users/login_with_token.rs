@@ -0,0 +1,130 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::Validatable;
+use crate::defaults::*;
+use crate::error::IggyError;
+use crate::{Command, LOGIN_WITH_TOKEN_CODE};
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::from_utf8;
+
+/// `LoginWithToken` command is used to login the user with a JSON Web Token issued by one of the external issuers
+/// configured on the server, instead of the username and password.
+/// It has additional payload:
+/// - `token` - the bearer token (JWT)
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct LoginWithToken {
+    /// The bearer token (JWT)
+    pub token: String,
+}
+
+impl Command for LoginWithToken {
+    fn code(&self) -> u32 {
+        LOGIN_WITH_TOKEN_CODE
+    }
+}
+
+impl Default for LoginWithToken {
+    fn default() -> Self {
+        LoginWithToken {
+            token: "token".to_string(),
+        }
+    }
+}
+
+impl Validatable<IggyError> for LoginWithToken {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.token.is_empty() || self.token.len() > MAX_ACCESS_TOKEN_LENGTH {
+            return Err(IggyError::InvalidAccessToken);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for LoginWithToken {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(4 + self.token.len());
+        bytes.put_u32_le(self.token.len() as u32);
+        bytes.put_slice(self.token.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<LoginWithToken, IggyError> {
+        if bytes.len() < 5 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let token_length = u32::from_le_bytes(
+            bytes[0..4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        let token = from_utf8(
+            bytes
+                .get(4..4 + token_length)
+                .ok_or(IggyError::InvalidCommand)?,
+        )
+        .map_err(|_| IggyError::InvalidUtf8)?
+        .to_string();
+
+        let command = LoginWithToken { token };
+        Ok(command)
+    }
+}
+
+impl Display for LoginWithToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "******")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = LoginWithToken {
+            token: "header.payload.signature".to_string(),
+        };
+
+        let bytes = command.to_bytes();
+        let token_length = u32::from_le_bytes(bytes[0..4].try_into().unwrap()) as usize;
+        let token = from_utf8(&bytes[4..4 + token_length]).unwrap();
+        assert!(!bytes.is_empty());
+        assert_eq!(token, command.token);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let token = "header.payload.signature";
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(token.len() as u32);
+        bytes.put_slice(token.as_bytes());
+
+        let command = LoginWithToken::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.token, token);
+    }
+}
@@ -22,7 +22,9 @@ pub mod defaults;
 pub mod delete_user;
 pub mod get_user;
 pub mod get_users;
+pub mod import_user;
 pub mod login_user;
+pub mod login_with_token;
 pub mod logout_user;
 pub mod update_permissions;
 pub mod update_user;
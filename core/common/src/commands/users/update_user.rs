@@ -20,6 +20,7 @@ use super::defaults::*;
 use crate::BytesSerializable;
 use crate::Identifier;
 use crate::Sizeable;
+use crate::UserQuotas;
 use crate::UserStatus;
 use crate::Validatable;
 use crate::error::IggyError;
@@ -29,17 +30,20 @@ use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use std::str::from_utf8;
 
-/// `UpdateUser` command is used to update a user's username and status.
+/// `UpdateUser` command is used to update a user's username, status and quotas.
 /// It has additional payload:
 /// - `user_id` - unique user ID (numeric or name).
 /// - `username` - new username (optional), if provided, must be between 3 and 50 characters long.
 /// - `status` - new status (optional)
+/// - `quotas` - new quotas (optional), the quotas without any limits remove the existing ones.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct UpdateUser {
     #[serde(skip)]
     pub user_id: Identifier,
     pub username: Option<String>,
     pub status: Option<UserStatus>,
+    #[serde(default)]
+    pub quotas: Option<UserQuotas>,
 }
 
 impl Command for UpdateUser {
@@ -85,6 +89,12 @@ impl BytesSerializable for UpdateUser {
         } else {
             bytes.put_u8(0);
         }
+        if let Some(quotas) = &self.quotas {
+            bytes.put_u8(1);
+            bytes.put_slice(&quotas.to_bytes());
+        } else {
+            bytes.put_u8(0);
+        }
 
         bytes.freeze()
     }
@@ -126,11 +136,28 @@ impl BytesSerializable for UpdateUser {
         } else {
             None
         };
+        position += 1;
+
+        // The quotas are optional, so that the command sent by the older clients is still valid.
+        let quotas = match bytes.get(position) {
+            Some(1) => {
+                position += 1;
+                if bytes.len() < position + UserQuotas::SIZE {
+                    return Err(IggyError::InvalidCommand);
+                }
+                Some(UserQuotas::from_bytes(
+                    bytes.slice(position..position + UserQuotas::SIZE),
+                )?)
+            }
+            Some(0) | None => None,
+            Some(_) => return Err(IggyError::InvalidCommand),
+        };
 
         let command = UpdateUser {
             user_id,
             username,
             status,
+            quotas,
         };
         Ok(command)
     }
@@ -143,7 +170,11 @@ impl Display for UpdateUser {
             .status
             .as_ref()
             .map_or_else(String::new, |s| s.to_string());
-        write!(f, "{}|{username}|{status}", self.user_id)
+        let quotas = self
+            .quotas
+            .as_ref()
+            .map_or_else(String::new, |q| q.to_string());
+        write!(f, "{}|{username}|{status}|{quotas}", self.user_id)
     }
 }
 
@@ -157,6 +188,10 @@ mod tests {
             user_id: Identifier::numeric(1).unwrap(),
             username: Some("user".to_string()),
             status: Some(UserStatus::Active),
+            quotas: Some(UserQuotas {
+                max_consumed_bytes_per_second: Some(1_000_000),
+                ..Default::default()
+            }),
         };
 
         let bytes = command.to_bytes();
@@ -171,6 +206,11 @@ mod tests {
         let has_status = bytes[position];
         position += 1;
         let status = UserStatus::from_code(bytes[position]).unwrap();
+        position += 1;
+        let has_quotas = bytes[position];
+        position += 1;
+        let quotas =
+            UserQuotas::from_bytes(bytes.slice(position..position + UserQuotas::SIZE)).unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(user_id, command.user_id);
@@ -178,6 +218,8 @@ mod tests {
         assert_eq!(username, command.username.unwrap());
         assert_eq!(has_status, 1);
         assert_eq!(status, command.status.unwrap());
+        assert_eq!(has_quotas, 1);
+        assert_eq!(quotas, command.quotas.unwrap());
     }
 
     #[test]
@@ -200,5 +242,6 @@ mod tests {
         assert_eq!(command.user_id, user_id);
         assert_eq!(command.username.unwrap(), username);
         assert_eq!(command.status.unwrap(), status);
+        assert!(command.quotas.is_none());
     }
 }
@@ -63,6 +63,10 @@ pub enum IggyError {
     StateFileCorrupted = 15,
     #[error("Invalid state entry checksum: {0}, expected: {1}, for index: {2}")]
     InvalidStateEntryChecksum(u32, u32, u64) = 16,
+    #[error("Audit log file corrupted")]
+    AuditLogCorrupted = 17,
+    #[error("Invalid audit record checksum: {0}, expected: {1}, for ID: {2}")]
+    InvalidAuditRecordChecksum(u32, u32, u64) = 18,
     #[error("Cannot open database, Path: {0}")]
     CannotOpenDatabase(String) = 19,
     #[error("Resource with key: {0} was not found.")]
@@ -111,8 +115,20 @@ pub enum IggyError {
     PersonalAccessTokenExpired(String, u32) = 54,
     #[error("Users limit reached.")]
     UsersLimitReached = 55,
+    #[error("Connections quota: {0} for user with ID: {1} has been exceeded.")]
+    ConnectionsQuotaExceeded(u32, u32) = 56,
+    #[error("Access token claims are not mapped to any user.")]
+    UnmappedAccessTokenClaims = 57,
+    #[error("Invalid role name")]
+    InvalidRoleName = 58,
+    #[error("Role: {0} was not found.")]
+    RoleNotFound(String) = 59,
+    #[error("Role: {0} already exists.")]
+    RoleAlreadyExists(String) = 60,
     #[error("Not connected")]
     NotConnected = 61,
+    #[error("Invalid resource pattern: {0}")]
+    InvalidResourcePattern(String) = 62,
     #[error("Client shutdown")]
     ClientShutdown = 63,
     #[error("Invalid TLS domain")]
@@ -123,6 +139,10 @@ pub enum IggyError {
     InvalidTlsCertificate = 66,
     #[error("Failed to add certificate")]
     FailedToAddCertificate = 67,
+    #[error("Cannot compress data")]
+    CannotCompressData = 68,
+    #[error("Cannot decompress data")]
+    CannotDecompressData = 69,
     #[error("Invalid encryption key")]
     InvalidEncryptionKey = 70,
     #[error("Cannot encrypt data")]
@@ -141,6 +161,8 @@ pub enum IggyError {
     AccessTokenMissing = 77,
     #[error("Invalid access token")]
     InvalidAccessToken = 78,
+    #[error("Cannot load JSON Web Key Set from: {0}")]
+    CannotLoadJwks(String) = 79,
     #[error("Invalid size bytes")]
     InvalidSizeBytes = 80,
     #[error("Invalid UTF-8")]
@@ -151,6 +173,24 @@ pub enum IggyError {
     InvalidBooleanValue = 83,
     #[error("Invalid number value")]
     InvalidNumberValue = 84,
+    #[error("Password or its hash is required to create user: {0}")]
+    MissingUserPassword(String) = 85,
+    #[error("Invalid schema type")]
+    InvalidSchemaType = 86,
+    #[error("Invalid schema compatibility")]
+    InvalidSchemaCompatibility = 87,
+    #[error("Invalid schema definition: {0}")]
+    InvalidSchemaDefinition(String) = 88,
+    #[error("Schema with ID: {0} was not found.")]
+    SchemaNotFound(u32) = 89,
+    #[error("Schema is incompatible with the latest version: {0}")]
+    IncompatibleSchema(String) = 90,
+    #[error("Message payload does not match schema with ID: {0}, reason: {1}")]
+    MessageSchemaViolation(u32, String) = 91,
+    #[error("Invalid schema ID header, expected uint32 value")]
+    InvalidSchemaIdHeader = 92,
+    #[error("Schema with ID: {0} does not belong to topic with ID: {1} for stream with ID: {2}")]
+    SchemaNotRegisteredForTopic(u32, u32, u32) = 93,
     #[error("Client with ID: {0} was not found.")]
     ClientNotFound(u32) = 100,
     #[error("Invalid client ID")]
@@ -247,6 +287,14 @@ pub enum IggyError {
     CannotReadTopics(u32) = 2017,
     #[error("Invalid replication factor")]
     InvalidReplicationFactor = 2018,
+    #[error("Invalid cleanup policy")]
+    InvalidCleanupPolicy = 2019,
+    #[error("Invalid partitioning hash")]
+    InvalidPartitioningHash = 2020,
+    #[error(
+        "Max partition size cannot be lower than segment size. Max partition size: {0} < segment size: {1}."
+    )]
+    InvalidPartitionSize(MaxTopicSize, IggyByteSize) = 2021,
     #[error("Cannot create partition with ID: {0} for stream with ID: {1} and topic with ID: {2}")]
     CannotCreatePartition(u32, u32, u32) = 3000,
     #[error(
@@ -285,6 +333,22 @@ pub enum IggyError {
     CannotReadConsumerOffsets(String) = 3020,
     #[error("Consumer offset for consumer with ID: {0} was not found.")]
     ConsumerOffsetNotFound(u32) = 3021,
+    #[error("Invalid consumer offset metadata")]
+    InvalidConsumerOffsetMetadata = 3022,
+    #[error("Invalid consumer offsets count")]
+    InvalidConsumerOffsetsCount = 3023,
+    #[error("Consumer offset for partition with ID: {0} was provided more than once.")]
+    DuplicatedConsumerOffsetPartition(u32) = 3024,
+    #[error("Data directory: {0} is not configured.")]
+    InvalidDataDirectory(String) = 3025,
+    #[error(
+        "Partition with ID: {0} for topic with ID: {1} for stream with ID: {2} is already being moved."
+    )]
+    PartitionMoveInProgress(u32, u32, u32) = 3026,
+    #[error(
+        "Failed to move partition with ID: {0} for topic with ID: {1} for stream with ID: {2} to data directory: {3}"
+    )]
+    CannotMovePartition(u32, u32, u32, String) = 3027,
     #[error("Segment not found")]
     SegmentNotFound = 4000,
     #[error("Segment with start offset: {0} and partition with ID: {1} is closed")]
@@ -361,6 +425,14 @@ pub enum IggyError {
     InvalidMessagesSize(u32, u32) = 4036,
     #[error("Too small message: {0}B, expected: {1}B")]
     TooSmallMessage(u32, u32) = 4037,
+    #[error("Invalid message filter")]
+    InvalidMessageFilter = 4038,
+    #[error("Cannot fetch offloaded segment with start offset: {0} for partition with ID: {1}")]
+    CannotFetchOffloadedSegment(u64, u32) = 4039,
+    #[error("Messages key header: {0} was not found")]
+    MessagesKeyHeaderNotFound(String) = 4040,
+    #[error("User header key: {0} is reserved")]
+    ReservedHeaderKey(String) = 4041,
     #[error("Cannot sed messages due to client disconnection")]
     CannotSendMessagesDueToClientDisconnection = 4050,
     #[error("Invalid offset: {0}")]
@@ -389,6 +461,30 @@ pub enum IggyError {
         "Failed to delete consumer group info file for ID: {0} for topic with ID: {1} for stream with ID: {2}."
     )]
     CannotDeleteConsumerGroupInfo(u32, u32, u32) = 5008,
+    #[error("Invalid consumer group assignment strategy")]
+    InvalidConsumerGroupAssignmentStrategy = 5009,
+    #[error(
+        "Consumer group with ID: {0} for topic with ID: {1} has active members, the offsets cannot be reset."
+    )]
+    ConsumerGroupHasActiveMembers(u32, u32) = 5010,
+    #[error("Invalid consumer offsets reset target")]
+    InvalidConsumerOffsetsResetTarget = 5011,
+    #[error("Transaction with ID: {0} was not found.")]
+    TransactionNotFound(u64) = 5100,
+    #[error("Invalid transaction ID")]
+    InvalidTransactionId = 5101,
+    #[error("Producer with ID: {0} was not found.")]
+    ProducerNotFound(u64) = 5200,
+    #[error(
+        "Producer with ID: {0} has been fenced, epoch: {1} doesn't match the current epoch: {2}."
+    )]
+    ProducerFenced(u64, u32, u32) = 5201,
+    #[error(
+        "Out of order sequence number: {1} for producer with ID: {0} in partition with ID: {3}, expected: {2}."
+    )]
+    OutOfOrderSequenceNumber(u64, u64, u64, u32) = 5202,
+    #[error("Idempotent messages cannot be sent using the balanced partitioning.")]
+    InvalidIdempotentPartitioning = 5203,
     #[error("Base offset is missing")]
     MissingBaseOffsetRetainedMessageBatch = 6000,
     #[error("Last offset delta is missing")]
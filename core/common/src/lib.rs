@@ -32,18 +32,23 @@ pub use commands::consumer_offsets::*;
 pub use commands::messages::*;
 pub use commands::partitions::*;
 pub use commands::personal_access_tokens::*;
+pub use commands::producers::*;
+pub use commands::roles::*;
+pub use commands::schemas::*;
 pub use commands::segments::*;
 pub use commands::streams::*;
 pub use commands::system::*;
 pub use commands::topics::*;
+pub use commands::transactions::*;
 pub use commands::users::*;
 // Traits
 pub use traits::bytes_serializable::BytesSerializable;
-pub use traits::partitioner::Partitioner;
+pub use traits::partitioner::{MessagesKeyPartitioner, Partitioner};
 pub use traits::sizeable::Sizeable;
 pub use traits::validatable::Validatable;
 // Types
 pub use types::args::*;
+pub use types::audit::*;
 pub use types::client::client_info::*;
 pub use types::client_state::ClientState;
 pub use types::command::*;
@@ -59,22 +64,35 @@ pub use types::configuration::quick_config::quic_client_reconnection_config::*;
 pub use types::configuration::tcp_config::tcp_client_config::*;
 pub use types::configuration::tcp_config::tcp_client_config_builder::*;
 pub use types::configuration::tcp_config::tcp_client_reconnection_config::*;
+pub use types::configuration::{SERVER_ADDRESSES_SEPARATOR, split_server_addresses};
 pub use types::confirmation::*;
 pub use types::consumer::consumer_group::*;
+pub use types::consumer::consumer_group_assignment_strategy::*;
 pub use types::consumer::consumer_kind::*;
 pub use types::consumer::consumer_offset_info::*;
+pub use types::consumer::consumer_offsets_reset::*;
+pub use types::consumer::consumer_partition_offset::*;
 pub use types::diagnostic::diagnostic_event::DiagnosticEvent;
 pub use types::identifier::*;
 pub use types::message::*;
+pub use types::metadata::metadata_plan::*;
+pub use types::metadata::server_metadata::*;
 pub use types::partition::*;
 pub use types::permissions::permissions_global::*;
 pub use types::permissions::personal_access_token::*;
+pub use types::permissions::role::*;
+pub use types::producer::*;
+pub use types::schema::schema_compatibility::*;
+pub use types::schema::schema_type::*;
+pub use types::schema::*;
 pub use types::snapshot::*;
 pub use types::stats::*;
 pub use types::stream::*;
 pub use types::topic::*;
+pub use types::transaction::*;
 pub use types::user::user_identity_info::*;
 pub use types::user::user_info::*;
+pub use types::user::user_quotas::*;
 pub use types::user::user_status::*;
 // Utils
 pub use utils::byte_size::IggyByteSize;
@@ -19,6 +19,7 @@
 use crate::Identifier;
 use crate::error::IggyError;
 use crate::types::message::IggyMessage;
+use crate::{HeaderKey, PartitioningHash};
 use std::fmt::Debug;
 
 /// The trait represent the logic responsible for calculating the partition ID and is used by the `IggyClient`.
@@ -31,3 +32,111 @@ pub trait Partitioner: Send + Sync + Debug {
         messages: &[IggyMessage],
     ) -> Result<u32, IggyError>;
 }
+
+/// The built-in partitioner calculating the partition ID from the messages key the same way as the server does
+/// for the topic created with the given `PartitioningHash`, e.g. to use the Kafka compatible murmur2 hashing
+/// or the jump consistent hashing on the client side.
+///
+/// The messages key is the raw value of the user header with the given key, taken from the first message,
+/// so all the messages sent in the same batch should share the same key.
+#[derive(Debug)]
+pub struct MessagesKeyPartitioner {
+    partitioning_hash: PartitioningHash,
+    partitions_count: u32,
+    key_header: HeaderKey,
+}
+
+impl MessagesKeyPartitioner {
+    /// Creates a new partitioner for the topic with the given partitioning hash and partitions count,
+    /// reading the messages key from the user header with the given key.
+    pub fn new(
+        partitioning_hash: PartitioningHash,
+        partitions_count: u32,
+        key_header: HeaderKey,
+    ) -> Self {
+        Self {
+            partitioning_hash,
+            partitions_count,
+            key_header,
+        }
+    }
+}
+
+impl Partitioner for MessagesKeyPartitioner {
+    fn calculate_partition_id(
+        &self,
+        _stream_id: &Identifier,
+        _topic_id: &Identifier,
+        messages: &[IggyMessage],
+    ) -> Result<u32, IggyError> {
+        let Some(message) = messages.first() else {
+            return Err(IggyError::InvalidMessagesCount);
+        };
+        let Some(messages_key) = message.get_user_header(&self.key_header)? else {
+            return Err(IggyError::MessagesKeyHeaderNotFound(
+                self.key_header.as_str().to_owned(),
+            ));
+        };
+        Ok(self
+            .partitioning_hash
+            .calculate_partition_id(messages_key.as_raw()?, self.partitions_count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HeaderValue, PartitioningHashAlgorithm, PartitioningHashMode};
+    use std::collections::HashMap;
+
+    fn message_with_key(key: &str) -> IggyMessage {
+        let key_header = HeaderKey::new("key").unwrap();
+        let headers = HashMap::from([(key_header, HeaderValue::from_raw(key.as_bytes()).unwrap())]);
+        IggyMessage::builder()
+            .payload("test".into())
+            .user_headers(headers)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn partition_id_should_match_the_server_hashing_of_the_messages_key() {
+        let partitioning_hash = PartitioningHash::new(
+            PartitioningHashAlgorithm::Murmur2,
+            PartitioningHashMode::Jump,
+        );
+        let partitioner =
+            MessagesKeyPartitioner::new(partitioning_hash, 5, HeaderKey::new("key").unwrap());
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(1).unwrap();
+
+        for key in ["order-1", "order-2", "order-3"] {
+            let partition_id = partitioner
+                .calculate_partition_id(&stream_id, &topic_id, &[message_with_key(key)])
+                .unwrap();
+            assert_eq!(
+                partition_id,
+                partitioning_hash.calculate_partition_id(key.as_bytes(), 5)
+            );
+        }
+    }
+
+    #[test]
+    fn missing_messages_key_header_should_fail() {
+        let partitioner = MessagesKeyPartitioner::new(
+            PartitioningHash::default(),
+            5,
+            HeaderKey::new("tenant").unwrap(),
+        );
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(1).unwrap();
+
+        let result =
+            partitioner.calculate_partition_id(&stream_id, &topic_id, &[message_with_key("key")]);
+
+        assert!(matches!(
+            result,
+            Err(IggyError::MessagesKeyHeaderNotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,134 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::utils::checksum::calculate_checksum;
+use crate::utils::timestamp::IggyTimestamp;
+use bytes::{BufMut, BytesMut};
+use serde::{Deserialize, Serialize};
+
+/// `AuditRecord` represents a single record of the audit log.
+/// The records are hash-chained, the checksum of each record covers the checksum of the previous one,
+/// so that any modified or removed record breaks the chain.
+/// It consists of the following fields:
+/// - `id`: the unique identifier (sequence number) of the record.
+/// - `timestamp`: the time when the command was handled.
+/// - `user_id`: the unique identifier of the user who issued the command. This field is optional, as the command might be issued by the unauthenticated client e.g. failed login.
+/// - `address`: the remote address of the client.
+/// - `transport`: the transport protocol used by the client.
+/// - `command`: the name of the command.
+/// - `target`: the identifiers of the resource affected by the command.
+/// - `error_code`: the code of the error returned by the command. This field is optional, as the command might have succeeded.
+/// - `previous_checksum`: the checksum of the previous record.
+/// - `checksum`: the checksum of the record.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AuditRecord {
+    /// The unique identifier (sequence number) of the record.
+    pub id: u64,
+    /// The time when the command was handled.
+    pub timestamp: IggyTimestamp,
+    /// The unique identifier of the user who issued the command. This field is optional, as the command might be issued by the unauthenticated client e.g. failed login.
+    pub user_id: Option<u32>,
+    /// The remote address of the client.
+    pub address: String,
+    /// The transport protocol used by the client.
+    pub transport: String,
+    /// The name of the command.
+    pub command: String,
+    /// The identifiers of the resource affected by the command.
+    pub target: String,
+    /// The code of the error returned by the command. This field is optional, as the command might have succeeded.
+    pub error_code: Option<u32>,
+    /// The checksum of the previous record.
+    pub previous_checksum: u32,
+    /// The checksum of the record.
+    pub checksum: u32,
+}
+
+impl AuditRecord {
+    /// Returns `true` if the audited command has succeeded.
+    pub fn is_success(&self) -> bool {
+        self.error_code.is_none()
+    }
+
+    /// Calculates the checksum of the record, including the checksum of the previous record.
+    pub fn calculate_checksum(&self) -> u32 {
+        let mut bytes = BytesMut::with_capacity(
+            4 + 8
+                + 8
+                + 4
+                + 4
+                + 4 * 4
+                + self.address.len()
+                + self.transport.len()
+                + self.command.len()
+                + self.target.len(),
+        );
+        bytes.put_u32_le(self.previous_checksum);
+        bytes.put_u64_le(self.id);
+        bytes.put_u64_le(self.timestamp.into());
+        bytes.put_u32_le(self.user_id.unwrap_or_default());
+        bytes.put_u32_le(self.error_code.unwrap_or_default());
+        for value in [&self.address, &self.transport, &self.command, &self.target] {
+            bytes.put_u32_le(value.len() as u32);
+            bytes.put_slice(value.as_bytes());
+        }
+        calculate_checksum(&bytes)
+    }
+
+    /// Returns `true` if the record is correctly chained to the previous one.
+    pub fn is_chained_to(&self, previous: &AuditRecord) -> bool {
+        self.id == previous.id + 1 && self.previous_checksum == previous.checksum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksum_should_cover_previous_checksum_and_fields() {
+        let mut first = record(1, 0);
+        first.checksum = first.calculate_checksum();
+        let mut second = record(2, first.checksum);
+        second.checksum = second.calculate_checksum();
+        assert!(second.is_chained_to(&first));
+
+        let mut tampered = second.clone();
+        tampered.target = "stream_id: 2".to_string();
+        assert_ne!(tampered.calculate_checksum(), second.checksum);
+
+        first.user_id = Some(2);
+        first.checksum = first.calculate_checksum();
+        assert!(!second.is_chained_to(&first));
+    }
+
+    fn record(id: u64, previous_checksum: u32) -> AuditRecord {
+        AuditRecord {
+            id,
+            timestamp: IggyTimestamp::from(1694968446131680),
+            user_id: Some(1),
+            address: "127.0.0.1:1234".to_string(),
+            transport: "TCP".to_string(),
+            command: "stream.delete".to_string(),
+            target: "stream_id: 1".to_string(),
+            error_code: None,
+            previous_checksum,
+            checksum: 0,
+        }
+    }
+}
@@ -25,12 +25,20 @@ pub trait Command: BytesSerializable + Validatable<IggyError> + Send + Sync + Di
     fn code(&self) -> u32;
 }
 
+/// The flag set in the code of a pipelined TCP request, which is followed by the 4-byte correlation ID
+/// echoed by the server before the response, so that multiple requests can be in flight on one connection.
+pub const CORRELATION_ID_FLAG: u32 = 1 << 31;
+
 pub const PING: &str = "ping";
 pub const PING_CODE: u32 = 1;
 pub const GET_STATS: &str = "stats";
 pub const GET_STATS_CODE: u32 = 10;
 pub const GET_SNAPSHOT_FILE: &str = "snapshot";
 pub const GET_SNAPSHOT_FILE_CODE: u32 = 11;
+pub const GET_AUDIT_LOG: &str = "audit_log";
+pub const GET_AUDIT_LOG_CODE: u32 = 12;
+pub const EXPORT_METADATA: &str = "metadata.export";
+pub const EXPORT_METADATA_CODE: u32 = 13;
 pub const GET_ME: &str = "me";
 pub const GET_ME_CODE: u32 = 20;
 pub const GET_CLIENT: &str = "client.get";
@@ -55,6 +63,8 @@ pub const LOGIN_USER: &str = "user.login";
 pub const LOGIN_USER_CODE: u32 = 38;
 pub const LOGOUT_USER: &str = "user.logout";
 pub const LOGOUT_USER_CODE: u32 = 39;
+pub const LOGIN_WITH_TOKEN: &str = "user.login_with_token";
+pub const LOGIN_WITH_TOKEN_CODE: u32 = 40;
 pub const GET_PERSONAL_ACCESS_TOKENS: &str = "personal_access_token.list";
 pub const GET_PERSONAL_ACCESS_TOKENS_CODE: u32 = 41;
 pub const CREATE_PERSONAL_ACCESS_TOKEN: &str = "personal_access_token.create";
@@ -63,6 +73,18 @@ pub const DELETE_PERSONAL_ACCESS_TOKEN: &str = "personal_access_token.delete";
 pub const DELETE_PERSONAL_ACCESS_TOKEN_CODE: u32 = 43;
 pub const LOGIN_WITH_PERSONAL_ACCESS_TOKEN: &str = "personal_access_token.login";
 pub const LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE: u32 = 44;
+pub const IMPORT_USER: &str = "user.import";
+pub const IMPORT_USER_CODE: u32 = 45;
+pub const GET_ROLES: &str = "role.list";
+pub const GET_ROLES_CODE: u32 = 50;
+pub const CREATE_ROLE: &str = "role.create";
+pub const CREATE_ROLE_CODE: u32 = 51;
+pub const UPDATE_ROLE: &str = "role.update";
+pub const UPDATE_ROLE_CODE: u32 = 52;
+pub const DELETE_ROLE: &str = "role.delete";
+pub const DELETE_ROLE_CODE: u32 = 53;
+pub const ASSIGN_ROLES: &str = "role.assign";
+pub const ASSIGN_ROLES_CODE: u32 = 54;
 pub const POLL_MESSAGES: &str = "message.poll";
 pub const POLL_MESSAGES_CODE: u32 = 100;
 pub const SEND_MESSAGES: &str = "message.send";
@@ -75,6 +97,8 @@ pub const STORE_CONSUMER_OFFSET: &str = "consumer_offset.store";
 pub const STORE_CONSUMER_OFFSET_CODE: u32 = 121;
 pub const DELETE_CONSUMER_OFFSET: &str = "consumer_offset.delete";
 pub const DELETE_CONSUMER_OFFSET_CODE: u32 = 122;
+pub const STORE_CONSUMER_OFFSETS: &str = "consumer_offset.store_many";
+pub const STORE_CONSUMER_OFFSETS_CODE: u32 = 123;
 pub const GET_STREAM: &str = "stream.get";
 pub const GET_STREAM_CODE: u32 = 200;
 pub const GET_STREAMS: &str = "stream.list";
@@ -103,6 +127,8 @@ pub const CREATE_PARTITIONS: &str = "partition.create";
 pub const CREATE_PARTITIONS_CODE: u32 = 402;
 pub const DELETE_PARTITIONS: &str = "partition.delete";
 pub const DELETE_PARTITIONS_CODE: u32 = 403;
+pub const MOVE_PARTITION: &str = "partition.move";
+pub const MOVE_PARTITION_CODE: u32 = 404;
 pub const DELETE_SEGMENTS: &str = "segment.delete";
 pub const DELETE_SEGMENTS_CODE: u32 = 503;
 pub const GET_CONSUMER_GROUP: &str = "consumer_group.get";
@@ -117,6 +143,30 @@ pub const JOIN_CONSUMER_GROUP: &str = "consumer_group.join";
 pub const JOIN_CONSUMER_GROUP_CODE: u32 = 604;
 pub const LEAVE_CONSUMER_GROUP: &str = "consumer_group.leave";
 pub const LEAVE_CONSUMER_GROUP_CODE: u32 = 605;
+pub const SYNC_CONSUMER_GROUP: &str = "consumer_group.sync";
+pub const SYNC_CONSUMER_GROUP_CODE: u32 = 606;
+pub const RECORD_DELIVERY_FAILURE: &str = "consumer_group.record_delivery_failure";
+pub const RECORD_DELIVERY_FAILURE_CODE: u32 = 607;
+pub const RESET_CONSUMER_GROUP_OFFSETS: &str = "consumer_group.reset_offsets";
+pub const RESET_CONSUMER_GROUP_OFFSETS_CODE: u32 = 608;
+pub const BEGIN_TRANSACTION: &str = "transaction.begin";
+pub const BEGIN_TRANSACTION_CODE: u32 = 700;
+pub const SEND_TRANSACTION_MESSAGES: &str = "transaction.send";
+pub const SEND_TRANSACTION_MESSAGES_CODE: u32 = 701;
+pub const COMMIT_TRANSACTION: &str = "transaction.commit";
+pub const COMMIT_TRANSACTION_CODE: u32 = 702;
+pub const ABORT_TRANSACTION: &str = "transaction.abort";
+pub const ABORT_TRANSACTION_CODE: u32 = 703;
+pub const INIT_PRODUCER: &str = "producer.init";
+pub const INIT_PRODUCER_CODE: u32 = 800;
+pub const GET_SCHEMA: &str = "schema.get";
+pub const GET_SCHEMA_CODE: u32 = 900;
+pub const GET_SCHEMAS: &str = "schema.list";
+pub const GET_SCHEMAS_CODE: u32 = 901;
+pub const REGISTER_SCHEMA: &str = "schema.register";
+pub const REGISTER_SCHEMA_CODE: u32 = 902;
+pub const DELETE_SCHEMA: &str = "schema.delete";
+pub const DELETE_SCHEMA_CODE: u32 = 903;
 
 pub fn get_name_from_code(code: u32) -> Result<&'static str, IggyError> {
     match code {
@@ -134,15 +184,23 @@ pub fn get_name_from_code(code: u32) -> Result<&'static str, IggyError> {
         CHANGE_PASSWORD_CODE => Ok(CHANGE_PASSWORD),
         LOGIN_USER_CODE => Ok(LOGIN_USER),
         LOGOUT_USER_CODE => Ok(LOGOUT_USER),
+        LOGIN_WITH_TOKEN_CODE => Ok(LOGIN_WITH_TOKEN),
         GET_PERSONAL_ACCESS_TOKENS_CODE => Ok(GET_PERSONAL_ACCESS_TOKENS),
         CREATE_PERSONAL_ACCESS_TOKEN_CODE => Ok(CREATE_PERSONAL_ACCESS_TOKEN),
         DELETE_PERSONAL_ACCESS_TOKEN_CODE => Ok(DELETE_PERSONAL_ACCESS_TOKEN),
         LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE => Ok(LOGIN_WITH_PERSONAL_ACCESS_TOKEN),
+        IMPORT_USER_CODE => Ok(IMPORT_USER),
+        GET_ROLES_CODE => Ok(GET_ROLES),
+        CREATE_ROLE_CODE => Ok(CREATE_ROLE),
+        UPDATE_ROLE_CODE => Ok(UPDATE_ROLE),
+        DELETE_ROLE_CODE => Ok(DELETE_ROLE),
+        ASSIGN_ROLES_CODE => Ok(ASSIGN_ROLES),
         SEND_MESSAGES_CODE => Ok(SEND_MESSAGES),
         POLL_MESSAGES_CODE => Ok(POLL_MESSAGES),
         FLUSH_UNSAVED_BUFFER_CODE => Ok(FLUSH_UNSAVED_BUFFER),
         STORE_CONSUMER_OFFSET_CODE => Ok(STORE_CONSUMER_OFFSET),
         GET_CONSUMER_OFFSET_CODE => Ok(GET_CONSUMER_OFFSET),
+        STORE_CONSUMER_OFFSETS_CODE => Ok(STORE_CONSUMER_OFFSETS),
         GET_STREAM_CODE => Ok(GET_STREAM),
         GET_STREAMS_CODE => Ok(GET_STREAMS),
         CREATE_STREAM_CODE => Ok(CREATE_STREAM),
@@ -157,13 +215,28 @@ pub fn get_name_from_code(code: u32) -> Result<&'static str, IggyError> {
         PURGE_TOPIC_CODE => Ok(PURGE_TOPIC),
         CREATE_PARTITIONS_CODE => Ok(CREATE_PARTITIONS),
         DELETE_PARTITIONS_CODE => Ok(DELETE_PARTITIONS),
+        MOVE_PARTITION_CODE => Ok(MOVE_PARTITION),
         GET_CONSUMER_GROUP_CODE => Ok(GET_CONSUMER_GROUP),
         GET_CONSUMER_GROUPS_CODE => Ok(GET_CONSUMER_GROUPS),
         CREATE_CONSUMER_GROUP_CODE => Ok(CREATE_CONSUMER_GROUP),
         DELETE_CONSUMER_GROUP_CODE => Ok(DELETE_CONSUMER_GROUP),
         JOIN_CONSUMER_GROUP_CODE => Ok(JOIN_CONSUMER_GROUP),
         LEAVE_CONSUMER_GROUP_CODE => Ok(LEAVE_CONSUMER_GROUP),
+        SYNC_CONSUMER_GROUP_CODE => Ok(SYNC_CONSUMER_GROUP),
+        RECORD_DELIVERY_FAILURE_CODE => Ok(RECORD_DELIVERY_FAILURE),
+        RESET_CONSUMER_GROUP_OFFSETS_CODE => Ok(RESET_CONSUMER_GROUP_OFFSETS),
+        BEGIN_TRANSACTION_CODE => Ok(BEGIN_TRANSACTION),
+        SEND_TRANSACTION_MESSAGES_CODE => Ok(SEND_TRANSACTION_MESSAGES),
+        COMMIT_TRANSACTION_CODE => Ok(COMMIT_TRANSACTION),
+        ABORT_TRANSACTION_CODE => Ok(ABORT_TRANSACTION),
+        INIT_PRODUCER_CODE => Ok(INIT_PRODUCER),
+        GET_SCHEMA_CODE => Ok(GET_SCHEMA),
+        GET_SCHEMAS_CODE => Ok(GET_SCHEMAS),
+        REGISTER_SCHEMA_CODE => Ok(REGISTER_SCHEMA),
+        DELETE_SCHEMA_CODE => Ok(DELETE_SCHEMA),
         GET_SNAPSHOT_FILE_CODE => Ok(GET_SNAPSHOT_FILE),
+        GET_AUDIT_LOG_CODE => Ok(GET_AUDIT_LOG),
+        EXPORT_METADATA_CODE => Ok(EXPORT_METADATA),
         _ => Err(IggyError::InvalidCommand),
     }
 }
@@ -27,8 +27,7 @@ use std::{
 
 use crate::error::IggyError;
 
-// for now only those, in the future will add snappy, lz4, zstd (same as in confluent kafka) in addition to that
-// we should consider brotli as well.
+// in the future we should consider brotli as well.
 /// Supported compression algorithms
 #[derive(Debug, Default, PartialEq, Clone, Copy)]
 pub enum CompressionAlgorithm {
@@ -37,6 +36,12 @@ pub enum CompressionAlgorithm {
     None,
     // Gzip compression algorithm
     Gzip,
+    // Zstandard compression algorithm, the level is configured on the server
+    Zstd,
+    // LZ4 compression algorithm
+    Lz4,
+    // Snappy compression algorithm
+    Snappy,
 }
 
 impl FromStr for CompressionAlgorithm {
@@ -45,6 +50,9 @@ impl FromStr for CompressionAlgorithm {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "gzip" => Ok(CompressionAlgorithm::Gzip),
+            "zstd" => Ok(CompressionAlgorithm::Zstd),
+            "lz4" => Ok(CompressionAlgorithm::Lz4),
+            "snappy" => Ok(CompressionAlgorithm::Snappy),
             "none" => Ok(CompressionAlgorithm::None),
             _ => Err(format!("Unknown compression type: {}", s)),
         }
@@ -56,6 +64,9 @@ impl CompressionAlgorithm {
         match self {
             CompressionAlgorithm::None => 1,
             CompressionAlgorithm::Gzip => 2,
+            CompressionAlgorithm::Zstd => 3,
+            CompressionAlgorithm::Lz4 => 4,
+            CompressionAlgorithm::Snappy => 5,
         }
     }
 
@@ -63,6 +74,9 @@ impl CompressionAlgorithm {
         match code {
             1 => Ok(CompressionAlgorithm::None),
             2 => Ok(CompressionAlgorithm::Gzip),
+            3 => Ok(CompressionAlgorithm::Zstd),
+            4 => Ok(CompressionAlgorithm::Lz4),
+            5 => Ok(CompressionAlgorithm::Snappy),
             _ => Err(IggyError::InvalidCommand),
         }
     }
@@ -73,6 +87,9 @@ impl Display for CompressionAlgorithm {
         match self {
             CompressionAlgorithm::None => write!(f, "none"),
             CompressionAlgorithm::Gzip => write!(f, "gzip"),
+            CompressionAlgorithm::Zstd => write!(f, "zstd"),
+            CompressionAlgorithm::Lz4 => write!(f, "lz4"),
+            CompressionAlgorithm::Snappy => write!(f, "snappy"),
         }
     }
 }
@@ -85,6 +102,9 @@ impl Serialize for CompressionAlgorithm {
         match self {
             CompressionAlgorithm::None => serializer.serialize_str("none"),
             CompressionAlgorithm::Gzip => serializer.serialize_str("gzip"),
+            CompressionAlgorithm::Zstd => serializer.serialize_str("zstd"),
+            CompressionAlgorithm::Lz4 => serializer.serialize_str("lz4"),
+            CompressionAlgorithm::Snappy => serializer.serialize_str("snappy"),
         }
     }
 }
@@ -94,6 +114,9 @@ impl From<CompressionAlgorithm> for String {
         match value {
             CompressionAlgorithm::None => "none".to_string(),
             CompressionAlgorithm::Gzip => "gzip".to_string(),
+            CompressionAlgorithm::Zstd => "zstd".to_string(),
+            CompressionAlgorithm::Lz4 => "lz4".to_string(),
+            CompressionAlgorithm::Snappy => "snappy".to_string(),
         }
     }
 }
@@ -144,6 +167,18 @@ mod tests {
         let gzip_alg = CompressionAlgorithm::from_str("Gzip");
         assert!(gzip_alg.is_ok());
         assert_eq!(gzip_alg.unwrap(), CompressionAlgorithm::Gzip);
+
+        let zstd_alg = CompressionAlgorithm::from_str("zstd");
+        assert!(zstd_alg.is_ok());
+        assert_eq!(zstd_alg.unwrap(), CompressionAlgorithm::Zstd);
+
+        let lz4_alg = CompressionAlgorithm::from_str("LZ4");
+        assert!(lz4_alg.is_ok());
+        assert_eq!(lz4_alg.unwrap(), CompressionAlgorithm::Lz4);
+
+        let snappy_alg = CompressionAlgorithm::from_str("snappy");
+        assert!(snappy_alg.is_ok());
+        assert_eq!(snappy_alg.unwrap(), CompressionAlgorithm::Snappy);
     }
 
     #[test]
@@ -166,6 +201,15 @@ mod tests {
         let gzip_string: String = gzip.into();
 
         assert_eq!(gzip_string, "gzip".to_string());
+
+        let zstd_string: String = CompressionAlgorithm::Zstd.into();
+        assert_eq!(zstd_string, "zstd".to_string());
+
+        let lz4_string: String = CompressionAlgorithm::Lz4.into();
+        assert_eq!(lz4_string, "lz4".to_string());
+
+        let snappy_string: String = CompressionAlgorithm::Snappy.into();
+        assert_eq!(snappy_string, "snappy".to_string());
     }
     #[test]
     fn test_as_code() {
@@ -176,6 +220,10 @@ mod tests {
         let gzip = CompressionAlgorithm::Gzip;
         let gzip_code = gzip.as_code();
         assert_eq!(gzip_code, 2);
+
+        assert_eq!(CompressionAlgorithm::Zstd.as_code(), 3);
+        assert_eq!(CompressionAlgorithm::Lz4.as_code(), 4);
+        assert_eq!(CompressionAlgorithm::Snappy.as_code(), 5);
     }
     #[test]
     fn test_from_code() {
@@ -186,6 +234,15 @@ mod tests {
         let gzip = CompressionAlgorithm::from_code(2);
         assert!(gzip.is_ok());
         assert_eq!(gzip.unwrap(), CompressionAlgorithm::Gzip);
+
+        let zstd = CompressionAlgorithm::from_code(3);
+        assert_eq!(zstd.unwrap(), CompressionAlgorithm::Zstd);
+
+        let lz4 = CompressionAlgorithm::from_code(4);
+        assert_eq!(lz4.unwrap(), CompressionAlgorithm::Lz4);
+
+        let snappy = CompressionAlgorithm::from_code(5);
+        assert_eq!(snappy.unwrap(), CompressionAlgorithm::Snappy);
     }
     #[test]
     fn test_from_code_invalid_input() {
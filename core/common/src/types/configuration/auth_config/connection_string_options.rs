@@ -26,9 +26,12 @@ pub struct ConnectionStringOptions {
     reconnection: TcpClientReconnectionConfig,
     heartbeat_interval: IggyDuration,
     nodelay: bool,
+    pipelining: bool,
+    connection_pool_size: u32,
 }
 
 impl ConnectionStringOptions {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         tls_enabled: bool,
         tls_domain: String,
@@ -36,6 +39,8 @@ impl ConnectionStringOptions {
         reconnection: TcpClientReconnectionConfig,
         heartbeat_interval: IggyDuration,
         nodelay: bool,
+        pipelining: bool,
+        connection_pool_size: u32,
     ) -> Self {
         Self {
             tls_enabled,
@@ -44,6 +49,8 @@ impl ConnectionStringOptions {
             reconnection,
             heartbeat_interval,
             nodelay,
+            pipelining,
+            connection_pool_size,
         }
     }
 }
@@ -72,6 +79,14 @@ impl ConnectionStringOptions {
     pub fn nodelay(&self) -> bool {
         self.nodelay
     }
+
+    pub fn pipelining(&self) -> bool {
+        self.pipelining
+    }
+
+    pub fn connection_pool_size(&self) -> u32 {
+        self.connection_pool_size
+    }
 }
 
 impl Default for ConnectionStringOptions {
@@ -83,6 +98,8 @@ impl Default for ConnectionStringOptions {
             reconnection: Default::default(),
             heartbeat_interval: IggyDuration::from_str("5s").unwrap(),
             nodelay: false,
+            pipelining: false,
+            connection_pool_size: 1,
         }
     }
 }
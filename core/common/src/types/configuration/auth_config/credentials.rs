@@ -19,4 +19,6 @@
 pub enum Credentials {
     UsernamePassword(String, String),
     PersonalAccessToken(String),
+    /// JSON Web Token issued by one of the external issuers configured on the server.
+    Token(String),
 }
@@ -19,3 +19,15 @@ pub(crate) mod auth_config;
 pub(crate) mod http_config;
 pub(crate) mod quick_config;
 pub(crate) mod tcp_config;
+
+/// The separator of the server addresses the client fails over between, e.g. `10.0.0.1:8090,10.0.0.2:8090`.
+pub const SERVER_ADDRESSES_SEPARATOR: char = ',';
+
+/// Splits the comma-separated server addresses, skipping the empty ones.
+pub fn split_server_addresses(server_address: &str) -> Vec<&str> {
+    server_address
+        .split(SERVER_ADDRESSES_SEPARATOR)
+        .map(str::trim)
+        .filter(|address| !address.is_empty())
+        .collect()
+}
@@ -16,7 +16,7 @@
  * under the License.
  */
 
-use crate::{AutoLogin, IggyDuration, QuicClientReconnectionConfig};
+use crate::{AutoLogin, IggyDuration, QuicClientReconnectionConfig, split_server_addresses};
 use std::str::FromStr;
 
 /// Configuration for the QUIC client.
@@ -24,7 +24,7 @@ use std::str::FromStr;
 pub struct QuicClientConfig {
     /// The address to bind the QUIC client to.
     pub client_address: String,
-    /// The address of the QUIC server to connect to.
+    /// The address of the QUIC server to connect to, or the comma-separated addresses of the servers to fail over between.
     pub server_address: String,
     /// The server name to use.
     pub server_name: String,
@@ -50,6 +50,10 @@ pub struct QuicClientConfig {
     pub max_idle_timeout: u64,
     /// Whether to validate the server certificate.
     pub validate_certificate: bool,
+    /// The path to the client certificate file, used to authenticate the client (mTLS) instead of the login.
+    pub client_cert_file: Option<String>,
+    /// The path to the client private key file, required along with the client certificate.
+    pub client_key_file: Option<String>,
     /// Interval of heartbeats sent by the client
     pub heartbeat_interval: IggyDuration,
 }
@@ -72,6 +76,15 @@ impl Default for QuicClientConfig {
             keep_alive_interval: 5000,
             max_idle_timeout: 10000,
             validate_certificate: false,
+            client_cert_file: None,
+            client_key_file: None,
         }
     }
 }
+
+impl QuicClientConfig {
+    /// Returns the addresses of the servers, the first one is connected to initially.
+    pub fn server_addresses(&self) -> Vec<&str> {
+        split_server_addresses(&self.server_address)
+    }
+}
@@ -16,13 +16,13 @@
  * under the License.
  */
 
-use crate::{AutoLogin, IggyDuration, QuicClientConfig};
+use crate::{AutoLogin, IggyDuration, QuicClientConfig, SERVER_ADDRESSES_SEPARATOR};
 
 /// Builder for the QUIC client configuration.
 ///
 /// Allows configuring the QUIC client with custom settings or using defaults:
 /// - `client_address`: Default is "127.0.0.1:0" (binds to any available port).
-/// - `server_address`: Default is "127.0.0.1:8080".
+/// - `server_address`: Default is "127.0.0.1:8080", multiple comma-separated addresses enable failover.
 /// - `server_name`: Default is "localhost".
 /// - `auto_login`: Default is AutoLogin::Disabled.
 /// - `reconnection`: Default is enabled unlimited retries and 1 second interval.
@@ -35,6 +35,8 @@ use crate::{AutoLogin, IggyDuration, QuicClientConfig};
 /// - `keep_alive_interval`: Default is 5000 milliseconds.
 /// - `max_idle_timeout`: Default is 10,000 milliseconds.
 /// - `validate_certificate`: Default is false (certificate validation is disabled).
+/// - `client_cert_file`: Default is None.
+/// - `client_key_file`: Default is None.
 #[derive(Debug, Default)]
 pub struct QuicClientConfigBuilder {
     config: QuicClientConfig,
@@ -58,6 +60,12 @@ impl QuicClientConfigBuilder {
         self
     }
 
+    /// Sets the addresses of the servers to fail over between, starting with the first one.
+    pub fn with_server_addresses(mut self, server_addresses: &[String]) -> Self {
+        self.config.server_address = server_addresses.join(&SERVER_ADDRESSES_SEPARATOR.to_string());
+        self
+    }
+
     /// Sets the auto sign in during connection.
     pub fn with_auto_sign_in(mut self, auto_sign_in: AutoLogin) -> Self {
         self.config.auto_login = auto_sign_in;
@@ -141,6 +149,13 @@ impl QuicClientConfigBuilder {
         self
     }
 
+    /// Sets the paths to the client certificate and private key files (mTLS).
+    pub fn with_client_certificate(mut self, cert_file: String, key_file: String) -> Self {
+        self.config.client_cert_file = Some(cert_file);
+        self.config.client_key_file = Some(key_file);
+        self
+    }
+
     /// Sets the heartbeat interval. Defaults to 5000ms.
     pub fn with_heartbeat_interval(mut self, interval: IggyDuration) -> Self {
         self.config.heartbeat_interval = interval;
@@ -16,13 +16,13 @@
  * under the License.
  */
 use crate::types::configuration::auth_config::connection_string::ConnectionString;
-use crate::{AutoLogin, IggyDuration, TcpClientReconnectionConfig};
+use crate::{AutoLogin, IggyDuration, TcpClientReconnectionConfig, split_server_addresses};
 use std::str::FromStr;
 
 /// Configuration for the TCP client.
 #[derive(Debug, Clone)]
 pub struct TcpClientConfig {
-    /// The address of the Iggy server.
+    /// The address of the Iggy server, or the comma-separated addresses of the servers to fail over between.
     pub server_address: String,
     /// Whether to use TLS when connecting to the server.
     pub tls_enabled: bool,
@@ -30,6 +30,10 @@ pub struct TcpClientConfig {
     pub tls_domain: String,
     /// The path to the CA file for TLS.
     pub tls_ca_file: Option<String>,
+    /// The path to the client certificate file for TLS, used to authenticate the client (mTLS) instead of the login.
+    pub tls_client_cert_file: Option<String>,
+    /// The path to the client private key file for TLS, required along with the client certificate.
+    pub tls_client_key_file: Option<String>,
     /// Whether to automatically login user after establishing connection.
     pub auto_login: AutoLogin,
     /// Whether to automatically reconnect when disconnected.
@@ -38,6 +42,12 @@ pub struct TcpClientConfig {
     pub heartbeat_interval: IggyDuration,
     /// Disable Nagle algorithm for the TCP socket.
     pub nodelay: bool,
+    /// Whether to send the requests with correlation IDs, so that multiple in-flight requests can share the connection.
+    pub pipelining: bool,
+    /// The number of connections to the server, the additional ones are used to send and poll (without consumer group) messages.
+    /// The long polls without consumer group always use their own connections, up to this number of them is kept open.
+    /// The long polls by a consumer group are sent using the primary connection, delaying the other requests of the client.
+    pub connection_pool_size: u32,
 }
 
 impl Default for TcpClientConfig {
@@ -47,14 +57,25 @@ impl Default for TcpClientConfig {
             tls_enabled: false,
             tls_domain: "localhost".to_string(),
             tls_ca_file: None,
+            tls_client_cert_file: None,
+            tls_client_key_file: None,
             heartbeat_interval: IggyDuration::from_str("5s").unwrap(),
             auto_login: AutoLogin::Disabled,
             reconnection: TcpClientReconnectionConfig::default(),
             nodelay: false,
+            pipelining: false,
+            connection_pool_size: 1,
         }
     }
 }
 
+impl TcpClientConfig {
+    /// Returns the addresses of the servers, the first one is connected to initially.
+    pub fn server_addresses(&self) -> Vec<&str> {
+        split_server_addresses(&self.server_address)
+    }
+}
+
 impl From<ConnectionString> for TcpClientConfig {
     fn from(connection_string: ConnectionString) -> Self {
         TcpClientConfig {
@@ -63,9 +84,13 @@ impl From<ConnectionString> for TcpClientConfig {
             tls_enabled: connection_string.options().tls_enabled(),
             tls_domain: connection_string.options().tls_domain().into(),
             tls_ca_file: connection_string.options().tls_ca_file().to_owned(),
+            tls_client_cert_file: None,
+            tls_client_key_file: None,
             reconnection: connection_string.options().reconnection().to_owned(),
             heartbeat_interval: connection_string.options().heartbeat_interval(),
             nodelay: connection_string.options().nodelay(),
+            pipelining: connection_string.options().pipelining(),
+            connection_pool_size: connection_string.options().connection_pool_size(),
         }
     }
 }
@@ -15,17 +15,21 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::{AutoLogin, IggyDuration, IggyError, TcpClientConfig};
+use crate::{AutoLogin, IggyDuration, IggyError, SERVER_ADDRESSES_SEPARATOR, TcpClientConfig};
 use std::net::SocketAddr;
 
 /// Builder for the TCP client configuration.
 /// Allows configuring the TCP client with custom settings or using defaults:
-/// - `server_address`: Default is "127.0.0.1:8090"
+/// - `server_address`: Default is "127.0.0.1:8090", multiple comma-separated addresses enable failover.
 /// - `auto_login`: Default is AutoLogin::Disabled.
 /// - `reconnection`: Default is enabled unlimited retries and 1 second interval.
 /// - `tls_enabled`: Default is false.
 /// - `tls_domain`: Default is "localhost".
 /// - `tls_ca_file`: Default is None.
+/// - `tls_client_cert_file`: Default is None.
+/// - `tls_client_key_file`: Default is None.
+/// - `pipelining`: Default is false.
+/// - `connection_pool_size`: Default is 1 (no additional connections).
 #[derive(Debug, Default)]
 pub struct TcpClientConfigBuilder {
     config: TcpClientConfig,
@@ -42,6 +46,12 @@ impl TcpClientConfigBuilder {
         self
     }
 
+    /// Sets the addresses of the servers the TCP client fails over between, starting with the first one.
+    pub fn with_server_addresses(mut self, server_addresses: &[String]) -> Self {
+        self.config.server_address = server_addresses.join(&SERVER_ADDRESSES_SEPARATOR.to_string());
+        self
+    }
+
     /// Sets the auto sign in during connection.
     pub fn with_auto_sign_in(mut self, auto_sign_in: AutoLogin) -> Self {
         self.config.auto_login = auto_sign_in;
@@ -83,19 +93,43 @@ impl TcpClientConfigBuilder {
         self
     }
 
+    /// Sets the paths to the client certificate and private key files for TLS (mTLS).
+    pub fn with_tls_client_certificate(mut self, cert_file: String, key_file: String) -> Self {
+        self.config.tls_client_cert_file = Some(cert_file);
+        self.config.tls_client_key_file = Some(key_file);
+        self
+    }
+
     /// Sets the nodelay option for the TCP socket.
     pub fn with_no_delay(mut self) -> Self {
         self.config.nodelay = true;
         self
     }
 
+    /// Enables sending the requests with correlation IDs, so that multiple in-flight requests can share the connection.
+    pub fn with_pipelining(mut self) -> Self {
+        self.config.pipelining = true;
+        self
+    }
+
+    /// Sets the number of connections to the server, the additional ones are used to send and poll messages.
+    pub fn with_connection_pool_size(mut self, connection_pool_size: u32) -> Self {
+        self.config.connection_pool_size = connection_pool_size;
+        self
+    }
+
     /// Builds the TCP client configuration.
     pub fn build(self) -> Result<TcpClientConfig, IggyError> {
-        let addr = self.config.server_address.trim();
+        for addr in self.config.server_address.split(SERVER_ADDRESSES_SEPARATOR) {
+            let addr = addr.trim();
+            if addr.parse::<SocketAddr>().is_err() {
+                let (ip, port) = addr.rsplit_once(':').unwrap_or((addr, ""));
+                return Err(IggyError::InvalidIpAddress(ip.to_owned(), port.to_owned()));
+            }
+        }
 
-        if addr.parse::<SocketAddr>().is_err() {
-            let (ip, port) = addr.rsplit_once(':').unwrap_or((addr, ""));
-            return Err(IggyError::InvalidIpAddress(ip.to_owned(), port.to_owned()));
+        if self.config.connection_pool_size == 0 {
+            return Err(IggyError::InvalidConfiguration);
         }
 
         Ok(self.config)
@@ -169,4 +203,41 @@ mod tests {
             Err(IggyError::InvalidIpAddress(_, _))
         ));
     }
+
+    #[test]
+    fn multiple_valid_addresses_should_succeed() {
+        let builder = builder_with_address("127.0.0.1:8090, [::1]:8091");
+        let config = builder.build().unwrap();
+        assert_eq!(
+            config.server_addresses(),
+            vec!["127.0.0.1:8090", "[::1]:8091"]
+        );
+    }
+
+    #[test]
+    fn multiple_addresses_with_invalid_one_should_fail() {
+        let builder = builder_with_address("127.0.0.1:8090,invalid.ip:8091");
+        assert!(matches!(
+            builder.build(),
+            Err(IggyError::InvalidIpAddress(_, _))
+        ));
+    }
+
+    #[test]
+    fn zero_connection_pool_size_should_fail() {
+        let builder = builder_with_address("127.0.0.1:8090").with_connection_pool_size(0);
+        assert!(matches!(
+            builder.build(),
+            Err(IggyError::InvalidConfiguration)
+        ));
+    }
+
+    #[test]
+    fn server_addresses_should_be_joined() {
+        let config = TcpClientConfigBuilder::new()
+            .with_server_addresses(&["127.0.0.1:8090".to_owned(), "127.0.0.1:8091".to_owned()])
+            .build()
+            .unwrap();
+        assert_eq!(config.server_address, "127.0.0.1:8090,127.0.0.1:8091");
+    }
 }
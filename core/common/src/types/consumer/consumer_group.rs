@@ -70,3 +70,22 @@ pub struct ConsumerGroupMember {
     /// The collection of partitions the consumer group member is consuming.
     pub partitions: Vec<u32>,
 }
+
+/// `ConsumerGroupAssignment` represents the partitions assigned to the consumer group member,
+/// along with the changes caused by the rebalances which haven't been acknowledged by the member yet.
+/// It consists of the following fields:
+/// - `generation`: the generation of the consumer group, incremented on every rebalance.
+/// - `partitions`: the collection of partitions the consumer group member is currently consuming.
+/// - `assigned_partitions`: the collection of partitions assigned to the member since the last acknowledgement.
+/// - `revoked_partitions`: the collection of partitions revoked from the member since the last acknowledgement.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone)]
+pub struct ConsumerGroupAssignment {
+    /// The generation of the consumer group, incremented on every rebalance.
+    pub generation: u32,
+    /// The collection of partitions the consumer group member is currently consuming.
+    pub partitions: Vec<u32>,
+    /// The collection of partitions assigned to the member since the last acknowledgement.
+    pub assigned_partitions: Vec<u32>,
+    /// The collection of partitions revoked from the member since the last acknowledgement.
+    pub revoked_partitions: Vec<u32>,
+}
@@ -0,0 +1,112 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `ConsumerGroupAssignmentStrategy` defines how the partitions are assigned to the consumer group members
+/// whenever a member joins or leaves the group.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Clone, Copy, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsumerGroupAssignmentStrategy {
+    /// All the partitions are reassigned to the members using round-robin on every rebalance.
+    #[default]
+    RoundRobin,
+    /// Only the partitions required to balance the group are moved between the members,
+    /// and the revoked partition is handed over to the new member once the previous one acknowledges the rebalance.
+    CooperativeSticky,
+}
+
+impl FromStr for ConsumerGroupAssignmentStrategy {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "round_robin" => Ok(ConsumerGroupAssignmentStrategy::RoundRobin),
+            "cooperative_sticky" => Ok(ConsumerGroupAssignmentStrategy::CooperativeSticky),
+            _ => Err(IggyError::InvalidConsumerGroupAssignmentStrategy),
+        }
+    }
+}
+
+impl Display for ConsumerGroupAssignmentStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConsumerGroupAssignmentStrategy::RoundRobin => write!(f, "round_robin"),
+            ConsumerGroupAssignmentStrategy::CooperativeSticky => write!(f, "cooperative_sticky"),
+        }
+    }
+}
+
+impl ConsumerGroupAssignmentStrategy {
+    /// Returns the code of the assignment strategy.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            ConsumerGroupAssignmentStrategy::RoundRobin => 1,
+            ConsumerGroupAssignmentStrategy::CooperativeSticky => 2,
+        }
+    }
+
+    /// Returns the assignment strategy from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(ConsumerGroupAssignmentStrategy::RoundRobin),
+            2 => Ok(ConsumerGroupAssignmentStrategy::CooperativeSticky),
+            _ => Err(IggyError::InvalidConsumerGroupAssignmentStrategy),
+        }
+    }
+
+    /// Returns `true` if the revoked partitions are handed over only after the acknowledgement.
+    pub fn is_cooperative(&self) -> bool {
+        *self == ConsumerGroupAssignmentStrategy::CooperativeSticky
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_parsed_from_str() {
+        assert_eq!(
+            ConsumerGroupAssignmentStrategy::from_str("round_robin").unwrap(),
+            ConsumerGroupAssignmentStrategy::RoundRobin
+        );
+        assert_eq!(
+            ConsumerGroupAssignmentStrategy::from_str("Cooperative_Sticky").unwrap(),
+            ConsumerGroupAssignmentStrategy::CooperativeSticky
+        );
+        assert!(ConsumerGroupAssignmentStrategy::from_str("sticky").is_err());
+    }
+
+    #[test]
+    fn should_be_converted_from_and_to_code() {
+        for strategy in [
+            ConsumerGroupAssignmentStrategy::RoundRobin,
+            ConsumerGroupAssignmentStrategy::CooperativeSticky,
+        ] {
+            assert_eq!(
+                ConsumerGroupAssignmentStrategy::from_code(strategy.as_code()).unwrap(),
+                strategy
+            );
+        }
+        assert!(ConsumerGroupAssignmentStrategy::from_code(0).is_err());
+        assert!(ConsumerGroupAssignmentStrategy::from_code(3).is_err());
+    }
+}
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::utils::timestamp::IggyTimestamp;
 use serde::{Deserialize, Serialize};
 
 /// `ConsumerOffsetInfo` represents the information about a consumer offset.
@@ -23,6 +24,8 @@ use serde::{Deserialize, Serialize};
 /// - `partition_id`: the unique identifier of the partition.
 /// - `current_offset`: the current offset of the partition.
 /// - `stored_offset`: the stored offset by the consumer in the partition.
+/// - `metadata`: the optional metadata stored along with the offset.
+/// - `committed_at`: the timestamp of the last commit of the offset.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ConsumerOffsetInfo {
     /// The unique identifier of the partition.
@@ -31,4 +34,10 @@ pub struct ConsumerOffsetInfo {
     pub current_offset: u64,
     /// The stored offset by the consumer in the partition.
     pub stored_offset: u64,
+    /// The optional metadata stored along with the offset.
+    #[serde(default)]
+    pub metadata: Option<String>,
+    /// The timestamp of the last commit of the offset.
+    #[serde(default = "IggyTimestamp::zero")]
+    pub committed_at: IggyTimestamp,
 }
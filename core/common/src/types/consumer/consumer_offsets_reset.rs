@@ -0,0 +1,90 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::ConsumerPartitionOffset;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `ConsumerOffsetsResetTarget` defines where the offsets of the consumer group are moved to.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Clone)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum ConsumerOffsetsResetTarget {
+    /// The group consumes each partition from its first available message.
+    #[default]
+    Earliest,
+    /// The group consumes only the messages appended after the reset.
+    Latest,
+    /// The group consumes each partition from the first message with the timestamp (in microseconds)
+    /// greater than or equal to the given one.
+    Timestamp(u64),
+    /// The stored offsets are shifted by the given number of messages, either forward or backward,
+    /// and clamped to the range of the available messages.
+    Shift(i64),
+    /// The explicit offsets are stored for the given partitions, the other partitions are left intact.
+    Offsets(Vec<ConsumerPartitionOffset>),
+}
+
+impl ConsumerOffsetsResetTarget {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            ConsumerOffsetsResetTarget::Earliest => 1,
+            ConsumerOffsetsResetTarget::Latest => 2,
+            ConsumerOffsetsResetTarget::Timestamp(_) => 3,
+            ConsumerOffsetsResetTarget::Shift(_) => 4,
+            ConsumerOffsetsResetTarget::Offsets(_) => 5,
+        }
+    }
+}
+
+impl Display for ConsumerOffsetsResetTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConsumerOffsetsResetTarget::Earliest => write!(f, "earliest"),
+            ConsumerOffsetsResetTarget::Latest => write!(f, "latest"),
+            ConsumerOffsetsResetTarget::Timestamp(timestamp) => write!(f, "timestamp:{timestamp}"),
+            ConsumerOffsetsResetTarget::Shift(shift) => write!(f, "shift:{shift}"),
+            ConsumerOffsetsResetTarget::Offsets(offsets) => {
+                let offsets = offsets
+                    .iter()
+                    .map(|offset| offset.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",");
+                write!(f, "offsets:{offsets}")
+            }
+        }
+    }
+}
+
+/// `ConsumerGroupOffsetReset` represents the outcome of the offset reset for a single partition.
+/// It consists of the following fields:
+/// - `partition_id`: the unique identifier of the partition.
+/// - `current_offset`: the current offset of the partition.
+/// - `old_offset`: the offset stored by the consumer group before the reset, if any.
+/// - `new_offset`: the offset stored by the consumer group after the reset,
+///   none means that the group consumes the partition from the beginning.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct ConsumerGroupOffsetReset {
+    /// The unique identifier of the partition.
+    pub partition_id: u32,
+    /// The current offset of the partition.
+    pub current_offset: u64,
+    /// The offset stored by the consumer group before the reset, if any.
+    pub old_offset: Option<u64>,
+    /// The offset stored by the consumer group after the reset, if any.
+    pub new_offset: Option<u64>,
+}
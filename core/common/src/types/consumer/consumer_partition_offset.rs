@@ -0,0 +1,164 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::error::IggyError;
+use crate::store_consumer_offset::MAX_CONSUMER_OFFSET_METADATA_LENGTH;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `ConsumerPartitionOffset` represents the offset committed by the consumer for a single partition.
+/// It consists of the following fields:
+/// - `partition_id`: the unique identifier of the partition.
+/// - `offset`: the offset to store.
+/// - `metadata`: the optional opaque metadata stored along with the offset, e.g. a processing checkpoint.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Clone)]
+pub struct ConsumerPartitionOffset {
+    /// The unique identifier of the partition.
+    pub partition_id: u32,
+    /// The offset to store.
+    pub offset: u64,
+    /// The optional opaque metadata stored along with the offset.
+    #[serde(default)]
+    pub metadata: Option<String>,
+}
+
+impl ConsumerPartitionOffset {
+    pub fn new(partition_id: u32, offset: u64, metadata: Option<String>) -> Self {
+        ConsumerPartitionOffset {
+            partition_id,
+            offset,
+            metadata,
+        }
+    }
+
+    /// Returns the size of the serialized offset in bytes.
+    pub fn get_size_bytes(&self) -> usize {
+        4 + 8 + metadata_size(self.metadata.as_deref())
+    }
+
+    /// Reads the offset starting at the given position and returns it along with the number of read bytes.
+    pub(crate) fn read(bytes: &Bytes, position: usize) -> Result<(Self, usize), IggyError> {
+        if bytes.len() < position + 14 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let partition_id = u32::from_le_bytes(
+            bytes[position..position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let offset = u64::from_le_bytes(
+            bytes[position + 4..position + 12]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let (metadata, metadata_size) = read_metadata(bytes, position + 12)?;
+        Ok((
+            ConsumerPartitionOffset {
+                partition_id,
+                offset,
+                metadata,
+            },
+            12 + metadata_size,
+        ))
+    }
+}
+
+impl BytesSerializable for ConsumerPartitionOffset {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(self.get_size_bytes());
+        bytes.put_u32_le(self.partition_id);
+        bytes.put_u64_le(self.offset);
+        write_metadata(self.metadata.as_deref(), &mut bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        let (offset, size) = Self::read(&bytes, 0)?;
+        if size != bytes.len() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(offset)
+    }
+}
+
+impl Display for ConsumerPartitionOffset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}",
+            self.partition_id,
+            self.offset,
+            self.metadata.as_deref().unwrap_or_default()
+        )
+    }
+}
+
+/// Validates the length of the optional offset metadata.
+pub(crate) fn validate_metadata(metadata: Option<&str>) -> Result<(), IggyError> {
+    if metadata.is_some_and(|metadata| metadata.len() > MAX_CONSUMER_OFFSET_METADATA_LENGTH) {
+        return Err(IggyError::InvalidConsumerOffsetMetadata);
+    }
+
+    Ok(())
+}
+
+/// The metadata is serialized as its length (u16) followed by the UTF-8 bytes, the empty one means no metadata.
+pub(crate) fn metadata_size(metadata: Option<&str>) -> usize {
+    2 + metadata.map_or(0, |metadata| metadata.len())
+}
+
+pub(crate) fn write_metadata(metadata: Option<&str>, bytes: &mut BytesMut) {
+    let metadata = metadata.unwrap_or_default();
+    bytes.put_u16_le(metadata.len() as u16);
+    bytes.put_slice(metadata.as_bytes());
+}
+
+pub(crate) fn read_metadata(
+    bytes: &Bytes,
+    position: usize,
+) -> Result<(Option<String>, usize), IggyError> {
+    if bytes.len() < position + 2 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let length = u16::from_le_bytes(
+        bytes[position..position + 2]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    ) as usize;
+    if length == 0 {
+        return Ok((None, 2));
+    }
+
+    if bytes.len() < position + 2 + length {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let metadata = from_utf8(&bytes[position + 2..position + 2 + length])
+        .map_err(|_| IggyError::InvalidUtf8)?
+        .to_string();
+    Ok((Some(metadata), 2 + length))
+}
@@ -26,8 +26,11 @@ use serde_with::{DisplayFromStr, serde_as};
 use std::fmt::Display;
 
 pub(crate) mod consumer_group;
+pub(crate) mod consumer_group_assignment_strategy;
 pub(crate) mod consumer_kind;
 pub(crate) mod consumer_offset_info;
+pub(crate) mod consumer_offsets_reset;
+pub(crate) mod consumer_partition_offset;
 
 /// `Consumer` represents the type of consumer that is consuming a message.
 /// It can be either a `Consumer` or a `ConsumerGroup`.
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use super::message_filter::find_header;
 use super::message_header::{IGGY_MESSAGE_HEADER_SIZE, IggyMessageHeader};
 use super::user_headers::get_user_headers_size;
 use crate::BytesSerializable;
@@ -23,7 +24,7 @@ use crate::Sizeable;
 use crate::error::IggyError;
 use crate::utils::byte_size::IggyByteSize;
 use crate::utils::timestamp::IggyTimestamp;
-use crate::{HeaderKey, HeaderValue};
+use crate::{HeaderKey, HeaderKind, HeaderValue};
 use bon::bon;
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -40,7 +41,7 @@ use tracing::warn;
 ///
 /// # Constraints
 ///
-/// * Minimum payload size: 1 byte (empty payloads are not allowed)
+/// * Minimum payload size: 1 byte (empty payloads are only allowed for tombstones)
 /// * Maximum payload size: 10 MB
 ///
 pub const MAX_PAYLOAD_SIZE: u32 = 10 * 1000 * 1000;
@@ -59,6 +60,18 @@ pub const MAX_PAYLOAD_SIZE: u32 = 10 * 1000 * 1000;
 ///
 pub const MAX_USER_HEADERS_SIZE: u32 = 100 * 1000;
 
+/// Reserved user header holding the timestamp (`uint64` microseconds) of the scheduled delivery.
+///
+/// The message with this header is not returned to the consumers polling the next messages before
+/// the specified time, while the subsequent messages which are already due are delivered as usual.
+pub const DELIVER_AT_HEADER: &str = "iggy-deliver-at";
+
+/// Reserved user header holding the ID (`uint32`) of the schema the payload conforms to.
+///
+/// The schema must be registered for the topic the message is sent to, and unless disabled
+/// on the server, the payload is validated against it before the message is appended.
+pub const SCHEMA_ID_HEADER: &str = "iggy-schema-id";
+
 /// A message stored in the Iggy messaging system.
 ///
 /// `IggyMessage` represents a single message that can be sent to or received from
@@ -105,7 +118,7 @@ pub const MAX_USER_HEADERS_SIZE: u32 = 100 * 1000;
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct IggyMessage {
     /// Message metadata
     pub header: IggyMessageHeader,
@@ -126,6 +139,8 @@ impl IggyMessage {
     /// * `id` - Optional message ID (defaults to 0 if None)
     /// * `payload` - The message content
     /// * `user_headers` - Optional user-defined headers
+    /// * `deliver_at` - Optional time of the scheduled delivery, stored in the `DELIVER_AT_HEADER` user header
+    /// * `schema_id` - Optional ID of the schema the payload conforms to, stored in the `SCHEMA_ID_HEADER` user header
     ///
     /// # Returns
     ///
@@ -161,12 +176,31 @@ impl IggyMessage {
     ///     .user_headers(user_headers)
     ///     .build()
     ///     .unwrap();
+    ///
+    /// // Message delivered to the consumers not earlier than in 1 minute
+    /// let deliver_at = IggyTimestamp::from(IggyTimestamp::now().as_micros() + 60_000_000);
+    /// let msg = IggyMessage::builder()
+    ///     .payload("Hello".into())
+    ///     .deliver_at(deliver_at)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(msg.deliver_at(), Some(deliver_at));
+    ///
+    /// // Message validated against the schema with ID 1 registered for the topic
+    /// let msg = IggyMessage::builder()
+    ///     .payload(r#"{"id": 1}"#.into())
+    ///     .schema_id(1)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(msg.schema_id().unwrap(), Some(1));
     /// ```
     #[builder]
     pub fn new(
         id: Option<u128>,
         payload: Bytes,
         user_headers: Option<HashMap<HeaderKey, HeaderValue>>,
+        deliver_at: Option<IggyTimestamp>,
+        schema_id: Option<u32>,
     ) -> Result<Self, IggyError> {
         if payload.is_empty() {
             return Err(IggyError::InvalidMessagePayloadLength);
@@ -176,6 +210,27 @@ impl IggyMessage {
             return Err(IggyError::TooBigMessagePayload);
         }
 
+        let mut reserved_headers = Vec::new();
+        if let Some(deliver_at) = deliver_at {
+            reserved_headers.push((
+                HeaderKey::new(DELIVER_AT_HEADER)?,
+                HeaderValue::from_uint64(deliver_at.as_micros())?,
+            ));
+        }
+        if let Some(schema_id) = schema_id {
+            reserved_headers.push((
+                HeaderKey::new(SCHEMA_ID_HEADER)?,
+                HeaderValue::from_uint32(schema_id)?,
+            ));
+        }
+        let user_headers = if reserved_headers.is_empty() {
+            user_headers
+        } else {
+            let mut user_headers = user_headers.unwrap_or_default();
+            user_headers.extend(reserved_headers);
+            Some(user_headers)
+        };
+
         let user_headers_length = get_user_headers_size(&user_headers).unwrap_or(0);
 
         if user_headers_length > MAX_USER_HEADERS_SIZE {
@@ -203,6 +258,51 @@ impl IggyMessage {
 }
 
 impl IggyMessage {
+    /// Creates a tombstone message with the given ID and an empty payload.
+    ///
+    /// In the topics with the `compact` cleanup policy, the tombstone sent with the `messages_key`
+    /// partitioning marks all the previous messages with the same key for removal, and is eventually
+    /// removed by the compactor as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iggy_common::*;
+    ///
+    /// let tombstone = IggyMessage::tombstone(42);
+    /// assert!(tombstone.is_tombstone());
+    /// ```
+    pub fn tombstone(id: u128) -> Self {
+        Self {
+            header: IggyMessageHeader {
+                checksum: 0,
+                id,
+                offset: 0,
+                timestamp: 0,
+                origin_timestamp: IggyTimestamp::now().as_micros(),
+                user_headers_length: 0,
+                payload_length: 0,
+            },
+            payload: Bytes::new(),
+            user_headers: None,
+        }
+    }
+
+    /// Returns `true` if the message has an empty payload, thus it's a tombstone.
+    pub fn is_tombstone(&self) -> bool {
+        self.payload.is_empty()
+    }
+
+    /// Returns the time of the scheduled delivery set in the `DELIVER_AT_HEADER` user header, if any.
+    pub fn deliver_at(&self) -> Option<IggyTimestamp> {
+        read_deliver_at(self.user_headers.as_deref())
+    }
+
+    /// Returns the ID of the schema set in the `SCHEMA_ID_HEADER` user header, if any.
+    pub fn schema_id(&self) -> Result<Option<u32>, IggyError> {
+        read_schema_id(self.user_headers.as_deref())
+    }
+
     /// Gets the user headers as a typed HashMap.
     ///
     /// This method parses the binary header data into a typed HashMap for easy access.
@@ -638,6 +738,28 @@ impl<'de> Deserialize<'de> for IggyMessage {
     }
 }
 
+/// Reads the time of the scheduled delivery from the serialized user headers.
+pub(crate) fn read_deliver_at(user_headers: Option<&[u8]>) -> Option<IggyTimestamp> {
+    let key = HeaderKey::new(DELIVER_AT_HEADER).ok()?;
+    match find_header(user_headers, &key)? {
+        (HeaderKind::Uint64, value) => Some(u64::from_le_bytes(value.try_into().ok()?).into()),
+        _ => None,
+    }
+}
+
+/// Reads the ID of the schema from the serialized user headers, the header must hold the `uint32` value.
+pub(crate) fn read_schema_id(user_headers: Option<&[u8]>) -> Result<Option<u32>, IggyError> {
+    let key = HeaderKey::new(SCHEMA_ID_HEADER)?;
+    match find_header(user_headers, &key) {
+        Some((HeaderKind::Uint32, value)) => value
+            .try_into()
+            .map(|value| Some(u32::from_le_bytes(value)))
+            .map_err(|_| IggyError::InvalidSchemaIdHeader),
+        Some(_) => Err(IggyError::InvalidSchemaIdHeader),
+        None => Ok(None),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -685,12 +807,70 @@ mod tests {
         assert!(headers_map.contains_key(&HeaderKey::new("content-type").unwrap()));
     }
 
+    #[test]
+    fn test_create_with_deliver_at() {
+        let deliver_at = IggyTimestamp::from(1_000_000);
+        let message = IggyMessage::builder()
+            .payload(Bytes::from("test with deliver at"))
+            .user_headers(HashMap::from([(
+                HeaderKey::new("content-type").unwrap(),
+                HeaderValue::from_str("text/plain").unwrap(),
+            )]))
+            .deliver_at(deliver_at)
+            .build()
+            .expect("String conversion should not fail");
+        assert_eq!(message.deliver_at(), Some(deliver_at));
+        assert_eq!(message.user_headers_map().unwrap().unwrap().len(), 2);
+
+        let restored = IggyMessage::from_bytes(message.to_bytes()).unwrap();
+        assert_eq!(restored.deliver_at(), Some(deliver_at));
+    }
+
+    #[test]
+    fn test_create_with_schema_id() {
+        let message = IggyMessage::builder()
+            .payload(Bytes::from(r#"{"id": 1}"#))
+            .deliver_at(IggyTimestamp::from(1_000_000))
+            .schema_id(7)
+            .build()
+            .expect("String conversion should not fail");
+        assert_eq!(message.schema_id(), Ok(Some(7)));
+        assert_eq!(message.user_headers_map().unwrap().unwrap().len(), 2);
+
+        let restored = IggyMessage::from_bytes(message.to_bytes()).unwrap();
+        assert_eq!(restored.schema_id(), Ok(Some(7)));
+    }
+
+    #[test]
+    fn test_schema_id_header_of_invalid_kind() {
+        let message = IggyMessage::builder()
+            .payload(Bytes::from("test"))
+            .user_headers(HashMap::from([(
+                HeaderKey::new(SCHEMA_ID_HEADER).unwrap(),
+                HeaderValue::from_str("7").unwrap(),
+            )]))
+            .build()
+            .expect("String conversion should not fail");
+        assert_eq!(message.schema_id(), Err(IggyError::InvalidSchemaIdHeader));
+    }
+
     #[test]
     fn test_empty_payload() {
         let message = IggyMessage::builder().payload(Bytes::new()).build();
         assert_eq!(message, Err(IggyError::InvalidMessagePayloadLength));
     }
 
+    #[test]
+    fn test_tombstone() {
+        let message = IggyMessage::tombstone(42);
+        assert!(message.is_tombstone());
+        assert_eq!(message.header.id, 42);
+
+        let restored = IggyMessage::from_bytes(message.to_bytes()).unwrap();
+        assert!(restored.is_tombstone());
+        assert_eq!(restored.header.id, 42);
+    }
+
     #[test]
     fn test_from_string() {
         let message: IggyMessage = "simple message".into();
@@ -0,0 +1,665 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use crate::{BytesSerializable, HeaderKey, HeaderKind, HeaderValue, Validatable};
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The maximum depth of the nested `and`, `or` and `not` expressions.
+pub const MAX_MESSAGE_FILTER_DEPTH: usize = 8;
+/// The maximum number of predicates (leaves) in a single filter expression.
+pub const MAX_MESSAGE_FILTER_PREDICATES: usize = 64;
+
+const EQUALS_CODE: u8 = 1;
+const IN_CODE: u8 = 2;
+const RANGE_CODE: u8 = 3;
+const EXISTS_CODE: u8 = 4;
+const AND_CODE: u8 = 10;
+const OR_CODE: u8 = 11;
+const NOT_CODE: u8 = 12;
+
+const RANGE_FROM_FLAG: u8 = 1;
+const RANGE_TO_FLAG: u8 = 2;
+
+/// `MessageFilter` is an expression over the user headers evaluated by the server when polling messages.
+/// Only the messages matching the expression are returned to the consumer.
+/// It has the following kinds:
+/// - `Equals` - the header exists and its value is equal to the specified one.
+/// - `In` - the header exists and its value is equal to one of the specified values.
+/// - `Range` - the header exists and its value is within the inclusive range (both bounds are optional).
+/// - `Exists` - the header exists, regardless of its value.
+/// - `And` - all the nested expressions match.
+/// - `Or` - at least one of the nested expressions matches.
+/// - `Not` - the nested expression does not match.
+///
+/// The values are compared only when their kinds are the same, e.g. `uint32` header never matches `uint64` value.
+/// Numeric kinds are compared numerically, while `string` and `raw` kinds are compared lexicographically.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum MessageFilter {
+    /// The header exists and its value is equal to the specified one.
+    Equals { key: HeaderKey, value: HeaderValue },
+    /// The header exists and its value is equal to one of the specified values.
+    In {
+        key: HeaderKey,
+        values: Vec<HeaderValue>,
+    },
+    /// The header exists and its value is within the inclusive range.
+    Range {
+        key: HeaderKey,
+        #[serde(default)]
+        from: Option<HeaderValue>,
+        #[serde(default)]
+        to: Option<HeaderValue>,
+    },
+    /// The header exists, regardless of its value.
+    Exists { key: HeaderKey },
+    /// All the nested expressions match.
+    And { filters: Vec<MessageFilter> },
+    /// At least one of the nested expressions matches.
+    Or { filters: Vec<MessageFilter> },
+    /// The nested expression does not match.
+    Not { filter: Box<MessageFilter> },
+}
+
+impl MessageFilter {
+    /// Matches the messages having the header with the specified value.
+    pub fn equals(key: HeaderKey, value: HeaderValue) -> Self {
+        MessageFilter::Equals { key, value }
+    }
+
+    /// Matches the messages having the header with one of the specified values.
+    pub fn any_of(key: HeaderKey, values: Vec<HeaderValue>) -> Self {
+        MessageFilter::In { key, values }
+    }
+
+    /// Matches the messages having the header with the value within the inclusive range.
+    pub fn range(key: HeaderKey, from: Option<HeaderValue>, to: Option<HeaderValue>) -> Self {
+        MessageFilter::Range { key, from, to }
+    }
+
+    /// Matches the messages having the specified header.
+    pub fn exists(key: HeaderKey) -> Self {
+        MessageFilter::Exists { key }
+    }
+
+    /// Matches the messages for which all the specified expressions match.
+    pub fn and(filters: Vec<MessageFilter>) -> Self {
+        MessageFilter::And { filters }
+    }
+
+    /// Matches the messages for which at least one of the specified expressions matches.
+    pub fn or(filters: Vec<MessageFilter>) -> Self {
+        MessageFilter::Or { filters }
+    }
+
+    /// Matches the messages for which the specified expression does not match.
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(filter: MessageFilter) -> Self {
+        MessageFilter::Not {
+            filter: Box::new(filter),
+        }
+    }
+
+    /// Evaluates the expression against the serialized user headers of the message.
+    /// The headers are scanned in place, without allocating the headers map.
+    pub fn matches(&self, user_headers: Option<&[u8]>) -> bool {
+        match self {
+            MessageFilter::Equals { key, value } => {
+                find_header(user_headers, key).is_some_and(|(kind, raw)| {
+                    compare(value.kind, &value.value, kind, raw) == Some(Ordering::Equal)
+                })
+            }
+            MessageFilter::In { key, values } => {
+                find_header(user_headers, key).is_some_and(|(kind, raw)| {
+                    values.iter().any(|value| {
+                        compare(value.kind, &value.value, kind, raw) == Some(Ordering::Equal)
+                    })
+                })
+            }
+            MessageFilter::Range { key, from, to } => {
+                find_header(user_headers, key).is_some_and(|(kind, raw)| {
+                    let above_from = from.as_ref().is_none_or(|from| {
+                        matches!(
+                            compare(kind, raw, from.kind, &from.value),
+                            Some(Ordering::Greater | Ordering::Equal)
+                        )
+                    });
+                    let below_to = to.as_ref().is_none_or(|to| {
+                        matches!(
+                            compare(kind, raw, to.kind, &to.value),
+                            Some(Ordering::Less | Ordering::Equal)
+                        )
+                    });
+                    above_from && below_to
+                })
+            }
+            MessageFilter::Exists { key } => find_header(user_headers, key).is_some(),
+            MessageFilter::And { filters } => {
+                filters.iter().all(|filter| filter.matches(user_headers))
+            }
+            MessageFilter::Or { filters } => {
+                filters.iter().any(|filter| filter.matches(user_headers))
+            }
+            MessageFilter::Not { filter } => !filter.matches(user_headers),
+        }
+    }
+
+    fn depth(&self) -> usize {
+        match self {
+            MessageFilter::And { filters } | MessageFilter::Or { filters } => {
+                1 + filters
+                    .iter()
+                    .map(|filter| filter.depth())
+                    .max()
+                    .unwrap_or(0)
+            }
+            MessageFilter::Not { filter } => 1 + filter.depth(),
+            _ => 0,
+        }
+    }
+
+    fn predicates_count(&self) -> usize {
+        match self {
+            MessageFilter::And { filters } | MessageFilter::Or { filters } => {
+                filters.iter().map(|filter| filter.predicates_count()).sum()
+            }
+            MessageFilter::Not { filter } => filter.predicates_count(),
+            MessageFilter::In { values, .. } => values.len(),
+            _ => 1,
+        }
+    }
+
+    fn write_bytes(&self, bytes: &mut BytesMut) {
+        match self {
+            MessageFilter::Equals { key, value } => {
+                bytes.put_u8(EQUALS_CODE);
+                write_key(bytes, key);
+                write_value(bytes, value);
+            }
+            MessageFilter::In { key, values } => {
+                bytes.put_u8(IN_CODE);
+                write_key(bytes, key);
+                bytes.put_u32_le(values.len() as u32);
+                for value in values {
+                    write_value(bytes, value);
+                }
+            }
+            MessageFilter::Range { key, from, to } => {
+                bytes.put_u8(RANGE_CODE);
+                write_key(bytes, key);
+                let mut flags = 0;
+                if from.is_some() {
+                    flags |= RANGE_FROM_FLAG;
+                }
+                if to.is_some() {
+                    flags |= RANGE_TO_FLAG;
+                }
+                bytes.put_u8(flags);
+                if let Some(from) = from {
+                    write_value(bytes, from);
+                }
+                if let Some(to) = to {
+                    write_value(bytes, to);
+                }
+            }
+            MessageFilter::Exists { key } => {
+                bytes.put_u8(EXISTS_CODE);
+                write_key(bytes, key);
+            }
+            MessageFilter::And { filters } | MessageFilter::Or { filters } => {
+                bytes.put_u8(if matches!(self, MessageFilter::And { .. }) {
+                    AND_CODE
+                } else {
+                    OR_CODE
+                });
+                bytes.put_u32_le(filters.len() as u32);
+                for filter in filters {
+                    filter.write_bytes(bytes);
+                }
+            }
+            MessageFilter::Not { filter } => {
+                bytes.put_u8(NOT_CODE);
+                filter.write_bytes(bytes);
+            }
+        }
+    }
+
+    /// Reads the expression from the bytes starting at the specified position and returns it along with the number of bytes read.
+    pub fn from_bytes_at(bytes: &[u8], position: usize) -> Result<(Self, usize), IggyError> {
+        let mut reader = FilterReader {
+            bytes,
+            position,
+            depth: 0,
+        };
+        let filter = reader.read_filter()?;
+        Ok((filter, reader.position - position))
+    }
+}
+
+impl Validatable<IggyError> for MessageFilter {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.depth() > MAX_MESSAGE_FILTER_DEPTH
+            || self.predicates_count() > MAX_MESSAGE_FILTER_PREDICATES
+        {
+            return Err(IggyError::InvalidMessageFilter);
+        }
+
+        match self {
+            MessageFilter::In { values, .. } if values.is_empty() => {
+                Err(IggyError::InvalidMessageFilter)
+            }
+            MessageFilter::Range { from, to, .. } => match (from, to) {
+                (None, None) => Err(IggyError::InvalidMessageFilter),
+                (Some(from), Some(to)) if from.kind != to.kind => {
+                    Err(IggyError::InvalidMessageFilter)
+                }
+                _ => Ok(()),
+            },
+            MessageFilter::And { filters } | MessageFilter::Or { filters } => {
+                if filters.is_empty() {
+                    return Err(IggyError::InvalidMessageFilter);
+                }
+                filters.iter().try_for_each(|filter| filter.validate())
+            }
+            MessageFilter::Not { filter } => filter.validate(),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl BytesSerializable for MessageFilter {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::new();
+        self.write_bytes(&mut bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        let (filter, read) = Self::from_bytes_at(&bytes, 0)?;
+        if read != bytes.len() {
+            return Err(IggyError::InvalidMessageFilter);
+        }
+        Ok(filter)
+    }
+}
+
+/// The filter is represented as JSON when passed as a query parameter (HTTP API) or a CLI argument.
+impl FromStr for MessageFilter {
+    type Err = IggyError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s).map_err(|_| IggyError::InvalidMessageFilter)
+    }
+}
+
+impl Display for MessageFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let json = serde_json::to_string(self).map_err(|_| std::fmt::Error)?;
+        write!(f, "{json}")
+    }
+}
+
+struct FilterReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    depth: usize,
+}
+
+impl FilterReader<'_> {
+    fn read_filter(&mut self) -> Result<MessageFilter, IggyError> {
+        let filter = match self.read_u8()? {
+            EQUALS_CODE => MessageFilter::Equals {
+                key: self.read_key()?,
+                value: self.read_value()?,
+            },
+            IN_CODE => {
+                let key = self.read_key()?;
+                let count = self.read_u32()? as usize;
+                if count > MAX_MESSAGE_FILTER_PREDICATES {
+                    return Err(IggyError::InvalidMessageFilter);
+                }
+                let mut values = Vec::with_capacity(count);
+                for _ in 0..count {
+                    values.push(self.read_value()?);
+                }
+                MessageFilter::In { key, values }
+            }
+            RANGE_CODE => {
+                let key = self.read_key()?;
+                let flags = self.read_u8()?;
+                let from = if flags & RANGE_FROM_FLAG != 0 {
+                    Some(self.read_value()?)
+                } else {
+                    None
+                };
+                let to = if flags & RANGE_TO_FLAG != 0 {
+                    Some(self.read_value()?)
+                } else {
+                    None
+                };
+                MessageFilter::Range { key, from, to }
+            }
+            EXISTS_CODE => MessageFilter::Exists {
+                key: self.read_key()?,
+            },
+            code @ (AND_CODE | OR_CODE) => {
+                let count = self.read_u32()? as usize;
+                if count > MAX_MESSAGE_FILTER_PREDICATES {
+                    return Err(IggyError::InvalidMessageFilter);
+                }
+                self.enter()?;
+                let mut filters = Vec::with_capacity(count);
+                for _ in 0..count {
+                    filters.push(self.read_filter()?);
+                }
+                self.depth -= 1;
+                if code == AND_CODE {
+                    MessageFilter::And { filters }
+                } else {
+                    MessageFilter::Or { filters }
+                }
+            }
+            NOT_CODE => {
+                self.enter()?;
+                let filter = self.read_filter()?;
+                self.depth -= 1;
+                MessageFilter::Not {
+                    filter: Box::new(filter),
+                }
+            }
+            _ => return Err(IggyError::InvalidMessageFilter),
+        };
+        Ok(filter)
+    }
+
+    fn enter(&mut self) -> Result<(), IggyError> {
+        self.depth += 1;
+        if self.depth > MAX_MESSAGE_FILTER_DEPTH {
+            return Err(IggyError::InvalidMessageFilter);
+        }
+        Ok(())
+    }
+
+    fn read_slice(&mut self, length: usize) -> Result<&[u8], IggyError> {
+        let end = self.position + length;
+        if end > self.bytes.len() {
+            return Err(IggyError::InvalidMessageFilter);
+        }
+        let slice = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(slice)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, IggyError> {
+        Ok(self.read_slice(1)?[0])
+    }
+
+    fn read_u32(&mut self) -> Result<u32, IggyError> {
+        let slice = self.read_slice(4)?;
+        Ok(u32::from_le_bytes(
+            slice
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ))
+    }
+
+    fn read_key(&mut self) -> Result<HeaderKey, IggyError> {
+        let length = self.read_u8()? as usize;
+        let key = std::str::from_utf8(self.read_slice(length)?)
+            .map_err(|_| IggyError::InvalidHeaderKey)?;
+        HeaderKey::new(key)
+    }
+
+    fn read_value(&mut self) -> Result<HeaderValue, IggyError> {
+        let kind = HeaderKind::from_code(self.read_u8()?)?;
+        let length = self.read_u8()? as usize;
+        let value = self.read_slice(length)?;
+        if value.is_empty() {
+            return Err(IggyError::InvalidHeaderValue);
+        }
+        Ok(HeaderValue {
+            kind,
+            value: Bytes::copy_from_slice(value),
+        })
+    }
+}
+
+fn write_key(bytes: &mut BytesMut, key: &HeaderKey) {
+    bytes.put_u8(key.as_str().len() as u8);
+    bytes.put_slice(key.as_str().as_bytes());
+}
+
+fn write_value(bytes: &mut BytesMut, value: &HeaderValue) {
+    bytes.put_u8(value.kind.as_code());
+    bytes.put_u8(value.value.len() as u8);
+    bytes.put_slice(&value.value);
+}
+
+/// Finds the header with the specified key in the serialized user headers and returns its kind and raw value.
+pub(crate) fn find_header<'a>(
+    user_headers: Option<&'a [u8]>,
+    key: &HeaderKey,
+) -> Option<(HeaderKind, &'a [u8])> {
+    let bytes = user_headers?;
+    let key = key.as_str().as_bytes();
+    let mut position = 0;
+    while position + 4 <= bytes.len() {
+        let key_length =
+            u32::from_le_bytes(bytes[position..position + 4].try_into().ok()?) as usize;
+        position += 4;
+        let current_key = bytes.get(position..position + key_length)?;
+        position += key_length;
+        let kind = *bytes.get(position)?;
+        position += 1;
+        let value_length =
+            u32::from_le_bytes(bytes.get(position..position + 4)?.try_into().ok()?) as usize;
+        position += 4;
+        let value = bytes.get(position..position + value_length)?;
+        position += value_length;
+        if current_key.eq_ignore_ascii_case(key) {
+            return HeaderKind::from_code(kind).ok().map(|kind| (kind, value));
+        }
+    }
+    None
+}
+
+/// Compares two raw header values, returns `None` if the kinds differ or the values cannot be decoded.
+fn compare(
+    left_kind: HeaderKind,
+    left: &[u8],
+    right_kind: HeaderKind,
+    right: &[u8],
+) -> Option<Ordering> {
+    if left_kind != right_kind {
+        return None;
+    }
+
+    macro_rules! compare_as {
+        ($type:ty) => {{
+            let left = <$type>::from_le_bytes(left.try_into().ok()?);
+            let right = <$type>::from_le_bytes(right.try_into().ok()?);
+            left.partial_cmp(&right)
+        }};
+    }
+
+    match left_kind {
+        HeaderKind::Raw | HeaderKind::String | HeaderKind::Bool => Some(left.cmp(right)),
+        HeaderKind::Int8 => compare_as!(i8),
+        HeaderKind::Int16 => compare_as!(i16),
+        HeaderKind::Int32 => compare_as!(i32),
+        HeaderKind::Int64 => compare_as!(i64),
+        HeaderKind::Int128 => compare_as!(i128),
+        HeaderKind::Uint8 => compare_as!(u8),
+        HeaderKind::Uint16 => compare_as!(u16),
+        HeaderKind::Uint32 => compare_as!(u32),
+        HeaderKind::Uint64 => compare_as!(u64),
+        HeaderKind::Uint128 => compare_as!(u128),
+        HeaderKind::Float32 => compare_as!(f32),
+        HeaderKind::Float64 => compare_as!(f64),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn equals_filter_should_match_only_the_same_value() {
+        let headers = headers(&[("region", HeaderValue::from_str("eu").unwrap())]);
+        let filter = MessageFilter::equals(key("region"), HeaderValue::from_str("eu").unwrap());
+        assert!(filter.matches(Some(&headers)));
+
+        let filter = MessageFilter::equals(key("region"), HeaderValue::from_str("us").unwrap());
+        assert!(!filter.matches(Some(&headers)));
+        assert!(!filter.matches(None));
+    }
+
+    #[test]
+    fn in_filter_should_match_any_of_the_values() {
+        let headers = headers(&[("tier", HeaderValue::from_uint32(2).unwrap())]);
+        let filter = MessageFilter::any_of(
+            key("tier"),
+            vec![
+                HeaderValue::from_uint32(1).unwrap(),
+                HeaderValue::from_uint32(2).unwrap(),
+            ],
+        );
+        assert!(filter.matches(Some(&headers)));
+
+        let filter = MessageFilter::any_of(key("tier"), vec![HeaderValue::from_uint32(3).unwrap()]);
+        assert!(!filter.matches(Some(&headers)));
+    }
+
+    #[test]
+    fn range_filter_should_compare_values_numerically() {
+        let headers = headers(&[("amount", HeaderValue::from_int64(-5).unwrap())]);
+        let filter = MessageFilter::range(
+            key("amount"),
+            Some(HeaderValue::from_int64(-10).unwrap()),
+            Some(HeaderValue::from_int64(0).unwrap()),
+        );
+        assert!(filter.matches(Some(&headers)));
+
+        let filter = MessageFilter::range(
+            key("amount"),
+            Some(HeaderValue::from_int64(-4).unwrap()),
+            None,
+        );
+        assert!(!filter.matches(Some(&headers)));
+    }
+
+    #[test]
+    fn range_filter_should_not_match_value_of_different_kind() {
+        let headers = headers(&[("amount", HeaderValue::from_int32(5).unwrap())]);
+        let filter = MessageFilter::range(
+            key("amount"),
+            Some(HeaderValue::from_int64(0).unwrap()),
+            None,
+        );
+        assert!(!filter.matches(Some(&headers)));
+    }
+
+    #[test]
+    fn nested_filters_should_be_evaluated() {
+        let headers = headers(&[
+            ("region", HeaderValue::from_str("eu").unwrap()),
+            ("priority", HeaderValue::from_uint8(7).unwrap()),
+        ]);
+        let filter = MessageFilter::and(vec![
+            MessageFilter::exists(key("priority")),
+            MessageFilter::not(MessageFilter::equals(
+                key("region"),
+                HeaderValue::from_str("us").unwrap(),
+            )),
+            MessageFilter::or(vec![
+                MessageFilter::exists(key("missing")),
+                MessageFilter::range(
+                    key("priority"),
+                    Some(HeaderValue::from_uint8(5).unwrap()),
+                    None,
+                ),
+            ]),
+        ]);
+        assert!(filter.matches(Some(&headers)));
+    }
+
+    #[test]
+    fn filter_should_be_serialized_and_deserialized_from_bytes() {
+        let filter = MessageFilter::and(vec![
+            MessageFilter::equals(key("region"), HeaderValue::from_str("eu").unwrap()),
+            MessageFilter::any_of(
+                key("tier"),
+                vec![
+                    HeaderValue::from_uint32(1).unwrap(),
+                    HeaderValue::from_uint32(2).unwrap(),
+                ],
+            ),
+            MessageFilter::not(MessageFilter::range(
+                key("amount"),
+                None,
+                Some(HeaderValue::from_float64(10.5).unwrap()),
+            )),
+        ]);
+
+        let bytes = filter.to_bytes();
+        let deserialized = MessageFilter::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, filter);
+    }
+
+    #[test]
+    fn filter_should_be_serialized_and_deserialized_from_json() {
+        let filter = MessageFilter::or(vec![
+            MessageFilter::exists(key("trace")),
+            MessageFilter::equals(key("region"), HeaderValue::from_str("eu").unwrap()),
+        ]);
+
+        let json = filter.to_string();
+        let deserialized = MessageFilter::from_str(&json).unwrap();
+        assert_eq!(deserialized, filter);
+    }
+
+    #[test]
+    fn too_deeply_nested_filter_should_be_invalid() {
+        let mut filter = MessageFilter::exists(key("region"));
+        for _ in 0..=MAX_MESSAGE_FILTER_DEPTH {
+            filter = MessageFilter::not(filter);
+        }
+        assert!(filter.validate().is_err());
+        assert!(MessageFilter::from_bytes(filter.to_bytes()).is_err());
+    }
+
+    fn key(key: &str) -> HeaderKey {
+        HeaderKey::new(key).unwrap()
+    }
+
+    fn headers(headers: &[(&str, HeaderValue)]) -> Vec<u8> {
+        let headers = headers
+            .iter()
+            .map(|(k, v)| (key(k), v.clone()))
+            .collect::<HashMap<_, _>>();
+        headers.to_bytes().to_vec()
+    }
+}
@@ -17,12 +17,14 @@
  */
 
 use super::HeaderValue;
+use super::iggy_message::{read_deliver_at, read_schema_id};
 use super::message_header::*;
 use crate::BytesSerializable;
 use crate::IggyByteSize;
 use crate::Sizeable;
 use crate::error::IggyError;
 use crate::utils::checksum;
+use crate::utils::timestamp::IggyTimestamp;
 use crate::{HeaderKey, IggyMessageHeaderView};
 use bytes::{Bytes, BytesMut};
 use std::{collections::HashMap, iter::Iterator};
@@ -76,6 +78,16 @@ impl<'a> IggyMessageView<'a> {
         }
     }
 
+    /// Returns the time of the scheduled delivery set in the `DELIVER_AT_HEADER` user header, if any.
+    pub fn deliver_at(&self) -> Option<IggyTimestamp> {
+        read_deliver_at(self.user_headers())
+    }
+
+    /// Returns the ID of the schema set in the `SCHEMA_ID_HEADER` user header, if any.
+    pub fn schema_id(&self) -> Result<Option<u32>, IggyError> {
+        read_schema_id(self.user_headers())
+    }
+
     /// Return instantiated user headers map
     pub fn user_headers_map(&self) -> Result<Option<HashMap<HeaderKey, HeaderValue>>, IggyError> {
         if let Some(headers) = self.user_headers() {
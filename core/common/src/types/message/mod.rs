@@ -20,6 +20,8 @@ mod iggy_message;
 mod index;
 mod index_view;
 mod indexes;
+pub mod isolation_level;
+mod message_filter;
 mod message_header;
 mod message_header_view;
 mod message_view;
@@ -36,10 +38,14 @@ pub const INDEX_SIZE: usize = 16;
 pub use crate::commands::messages::flush_unsaved_buffer::FlushUnsavedBuffer;
 pub use crate::commands::messages::poll_messages::PollMessages;
 pub use crate::commands::messages::send_messages::SendMessages;
-pub use iggy_message::{IggyMessage, MAX_PAYLOAD_SIZE, MAX_USER_HEADERS_SIZE};
+pub use iggy_message::{
+    DELIVER_AT_HEADER, IggyMessage, MAX_PAYLOAD_SIZE, MAX_USER_HEADERS_SIZE, SCHEMA_ID_HEADER,
+};
 pub use index::IggyIndex;
 pub use index_view::IggyIndexView;
 pub use indexes::IggyIndexes;
+pub use isolation_level::IsolationLevel;
+pub use message_filter::{MAX_MESSAGE_FILTER_DEPTH, MAX_MESSAGE_FILTER_PREDICATES, MessageFilter};
 pub use message_header::{
     IGGY_MESSAGE_CHECKSUM_OFFSET_RANGE, IGGY_MESSAGE_HEADER_RANGE, IGGY_MESSAGE_HEADER_SIZE,
     IGGY_MESSAGE_HEADERS_LENGTH_OFFSET_RANGE, IGGY_MESSAGE_ID_OFFSET_RANGE,
@@ -29,6 +29,7 @@ use tracing::error;
 /// - `current_offset`: the current offset of the partition.
 /// - `count`: the count of messages.
 /// - `messages`: the collection of messages.
+/// - `next_offset`: the offset from which the next poll should start, available only for the filtered polls.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PolledMessages {
     /// The identifier of the partition. If it's '0', then there's no partition assigned to the consumer group member.
@@ -39,6 +40,10 @@ pub struct PolledMessages {
     pub count: u32,
     /// The collection of messages.
     pub messages: Vec<IggyMessage>,
+    /// The offset from which the next poll should start, available only for the filtered polls.
+    /// Messages not matching the filter are skipped, so it might be greater than the offset of the last returned message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<u64>,
 }
 
 impl PolledMessages {
@@ -48,43 +53,65 @@ impl PolledMessages {
             current_offset: 0,
             count: 0,
             messages: Vec::new(),
+            next_offset: None,
         }
     }
-}
 
-impl BytesSerializable for PolledMessages {
-    fn to_bytes(&self) -> Bytes {
-        panic!("should not be used")
+    /// Deserializes the response of the filtered poll, which carries the next offset right after the messages count.
+    pub fn from_filtered_bytes(bytes: Bytes) -> Result<Self, IggyError> {
+        if bytes.len() < 24 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let next_offset = u64::from_le_bytes(
+            bytes[16..24]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let mut polled_messages = Self::from_header_and_messages(&bytes, bytes.slice(24..))?;
+        polled_messages.next_offset = Some(next_offset);
+        Ok(polled_messages)
     }
 
-    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError> {
+    fn from_header_and_messages(header: &[u8], messages: Bytes) -> Result<Self, IggyError> {
         let partition_id = u32::from_le_bytes(
-            bytes[0..4]
+            header[0..4]
                 .try_into()
                 .map_err(|_| IggyError::InvalidNumberEncoding)?,
         );
         let current_offset = u64::from_le_bytes(
-            bytes[4..12]
+            header[4..12]
                 .try_into()
                 .map_err(|_| IggyError::InvalidNumberEncoding)?,
         );
         let count = u32::from_le_bytes(
-            bytes[12..16]
+            header[12..16]
                 .try_into()
                 .map_err(|_| IggyError::InvalidNumberEncoding)?,
         );
 
-        let messages = messages_from_bytes_and_count(bytes.slice(16..), count)?;
+        let messages = messages_from_bytes_and_count(messages, count)?;
 
         Ok(Self {
             partition_id,
             current_offset,
             count,
             messages,
+            next_offset: None,
         })
     }
 }
 
+impl BytesSerializable for PolledMessages {
+    fn to_bytes(&self) -> Bytes {
+        panic!("should not be used")
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError> {
+        Self::from_header_and_messages(&bytes, bytes.slice(16..))
+    }
+}
+
 /// Convert Bytes to messages
 fn messages_from_bytes_and_count(buffer: Bytes, count: u32) -> Result<Vec<IggyMessage>, IggyError> {
     let mut messages = Vec::with_capacity(count as usize);
@@ -0,0 +1,613 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::defaults::DEFAULT_ROOT_USER_ID;
+use crate::error::IggyError;
+use crate::{
+    ConsumerGroupMetadata, Permissions, Role, ServerMetadata, TopicMetadata, UserMetadata,
+    UserQuotas, UserStatus,
+};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+/// `MetadataOperation` is a single change required to reconcile the server with the desired metadata.
+/// The streams, topics, consumer groups, users and roles are matched by their names,
+/// while their IDs are only used to create them.
+#[derive(Debug, PartialEq, Clone)]
+pub enum MetadataOperation {
+    CreateRole(Role),
+    UpdateRole(Role),
+    DeleteRole {
+        name: String,
+    },
+    CreateStream {
+        id: u32,
+        name: String,
+    },
+    DeleteStream {
+        name: String,
+    },
+    CreateTopic {
+        stream: String,
+        topic: TopicMetadata,
+    },
+    UpdateTopic {
+        stream: String,
+        topic: TopicMetadata,
+    },
+    DeleteTopic {
+        stream: String,
+        name: String,
+    },
+    CreatePartitions {
+        stream: String,
+        topic: String,
+        count: u32,
+    },
+    DeletePartitions {
+        stream: String,
+        topic: String,
+        count: u32,
+    },
+    CreateConsumerGroup {
+        stream: String,
+        topic: String,
+        group: ConsumerGroupMetadata,
+    },
+    DeleteConsumerGroup {
+        stream: String,
+        topic: String,
+        name: String,
+    },
+    CreateUser(UserMetadata),
+    UpdateUser {
+        username: String,
+        status: UserStatus,
+        quotas: Option<UserQuotas>,
+    },
+    UpdatePermissions {
+        username: String,
+        permissions: Option<Permissions>,
+    },
+    AssignRoles {
+        username: String,
+        roles: Vec<String>,
+    },
+    DeleteUser {
+        username: String,
+    },
+}
+
+impl ServerMetadata {
+    /// Returns the operations required to reconcile the server described by this metadata with the desired one.
+    /// The resources missing from the desired metadata and the partitions above its count are deleted only if
+    /// `prune` is set, otherwise the desired metadata is merged into the current one.
+    /// The deletions come first, so the IDs of the deleted resources can be reused by the created ones.
+    /// The root user and the personal access tokens are never changed,
+    /// and the users can be created only if their password is provided.
+    pub fn plan(
+        &self,
+        desired: &ServerMetadata,
+        prune: bool,
+    ) -> Result<Vec<MetadataOperation>, IggyError> {
+        let mut deletions = Vec::new();
+        let mut changes = Vec::new();
+
+        for role in &desired.roles {
+            match self.roles.iter().find(|current| current.name == role.name) {
+                None => changes.push(MetadataOperation::CreateRole(role.clone())),
+                Some(current) if current.rules != role.rules => {
+                    changes.push(MetadataOperation::UpdateRole(role.clone()))
+                }
+                Some(_) => {}
+            }
+        }
+
+        for stream in &desired.streams {
+            let Some(current) = self
+                .streams
+                .iter()
+                .find(|current| current.name == stream.name)
+            else {
+                changes.push(MetadataOperation::CreateStream {
+                    id: stream.id,
+                    name: stream.name.clone(),
+                });
+                for topic in &stream.topics {
+                    plan_topic_creation(&stream.name, topic, &mut changes);
+                }
+                continue;
+            };
+
+            for topic in &stream.topics {
+                let Some(current_topic) = current
+                    .topics
+                    .iter()
+                    .find(|current_topic| current_topic.name == topic.name)
+                else {
+                    plan_topic_creation(&stream.name, topic, &mut changes);
+                    continue;
+                };
+
+                if !has_same_config(topic, current_topic) {
+                    changes.push(MetadataOperation::UpdateTopic {
+                        stream: stream.name.clone(),
+                        topic: topic.clone(),
+                    });
+                }
+
+                match topic.partitions_count.cmp(&current_topic.partitions_count) {
+                    Ordering::Greater => changes.push(MetadataOperation::CreatePartitions {
+                        stream: stream.name.clone(),
+                        topic: topic.name.clone(),
+                        count: topic.partitions_count - current_topic.partitions_count,
+                    }),
+                    Ordering::Less if prune => changes.push(MetadataOperation::DeletePartitions {
+                        stream: stream.name.clone(),
+                        topic: topic.name.clone(),
+                        count: current_topic.partitions_count - topic.partitions_count,
+                    }),
+                    Ordering::Less | Ordering::Equal => {}
+                }
+
+                for group in &topic.consumer_groups {
+                    let current_group = current_topic
+                        .consumer_groups
+                        .iter()
+                        .find(|current_group| current_group.name == group.name);
+                    if let Some(current_group) = current_group {
+                        if current_group.assignment_strategy == group.assignment_strategy {
+                            continue;
+                        }
+
+                        // The assignment strategy can't be updated, so the group has to be recreated.
+                        deletions.push(MetadataOperation::DeleteConsumerGroup {
+                            stream: stream.name.clone(),
+                            topic: topic.name.clone(),
+                            name: group.name.clone(),
+                        });
+                    }
+
+                    changes.push(MetadataOperation::CreateConsumerGroup {
+                        stream: stream.name.clone(),
+                        topic: topic.name.clone(),
+                        group: group.clone(),
+                    });
+                }
+
+                for current_group in &current_topic.consumer_groups {
+                    if prune
+                        && !topic
+                            .consumer_groups
+                            .iter()
+                            .any(|group| group.name == current_group.name)
+                    {
+                        deletions.push(MetadataOperation::DeleteConsumerGroup {
+                            stream: stream.name.clone(),
+                            topic: topic.name.clone(),
+                            name: current_group.name.clone(),
+                        });
+                    }
+                }
+            }
+
+            for current_topic in &current.topics {
+                if prune
+                    && !stream
+                        .topics
+                        .iter()
+                        .any(|topic| topic.name == current_topic.name)
+                {
+                    deletions.push(MetadataOperation::DeleteTopic {
+                        stream: stream.name.clone(),
+                        name: current_topic.name.clone(),
+                    });
+                }
+            }
+        }
+
+        for current in &self.streams {
+            if prune
+                && !desired
+                    .streams
+                    .iter()
+                    .any(|stream| stream.name == current.name)
+            {
+                deletions.push(MetadataOperation::DeleteStream {
+                    name: current.name.clone(),
+                });
+            }
+        }
+
+        let desired_users = desired
+            .users
+            .iter()
+            .filter(|user| user.id != DEFAULT_ROOT_USER_ID);
+        let current_users = self
+            .users
+            .iter()
+            .filter(|user| user.id != DEFAULT_ROOT_USER_ID)
+            .collect::<Vec<_>>();
+        for user in desired_users {
+            let Some(current) = current_users
+                .iter()
+                .find(|current| current.username == user.username)
+            else {
+                if user.password.is_none() && user.password_hash.is_none() {
+                    return Err(IggyError::MissingUserPassword(user.username.clone()));
+                }
+
+                changes.push(MetadataOperation::CreateUser(user.clone()));
+                if !user.roles.is_empty() {
+                    changes.push(MetadataOperation::AssignRoles {
+                        username: user.username.clone(),
+                        roles: user.roles.clone(),
+                    });
+                }
+                continue;
+            };
+
+            let quotas = user.quotas.filter(|quotas| !quotas.is_unlimited());
+            let current_quotas = current.quotas.filter(|quotas| !quotas.is_unlimited());
+            if user.status != current.status || quotas != current_quotas {
+                changes.push(MetadataOperation::UpdateUser {
+                    username: user.username.clone(),
+                    status: user.status,
+                    quotas,
+                });
+            }
+
+            if user.permissions != current.permissions {
+                changes.push(MetadataOperation::UpdatePermissions {
+                    username: user.username.clone(),
+                    permissions: user.permissions.clone(),
+                });
+            }
+
+            let mut roles = user.roles.clone();
+            let mut current_roles = current.roles.clone();
+            roles.sort();
+            current_roles.sort();
+            if roles != current_roles {
+                changes.push(MetadataOperation::AssignRoles {
+                    username: user.username.clone(),
+                    roles: user.roles.clone(),
+                });
+            }
+        }
+
+        for current in current_users {
+            if prune
+                && !desired
+                    .users
+                    .iter()
+                    .any(|user| user.username == current.username)
+            {
+                deletions.push(MetadataOperation::DeleteUser {
+                    username: current.username.clone(),
+                });
+            }
+        }
+
+        for current in &self.roles {
+            if prune && !desired.roles.iter().any(|role| role.name == current.name) {
+                deletions.push(MetadataOperation::DeleteRole {
+                    name: current.name.clone(),
+                });
+            }
+        }
+
+        deletions.extend(changes);
+        Ok(deletions)
+    }
+}
+
+fn plan_topic_creation(stream: &str, topic: &TopicMetadata, changes: &mut Vec<MetadataOperation>) {
+    changes.push(MetadataOperation::CreateTopic {
+        stream: stream.to_string(),
+        topic: topic.clone(),
+    });
+    for group in &topic.consumer_groups {
+        changes.push(MetadataOperation::CreateConsumerGroup {
+            stream: stream.to_string(),
+            topic: topic.name.clone(),
+            group: group.clone(),
+        });
+    }
+}
+
+fn has_same_config(topic: &TopicMetadata, current: &TopicMetadata) -> bool {
+    topic.compression_algorithm == current.compression_algorithm
+        && topic.message_expiry == current.message_expiry
+        && topic.max_topic_size == current.max_topic_size
+        && topic.replication_factor == current.replication_factor
+        && topic.cleanup_policy == current.cleanup_policy
+        && topic.partitioning_hash == current.partitioning_hash
+        && topic.segment_max_age == current.segment_max_age
+        && topic.max_partition_size == current.max_partition_size
+}
+
+impl Display for MetadataOperation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MetadataOperation::CreateRole(role) => write!(f, "create role: {}", role.name),
+            MetadataOperation::UpdateRole(role) => write!(f, "update role: {}", role.name),
+            MetadataOperation::DeleteRole { name } => write!(f, "delete role: {name}"),
+            MetadataOperation::CreateStream { id, name } => {
+                write!(f, "create stream: {name} (ID: {id})")
+            }
+            MetadataOperation::DeleteStream { name } => write!(f, "delete stream: {name}"),
+            MetadataOperation::CreateTopic { stream, topic } => write!(
+                f,
+                "create topic: {} (ID: {}) with {} partitions in stream: {stream}",
+                topic.name, topic.id, topic.partitions_count
+            ),
+            MetadataOperation::UpdateTopic { stream, topic } => {
+                write!(f, "update topic: {} in stream: {stream}", topic.name)
+            }
+            MetadataOperation::DeleteTopic { stream, name } => {
+                write!(f, "delete topic: {name} in stream: {stream}")
+            }
+            MetadataOperation::CreatePartitions {
+                stream,
+                topic,
+                count,
+            } => write!(
+                f,
+                "create {count} partitions in topic: {topic}, stream: {stream}"
+            ),
+            MetadataOperation::DeletePartitions {
+                stream,
+                topic,
+                count,
+            } => write!(
+                f,
+                "delete {count} partitions in topic: {topic}, stream: {stream}"
+            ),
+            MetadataOperation::CreateConsumerGroup {
+                stream,
+                topic,
+                group,
+            } => write!(
+                f,
+                "create consumer group: {} (ID: {}) in topic: {topic}, stream: {stream}",
+                group.name, group.id
+            ),
+            MetadataOperation::DeleteConsumerGroup {
+                stream,
+                topic,
+                name,
+            } => write!(
+                f,
+                "delete consumer group: {name} in topic: {topic}, stream: {stream}"
+            ),
+            MetadataOperation::CreateUser(user) => {
+                write!(f, "create user: {} (ID: {})", user.username, user.id)
+            }
+            MetadataOperation::UpdateUser { username, .. } => write!(f, "update user: {username}"),
+            MetadataOperation::UpdatePermissions { username, .. } => {
+                write!(f, "update permissions of user: {username}")
+            }
+            MetadataOperation::AssignRoles { username, roles } => write!(
+                f,
+                "assign roles: [{}] to user: {username}",
+                roles.join(", ")
+            ),
+            MetadataOperation::DeleteUser { username } => write!(f, "delete user: {username}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        CleanupPolicy, CompressionAlgorithm, ConsumerGroupAssignmentStrategy, GlobalPermissions,
+        IggyExpiry, MaxTopicSize, PartitioningHash, ResourcePattern, RoleAction, RoleRule,
+        StreamMetadata,
+    };
+
+    fn topic(id: u32, name: &str, partitions_count: u32) -> TopicMetadata {
+        TopicMetadata {
+            id,
+            name: name.to_string(),
+            partitions_count,
+            compression_algorithm: CompressionAlgorithm::None,
+            message_expiry: IggyExpiry::NeverExpire,
+            max_topic_size: MaxTopicSize::Unlimited,
+            replication_factor: None,
+            cleanup_policy: CleanupPolicy::Delete,
+            partitioning_hash: PartitioningHash::default(),
+            segment_max_age: IggyExpiry::ServerDefault,
+            max_partition_size: MaxTopicSize::ServerDefault,
+            consumer_groups: vec![],
+        }
+    }
+
+    fn group(id: u32, name: &str) -> ConsumerGroupMetadata {
+        ConsumerGroupMetadata {
+            id,
+            name: name.to_string(),
+            assignment_strategy: ConsumerGroupAssignmentStrategy::RoundRobin,
+        }
+    }
+
+    fn user(id: u32, username: &str) -> UserMetadata {
+        UserMetadata {
+            id,
+            username: username.to_string(),
+            status: UserStatus::Active,
+            permissions: None,
+            quotas: None,
+            roles: vec![],
+            password_hash: None,
+            password: None,
+            personal_access_tokens: vec![],
+        }
+    }
+
+    fn metadata() -> ServerMetadata {
+        let mut orders = topic(1, "orders", 2);
+        orders.consumer_groups = vec![group(1, "billing")];
+        ServerMetadata {
+            streams: vec![StreamMetadata {
+                id: 1,
+                name: "shop".to_string(),
+                topics: vec![orders, topic(2, "payments", 1)],
+            }],
+            roles: vec![Role::new(
+                "reader",
+                vec![RoleRule::allow(
+                    ResourcePattern::stream("shop"),
+                    &[RoleAction::ReadStream],
+                )],
+            )],
+            users: vec![user(1, "iggy"), user(2, "shop")],
+        }
+    }
+
+    #[test]
+    fn plan_should_be_empty_for_the_same_metadata() {
+        let metadata = metadata();
+        assert!(metadata.plan(&metadata.clone(), true).unwrap().is_empty());
+    }
+
+    #[test]
+    fn plan_should_create_everything_on_empty_server() {
+        let mut desired = metadata();
+        desired.users[1].password = Some("secret".to_string());
+        desired.users[1].roles = vec!["reader".to_string()];
+        let current = ServerMetadata {
+            users: vec![user(1, "iggy")],
+            ..Default::default()
+        };
+
+        let plan = current.plan(&desired, true).unwrap();
+        let plan = plan.iter().map(|op| op.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            plan,
+            vec![
+                "create role: reader",
+                "create stream: shop (ID: 1)",
+                "create topic: orders (ID: 1) with 2 partitions in stream: shop",
+                "create consumer group: billing (ID: 1) in topic: orders, stream: shop",
+                "create topic: payments (ID: 2) with 1 partitions in stream: shop",
+                "create user: shop (ID: 2)",
+                "assign roles: [reader] to user: shop",
+            ]
+        );
+    }
+
+    #[test]
+    fn plan_should_fail_when_created_user_has_no_password() {
+        let current = ServerMetadata::default();
+        let result = current.plan(&metadata(), true);
+        assert_eq!(
+            result.unwrap_err().as_code(),
+            IggyError::MissingUserPassword("shop".to_string()).as_code()
+        );
+    }
+
+    #[test]
+    fn plan_should_create_user_given_password_hash() {
+        let current = ServerMetadata::default();
+        let mut desired = metadata();
+        desired.users[1].password_hash = Some("hash".to_string());
+
+        let plan = current.plan(&desired, true).unwrap();
+
+        assert!(plan.iter().any(|operation| matches!(
+            operation,
+            MetadataOperation::CreateUser(user) if user.password_hash.as_deref() == Some("hash")
+        )));
+    }
+
+    #[test]
+    fn plan_should_update_and_delete_changed_resources() {
+        let current = metadata();
+        let mut desired = metadata();
+        desired.roles.clear();
+        desired.streams[0].topics.remove(1);
+        let orders = &mut desired.streams[0].topics[0];
+        orders.partitions_count = 5;
+        orders.message_expiry = IggyExpiry::ServerDefault;
+        orders.consumer_groups[0].assignment_strategy =
+            ConsumerGroupAssignmentStrategy::CooperativeSticky;
+        desired.users[1].permissions = Some(Permissions {
+            global: GlobalPermissions {
+                poll_messages: true,
+                ..Default::default()
+            },
+            streams: None,
+        });
+        desired.users[1].quotas = Some(UserQuotas::default());
+
+        let plan = current.plan(&desired, true).unwrap();
+        let plan = plan.iter().map(|op| op.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            plan,
+            vec![
+                "delete consumer group: billing in topic: orders, stream: shop",
+                "delete topic: payments in stream: shop",
+                "delete role: reader",
+                "update topic: orders in stream: shop",
+                "create 3 partitions in topic: orders, stream: shop",
+                "create consumer group: billing (ID: 1) in topic: orders, stream: shop",
+                "update permissions of user: shop",
+            ]
+        );
+    }
+
+    #[test]
+    fn plan_without_prune_should_keep_resources_missing_from_desired_metadata() {
+        let current = metadata();
+        let mut desired = metadata();
+        desired.roles.clear();
+        desired.users.remove(1);
+        desired.streams[0].topics.remove(1);
+        let orders = &mut desired.streams[0].topics[0];
+        orders.partitions_count = 1;
+        orders.consumer_groups[0].assignment_strategy =
+            ConsumerGroupAssignmentStrategy::CooperativeSticky;
+
+        let plan = current.plan(&desired, false).unwrap();
+        let plan = plan.iter().map(|op| op.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            plan,
+            vec![
+                "delete consumer group: billing in topic: orders, stream: shop",
+                "create consumer group: billing (ID: 1) in topic: orders, stream: shop",
+            ]
+        );
+
+        desired.streams.clear();
+        assert!(current.plan(&desired, false).unwrap().is_empty());
+    }
+
+    #[test]
+    fn plan_should_never_change_root_user() {
+        let current = metadata();
+        let mut desired = metadata();
+        desired.users.remove(0);
+        let mut root = user(1, "admin");
+        root.status = UserStatus::Inactive;
+        desired.users.push(root);
+
+        assert!(current.plan(&desired, true).unwrap().is_empty());
+    }
+}
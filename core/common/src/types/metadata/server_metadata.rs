@@ -0,0 +1,548 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::error::IggyError;
+use crate::utils::expiry::IggyExpiry;
+use crate::utils::timestamp::IggyTimestamp;
+use crate::utils::topic_size::MaxTopicSize;
+use crate::{
+    CleanupPolicy, CompressionAlgorithm, ConsumerGroupAssignmentStrategy, PartitioningHash,
+    Permissions, Role, UserQuotas, UserStatus,
+};
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+
+/// `ServerMetadata` is the declarative description of the server topology,
+/// which can be exported from one server and applied to another one.
+/// It doesn't contain any messages, consumer offsets or the secrets of the personal access tokens.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct ServerMetadata {
+    /// Streams with their topics and consumer groups.
+    #[serde(default)]
+    pub streams: Vec<StreamMetadata>,
+    /// Roles which can be assigned to the users.
+    #[serde(default)]
+    pub roles: Vec<Role>,
+    /// Users with their permissions, quotas and roles.
+    #[serde(default)]
+    pub users: Vec<UserMetadata>,
+}
+
+/// `StreamMetadata` describes the stream and its topics.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct StreamMetadata {
+    /// Unique stream ID (numeric).
+    pub id: u32,
+    /// Unique stream name.
+    pub name: String,
+    /// Topics of the stream.
+    #[serde(default)]
+    pub topics: Vec<TopicMetadata>,
+}
+
+/// `TopicMetadata` describes the topic configuration, its partitions count and consumer groups.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct TopicMetadata {
+    /// Unique topic ID (numeric) within the stream.
+    pub id: u32,
+    /// Unique topic name within the stream.
+    pub name: String,
+    /// Number of partitions in the topic.
+    pub partitions_count: u32,
+    /// Compression algorithm of the topic.
+    #[serde(default)]
+    pub compression_algorithm: CompressionAlgorithm,
+    /// Message expiry, e.g. `7days`, `never_expire` or `server_default`.
+    #[serde(default, with = "expiry_format")]
+    pub message_expiry: IggyExpiry,
+    /// Maximum size of the topic, e.g. `10GB`, `unlimited` or `server_default`.
+    #[serde(default, with = "max_topic_size_format")]
+    pub max_topic_size: MaxTopicSize,
+    /// Replication factor of the topic.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replication_factor: Option<u8>,
+    /// Cleanup policy of the topic.
+    #[serde(default)]
+    pub cleanup_policy: CleanupPolicy,
+    /// Hashing of the messages key to the partitions.
+    #[serde(default)]
+    pub partitioning_hash: PartitioningHash,
+    /// Max age of the open segment, e.g. `1day`, `never_expire` or `server_default`.
+    #[serde(default, with = "expiry_format")]
+    pub segment_max_age: IggyExpiry,
+    /// Maximum size of each partition, e.g. `1GB`, `unlimited` or `server_default`.
+    #[serde(default, with = "max_topic_size_format")]
+    pub max_partition_size: MaxTopicSize,
+    /// Consumer groups of the topic.
+    #[serde(default)]
+    pub consumer_groups: Vec<ConsumerGroupMetadata>,
+}
+
+/// `ConsumerGroupMetadata` describes the consumer group without its members and offsets.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct ConsumerGroupMetadata {
+    /// Unique consumer group ID (numeric) within the topic.
+    pub id: u32,
+    /// Unique consumer group name within the topic.
+    pub name: String,
+    /// Strategy of assigning the partitions to the members.
+    #[serde(default)]
+    pub assignment_strategy: ConsumerGroupAssignmentStrategy,
+}
+
+/// `UserMetadata` describes the user, its permissions, quotas, roles and personal access tokens.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct UserMetadata {
+    /// Unique user ID (numeric).
+    pub id: u32,
+    /// Unique username.
+    pub username: String,
+    /// Status of the user.
+    #[serde(default)]
+    pub status: UserStatus,
+    /// Permissions of the user, if not set then the user has no permissions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Permissions>,
+    /// Quotas of the user, if not set then the user is not limited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quotas: Option<UserQuotas>,
+    /// Names of the roles assigned to the user.
+    #[serde(default)]
+    pub roles: Vec<String>,
+    /// Hash of the user's password, exported only when explicitly requested.
+    /// It's used to import the user when applying the metadata, unless the password is provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password_hash: Option<String>,
+    /// Password used to create the user when applying the metadata, it's never exported.
+    /// It takes precedence over the password hash.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    /// Personal access tokens of the user, only their names and expiry, as the tokens can't be recreated.
+    #[serde(default)]
+    pub personal_access_tokens: Vec<PersonalAccessTokenMetadata>,
+}
+
+/// `PersonalAccessTokenMetadata` describes the personal access token without its secret.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct PersonalAccessTokenMetadata {
+    /// Unique name of the token within the user.
+    pub name: String,
+    /// Expiry of the token, if not set then the token never expires.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiry_at: Option<IggyTimestamp>,
+}
+
+impl BytesSerializable for ServerMetadata {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(self.streams.len() as u32);
+        for stream in &self.streams {
+            bytes.put_u32_le(stream.id);
+            put_name(&mut bytes, &stream.name);
+            bytes.put_u32_le(stream.topics.len() as u32);
+            for topic in &stream.topics {
+                bytes.put_u32_le(topic.id);
+                put_name(&mut bytes, &topic.name);
+                bytes.put_u32_le(topic.partitions_count);
+                bytes.put_u8(topic.compression_algorithm.as_code());
+                bytes.put_u64_le(topic.message_expiry.into());
+                bytes.put_u64_le(topic.max_topic_size.into());
+                bytes.put_u8(topic.replication_factor.unwrap_or(0));
+                bytes.put_u8(topic.cleanup_policy.as_code());
+                bytes.put_slice(&topic.partitioning_hash.as_code());
+                bytes.put_u64_le(topic.segment_max_age.into());
+                bytes.put_u64_le(topic.max_partition_size.into());
+                bytes.put_u32_le(topic.consumer_groups.len() as u32);
+                for group in &topic.consumer_groups {
+                    bytes.put_u32_le(group.id);
+                    put_name(&mut bytes, &group.name);
+                    bytes.put_u8(group.assignment_strategy.as_code());
+                }
+            }
+        }
+
+        bytes.put_u32_le(self.roles.len() as u32);
+        for role in &self.roles {
+            let role = role.to_bytes();
+            bytes.put_u32_le(role.len() as u32);
+            bytes.put_slice(&role);
+        }
+
+        bytes.put_u32_le(self.users.len() as u32);
+        for user in &self.users {
+            bytes.put_u32_le(user.id);
+            put_name(&mut bytes, &user.username);
+            bytes.put_u8(user.status.as_code());
+            match &user.permissions {
+                Some(permissions) => {
+                    let permissions = permissions.to_bytes();
+                    bytes.put_u32_le(permissions.len() as u32);
+                    bytes.put_slice(&permissions);
+                }
+                None => bytes.put_u32_le(0),
+            }
+            match &user.quotas {
+                Some(quotas) => {
+                    bytes.put_u8(1);
+                    bytes.put_slice(&quotas.to_bytes());
+                }
+                None => bytes.put_u8(0),
+            }
+            bytes.put_u32_le(user.roles.len() as u32);
+            for role in &user.roles {
+                put_name(&mut bytes, role);
+            }
+            let password_hash = user.password_hash.as_deref().unwrap_or_default();
+            bytes.put_u16_le(password_hash.len() as u16);
+            bytes.put_slice(password_hash.as_bytes());
+            bytes.put_u32_le(user.personal_access_tokens.len() as u32);
+            for token in &user.personal_access_tokens {
+                put_name(&mut bytes, &token.name);
+                bytes.put_u64_le(token.expiry_at.map(|at| at.as_micros()).unwrap_or(0));
+            }
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        let mut reader = BytesReader::new(&bytes);
+        let mut metadata = ServerMetadata::default();
+        for _ in 0..reader.u32()? {
+            let id = reader.u32()?;
+            let name = reader.name()?;
+            let mut topics = Vec::new();
+            for _ in 0..reader.u32()? {
+                let id = reader.u32()?;
+                let name = reader.name()?;
+                let partitions_count = reader.u32()?;
+                let compression_algorithm = CompressionAlgorithm::from_code(reader.u8()?)?;
+                let message_expiry = reader.u64()?.into();
+                let max_topic_size = reader.u64()?.into();
+                let replication_factor = match reader.u8()? {
+                    0 => None,
+                    factor => Some(factor),
+                };
+                let cleanup_policy = CleanupPolicy::from_code(reader.u8()?)?;
+                let partitioning_hash = PartitioningHash::from_code(reader.u8()?, reader.u8()?)?;
+                let segment_max_age = reader.u64()?.into();
+                let max_partition_size = reader.u64()?.into();
+                let mut consumer_groups = Vec::new();
+                for _ in 0..reader.u32()? {
+                    consumer_groups.push(ConsumerGroupMetadata {
+                        id: reader.u32()?,
+                        name: reader.name()?,
+                        assignment_strategy: ConsumerGroupAssignmentStrategy::from_code(
+                            reader.u8()?,
+                        )?,
+                    });
+                }
+                topics.push(TopicMetadata {
+                    id,
+                    name,
+                    partitions_count,
+                    compression_algorithm,
+                    message_expiry,
+                    max_topic_size,
+                    replication_factor,
+                    cleanup_policy,
+                    partitioning_hash,
+                    segment_max_age,
+                    max_partition_size,
+                    consumer_groups,
+                });
+            }
+            metadata.streams.push(StreamMetadata { id, name, topics });
+        }
+
+        for _ in 0..reader.u32()? {
+            let length = reader.u32()? as usize;
+            let role = Role::from_bytes(Bytes::copy_from_slice(reader.take(length)?))?;
+            metadata.roles.push(role);
+        }
+
+        for _ in 0..reader.u32()? {
+            let id = reader.u32()?;
+            let username = reader.name()?;
+            let status = UserStatus::from_code(reader.u8()?)?;
+            let permissions = match reader.u32()? as usize {
+                0 => None,
+                length => Some(Permissions::from_bytes(Bytes::copy_from_slice(
+                    reader.take(length)?,
+                ))?),
+            };
+            let quotas = match reader.u8()? {
+                0 => None,
+                _ => Some(UserQuotas::from_bytes(Bytes::copy_from_slice(
+                    reader.take(UserQuotas::SIZE)?,
+                ))?),
+            };
+            let mut roles = Vec::new();
+            for _ in 0..reader.u32()? {
+                roles.push(reader.name()?);
+            }
+            let password_hash_length = reader.u16()? as usize;
+            let password_hash = match password_hash_length {
+                0 => None,
+                length => Some(reader.string(length)?),
+            };
+            let mut personal_access_tokens = Vec::new();
+            for _ in 0..reader.u32()? {
+                let name = reader.name()?;
+                let expiry_at = match reader.u64()? {
+                    0 => None,
+                    expiry_at => Some(expiry_at.into()),
+                };
+                personal_access_tokens.push(PersonalAccessTokenMetadata { name, expiry_at });
+            }
+            metadata.users.push(UserMetadata {
+                id,
+                username,
+                status,
+                permissions,
+                quotas,
+                roles,
+                password_hash,
+                password: None,
+                personal_access_tokens,
+            });
+        }
+
+        if !reader.is_empty() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(metadata)
+    }
+}
+
+fn put_name(bytes: &mut BytesMut, name: &str) {
+    bytes.put_u8(name.len() as u8);
+    bytes.put_slice(name.as_bytes());
+}
+
+struct BytesReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BytesReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    fn take(&mut self, length: usize) -> Result<&'a [u8], IggyError> {
+        let slice = self
+            .bytes
+            .get(self.position..self.position + length)
+            .ok_or(IggyError::InvalidCommand)?;
+        self.position += length;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8, IggyError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, IggyError> {
+        let value = self.take(2)?;
+        Ok(u16::from_le_bytes(
+            value
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ))
+    }
+
+    fn u32(&mut self) -> Result<u32, IggyError> {
+        let value = self.take(4)?;
+        Ok(u32::from_le_bytes(
+            value
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ))
+    }
+
+    fn u64(&mut self) -> Result<u64, IggyError> {
+        let value = self.take(8)?;
+        Ok(u64::from_le_bytes(
+            value
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ))
+    }
+
+    fn string(&mut self, length: usize) -> Result<String, IggyError> {
+        let value = self.take(length)?;
+        String::from_utf8(value.to_vec()).map_err(|_| IggyError::InvalidUtf8)
+    }
+
+    fn name(&mut self) -> Result<String, IggyError> {
+        let length = self.u8()? as usize;
+        self.string(length)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.position == self.bytes.len()
+    }
+}
+
+/// The expiry is (de)serialized as the human-readable string, e.g. `7days` or `never_expire`,
+/// as its numeric representation exceeds the range of the integers supported by TOML.
+mod expiry_format {
+    use crate::utils::expiry::IggyExpiry;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(expiry: &IggyExpiry, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(expiry)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<IggyExpiry, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// The custom topic size is serialized as the exact number of bytes, to not lose the precision of the human-readable format.
+mod max_topic_size_format {
+    use crate::utils::topic_size::MaxTopicSize;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(size: &MaxTopicSize, serializer: S) -> Result<S::Ok, S::Error> {
+        match size {
+            MaxTopicSize::Custom(size) => serializer.collect_str(&size.as_bytes_u64()),
+            size => serializer.collect_str(size),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<MaxTopicSize, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GlobalPermissions, ResourcePattern, RoleAction, RoleRule};
+    use crate::{IggyByteSize, IggyDuration};
+    use std::str::FromStr;
+
+    fn metadata() -> ServerMetadata {
+        ServerMetadata {
+            streams: vec![StreamMetadata {
+                id: 1,
+                name: "orders".to_string(),
+                topics: vec![TopicMetadata {
+                    id: 2,
+                    name: "created".to_string(),
+                    partitions_count: 3,
+                    compression_algorithm: CompressionAlgorithm::Gzip,
+                    message_expiry: IggyExpiry::ExpireDuration(
+                        IggyDuration::from_str("7days").unwrap(),
+                    ),
+                    max_topic_size: MaxTopicSize::Custom(IggyByteSize::from(1_500_000_123)),
+                    replication_factor: Some(1),
+                    cleanup_policy: CleanupPolicy::Compact,
+                    partitioning_hash: PartitioningHash::default(),
+                    segment_max_age: IggyExpiry::ExpireDuration(
+                        IggyDuration::from_str("1h").unwrap(),
+                    ),
+                    max_partition_size: MaxTopicSize::Unlimited,
+                    consumer_groups: vec![ConsumerGroupMetadata {
+                        id: 4,
+                        name: "billing".to_string(),
+                        assignment_strategy: ConsumerGroupAssignmentStrategy::CooperativeSticky,
+                    }],
+                }],
+            }],
+            roles: vec![Role::new(
+                "reader",
+                vec![RoleRule::allow(
+                    ResourcePattern::stream("orders"),
+                    &[RoleAction::ReadStream],
+                )],
+            )],
+            users: vec![UserMetadata {
+                id: 2,
+                username: "user".to_string(),
+                status: UserStatus::Inactive,
+                permissions: Some(Permissions {
+                    global: GlobalPermissions {
+                        read_streams: true,
+                        ..Default::default()
+                    },
+                    streams: None,
+                }),
+                quotas: Some(UserQuotas {
+                    max_connections: Some(5),
+                    ..Default::default()
+                }),
+                roles: vec!["reader".to_string()],
+                password_hash: Some("hash".to_string()),
+                password: None,
+                personal_access_tokens: vec![PersonalAccessTokenMetadata {
+                    name: "token".to_string(),
+                    expiry_at: Some(IggyTimestamp::from(1_000_000)),
+                }],
+            }],
+        }
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let metadata = metadata();
+        let bytes = metadata.to_bytes();
+        let deserialized = ServerMetadata::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, metadata);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let bytes = metadata().to_bytes();
+        let truncated = bytes.slice(..bytes.len() - 1);
+        assert!(ServerMetadata::from_bytes(truncated).is_err());
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_json() {
+        let metadata = metadata();
+        let json = serde_json::to_string(&metadata).unwrap();
+        assert!(json.contains("\"message_expiry\":\"7days\""));
+        assert!(json.contains("\"max_topic_size\":\"1500000123\""));
+        let deserialized: ServerMetadata = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, metadata);
+    }
+
+    #[test]
+    fn should_use_defaults_for_missing_topic_fields() {
+        let json = r#"{"streams":[{"id":1,"name":"orders","topics":[{"id":1,"name":"created","partitions_count":1}]}]}"#;
+        let metadata: ServerMetadata = serde_json::from_str(json).unwrap();
+        let topic = &metadata.streams[0].topics[0];
+        assert_eq!(topic.message_expiry, IggyExpiry::ServerDefault);
+        assert_eq!(topic.max_topic_size, MaxTopicSize::ServerDefault);
+        assert_eq!(topic.segment_max_age, IggyExpiry::ServerDefault);
+        assert_eq!(topic.max_partition_size, MaxTopicSize::ServerDefault);
+        assert!(topic.consumer_groups.is_empty());
+        assert!(metadata.users.is_empty());
+    }
+}
@@ -16,6 +16,7 @@
 // under the License.
 
 pub(crate) mod args;
+pub(crate) mod audit;
 pub(crate) mod client;
 pub(crate) mod client_state;
 pub(crate) mod command;
@@ -26,10 +27,14 @@ pub(crate) mod consumer;
 pub(crate) mod diagnostic;
 pub(crate) mod identifier;
 pub(crate) mod message;
+pub(crate) mod metadata;
 pub(crate) mod partition;
 pub(crate) mod permissions;
+pub(crate) mod producer;
+pub(crate) mod schema;
 pub(crate) mod snapshot;
 pub(crate) mod stats;
 pub(crate) mod stream;
 pub(crate) mod topic;
+pub(crate) mod transaction;
 pub(crate) mod user;
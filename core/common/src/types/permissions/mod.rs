@@ -17,3 +17,4 @@
 
 pub(crate) mod permissions_global;
 pub(crate) mod personal_access_token;
+pub(crate) mod role;
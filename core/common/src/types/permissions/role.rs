@@ -0,0 +1,574 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::defaults::{MAX_ROLE_NAME_LENGTH, MIN_ROLE_NAME_LENGTH};
+use crate::error::IggyError;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+const STREAM_PREFIX: &str = "stream:";
+const TOPIC_PREFIX: &str = "topic:";
+const TOPIC_SEPARATOR: &str = "/topic:";
+
+/// `Role` is a named set of rules which can be assigned to the users.
+/// Unlike the `Permissions`, the rules do not refer to the streams and topics by their numeric IDs,
+/// but match them by name patterns, thus the newly created resources are covered without updating the users.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct Role {
+    /// Unique name of the role.
+    pub name: String,
+    /// Rules evaluated for the users having the role assigned.
+    pub rules: Vec<RoleRule>,
+}
+
+/// `RoleRule` allows or denies the listed actions on the streams and topics matching the resource pattern.
+/// The deny rules take priority over the allow rules of all the assigned roles and over the user's permissions.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct RoleRule {
+    /// Whether the rule allows or denies the actions.
+    pub effect: RuleEffect,
+    /// The streams and topics the rule applies to.
+    pub resource: ResourcePattern,
+    /// The actions allowed or denied by the rule.
+    pub actions: Vec<RoleAction>,
+}
+
+/// `RuleEffect` defines whether the rule allows or denies the actions.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleEffect {
+    Allow,
+    Deny,
+}
+
+/// `RoleAction` is the action on a stream or topic which can be allowed or denied by the rule.
+/// The allowed action includes the other ones in the same way as the corresponding `StreamPermissions`, e.g. `manage_stream` allows everything,
+/// while `read_topic` also allows to poll the messages. The denied action includes the other ones in the same way.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum RoleAction {
+    ManageStream,
+    ReadStream,
+    ManageTopic,
+    ReadTopic,
+    PollMessages,
+    SendMessages,
+}
+
+/// `ResourcePattern` matches the streams and optionally the topics by name.
+/// The names are matched using the glob patterns, where `*` matches any sequence of characters and `?` matches a single character.
+/// The supported formats are:
+/// - `stream:<pattern>` - the matching streams and all their topics.
+/// - `stream:<pattern>/topic:<pattern>` - the matching topics of the matching streams.
+/// - `topic:<pattern>` - the matching topics of any stream.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(try_from = "String", into = "String")]
+pub struct ResourcePattern {
+    /// The pattern of the stream name.
+    pub stream: String,
+    /// The pattern of the topic name, if `None`, then the rule applies to the stream itself and all its topics.
+    pub topic: Option<String>,
+}
+
+impl Role {
+    pub fn new(name: &str, rules: Vec<RoleRule>) -> Self {
+        Role {
+            name: name.to_owned(),
+            rules,
+        }
+    }
+
+    pub(crate) fn validate(name: &str, rules: &[RoleRule]) -> Result<(), IggyError> {
+        if name.is_empty() || name.len() > MAX_ROLE_NAME_LENGTH || name.len() < MIN_ROLE_NAME_LENGTH
+        {
+            return Err(IggyError::InvalidRoleName);
+        }
+
+        for rule in rules {
+            if rule.actions.is_empty() || rule.actions.len() > u8::MAX as usize {
+                return Err(IggyError::InvalidCommand);
+            }
+
+            if rule.resource.to_string().len() > u16::MAX as usize {
+                return Err(IggyError::InvalidResourcePattern(rule.resource.to_string()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl RoleRule {
+    pub fn allow(resource: ResourcePattern, actions: &[RoleAction]) -> Self {
+        RoleRule {
+            effect: RuleEffect::Allow,
+            resource,
+            actions: actions.to_vec(),
+        }
+    }
+
+    pub fn deny(resource: ResourcePattern, actions: &[RoleAction]) -> Self {
+        RoleRule {
+            effect: RuleEffect::Deny,
+            resource,
+            actions: actions.to_vec(),
+        }
+    }
+
+    /// Returns `true` if the rule matches the action on the given stream and topic.
+    /// Both the allow and the deny rule match also the actions included in the listed ones.
+    pub fn matches(&self, action: RoleAction, stream: &str, topic: Option<&str>) -> bool {
+        if !self.resource.matches(stream, topic) {
+            return false;
+        }
+
+        self.actions.iter().any(|listed| listed.includes(action))
+    }
+
+    pub(crate) fn write_all(rules: &[RoleRule], bytes: &mut BytesMut) {
+        bytes.put_u32_le(rules.len() as u32);
+        for rule in rules {
+            bytes.put_u8(rule.effect.as_code());
+            let resource = rule.resource.to_string();
+            bytes.put_u16_le(resource.len() as u16);
+            bytes.put_slice(resource.as_bytes());
+            bytes.put_u8(rule.actions.len() as u8);
+            for action in &rule.actions {
+                bytes.put_u8(action.as_code());
+            }
+        }
+    }
+
+    pub(crate) fn read_all(bytes: &[u8]) -> Result<Vec<RoleRule>, IggyError> {
+        let read = |position: usize, length: usize| {
+            bytes
+                .get(position..position + length)
+                .ok_or(IggyError::InvalidCommand)
+        };
+        let count = u32::from_le_bytes(
+            read(0, 4)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let mut position = 4;
+        let mut rules = Vec::new();
+        for _ in 0..count {
+            let effect = RuleEffect::from_code(read(position, 1)?[0])?;
+            let resource_length = u16::from_le_bytes(
+                read(position + 1, 2)?
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ) as usize;
+            position += 3;
+            let resource = std::str::from_utf8(read(position, resource_length)?)
+                .map_err(|_| IggyError::InvalidUtf8)?
+                .parse::<ResourcePattern>()?;
+            position += resource_length;
+            let actions_count = read(position, 1)?[0] as usize;
+            position += 1;
+            let actions = read(position, actions_count)?
+                .iter()
+                .map(|code| RoleAction::from_code(*code))
+                .collect::<Result<Vec<_>, _>>()?;
+            position += actions_count;
+            rules.push(RoleRule {
+                effect,
+                resource,
+                actions,
+            });
+        }
+
+        if position != bytes.len() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(rules)
+    }
+}
+
+impl RuleEffect {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            RuleEffect::Allow => 1,
+            RuleEffect::Deny => 2,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(RuleEffect::Allow),
+            2 => Ok(RuleEffect::Deny),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl RoleAction {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            RoleAction::ManageStream => 1,
+            RoleAction::ReadStream => 2,
+            RoleAction::ManageTopic => 3,
+            RoleAction::ReadTopic => 4,
+            RoleAction::PollMessages => 5,
+            RoleAction::SendMessages => 6,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(RoleAction::ManageStream),
+            2 => Ok(RoleAction::ReadStream),
+            3 => Ok(RoleAction::ManageTopic),
+            4 => Ok(RoleAction::ReadTopic),
+            5 => Ok(RoleAction::PollMessages),
+            6 => Ok(RoleAction::SendMessages),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+
+    /// Returns `true` if allowing this action allows also the given one.
+    pub fn includes(&self, action: RoleAction) -> bool {
+        match self {
+            RoleAction::ManageStream => true,
+            RoleAction::ReadStream => matches!(
+                action,
+                RoleAction::ReadStream | RoleAction::ReadTopic | RoleAction::PollMessages
+            ),
+            RoleAction::ManageTopic => matches!(
+                action,
+                RoleAction::ManageTopic
+                    | RoleAction::ReadTopic
+                    | RoleAction::PollMessages
+                    | RoleAction::SendMessages
+            ),
+            RoleAction::ReadTopic => {
+                matches!(action, RoleAction::ReadTopic | RoleAction::PollMessages)
+            }
+            RoleAction::PollMessages => action == RoleAction::PollMessages,
+            RoleAction::SendMessages => action == RoleAction::SendMessages,
+        }
+    }
+}
+
+impl FromStr for RoleAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "manage_stream" => Ok(RoleAction::ManageStream),
+            "read_stream" => Ok(RoleAction::ReadStream),
+            "manage_topic" => Ok(RoleAction::ManageTopic),
+            "read_topic" => Ok(RoleAction::ReadTopic),
+            "poll_messages" => Ok(RoleAction::PollMessages),
+            "send_messages" => Ok(RoleAction::SendMessages),
+            _ => Err(format!("Unknown role action: {s}")),
+        }
+    }
+}
+
+impl Display for RoleAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RoleAction::ManageStream => write!(f, "manage_stream"),
+            RoleAction::ReadStream => write!(f, "read_stream"),
+            RoleAction::ManageTopic => write!(f, "manage_topic"),
+            RoleAction::ReadTopic => write!(f, "read_topic"),
+            RoleAction::PollMessages => write!(f, "poll_messages"),
+            RoleAction::SendMessages => write!(f, "send_messages"),
+        }
+    }
+}
+
+impl Display for RuleEffect {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RuleEffect::Allow => write!(f, "allow"),
+            RuleEffect::Deny => write!(f, "deny"),
+        }
+    }
+}
+
+impl ResourcePattern {
+    pub fn stream(stream: &str) -> Self {
+        ResourcePattern {
+            stream: stream.to_owned(),
+            topic: None,
+        }
+    }
+
+    pub fn topic(stream: &str, topic: &str) -> Self {
+        ResourcePattern {
+            stream: stream.to_owned(),
+            topic: Some(topic.to_owned()),
+        }
+    }
+
+    /// Returns `true` if the pattern matches the stream, when the topic is not provided,
+    /// or the topic of the stream otherwise. The pattern without the topic matches all the topics of the stream,
+    /// while the pattern with the topic never matches the stream itself.
+    pub fn matches(&self, stream: &str, topic: Option<&str>) -> bool {
+        if !glob_matches(&self.stream, stream) {
+            return false;
+        }
+
+        match (&self.topic, topic) {
+            (None, _) => true,
+            (Some(pattern), Some(topic)) => glob_matches(pattern, topic),
+            (Some(_), None) => false,
+        }
+    }
+}
+
+impl FromStr for ResourcePattern {
+    type Err = IggyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || IggyError::InvalidResourcePattern(s.to_owned());
+        let (stream, topic) = if let Some(topic) = s.strip_prefix(TOPIC_PREFIX) {
+            ("*", Some(topic))
+        } else if let Some(resource) = s.strip_prefix(STREAM_PREFIX) {
+            match resource.split_once(TOPIC_SEPARATOR) {
+                Some((stream, topic)) => (stream, Some(topic)),
+                None => (resource, None),
+            }
+        } else {
+            return Err(invalid());
+        };
+
+        if stream.is_empty() || topic.is_some_and(|topic| topic.is_empty()) {
+            return Err(invalid());
+        }
+
+        Ok(ResourcePattern {
+            stream: stream.to_owned(),
+            topic: topic.map(|topic| topic.to_owned()),
+        })
+    }
+}
+
+impl TryFrom<String> for ResourcePattern {
+    type Error = IggyError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<ResourcePattern> for String {
+    fn from(value: ResourcePattern) -> Self {
+        value.to_string()
+    }
+}
+
+impl Display for ResourcePattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.topic {
+            Some(topic) => write!(f, "{STREAM_PREFIX}{}{TOPIC_SEPARATOR}{topic}", self.stream),
+            None => write!(f, "{STREAM_PREFIX}{}", self.stream),
+        }
+    }
+}
+
+impl Display for RoleRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let actions = self
+            .actions
+            .iter()
+            .map(|action| action.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(f, "{} {} on {}", self.effect, actions, self.resource)
+    }
+}
+
+impl Display for Role {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let rules = self
+            .rules
+            .iter()
+            .map(|rule| rule.to_string())
+            .collect::<Vec<_>>()
+            .join("; ");
+        write!(f, "{}: {rules}", self.name)
+    }
+}
+
+impl BytesSerializable for Role {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::new();
+        bytes.put_u8(self.name.len() as u8);
+        bytes.put_slice(self.name.as_bytes());
+        RoleRule::write_all(&self.rules, &mut bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        if bytes.is_empty() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name_length = bytes[0] as usize;
+        let name = bytes
+            .get(1..1 + name_length)
+            .ok_or(IggyError::InvalidCommand)?;
+        let name = String::from_utf8(name.to_vec()).map_err(|_| IggyError::InvalidUtf8)?;
+        let rules = RoleRule::read_all(&bytes[1 + name_length..])?;
+        Ok(Role { name, rules })
+    }
+}
+
+/// Matches the value against the glob pattern, where `*` matches any sequence of characters and `?` matches a single character.
+fn glob_matches(pattern: &str, value: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let value = value.chars().collect::<Vec<_>>();
+    let (mut p, mut v) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while v < value.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == value[v]) {
+            p += 1;
+            v += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, v));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            v = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glob_should_match_prefix_suffix_and_single_characters() {
+        assert!(glob_matches("*", ""));
+        assert!(glob_matches("tenant-*", "tenant-1"));
+        assert!(glob_matches("tenant-*", "tenant-"));
+        assert!(!glob_matches("tenant-*", "tenants"));
+        assert!(glob_matches("*-orders", "tenant-1-orders"));
+        assert!(glob_matches("tenant-?", "tenant-7"));
+        assert!(!glob_matches("tenant-?", "tenant-17"));
+        assert!(glob_matches("a*b*c", "aXbYbZc"));
+        assert!(!glob_matches("a*b*c", "aXbYbZ"));
+        assert!(glob_matches("orders", "orders"));
+        assert!(!glob_matches("orders", "orders-1"));
+    }
+
+    #[test]
+    fn resource_pattern_should_be_parsed_and_displayed() {
+        let pattern = "stream:tenant-*".parse::<ResourcePattern>().unwrap();
+        assert_eq!(pattern, ResourcePattern::stream("tenant-*"));
+        assert_eq!(pattern.to_string(), "stream:tenant-*");
+
+        let pattern = "stream:tenant-*/topic:orders-?"
+            .parse::<ResourcePattern>()
+            .unwrap();
+        assert_eq!(pattern, ResourcePattern::topic("tenant-*", "orders-?"));
+        assert_eq!(pattern.to_string(), "stream:tenant-*/topic:orders-?");
+
+        let pattern = "topic:*".parse::<ResourcePattern>().unwrap();
+        assert_eq!(pattern, ResourcePattern::topic("*", "*"));
+
+        assert!("tenant-*".parse::<ResourcePattern>().is_err());
+        assert!("stream:".parse::<ResourcePattern>().is_err());
+        assert!("stream:a/topic:".parse::<ResourcePattern>().is_err());
+    }
+
+    #[test]
+    fn resource_pattern_with_topic_should_not_match_stream() {
+        let stream_pattern = ResourcePattern::stream("tenant-*");
+        assert!(stream_pattern.matches("tenant-1", None));
+        assert!(stream_pattern.matches("tenant-1", Some("orders")));
+        assert!(!stream_pattern.matches("other", Some("orders")));
+
+        let topic_pattern = ResourcePattern::topic("tenant-*", "orders*");
+        assert!(!topic_pattern.matches("tenant-1", None));
+        assert!(topic_pattern.matches("tenant-1", Some("orders-eu")));
+        assert!(!topic_pattern.matches("tenant-1", Some("payments")));
+    }
+
+    #[test]
+    fn allow_rule_should_include_implied_actions() {
+        let allow = RoleRule::allow(ResourcePattern::stream("*"), &[RoleAction::ReadTopic]);
+        assert!(allow.matches(RoleAction::PollMessages, "a", Some("b")));
+        assert!(!allow.matches(RoleAction::ManageTopic, "a", Some("b")));
+        assert!(!allow.matches(RoleAction::SendMessages, "a", Some("b")));
+    }
+
+    #[test]
+    fn deny_rule_should_include_implied_actions() {
+        let deny = RoleRule::deny(ResourcePattern::stream("*"), &[RoleAction::ReadStream]);
+        assert!(deny.matches(RoleAction::ReadStream, "a", None));
+        assert!(deny.matches(RoleAction::ReadTopic, "a", Some("b")));
+        assert!(deny.matches(RoleAction::PollMessages, "a", Some("b")));
+        assert!(!deny.matches(RoleAction::ManageStream, "a", None));
+        assert!(!deny.matches(RoleAction::SendMessages, "a", Some("b")));
+
+        let deny = RoleRule::deny(ResourcePattern::stream("*"), &[RoleAction::PollMessages]);
+        assert!(!deny.matches(RoleAction::ManageTopic, "a", Some("b")));
+        assert!(!deny.matches(RoleAction::SendMessages, "a", Some("b")));
+    }
+
+    #[test]
+    fn role_should_be_serialized_and_deserialized() {
+        let role = Role::new(
+            "tenant",
+            vec![
+                RoleRule::allow(
+                    ResourcePattern::stream("tenant-*"),
+                    &[RoleAction::ReadStream, RoleAction::SendMessages],
+                ),
+                RoleRule::deny(
+                    ResourcePattern::topic("tenant-*", "internal-*"),
+                    &[RoleAction::PollMessages],
+                ),
+            ],
+        );
+
+        let deserialized = Role::from_bytes(role.to_bytes()).unwrap();
+        assert_eq!(deserialized, role);
+    }
+
+    #[test]
+    fn role_should_be_deserialized_from_json() {
+        let json = r#"{"name":"tenant","rules":[{"effect":"deny","resource":"stream:tenant-*/topic:*","actions":["send_messages"]}]}"#;
+        let role = serde_json::from_str::<Role>(json).unwrap();
+        assert_eq!(
+            role.rules[0],
+            RoleRule::deny(
+                ResourcePattern::topic("tenant-*", "*"),
+                &[RoleAction::SendMessages]
+            )
+        );
+        assert!(serde_json::from_str::<Role>(&json.replace("stream:", "")).is_err());
+    }
+}
@@ -0,0 +1,51 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use serde::{Deserialize, Serialize};
+
+/// `ProducerInfo` represents the initialized idempotent producer.
+/// It consists of the following fields:
+/// - `id`: the unique ID of the producer, which should be provided when sending the messages and initializing the producer again after restart.
+/// - `epoch`: the epoch of the producer, bumped on each initialization, so that the previous instance of the producer gets fenced.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+pub struct ProducerInfo {
+    /// The unique ID of the producer.
+    pub id: u64,
+    /// The epoch of the producer.
+    pub epoch: u32,
+}
+
+/// `ProducerSequence` is attached to the messages sent by the idempotent producer.
+/// It consists of the following fields:
+/// - `producer_id`: the unique ID of the producer.
+/// - `producer_epoch`: the epoch of the producer returned on its initialization.
+/// - `sequence`: the sequence number of the batch, starting from 0 and incremented by 1 for each batch sent to the same partition.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+pub struct ProducerSequence {
+    /// The unique ID of the producer.
+    pub producer_id: u64,
+    /// The epoch of the producer.
+    pub producer_epoch: u32,
+    /// The sequence number of the batch within the partition.
+    pub sequence: u64,
+}
+
+impl ProducerSequence {
+    /// The size of the producer sequence in bytes: producer ID (8 bytes), epoch (4 bytes) and sequence (8 bytes).
+    pub const SIZE: usize = 20;
+}
@@ -0,0 +1,159 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+pub(crate) mod schema_compatibility;
+pub(crate) mod schema_type;
+
+use crate::BytesSerializable;
+use crate::error::IggyError;
+use crate::utils::timestamp::IggyTimestamp;
+use crate::{SchemaCompatibility, SchemaType};
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use serde_with::base64::Base64;
+use serde_with::serde_as;
+
+/// `Schema` is a single version of the schema registered for the topic.
+/// It consists of the following fields:
+/// - `id`: unique identifier of the schema, referenced by the messages using the reserved user header.
+/// - `stream_id`: unique identifier of the stream.
+/// - `topic_id`: unique identifier of the topic.
+/// - `version`: version of the schema, incremented with each registration for the topic.
+/// - `schema_type`: format of the schema definition.
+/// - `compatibility`: compatibility checked when the schema was registered.
+/// - `definition`: the schema definition.
+/// - `created_at`: the timestamp when the schema was registered.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct Schema {
+    /// Unique identifier of the schema, referenced by the messages using the reserved user header.
+    pub id: u32,
+    /// Unique identifier of the stream.
+    pub stream_id: u32,
+    /// Unique identifier of the topic.
+    pub topic_id: u32,
+    /// Version of the schema, incremented with each registration for the topic.
+    pub version: u32,
+    /// Format of the schema definition.
+    pub schema_type: SchemaType,
+    /// Compatibility checked when the schema was registered.
+    pub compatibility: SchemaCompatibility,
+    /// The schema definition, base64 encoded in JSON.
+    #[serde_as(as = "Base64")]
+    pub definition: Bytes,
+    /// The timestamp when the schema was registered.
+    pub created_at: IggyTimestamp,
+}
+
+impl BytesSerializable for Schema {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(30 + self.definition.len());
+        bytes.put_u32_le(self.id);
+        bytes.put_u32_le(self.stream_id);
+        bytes.put_u32_le(self.topic_id);
+        bytes.put_u32_le(self.version);
+        bytes.put_u8(self.schema_type.as_code());
+        bytes.put_u8(self.compatibility.as_code());
+        bytes.put_u64_le(self.created_at.into());
+        bytes.put_u32_le(self.definition.len() as u32);
+        bytes.put_slice(&self.definition);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        if bytes.len() < 30 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let read_u32 = |position: usize| {
+            u32::from_le_bytes(
+                bytes[position..position + 4]
+                    .try_into()
+                    .expect("slice of 4 bytes"),
+            )
+        };
+        let id = read_u32(0);
+        let stream_id = read_u32(4);
+        let topic_id = read_u32(8);
+        let version = read_u32(12);
+        let schema_type = SchemaType::from_code(bytes[16])?;
+        let compatibility = SchemaCompatibility::from_code(bytes[17])?;
+        let created_at = u64::from_le_bytes(
+            bytes[18..26]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        )
+        .into();
+        let definition_length = read_u32(26) as usize;
+        if bytes.len() != 30 + definition_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(Schema {
+            id,
+            stream_id,
+            topic_id,
+            version,
+            schema_type,
+            compatibility,
+            definition: bytes.slice(30..),
+            created_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema() -> Schema {
+        Schema {
+            id: 7,
+            stream_id: 1,
+            topic_id: 2,
+            version: 3,
+            schema_type: SchemaType::Avro,
+            compatibility: SchemaCompatibility::Full,
+            definition: Bytes::from_static(br#"{"type":"string"}"#),
+            created_at: 1_000_000.into(),
+        }
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let schema = schema();
+        let deserialized = Schema::from_bytes(schema.to_bytes()).unwrap();
+        assert_eq!(deserialized, schema);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let bytes = schema().to_bytes();
+        assert!(Schema::from_bytes(bytes.slice(..bytes.len() - 1)).is_err());
+    }
+
+    #[test]
+    fn definition_should_be_base64_encoded_in_json() {
+        let json = serde_json::to_string(&schema()).unwrap();
+        assert!(json.contains(r#""definition":"eyJ0eXBlIjoic3RyaW5nIn0=""#));
+        assert_eq!(serde_json::from_str::<Schema>(&json).unwrap(), schema());
+    }
+}
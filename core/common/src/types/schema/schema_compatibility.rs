@@ -0,0 +1,135 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `SchemaCompatibility` defines how the newly registered schema version is checked against the latest one of the topic.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Clone, Copy, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum SchemaCompatibility {
+    /// The new version is not checked at all.
+    None,
+    /// The consumers using the new version can read the messages produced with the latest one.
+    #[default]
+    Backward,
+    /// The consumers using the latest version can read the messages produced with the new one.
+    Forward,
+    /// Both the backward and the forward compatibility are required.
+    Full,
+}
+
+impl FromStr for SchemaCompatibility {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "none" => Ok(SchemaCompatibility::None),
+            "backward" => Ok(SchemaCompatibility::Backward),
+            "forward" => Ok(SchemaCompatibility::Forward),
+            "full" => Ok(SchemaCompatibility::Full),
+            _ => Err(IggyError::InvalidSchemaCompatibility),
+        }
+    }
+}
+
+impl Display for SchemaCompatibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaCompatibility::None => write!(f, "none"),
+            SchemaCompatibility::Backward => write!(f, "backward"),
+            SchemaCompatibility::Forward => write!(f, "forward"),
+            SchemaCompatibility::Full => write!(f, "full"),
+        }
+    }
+}
+
+impl SchemaCompatibility {
+    /// Returns the code of the compatibility.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            SchemaCompatibility::None => 0,
+            SchemaCompatibility::Backward => 1,
+            SchemaCompatibility::Forward => 2,
+            SchemaCompatibility::Full => 3,
+        }
+    }
+
+    /// Returns the compatibility from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            0 => Ok(SchemaCompatibility::None),
+            1 => Ok(SchemaCompatibility::Backward),
+            2 => Ok(SchemaCompatibility::Forward),
+            3 => Ok(SchemaCompatibility::Full),
+            _ => Err(IggyError::InvalidSchemaCompatibility),
+        }
+    }
+
+    /// Returns `true` if the new version must be able to read the messages of the latest one.
+    pub fn is_backward(&self) -> bool {
+        matches!(
+            self,
+            SchemaCompatibility::Backward | SchemaCompatibility::Full
+        )
+    }
+
+    /// Returns `true` if the latest version must be able to read the messages of the new one.
+    pub fn is_forward(&self) -> bool {
+        matches!(
+            self,
+            SchemaCompatibility::Forward | SchemaCompatibility::Full
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_parsed_from_str_and_code() {
+        for compatibility in [
+            SchemaCompatibility::None,
+            SchemaCompatibility::Backward,
+            SchemaCompatibility::Forward,
+            SchemaCompatibility::Full,
+        ] {
+            assert_eq!(
+                SchemaCompatibility::from_str(&compatibility.to_string()).unwrap(),
+                compatibility
+            );
+            assert_eq!(
+                SchemaCompatibility::from_code(compatibility.as_code()).unwrap(),
+                compatibility
+            );
+        }
+        assert!(SchemaCompatibility::from_str("transitive").is_err());
+        assert!(SchemaCompatibility::from_code(4).is_err());
+    }
+
+    #[test]
+    fn full_compatibility_should_be_backward_and_forward() {
+        assert!(SchemaCompatibility::Full.is_backward());
+        assert!(SchemaCompatibility::Full.is_forward());
+        assert!(!SchemaCompatibility::Backward.is_forward());
+        assert!(!SchemaCompatibility::Forward.is_backward());
+        assert!(!SchemaCompatibility::None.is_backward());
+    }
+}
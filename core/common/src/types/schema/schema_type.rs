@@ -0,0 +1,100 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `SchemaType` defines the format of the schema definition and of the validated message payloads.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Clone, Copy, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum SchemaType {
+    /// JSON Schema document, the payloads are JSON documents.
+    #[default]
+    Json,
+    /// Avro schema in its JSON form, the payloads are Avro binary encoded datums.
+    Avro,
+    /// Serialized protobuf `FileDescriptorSet`, the payloads are protobuf encoded messages
+    /// of the first message type declared in the last file of the set.
+    Protobuf,
+}
+
+impl FromStr for SchemaType {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "json" => Ok(SchemaType::Json),
+            "avro" => Ok(SchemaType::Avro),
+            "protobuf" => Ok(SchemaType::Protobuf),
+            _ => Err(IggyError::InvalidSchemaType),
+        }
+    }
+}
+
+impl Display for SchemaType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaType::Json => write!(f, "json"),
+            SchemaType::Avro => write!(f, "avro"),
+            SchemaType::Protobuf => write!(f, "protobuf"),
+        }
+    }
+}
+
+impl SchemaType {
+    /// Returns the code of the schema type.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            SchemaType::Json => 1,
+            SchemaType::Avro => 2,
+            SchemaType::Protobuf => 3,
+        }
+    }
+
+    /// Returns the schema type from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(SchemaType::Json),
+            2 => Ok(SchemaType::Avro),
+            3 => Ok(SchemaType::Protobuf),
+            _ => Err(IggyError::InvalidSchemaType),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_parsed_from_str_and_code() {
+        for schema_type in [SchemaType::Json, SchemaType::Avro, SchemaType::Protobuf] {
+            assert_eq!(
+                SchemaType::from_str(&schema_type.to_string()).unwrap(),
+                schema_type
+            );
+            assert_eq!(
+                SchemaType::from_code(schema_type.as_code()).unwrap(),
+                schema_type
+            );
+        }
+        assert!(SchemaType::from_str("xml").is_err());
+        assert!(SchemaType::from_code(0).is_err());
+    }
+}
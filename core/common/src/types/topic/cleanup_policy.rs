@@ -0,0 +1,105 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `CleanupPolicy` defines how the old messages in the topic are cleaned up.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Clone, Copy, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum CleanupPolicy {
+    /// The whole segments are deleted based on the message expiry and the max topic size.
+    #[default]
+    Delete,
+    /// Only the latest message per messages key is retained in the closed segments,
+    /// and the messages with an empty payload act as tombstones. The key is taken from
+    /// the `messages_key` partitioning, the messages sent with any other partitioning are never removed.
+    Compact,
+}
+
+impl FromStr for CleanupPolicy {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "delete" => Ok(CleanupPolicy::Delete),
+            "compact" => Ok(CleanupPolicy::Compact),
+            _ => Err(IggyError::InvalidCleanupPolicy),
+        }
+    }
+}
+
+impl Display for CleanupPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CleanupPolicy::Delete => write!(f, "delete"),
+            CleanupPolicy::Compact => write!(f, "compact"),
+        }
+    }
+}
+
+impl CleanupPolicy {
+    /// Returns the code of the cleanup policy.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            CleanupPolicy::Delete => 1,
+            CleanupPolicy::Compact => 2,
+        }
+    }
+
+    /// Returns the cleanup policy from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(CleanupPolicy::Delete),
+            2 => Ok(CleanupPolicy::Compact),
+            _ => Err(IggyError::InvalidCleanupPolicy),
+        }
+    }
+
+    /// Returns `true` if the topic should be compacted.
+    pub fn is_compact(&self) -> bool {
+        *self == CleanupPolicy::Compact
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_parsed_from_str() {
+        assert_eq!(
+            CleanupPolicy::from_str("delete").unwrap(),
+            CleanupPolicy::Delete
+        );
+        assert_eq!(
+            CleanupPolicy::from_str("Compact").unwrap(),
+            CleanupPolicy::Compact
+        );
+        assert!(CleanupPolicy::from_str("compacted").is_err());
+    }
+
+    #[test]
+    fn should_be_converted_from_and_to_code() {
+        for policy in [CleanupPolicy::Delete, CleanupPolicy::Compact] {
+            assert_eq!(CleanupPolicy::from_code(policy.as_code()).unwrap(), policy);
+        }
+        assert!(CleanupPolicy::from_code(0).is_err());
+        assert!(CleanupPolicy::from_code(3).is_err());
+    }
+}
@@ -16,6 +16,14 @@
  * under the License.
  */
 
+mod cleanup_policy;
+mod partitioning_hash;
+mod topic_options;
+
+pub use cleanup_policy::CleanupPolicy;
+pub use partitioning_hash::{PartitioningHash, PartitioningHashAlgorithm, PartitioningHashMode};
+pub use topic_options::TopicOptions;
+
 use crate::CompressionAlgorithm;
 use crate::Partition;
 use crate::utils::byte_size::IggyByteSize;
@@ -33,6 +41,10 @@ use serde::{Deserialize, Serialize};
 /// - `message_expiry`: the expiry of the messages in the topic.
 /// - `max_topic_size`: the maximum size of the topic.
 /// - `replication_factor`: replication factor for the topic.
+/// - `cleanup_policy`: the cleanup policy of the topic.
+/// - `partitioning_hash`: the hashing of the messages key to the partitions.
+/// - `segment_max_age`: the max age of the open segment, after which a new segment is rolled.
+/// - `max_partition_size`: the maximum size of each partition.
 /// - `messages_count`: the total number of messages in the topic.
 /// - `partitions_count`: the total number of partitions in the topic.
 #[derive(Debug, Serialize, Deserialize)]
@@ -54,6 +66,18 @@ pub struct Topic {
     pub max_topic_size: MaxTopicSize,
     /// Replication factor for the topic.
     pub replication_factor: u8,
+    /// The cleanup policy of the topic.
+    #[serde(default)]
+    pub cleanup_policy: CleanupPolicy,
+    /// The hashing of the messages key to the partitions.
+    #[serde(default)]
+    pub partitioning_hash: PartitioningHash,
+    /// The max age of the open segment, after which a new segment is rolled.
+    #[serde(default)]
+    pub segment_max_age: IggyExpiry,
+    /// The maximum size of each partition, the oldest segments of the partition are deleted above it.
+    #[serde(default)]
+    pub max_partition_size: MaxTopicSize,
     /// The total number of messages in the topic.
     pub messages_count: u64,
     /// The total number of partitions in the topic.
@@ -69,6 +93,10 @@ pub struct Topic {
 /// - `message_expiry`: the expiry of the messages in the topic.
 /// - `max_topic_size`: the maximum size of the topic.
 /// - `replication_factor`: replication factor for the topic.
+/// - `cleanup_policy`: the cleanup policy of the topic.
+/// - `partitioning_hash`: the hashing of the messages key to the partitions.
+/// - `segment_max_age`: the max age of the open segment, after which a new segment is rolled.
+/// - `max_partition_size`: the maximum size of each partition.
 /// - `messages_count`: the total number of messages in the topic.
 /// - `partitions_count`: the total number of partitions in the topic.
 /// - `partitions`: the collection of partitions in the topic.
@@ -91,6 +119,18 @@ pub struct TopicDetails {
     pub max_topic_size: MaxTopicSize,
     /// Replication factor for the topic.
     pub replication_factor: u8,
+    /// The cleanup policy of the topic.
+    #[serde(default)]
+    pub cleanup_policy: CleanupPolicy,
+    /// The hashing of the messages key to the partitions.
+    #[serde(default)]
+    pub partitioning_hash: PartitioningHash,
+    /// The max age of the open segment, after which a new segment is rolled.
+    #[serde(default)]
+    pub segment_max_age: IggyExpiry,
+    /// The maximum size of each partition, the oldest segments of the partition are deleted above it.
+    #[serde(default)]
+    pub max_partition_size: MaxTopicSize,
     /// The total number of messages in the topic.
     pub messages_count: u64,
     /// The total number of partitions in the topic.
@@ -0,0 +1,392 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+use twox_hash::{XxHash3_64, XxHash32};
+
+const MURMUR2_SEED: u32 = 0x9747_b28c;
+const MURMUR2_MULTIPLIER: u32 = 0x5bd1_e995;
+const MURMUR2_SHIFT: u32 = 24;
+const JUMP_HASH_MULTIPLIER: u64 = 2_862_933_555_777_941_757;
+
+/// `PartitioningHash` defines how the messages key is mapped to the partition of the topic
+/// when the messages are sent with the `messages_key` partitioning.
+///
+/// The hashing of each algorithm is frozen, as the code of the algorithm identifies its version,
+/// so the same key is always mapped to the same partition by the server and all the SDKs.
+/// Any new hashing will be added as a new algorithm with a new code.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Clone, Copy, Hash)]
+pub struct PartitioningHash {
+    /// The hash function calculated for the messages key.
+    pub algorithm: PartitioningHashAlgorithm,
+    /// The way the hash is mapped to the partition.
+    pub mode: PartitioningHashMode,
+}
+
+/// `PartitioningHashAlgorithm` is the hash function calculated for the messages key.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Clone, Copy, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum PartitioningHashAlgorithm {
+    /// XXH32 with the seed `0`, the original algorithm used by the server.
+    #[default]
+    XxHash32,
+    /// 32-bit MurmurHash2 with the seed `0x9747b28c` as used by the Kafka default partitioner.
+    Murmur2,
+    /// 64-bit XXH3 with the default seed and secret.
+    XxHash3,
+}
+
+/// `PartitioningHashMode` is the way the hash of the messages key is mapped to the partition.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Clone, Copy, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum PartitioningHashMode {
+    /// The hash modulo the partitions count, adding the partitions remaps most of the keys.
+    #[default]
+    Modulo,
+    /// The jump consistent hash, adding the partitions moves only the keys assigned to the new partitions.
+    Jump,
+}
+
+impl PartitioningHash {
+    /// Creates a new partitioning hash with the given algorithm and mode.
+    pub fn new(algorithm: PartitioningHashAlgorithm, mode: PartitioningHashMode) -> Self {
+        PartitioningHash { algorithm, mode }
+    }
+
+    /// Calculates the partition ID (starting from 1) for the messages key.
+    /// Returns `0` if the topic has no partitions.
+    ///
+    /// In the modulo mode, the XXH32 hash equal to `0` modulo the partitions count is mapped
+    /// to the last partition, as it was always done by the server, while the other algorithms
+    /// are mapped to the partition `hash % partitions_count + 1`, so the murmur2 keys land
+    /// in the same partitions as with the Kafka default partitioner.
+    pub fn calculate_partition_id(&self, messages_key: &[u8], partitions_count: u32) -> u32 {
+        if partitions_count == 0 {
+            return 0;
+        }
+
+        let hash = self.algorithm.hash(messages_key);
+        match self.mode {
+            PartitioningHashMode::Modulo => {
+                let partition_id = (hash % partitions_count as u64) as u32;
+                match self.algorithm {
+                    PartitioningHashAlgorithm::XxHash32 if partition_id == 0 => partitions_count,
+                    PartitioningHashAlgorithm::XxHash32 => partition_id,
+                    _ => partition_id + 1,
+                }
+            }
+            PartitioningHashMode::Jump => jump_consistent_hash(hash, partitions_count) + 1,
+        }
+    }
+
+    /// Returns the codes of the algorithm and the mode.
+    pub fn as_code(&self) -> [u8; 2] {
+        [self.algorithm.as_code(), self.mode.as_code()]
+    }
+
+    /// Returns the partitioning hash from the codes of the algorithm and the mode.
+    pub fn from_code(algorithm: u8, mode: u8) -> Result<Self, IggyError> {
+        Ok(PartitioningHash {
+            algorithm: PartitioningHashAlgorithm::from_code(algorithm)?,
+            mode: PartitioningHashMode::from_code(mode)?,
+        })
+    }
+}
+
+impl PartitioningHashAlgorithm {
+    /// Calculates the hash of the messages key, the 32-bit hashes are extended to `u64`.
+    /// The murmur2 hash is made positive by clearing the sign bit, the same as in Kafka.
+    pub fn hash(&self, messages_key: &[u8]) -> u64 {
+        match self {
+            PartitioningHashAlgorithm::XxHash32 => XxHash32::oneshot(0, messages_key) as u64,
+            PartitioningHashAlgorithm::Murmur2 => (murmur2(messages_key) & 0x7fff_ffff) as u64,
+            PartitioningHashAlgorithm::XxHash3 => XxHash3_64::oneshot(messages_key),
+        }
+    }
+
+    /// Returns the code of the algorithm.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            PartitioningHashAlgorithm::XxHash32 => 1,
+            PartitioningHashAlgorithm::Murmur2 => 2,
+            PartitioningHashAlgorithm::XxHash3 => 3,
+        }
+    }
+
+    /// Returns the algorithm from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(PartitioningHashAlgorithm::XxHash32),
+            2 => Ok(PartitioningHashAlgorithm::Murmur2),
+            3 => Ok(PartitioningHashAlgorithm::XxHash3),
+            _ => Err(IggyError::InvalidPartitioningHash),
+        }
+    }
+}
+
+impl PartitioningHashMode {
+    /// Returns the code of the mode.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            PartitioningHashMode::Modulo => 1,
+            PartitioningHashMode::Jump => 2,
+        }
+    }
+
+    /// Returns the mode from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(PartitioningHashMode::Modulo),
+            2 => Ok(PartitioningHashMode::Jump),
+            _ => Err(IggyError::InvalidPartitioningHash),
+        }
+    }
+}
+
+/// Parses the partitioning hash in the `algorithm[:mode]` format, e.g. `murmur2` or `xxhash3:jump`.
+impl FromStr for PartitioningHash {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (algorithm, mode) = match input.split_once(':') {
+            Some((algorithm, mode)) => (algorithm.parse()?, mode.parse()?),
+            None => (input.parse()?, PartitioningHashMode::default()),
+        };
+        Ok(PartitioningHash { algorithm, mode })
+    }
+}
+
+impl FromStr for PartitioningHashAlgorithm {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "xxhash32" => Ok(PartitioningHashAlgorithm::XxHash32),
+            "murmur2" => Ok(PartitioningHashAlgorithm::Murmur2),
+            "xxhash3" => Ok(PartitioningHashAlgorithm::XxHash3),
+            _ => Err(IggyError::InvalidPartitioningHash),
+        }
+    }
+}
+
+impl FromStr for PartitioningHashMode {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "modulo" => Ok(PartitioningHashMode::Modulo),
+            "jump" => Ok(PartitioningHashMode::Jump),
+            _ => Err(IggyError::InvalidPartitioningHash),
+        }
+    }
+}
+
+impl Display for PartitioningHash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.mode {
+            PartitioningHashMode::Modulo => write!(f, "{}", self.algorithm),
+            PartitioningHashMode::Jump => write!(f, "{}:{}", self.algorithm, self.mode),
+        }
+    }
+}
+
+impl Display for PartitioningHashAlgorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartitioningHashAlgorithm::XxHash32 => write!(f, "xxhash32"),
+            PartitioningHashAlgorithm::Murmur2 => write!(f, "murmur2"),
+            PartitioningHashAlgorithm::XxHash3 => write!(f, "xxhash3"),
+        }
+    }
+}
+
+impl Display for PartitioningHashMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartitioningHashMode::Modulo => write!(f, "modulo"),
+            PartitioningHashMode::Jump => write!(f, "jump"),
+        }
+    }
+}
+
+/// The 32-bit MurmurHash2 variant used by Kafka (`org.apache.kafka.common.utils.Utils.murmur2`).
+fn murmur2(data: &[u8]) -> u32 {
+    let mut hash = MURMUR2_SEED ^ data.len() as u32;
+    let mut chunks = data.chunks_exact(4);
+    for chunk in &mut chunks {
+        let mut k = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        k = k.wrapping_mul(MURMUR2_MULTIPLIER);
+        k ^= k >> MURMUR2_SHIFT;
+        k = k.wrapping_mul(MURMUR2_MULTIPLIER);
+        hash = hash.wrapping_mul(MURMUR2_MULTIPLIER);
+        hash ^= k;
+    }
+
+    let tail = chunks.remainder();
+    if tail.len() >= 3 {
+        hash ^= (tail[2] as u32) << 16;
+    }
+    if tail.len() >= 2 {
+        hash ^= (tail[1] as u32) << 8;
+    }
+    if !tail.is_empty() {
+        hash ^= tail[0] as u32;
+        hash = hash.wrapping_mul(MURMUR2_MULTIPLIER);
+    }
+
+    hash ^= hash >> 13;
+    hash = hash.wrapping_mul(MURMUR2_MULTIPLIER);
+    hash ^= hash >> 15;
+    hash
+}
+
+/// The jump consistent hash by Lamping and Veach, returns the bucket in the `0..buckets` range.
+fn jump_consistent_hash(mut key: u64, buckets: u32) -> u32 {
+    let mut bucket: i64 = -1;
+    let mut next: i64 = 0;
+    while next < buckets as i64 {
+        bucket = next;
+        key = key.wrapping_mul(JUMP_HASH_MULTIPLIER).wrapping_add(1);
+        next = ((bucket + 1) as f64 * ((1u64 << 31) as f64 / ((key >> 33) + 1) as f64)) as i64;
+    }
+    bucket as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn murmur2_should_match_kafka_hashes() {
+        let cases: [(&str, i32); 6] = [
+            ("21", -973932308),
+            ("foobar", -790332482),
+            ("a-little-bit-long-string", -985981536),
+            ("a-little-bit-longer-string", -1486304829),
+            (
+                "lkjh234lh9fiuh90y23oiuhsafujhadof229phr9h19h89h8",
+                -58897971,
+            ),
+            ("abc", 479470107),
+        ];
+        for (key, expected_hash) in cases {
+            assert_eq!(murmur2(key.as_bytes()) as i32, expected_hash);
+        }
+    }
+
+    #[test]
+    fn xxhash32_modulo_should_map_keys_as_before() {
+        let partitioning_hash = PartitioningHash::default();
+        for key in ["a", "b", "c", "key-1", "key-2", "key-3"] {
+            let hash = XxHash32::oneshot(0, key.as_bytes());
+            let expected_partition_id = match hash % 3 {
+                0 => 3,
+                partition_id => partition_id,
+            };
+            assert_eq!(
+                partitioning_hash.calculate_partition_id(key.as_bytes(), 3),
+                expected_partition_id
+            );
+        }
+    }
+
+    #[test]
+    fn murmur2_modulo_should_map_keys_as_kafka() {
+        let partitioning_hash = PartitioningHash::new(
+            PartitioningHashAlgorithm::Murmur2,
+            PartitioningHashMode::Modulo,
+        );
+        let hash = (-790332482i32 & 0x7fff_ffff) as u32;
+        assert_eq!(
+            partitioning_hash.calculate_partition_id(b"foobar", 10),
+            hash % 10 + 1
+        );
+    }
+
+    #[test]
+    fn partition_id_should_be_within_partitions_range() {
+        for algorithm in [
+            PartitioningHashAlgorithm::XxHash32,
+            PartitioningHashAlgorithm::Murmur2,
+            PartitioningHashAlgorithm::XxHash3,
+        ] {
+            for mode in [PartitioningHashMode::Modulo, PartitioningHashMode::Jump] {
+                let partitioning_hash = PartitioningHash::new(algorithm, mode);
+                for key in 0..1000u32 {
+                    let partition_id =
+                        partitioning_hash.calculate_partition_id(&key.to_le_bytes(), 7);
+                    assert!((1..=7).contains(&partition_id));
+                }
+                assert_eq!(partitioning_hash.calculate_partition_id(b"key", 0), 0);
+            }
+        }
+    }
+
+    #[test]
+    fn jump_mode_should_move_keys_only_to_new_partitions() {
+        let partitioning_hash = PartitioningHash::new(
+            PartitioningHashAlgorithm::XxHash3,
+            PartitioningHashMode::Jump,
+        );
+        let keys_count = 10_000u32;
+        let mut moved_keys = 0;
+        for key in 0..keys_count {
+            let key = key.to_le_bytes();
+            let before = partitioning_hash.calculate_partition_id(&key, 10);
+            let after = partitioning_hash.calculate_partition_id(&key, 11);
+            if before != after {
+                assert_eq!(after, 11);
+                moved_keys += 1;
+            }
+        }
+        assert!(moved_keys < keys_count / 5);
+    }
+
+    #[test]
+    fn should_be_parsed_from_str() {
+        assert_eq!(
+            PartitioningHash::from_str("xxhash32").unwrap(),
+            PartitioningHash::default()
+        );
+        assert_eq!(
+            PartitioningHash::from_str("Murmur2:jump").unwrap(),
+            PartitioningHash::new(
+                PartitioningHashAlgorithm::Murmur2,
+                PartitioningHashMode::Jump
+            )
+        );
+        assert!(PartitioningHash::from_str("murmur3").is_err());
+        assert!(PartitioningHash::from_str("xxhash3:ring").is_err());
+    }
+
+    #[test]
+    fn should_be_converted_from_and_to_code() {
+        let partitioning_hash = PartitioningHash::new(
+            PartitioningHashAlgorithm::XxHash3,
+            PartitioningHashMode::Jump,
+        );
+        let [algorithm, mode] = partitioning_hash.as_code();
+        assert_eq!(
+            PartitioningHash::from_code(algorithm, mode).unwrap(),
+            partitioning_hash
+        );
+        assert_eq!(partitioning_hash.to_string(), "xxhash3:jump");
+        assert!(PartitioningHash::from_code(0, 1).is_err());
+        assert!(PartitioningHash::from_code(1, 3).is_err());
+    }
+}
@@ -0,0 +1,45 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::utils::expiry::IggyExpiry;
+use crate::utils::topic_size::MaxTopicSize;
+use crate::{CleanupPolicy, PartitioningHash};
+use serde::{Deserialize, Serialize};
+
+/// `TopicOptions` groups the optional settings of the topic, which can be set when the topic is created or updated.
+/// The settings not provided explicitly keep their defaults, e.g.
+/// `TopicOptions { cleanup_policy: CleanupPolicy::Compact, ..Default::default() }`.
+/// It consists of the following fields:
+/// - `cleanup_policy`: the cleanup policy of the topic, either `delete` or `compact`.
+/// - `partitioning_hash`: the hashing of the messages key to the partitions.
+/// - `segment_max_age`: the max age of the open segment, after which a new segment is rolled.
+/// - `max_partition_size`: the maximum size of each partition.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+pub struct TopicOptions {
+    /// Cleanup policy of the topic, either `delete` or `compact`.
+    #[serde(default)]
+    pub cleanup_policy: CleanupPolicy,
+    /// Hashing of the messages key to the partitions, e.g. `murmur2` or `xxhash3:jump`.
+    #[serde(default)]
+    pub partitioning_hash: PartitioningHash,
+    /// Max age of the open segment, after which a new segment is rolled, if `NeverExpire` then segments are rolled only by size.
+    #[serde(default)]
+    pub segment_max_age: IggyExpiry,
+    /// Max partition size, if `Unlimited` then the oldest segments of the partition are never deleted by size.
+    #[serde(default)]
+    pub max_partition_size: MaxTopicSize,
+}
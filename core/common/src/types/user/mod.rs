@@ -17,4 +17,5 @@
 
 pub(crate) mod user_identity_info;
 pub(crate) mod user_info;
+pub(crate) mod user_quotas;
 pub(crate) mod user_status;
@@ -17,6 +17,7 @@
  */
 
 use crate::Permissions;
+use crate::UserQuotas;
 use crate::types::user::user_status::UserStatus;
 use crate::utils::timestamp::IggyTimestamp;
 use serde::{Deserialize, Serialize};
@@ -53,6 +54,8 @@ pub struct UserInfo {
 /// - `status`: the status of the user.
 /// - `username`: the username of the user.
 /// - `permissions`: the optional permissions of the user.
+/// - `quotas`: the optional quotas of the user.
+/// - `roles`: the names of the roles assigned to the user.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UserInfoDetails {
     /// The unique identifier (numeric) of the user.
@@ -65,4 +68,10 @@ pub struct UserInfoDetails {
     pub username: String,
     /// The optional permissions of the user.
     pub permissions: Option<Permissions>,
+    /// The optional quotas of the user.
+    #[serde(default)]
+    pub quotas: Option<UserQuotas>,
+    /// The names of the roles assigned to the user.
+    #[serde(default)]
+    pub roles: Vec<String>,
 }
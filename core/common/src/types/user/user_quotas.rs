@@ -0,0 +1,150 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::IggyByteSize;
+use crate::error::IggyError;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UserQuotas` represents the optional limits applied to all the clients of the user.
+/// It consists of the following fields:
+/// - `max_produced_bytes_per_second`: the maximum amount of bytes produced per second.
+/// - `max_produced_messages_per_second`: the maximum number of messages produced per second.
+/// - `max_consumed_bytes_per_second`: the maximum amount of bytes consumed per second.
+/// - `max_connections`: the maximum number of concurrent connections.
+///
+/// The missing value means that there's no limit.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Clone, Copy)]
+pub struct UserQuotas {
+    /// The maximum amount of bytes produced per second.
+    #[serde(default)]
+    pub max_produced_bytes_per_second: Option<u64>,
+    /// The maximum number of messages produced per second.
+    #[serde(default)]
+    pub max_produced_messages_per_second: Option<u64>,
+    /// The maximum amount of bytes consumed per second.
+    #[serde(default)]
+    pub max_consumed_bytes_per_second: Option<u64>,
+    /// The maximum number of concurrent connections.
+    #[serde(default)]
+    pub max_connections: Option<u32>,
+}
+
+impl UserQuotas {
+    /// The size of the serialized quotas in bytes.
+    pub const SIZE: usize = 8 + 8 + 8 + 4;
+
+    /// Returns `true` if none of the limits is set.
+    pub fn is_unlimited(&self) -> bool {
+        self.max_produced_bytes_per_second.is_none()
+            && self.max_produced_messages_per_second.is_none()
+            && self.max_consumed_bytes_per_second.is_none()
+            && self.max_connections.is_none()
+    }
+}
+
+impl BytesSerializable for UserQuotas {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(Self::SIZE);
+        bytes.put_u64_le(self.max_produced_bytes_per_second.unwrap_or_default());
+        bytes.put_u64_le(self.max_produced_messages_per_second.unwrap_or_default());
+        bytes.put_u64_le(self.max_consumed_bytes_per_second.unwrap_or_default());
+        bytes.put_u32_le(self.max_connections.unwrap_or_default());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        if bytes.len() != Self::SIZE {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let read_u64 = |position: usize| {
+            u64::from_le_bytes(
+                bytes[position..position + 8]
+                    .try_into()
+                    .expect("the slice is always 8 bytes long"),
+            )
+        };
+        let max_connections = u32::from_le_bytes(
+            bytes[24..28]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        Ok(UserQuotas {
+            max_produced_bytes_per_second: Some(read_u64(0)).filter(|value| *value > 0),
+            max_produced_messages_per_second: Some(read_u64(8)).filter(|value| *value > 0),
+            max_consumed_bytes_per_second: Some(read_u64(16)).filter(|value| *value > 0),
+            max_connections: Some(max_connections).filter(|value| *value > 0),
+        })
+    }
+}
+
+impl Display for UserQuotas {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bytes_rate = |value: Option<u64>| {
+            value.map_or("unlimited".to_string(), |value| {
+                format!("{}/s", IggyByteSize::from(value).as_human_string())
+            })
+        };
+        let messages_rate = |value: Option<u64>| {
+            value.map_or("unlimited".to_string(), |value| format!("{value}/s"))
+        };
+        let connections = self
+            .max_connections
+            .map_or("unlimited".to_string(), |value| value.to_string());
+        write!(
+            f,
+            "produced: {}, {} messages, consumed: {}, connections: {connections}",
+            bytes_rate(self.max_produced_bytes_per_second),
+            messages_rate(self.max_produced_messages_per_second),
+            bytes_rate(self.max_consumed_bytes_per_second),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_zero_as_unlimited() {
+        let quotas = UserQuotas {
+            max_produced_bytes_per_second: Some(1_000_000),
+            max_produced_messages_per_second: None,
+            max_consumed_bytes_per_second: Some(2_000_000),
+            max_connections: Some(5),
+        };
+
+        let bytes = quotas.to_bytes();
+        assert_eq!(bytes.len(), UserQuotas::SIZE);
+        assert_eq!(&bytes[8..16], &[0; 8]);
+
+        let deserialized = UserQuotas::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, quotas);
+        assert!(
+            UserQuotas::from_bytes(UserQuotas::default().to_bytes())
+                .unwrap()
+                .is_unlimited()
+        );
+    }
+}
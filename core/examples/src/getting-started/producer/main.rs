@@ -70,6 +70,7 @@ async fn init_system(client: &IggyClient) {
             Some(TOPIC_ID),
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            TopicOptions::default(),
         )
         .await
     {
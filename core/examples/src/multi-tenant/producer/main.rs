@@ -340,7 +340,13 @@ async fn create_stream_and_user(
         ..Default::default()
     };
     let user = client
-        .create_user(username, PASSWORD, UserStatus::Active, Some(permissions))
+        .create_user(
+            username,
+            PASSWORD,
+            UserStatus::Active,
+            Some(permissions),
+            None,
+        )
         .await?;
     info!(
         "Created user: {username} with ID: {}, with permissions for stream: {stream_name}",
@@ -93,6 +93,7 @@ pub async fn init_by_producer(args: &Args, client: &dyn Client) -> Result<(), Ig
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            TopicOptions::default(),
         )
         .await?;
     Ok(())
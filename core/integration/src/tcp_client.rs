@@ -25,6 +25,8 @@ use std::sync::Arc;
 pub struct TcpClientFactory {
     pub server_addr: String,
     pub nodelay: bool,
+    pub pipelining: bool,
+    pub connection_pool_size: u32,
 }
 
 #[async_trait]
@@ -33,6 +35,8 @@ impl ClientFactory for TcpClientFactory {
         let config = TcpClientConfig {
             server_address: self.server_addr.clone(),
             nodelay: self.nodelay,
+            pipelining: self.pipelining,
+            connection_pool_size: self.connection_pool_size.max(1),
             ..TcpClientConfig::default()
         };
         let client = TcpClient::create(Arc::new(config)).unwrap_or_else(|e| {
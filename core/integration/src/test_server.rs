@@ -480,6 +480,7 @@ pub async fn create_user(client: &IggyClient, username: &str) {
                 },
                 streams: None,
             }),
+            None,
         )
         .await
         .unwrap();
@@ -21,3 +21,4 @@ mod test_consumer_group_delete_command;
 mod test_consumer_group_get_command;
 mod test_consumer_group_help_command;
 mod test_consumer_group_list_command;
+mod test_consumer_group_reset_offsets_command;
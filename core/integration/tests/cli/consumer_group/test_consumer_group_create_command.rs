@@ -22,7 +22,7 @@ use crate::cli::common::{
 };
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
-use iggy::prelude::{Client, IggyExpiry, MaxTopicSize};
+use iggy::prelude::{Client, IggyExpiry, MaxTopicSize, TopicOptions};
 use predicates::str::diff;
 use serial_test::parallel;
 
@@ -101,6 +101,7 @@ impl IggyCmdTestCase for TestConsumerGroupCreateCmd {
                 Some(self.topic_id),
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                TopicOptions::default(),
             )
             .await;
         assert!(topic.is_ok());
@@ -131,7 +132,7 @@ impl IggyCmdTestCase for TestConsumerGroupCreateCmd {
         };
 
         let message = format!(
-            "Executing create consumer group: {}, name: {} for topic with ID: {} and stream with ID: {}\nConsumer group: {}, name: {} created for topic with ID: {} and stream with ID: {}\n",
+            "Executing create consumer group: {}, name: {}, assignment strategy: round_robin for topic with ID: {} and stream with ID: {}\nConsumer group: {}, name: {}, assignment strategy: round_robin created for topic with ID: {} and stream with ID: {}\n",
             group_id,
             self.group_name,
             topic_id,
@@ -274,6 +275,11 @@ Options:
   -g, --group-id <GROUP_ID>
           Consumer group ID to create
 
+  -a, --assignment-strategy <ASSIGNMENT_STRATEGY>
+          Strategy of assigning the partitions to the members, either "round_robin" or "cooperative_sticky"
+{CLAP_INDENT}
+          [default: round_robin]
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -301,8 +307,12 @@ Arguments:
   <NAME>       Consumer group name to create
 
 Options:
-  -g, --group-id <GROUP_ID>  Consumer group ID to create
-  -h, --help                 Print help (see more with '--help')
+  -g, --group-id <GROUP_ID>
+          Consumer group ID to create
+  -a, --assignment-strategy <ASSIGNMENT_STRATEGY>
+          Strategy of assigning the partitions to the members, either "round_robin" or "cooperative_sticky" [default: round_robin]
+  -h, --help
+          Print help (see more with '--help')
 "#,
             ),
         ))
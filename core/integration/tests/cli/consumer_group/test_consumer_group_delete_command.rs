@@ -22,9 +22,12 @@ use crate::cli::common::{
 };
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
+
 use iggy::prelude::Client;
+use iggy::prelude::ConsumerGroupAssignmentStrategy;
 use iggy::prelude::IggyExpiry;
 use iggy::prelude::MaxTopicSize;
+use iggy::prelude::TopicOptions;
 use predicates::str::diff;
 use serial_test::parallel;
 
@@ -104,6 +107,7 @@ impl IggyCmdTestCase for TestConsumerGroupDeleteCmd {
                 Some(self.topic_id),
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                TopicOptions::default(),
             )
             .await;
         assert!(topic.is_ok());
@@ -114,6 +118,7 @@ impl IggyCmdTestCase for TestConsumerGroupDeleteCmd {
                 &self.topic_id.try_into().unwrap(),
                 &self.group_name,
                 Some(self.group_id),
+                ConsumerGroupAssignmentStrategy::default(),
             )
             .await;
         assert!(consumer_group.is_ok());
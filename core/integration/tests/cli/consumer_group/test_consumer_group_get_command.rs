@@ -22,9 +22,12 @@ use crate::cli::common::{
 };
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
+
 use iggy::prelude::Client;
+use iggy::prelude::ConsumerGroupAssignmentStrategy;
 use iggy::prelude::IggyExpiry;
 use iggy::prelude::MaxTopicSize;
+use iggy::prelude::TopicOptions;
 use predicates::str::{contains, starts_with};
 use serial_test::parallel;
 
@@ -104,6 +107,7 @@ impl IggyCmdTestCase for TestConsumerGroupGetCmd {
                 Some(self.topic_id),
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                TopicOptions::default(),
             )
             .await;
         assert!(topic.is_ok());
@@ -114,6 +118,7 @@ impl IggyCmdTestCase for TestConsumerGroupGetCmd {
                 &self.topic_id.try_into().unwrap(),
                 &self.group_name,
                 self.group_id.into(),
+                ConsumerGroupAssignmentStrategy::default(),
             )
             .await;
         assert!(consumer_group.is_ok());
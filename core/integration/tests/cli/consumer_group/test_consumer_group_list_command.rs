@@ -22,9 +22,12 @@ use crate::cli::common::{
 };
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
+
 use iggy::prelude::Client;
+use iggy::prelude::ConsumerGroupAssignmentStrategy;
 use iggy::prelude::IggyExpiry;
 use iggy::prelude::MaxTopicSize;
+use iggy::prelude::TopicOptions;
 use predicates::str::{contains, starts_with};
 use serial_test::parallel;
 
@@ -101,6 +104,7 @@ impl IggyCmdTestCase for TestConsumerGroupListCmd {
                 Some(self.topic_id),
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                TopicOptions::default(),
             )
             .await;
         assert!(topic.is_ok());
@@ -111,6 +115,7 @@ impl IggyCmdTestCase for TestConsumerGroupListCmd {
                 &self.topic_id.try_into().unwrap(),
                 &self.consumer_group_name,
                 self.consumer_group_id.into(),
+                ConsumerGroupAssignmentStrategy::default(),
             )
             .await;
         assert!(consumer_group.is_ok());
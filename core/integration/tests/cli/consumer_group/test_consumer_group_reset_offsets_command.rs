@@ -0,0 +1,289 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{
+    CLAP_INDENT, IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::prelude::*;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::str::FromStr;
+
+struct TestConsumerGroupResetOffsetsCmd {
+    stream_id: u32,
+    topic_id: u32,
+    group_id: u32,
+    messages_count: u32,
+    args: Vec<String>,
+    dry_run: bool,
+    expected_offset: Option<u64>,
+}
+
+impl TestConsumerGroupResetOffsetsCmd {
+    fn new(args: &[&str], dry_run: bool, expected_offset: Option<u64>) -> Self {
+        Self {
+            stream_id: 1,
+            topic_id: 2,
+            group_id: 3,
+            messages_count: 10,
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            dry_run,
+            expected_offset,
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestConsumerGroupResetOffsetsCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client.create_stream("stream", Some(self.stream_id)).await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(
+                &self.stream_id.try_into().unwrap(),
+                "topic",
+                1,
+                Default::default(),
+                None,
+                Some(self.topic_id),
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+                TopicOptions::default(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let consumer_group = client
+            .create_consumer_group(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+                "consumer-group",
+                Some(self.group_id),
+                ConsumerGroupAssignmentStrategy::default(),
+            )
+            .await;
+        assert!(consumer_group.is_ok());
+
+        let mut messages = (1..=self.messages_count)
+            .filter_map(|id| IggyMessage::from_str(format!("Test message {id}").as_str()).ok())
+            .collect::<Vec<_>>();
+        let send_status = client
+            .send_messages(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+                &Partitioning::partition_id(1),
+                &mut messages,
+            )
+            .await;
+        assert!(send_status.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("consumer-group")
+            .arg("reset-offsets")
+            .arg(format!("{}", self.stream_id))
+            .arg(format!("{}", self.topic_id))
+            .arg(format!("{}", self.group_id))
+            .args(self.args.clone())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let message = if self.dry_run {
+            format!(
+                "Dry run, the offsets of consumer group with ID: {} were not changed",
+                self.group_id
+            )
+        } else {
+            format!(
+                "Offsets of consumer group with ID: {} were reset",
+                self.group_id
+            )
+        };
+
+        command_state
+            .success()
+            .stdout(starts_with(format!(
+                "Executing reset offsets of consumer group with ID: {} for topic with ID: {} and stream with ID: {}",
+                self.group_id, self.topic_id, self.stream_id
+            )))
+            .stdout(contains(message))
+            .stdout(contains("Old offset"))
+            .stdout(contains("New offset"));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let offset = client
+            .get_consumer_offset(
+                &Consumer::group(self.group_id.try_into().unwrap()),
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+                Some(1),
+            )
+            .await;
+        assert!(offset.is_ok());
+        assert_eq!(
+            offset.unwrap().map(|offset| offset.stored_offset),
+            self.expected_offset
+        );
+
+        let stream = client
+            .delete_stream(&self.stream_id.try_into().unwrap())
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    let test_parameters = vec![
+        (vec!["--to-latest", "--dry-run"], true, None),
+        (vec!["--to-latest"], false, Some(9)),
+        (vec!["--to-earliest"], false, None),
+        (vec!["--to-timestamp", "0"], false, None),
+        (vec!["--shift-by", "5"], false, Some(4)),
+        (vec!["--shift-by", "-5", "--force"], false, None),
+    ];
+
+    iggy_cmd_test.setup().await;
+    for (args, dry_run, expected_offset) in test_parameters {
+        iggy_cmd_test
+            .execute_test(TestConsumerGroupResetOffsetsCmd::new(
+                &args,
+                dry_run,
+                expected_offset,
+            ))
+            .await;
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["consumer-group", "reset-offsets", "--help"],
+            format!(
+                r#"Reset offsets of consumer group with given ID for given stream ID and topic ID
+
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+Consumer group ID can be specified as a consumer group name or ID
+Offsets cannot be reset while the consumer group has members, unless forced
+
+Examples:
+ iggy consumer-group reset-offsets 1 2 3 --to-earliest
+ iggy consumer-group reset-offsets stream topic group --to-latest --dry-run
+ iggy consumer-group reset-offsets stream topic group --to-timestamp 1735689600000000
+ iggy consumer-group reset-offsets stream topic group --shift-by -100 --force
+ iggy consumer-group reset-offsets stream topic group --from-csv offsets.csv
+
+{USAGE_PREFIX} consumer-group reset-offsets [OPTIONS] <--to-earliest|--to-latest|--to-timestamp <TO_TIMESTAMP>|--shift-by <SHIFT_BY>|--from-csv <FROM_CSV>> <STREAM_ID> <TOPIC_ID> <GROUP_ID>
+
+Arguments:
+  <STREAM_ID>
+          Stream ID to reset consumer group offsets
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TOPIC_ID>
+          Topic ID to reset consumer group offsets
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+  <GROUP_ID>
+          Consumer group ID to reset offsets
+{CLAP_INDENT}
+          Consumer group ID can be specified as a consumer group name or ID
+
+Options:
+      --to-earliest
+          Reset offsets to the first available message in each partition
+
+      --to-latest
+          Reset offsets to the end of each partition, skipping all the existing messages
+
+      --to-timestamp <TO_TIMESTAMP>
+          Reset offsets to the first message with given or later timestamp
+{CLAP_INDENT}
+          Timestamp must be specified in microseconds since the Unix epoch
+
+      --shift-by <SHIFT_BY>
+          Shift offsets by given number of messages, negative value moves them back
+
+      --from-csv <FROM_CSV>
+          Reset offsets to the values from the CSV file
+{CLAP_INDENT}
+          Each line of the file contains the partition ID and the offset,
+          e.g. "1,100", the partitions missing in the file are left intact
+
+  -d, --dry-run
+          Only print the old and new offsets without storing them
+
+  -f, --force
+          Reset offsets even if the consumer group has members
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["consumer-group", "reset-offsets", "-h"],
+            format!(
+                r#"Reset offsets of consumer group with given ID for given stream ID and topic ID
+
+{USAGE_PREFIX} consumer-group reset-offsets [OPTIONS] <--to-earliest|--to-latest|--to-timestamp <TO_TIMESTAMP>|--shift-by <SHIFT_BY>|--from-csv <FROM_CSV>> <STREAM_ID> <TOPIC_ID> <GROUP_ID>
+
+Arguments:
+  <STREAM_ID>  Stream ID to reset consumer group offsets
+  <TOPIC_ID>   Topic ID to reset consumer group offsets
+  <GROUP_ID>   Consumer group ID to reset offsets
+
+Options:
+      --to-earliest                  Reset offsets to the first available message in each partition
+      --to-latest                    Reset offsets to the end of each partition, skipping all the existing messages
+      --to-timestamp <TO_TIMESTAMP>  Reset offsets to the first message with given or later timestamp
+      --shift-by <SHIFT_BY>          Shift offsets by given number of messages, negative value moves them back
+      --from-csv <FROM_CSV>          Reset offsets to the values from the CSV file
+  -d, --dry-run                      Only print the old and new offsets without storing them
+  -f, --force                        Reset offsets even if the consumer group has members
+  -h, --help                         Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -112,6 +112,7 @@ impl IggyCmdTestCase for TestConsumerOffsetGetCmd {
                 Some(self.topic_id),
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                TopicOptions::default(),
             )
             .await;
         assert!(topic.is_ok());
@@ -144,6 +145,7 @@ impl IggyCmdTestCase for TestConsumerOffsetGetCmd {
                 &self.topic_id.try_into().unwrap(),
                 Some(self.partition_id),
                 self.stored_offset,
+                None,
             )
             .await;
         assert!(offset.is_ok());
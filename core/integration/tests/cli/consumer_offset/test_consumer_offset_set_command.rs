@@ -112,6 +112,7 @@ impl IggyCmdTestCase for TestConsumerOffsetSetCmd {
                 Some(self.topic_id),
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                TopicOptions::default(),
             )
             .await;
         assert!(topic.is_ok());
@@ -309,7 +310,7 @@ Examples:
  iggy consumer-offset set 1 stream topic 1 100
  iggy consumer-offset set consumer stream topic 1 100
 
-{USAGE_PREFIX} consumer-offset set <CONSUMER_ID> <STREAM_ID> <TOPIC_ID> <PARTITION_ID> <OFFSET>
+{USAGE_PREFIX} consumer-offset set [OPTIONS] <CONSUMER_ID> <STREAM_ID> <TOPIC_ID> <PARTITION_ID> <OFFSET>
 
 Arguments:
   <CONSUMER_ID>
@@ -334,6 +335,9 @@ Arguments:
           Offset to set
 
 Options:
+  -m, --metadata <METADATA>
+          Optional metadata stored along with the offset, e.g. a processing checkpoint
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -353,7 +357,7 @@ pub async fn should_short_help_match() {
             format!(
                 r#"Set the offset of a consumer for a given partition on the server
 
-{USAGE_PREFIX} consumer-offset set <CONSUMER_ID> <STREAM_ID> <TOPIC_ID> <PARTITION_ID> <OFFSET>
+{USAGE_PREFIX} consumer-offset set [OPTIONS] <CONSUMER_ID> <STREAM_ID> <TOPIC_ID> <PARTITION_ID> <OFFSET>
 
 Arguments:
   <CONSUMER_ID>   Regular consumer for which the offset is set
@@ -363,7 +367,8 @@ Arguments:
   <OFFSET>        Offset to set
 
 Options:
-  -h, --help  Print help (see more with '--help')
+  -m, --metadata <METADATA>  Optional metadata stored along with the offset, e.g. a processing checkpoint
+  -h, --help                 Print help (see more with '--help')
 "#,
             ),
         ))
@@ -43,11 +43,14 @@ Commands:
   snapshot         collect iggy server troubleshooting data
   pat              personal access token operations
   user             user operations [aliases: u]
+  role             role operations [aliases: r]
   client           client operations [aliases: c]
   consumer-group   consumer group operations [aliases: g]
   consumer-offset  consumer offset operations [aliases: o]
+  schema           schema registry operations [aliases: sc]
   message          message operations [aliases: m]
   context          context operations [aliases: ctx]
+  metadata         metadata export and apply operations [aliases: md]
   login            login to Iggy server [aliases: li]
   logout           logout from Iggy server [aliases: lo]
   help             Print this message or the help of the given subcommand(s)
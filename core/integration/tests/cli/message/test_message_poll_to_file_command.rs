@@ -111,6 +111,7 @@ impl IggyCmdTestCase for TestMessagePollToFileCmd<'_> {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                TopicOptions::default(),
             )
             .await;
         assert!(topic.is_ok());
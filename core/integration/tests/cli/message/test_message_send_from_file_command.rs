@@ -94,6 +94,7 @@ impl IggyCmdTestCase for TestMessageSendFromFileCmd<'_> {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                TopicOptions::default(),
             )
             .await;
         assert!(topic.is_ok());
@@ -0,0 +1,203 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::prelude::{Client, Identifier};
+use predicates::str::diff;
+use serial_test::parallel;
+use std::io::Write;
+use tempfile::NamedTempFile;
+
+const METADATA: &str = r#"
+[[streams]]
+id = 5
+name = "inventory"
+
+[[streams.topics]]
+id = 1
+name = "items"
+partitions_count = 2
+message_expiry = "1day"
+
+[[streams.topics.consumer_groups]]
+id = 3
+name = "stock"
+
+[[roles]]
+name = "auditor"
+
+[[roles.rules]]
+effect = "allow"
+resource = "stream:*"
+actions = ["read_stream"]
+
+[[users]]
+id = 2
+username = "inventory"
+password = "secret"
+roles = ["auditor"]
+"#;
+
+const LEGACY_STREAM_ID: u32 = 7;
+const LEGACY_STREAM_NAME: &str = "legacy";
+
+struct TestMetadataApplyCmd {
+    file: NamedTempFile,
+    dry_run: bool,
+    prune: bool,
+}
+
+impl TestMetadataApplyCmd {
+    fn new(dry_run: bool, prune: bool) -> Self {
+        let mut file = tempfile::Builder::new().suffix(".toml").tempfile().unwrap();
+        file.write_all(METADATA.as_bytes()).unwrap();
+        Self {
+            file,
+            dry_run,
+            prune,
+        }
+    }
+
+    fn path(&self) -> String {
+        self.file.path().to_str().unwrap().to_string()
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut args = vec![self.path()];
+        if self.dry_run {
+            args.push(String::from("--dry-run"));
+        }
+        if self.prune {
+            args.push(String::from("--prune"));
+        }
+        args
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestMetadataApplyCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(LEGACY_STREAM_NAME, Some(LEGACY_STREAM_ID))
+            .await;
+        assert!(stream.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("metadata")
+            .arg("apply")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let action = if self.dry_run { "plan" } else { "apply" };
+        let operations = if self.prune { 7 } else { 6 };
+        let mut message = format!("Executing {action} metadata from file: {}\n", self.path());
+        if !self.prune {
+            message.push_str(
+                "Skipped 1 deletion(s) of the resources not present in the file, use --prune to apply them\n",
+            );
+        }
+        message.push_str(&format!("Plan with {operations} operation(s):\n"));
+        if self.prune {
+            message.push_str(&format!("  delete stream: {LEGACY_STREAM_NAME}\n"));
+        }
+        message.push_str(
+            "  create role: auditor\n\
+            \x20 create stream: inventory (ID: 5)\n\
+            \x20 create topic: items (ID: 1) with 2 partitions in stream: inventory\n\
+            \x20 create consumer group: stock (ID: 3) in topic: items, stream: inventory\n\
+            \x20 create user: inventory (ID: 2)\n\
+            \x20 assign roles: [auditor] to user: inventory\n",
+        );
+        if !self.dry_run {
+            message.push_str(&format!("Applied {operations} operation(s)\n"));
+        }
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let metadata = client.export_metadata(false).await.unwrap();
+        let legacy_stream = metadata
+            .streams
+            .iter()
+            .find(|stream| stream.name == LEGACY_STREAM_NAME);
+        if self.prune && !self.dry_run {
+            assert!(legacy_stream.is_none());
+        } else {
+            assert!(legacy_stream.is_some());
+            let stream_deleted = client
+                .delete_stream(&Identifier::named(LEGACY_STREAM_NAME).unwrap())
+                .await;
+            assert!(stream_deleted.is_ok());
+        }
+
+        if self.dry_run {
+            assert_eq!(metadata.streams.len(), 1);
+            assert!(metadata.roles.is_empty());
+            assert_eq!(metadata.users.len(), 1);
+            return;
+        }
+
+        let stream = metadata
+            .streams
+            .iter()
+            .find(|stream| stream.name == "inventory")
+            .unwrap();
+        assert_eq!(stream.id, 5);
+        assert_eq!(stream.topics.len(), 1);
+        assert_eq!(stream.topics[0].partitions_count, 2);
+        assert_eq!(stream.topics[0].consumer_groups.len(), 1);
+        assert_eq!(metadata.roles.len(), 1);
+        assert_eq!(metadata.users.len(), 2);
+        assert_eq!(metadata.users[1].roles, vec![String::from("auditor")]);
+
+        let user_deleted = client
+            .delete_user(&Identifier::named("inventory").unwrap())
+            .await;
+        assert!(user_deleted.is_ok());
+        let role_deleted = client.delete_role("auditor").await;
+        assert!(role_deleted.is_ok());
+        let stream_deleted = client
+            .delete_stream(&Identifier::named("inventory").unwrap())
+            .await;
+        assert!(stream_deleted.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestMetadataApplyCmd::new(true, false))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestMetadataApplyCmd::new(false, false))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestMetadataApplyCmd::new(false, true))
+        .await;
+}
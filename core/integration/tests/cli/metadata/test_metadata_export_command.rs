@@ -0,0 +1,109 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::prelude::{Client, Identifier};
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+
+struct TestMetadataExportCmd {
+    stream_name: String,
+    format: Option<String>,
+    expected_content: String,
+}
+
+impl TestMetadataExportCmd {
+    fn new(stream_name: &str, format: Option<&str>, expected_content: String) -> Self {
+        Self {
+            stream_name: stream_name.to_string(),
+            format: format.map(String::from),
+            expected_content,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        match &self.format {
+            Some(format) => vec![String::from("--format"), format.clone()],
+            None => vec![],
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestMetadataExportCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client.create_stream(&self.stream_name, Some(1)).await;
+        assert!(stream.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("metadata")
+            .arg("export")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let format = self.format.as_deref().unwrap_or("toml");
+        command_state
+            .success()
+            .stdout(starts_with(format!(
+                "Executing export metadata in format: {format}\n"
+            )))
+            .stdout(contains(self.expected_content.clone()));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let deleted = client
+            .delete_stream(&Identifier::named(&self.stream_name).unwrap())
+            .await;
+        assert!(deleted.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestMetadataExportCmd::new(
+            "orders",
+            None,
+            String::from("[[streams]]\nid = 1\nname = \"orders\"\n"),
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestMetadataExportCmd::new(
+            "payments",
+            Some("json"),
+            String::from("\"name\": \"payments\""),
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestMetadataExportCmd::new(
+            "invoices",
+            Some("yaml"),
+            String::from("- id: 1\n  name: invoices\n"),
+        ))
+        .await;
+}
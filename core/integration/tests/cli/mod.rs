@@ -23,8 +23,11 @@ mod consumer_offset;
 mod context;
 mod general;
 mod message;
+mod metadata;
 mod partition;
 mod personal_access_token;
+mod role;
+mod schema;
 mod stream;
 mod system;
 mod topic;
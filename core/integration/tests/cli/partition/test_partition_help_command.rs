@@ -37,6 +37,8 @@ Commands:
           and stream ID based on the given count. [aliases: c]
   delete  Delete partitions for the specified topic ID
           and stream ID based on the given count. [aliases: d]
+  move    Move partition with given ID for the specified topic ID
+          and stream ID to another data directory configured on the server. [aliases: m]
   help    Print this message or the help of the given subcommand(s)
 
 Options:
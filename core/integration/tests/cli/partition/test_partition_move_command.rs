@@ -0,0 +1,278 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{
+    CLAP_INDENT, IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestStreamId,
+    TestTopicId, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+
+use iggy::prelude::Client;
+use iggy::prelude::IggyExpiry;
+use iggy::prelude::MaxTopicSize;
+use iggy::prelude::TopicOptions;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+
+struct TestPartitionMoveCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    partitions_count: u32,
+    partition_id: u32,
+    data_directory: String,
+    using_stream_id: TestStreamId,
+    using_topic_id: TestTopicId,
+}
+
+impl TestPartitionMoveCmd {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        stream_id: u32,
+        stream_name: String,
+        topic_id: u32,
+        topic_name: String,
+        partitions_count: u32,
+        partition_id: u32,
+        data_directory: String,
+        using_stream_id: TestStreamId,
+        using_topic_id: TestTopicId,
+    ) -> Self {
+        Self {
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            partitions_count,
+            partition_id,
+            data_directory,
+            using_stream_id,
+            using_topic_id,
+        }
+    }
+
+    fn get_stream_id(&self) -> String {
+        match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        }
+    }
+
+    fn get_topic_id(&self) -> String {
+        match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        vec![
+            self.get_stream_id(),
+            self.get_topic_id(),
+            format!("{}", self.partition_id),
+            self.data_directory.clone(),
+        ]
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestPartitionMoveCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&self.stream_name, self.stream_id.into())
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_name,
+                self.partitions_count,
+                Default::default(),
+                None,
+                Some(self.topic_id),
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+                TopicOptions::default(),
+            )
+            .await;
+        assert!(topic.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("partition")
+            .arg("move")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let message = format!(
+            "Executing move partition with ID: {} for topic with ID: {} and stream with ID: {} to data directory: {}\n",
+            self.partition_id,
+            self.get_topic_id(),
+            self.get_stream_id(),
+            self.data_directory
+        );
+
+        command_state
+            .failure()
+            .stdout(starts_with(message))
+            .stderr(contains(format!(
+                "Problem moving partition with ID: {} for topic with ID: {} and stream with ID: {} to data directory: {}",
+                self.partition_id,
+                self.get_topic_id(),
+                self.get_stream_id(),
+                self.data_directory
+            )));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let topic = client
+            .get_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+            )
+            .await;
+        assert!(topic.is_ok());
+        let topic_details = topic.unwrap().expect("Failed to get topic");
+        assert_eq!(topic_details.partitions_count, self.partitions_count);
+
+        let stream = client
+            .delete_stream(&self.stream_id.try_into().unwrap())
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_fail_for_not_configured_data_directory() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestPartitionMoveCmd::new(
+            1,
+            String::from("main"),
+            1,
+            String::from("sync"),
+            3,
+            1,
+            String::from("/mnt/disk2/iggy"),
+            TestStreamId::Numeric,
+            TestTopicId::Numeric,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestPartitionMoveCmd::new(
+            2,
+            String::from("production"),
+            5,
+            String::from("test"),
+            2,
+            2,
+            String::from("not-configured"),
+            TestStreamId::Named,
+            TestTopicId::Named,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["partition", "move", "--help"],
+            format!(
+                r#"Move partition with given ID for the specified topic ID
+and stream ID to another data directory configured on the server.
+
+The partition stays available while its segments are copied,
+only the appends are paused briefly to switch over to the new directory.
+
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+
+Examples
+ iggy partition move 1 1 1 /mnt/disk2/iggy
+ iggy partition move prod sensor 2 /mnt/disk3/iggy
+
+{USAGE_PREFIX} partition move <STREAM_ID> <TOPIC_ID> <PARTITION_ID> <DATA_DIRECTORY>
+
+Arguments:
+  <STREAM_ID>
+          Stream ID of the partition to move
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TOPIC_ID>
+          Topic ID of the partition to move
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+  <PARTITION_ID>
+          Partition ID to move
+
+  <DATA_DIRECTORY>
+          Data directory to move the partition to
+{CLAP_INDENT}
+          Must be either the system path or one of the data directories configured on the server
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["partition", "move", "-h"],
+            format!(
+                r#"Move partition with given ID for the specified topic ID
+and stream ID to another data directory configured on the server.
+
+{USAGE_PREFIX} partition move <STREAM_ID> <TOPIC_ID> <PARTITION_ID> <DATA_DIRECTORY>
+
+Arguments:
+  <STREAM_ID>       Stream ID of the partition to move
+  <TOPIC_ID>        Topic ID of the partition to move
+  <PARTITION_ID>    Partition ID to move
+  <DATA_DIRECTORY>  Data directory to move the partition to
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
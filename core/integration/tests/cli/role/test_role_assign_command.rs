@@ -0,0 +1,119 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::prelude::{
+    Client, Identifier, ResourcePattern, RoleAction, RoleRule, UserId, UserStatus,
+};
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestRoleAssignCmd {
+    username: String,
+    roles: Vec<String>,
+    user_id: Option<UserId>,
+}
+
+impl TestRoleAssignCmd {
+    fn new(username: String, roles: Vec<String>) -> Self {
+        Self {
+            username,
+            roles,
+            user_id: None,
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestRoleAssignCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        for role in &self.roles {
+            let created = client
+                .create_role(
+                    role,
+                    vec![RoleRule::allow(
+                        ResourcePattern::stream(role),
+                        &[RoleAction::ReadStream],
+                    )],
+                )
+                .await;
+            assert!(created.is_ok());
+        }
+        let user = client
+            .create_user(&self.username, "secret", UserStatus::Active, None, None)
+            .await;
+        assert!(user.is_ok());
+        self.user_id = Some(user.unwrap().id);
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("role")
+            .arg("assign")
+            .arg(self.username.clone())
+            .args(self.roles.clone())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let roles = self.roles.join(", ");
+        let message = format!(
+            "Executing assign roles: [{roles}] to user with ID: {}\nRoles: [{roles}] assigned to user with ID: {}\n",
+            self.username, self.username
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let user_id = Identifier::numeric(self.user_id.unwrap()).unwrap();
+        let user = client.get_user(&user_id).await;
+        assert!(user.is_ok());
+        let user = user.unwrap().expect("User not found");
+        assert_eq!(user.roles, self.roles);
+
+        let deleted = client.delete_user(&user_id).await;
+        assert!(deleted.is_ok());
+        for role in &self.roles {
+            let deleted = client.delete_role(role).await;
+            assert!(deleted.is_ok());
+        }
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestRoleAssignCmd::new(
+            String::from("tenant-user"),
+            vec![String::from("tenant"), String::from("reader")],
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestRoleAssignCmd::new(
+            String::from("no-roles-user"),
+            vec![],
+        ))
+        .await;
+}
@@ -0,0 +1,130 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::prelude::{Client, ResourcePattern, RoleAction, RoleRule};
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestRoleCreateCmd {
+    name: String,
+    allow: Vec<String>,
+    deny: Vec<String>,
+    expected_rules: Vec<RoleRule>,
+}
+
+impl TestRoleCreateCmd {
+    fn new(
+        name: String,
+        allow: Vec<String>,
+        deny: Vec<String>,
+        expected_rules: Vec<RoleRule>,
+    ) -> Self {
+        Self {
+            name,
+            allow,
+            deny,
+            expected_rules,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut args = vec![self.name.clone()];
+        for rule in &self.allow {
+            args.push(String::from("--allow"));
+            args.push(rule.clone());
+        }
+        for rule in &self.deny {
+            args.push(String::from("--deny"));
+            args.push(rule.clone());
+        }
+        args
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestRoleCreateCmd {
+    async fn prepare_server_state(&mut self, _client: &dyn Client) {}
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("role")
+            .arg("create")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let message = format!(
+            "Executing create role with name: {}\nRole with name: {} created\n",
+            self.name, self.name
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let roles = client.get_roles().await;
+        assert!(roles.is_ok());
+        let roles = roles.unwrap();
+        assert_eq!(roles.len(), 1);
+        assert_eq!(roles[0].name, self.name);
+        assert_eq!(roles[0].rules, self.expected_rules);
+
+        let deleted = client.delete_role(&self.name).await;
+        assert!(deleted.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestRoleCreateCmd::new(
+            String::from("tenant"),
+            vec![String::from("stream:tenant-*=read_stream,send_messages")],
+            vec![],
+            vec![RoleRule::allow(
+                ResourcePattern::stream("tenant-*"),
+                &[RoleAction::ReadStream, RoleAction::SendMessages],
+            )],
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestRoleCreateCmd::new(
+            String::from("reader"),
+            vec![String::from("topic:*=poll_messages")],
+            vec![String::from("stream:internal/topic:audit-?=poll_messages")],
+            vec![
+                RoleRule::allow(
+                    ResourcePattern::topic("*", "*"),
+                    &[RoleAction::PollMessages],
+                ),
+                RoleRule::deny(
+                    ResourcePattern::topic("internal", "audit-?"),
+                    &[RoleAction::PollMessages],
+                ),
+            ],
+        ))
+        .await;
+}
@@ -0,0 +1,84 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::prelude::{Client, ResourcePattern, RoleAction, RoleRule};
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestRoleDeleteCmd {
+    name: String,
+}
+
+impl TestRoleDeleteCmd {
+    fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestRoleDeleteCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let role = client
+            .create_role(
+                &self.name,
+                vec![RoleRule::allow(
+                    ResourcePattern::stream("*"),
+                    &[RoleAction::ReadStream],
+                )],
+            )
+            .await;
+        assert!(role.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("role")
+            .arg("delete")
+            .arg(self.name.clone())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let message = format!(
+            "Executing delete role with name: {}\nRole with name: {} deleted\n",
+            self.name, self.name
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let roles = client.get_roles().await;
+        assert!(roles.is_ok());
+        assert!(roles.unwrap().is_empty());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestRoleDeleteCmd::new(String::from("tenant")))
+        .await;
+}
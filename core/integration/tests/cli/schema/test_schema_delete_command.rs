@@ -0,0 +1,120 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use bytes::Bytes;
+use iggy::prelude::{
+    Client, CompressionAlgorithm, Identifier, IggyExpiry, MaxTopicSize, SchemaCompatibility,
+    SchemaType, TopicOptions,
+};
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestSchemaDeleteCmd {
+    stream_name: String,
+    topic_name: String,
+    schema_id: u32,
+}
+
+impl TestSchemaDeleteCmd {
+    fn new(stream_name: String, topic_name: String) -> Self {
+        Self {
+            stream_name,
+            topic_name,
+            schema_id: 0,
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestSchemaDeleteCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream_id = Identifier::named(&self.stream_name).unwrap();
+        let stream = client.create_stream(&self.stream_name, None).await;
+        assert!(stream.is_ok());
+        let topic = client
+            .create_topic(
+                &stream_id,
+                &self.topic_name,
+                1,
+                CompressionAlgorithm::default(),
+                None,
+                None,
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+                TopicOptions::default(),
+            )
+            .await;
+        assert!(topic.is_ok());
+        let schema = client
+            .register_schema(
+                &stream_id,
+                &Identifier::named(&self.topic_name).unwrap(),
+                SchemaType::Avro,
+                SchemaCompatibility::Backward,
+                Bytes::from_static(br#""string""#),
+            )
+            .await;
+        assert!(schema.is_ok());
+        self.schema_id = schema.unwrap().id;
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("schema")
+            .arg("delete")
+            .arg(self.schema_id.to_string())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let message = format!(
+            "Executing delete schema with ID: {}\nSchema with ID: {} deleted\n",
+            self.schema_id, self.schema_id
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let schema = client.get_schema(self.schema_id).await;
+        assert!(schema.is_ok());
+        assert!(schema.unwrap().is_none());
+
+        let stream_deleted = client
+            .delete_stream(&Identifier::named(&self.stream_name).unwrap())
+            .await;
+        assert!(stream_deleted.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestSchemaDeleteCmd::new(
+            String::from("orders"),
+            String::from("created"),
+        ))
+        .await;
+}
@@ -0,0 +1,179 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use bytes::Bytes;
+use iggy::prelude::{
+    Client, CompressionAlgorithm, Identifier, IggyExpiry, MaxTopicSize, SchemaCompatibility,
+    SchemaType, TopicOptions,
+};
+use predicates::str::diff;
+use serial_test::parallel;
+use std::io::Write;
+use tempfile::NamedTempFile;
+
+const ORDER_V1: &str =
+    r#"{"type": "object", "properties": {"id": {"type": "integer"}}, "required": ["id"]}"#;
+const ORDER_V2: &str = r#"{"type": "object", "properties": {"id": {"type": "integer"}}}"#;
+
+struct TestSchemaRegisterCmd {
+    stream_name: String,
+    topic_name: String,
+    compatibility: Option<SchemaCompatibility>,
+    file: NamedTempFile,
+    schema_id: u32,
+}
+
+impl TestSchemaRegisterCmd {
+    fn new(
+        stream_name: String,
+        topic_name: String,
+        compatibility: Option<SchemaCompatibility>,
+        definition: &str,
+    ) -> Self {
+        let mut file = tempfile::Builder::new().suffix(".json").tempfile().unwrap();
+        file.write_all(definition.as_bytes()).unwrap();
+        Self {
+            stream_name,
+            topic_name,
+            compatibility,
+            file,
+            schema_id: 0,
+        }
+    }
+
+    fn path(&self) -> String {
+        self.file.path().to_str().unwrap().to_string()
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut args = vec![
+            self.stream_name.clone(),
+            self.topic_name.clone(),
+            String::from("json"),
+            self.path(),
+        ];
+        if let Some(compatibility) = self.compatibility {
+            args.push(String::from("--compatibility"));
+            args.push(compatibility.to_string());
+        }
+        args
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestSchemaRegisterCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream_id = Identifier::named(&self.stream_name).unwrap();
+        let stream = client.create_stream(&self.stream_name, None).await;
+        assert!(stream.is_ok());
+        let topic = client
+            .create_topic(
+                &stream_id,
+                &self.topic_name,
+                1,
+                CompressionAlgorithm::default(),
+                None,
+                None,
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+                TopicOptions::default(),
+            )
+            .await;
+        assert!(topic.is_ok());
+        let schema = client
+            .register_schema(
+                &stream_id,
+                &Identifier::named(&self.topic_name).unwrap(),
+                SchemaType::Json,
+                SchemaCompatibility::None,
+                Bytes::from_static(ORDER_V1.as_bytes()),
+            )
+            .await;
+        assert!(schema.is_ok());
+        // The registered schema is the first version, the next one gets the following ID.
+        self.schema_id = schema.unwrap().id + 1;
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("schema")
+            .arg("register")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let compatibility = self.compatibility.unwrap_or_default();
+        let message = format!(
+            "Executing register json schema from file: {} with {compatibility} compatibility for topic with ID: {} and stream with ID: {}\n\
+            Schema with ID: {} and version: 2 registered for topic with ID: {} and stream with ID: {}\n",
+            self.path(),
+            self.topic_name,
+            self.stream_name,
+            self.schema_id,
+            self.topic_name,
+            self.stream_name
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let stream_id = Identifier::named(&self.stream_name).unwrap();
+        let schemas = client
+            .get_schemas(&stream_id, &Identifier::named(&self.topic_name).unwrap())
+            .await;
+        assert!(schemas.is_ok());
+        let schemas = schemas.unwrap();
+        assert_eq!(schemas.len(), 2);
+        let schema = &schemas[1];
+        assert_eq!(schema.id, self.schema_id);
+        assert_eq!(schema.version, 2);
+        assert_eq!(schema.compatibility, self.compatibility.unwrap_or_default());
+
+        let stream_deleted = client.delete_stream(&stream_id).await;
+        assert!(stream_deleted.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestSchemaRegisterCmd::new(
+            String::from("orders"),
+            String::from("created"),
+            None,
+            ORDER_V2,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestSchemaRegisterCmd::new(
+            String::from("payments"),
+            String::from("settled"),
+            Some(SchemaCompatibility::None),
+            ORDER_V2,
+        ))
+        .await;
+}
@@ -72,6 +72,7 @@ impl IggyCmdTestCase for TestStreamPurgeCmd {
                 Some(self.topic_id),
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                TopicOptions::default(),
             )
             .await;
         assert!(topic.is_ok());
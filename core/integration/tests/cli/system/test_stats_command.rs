@@ -21,10 +21,12 @@ use crate::cli::common::{
 };
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
+
 use iggy::prelude::Client;
 use iggy::prelude::Identifier;
 use iggy::prelude::IggyExpiry;
 use iggy::prelude::MaxTopicSize;
+use iggy::prelude::TopicOptions;
 use iggy_binary_protocol::cli::binary_system::stats::GetStatsOutput;
 use predicates::str::{contains, starts_with};
 use serial_test::parallel;
@@ -77,6 +79,7 @@ impl IggyCmdTestCase for TestStatsCmd {
                 Some(1),
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                TopicOptions::default(),
             )
             .await;
         assert!(topic.is_ok());
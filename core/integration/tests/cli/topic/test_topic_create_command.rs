@@ -139,9 +139,11 @@ impl IggyCmdTestCase for TestTopicCreateCmd {
 
         let message = format!(
             "Executing create topic with name: {topic_name}, {topic_id}, message expiry: {message_expiry}, compression algorithm: {compression_algorithm}, \
-            max topic size: {max_topic_size}, replication factor: {replication_factor} in stream with ID: {stream_id}\n\
+            max topic size: {max_topic_size}, replication factor: {replication_factor}, cleanup policy: delete, partitioning hash: xxhash32, \
+            segment max age: server_default, max partition size: server_default in stream with ID: {stream_id}\n\
             Topic with name: {topic_name}, {topic_id}, partitions count: {partitions_count}, compression algorithm: {compression_algorithm}, message expiry: {message_expiry}, \
-            max topic size: {max_topic_size}, replication factor: {replication_factor} created in stream with ID: {stream_id}\n",
+            max topic size: {max_topic_size}, replication factor: {replication_factor}, cleanup policy: delete, partitioning hash: xxhash32, \
+            segment max age: server_default, max partition size: server_default created in stream with ID: {stream_id}\n",
         );
 
         command_state.success().stdout(diff(message));
@@ -297,6 +299,8 @@ Arguments:
 
   <COMPRESSION_ALGORITHM>
           Compression algorithm for the topic, set to "none" for no compression
+{CLAP_INDENT}
+          Available algorithms: "none", "gzip", "zstd", "lz4" and "snappy"
 
   [MESSAGE_EXPIRY]...
           Message expiry time in human-readable format like "unlimited" or "15days 2min 2s"
@@ -322,6 +326,33 @@ Options:
 {CLAP_INDENT}
           [default: 1]
 
+  -c, --cleanup-policy <CLEANUP_POLICY>
+          Cleanup policy for the topic, either "delete" or "compact"
+{CLAP_INDENT}
+          [default: delete]
+
+      --partitioning-hash <PARTITIONING_HASH>
+          Hashing of the messages key to the partitions in the "algorithm[:mode]" format
+{CLAP_INDENT}
+          Algorithm is one of "xxhash32", "murmur2" (Kafka compatible) or "xxhash3", mode is either "modulo" (default) or "jump" (consistent hashing).
+{CLAP_INDENT}
+          [default: xxhash32]
+
+      --segment-max-age <SEGMENT_MAX_AGE>
+          Max age of the open segment after which it is closed and a new one is started, in human-readable format like "none" or "1h"
+{CLAP_INDENT}
+          "server_default" or skipping parameter makes CLI to use server default (from current server config) segment max age
+{CLAP_INDENT}
+          [default: server_default]
+
+      --max-partition-size <MAX_PARTITION_SIZE>
+          Max size of a single partition in human-readable format like "unlimited" or "5GB"
+{CLAP_INDENT}
+          "server_default" or skipping parameter makes CLI to use server default (from current server config) max partition size
+          Can't be lower than segment size in the config.
+{CLAP_INDENT}
+          [default: server_default]
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -356,6 +387,14 @@ Options:
           Max topic size in human-readable format like "unlimited" or "15GB" [default: server_default]
   -r, --replication-factor <REPLICATION_FACTOR>
           Replication factor for the topic [default: 1]
+  -c, --cleanup-policy <CLEANUP_POLICY>
+          Cleanup policy for the topic, either "delete" or "compact" [default: delete]
+      --partitioning-hash <PARTITIONING_HASH>
+          Hashing of the messages key to the partitions in the "algorithm[:mode]" format [default: xxhash32]
+      --segment-max-age <SEGMENT_MAX_AGE>
+          Max age of the open segment after which it is closed and a new one is started, in human-readable format like "none" or "1h" [default: server_default]
+      --max-partition-size <MAX_PARTITION_SIZE>
+          Max size of a single partition in human-readable format like "unlimited" or "5GB" [default: server_default]
   -h, --help
           Print help (see more with '--help')
 "#,
@@ -22,9 +22,11 @@ use crate::cli::common::{
 };
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
+
 use iggy::prelude::Client;
 use iggy::prelude::IggyExpiry;
 use iggy::prelude::MaxTopicSize;
+use iggy::prelude::TopicOptions;
 use predicates::str::{contains, starts_with};
 use serial_test::parallel;
 
@@ -89,6 +91,7 @@ impl IggyCmdTestCase for TestTopicGetCmd {
                 Some(self.topic_id),
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                TopicOptions::default(),
             )
             .await;
         assert!(topic.is_ok());
@@ -129,6 +132,10 @@ impl IggyCmdTestCase for TestTopicGetCmd {
             .stdout(contains("Topic size          | 0"))
             .stdout(contains("Message expiry      | unlimited"))
             .stdout(contains("Max topic size      | unlimited"))
+            .stdout(contains("Cleanup policy      | delete"))
+            .stdout(contains("Partitioning hash   | xxhash32"))
+            .stdout(contains("Segment max age     | unlimited"))
+            .stdout(contains("Max partition size  | unlimited"))
             .stdout(contains("Topic message count | 0"))
             .stdout(contains("Partitions count    | 1"));
     }
@@ -88,6 +88,7 @@ impl IggyCmdTestCase for TestTopicPurgeCmd {
                 Some(self.topic_id),
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                TopicOptions::default(),
             )
             .await;
         assert!(topic.is_ok());
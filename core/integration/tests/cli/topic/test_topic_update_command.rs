@@ -23,11 +23,13 @@ use crate::cli::common::{
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use humantime::Duration as HumanDuration;
+
 use iggy::prelude::Client;
 use iggy::prelude::CompressionAlgorithm;
 use iggy::prelude::IggyByteSize;
 use iggy::prelude::IggyExpiry;
 use iggy::prelude::MaxTopicSize;
+use iggy::prelude::TopicOptions;
 use predicates::str::diff;
 use serial_test::parallel;
 use std::str::FromStr;
@@ -147,6 +149,7 @@ impl IggyCmdTestCase for TestTopicUpdateCmd {
                 Some(self.topic_id),
                 message_expiry,
                 self.max_topic_size,
+                TopicOptions::default(),
             )
             .await;
         assert!(topic.is_ok());
@@ -186,10 +189,12 @@ impl IggyCmdTestCase for TestTopicUpdateCmd {
         let expected_message = format!(
             "Executing update topic with ID: {topic_id}, name: {new_topic_name}, \
                                 message expiry: {message_expiry}, compression algorithm: {compression_algorithm}, max topic size: {new_max_topic_size}, \
-                                replication factor: {replication_factor}, in stream with ID: {stream_id}\n\
+                                replication factor: {replication_factor}, cleanup policy: delete, partitioning hash: xxhash32, \
+                                segment max age: server_default, max partition size: server_default, in stream with ID: {stream_id}\n\
                                 Topic with ID: {topic_id} updated name: {new_topic_name}, updated message expiry: {message_expiry}, \
                                 updated compression algorithm: {compression_algorithm}, updated max topic size: {new_max_topic_size}, \
-                                updated replication factor: {replication_factor} in stream with ID: {stream_id}\n"
+                                updated replication factor: {replication_factor}, updated cleanup policy: delete, updated partitioning hash: xxhash32, \
+                                updated segment max age: server_default, updated max partition size: server_default in stream with ID: {stream_id}\n"
         );
 
         command_state.success().stdout(diff(expected_message));
@@ -406,6 +411,8 @@ Arguments:
 
   <COMPRESSION_ALGORITHM>
           Compression algorithm for the topic, set to "none" for no compression
+{CLAP_INDENT}
+          Available algorithms: "none", "gzip", "zstd", "lz4" and "snappy"
 
   [MESSAGE_EXPIRY]...
           New message expiry time in human-readable format like "unlimited" or "15days 2min 2s"
@@ -428,6 +435,33 @@ Options:
 {CLAP_INDENT}
           [default: 1]
 
+  -c, --cleanup-policy <CLEANUP_POLICY>
+          New cleanup policy for the topic, either "delete" or "compact"
+{CLAP_INDENT}
+          [default: delete]
+
+      --partitioning-hash <PARTITIONING_HASH>
+          New hashing of the messages key to the partitions in the "algorithm[:mode]" format
+{CLAP_INDENT}
+          Algorithm is one of "xxhash32", "murmur2" (Kafka compatible) or "xxhash3", mode is either "modulo" (default) or "jump" (consistent hashing).
+{CLAP_INDENT}
+          [default: xxhash32]
+
+      --segment-max-age <SEGMENT_MAX_AGE>
+          New max age of the open segment after which it is closed and a new one is started, in human-readable format like "none" or "1h"
+{CLAP_INDENT}
+          "server_default" or skipping parameter makes CLI to use server default (from current server config) segment max age
+{CLAP_INDENT}
+          [default: server_default]
+
+      --max-partition-size <MAX_PARTITION_SIZE>
+          New max size of a single partition in human-readable format like "unlimited" or "5GB"
+{CLAP_INDENT}
+          "server_default" or skipping parameter makes CLI to use server default (from current server config) max partition size
+          Can't be lower than segment size in the config.
+{CLAP_INDENT}
+          [default: server_default]
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -461,6 +495,14 @@ Options:
           New max topic size in human-readable format like "unlimited" or "15GB" [default: server_default]
   -r, --replication-factor <REPLICATION_FACTOR>
           New replication factor for the topic [default: 1]
+  -c, --cleanup-policy <CLEANUP_POLICY>
+          New cleanup policy for the topic, either "delete" or "compact" [default: delete]
+      --partitioning-hash <PARTITIONING_HASH>
+          New hashing of the messages key to the partitions in the "algorithm[:mode]" format [default: xxhash32]
+      --segment-max-age <SEGMENT_MAX_AGE>
+          New max age of the open segment after which it is closed and a new one is started, in human-readable format like "none" or "1h" [default: server_default]
+      --max-partition-size <MAX_PARTITION_SIZE>
+          New max size of a single partition in human-readable format like "unlimited" or "5GB" [default: server_default]
   -h, --help
           Print help (see more with '--help')
 "#,
@@ -26,4 +26,5 @@ mod test_user_list_command;
 mod test_user_name_command;
 mod test_user_password_command;
 mod test_user_permissions_command;
+mod test_user_quotas_command;
 mod test_user_status_command;
@@ -355,6 +355,18 @@ Options:
            iggy user create sender s3n43r -s 3#1:s_msg#2:s_msg
            iggy user create user1 test12 -s 4:manage_stream,r_top#1:s_msg,p_msg#2:manage_topic
 
+      --max-produced-bytes-per-second <MAX_PRODUCED_BYTES_PER_SECOND>
+          Maximum amount of bytes produced per second by all clients of the user
+
+      --max-produced-messages-per-second <MAX_PRODUCED_MESSAGES_PER_SECOND>
+          Maximum number of messages produced per second by all clients of the user
+
+      --max-consumed-bytes-per-second <MAX_CONSUMED_BYTES_PER_SECOND>
+          Maximum amount of bytes consumed per second by all clients of the user
+
+      --max-connections <MAX_CONNECTIONS>
+          Maximum number of concurrent connections of the user
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -387,6 +399,14 @@ Options:
           Set global permissions for created user
   -s, --stream-permissions <STREAM_PERMISSIONS>
           Set stream permissions for created user
+      --max-produced-bytes-per-second <MAX_PRODUCED_BYTES_PER_SECOND>
+          Maximum amount of bytes produced per second by all clients of the user
+      --max-produced-messages-per-second <MAX_PRODUCED_MESSAGES_PER_SECOND>
+          Maximum number of messages produced per second by all clients of the user
+      --max-consumed-bytes-per-second <MAX_CONSUMED_BYTES_PER_SECOND>
+          Maximum amount of bytes consumed per second by all clients of the user
+      --max-connections <MAX_CONNECTIONS>
+          Maximum number of concurrent connections of the user
   -h, --help
           Print help (see more with '--help')
 "#,
@@ -64,7 +64,7 @@ impl TestUserDeleteCmd {
 impl IggyCmdTestCase for TestUserDeleteCmd {
     async fn prepare_server_state(&mut self, client: &dyn Client) {
         let user = client
-            .create_user(&self.username, &self.password, self.status, None)
+            .create_user(&self.username, &self.password, self.status, None, None)
             .await;
         assert!(user.is_ok());
     }
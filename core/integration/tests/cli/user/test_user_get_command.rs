@@ -112,6 +112,7 @@ impl IggyCmdTestCase for TestUserGetCmd {
                     "secret",
                     self.status,
                     self.get_permissions(),
+                    None,
                 )
                 .await;
             assert!(create_user.is_ok());
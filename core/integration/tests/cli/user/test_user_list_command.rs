@@ -51,7 +51,7 @@ impl TestUserListCmd {
 impl IggyCmdTestCase for TestUserListCmd {
     async fn prepare_server_state(&mut self, client: &dyn Client) {
         let stream = client
-            .create_user(&self.username, "secret", self.status, None)
+            .create_user(&self.username, "secret", self.status, None, None)
             .await;
         assert!(stream.is_ok());
     }
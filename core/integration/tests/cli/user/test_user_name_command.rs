@@ -62,7 +62,7 @@ impl TestUserNameCmd {
 impl IggyCmdTestCase for TestUserNameCmd {
     async fn prepare_server_state(&mut self, client: &dyn Client) {
         let create_user = client
-            .create_user(&self.username, "secret", UserStatus::Active, None)
+            .create_user(&self.username, "secret", UserStatus::Active, None, None)
             .await;
         assert!(create_user.is_ok());
         let user = client
@@ -75,7 +75,13 @@ impl TestUserPasswordCmd {
 impl IggyCmdTestCase for TestUserPasswordCmd {
     async fn prepare_server_state(&mut self, client: &dyn Client) {
         let create_user = client
-            .create_user(&self.username, &self.password, UserStatus::Active, None)
+            .create_user(
+                &self.username,
+                &self.password,
+                UserStatus::Active,
+                None,
+                None,
+            )
             .await;
         assert!(create_user.is_ok());
         let user = client
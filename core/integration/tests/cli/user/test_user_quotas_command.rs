@@ -0,0 +1,255 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{
+    CLAP_INDENT, IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestUserId,
+    USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::prelude::Client;
+use iggy::prelude::UserId;
+use iggy::prelude::UserQuotas;
+use iggy::prelude::UserStatus;
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestUserQuotasCmd {
+    username: String,
+    quotas: UserQuotas,
+    using_identifier: TestUserId,
+    user_id: Option<UserId>,
+}
+
+impl TestUserQuotasCmd {
+    fn new(username: String, quotas: UserQuotas, using_identifier: TestUserId) -> Self {
+        Self {
+            username,
+            quotas,
+            using_identifier,
+            user_id: None,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut args = match self.using_identifier {
+            TestUserId::Named => vec![self.username.clone()],
+            TestUserId::Numeric => vec![format!("{}", self.user_id.unwrap())],
+        };
+
+        if let Some(value) = self.quotas.max_produced_bytes_per_second {
+            args.push(String::from("--max-produced-bytes-per-second"));
+            args.push(format!("{value}B"));
+        }
+        if let Some(value) = self.quotas.max_produced_messages_per_second {
+            args.push(String::from("--max-produced-messages-per-second"));
+            args.push(format!("{value}"));
+        }
+        if let Some(value) = self.quotas.max_consumed_bytes_per_second {
+            args.push(String::from("--max-consumed-bytes-per-second"));
+            args.push(format!("{value}B"));
+        }
+        if let Some(value) = self.quotas.max_connections {
+            args.push(String::from("--max-connections"));
+            args.push(format!("{value}"));
+        }
+
+        args
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestUserQuotasCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let create_user = client
+            .create_user(
+                &self.username,
+                "secret",
+                UserStatus::Active,
+                None,
+                Some(UserQuotas {
+                    max_connections: Some(1),
+                    ..Default::default()
+                }),
+            )
+            .await;
+        assert!(create_user.is_ok());
+        let user = client
+            .get_user(&self.username.clone().try_into().unwrap())
+            .await;
+        assert!(user.is_ok());
+        let user = user.unwrap().expect("User not found");
+        self.user_id = Some(user.id);
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("user")
+            .arg("quotas")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let user_id = match self.using_identifier {
+            TestUserId::Named => self.username.clone(),
+            TestUserId::Numeric => format!("{}", self.user_id.unwrap()),
+        };
+        let message = format!(
+            "Executing update user with ID: {user_id} with quotas: {}\nUser with ID: {user_id} updated with quotas: {}\n",
+            self.quotas, self.quotas
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let user = client
+            .get_user(&self.user_id.unwrap().try_into().unwrap())
+            .await;
+        assert!(user.is_ok());
+        let user = user.unwrap().expect("User not found");
+        let expected_quotas = Some(self.quotas).filter(|quotas| !quotas.is_unlimited());
+        assert_eq!(user.quotas, expected_quotas);
+
+        let deleted = client
+            .delete_user(&self.user_id.unwrap().try_into().unwrap())
+            .await;
+        assert!(deleted.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestUserQuotasCmd::new(
+            String::from("producer"),
+            UserQuotas {
+                max_produced_bytes_per_second: Some(10_000_000),
+                max_produced_messages_per_second: Some(1000),
+                ..Default::default()
+            },
+            TestUserId::Numeric,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserQuotasCmd::new(
+            String::from("consumer"),
+            UserQuotas {
+                max_consumed_bytes_per_second: Some(5_000_000),
+                max_connections: Some(10),
+                ..Default::default()
+            },
+            TestUserId::Named,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserQuotasCmd::new(
+            String::from("unlimited"),
+            UserQuotas::default(),
+            TestUserId::Named,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "quotas", "--help"],
+            format!(
+                r#"Set quotas for user with given ID
+
+The user ID can be specified as either a username or an ID. Quotas
+are configured based on the options provided with this command. If no
+options are set, the default behavior is to remove quotas for the
+specified user.
+
+Examples:
+ iggy user quotas 2
+ iggy user quotas client --max-produced-bytes-per-second 10MB
+ iggy user quotas 3 --max-consumed-bytes-per-second 1GB --max-connections 10
+
+{USAGE_PREFIX} user quotas [OPTIONS] <USER_ID>
+
+Arguments:
+  <USER_ID>
+          User ID to update
+{CLAP_INDENT}
+          The user ID can be specified as either a username or an ID
+
+Options:
+      --max-produced-bytes-per-second <MAX_PRODUCED_BYTES_PER_SECOND>
+          Maximum amount of bytes produced per second by all clients of the user
+
+      --max-produced-messages-per-second <MAX_PRODUCED_MESSAGES_PER_SECOND>
+          Maximum number of messages produced per second by all clients of the user
+
+      --max-consumed-bytes-per-second <MAX_CONSUMED_BYTES_PER_SECOND>
+          Maximum amount of bytes consumed per second by all clients of the user
+
+      --max-connections <MAX_CONNECTIONS>
+          Maximum number of concurrent connections of the user
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "quotas", "-h"],
+            format!(
+                r#"Set quotas for user with given ID
+
+{USAGE_PREFIX} user quotas [OPTIONS] <USER_ID>
+
+Arguments:
+  <USER_ID>  User ID to update
+
+Options:
+      --max-produced-bytes-per-second <MAX_PRODUCED_BYTES_PER_SECOND>
+          Maximum amount of bytes produced per second by all clients of the user
+      --max-produced-messages-per-second <MAX_PRODUCED_MESSAGES_PER_SECOND>
+          Maximum number of messages produced per second by all clients of the user
+      --max-consumed-bytes-per-second <MAX_CONSUMED_BYTES_PER_SECOND>
+          Maximum amount of bytes consumed per second by all clients of the user
+      --max-connections <MAX_CONNECTIONS>
+          Maximum number of concurrent connections of the user
+  -h, --help
+          Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
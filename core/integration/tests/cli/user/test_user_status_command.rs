@@ -72,7 +72,7 @@ impl TestUserStatusCmd {
 impl IggyCmdTestCase for TestUserStatusCmd {
     async fn prepare_server_state(&mut self, client: &dyn Client) {
         let create_user = client
-            .create_user(&self.username, "secret", self.status, None)
+            .create_user(&self.username, "secret", self.status, None, None)
             .await;
         assert!(create_user.is_ok());
         let user = client
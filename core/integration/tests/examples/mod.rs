@@ -133,6 +133,7 @@ impl<'a> IggyExampleTest<'a> {
                     None,
                     IggyExpiry::NeverExpire,
                     MaxTopicSize::ServerDefault,
+                    TopicOptions::default(),
                 )
                 .await
                 .unwrap();
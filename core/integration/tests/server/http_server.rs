@@ -17,10 +17,15 @@
  */
 
 use crate::server::scenarios::{
-    create_message_payload, stream_size_validation_scenario, system_scenario, user_scenario,
+    consumer_offsets_scenario, create_message_payload, partition_move_scenario, schemas_scenario,
+    stream_size_validation_scenario, system_scenario, user_scenario,
+};
+use integration::{
+    http_client::HttpClientFactory,
+    test_server::{IpAddrKind, TestServer},
 };
-use integration::{http_client::HttpClientFactory, test_server::TestServer};
 use serial_test::parallel;
+use std::collections::HashMap;
 
 #[tokio::test]
 #[parallel]
@@ -71,3 +76,44 @@ async fn user_scenario_should_be_valid() {
     let client_factory = HttpClientFactory { server_addr };
     user_scenario::run(&client_factory).await;
 }
+
+#[tokio::test]
+#[parallel]
+async fn consumer_offsets_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    consumer_offsets_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn schemas_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    schemas_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn partition_move_scenario_should_be_valid() {
+    let data_directory = format!("{}_data", TestServer::get_random_path());
+    let extra_envs = HashMap::from([(
+        "IGGY_SYSTEM_DATA_DIRECTORIES".to_string(),
+        format!("[{data_directory}]"),
+    )]);
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    partition_move_scenario::run(
+        &client_factory,
+        test_server.get_local_data_path(),
+        &data_directory,
+    )
+    .await;
+    std::fs::remove_dir_all(&data_directory).unwrap();
+}
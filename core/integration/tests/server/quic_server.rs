@@ -17,9 +17,12 @@
  */
 
 use crate::server::scenarios::{
-    consumer_group_join_scenario, consumer_group_with_multiple_clients_polling_messages_scenario,
-    consumer_group_with_single_client_polling_messages_scenario, create_message_payload,
-    message_headers_scenario, stream_size_validation_scenario, system_scenario, user_scenario,
+    consumer_group_join_scenario, consumer_group_offsets_reset_scenario,
+    consumer_group_with_multiple_clients_polling_messages_scenario,
+    consumer_group_with_single_client_polling_messages_scenario, consumer_offsets_scenario,
+    create_message_payload, long_polling_scenario, message_headers_scenario, metadata_scenario,
+    roles_scenario, schemas_scenario, stream_size_validation_scenario, system_scenario,
+    user_quotas_scenario, user_scenario,
 };
 use integration::{quic_client::QuicClientFactory, test_server::TestServer};
 use serial_test::parallel;
@@ -44,6 +47,26 @@ async fn user_scenario_should_be_valid() {
     user_scenario::run(&client_factory).await;
 }
 
+#[tokio::test]
+#[parallel]
+async fn user_quotas_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    user_quotas_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn roles_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    roles_scenario::run(&client_factory).await;
+}
+
 #[tokio::test]
 #[parallel]
 async fn message_headers_scenario_should_be_valid() {
@@ -103,3 +126,53 @@ async fn stream_size_validation_scenario_should_be_valid() {
     let client_factory = QuicClientFactory { server_addr };
     stream_size_validation_scenario::run(&client_factory).await;
 }
+
+#[tokio::test]
+#[parallel]
+async fn consumer_offsets_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    consumer_offsets_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn consumer_group_offsets_reset_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    consumer_group_offsets_reset_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn metadata_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    metadata_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn schemas_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    schemas_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn long_polling_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    long_polling_scenario::run(&client_factory).await;
+}
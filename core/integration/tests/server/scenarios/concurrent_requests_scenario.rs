@@ -0,0 +1,136 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    PARTITIONS_COUNT, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME, cleanup, create_client,
+};
+use futures::future::join_all;
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, assert_clean_system, login_root};
+use std::collections::HashSet;
+
+const BATCHES_COUNT: u32 = 20;
+const MESSAGES_PER_BATCH: u32 = 10;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. Send the batches of messages to all the partitions concurrently, along with the other requests
+    let sends = (1..=PARTITIONS_COUNT).flat_map(|partition_id| {
+        let client = &client;
+        (0..BATCHES_COUNT).map(move |batch| send_batch(client, partition_id, batch))
+    });
+    let requests = (0..BATCHES_COUNT).map(|_| async {
+        client.ping().await.unwrap();
+        let stream = client
+            .get_stream(&Identifier::numeric(STREAM_ID).unwrap())
+            .await
+            .unwrap()
+            .expect("Failed to get stream");
+        assert_eq!(stream.name, STREAM_NAME);
+    });
+    tokio::join!(join_all(sends), join_all(requests));
+
+    // 2. Poll the messages from all the partitions concurrently and validate them
+    let polls = (1..=PARTITIONS_COUNT).map(|partition_id| {
+        let client = &client;
+        async move {
+            let polled_messages = client
+                .poll_messages(
+                    &Identifier::numeric(STREAM_ID).unwrap(),
+                    &Identifier::numeric(TOPIC_ID).unwrap(),
+                    Some(partition_id),
+                    &Consumer::default(),
+                    &PollingStrategy::offset(0),
+                    BATCHES_COUNT * MESSAGES_PER_BATCH,
+                    false,
+                )
+                .await
+                .unwrap();
+            (partition_id, polled_messages)
+        }
+    });
+
+    for (partition_id, polled_messages) in join_all(polls).await {
+        assert_eq!(
+            polled_messages.messages.len() as u32,
+            BATCHES_COUNT * MESSAGES_PER_BATCH
+        );
+        let payloads = polled_messages
+            .messages
+            .iter()
+            .map(|message| String::from_utf8(message.payload.to_vec()).unwrap())
+            .collect::<HashSet<_>>();
+        for batch in 0..BATCHES_COUNT {
+            for index in 0..MESSAGES_PER_BATCH {
+                assert!(payloads.contains(&create_payload(partition_id, batch, index)));
+            }
+        }
+    }
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn send_batch(client: &IggyClient, partition_id: u32, batch: u32) {
+    let mut messages = (0..MESSAGES_PER_BATCH)
+        .map(|index| {
+            IggyMessage::builder()
+                .payload(create_payload(partition_id, batch, index).into())
+                .build()
+                .expect("Failed to create message")
+        })
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(partition_id),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
+
+fn create_payload(partition_id: u32, batch: u32, index: u32) -> String {
+    format!("partition {partition_id}, batch {batch}, message {index}")
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            TopicOptions::default(),
+        )
+        .await
+        .unwrap();
+}
@@ -21,12 +21,15 @@ use crate::server::scenarios::{
     TOPIC_NAME, USERNAME_1, USERNAME_2, USERNAME_3, cleanup, create_client, join_consumer_group,
 };
 use iggy::clients::client::IggyClient;
+
 use iggy::prelude::ClientInfoDetails;
 use iggy::prelude::CompressionAlgorithm;
+use iggy::prelude::ConsumerGroupAssignmentStrategy;
 use iggy::prelude::ConsumerGroupDetails;
 use iggy::prelude::Identifier;
 use iggy::prelude::IggyExpiry;
 use iggy::prelude::MaxTopicSize;
+use iggy::prelude::TopicOptions;
 use iggy::prelude::{ConsumerGroupClient, StreamClient, SystemClient, TopicClient};
 use integration::test_server::{
     ClientFactory, assert_clean_system, create_user, login_root, login_user,
@@ -58,6 +61,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             Some(TOPIC_ID),
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            TopicOptions::default(),
         )
         .await
         .unwrap();
@@ -69,6 +73,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             &Identifier::numeric(TOPIC_ID).unwrap(),
             CONSUMER_GROUP_NAME,
             Some(CONSUMER_GROUP_ID),
+            ConsumerGroupAssignmentStrategy::default(),
         )
         .await
         .unwrap();
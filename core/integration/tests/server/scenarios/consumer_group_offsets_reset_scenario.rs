@@ -0,0 +1,247 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    CONSUMER_GROUP_ID, CONSUMER_GROUP_NAME, PARTITIONS_COUNT, STREAM_ID, STREAM_NAME, TOPIC_ID,
+    TOPIC_NAME, cleanup, create_client, join_consumer_group, leave_consumer_group,
+};
+use bytes::Bytes;
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, assert_clean_system, login_root};
+
+const MESSAGES_PER_PARTITION: u64 = 10;
+const LAST_OFFSET: u64 = MESSAGES_PER_PARTITION - 1;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+
+    // 1. Create the stream, topic and consumer group, and send the messages to each partition
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            Default::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            TopicOptions::default(),
+        )
+        .await
+        .unwrap();
+    client
+        .create_consumer_group(
+            &stream_id,
+            &topic_id,
+            CONSUMER_GROUP_NAME,
+            Some(CONSUMER_GROUP_ID),
+            ConsumerGroupAssignmentStrategy::default(),
+        )
+        .await
+        .unwrap();
+    for partition_id in 1..=PARTITIONS_COUNT {
+        send_messages(&client, partition_id).await;
+    }
+
+    // 2. The dry run resolves the offsets, but does not store them
+    let resets = reset_offsets(&client, ConsumerOffsetsResetTarget::Latest, true, false)
+        .await
+        .unwrap();
+    assert_resets(&resets, None, Some(LAST_OFFSET));
+    assert_stored_offsets(&client, None).await;
+
+    // 3. Reset the offsets to the end of each partition
+    let resets = reset_offsets(&client, ConsumerOffsetsResetTarget::Latest, false, false)
+        .await
+        .unwrap();
+    assert_resets(&resets, None, Some(LAST_OFFSET));
+    assert_stored_offsets(&client, Some(LAST_OFFSET)).await;
+
+    // 4. Shift the offsets back, and then beyond the end of each partition
+    let resets = reset_offsets(&client, ConsumerOffsetsResetTarget::Shift(-3), false, false)
+        .await
+        .unwrap();
+    assert_resets(&resets, Some(LAST_OFFSET), Some(LAST_OFFSET - 3));
+    assert_stored_offsets(&client, Some(LAST_OFFSET - 3)).await;
+
+    let resets = reset_offsets(
+        &client,
+        ConsumerOffsetsResetTarget::Shift(100),
+        false,
+        false,
+    )
+    .await
+    .unwrap();
+    assert_resets(&resets, Some(LAST_OFFSET - 3), Some(LAST_OFFSET));
+
+    // 5. Reset the offsets to the beginning, so that the stored offsets are removed
+    let resets = reset_offsets(&client, ConsumerOffsetsResetTarget::Earliest, false, false)
+        .await
+        .unwrap();
+    assert_resets(&resets, Some(LAST_OFFSET), None);
+    assert_stored_offsets(&client, None).await;
+
+    // 6. Reset the offsets to the timestamps preceding and following all the messages
+    let resets = reset_offsets(
+        &client,
+        ConsumerOffsetsResetTarget::Timestamp(u64::MAX),
+        false,
+        false,
+    )
+    .await
+    .unwrap();
+    assert_resets(&resets, None, Some(LAST_OFFSET));
+
+    let resets = reset_offsets(
+        &client,
+        ConsumerOffsetsResetTarget::Timestamp(0),
+        false,
+        false,
+    )
+    .await
+    .unwrap();
+    assert_resets(&resets, Some(LAST_OFFSET), None);
+
+    // 7. Reset the offset of the single partition to the explicit value
+    let resets = reset_offsets(
+        &client,
+        ConsumerOffsetsResetTarget::Offsets(vec![ConsumerPartitionOffset::new(2, 4, None)]),
+        false,
+        false,
+    )
+    .await
+    .unwrap();
+    assert_eq!(resets.len(), 1);
+    assert_eq!(resets[0].partition_id, 2);
+    assert_eq!(resets[0].new_offset, Some(4));
+    assert_eq!(get_stored_offset(&client, 1).await, None);
+    assert_eq!(get_stored_offset(&client, 2).await, Some(4));
+
+    // 8. Ensure that the explicit offset cannot exceed the current offset of the partition
+    let result = reset_offsets(
+        &client,
+        ConsumerOffsetsResetTarget::Offsets(vec![ConsumerPartitionOffset::new(
+            2,
+            MESSAGES_PER_PARTITION,
+            None,
+        )]),
+        false,
+        false,
+    )
+    .await;
+    assert!(result.is_err());
+    assert_eq!(get_stored_offset(&client, 2).await, Some(4));
+
+    // 9. Ensure that the offsets cannot be reset while the group has members, unless forced
+    join_consumer_group(&client).await;
+    let result = reset_offsets(&client, ConsumerOffsetsResetTarget::Latest, false, false).await;
+    assert!(result.is_err());
+    assert_eq!(get_stored_offset(&client, 2).await, Some(4));
+
+    let resets = reset_offsets(&client, ConsumerOffsetsResetTarget::Latest, false, true)
+        .await
+        .unwrap();
+    assert_eq!(resets.len(), PARTITIONS_COUNT as usize);
+    assert_stored_offsets(&client, Some(LAST_OFFSET)).await;
+    leave_consumer_group(&client).await;
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn reset_offsets(
+    client: &IggyClient,
+    target: ConsumerOffsetsResetTarget,
+    dry_run: bool,
+    force: bool,
+) -> Result<Vec<ConsumerGroupOffsetReset>, IggyError> {
+    client
+        .reset_consumer_group_offsets(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+            &target,
+            dry_run,
+            force,
+        )
+        .await
+}
+
+fn assert_resets(
+    resets: &[ConsumerGroupOffsetReset],
+    old_offset: Option<u64>,
+    new_offset: Option<u64>,
+) {
+    assert_eq!(resets.len(), PARTITIONS_COUNT as usize);
+    for (reset, partition_id) in resets.iter().zip(1..=PARTITIONS_COUNT) {
+        assert_eq!(reset.partition_id, partition_id);
+        assert_eq!(reset.current_offset, LAST_OFFSET);
+        assert_eq!(reset.old_offset, old_offset);
+        assert_eq!(reset.new_offset, new_offset);
+    }
+}
+
+async fn assert_stored_offsets(client: &IggyClient, expected_offset: Option<u64>) {
+    for partition_id in 1..=PARTITIONS_COUNT {
+        assert_eq!(
+            get_stored_offset(client, partition_id).await,
+            expected_offset
+        );
+    }
+}
+
+async fn get_stored_offset(client: &IggyClient, partition_id: u32) -> Option<u64> {
+    client
+        .get_consumer_offset(
+            &Consumer::group(Identifier::numeric(CONSUMER_GROUP_ID).unwrap()),
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(partition_id),
+        )
+        .await
+        .unwrap()
+        .map(|offset| offset.stored_offset)
+}
+
+async fn send_messages(client: &IggyClient, partition_id: u32) {
+    let mut messages = (0..MESSAGES_PER_PARTITION)
+        .map(|id| {
+            IggyMessage::builder()
+                .payload(Bytes::from(format!("message-{id}")))
+                .build()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(partition_id),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
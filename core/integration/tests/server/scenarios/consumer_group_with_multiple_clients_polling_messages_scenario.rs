@@ -66,6 +66,7 @@ async fn init_system(
             Some(TOPIC_ID),
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            TopicOptions::default(),
         )
         .await
         .unwrap();
@@ -77,6 +78,7 @@ async fn init_system(
             &Identifier::numeric(TOPIC_ID).unwrap(),
             CONSUMER_GROUP_NAME,
             Some(CONSUMER_GROUP_ID),
+            ConsumerGroupAssignmentStrategy::default(),
         )
         .await
         .unwrap();
@@ -58,6 +58,7 @@ async fn init_system(client: &IggyClient) {
             Some(TOPIC_ID),
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            TopicOptions::default(),
         )
         .await
         .unwrap();
@@ -69,6 +70,7 @@ async fn init_system(client: &IggyClient) {
             &Identifier::numeric(TOPIC_ID).unwrap(),
             CONSUMER_GROUP_NAME,
             Some(CONSUMER_GROUP_ID),
+            ConsumerGroupAssignmentStrategy::default(),
         )
         .await
         .unwrap();
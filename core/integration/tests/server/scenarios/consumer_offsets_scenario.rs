@@ -0,0 +1,159 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    CONSUMER_ID, PARTITIONS_COUNT, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME, cleanup,
+    create_client,
+};
+use bytes::Bytes;
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, assert_clean_system, login_root};
+
+const MESSAGES_PER_PARTITION: u64 = 10;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    let consumer = Consumer::new(Identifier::numeric(CONSUMER_ID).unwrap());
+
+    // 1. Create the stream and topic, and send the messages to each partition
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            Default::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            TopicOptions::default(),
+        )
+        .await
+        .unwrap();
+    for partition_id in 1..=PARTITIONS_COUNT {
+        send_messages(&client, partition_id).await;
+    }
+
+    // 2. Store the single offset with the metadata
+    client
+        .store_consumer_offset(
+            &consumer,
+            &stream_id,
+            &topic_id,
+            Some(1),
+            3,
+            Some("checkpoint-1"),
+        )
+        .await
+        .unwrap();
+    let offset = get_offset(&client, &consumer, 1).await;
+    assert_eq!(offset.stored_offset, 3);
+    assert_eq!(offset.metadata.as_deref(), Some("checkpoint-1"));
+    assert!(offset.committed_at.as_micros() > 0);
+
+    // 3. Store the offsets for all the partitions at once
+    let offsets = (1..=PARTITIONS_COUNT)
+        .map(|partition_id| {
+            ConsumerPartitionOffset::new(
+                partition_id,
+                partition_id as u64 + 4,
+                (partition_id > 1).then(|| format!("checkpoint-{partition_id}")),
+            )
+        })
+        .collect::<Vec<_>>();
+    client
+        .store_consumer_offsets(&consumer, &stream_id, &topic_id, &offsets)
+        .await
+        .unwrap();
+    for expected_offset in &offsets {
+        let offset = get_offset(&client, &consumer, expected_offset.partition_id).await;
+        assert_eq!(offset.stored_offset, expected_offset.offset);
+        assert_eq!(offset.metadata, expected_offset.metadata);
+    }
+
+    // 4. Ensure that none of the offsets is stored if any of them is invalid
+    let invalid_offsets = vec![
+        ConsumerPartitionOffset::new(1, 1, None),
+        ConsumerPartitionOffset::new(2, MESSAGES_PER_PARTITION, None),
+    ];
+    let result = client
+        .store_consumer_offsets(&consumer, &stream_id, &topic_id, &invalid_offsets)
+        .await;
+    assert!(result.is_err());
+    for expected_offset in &offsets {
+        let offset = get_offset(&client, &consumer, expected_offset.partition_id).await;
+        assert_eq!(offset.stored_offset, expected_offset.offset);
+    }
+
+    // 5. Ensure that the offsets for the same partition cannot be stored twice at once
+    let duplicated_offsets = vec![
+        ConsumerPartitionOffset::new(1, 1, None),
+        ConsumerPartitionOffset::new(1, 2, None),
+    ];
+    let result = client
+        .store_consumer_offsets(&consumer, &stream_id, &topic_id, &duplicated_offsets)
+        .await;
+    assert!(result.is_err());
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn get_offset(
+    client: &IggyClient,
+    consumer: &Consumer,
+    partition_id: u32,
+) -> ConsumerOffsetInfo {
+    client
+        .get_consumer_offset(
+            consumer,
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(partition_id),
+        )
+        .await
+        .unwrap()
+        .expect("Failed to get consumer offset")
+}
+
+async fn send_messages(client: &IggyClient, partition_id: u32) {
+    let mut messages = (0..MESSAGES_PER_PARTITION)
+        .map(|id| {
+            IggyMessage::builder()
+                .payload(Bytes::from(format!("message-{id}")))
+                .build()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(partition_id),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
@@ -0,0 +1,220 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    CONSUMER_GROUP_ID, CONSUMER_GROUP_NAME, CONSUMER_ID, PARTITION_ID, PARTITIONS_COUNT, STREAM_ID,
+    STREAM_NAME, TOPIC_ID, TOPIC_NAME, create_client, join_consumer_group,
+};
+use futures::StreamExt;
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, assert_clean_system, login_root};
+use std::str::{FromStr, from_utf8};
+use std::time::{Duration, Instant};
+use tokio::time::{sleep, timeout};
+
+const SHORT_WAIT: Duration = Duration::from_millis(500);
+const LONG_WAIT: Duration = Duration::from_secs(3);
+const APPEND_DELAY: Duration = Duration::from_millis(200);
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    let producer = create_client(client_factory).await;
+    login_root(&client).await;
+    login_root(&producer).await;
+    init_system(&client).await;
+    let consumer = Consumer::new(Identifier::numeric(CONSUMER_ID).unwrap());
+
+    // 1. Without any messages, the poll should wait for the max wait and return the empty batch
+    let started_at = Instant::now();
+    let polled_messages =
+        long_poll_messages(&client, &consumer, Some(PARTITION_ID), SHORT_WAIT, 0).await;
+    assert!(polled_messages.messages.is_empty());
+    assert!(started_at.elapsed() >= SHORT_WAIT);
+
+    // 2. The poll should be answered as soon as the message is appended
+    let started_at = Instant::now();
+    let (polled_messages, _) = tokio::join!(
+        long_poll_messages(&client, &consumer, Some(PARTITION_ID), LONG_WAIT, 0),
+        send_message_after_delay(&producer, PARTITION_ID, "message-1"),
+    );
+    assert_eq!(polled_messages.messages.len(), 1);
+    assert_eq!(polled_messages.messages[0].header.offset, 0);
+    assert_eq!(
+        from_utf8(&polled_messages.messages[0].payload).unwrap(),
+        "message-1"
+    );
+    assert!(started_at.elapsed() < LONG_WAIT);
+
+    // 3. The messages smaller than the min bytes should be returned (and committed) only after the max wait
+    send_message(&producer, PARTITION_ID, "message-2").await;
+    let started_at = Instant::now();
+    let polled_messages = long_poll_messages(
+        &client,
+        &consumer,
+        Some(PARTITION_ID),
+        SHORT_WAIT,
+        1_000_000,
+    )
+    .await;
+    assert_eq!(polled_messages.messages.len(), 1);
+    assert_eq!(polled_messages.messages[0].header.offset, 1);
+    assert!(started_at.elapsed() >= SHORT_WAIT);
+    let offset = client
+        .get_consumer_offset(
+            &consumer,
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(PARTITION_ID),
+        )
+        .await
+        .unwrap()
+        .expect("Failed to get consumer offset");
+    assert_eq!(offset.stored_offset, 1);
+
+    // 4. The consumer group member should be answered when the message is appended to any partition
+    join_consumer_group(&client).await;
+    let consumer_group = Consumer::group(Identifier::numeric(CONSUMER_GROUP_ID).unwrap());
+    let mut polled_messages_count = 0;
+    for _ in 0..PARTITIONS_COUNT {
+        polled_messages_count +=
+            long_poll_messages(&client, &consumer_group, None, Duration::ZERO, 0)
+                .await
+                .messages
+                .len();
+    }
+    assert_eq!(polled_messages_count, 2);
+    let started_at = Instant::now();
+    let (polled_messages, _) = tokio::join!(
+        long_poll_messages(&client, &consumer_group, None, LONG_WAIT, 0),
+        send_message_after_delay(&producer, PARTITIONS_COUNT, "message-3"),
+    );
+    assert_eq!(polled_messages.messages.len(), 1);
+    assert_eq!(polled_messages.partition_id, PARTITIONS_COUNT);
+    assert_eq!(
+        from_utf8(&polled_messages.messages[0].payload).unwrap(),
+        "message-3"
+    );
+    assert!(started_at.elapsed() < LONG_WAIT);
+
+    // 5. The consumer with the long polling enabled should receive the message once it's appended
+    let mut iggy_consumer = client
+        .consumer(
+            "long-polling-consumer",
+            STREAM_NAME,
+            TOPIC_NAME,
+            PARTITION_ID,
+        )
+        .unwrap()
+        .polling_strategy(PollingStrategy::offset(2))
+        .long_polling(IggyDuration::ONE_SECOND, 0)
+        .build();
+    iggy_consumer.init().await.unwrap();
+    let (received_message, _) = tokio::join!(
+        timeout(LONG_WAIT, iggy_consumer.next()),
+        send_message_after_delay(&producer, PARTITION_ID, "message-4"),
+    );
+    let received_message = received_message
+        .expect("Failed to receive the message in time")
+        .unwrap()
+        .unwrap();
+    assert_eq!(received_message.message.header.offset, 2);
+    assert_eq!(
+        from_utf8(&received_message.message.payload).unwrap(),
+        "message-4"
+    );
+    drop(iggy_consumer);
+
+    client
+        .delete_stream(&Identifier::numeric(STREAM_ID).unwrap())
+        .await
+        .unwrap();
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            TopicOptions::default(),
+        )
+        .await
+        .unwrap();
+    client
+        .create_consumer_group(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            CONSUMER_GROUP_NAME,
+            Some(CONSUMER_GROUP_ID),
+            ConsumerGroupAssignmentStrategy::default(),
+        )
+        .await
+        .unwrap();
+}
+
+async fn long_poll_messages(
+    client: &IggyClient,
+    consumer: &Consumer,
+    partition_id: Option<u32>,
+    max_wait: Duration,
+    min_bytes: u32,
+) -> PolledMessages {
+    client
+        .long_poll_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            partition_id,
+            consumer,
+            &PollingStrategy::next(),
+            10,
+            true,
+            None,
+            max_wait.into(),
+            min_bytes,
+        )
+        .await
+        .unwrap()
+}
+
+async fn send_message_after_delay(client: &IggyClient, partition_id: u32, payload: &str) {
+    sleep(APPEND_DELAY).await;
+    send_message(client, partition_id, payload).await;
+}
+
+async fn send_message(client: &IggyClient, partition_id: u32, payload: &str) {
+    let mut messages = vec![IggyMessage::from_str(payload).unwrap()];
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(partition_id),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
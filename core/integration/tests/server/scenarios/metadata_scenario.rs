@@ -0,0 +1,241 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    CONSUMER_GROUP_ID, CONSUMER_GROUP_NAME, PARTITIONS_COUNT, STREAM_ID, STREAM_NAME, TOPIC_ID,
+    TOPIC_NAME, USERNAME_1, create_client,
+};
+use iggy::prelude::*;
+use integration::test_server::{
+    ClientFactory, assert_clean_system, delete_user, login_root, login_user,
+};
+use std::str::FromStr;
+
+const PASSWORD: &str = "secret";
+const ROLE_NAME: &str = "reader";
+const PAT_NAME: &str = "metadata";
+const NEW_STREAM_ID: u32 = 2;
+const NEW_STREAM_NAME: &str = "new-stream";
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let root_client = create_client(client_factory).await;
+    login_root(&root_client).await;
+
+    // 1. Create the stream with topic and consumer group, the role, the user with the role and the root token
+    root_client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    let message_expiry = IggyExpiry::from_str("1day").unwrap();
+    root_client
+        .create_topic(
+            &STREAM_ID.try_into().unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::Gzip,
+            None,
+            Some(TOPIC_ID),
+            message_expiry,
+            MaxTopicSize::Unlimited,
+            TopicOptions::default(),
+        )
+        .await
+        .unwrap();
+    root_client
+        .create_consumer_group(
+            &STREAM_ID.try_into().unwrap(),
+            &TOPIC_ID.try_into().unwrap(),
+            CONSUMER_GROUP_NAME,
+            Some(CONSUMER_GROUP_ID),
+            ConsumerGroupAssignmentStrategy::RoundRobin,
+        )
+        .await
+        .unwrap();
+    root_client
+        .create_role(
+            ROLE_NAME,
+            vec![RoleRule::allow(
+                ResourcePattern::stream("*"),
+                &[RoleAction::ReadStream],
+            )],
+        )
+        .await
+        .unwrap();
+    let permissions = Permissions {
+        global: GlobalPermissions {
+            read_servers: true,
+            read_streams: true,
+            read_users: true,
+            ..Default::default()
+        },
+        streams: None,
+    };
+    root_client
+        .create_user(
+            USERNAME_1,
+            PASSWORD,
+            UserStatus::Active,
+            Some(permissions.clone()),
+            None,
+        )
+        .await
+        .unwrap();
+    root_client
+        .assign_roles(
+            &Identifier::named(USERNAME_1).unwrap(),
+            vec![ROLE_NAME.to_string()],
+        )
+        .await
+        .unwrap();
+    root_client
+        .create_personal_access_token(PAT_NAME, PersonalAccessTokenExpiry::NeverExpire)
+        .await
+        .unwrap();
+
+    // 2. Export the metadata without the password hashes
+    let metadata = root_client.export_metadata(false).await.unwrap();
+    assert_eq!(metadata.streams.len(), 1);
+    let stream = &metadata.streams[0];
+    assert_eq!(stream.id, STREAM_ID);
+    assert_eq!(stream.name, STREAM_NAME);
+    assert_eq!(stream.topics.len(), 1);
+    let topic = &stream.topics[0];
+    assert_eq!(topic.id, TOPIC_ID);
+    assert_eq!(topic.name, TOPIC_NAME);
+    assert_eq!(topic.partitions_count, PARTITIONS_COUNT);
+    assert_eq!(topic.compression_algorithm, CompressionAlgorithm::Gzip);
+    assert_eq!(topic.message_expiry, message_expiry);
+    assert_eq!(topic.consumer_groups.len(), 1);
+    assert_eq!(topic.consumer_groups[0].id, CONSUMER_GROUP_ID);
+    assert_eq!(topic.consumer_groups[0].name, CONSUMER_GROUP_NAME);
+    assert_eq!(metadata.roles.len(), 1);
+    assert_eq!(metadata.roles[0].name, ROLE_NAME);
+    assert_eq!(metadata.users.len(), 2);
+    let root = &metadata.users[0];
+    assert_eq!(root.username, DEFAULT_ROOT_USERNAME);
+    assert_eq!(root.personal_access_tokens.len(), 1);
+    assert_eq!(root.personal_access_tokens[0].name, PAT_NAME);
+    let user = &metadata.users[1];
+    assert_eq!(user.username, USERNAME_1);
+    assert_eq!(user.permissions, Some(permissions));
+    assert_eq!(user.roles, vec![ROLE_NAME.to_string()]);
+    assert!(
+        metadata
+            .users
+            .iter()
+            .all(|user| user.password_hash.is_none())
+    );
+
+    // 3. Export the metadata with the password hashes
+    let metadata_with_hashes = root_client.export_metadata(true).await.unwrap();
+    assert!(
+        metadata_with_hashes
+            .users
+            .iter()
+            .all(|user| user.password_hash.is_some())
+    );
+
+    // 4. Ensure that the user without the permission to manage users can't export the password hashes
+    let user_client = create_client(client_factory).await;
+    login_user(&user_client, USERNAME_1).await;
+    let user_metadata = user_client.export_metadata(false).await.unwrap();
+    assert_eq!(user_metadata, metadata);
+    assert!(user_client.export_metadata(true).await.is_err());
+
+    // 5. Ensure that there's nothing to apply for the same metadata
+    assert!(metadata.plan(&metadata, true).unwrap().is_empty());
+
+    // 6. Plan the changes of the modified metadata
+    let mut desired = metadata.clone();
+    desired.roles.clear();
+    desired.users[1].roles.clear();
+    let topic = &mut desired.streams[0].topics[0];
+    topic.partitions_count += 2;
+    topic.consumer_groups.clear();
+    desired.streams.push(StreamMetadata {
+        id: NEW_STREAM_ID,
+        name: NEW_STREAM_NAME.to_string(),
+        topics: vec![],
+    });
+    let plan = metadata.plan(&desired, true).unwrap();
+    assert_eq!(
+        plan,
+        vec![
+            MetadataOperation::DeleteConsumerGroup {
+                stream: STREAM_NAME.to_string(),
+                topic: TOPIC_NAME.to_string(),
+                name: CONSUMER_GROUP_NAME.to_string(),
+            },
+            MetadataOperation::DeleteRole {
+                name: ROLE_NAME.to_string(),
+            },
+            MetadataOperation::CreatePartitions {
+                stream: STREAM_NAME.to_string(),
+                topic: TOPIC_NAME.to_string(),
+                count: 2,
+            },
+            MetadataOperation::CreateStream {
+                id: NEW_STREAM_ID,
+                name: NEW_STREAM_NAME.to_string(),
+            },
+            MetadataOperation::AssignRoles {
+                username: USERNAME_1.to_string(),
+                roles: vec![],
+            },
+        ]
+    );
+
+    // 7. Delete the user, role, token and stream
+    delete_user(&root_client, USERNAME_1).await;
+    root_client.delete_role(ROLE_NAME).await.unwrap();
+    root_client
+        .delete_personal_access_token(PAT_NAME)
+        .await
+        .unwrap();
+    root_client
+        .delete_stream(&STREAM_ID.try_into().unwrap())
+        .await
+        .unwrap();
+    let metadata = root_client.export_metadata(false).await.unwrap();
+    assert!(metadata.streams.is_empty());
+    assert!(metadata.roles.is_empty());
+    assert_eq!(metadata.users.len(), 1);
+
+    // 8. Import the deleted user with the exported password hash, so that it can log in with the same password
+    let exported_user = &metadata_with_hashes.users[1];
+    root_client
+        .import_user(
+            &exported_user.username,
+            exported_user.password_hash.as_deref().unwrap(),
+            exported_user.status,
+            exported_user.permissions.clone(),
+            None,
+        )
+        .await
+        .unwrap();
+    assert!(
+        root_client
+            .import_user("invalid", PASSWORD, UserStatus::Active, None, None)
+            .await
+            .is_err()
+    );
+    let imported_user_client = create_client(client_factory).await;
+    login_user(&imported_user_client, USERNAME_1).await;
+    delete_user(&root_client, USERNAME_1).await;
+    assert_clean_system(&root_client).await;
+}
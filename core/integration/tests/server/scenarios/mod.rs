@@ -23,14 +23,24 @@ use iggy::prelude::Identifier;
 use iggy::prelude::{ConsumerGroupClient, StreamClient};
 use integration::test_server::{ClientFactory, delete_user};
 
+pub mod concurrent_requests_scenario;
 pub mod consumer_group_join_scenario;
+pub mod consumer_group_offsets_reset_scenario;
 pub mod consumer_group_with_multiple_clients_polling_messages_scenario;
 pub mod consumer_group_with_single_client_polling_messages_scenario;
+pub mod consumer_offsets_scenario;
 pub mod create_message_payload;
+pub mod long_polling_scenario;
 pub mod message_headers_scenario;
 pub mod message_size_scenario;
+pub mod metadata_scenario;
+pub mod partition_move_scenario;
+pub mod pipelined_requests_scenario;
+pub mod roles_scenario;
+pub mod schemas_scenario;
 pub mod stream_size_validation_scenario;
 pub mod system_scenario;
+pub mod user_quotas_scenario;
 pub mod user_scenario;
 
 const STREAM_ID: u32 = 1;
@@ -0,0 +1,154 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    CONSUMER_ID, PARTITION_ID, PARTITIONS_COUNT, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+    create_client,
+};
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, assert_clean_system, login_root};
+use std::fs;
+use std::str::FromStr;
+
+const MESSAGES_PER_BATCH: u32 = 10;
+
+pub async fn run(client_factory: &dyn ClientFactory, system_path: &str, data_directory: &str) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let partition_path = get_partition_path(system_path, PARTITION_ID);
+    let moved_partition_path = get_partition_path(data_directory, PARTITION_ID);
+
+    // 1. The partition should be moved to the data directory and linked from the system path
+    send_messages(&client, PARTITION_ID).await;
+    move_partition(&client, PARTITION_ID, data_directory)
+        .await
+        .unwrap();
+    assert!(fs::symlink_metadata(&partition_path).unwrap().is_symlink());
+    assert!(fs::metadata(&moved_partition_path).unwrap().is_dir());
+    assert_eq!(poll_messages_count(&client).await, MESSAGES_PER_BATCH);
+
+    // 2. The moved partition should keep accepting the messages, and moving it again should be a no-op
+    send_messages(&client, PARTITION_ID).await;
+    move_partition(&client, PARTITION_ID, data_directory)
+        .await
+        .unwrap();
+    assert_eq!(poll_messages_count(&client).await, MESSAGES_PER_BATCH * 2);
+
+    // 3. The data directory must be configured and the partition must exist
+    let result = move_partition(&client, PARTITION_ID, "not-configured").await;
+    assert!(result.is_err());
+    let result = move_partition(&client, PARTITIONS_COUNT + 1, data_directory).await;
+    assert!(result.is_err());
+
+    // 4. The partition should be moved back to the system path, removing the previous location
+    move_partition(&client, PARTITION_ID, system_path)
+        .await
+        .unwrap();
+    assert!(fs::symlink_metadata(&partition_path).unwrap().is_dir());
+    assert!(fs::metadata(&moved_partition_path).is_err());
+    assert_eq!(poll_messages_count(&client).await, MESSAGES_PER_BATCH * 2);
+
+    // 5. Deleting the stream should remove the moved partitions from the data directory as well
+    move_partition(&client, PARTITIONS_COUNT, data_directory)
+        .await
+        .unwrap();
+    assert!(
+        fs::metadata(get_partition_path(data_directory, PARTITIONS_COUNT))
+            .unwrap()
+            .is_dir()
+    );
+    client
+        .delete_stream(&Identifier::numeric(STREAM_ID).unwrap())
+        .await
+        .unwrap();
+    assert!(fs::metadata(get_partition_path(data_directory, PARTITIONS_COUNT)).is_err());
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            TopicOptions::default(),
+        )
+        .await
+        .unwrap();
+}
+
+async fn move_partition(
+    client: &IggyClient,
+    partition_id: u32,
+    data_directory: &str,
+) -> Result<(), IggyError> {
+    client
+        .move_partition(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            partition_id,
+            data_directory,
+        )
+        .await
+}
+
+async fn send_messages(client: &IggyClient, partition_id: u32) {
+    let mut messages = (0..MESSAGES_PER_BATCH)
+        .map(|id| IggyMessage::from_str(&format!("message-{id}")).unwrap())
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(partition_id),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
+
+async fn poll_messages_count(client: &IggyClient) -> u32 {
+    client
+        .poll_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(PARTITION_ID),
+            &Consumer::new(Identifier::numeric(CONSUMER_ID).unwrap()),
+            &PollingStrategy::offset(0),
+            MESSAGES_PER_BATCH * 10,
+            false,
+        )
+        .await
+        .unwrap()
+        .messages
+        .len() as u32
+}
+
+fn get_partition_path(data_directory: &str, partition_id: u32) -> String {
+    format!("{data_directory}/streams/{STREAM_ID}/topics/{TOPIC_ID}/partitions/{partition_id}")
+}
@@ -0,0 +1,142 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    CONSUMER_GROUP_ID, CONSUMER_GROUP_NAME, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID,
+    TOPIC_NAME, create_client, join_consumer_group,
+};
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, assert_clean_system, login_root};
+use std::str::{FromStr, from_utf8};
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
+
+const LONG_WAIT: Duration = Duration::from_secs(3);
+const APPEND_DELAY: Duration = Duration::from_millis(200);
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    // The long polls by a consumer group are bound to the session, so they're sent using the primary connection.
+    join_consumer_group(&client).await;
+    let consumer = Consumer::group(Identifier::numeric(CONSUMER_GROUP_ID).unwrap());
+
+    // 1. The request pipelined after the long poll should be answered without waiting for the long poll
+    let started_at = Instant::now();
+    let (polled_messages, (stream_name, answered_at)) = tokio::join!(
+        long_poll_messages(&client, &consumer),
+        get_stream_name_after_delay(&client),
+    );
+    assert!(polled_messages.messages.is_empty());
+    assert!(started_at.elapsed() >= LONG_WAIT);
+    assert_eq!(stream_name, STREAM_NAME);
+    assert!(answered_at.duration_since(started_at) < LONG_WAIT);
+
+    // 2. The message sent on the same connection should answer the pipelined long poll
+    let started_at = Instant::now();
+    let (polled_messages, _) = tokio::join!(
+        long_poll_messages(&client, &consumer),
+        send_message_after_delay(&client, "message-1"),
+    );
+    assert_eq!(polled_messages.messages.len(), 1);
+    assert_eq!(
+        from_utf8(&polled_messages.messages[0].payload).unwrap(),
+        "message-1"
+    );
+    assert!(started_at.elapsed() < LONG_WAIT);
+
+    client
+        .delete_stream(&Identifier::numeric(STREAM_ID).unwrap())
+        .await
+        .unwrap();
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            TopicOptions::default(),
+        )
+        .await
+        .unwrap();
+    client
+        .create_consumer_group(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            CONSUMER_GROUP_NAME,
+            Some(CONSUMER_GROUP_ID),
+            ConsumerGroupAssignmentStrategy::default(),
+        )
+        .await
+        .unwrap();
+}
+
+async fn long_poll_messages(client: &IggyClient, consumer: &Consumer) -> PolledMessages {
+    client
+        .long_poll_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            None,
+            consumer,
+            &PollingStrategy::next(),
+            10,
+            true,
+            None,
+            LONG_WAIT.into(),
+            0,
+        )
+        .await
+        .unwrap()
+}
+
+async fn get_stream_name_after_delay(client: &IggyClient) -> (String, Instant) {
+    sleep(APPEND_DELAY).await;
+    let stream = client
+        .get_stream(&Identifier::numeric(STREAM_ID).unwrap())
+        .await
+        .unwrap()
+        .expect("Failed to get stream");
+    (stream.name, Instant::now())
+}
+
+async fn send_message_after_delay(client: &IggyClient, payload: &str) {
+    sleep(APPEND_DELAY).await;
+    let mut messages = vec![IggyMessage::from_str(payload).unwrap()];
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
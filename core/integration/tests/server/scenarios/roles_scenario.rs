@@ -0,0 +1,239 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{PARTITION_ID, USERNAME_1, create_client};
+use bytes::Bytes;
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, assert_clean_system, login_root};
+
+const PASSWORD: &str = "secret";
+const ROLE_NAME: &str = "tenant";
+const TENANT_STREAM_ID: u32 = 1;
+const TENANT_STREAM_NAME: &str = "tenant-a";
+const OTHER_STREAM_ID: u32 = 2;
+const OTHER_STREAM_NAME: &str = "other";
+const ORDERS_TOPIC_ID: u32 = 1;
+const ORDERS_TOPIC_NAME: &str = "orders";
+const INTERNAL_TOPIC_ID: u32 = 2;
+const INTERNAL_TOPIC_NAME: &str = "internal-audit";
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let root_client = create_client(client_factory).await;
+    login_root(&root_client).await;
+
+    // 1. Create the tenant stream with two topics, another stream and the user without any permissions
+    for (stream_id, stream_name) in [
+        (TENANT_STREAM_ID, TENANT_STREAM_NAME),
+        (OTHER_STREAM_ID, OTHER_STREAM_NAME),
+    ] {
+        root_client
+            .create_stream(stream_name, Some(stream_id))
+            .await
+            .unwrap();
+    }
+    for (topic_id, topic_name) in [
+        (ORDERS_TOPIC_ID, ORDERS_TOPIC_NAME),
+        (INTERNAL_TOPIC_ID, INTERNAL_TOPIC_NAME),
+    ] {
+        root_client
+            .create_topic(
+                &TENANT_STREAM_ID.try_into().unwrap(),
+                topic_name,
+                1,
+                Default::default(),
+                None,
+                Some(topic_id),
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+                TopicOptions::default(),
+            )
+            .await
+            .unwrap();
+    }
+    root_client
+        .create_user(USERNAME_1, PASSWORD, UserStatus::Active, None, None)
+        .await
+        .unwrap();
+    let user_client = create_client(client_factory).await;
+    user_client.login_user(USERNAME_1, PASSWORD).await.unwrap();
+    assert!(
+        user_client
+            .get_stream(&TENANT_STREAM_ID.try_into().unwrap())
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert_unauthorized(user_client.get_streams().await);
+
+    // 2. Create the role allowing to read and write the tenant streams, except polling the internal topics
+    root_client
+        .create_role(
+            ROLE_NAME,
+            vec![
+                RoleRule::allow(
+                    ResourcePattern::stream("tenant-*"),
+                    &[RoleAction::ReadStream, RoleAction::SendMessages],
+                ),
+                RoleRule::deny(
+                    ResourcePattern::topic("tenant-*", "internal-*"),
+                    &[RoleAction::PollMessages],
+                ),
+            ],
+        )
+        .await
+        .unwrap();
+    let roles = root_client.get_roles().await.unwrap();
+    assert_eq!(roles.len(), 1);
+    assert_eq!(roles[0].name, ROLE_NAME);
+    assert_eq!(roles[0].rules.len(), 2);
+
+    // 3. Creating the role with the same name should fail
+    let result = root_client.create_role(ROLE_NAME, vec![]).await;
+    assert!(result.is_err());
+
+    // 4. Assign the role to the user
+    root_client
+        .assign_roles(
+            &Identifier::named(USERNAME_1).unwrap(),
+            vec![ROLE_NAME.to_string()],
+        )
+        .await
+        .unwrap();
+    let user = root_client
+        .get_user(&Identifier::named(USERNAME_1).unwrap())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(user.roles, vec![ROLE_NAME.to_string()]);
+
+    // 5. The user should have access only to the resources matching the role rules
+    let stream = user_client
+        .get_stream(&TENANT_STREAM_ID.try_into().unwrap())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stream.name, TENANT_STREAM_NAME);
+    assert!(
+        user_client
+            .get_stream(&OTHER_STREAM_ID.try_into().unwrap())
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert_unauthorized(
+        user_client
+            .delete_stream(&TENANT_STREAM_ID.try_into().unwrap())
+            .await,
+    );
+    assert_unauthorized(user_client.create_stream("tenant-b", None).await);
+    let streams = user_client.get_streams().await.unwrap();
+    assert_eq!(streams.len(), 1);
+    assert_eq!(streams[0].name, TENANT_STREAM_NAME);
+    let topics = user_client
+        .get_topics(&TENANT_STREAM_ID.try_into().unwrap())
+        .await
+        .unwrap();
+    assert_eq!(topics.len(), 2);
+    send_message(&user_client, ORDERS_TOPIC_ID).await.unwrap();
+    send_message(&user_client, INTERNAL_TOPIC_ID).await.unwrap();
+    let polled_messages = poll_message(&user_client, ORDERS_TOPIC_ID).await.unwrap();
+    assert_eq!(polled_messages.messages.len(), 1);
+    assert_unauthorized(poll_message(&user_client, INTERNAL_TOPIC_ID).await);
+
+    // 6. Update the role to remove the deny rule, so the internal topic can be polled
+    root_client
+        .update_role(
+            ROLE_NAME,
+            vec![RoleRule::allow(
+                ResourcePattern::stream("tenant-*"),
+                &[RoleAction::ReadStream, RoleAction::SendMessages],
+            )],
+        )
+        .await
+        .unwrap();
+    let polled_messages = poll_message(&user_client, INTERNAL_TOPIC_ID).await.unwrap();
+    assert_eq!(polled_messages.messages.len(), 1);
+
+    // 7. Delete the role, which should also unassign it from the user
+    root_client.delete_role(ROLE_NAME).await.unwrap();
+    assert!(root_client.get_roles().await.unwrap().is_empty());
+    let user = root_client
+        .get_user(&Identifier::named(USERNAME_1).unwrap())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(user.roles.is_empty());
+    assert!(
+        user_client
+            .get_stream(&TENANT_STREAM_ID.try_into().unwrap())
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    // 8. Delete the user and streams
+    root_client
+        .delete_user(&Identifier::named(USERNAME_1).unwrap())
+        .await
+        .unwrap();
+    for stream_id in [TENANT_STREAM_ID, OTHER_STREAM_ID] {
+        root_client
+            .delete_stream(&stream_id.try_into().unwrap())
+            .await
+            .unwrap();
+    }
+    assert_clean_system(&root_client).await;
+}
+
+fn assert_unauthorized<T: std::fmt::Debug>(result: Result<T, IggyError>) {
+    assert_eq!(
+        result.unwrap_err().as_code(),
+        IggyError::Unauthorized.as_code()
+    );
+}
+
+async fn send_message(client: &IggyClient, topic_id: u32) -> Result<(), IggyError> {
+    let mut messages = vec![
+        IggyMessage::builder()
+            .payload(Bytes::from("message"))
+            .build()
+            .unwrap(),
+    ];
+    client
+        .send_messages(
+            &TENANT_STREAM_ID.try_into().unwrap(),
+            &topic_id.try_into().unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+}
+
+async fn poll_message(client: &IggyClient, topic_id: u32) -> Result<PolledMessages, IggyError> {
+    client
+        .poll_messages(
+            &TENANT_STREAM_ID.try_into().unwrap(),
+            &topic_id.try_into().unwrap(),
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            1,
+            false,
+        )
+        .await
+}
@@ -0,0 +1,213 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME, create_client,
+};
+use bytes::Bytes;
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, assert_clean_system, login_root};
+
+const ORDER_V1: &str = r#"{
+    "type": "object",
+    "properties": {
+        "id": { "type": "integer" },
+        "status": { "type": "string", "enum": ["new", "paid"] }
+    },
+    "required": ["id"]
+}"#;
+const ORDER_V2: &str = r#"{
+    "type": "object",
+    "properties": {
+        "id": { "type": "integer" },
+        "status": { "type": "string", "enum": ["new", "paid"] },
+        "note": { "type": "string" }
+    },
+    "required": ["id"]
+}"#;
+const ORDER_WITH_REQUIRED_AMOUNT: &str = r#"{
+    "type": "object",
+    "properties": {
+        "id": { "type": "integer" },
+        "amount": { "type": "number" }
+    },
+    "required": ["id", "amount"]
+}"#;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+
+    // 1. Create the stream and topic without any schemas
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            1,
+            Default::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            TopicOptions::default(),
+        )
+        .await
+        .unwrap();
+    assert!(
+        client
+            .get_schemas(&stream_id, &topic_id)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+
+    // 2. Register the first version of the schema
+    let v1 = register_schema(&client, ORDER_V1, SchemaCompatibility::Backward)
+        .await
+        .unwrap();
+    assert_eq!(v1.version, 1);
+    assert_eq!(v1.stream_id, STREAM_ID);
+    assert_eq!(v1.topic_id, TOPIC_ID);
+    assert_eq!(v1.schema_type, SchemaType::Json);
+    assert_eq!(v1.definition, Bytes::from(ORDER_V1));
+
+    // 3. Registering the schema with the new required property should not be backward compatible
+    assert!(
+        register_schema(
+            &client,
+            ORDER_WITH_REQUIRED_AMOUNT,
+            SchemaCompatibility::Backward
+        )
+        .await
+        .is_err()
+    );
+
+    // 4. Register the second version adding the optional property, compatible both ways
+    let v2 = register_schema(&client, ORDER_V2, SchemaCompatibility::Full)
+        .await
+        .unwrap();
+    assert_eq!(v2.version, 2);
+    assert_ne!(v2.id, v1.id);
+    let schemas = client.get_schemas(&stream_id, &topic_id).await.unwrap();
+    assert_eq!(schemas.len(), 2);
+    assert_eq!(schemas[0].id, v1.id);
+    assert_eq!(schemas[1].id, v2.id);
+    let schema = client.get_schema(v2.id).await.unwrap().unwrap();
+    assert_eq!(schema, v2);
+
+    // 5. The messages referencing the schema should be validated, the ones without the header are not
+    send_message(&client, r#"{"id": 1, "status": "new"}"#, Some(v1.id))
+        .await
+        .unwrap();
+    send_message(&client, r#"{"id": 2, "note": "gift"}"#, Some(v2.id))
+        .await
+        .unwrap();
+    send_message(&client, "not json", None).await.unwrap();
+    assert!(
+        send_message(&client, r#"{"status": "new"}"#, Some(v1.id))
+            .await
+            .is_err()
+    );
+    assert!(
+        send_message(&client, r#"{"id": 3, "status": "sent"}"#, Some(v2.id))
+            .await
+            .is_err()
+    );
+    assert!(
+        send_message(&client, r#"{"id": 4}"#, Some(v2.id + 100))
+            .await
+            .is_err()
+    );
+    let polled_messages = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            10,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), 3);
+    assert_eq!(polled_messages.messages[0].schema_id(), Ok(Some(v1.id)));
+    assert_eq!(polled_messages.messages[2].schema_id(), Ok(None));
+
+    // 6. Delete the first version, the messages referencing it should no longer be accepted
+    client.delete_schema(v1.id).await.unwrap();
+    assert!(client.get_schema(v1.id).await.unwrap().is_none());
+    assert!(client.delete_schema(v1.id).await.is_err());
+    assert!(
+        send_message(&client, r#"{"id": 5}"#, Some(v1.id))
+            .await
+            .is_err()
+    );
+    let schemas = client.get_schemas(&stream_id, &topic_id).await.unwrap();
+    assert_eq!(schemas.len(), 1);
+    assert_eq!(schemas[0].id, v2.id);
+
+    // 7. Delete the stream, which should also delete the schemas of its topics
+    client.delete_stream(&stream_id).await.unwrap();
+    assert!(client.get_schema(v2.id).await.unwrap().is_none());
+    assert_clean_system(&client).await;
+}
+
+async fn register_schema(
+    client: &IggyClient,
+    definition: &str,
+    compatibility: SchemaCompatibility,
+) -> Result<Schema, IggyError> {
+    client
+        .register_schema(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            SchemaType::Json,
+            compatibility,
+            Bytes::from(definition.to_owned()),
+        )
+        .await
+}
+
+async fn send_message(
+    client: &IggyClient,
+    payload: &str,
+    schema_id: Option<u32>,
+) -> Result<(), IggyError> {
+    let mut messages = vec![
+        IggyMessage::builder()
+            .payload(Bytes::from(payload.to_owned()))
+            .maybe_schema_id(schema_id)
+            .build()
+            .unwrap(),
+    ];
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+}
@@ -147,6 +147,7 @@ async fn create_topic_assert_empty(client: &IggyClient, stream_name: &str, topic
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            TopicOptions::default(),
         )
         .await
         .unwrap();
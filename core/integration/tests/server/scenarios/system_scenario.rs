@@ -112,6 +112,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             Some(TOPIC_ID),
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            TopicOptions::default(),
         )
         .await
         .unwrap();
@@ -202,6 +203,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             Some(TOPIC_ID),
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            TopicOptions::default(),
         )
         .await;
     assert!(create_topic_result.is_err());
@@ -217,6 +219,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             Some(TOPIC_ID + 1),
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            TopicOptions::default(),
         )
         .await;
     assert!(create_topic_result.is_err());
@@ -336,6 +339,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             &Identifier::numeric(TOPIC_ID).unwrap(),
             Some(PARTITION_ID),
             stored_offset,
+            None,
         )
         .await
         .unwrap();
@@ -386,6 +390,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             &Identifier::numeric(TOPIC_ID).unwrap(),
             Some(PARTITION_ID),
             stored_offset,
+            None,
         )
         .await
         .unwrap();
@@ -444,6 +449,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             &Identifier::numeric(TOPIC_ID).unwrap(),
             CONSUMER_GROUP_NAME,
             Some(CONSUMER_GROUP_ID),
+            ConsumerGroupAssignmentStrategy::default(),
         )
         .await
         .unwrap();
@@ -608,6 +614,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             Some(updated_replication_factor),
             IggyExpiry::ExpireDuration(message_expiry_duration),
             updated_max_topic_size,
+            TopicOptions::default(),
         )
         .await
         .unwrap();
@@ -749,6 +756,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            TopicOptions::default(),
         )
         .await
         .unwrap();
@@ -0,0 +1,136 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    PARTITION_ID, PARTITIONS_COUNT, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME, USERNAME_1,
+    create_client,
+};
+use bytes::Bytes;
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, assert_clean_system, login_root};
+use std::time::{Duration, Instant};
+
+const PASSWORD: &str = "secret";
+const MAX_PRODUCED_MESSAGES_PER_SECOND: u64 = 10;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let root_client = create_client(client_factory).await;
+    login_root(&root_client).await;
+
+    // 1. Create the stream, topic and user allowed to have a single connection and produce 10 messages per second
+    root_client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    root_client
+        .create_topic(
+            &STREAM_ID.try_into().unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            Default::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            TopicOptions::default(),
+        )
+        .await
+        .unwrap();
+    root_client
+        .create_user(
+            USERNAME_1,
+            PASSWORD,
+            UserStatus::Active,
+            Some(Permissions {
+                global: GlobalPermissions {
+                    read_streams: true,
+                    send_messages: true,
+                    ..Default::default()
+                },
+                streams: None,
+            }),
+            Some(UserQuotas {
+                max_produced_messages_per_second: Some(MAX_PRODUCED_MESSAGES_PER_SECOND),
+                max_connections: Some(1),
+                ..Default::default()
+            }),
+        )
+        .await
+        .unwrap();
+
+    // 2. Login as the user
+    let user_client = create_client(client_factory).await;
+    user_client.login_user(USERNAME_1, PASSWORD).await.unwrap();
+
+    // 3. Logging in as the same user from another connection should exceed the connections quota
+    let another_user_client = create_client(client_factory).await;
+    let login_result = another_user_client.login_user(USERNAME_1, PASSWORD).await;
+    assert_eq!(
+        login_result.unwrap_err().as_code(),
+        IggyError::ConnectionsQuotaExceeded(1, 0).as_code()
+    );
+
+    // 4. Sending the messages within the quota should not be throttled
+    let started_at = Instant::now();
+    send_messages(&user_client, MAX_PRODUCED_MESSAGES_PER_SECOND).await;
+    assert!(started_at.elapsed() < Duration::from_secs(1));
+
+    // 5. Sending the messages exceeding the quota should be throttled, but not rejected
+    let started_at = Instant::now();
+    send_messages(&user_client, MAX_PRODUCED_MESSAGES_PER_SECOND).await;
+    assert!(started_at.elapsed() >= Duration::from_millis(900));
+
+    // 6. Logging out should release the connection for another client
+    user_client.logout_user().await.unwrap();
+    another_user_client
+        .login_user(USERNAME_1, PASSWORD)
+        .await
+        .unwrap();
+    another_user_client.logout_user().await.unwrap();
+
+    // 7. Delete the user and stream
+    root_client
+        .delete_user(&Identifier::named(USERNAME_1).unwrap())
+        .await
+        .unwrap();
+    root_client
+        .delete_stream(&STREAM_ID.try_into().unwrap())
+        .await
+        .unwrap();
+    assert_clean_system(&root_client).await;
+}
+
+async fn send_messages(client: &IggyClient, count: u64) {
+    let mut messages = (0..count)
+        .map(|id| {
+            IggyMessage::builder()
+                .payload(Bytes::from(format!("message-{id}")))
+                .build()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &STREAM_ID.try_into().unwrap(),
+            &TOPIC_ID.try_into().unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
@@ -19,6 +19,7 @@
 use crate::server::scenarios::create_client;
 use iggy::prelude::Identifier;
 use iggy::prelude::PersonalAccessTokenExpiry;
+use iggy::prelude::UserQuotas;
 use iggy::prelude::UserStatus;
 use iggy::prelude::defaults::DEFAULT_ROOT_USERNAME;
 use iggy::prelude::{GlobalPermissions, Permissions};
@@ -85,6 +86,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
                 },
                 streams: None,
             }),
+            None,
         )
         .await
         .unwrap();
@@ -106,7 +108,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     // 10. Trying to create a new user (or any other resource) without the appropriate permissions should fail
     let create_user_result = client
-        .create_user("user2", "secret", UserStatus::Active, None)
+        .create_user("user2", "secret", UserStatus::Active, None, None)
         .await;
 
     assert!(create_user_result.is_err());
@@ -197,23 +199,37 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     // 20. Trying to create a new user with the same username should fail
     let create_duplicated_user = client
-        .create_user(test_user, test_password, UserStatus::Active, None)
+        .create_user(test_user, test_password, UserStatus::Active, None, None)
         .await;
 
     assert!(create_duplicated_user.is_err());
 
     // 21. Update user details
     let updated_test_user = "user2";
+    let quotas = UserQuotas {
+        max_produced_bytes_per_second: Some(1_000_000),
+        max_connections: Some(5),
+        ..Default::default()
+    };
 
     client
         .update_user(
             &Identifier::named(test_user).unwrap(),
             Some(updated_test_user),
             Some(UserStatus::Inactive),
+            Some(quotas),
         )
         .await
         .unwrap();
 
+    let user = client
+        .get_user(&Identifier::named(updated_test_user).unwrap())
+        .await
+        .unwrap()
+        .expect("Failed to get user");
+    assert_eq!(user.status, UserStatus::Inactive);
+    assert_eq!(user.quotas, Some(quotas));
+
     // 22. Update user permissions
     client
         .update_permissions(
@@ -17,13 +17,21 @@
  */
 
 use crate::server::scenarios::{
-    consumer_group_join_scenario, consumer_group_with_multiple_clients_polling_messages_scenario,
-    consumer_group_with_single_client_polling_messages_scenario, create_message_payload,
-    message_headers_scenario, message_size_scenario, stream_size_validation_scenario,
-    system_scenario, user_scenario,
+    concurrent_requests_scenario, consumer_group_join_scenario,
+    consumer_group_offsets_reset_scenario,
+    consumer_group_with_multiple_clients_polling_messages_scenario,
+    consumer_group_with_single_client_polling_messages_scenario, consumer_offsets_scenario,
+    create_message_payload, long_polling_scenario, message_headers_scenario, message_size_scenario,
+    metadata_scenario, partition_move_scenario, pipelined_requests_scenario, roles_scenario,
+    schemas_scenario, stream_size_validation_scenario, system_scenario, user_quotas_scenario,
+    user_scenario,
+};
+use integration::{
+    tcp_client::TcpClientFactory,
+    test_server::{IpAddrKind, TestServer},
 };
-use integration::{tcp_client::TcpClientFactory, test_server::TestServer};
 use serial_test::parallel;
+use std::collections::HashMap;
 
 #[tokio::test]
 #[parallel]
@@ -51,6 +59,32 @@ async fn user_scenario_should_be_valid() {
     user_scenario::run(&client_factory).await;
 }
 
+#[tokio::test]
+#[parallel]
+async fn user_quotas_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    user_quotas_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn roles_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    roles_scenario::run(&client_factory).await;
+}
+
 #[tokio::test]
 #[parallel]
 async fn message_headers_scenario_should_be_valid() {
@@ -141,3 +175,167 @@ async fn message_size_scenario_should_be_valid() {
     };
     message_size_scenario::run(&client_factory).await;
 }
+
+#[tokio::test]
+#[parallel]
+async fn consumer_offsets_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    consumer_offsets_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn consumer_group_offsets_reset_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    consumer_group_offsets_reset_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn metadata_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    metadata_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn schemas_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    schemas_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn system_scenario_should_be_valid_after_failing_over_from_unavailable_server() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let unavailable_server_addr = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr: format!("{unavailable_server_addr},{server_addr}"),
+        ..Default::default()
+    };
+    system_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn concurrent_requests_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    concurrent_requests_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn concurrent_requests_scenario_should_be_valid_with_pipelining_and_connection_pool() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        pipelining: true,
+        connection_pool_size: 3,
+        ..Default::default()
+    };
+    concurrent_requests_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn consumer_group_with_multiple_clients_polling_messages_scenario_should_be_valid_with_pipelining_and_connection_pool()
+ {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        pipelining: true,
+        connection_pool_size: 3,
+        ..Default::default()
+    };
+    consumer_group_with_multiple_clients_polling_messages_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn pipelined_requests_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        pipelining: true,
+        ..Default::default()
+    };
+    pipelined_requests_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn long_polling_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    long_polling_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn partition_move_scenario_should_be_valid() {
+    let data_directory = format!("{}_data", TestServer::get_random_path());
+    let extra_envs = HashMap::from([(
+        "IGGY_SYSTEM_DATA_DIRECTORIES".to_string(),
+        format!("[{data_directory}]"),
+    )]);
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    partition_move_scenario::run(
+        &client_factory,
+        test_server.get_local_data_path(),
+        &data_directory,
+    )
+    .await;
+    std::fs::remove_dir_all(&data_directory).unwrap();
+}
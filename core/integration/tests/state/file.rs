@@ -49,6 +49,7 @@ async fn should_apply_single_entry() {
             password: "secret".to_string(),
             status: Default::default(),
             permissions: None,
+            quotas: None,
         },
     });
     let command_bytes = command.to_bytes();
@@ -75,6 +76,7 @@ async fn should_apply_encrypted_entry() {
             password: "secret".to_string(),
             status: Default::default(),
             permissions: None,
+            quotas: None,
         },
     });
     let command_bytes = command.to_bytes();
@@ -106,6 +108,7 @@ async fn should_apply_multiple_entries() {
             password: "secret".to_string(),
             status: Default::default(),
             permissions: None,
+            quotas: None,
         },
     });
     let create_user_bytes = create_user.to_bytes();
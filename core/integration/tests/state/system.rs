@@ -18,6 +18,7 @@
 
 use crate::state::StateSetup;
 use iggy::prelude::IggyExpiry;
+use iggy::prelude::UserQuotas;
 use iggy_common::create_consumer_group::CreateConsumerGroup;
 use iggy_common::create_partitions::CreatePartitions;
 use iggy_common::create_personal_access_token::CreatePersonalAccessToken;
@@ -40,17 +41,23 @@ async fn should_be_initialized_based_on_state_entries() {
     state.init().await.unwrap();
 
     let user_id = 1;
+    let quotas = UserQuotas {
+        max_consumed_bytes_per_second: Some(1_000_000),
+        ..Default::default()
+    };
     let create_user = CreateUser {
         username: "user".to_string(),
         password: "secret".to_string(),
         status: Default::default(),
         permissions: None,
+        quotas: Some(quotas),
     };
     let create_user_clone = CreateUser {
         username: "user".to_string(),
         password: "secret".to_string(),
         status: Default::default(),
         permissions: None,
+        quotas: Some(quotas),
     };
 
     let stream1_id = 1;
@@ -74,6 +81,10 @@ async fn should_be_initialized_based_on_state_entries() {
         max_topic_size: Default::default(),
         name: "topic1".to_string(),
         replication_factor: None,
+        cleanup_policy: Default::default(),
+        partitioning_hash: Default::default(),
+        segment_max_age: Default::default(),
+        max_partition_size: Default::default(),
     };
 
     let create_topic1_clone = CreateTopic {
@@ -85,6 +96,10 @@ async fn should_be_initialized_based_on_state_entries() {
         max_topic_size: Default::default(),
         name: "topic1".to_string(),
         replication_factor: None,
+        cleanup_policy: Default::default(),
+        partitioning_hash: Default::default(),
+        segment_max_age: Default::default(),
+        max_partition_size: Default::default(),
     };
 
     let stream2_id = 2;
@@ -103,6 +118,10 @@ async fn should_be_initialized_based_on_state_entries() {
         max_topic_size: Default::default(),
         name: "topic2".to_string(),
         replication_factor: None,
+        cleanup_policy: Default::default(),
+        partitioning_hash: Default::default(),
+        segment_max_age: Default::default(),
+        max_partition_size: Default::default(),
     };
 
     let create_partitions = CreatePartitions {
@@ -137,6 +156,7 @@ async fn should_be_initialized_based_on_state_entries() {
         topic_id: topic1_id.try_into().unwrap(),
         group_id: Some(group_id),
         name: "test".to_string(),
+        assignment_strategy: Default::default(),
     };
 
     let create_consumer_group_clone = CreateConsumerGroup {
@@ -144,6 +164,7 @@ async fn should_be_initialized_based_on_state_entries() {
         topic_id: topic1_id.try_into().unwrap(),
         group_id: Some(group_id),
         name: "test".to_string(),
+        assignment_strategy: Default::default(),
     };
 
     state
@@ -232,6 +253,7 @@ async fn should_be_initialized_based_on_state_entries() {
     assert_eq!(user.id, user_id);
     assert_eq!(user.username, create_user_clone.username);
     assert_eq!(user.password_hash, create_user_clone.password);
+    assert_eq!(user.quotas, create_user_clone.quotas);
     assert_eq!(user.personal_access_tokens.len(), 1);
 
     let personal_access_token = user
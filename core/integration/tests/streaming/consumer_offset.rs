@@ -17,7 +17,7 @@
  */
 
 use crate::streaming::common::test_setup::TestSetup;
-use iggy::prelude::ConsumerKind;
+use iggy::prelude::{ConsumerKind, IggyTimestamp};
 use server::configs::system::SystemConfig;
 use server::streaming::partitions::partition::ConsumerOffset;
 use server::streaming::storage::PartitionStorageKind;
@@ -48,7 +48,15 @@ async fn assert_persisted_offsets(
     for consumer_id in 1..=consumer_ids_count {
         let expected_offsets_count = consumer_id;
         for offset in 0..=offsets_count {
-            let consumer_offset = ConsumerOffset::new(kind, consumer_id, offset, &path);
+            let metadata = (offset % 2 == 0).then(|| format!("checkpoint-{offset}"));
+            let consumer_offset = ConsumerOffset::new(
+                kind,
+                consumer_id,
+                offset,
+                metadata,
+                IggyTimestamp::now(),
+                &path,
+            );
             assert_persisted_offset(&path, storage, &consumer_offset, expected_offsets_count).await;
         }
     }
@@ -60,10 +68,7 @@ async fn assert_persisted_offset(
     consumer_offset: &ConsumerOffset,
     expected_offsets_count: u32,
 ) {
-    storage
-        .save_consumer_offset(consumer_offset.offset, &consumer_offset.path)
-        .await
-        .unwrap();
+    storage.save_consumer_offset(consumer_offset).await.unwrap();
     let consumer_offsets = storage
         .load_consumer_offsets(consumer_offset.kind, path)
         .await
@@ -73,6 +78,11 @@ async fn assert_persisted_offset(
     let loaded_consumer_offset = consumer_offsets.get(expected_offsets_count - 1).unwrap();
 
     assert_eq!(loaded_consumer_offset.offset, consumer_offset.offset);
+    assert_eq!(loaded_consumer_offset.metadata, consumer_offset.metadata);
+    assert_eq!(
+        loaded_consumer_offset.committed_at.as_micros(),
+        consumer_offset.committed_at.as_micros()
+    );
 
     assert_eq!(loaded_consumer_offset.kind, consumer_offset.kind);
     assert_eq!(
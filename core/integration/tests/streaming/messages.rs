@@ -284,6 +284,7 @@ async fn should_persist_messages_and_then_load_them_from_disk() {
     let partition_state = PartitionState {
         id: partition.partition_id,
         created_at: now,
+        consumer_offsets: Default::default(),
     };
     loaded_partition.load(partition_state).await.unwrap();
     let loaded_messages = loaded_partition
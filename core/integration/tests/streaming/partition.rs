@@ -18,13 +18,19 @@
 
 use crate::streaming::common::test_setup::TestSetup;
 use crate::streaming::create_messages;
-use iggy::prelude::{IggyExpiry, IggyTimestamp, Sizeable};
+use iggy::prelude::locking::{IggySharedMut, IggySharedMutFn};
+use iggy::prelude::{
+    IggyByteSize, IggyDuration, IggyExpiry, IggyTimestamp, MaxTopicSize, Sizeable,
+};
+use server::configs::system::SystemConfig;
 use server::state::system::PartitionState;
 use server::streaming::partitions::partition::Partition;
 use server::streaming::segments::*;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU32, AtomicU64};
 use tokio::fs;
+use uuid::Uuid;
 
 #[tokio::test]
 async fn should_persist_partition_with_segment() {
@@ -106,6 +112,7 @@ async fn should_load_existing_partition_from_disk() {
         let partition_state = PartitionState {
             id: partition.partition_id,
             created_at: now,
+            consumer_offsets: Default::default(),
         };
         loaded_partition.load(partition_state).await.unwrap();
 
@@ -208,6 +215,199 @@ async fn should_purge_existing_partition_on_disk() {
     }
 }
 
+#[tokio::test]
+async fn should_roll_segment_older_than_max_age_and_return_oversized_segments() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        setup.config.clone(),
+        setup.storage.clone(),
+        IggyExpiry::NeverExpire,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU32::new(0)),
+        IggyTimestamp::now(),
+    )
+    .await;
+    partition.persist().await.unwrap();
+    let segment_max_age = IggyExpiry::ExpireDuration(IggyDuration::from_str("1h").unwrap());
+    let later = IggyTimestamp::from(IggyTimestamp::now().as_micros() + 2 * 3_600_000_000);
+
+    // The empty open segment is never rolled
+    assert!(
+        !partition
+            .roll_segment_by_age(segment_max_age, later)
+            .await
+            .unwrap()
+    );
+
+    append_messages(&mut partition).await;
+    assert!(
+        !partition
+            .roll_segment_by_age(segment_max_age, IggyTimestamp::now())
+            .await
+            .unwrap()
+    );
+    assert!(
+        !partition
+            .roll_segment_by_age(IggyExpiry::NeverExpire, later)
+            .await
+            .unwrap()
+    );
+    assert!(
+        partition
+            .roll_segment_by_age(segment_max_age, later)
+            .await
+            .unwrap()
+    );
+    assert!(partition.get_segments()[0].is_closed());
+
+    append_messages(&mut partition).await;
+    let segments = partition.get_segments();
+    assert_eq!(segments.len(), 2);
+    assert!(!segments[1].is_closed());
+    let first_segment_size = segments[0].get_messages_size().as_bytes_u64();
+    let second_segment_start_offset = segments[1].start_offset();
+    assert_eq!(second_segment_start_offset, segments[0].end_offset() + 1);
+
+    assert!(
+        partition
+            .get_oversized_segments_start_offsets(MaxTopicSize::Unlimited)
+            .is_empty()
+    );
+    assert!(
+        partition
+            .get_oversized_segments_start_offsets(MaxTopicSize::Custom(IggyByteSize::from(
+                first_segment_size * 2
+            )))
+            .is_empty()
+    );
+    // The open segment is never returned, even if the partition still exceeds the max size
+    assert_eq!(
+        partition.get_oversized_segments_start_offsets(MaxTopicSize::Custom(IggyByteSize::from(1))),
+        vec![0]
+    );
+}
+
+#[tokio::test]
+async fn should_move_partition_to_another_data_directory_and_back() {
+    let data_directory = format!("local_data_{}", Uuid::now_v7().to_u128_le());
+    let setup = TestSetup::init_with_config(SystemConfig {
+        data_directories: vec![data_directory.clone()],
+        ..Default::default()
+    })
+    .await;
+    fs::create_dir(&data_directory).await.unwrap();
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        setup.config.clone(),
+        setup.storage.clone(),
+        IggyExpiry::NeverExpire,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU32::new(0)),
+        IggyTimestamp::now(),
+    )
+    .await;
+    partition.persist().await.unwrap();
+    append_messages(&mut partition).await;
+    let messages_count = create_messages().len() as u32;
+    let partition_path = partition.partition_path.clone();
+    let moved_partition_path =
+        setup
+            .config
+            .get_partition_path_in(&data_directory, stream_id, topic_id, partition_id);
+    let partition = IggySharedMut::new(partition);
+
+    Partition::move_to_data_directory(&partition, &data_directory)
+        .await
+        .unwrap();
+
+    let metadata = fs::symlink_metadata(&partition_path).await.unwrap();
+    assert!(metadata.is_symlink());
+    assert_persisted_partition(&moved_partition_path, true).await;
+    {
+        let mut partition = partition.write().await;
+        assert_eq!(partition.data_directory, data_directory);
+        append_messages(&mut partition).await;
+        partition.flush_unsaved_buffer(true).await.unwrap();
+        let loaded_messages = partition.get_messages_by_offset(0, 100).await.unwrap();
+        assert_eq!(loaded_messages.count(), messages_count * 2);
+    }
+
+    let mut loaded_partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        false,
+        setup.config.clone(),
+        setup.storage.clone(),
+        IggyExpiry::NeverExpire,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU32::new(0)),
+        IggyTimestamp::now(),
+    )
+    .await;
+    let partition_state = PartitionState {
+        id: partition_id,
+        created_at: IggyTimestamp::now(),
+        consumer_offsets: Default::default(),
+    };
+    loaded_partition.load(partition_state).await.unwrap();
+    assert_eq!(loaded_partition.data_directory, data_directory);
+    assert_eq!(
+        loaded_partition.current_offset,
+        partition.read().await.current_offset
+    );
+    drop(loaded_partition);
+
+    let system_path = setup.config.get_system_path();
+    Partition::move_to_data_directory(&partition, &system_path)
+        .await
+        .unwrap();
+
+    let metadata = fs::symlink_metadata(&partition_path).await.unwrap();
+    assert!(metadata.is_dir());
+    assert!(fs::metadata(&moved_partition_path).await.is_err());
+    assert_persisted_partition(&partition_path, true).await;
+    let partition = partition.read().await;
+    assert_eq!(partition.data_directory, system_path);
+    let loaded_messages = partition.get_messages_by_offset(0, 100).await.unwrap();
+    assert_eq!(loaded_messages.count(), messages_count * 2);
+    fs::remove_dir_all(&data_directory).await.unwrap();
+}
+
+async fn append_messages(partition: &mut Partition) {
+    let messages = create_messages();
+    let messages_size: u32 = messages
+        .iter()
+        .map(|msg| msg.get_size_bytes().as_bytes_u32())
+        .sum();
+    let batch = IggyMessagesBatchMut::from_messages(&messages, messages_size);
+    partition.append_messages(batch, None).await.unwrap();
+}
+
 async fn assert_persisted_partition(partition_path: &str, with_segment: bool) {
     assert!(fs::metadata(&partition_path).await.is_ok());
 
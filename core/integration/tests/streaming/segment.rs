@@ -510,6 +510,10 @@ async fn should_delete_persisted_segments() -> Result<(), Box<dyn std::error::Er
             CompressionAlgorithm::default(),
             MaxTopicSize::default(),
             None,
+            CleanupPolicy::default(),
+            PartitioningHash::default(),
+            IggyExpiry::ServerDefault,
+            MaxTopicSize::ServerDefault,
         )
         .await?;
 
@@ -131,6 +131,10 @@ async fn should_purge_existing_stream_on_disk() {
                 Default::default(),
                 MaxTopicSize::ServerDefault,
                 1,
+                CleanupPolicy::default(),
+                PartitioningHash::default(),
+                IggyExpiry::ServerDefault,
+                MaxTopicSize::ServerDefault,
             )
             .await
             .unwrap();
@@ -155,6 +159,8 @@ async fn should_purge_existing_stream_on_disk() {
                 1,
                 PollingStrategy::offset(0),
                 100,
+                None,
+                IsolationLevel::ReadUncommitted,
             )
             .await
             .unwrap();
@@ -168,6 +174,8 @@ async fn should_purge_existing_stream_on_disk() {
                 1,
                 PollingStrategy::offset(0),
                 100,
+                None,
+                IsolationLevel::ReadUncommitted,
             )
             .await
             .unwrap();
@@ -17,7 +17,7 @@
  */
 
 use crate::streaming::common::test_setup::TestSetup;
-use iggy::prelude::Identifier;
+use iggy::prelude::*;
 use server::configs::server::{DataMaintenanceConfig, PersonalAccessTokenConfig};
 use server::streaming::session::Session;
 use server::streaming::systems::system::System;
@@ -120,6 +120,71 @@ async fn should_delete_persisted_stream() {
     assert!(fs::metadata(stream_path).await.is_err());
 }
 
+#[tokio::test]
+async fn should_coalesce_committed_consumer_offsets_into_single_state_entry() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(
+        setup.config.clone(),
+        DataMaintenanceConfig::default(),
+        PersonalAccessTokenConfig::default(),
+    );
+    let stream_id = Identifier::numeric(1).unwrap();
+    let topic_id = Identifier::numeric(1).unwrap();
+    let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    system.init().await.unwrap();
+    system
+        .create_stream(&session, Some(1), "test")
+        .await
+        .unwrap();
+    system
+        .create_topic(
+            &session,
+            &stream_id,
+            Some(1),
+            "test",
+            2,
+            IggyExpiry::default(),
+            CompressionAlgorithm::default(),
+            MaxTopicSize::default(),
+            None,
+            CleanupPolicy::default(),
+            PartitioningHash::default(),
+            IggyExpiry::ServerDefault,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+    let consumer = Consumer::new(Identifier::numeric(1).unwrap());
+
+    for partition_id in [1, 2, 1] {
+        system
+            .store_consumer_offset(
+                &session,
+                consumer.clone(),
+                &stream_id,
+                &topic_id,
+                Some(partition_id),
+                0,
+                None,
+            )
+            .await
+            .unwrap();
+    }
+    system
+        .store_consumer_offsets(
+            &session,
+            &consumer,
+            &stream_id,
+            &topic_id,
+            vec![ConsumerPartitionOffset::new(2, 0, None)],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(system.persist_consumer_offsets().await.unwrap(), 1);
+    assert_eq!(system.persist_consumer_offsets().await.unwrap(), 0);
+}
+
 async fn assert_persisted_stream(streams_path: &str, stream_id: u32) {
     let streams_metadata = fs::metadata(streams_path).await.unwrap();
     assert!(streams_metadata.is_dir());
@@ -120,14 +120,28 @@ async fn should_load_existing_topic_from_disk() {
                 AHashMap::new()
             } else {
                 (1..=partitions_count)
-                    .map(|id| (id, PartitionState { id, created_at }))
+                    .map(|id| {
+                        (
+                            id,
+                            PartitionState {
+                                id,
+                                created_at,
+                                consumer_offsets: AHashMap::new(),
+                            },
+                        )
+                    })
                     .collect()
             },
             consumer_groups: Default::default(),
+            schemas: Default::default(),
             compression_algorithm: Default::default(),
             message_expiry: IggyExpiry::NeverExpire,
             max_topic_size: MaxTopicSize::ServerDefault,
             replication_factor: Some(1),
+            cleanup_policy: CleanupPolicy::default(),
+            partitioning_hash: PartitioningHash::default(),
+            segment_max_age: IggyExpiry::ServerDefault,
+            max_partition_size: MaxTopicSize::ServerDefault,
             created_at: Default::default(),
         };
         loaded_topic.load(topic_state).await.unwrap();
@@ -235,6 +249,8 @@ async fn should_purge_existing_topic_on_disk() {
                 1,
                 PollingStrategy::offset(0),
                 100,
+                None,
+                IsolationLevel::ReadUncommitted,
             )
             .await
             .unwrap();
@@ -247,6 +263,8 @@ async fn should_purge_existing_topic_on_disk() {
                 1,
                 PollingStrategy::offset(0),
                 100,
+                None,
+                IsolationLevel::ReadUncommitted,
             )
             .await
             .unwrap();
@@ -74,6 +74,8 @@ async fn assert_polling_messages() {
             partition_id,
             PollingStrategy::offset(0),
             messages_count,
+            None,
+            IsolationLevel::ReadUncommitted,
         )
         .await
         .unwrap();
@@ -204,7 +206,14 @@ fn get_payload(id: u32) -> String {
 async fn assert_messages(topic: &Topic, partition_id: u32, expected_messages: u32) {
     let consumer = PollingConsumer::Consumer(0, partition_id);
     let (_, polled_messages) = topic
-        .get_messages(consumer, partition_id, PollingStrategy::offset(0), 1000)
+        .get_messages(
+            consumer,
+            partition_id,
+            PollingStrategy::offset(0),
+            1000,
+            None,
+            IsolationLevel::ReadUncommitted,
+        )
         .await
         .unwrap();
     assert_eq!(polled_messages.count(), expected_messages);
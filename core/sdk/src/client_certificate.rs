@@ -0,0 +1,49 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy_common::IggyError;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use tracing::error;
+
+/// Loads the client certificate chain and its private key (PEM) used for the mutual TLS authentication.
+pub(crate) fn load_client_certificate(
+    cert_file: &str,
+    key_file: &str,
+) -> Result<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>), IggyError> {
+    let certificates = CertificateDer::pem_file_iter(cert_file)
+        .map_err(|error| {
+            error!("Failed to read the client certificate file: {cert_file}. {error}");
+            IggyError::InvalidTlsCertificatePath
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| {
+            error!("Failed to read a certificate from the client certificate file: {cert_file}. {error}");
+            IggyError::InvalidTlsCertificate
+        })?;
+    if certificates.is_empty() {
+        error!("The client certificate file: {cert_file} does not contain any certificate.");
+        return Err(IggyError::InvalidTlsCertificate);
+    }
+
+    let key = PrivateKeyDer::from_pem_file(key_file).map_err(|error| {
+        error!("Failed to read the client private key file: {key_file}. {error}");
+        IggyError::InvalidTlsCertificate
+    })?;
+    Ok((certificates, key))
+}
@@ -117,6 +117,7 @@ impl ClientProviderConfig {
                     keep_alive_interval: args.quic_keep_alive_interval,
                     max_idle_timeout: args.quic_max_idle_timeout,
                     validate_certificate: args.quic_validate_certificate,
+                    ..QuicClientConfig::default()
                 }));
             }
             HTTP_TRANSPORT => {
@@ -131,7 +132,11 @@ impl ClientProviderConfig {
                     tls_enabled: args.tcp_tls_enabled,
                     tls_domain: args.tcp_tls_domain,
                     tls_ca_file: args.tcp_tls_ca_file,
+                    tls_client_cert_file: None,
+                    tls_client_key_file: None,
                     nodelay: args.tcp_nodelay,
+                    pipelining: false,
+                    connection_pool_size: 1,
                     heartbeat_interval: IggyDuration::from_str(&args.tcp_heartbeat_interval)
                         .unwrap(),
                     reconnection: TcpClientReconnectionConfig {
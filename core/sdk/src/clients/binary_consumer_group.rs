@@ -21,7 +21,10 @@ use async_dropper::AsyncDrop;
 use async_trait::async_trait;
 use iggy_binary_protocol::ConsumerGroupClient;
 use iggy_common::locking::IggySharedMutFn;
-use iggy_common::{ConsumerGroup, ConsumerGroupDetails, Identifier, IggyError};
+use iggy_common::{
+    ConsumerGroup, ConsumerGroupAssignment, ConsumerGroupAssignmentStrategy, ConsumerGroupDetails,
+    ConsumerGroupOffsetReset, ConsumerOffsetsResetTarget, Identifier, IggyError,
+};
 
 #[async_trait]
 impl ConsumerGroupClient for IggyClient {
@@ -56,11 +59,12 @@ impl ConsumerGroupClient for IggyClient {
         topic_id: &Identifier,
         name: &str,
         group_id: Option<u32>,
+        assignment_strategy: ConsumerGroupAssignmentStrategy,
     ) -> Result<ConsumerGroupDetails, IggyError> {
         self.client
             .read()
             .await
-            .create_consumer_group(stream_id, topic_id, name, group_id)
+            .create_consumer_group(stream_id, topic_id, name, group_id, assignment_strategy)
             .await
     }
 
@@ -102,6 +106,51 @@ impl ConsumerGroupClient for IggyClient {
             .leave_consumer_group(stream_id, topic_id, group_id)
             .await
     }
+
+    async fn sync_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        generation: u32,
+    ) -> Result<ConsumerGroupAssignment, IggyError> {
+        self.client
+            .read()
+            .await
+            .sync_consumer_group(stream_id, topic_id, group_id, generation)
+            .await
+    }
+
+    async fn record_delivery_failure(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+    ) -> Result<u32, IggyError> {
+        self.client
+            .read()
+            .await
+            .record_delivery_failure(stream_id, topic_id, group_id, partition_id, offset)
+            .await
+    }
+
+    async fn reset_consumer_group_offsets(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        target: &ConsumerOffsetsResetTarget,
+        dry_run: bool,
+        force: bool,
+    ) -> Result<Vec<ConsumerGroupOffsetReset>, IggyError> {
+        self.client
+            .read()
+            .await
+            .reset_consumer_group_offsets(stream_id, topic_id, group_id, target, dry_run, force)
+            .await
+    }
 }
 
 #[async_trait]
@@ -20,7 +20,7 @@ use crate::prelude::IggyClient;
 use async_trait::async_trait;
 use iggy_binary_protocol::ConsumerOffsetClient;
 use iggy_common::locking::IggySharedMutFn;
-use iggy_common::{Consumer, ConsumerOffsetInfo, Identifier, IggyError};
+use iggy_common::{Consumer, ConsumerOffsetInfo, ConsumerPartitionOffset, Identifier, IggyError};
 
 #[async_trait]
 impl ConsumerOffsetClient for IggyClient {
@@ -31,11 +31,33 @@ impl ConsumerOffsetClient for IggyClient {
         topic_id: &Identifier,
         partition_id: Option<u32>,
         offset: u64,
+        metadata: Option<&str>,
     ) -> Result<(), IggyError> {
         self.client
             .read()
             .await
-            .store_consumer_offset(consumer, stream_id, topic_id, partition_id, offset)
+            .store_consumer_offset(
+                consumer,
+                stream_id,
+                topic_id,
+                partition_id,
+                offset,
+                metadata,
+            )
+            .await
+    }
+
+    async fn store_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        offsets: &[ConsumerPartitionOffset],
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .store_consumer_offsets(consumer, stream_id, topic_id, offsets)
             .await
     }
 
@@ -22,7 +22,8 @@ use bytes::Bytes;
 use iggy_binary_protocol::MessageClient;
 use iggy_common::locking::IggySharedMutFn;
 use iggy_common::{
-    Consumer, Identifier, IggyError, IggyMessage, Partitioning, PolledMessages, PollingStrategy,
+    Consumer, Identifier, IggyDuration, IggyError, IggyMessage, MessageFilter, Partitioning,
+    PolledMessages, PollingStrategy,
 };
 
 #[async_trait]
@@ -67,6 +68,94 @@ impl MessageClient for IggyClient {
         Ok(polled_messages)
     }
 
+    async fn poll_messages_with_filter(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        filter: &MessageFilter,
+    ) -> Result<PolledMessages, IggyError> {
+        if count == 0 {
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        let mut polled_messages = self
+            .client
+            .read()
+            .await
+            .poll_messages_with_filter(
+                stream_id,
+                topic_id,
+                partition_id,
+                consumer,
+                strategy,
+                count,
+                auto_commit,
+                filter,
+            )
+            .await?;
+
+        if let Some(ref encryptor) = self.encryptor {
+            for message in &mut polled_messages.messages {
+                let payload = encryptor.decrypt(&message.payload)?;
+                message.payload = Bytes::from(payload);
+                message.header.payload_length = message.payload.len() as u32;
+            }
+        }
+
+        Ok(polled_messages)
+    }
+
+    async fn long_poll_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        filter: Option<&MessageFilter>,
+        max_wait: IggyDuration,
+        min_bytes: u32,
+    ) -> Result<PolledMessages, IggyError> {
+        if count == 0 {
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        let mut polled_messages = self
+            .client
+            .read()
+            .await
+            .long_poll_messages(
+                stream_id,
+                topic_id,
+                partition_id,
+                consumer,
+                strategy,
+                count,
+                auto_commit,
+                filter,
+                max_wait,
+                min_bytes,
+            )
+            .await?;
+
+        if let Some(ref encryptor) = self.encryptor {
+            for message in &mut polled_messages.messages {
+                let payload = encryptor.decrypt(&message.payload)?;
+                message.payload = Bytes::from(payload);
+                message.header.payload_length = message.payload.len() as u32;
+            }
+        }
+
+        Ok(polled_messages)
+    }
+
     async fn send_messages(
         &self,
         stream_id: &Identifier,
@@ -49,4 +49,18 @@ impl PartitionClient for IggyClient {
             .delete_partitions(stream_id, topic_id, partitions_count)
             .await
     }
+
+    async fn move_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        data_directory: &str,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .move_partition(stream_id, topic_id, partition_id, data_directory)
+            .await
+    }
 }
@@ -0,0 +1,50 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::prelude::IggyClient;
+use async_trait::async_trait;
+use iggy_binary_protocol::RoleClient;
+use iggy_common::locking::IggySharedMutFn;
+use iggy_common::{Identifier, IggyError, Role, RoleRule};
+
+#[async_trait]
+impl RoleClient for IggyClient {
+    async fn get_roles(&self) -> Result<Vec<Role>, IggyError> {
+        self.client.read().await.get_roles().await
+    }
+
+    async fn create_role(&self, name: &str, rules: Vec<RoleRule>) -> Result<(), IggyError> {
+        self.client.read().await.create_role(name, rules).await
+    }
+
+    async fn update_role(&self, name: &str, rules: Vec<RoleRule>) -> Result<(), IggyError> {
+        self.client.read().await.update_role(name, rules).await
+    }
+
+    async fn delete_role(&self, name: &str) -> Result<(), IggyError> {
+        self.client.read().await.delete_role(name).await
+    }
+
+    async fn assign_roles(
+        &self,
+        user_id: &Identifier,
+        roles: Vec<String>,
+    ) -> Result<(), IggyError> {
+        self.client.read().await.assign_roles(user_id, roles).await
+    }
+}
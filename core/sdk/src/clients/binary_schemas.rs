@@ -0,0 +1,62 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::prelude::IggyClient;
+use async_trait::async_trait;
+use bytes::Bytes;
+use iggy_binary_protocol::SchemaClient;
+use iggy_common::locking::IggySharedMutFn;
+use iggy_common::{Identifier, IggyError, Schema, SchemaCompatibility, SchemaType};
+
+#[async_trait]
+impl SchemaClient for IggyClient {
+    async fn get_schema(&self, schema_id: u32) -> Result<Option<Schema>, IggyError> {
+        self.client.read().await.get_schema(schema_id).await
+    }
+
+    async fn get_schemas(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<Schema>, IggyError> {
+        self.client
+            .read()
+            .await
+            .get_schemas(stream_id, topic_id)
+            .await
+    }
+
+    async fn register_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        schema_type: SchemaType,
+        compatibility: SchemaCompatibility,
+        definition: Bytes,
+    ) -> Result<Schema, IggyError> {
+        self.client
+            .read()
+            .await
+            .register_schema(stream_id, topic_id, schema_type, compatibility, definition)
+            .await
+    }
+
+    async fn delete_schema(&self, schema_id: u32) -> Result<(), IggyError> {
+        self.client.read().await.delete_schema(schema_id).await
+    }
+}
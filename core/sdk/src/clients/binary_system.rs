@@ -21,8 +21,8 @@ use async_trait::async_trait;
 use iggy_binary_protocol::SystemClient;
 use iggy_common::locking::IggySharedMutFn;
 use iggy_common::{
-    ClientInfo, ClientInfoDetails, IggyDuration, IggyError, Snapshot, SnapshotCompression, Stats,
-    SystemSnapshotType,
+    AuditRecord, ClientInfo, ClientInfoDetails, IggyDuration, IggyError, ServerMetadata, Snapshot,
+    SnapshotCompression, Stats, SystemSnapshotType,
 };
 
 #[async_trait]
@@ -62,4 +62,27 @@ impl SystemClient for IggyClient {
             .snapshot(compression, snapshot_types)
             .await
     }
+
+    async fn get_audit_log(
+        &self,
+        start_id: u64,
+        count: u32,
+    ) -> Result<Vec<AuditRecord>, IggyError> {
+        self.client
+            .read()
+            .await
+            .get_audit_log(start_id, count)
+            .await
+    }
+
+    async fn export_metadata(
+        &self,
+        include_password_hashes: bool,
+    ) -> Result<ServerMetadata, IggyError> {
+        self.client
+            .read()
+            .await
+            .export_metadata(include_password_hashes)
+            .await
+    }
 }
@@ -22,6 +22,7 @@ use iggy_binary_protocol::TopicClient;
 use iggy_common::locking::IggySharedMutFn;
 use iggy_common::{
     CompressionAlgorithm, Identifier, IggyError, IggyExpiry, MaxTopicSize, Topic, TopicDetails,
+    TopicOptions,
 };
 
 #[async_trait]
@@ -52,6 +53,7 @@ impl TopicClient for IggyClient {
         topic_id: Option<u32>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+        options: TopicOptions,
     ) -> Result<TopicDetails, IggyError> {
         self.client
             .read()
@@ -65,6 +67,7 @@ impl TopicClient for IggyClient {
                 topic_id,
                 message_expiry,
                 max_topic_size,
+                options,
             )
             .await
     }
@@ -78,6 +81,7 @@ impl TopicClient for IggyClient {
         replication_factor: Option<u8>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+        options: TopicOptions,
     ) -> Result<(), IggyError> {
         self.client
             .read()
@@ -90,6 +94,7 @@ impl TopicClient for IggyClient {
                 replication_factor,
                 message_expiry,
                 max_topic_size,
+                options,
             )
             .await
     }
@@ -21,7 +21,8 @@ use async_trait::async_trait;
 use iggy_binary_protocol::UserClient;
 use iggy_common::locking::IggySharedMutFn;
 use iggy_common::{
-    Identifier, IdentityInfo, IggyError, Permissions, UserInfo, UserInfoDetails, UserStatus,
+    Identifier, IdentityInfo, IggyError, Permissions, UserInfo, UserInfoDetails, UserQuotas,
+    UserStatus,
 };
 
 #[async_trait]
@@ -40,11 +41,27 @@ impl UserClient for IggyClient {
         password: &str,
         status: UserStatus,
         permissions: Option<Permissions>,
+        quotas: Option<UserQuotas>,
     ) -> Result<UserInfoDetails, IggyError> {
         self.client
             .read()
             .await
-            .create_user(username, password, status, permissions)
+            .create_user(username, password, status, permissions, quotas)
+            .await
+    }
+
+    async fn import_user(
+        &self,
+        username: &str,
+        password_hash: &str,
+        status: UserStatus,
+        permissions: Option<Permissions>,
+        quotas: Option<UserQuotas>,
+    ) -> Result<UserInfoDetails, IggyError> {
+        self.client
+            .read()
+            .await
+            .import_user(username, password_hash, status, permissions, quotas)
             .await
     }
 
@@ -57,11 +74,12 @@ impl UserClient for IggyClient {
         user_id: &Identifier,
         username: Option<&str>,
         status: Option<UserStatus>,
+        quotas: Option<UserQuotas>,
     ) -> Result<(), IggyError> {
         self.client
             .read()
             .await
-            .update_user(user_id, username, status)
+            .update_user(user_id, username, status, quotas)
             .await
     }
 
@@ -98,6 +116,10 @@ impl UserClient for IggyClient {
             .await
     }
 
+    async fn login_with_token(&self, token: &str) -> Result<IdentityInfo, IggyError> {
+        self.client.read().await.login_with_token(token).await
+    }
+
     async fn logout_user(&self) -> Result<(), IggyError> {
         self.client.read().await.logout_user().await
     }
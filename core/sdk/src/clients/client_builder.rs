@@ -126,6 +126,12 @@ impl TcpClientBuilder {
         self
     }
 
+    /// Sets the addresses of the servers the TCP client fails over between, starting with the first one.
+    pub fn with_server_addresses(mut self, server_addresses: &[String]) -> Self {
+        self.config = self.config.with_server_addresses(server_addresses);
+        self
+    }
+
     /// Sets the auto sign in during connection.
     pub fn with_auto_sign_in(mut self, auto_sign_in: AutoLogin) -> Self {
         self.config = self.config.with_auto_sign_in(auto_sign_in);
@@ -166,12 +172,30 @@ impl TcpClientBuilder {
         self
     }
 
+    /// Sets the paths to the client certificate and private key files for TLS (mTLS).
+    pub fn with_tls_client_certificate(mut self, cert_file: String, key_file: String) -> Self {
+        self.config = self.config.with_tls_client_certificate(cert_file, key_file);
+        self
+    }
+
     /// Sets the nodelay option for the TCP socket.
     pub fn with_no_delay(mut self) -> Self {
         self.config = self.config.with_no_delay();
         self
     }
 
+    /// Enables the pipelining of the requests with correlation IDs on the TCP connection.
+    pub fn with_pipelining(mut self) -> Self {
+        self.config = self.config.with_pipelining();
+        self
+    }
+
+    /// Sets the number of TCP connections, the additional ones are used to send and poll messages.
+    pub fn with_connection_pool_size(mut self, connection_pool_size: u32) -> Self {
+        self.config = self.config.with_connection_pool_size(connection_pool_size);
+        self
+    }
+
     /// Builds the parent `IggyClient` with TCP configuration.
     pub fn build(self) -> Result<IggyClient, IggyError> {
         let client = TcpClient::create(Arc::new(self.config.build()?))?;
@@ -193,6 +217,12 @@ impl QuicClientBuilder {
         self
     }
 
+    /// Sets the addresses of the servers the QUIC client fails over between, starting with the first one.
+    pub fn with_server_addresses(mut self, server_addresses: &[String]) -> Self {
+        self.config = self.config.with_server_addresses(server_addresses);
+        self
+    }
+
     /// Sets the auto sign in during connection.
     pub fn with_auto_sign_in(mut self, auto_sign_in: AutoLogin) -> Self {
         self.config = self.config.with_auto_sign_in(auto_sign_in);
@@ -221,6 +251,12 @@ impl QuicClientBuilder {
         self
     }
 
+    /// Sets the paths to the client certificate and private key files (mTLS).
+    pub fn with_client_certificate(mut self, cert_file: String, key_file: String) -> Self {
+        self.config = self.config.with_client_certificate(cert_file, key_file);
+        self
+    }
+
     /// Builds the parent `IggyClient` with QUIC configuration.
     pub fn build(self) -> Result<IggyClient, IggyError> {
         let client = QuicClient::create(Arc::new(self.config.build()))?;
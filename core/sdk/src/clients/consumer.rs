@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use async_trait::async_trait;
 use bytes::Bytes;
 use dashmap::DashMap;
 use futures::Stream;
@@ -23,12 +24,15 @@ use futures_util::{FutureExt, StreamExt};
 use iggy_binary_protocol::Client;
 use iggy_common::locking::{IggySharedMut, IggySharedMutFn};
 use iggy_common::{
-    Consumer, ConsumerKind, DiagnosticEvent, EncryptorKind, IdKind, Identifier, IggyDuration,
-    IggyError, IggyMessage, IggyTimestamp, PolledMessages, PollingKind, PollingStrategy,
+    Consumer, ConsumerGroupAssignmentStrategy, ConsumerKind, DiagnosticEvent, EncryptorKind,
+    HeaderKey, HeaderValue, IdKind, Identifier, IggyDuration, IggyError, IggyMessage,
+    IggyTimestamp, MessageFilter, Partitioning, PolledMessages, PollingKind, PollingStrategy,
 };
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Debug;
 use std::future::Future;
 use std::pin::Pin;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64};
 use std::task::{Context, Poll};
@@ -87,6 +91,49 @@ pub enum AutoCommitAfter {
     ConsumingEveryNthMessage(u32),
 }
 
+/// The callbacks invoked when the partitions of the consumer group are rebalanced between its members.
+///
+/// The partitions revoked from the consumer are handed over to the other members only after the callbacks complete
+/// when the consumer group uses the cooperative assignment strategy, so the offsets can be safely stored beforehand.
+#[async_trait]
+pub trait ConsumerRebalanceListener: Send + Sync + Debug {
+    /// Invoked when the partitions are revoked from the consumer, after the offsets of the consumed messages
+    /// have been stored (unless the auto-commit is disabled).
+    async fn on_partitions_revoked(&self, _partitions: &[u32]) {}
+
+    /// Invoked when the partitions are assigned to the consumer.
+    async fn on_partitions_assigned(&self, _partitions: &[u32]) {}
+}
+
+/// The user header of the dead-lettered message containing the ID of the stream it was consumed from.
+pub const DEAD_LETTER_ORIGINAL_STREAM_HEADER: &str = "iggy-dlt-original-stream";
+/// The user header of the dead-lettered message containing the ID of the topic it was consumed from.
+pub const DEAD_LETTER_ORIGINAL_TOPIC_HEADER: &str = "iggy-dlt-original-topic";
+/// The user header of the dead-lettered message containing the ID of the partition it was consumed from.
+pub const DEAD_LETTER_ORIGINAL_PARTITION_HEADER: &str = "iggy-dlt-original-partition";
+/// The user header of the dead-lettered message containing its offset in the original partition.
+pub const DEAD_LETTER_ORIGINAL_OFFSET_HEADER: &str = "iggy-dlt-original-offset";
+/// The user header of the dead-lettered message containing the error of the last failed attempt.
+pub const DEAD_LETTER_ERROR_HEADER: &str = "iggy-dlt-error";
+/// The user header of the dead-lettered message containing the number of the failed delivery attempts.
+pub const DEAD_LETTER_ATTEMPTS_HEADER: &str = "iggy-dlt-attempts";
+
+/// The topic to which the messages are republished after exceeding the maximum number of the failed delivery attempts.
+#[derive(Debug)]
+struct DeadLetterPolicy {
+    stream_id: Identifier,
+    topic_id: Identifier,
+    max_attempts: u32,
+}
+
+/// Synchronizes the assignment of the consumer group member, so that the rebalances are acknowledged.
+#[derive(Debug)]
+struct ConsumerGroupRebalance {
+    listener: Option<Arc<dyn ConsumerRebalanceListener>>,
+    sync_interval_micros: u64,
+    last_synced_at: AtomicU64,
+}
+
 unsafe impl Send for IggyConsumer {}
 unsafe impl Sync for IggyConsumer {}
 
@@ -124,6 +171,13 @@ pub struct IggyConsumer {
     init_retries: Option<u32>,
     init_retry_interval: IggyDuration,
     allow_replay: bool,
+    filter: Option<Arc<MessageFilter>>,
+    filtered_next_offset: Option<u64>,
+    long_polling: Option<(IggyDuration, u32)>,
+    assignment_strategy: ConsumerGroupAssignmentStrategy,
+    rebalance: Option<Arc<ConsumerGroupRebalance>>,
+    dead_letter: Option<Arc<DeadLetterPolicy>>,
+    delivery_attempts: Arc<DashMap<u32, (u64, u32)>>,
 }
 
 impl IggyConsumer {
@@ -146,11 +200,28 @@ impl IggyConsumer {
         init_retries: Option<u32>,
         init_retry_interval: IggyDuration,
         allow_replay: bool,
+        filter: Option<MessageFilter>,
+        long_polling: Option<(IggyDuration, u32)>,
+        assignment_strategy: ConsumerGroupAssignmentStrategy,
+        rebalance_listener: Option<Arc<dyn ConsumerRebalanceListener>>,
+        rebalance_sync_interval: IggyDuration,
+        dead_letter_topic: Option<(Identifier, Identifier, u32)>,
     ) -> Self {
         let (store_offset_sender, _) = flume::unbounded();
+        let is_consumer_group = consumer.kind == ConsumerKind::ConsumerGroup;
+        // The cooperative strategy requires acknowledging the rebalances, even without the listener.
+        let rebalance = (is_consumer_group
+            && (rebalance_listener.is_some() || assignment_strategy.is_cooperative()))
+        .then(|| {
+            Arc::new(ConsumerGroupRebalance {
+                listener: rebalance_listener,
+                sync_interval_micros: rebalance_sync_interval.as_micros(),
+                last_synced_at: AtomicU64::new(0),
+            })
+        });
         Self {
             initialized: false,
-            is_consumer_group: consumer.kind == ConsumerKind::ConsumerGroup,
+            is_consumer_group,
             joined_consumer_group: Arc::new(AtomicBool::new(false)),
             can_poll: Arc::new(AtomicBool::new(true)),
             client,
@@ -200,6 +271,19 @@ impl IggyConsumer {
             init_retries,
             init_retry_interval,
             allow_replay,
+            filter: filter.map(Arc::new),
+            filtered_next_offset: None,
+            long_polling,
+            assignment_strategy,
+            rebalance,
+            dead_letter: dead_letter_topic.map(|(stream_id, topic_id, max_attempts)| {
+                Arc::new(DeadLetterPolicy {
+                    stream_id,
+                    topic_id,
+                    max_attempts,
+                })
+            }),
+            delivery_attempts: Arc::new(DashMap::new()),
         }
     }
 
@@ -207,6 +291,10 @@ impl IggyConsumer {
         self.auto_commit
     }
 
+    pub(crate) fn has_dead_letter_topic(&self) -> bool {
+        self.dead_letter.is_some()
+    }
+
     /// Returns the name of the consumer.
     pub fn name(&self) -> &str {
         &self.consumer_name
@@ -264,6 +352,114 @@ impl IggyConsumer {
             .await
     }
 
+    /// Records the failed delivery attempt of the message which could not be processed and, once the maximum number
+    /// of attempts is reached, republishes it to the dead-letter topic and stores its offset, so the consumer can move on.
+    ///
+    /// Returns `true` if the message has been moved to the dead-letter topic, or `false` if it should be retried.
+    /// The attempts are counted by the server for the consumer groups, so they survive the restarts of the consumer.
+    ///
+    /// Note: The dead-letter topic must be configured with `IggyConsumerBuilder::dead_letter_topic()`.
+    pub async fn handle_failed_message(
+        &self,
+        message: &ReceivedMessage,
+        error: &IggyError,
+    ) -> Result<bool, IggyError> {
+        let Some(dead_letter) = &self.dead_letter else {
+            error!(
+                "Dead-letter topic is not configured for consumer: {}",
+                self.consumer_name
+            );
+            return Err(IggyError::InvalidConfiguration);
+        };
+
+        let partition_id = message.partition_id;
+        let offset = message.message.header.offset;
+        let attempts = self.record_delivery_failure(partition_id, offset).await?;
+        if attempts < dead_letter.max_attempts {
+            warn!(
+                "Failed to process message at offset: {offset}, partition: {partition_id} by consumer: {}, attempt: {attempts}/{}: {error}",
+                self.consumer_name, dead_letter.max_attempts
+            );
+            return Ok(false);
+        }
+
+        let dead_letter_message = self.to_dead_letter_message(message, error, attempts)?;
+        {
+            let client = self.client.read().await;
+            client
+                .send_messages(
+                    &dead_letter.stream_id,
+                    &dead_letter.topic_id,
+                    &Partitioning::balanced(),
+                    &mut [dead_letter_message],
+                )
+                .await?;
+        }
+        warn!(
+            "Moved message at offset: {offset}, partition: {partition_id} of consumer: {} to dead-letter topic: {}, stream: {} after {attempts} failed attempts: {error}",
+            self.consumer_name, dead_letter.topic_id, dead_letter.stream_id
+        );
+
+        self.store_offset(offset, Some(partition_id)).await?;
+        self.delivery_attempts.remove(&partition_id);
+        Ok(true)
+    }
+
+    async fn record_delivery_failure(
+        &self,
+        partition_id: u32,
+        offset: u64,
+    ) -> Result<u32, IggyError> {
+        if self.is_consumer_group {
+            let client = self.client.read().await;
+            return client
+                .record_delivery_failure(
+                    &self.stream_id,
+                    &self.topic_id,
+                    &self.consumer.id,
+                    partition_id,
+                    offset,
+                )
+                .await;
+        }
+
+        let mut entry = self
+            .delivery_attempts
+            .entry(partition_id)
+            .or_insert((offset, 0));
+        if entry.0 != offset {
+            *entry = (offset, 0);
+        }
+        entry.1 += 1;
+        Ok(entry.1)
+    }
+
+    fn to_dead_letter_message(
+        &self,
+        message: &ReceivedMessage,
+        error: &IggyError,
+        attempts: u32,
+    ) -> Result<IggyMessage, IggyError> {
+        let mut user_headers = message.message.user_headers_map()?.unwrap_or_default();
+        user_headers.extend(dead_letter_headers(
+            &self.stream_id,
+            &self.topic_id,
+            message.partition_id,
+            message.message.header.offset,
+            error,
+            attempts,
+        )?);
+        let payload = match &self.encryptor {
+            Some(encryptor) => Bytes::from(encryptor.encrypt(&message.message.payload)?),
+            None => message.message.payload.clone(),
+        };
+        IggyMessage::builder()
+            .id(message.message.header.id)
+            .payload(payload)
+            .user_headers(user_headers)
+            .build()
+    }
+
     /// Initializes the consumer by subscribing to diagnostic events, initializing the consumer group if needed, storing the offsets in the background etc.
     ///
     /// Note: This method must be called before polling messages.
@@ -420,7 +616,14 @@ impl IggyConsumer {
 
         let client = client.read().await;
         if let Err(error) = client
-            .store_consumer_offset(consumer, stream_id, topic_id, Some(partition_id), offset)
+            .store_consumer_offset(
+                consumer,
+                stream_id,
+                topic_id,
+                Some(partition_id),
+                offset,
+                None,
+            )
             .await
         {
             error!(
@@ -476,6 +679,50 @@ impl IggyConsumer {
         }
     }
 
+    /// Moves the offset polling strategy past the consumed message, or further if the server
+    /// has already scanned the subsequent messages that didn't match the filter.
+    fn advance_offset_polling_strategy(&mut self, consumed_offset: u64) {
+        if self.polling_strategy.kind != PollingKind::Offset {
+            return;
+        }
+
+        let next_offset = self
+            .filtered_next_offset
+            .map_or(consumed_offset + 1, |offset| {
+                offset.max(consumed_offset + 1)
+            });
+        self.polling_strategy = PollingStrategy::offset(next_offset);
+    }
+
+    /// Skips the messages which were scanned by the server but didn't match the filter,
+    /// so that the subsequent polls don't have to examine them again.
+    fn skip_filtered_messages(&mut self, partition_id: u32, next_offset: u64) {
+        if self.polling_strategy.kind == PollingKind::Offset {
+            self.polling_strategy = PollingStrategy::offset(next_offset);
+            return;
+        }
+
+        // When polling the next messages, the position is tracked by the stored offset,
+        // which is already updated on the server if auto-commit after polling is enabled.
+        if self.polling_strategy.kind != PollingKind::Next
+            || next_offset == 0
+            || self.auto_commit == AutoCommit::Disabled
+            || self.auto_commit_after_polling
+        {
+            return;
+        }
+
+        let last_stored_offset = self
+            .last_stored_offsets
+            .get(&partition_id)
+            .map(|offset| offset.load(ORDERING));
+        if last_stored_offset.is_some_and(|offset| offset >= next_offset - 1) {
+            return;
+        }
+
+        self.send_store_offset(partition_id, next_offset - 1);
+    }
+
     async fn init_consumer_group(&self) -> Result<(), IggyError> {
         if !self.is_consumer_group {
             return Ok(());
@@ -489,6 +736,7 @@ impl IggyConsumer {
         Self::initialize_consumer_group(
             self.client.clone(),
             self.create_consumer_group_if_not_exists,
+            self.assignment_strategy,
             self.stream_id.clone(),
             self.topic_id.clone(),
             self.consumer.clone(),
@@ -510,6 +758,7 @@ impl IggyConsumer {
         let can_join_consumer_group = is_consumer_group && self.auto_join_consumer_group;
         let client = self.client.clone();
         let create_consumer_group_if_not_exists = self.create_consumer_group_if_not_exists;
+        let assignment_strategy = self.assignment_strategy;
         let stream_id = self.stream_id.clone();
         let topic_id = self.topic_id.clone();
         let consumer = self.consumer.clone();
@@ -573,6 +822,7 @@ impl IggyConsumer {
                         if let Err(error) = Self::initialize_consumer_group(
                             client.clone(),
                             create_consumer_group_if_not_exists,
+                            assignment_strategy,
                             stream_id.clone(),
                             topic_id.clone(),
                             consumer.clone(),
@@ -619,6 +869,12 @@ impl IggyConsumer {
         let last_stored_offset = self.last_stored_offsets.clone();
         let last_consumed_offset = self.last_consumed_offsets.clone();
         let allow_replay = self.allow_replay;
+        let filter = self.filter.clone();
+        let long_polling = self.long_polling;
+        let rebalance = self
+            .rebalance
+            .clone()
+            .filter(|_| self.joined_consumer_group.load(ORDERING));
 
         async move {
             if interval > 0 {
@@ -630,21 +886,69 @@ impl IggyConsumer {
                 sleep(retry_interval.get_duration()).await;
             }
 
-            trace!("Sending poll messages request");
-            last_polled_at.store(IggyTimestamp::now().into(), ORDERING);
-            let polled_messages = client
-                .read()
-                .await
-                .poll_messages(
+            if let Some(rebalance) = rebalance {
+                Self::sync_consumer_group(
+                    &client,
+                    &consumer,
                     &stream_id,
                     &topic_id,
-                    partition_id,
-                    &consumer,
-                    &polling_strategy,
-                    count,
-                    auto_commit_after_polling,
+                    &rebalance,
+                    auto_commit_enabled,
+                    &last_consumed_offset,
+                    &last_stored_offset,
                 )
-                .await;
+                .await?;
+            }
+
+            trace!("Sending poll messages request");
+            last_polled_at.store(IggyTimestamp::now().into(), ORDERING);
+            let client_guard = client.read().await;
+            let polled_messages = match (long_polling, filter.as_deref()) {
+                (Some((max_wait, min_bytes)), filter) => {
+                    client_guard
+                        .long_poll_messages(
+                            &stream_id,
+                            &topic_id,
+                            partition_id,
+                            &consumer,
+                            &polling_strategy,
+                            count,
+                            auto_commit_after_polling,
+                            filter,
+                            max_wait,
+                            min_bytes,
+                        )
+                        .await
+                }
+                (None, Some(filter)) => {
+                    client_guard
+                        .poll_messages_with_filter(
+                            &stream_id,
+                            &topic_id,
+                            partition_id,
+                            &consumer,
+                            &polling_strategy,
+                            count,
+                            auto_commit_after_polling,
+                            filter,
+                        )
+                        .await
+                }
+                (None, None) => {
+                    client_guard
+                        .poll_messages(
+                            &stream_id,
+                            &topic_id,
+                            partition_id,
+                            &consumer,
+                            &polling_strategy,
+                            count,
+                            auto_commit_after_polling,
+                        )
+                        .await
+                }
+            };
+            drop(client_guard);
 
             if let Ok(mut polled_messages) = polled_messages {
                 if polled_messages.messages.is_empty() {
@@ -664,11 +968,15 @@ impl IggyConsumer {
                 }
 
                 if !allow_replay && has_consumed_offset {
-                    polled_messages
-                        .messages
-                        .retain(|message| message.header.offset > consumed_offset);
+                    polled_messages.messages.retain(|message| {
+                        // The delayed messages which are due by now are delivered behind the consumed offset.
+                        message.header.offset > consumed_offset || message.deliver_at().is_some()
+                    });
                     if polled_messages.messages.is_empty() {
-                        return Ok(PolledMessages::empty());
+                        return Ok(PolledMessages {
+                            next_offset: polled_messages.next_offset,
+                            ..PolledMessages::empty()
+                        });
                     }
                 }
 
@@ -713,6 +1021,7 @@ impl IggyConsumer {
                                 &topic_id,
                                 Some(partition_id),
                                 consumed_offset,
+                                None,
                             )
                             .await?;
                         if let Some(stored_offset_entry) = last_stored_offset.get(&partition_id) {
@@ -728,6 +1037,7 @@ impl IggyConsumer {
                         current_offset: polled_messages.current_offset,
                         partition_id,
                         count: 0,
+                        next_offset: polled_messages.next_offset,
                     });
                 }
 
@@ -766,9 +1076,98 @@ impl IggyConsumer {
         sleep(Duration::from_micros(remaining)).await;
     }
 
+    /// Fetches the assignment of the consumer group member and, if it has been rebalanced, invokes the listener
+    /// and acknowledges the rebalance, which hands over the revoked partitions to the other members.
+    #[allow(clippy::too_many_arguments)]
+    async fn sync_consumer_group(
+        client: &IggySharedMut<Box<dyn Client>>,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        rebalance: &ConsumerGroupRebalance,
+        auto_commit_enabled: bool,
+        last_consumed_offsets: &DashMap<u32, AtomicU64>,
+        last_stored_offsets: &DashMap<u32, AtomicU64>,
+    ) -> Result<(), IggyError> {
+        let now: u64 = IggyTimestamp::now().into();
+        if now - rebalance.last_synced_at.load(ORDERING) < rebalance.sync_interval_micros {
+            return Ok(());
+        }
+
+        rebalance.last_synced_at.store(now, ORDERING);
+        let assignment = client
+            .read()
+            .await
+            .sync_consumer_group(stream_id, topic_id, &consumer.id, 0)
+            .await?;
+        if assignment.assigned_partitions.is_empty() && assignment.revoked_partitions.is_empty() {
+            return Ok(());
+        }
+
+        if !assignment.revoked_partitions.is_empty() {
+            info!(
+                "Partitions: {:?} have been revoked from consumer: {consumer}, topic: {topic_id}, stream: {stream_id}.",
+                assignment.revoked_partitions
+            );
+            if auto_commit_enabled {
+                for partition_id in &assignment.revoked_partitions {
+                    let Some(offset) = last_consumed_offsets
+                        .get(partition_id)
+                        .map(|offset| offset.load(ORDERING))
+                    else {
+                        continue;
+                    };
+
+                    Self::store_consumer_offset(
+                        client,
+                        consumer,
+                        stream_id,
+                        topic_id,
+                        *partition_id,
+                        offset,
+                        last_stored_offsets,
+                        false,
+                    )
+                    .await?;
+                }
+            }
+
+            if let Some(listener) = &rebalance.listener {
+                listener
+                    .on_partitions_revoked(&assignment.revoked_partitions)
+                    .await;
+            }
+        }
+
+        if !assignment.assigned_partitions.is_empty() {
+            info!(
+                "Partitions: {:?} have been assigned to consumer: {consumer}, topic: {topic_id}, stream: {stream_id}.",
+                assignment.assigned_partitions
+            );
+            if let Some(listener) = &rebalance.listener {
+                listener
+                    .on_partitions_assigned(&assignment.assigned_partitions)
+                    .await;
+            }
+        }
+
+        client
+            .read()
+            .await
+            .sync_consumer_group(stream_id, topic_id, &consumer.id, assignment.generation)
+            .await?;
+        trace!(
+            "Acknowledged rebalance with generation: {} for consumer: {consumer}, topic: {topic_id}, stream: {stream_id}.",
+            assignment.generation
+        );
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
     async fn initialize_consumer_group(
         client: IggySharedMut<Box<dyn Client>>,
         create_consumer_group_if_not_exists: bool,
+        assignment_strategy: ConsumerGroupAssignmentStrategy,
         stream_id: Arc<Identifier>,
         topic_id: Arc<Identifier>,
         consumer: Arc<Consumer>,
@@ -806,7 +1205,7 @@ impl IggyConsumer {
                 "Creating consumer group: {consumer_group_id} for topic: {topic_id}, stream: {stream_id}"
             );
             client
-                .create_consumer_group(&stream_id, &topic_id, &name, id)
+                .create_consumer_group(&stream_id, &topic_id, &name, id, assignment_strategy)
                 .await?;
         }
 
@@ -832,6 +1231,53 @@ impl IggyConsumer {
     }
 }
 
+fn dead_letter_headers(
+    stream_id: &Identifier,
+    topic_id: &Identifier,
+    partition_id: u32,
+    offset: u64,
+    error: &IggyError,
+    attempts: u32,
+) -> Result<HashMap<HeaderKey, HeaderValue>, IggyError> {
+    // The header values are limited to 255 bytes.
+    let mut error = error.to_string();
+    if error.len() > 255 {
+        let mut length = 255;
+        while !error.is_char_boundary(length) {
+            length -= 1;
+        }
+        error.truncate(length);
+    }
+
+    Ok(HashMap::from([
+        (
+            HeaderKey::new(DEAD_LETTER_ORIGINAL_STREAM_HEADER)?,
+            HeaderValue::from_str(&stream_id.to_string())?,
+        ),
+        (
+            HeaderKey::new(DEAD_LETTER_ORIGINAL_TOPIC_HEADER)?,
+            HeaderValue::from_str(&topic_id.to_string())?,
+        ),
+        (
+            HeaderKey::new(DEAD_LETTER_ORIGINAL_PARTITION_HEADER)?,
+            HeaderValue::from_uint32(partition_id)?,
+        ),
+        (
+            HeaderKey::new(DEAD_LETTER_ORIGINAL_OFFSET_HEADER)?,
+            HeaderValue::from_uint64(offset)?,
+        ),
+        (
+            HeaderKey::new(DEAD_LETTER_ERROR_HEADER)?,
+            HeaderValue::from_str(&error)?,
+        ),
+        (
+            HeaderKey::new(DEAD_LETTER_ATTEMPTS_HEADER)?,
+            HeaderValue::from_uint32(attempts)?,
+        ),
+    ]))
+}
+
+#[derive(Clone)]
 pub struct ReceivedMessage {
     pub message: IggyMessage,
     pub current_offset: u64,
@@ -858,7 +1304,7 @@ impl Stream for IggyConsumer {
                 if let Some(last_consumed_offset_entry) =
                     self.last_consumed_offsets.get(&partition_id)
                 {
-                    last_consumed_offset_entry.store(message.header.offset, ORDERING);
+                    last_consumed_offset_entry.fetch_max(message.header.offset, ORDERING);
                 } else {
                     self.last_consumed_offsets
                         .insert(partition_id, AtomicU64::new(message.header.offset));
@@ -873,9 +1319,7 @@ impl Stream for IggyConsumer {
             }
 
             if self.buffered_messages.is_empty() {
-                if self.polling_strategy.kind == PollingKind::Offset {
-                    self.polling_strategy = PollingStrategy::offset(message.header.offset + 1);
-                }
+                self.advance_offset_polling_strategy(message.header.offset);
 
                 if self.store_offset_after_all_messages {
                     self.send_store_offset(partition_id, message.header.offset);
@@ -906,7 +1350,11 @@ impl Stream for IggyConsumer {
                 Poll::Ready(Ok(mut polled_messages)) => {
                     let partition_id = polled_messages.partition_id;
                     self.current_partition_id.store(partition_id, ORDERING);
+                    self.filtered_next_offset = polled_messages.next_offset;
                     if polled_messages.messages.is_empty() {
+                        if let Some(next_offset) = polled_messages.next_offset {
+                            self.skip_filtered_messages(partition_id, next_offset);
+                        }
                         self.poll_future = Some(Box::pin(self.create_poll_messages_future()));
                     } else {
                         if let Some(ref encryptor) = self.encryptor {
@@ -941,15 +1389,12 @@ impl Stream for IggyConsumer {
                         let message = polled_messages.messages.remove(0);
                         self.buffered_messages.extend(polled_messages.messages);
 
-                        if self.polling_strategy.kind == PollingKind::Offset {
-                            self.polling_strategy =
-                                PollingStrategy::offset(message.header.offset + 1);
-                        }
+                        self.advance_offset_polling_strategy(message.header.offset);
 
                         if let Some(last_consumed_offset_entry) =
                             self.last_consumed_offsets.get(&partition_id)
                         {
-                            last_consumed_offset_entry.store(message.header.offset, ORDERING);
+                            last_consumed_offset_entry.fetch_max(message.header.offset, ORDERING);
                         } else {
                             self.last_consumed_offsets
                                 .insert(partition_id, AtomicU64::new(message.header.offset));
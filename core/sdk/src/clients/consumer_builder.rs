@@ -16,10 +16,13 @@
  * under the License.
  */
 
-use crate::prelude::{AutoCommit, AutoCommitWhen, IggyConsumer};
+use crate::prelude::{AutoCommit, AutoCommitWhen, ConsumerRebalanceListener, IggyConsumer};
 use iggy_binary_protocol::Client;
 use iggy_common::locking::IggySharedMut;
-use iggy_common::{Consumer, EncryptorKind, Identifier, IggyDuration, PollingStrategy};
+use iggy_common::{
+    Consumer, ConsumerGroupAssignmentStrategy, EncryptorKind, Identifier, IggyDuration,
+    MessageFilter, PollingStrategy,
+};
 use std::sync::Arc;
 
 #[derive(Debug)]
@@ -41,6 +44,12 @@ pub struct IggyConsumerBuilder {
     init_retries: Option<u32>,
     init_retry_interval: IggyDuration,
     allow_replay: bool,
+    filter: Option<MessageFilter>,
+    long_polling: Option<(IggyDuration, u32)>,
+    assignment_strategy: ConsumerGroupAssignmentStrategy,
+    rebalance_listener: Option<Arc<dyn ConsumerRebalanceListener>>,
+    rebalance_sync_interval: IggyDuration,
+    dead_letter_topic: Option<(Identifier, Identifier, u32)>,
 }
 
 impl IggyConsumerBuilder {
@@ -76,6 +85,12 @@ impl IggyConsumerBuilder {
             init_retries: None,
             init_retry_interval: IggyDuration::ONE_SECOND,
             allow_replay: false,
+            filter: None,
+            long_polling: None,
+            assignment_strategy: ConsumerGroupAssignmentStrategy::default(),
+            rebalance_listener: None,
+            rebalance_sync_interval: IggyDuration::ONE_SECOND,
+            dead_letter_topic: None,
         }
     }
 
@@ -213,6 +228,78 @@ impl IggyConsumerBuilder {
         }
     }
 
+    /// Sets the filter over the user headers, which is applied on the server side,
+    /// so that only the matching messages are returned. By default, no filter is applied.
+    ///
+    /// Note: When polling the next messages with the auto-commit disabled, the offset must be stored
+    /// manually, otherwise the non-matching messages will be scanned again by the subsequent polls.
+    pub fn filter(self, filter: MessageFilter) -> Self {
+        Self {
+            filter: Some(filter),
+            ..self
+        }
+    }
+
+    /// Sets the maximum time for which the server waits for the new messages to be appended, if there are fewer than
+    /// `min_bytes` of them (zero for any messages), instead of responding with the empty batch right away (long polling).
+    /// Disabled by default, as the long poll of the consumer group member is sent over the same connection as the other
+    /// requests of the client, so e.g. the producer sharing the client waits until the poll completes.
+    pub fn long_polling(self, max_wait: IggyDuration, min_bytes: u32) -> Self {
+        Self {
+            long_polling: Some((max_wait, min_bytes)),
+            ..self
+        }
+    }
+
+    /// Disables the long polling, so that the server responds right away, even if there are no messages (default).
+    pub fn without_long_polling(self) -> Self {
+        Self {
+            long_polling: None,
+            ..self
+        }
+    }
+
+    /// Sets the strategy of assigning the partitions to the members, which is used when the consumer group is created.
+    /// By default, the partitions are assigned using round-robin.
+    pub fn assignment_strategy(self, assignment_strategy: ConsumerGroupAssignmentStrategy) -> Self {
+        Self {
+            assignment_strategy,
+            ..self
+        }
+    }
+
+    /// Sets the listener invoked when the partitions are assigned to or revoked from the consumer group member,
+    /// e.g. to store the offsets before the partition is handed over to the other member.
+    pub fn rebalance_listener(self, listener: Arc<dyn ConsumerRebalanceListener>) -> Self {
+        Self {
+            rebalance_listener: Some(listener),
+            ..self
+        }
+    }
+
+    /// Sets how often the consumer group member checks whether its partitions have been rebalanced, 1 second by default.
+    pub fn rebalance_sync_interval(self, interval: IggyDuration) -> Self {
+        Self {
+            rebalance_sync_interval: interval,
+            ..self
+        }
+    }
+
+    /// Sets the dead-letter stream and topic, to which the messages are republished after `max_attempts` failed
+    /// delivery attempts, along with the headers describing their origin and the error, so that the offset can advance.
+    /// The failed messages are retried by `consume_messages()`, or can be reported with `handle_failed_message()`.
+    pub fn dead_letter_topic(
+        self,
+        stream: Identifier,
+        topic: Identifier,
+        max_attempts: u32,
+    ) -> Self {
+        Self {
+            dead_letter_topic: Some((stream, topic, max_attempts.max(1))),
+            ..self
+        }
+    }
+
     /// Builds the consumer.
     ///
     /// Note: After building the consumer, `init()` must be invoked before producing messages.
@@ -235,6 +322,12 @@ impl IggyConsumerBuilder {
             self.init_retries,
             self.init_retry_interval,
             self.allow_replay,
+            self.filter,
+            self.long_polling,
+            self.assignment_strategy,
+            self.rebalance_listener,
+            self.rebalance_sync_interval,
+            self.dead_letter_topic,
         )
     }
 }
@@ -0,0 +1,241 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy_binary_protocol::Client;
+use iggy_common::{
+    Identifier, IggyError, Partitioning, PartitioningHash, PartitioningKind, ProducerInfo,
+    ProducerSequence,
+};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// How long the fetched topic partitioning is used before it's fetched again,
+/// so that the partitions created in the meantime are taken into account.
+const TOPIC_PARTITIONING_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The partition resolved to the numeric IDs, so that the same partition has the single sequence,
+/// no matter whether its stream and topic are referred to by the ID or by the name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct PartitionKey {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TopicPartitioning {
+    stream_id: u32,
+    topic_id: u32,
+    partitions_count: u32,
+    partitioning_hash: PartitioningHash,
+    fetched_at: Instant,
+}
+
+/// The state of the idempotent producer, which attaches the per-partition sequence numbers to the sent batches,
+/// so that the batches retried e.g. after a timeout are never appended twice.
+#[derive(Debug, Default)]
+pub(crate) struct IdempotentProducer {
+    producer_id: Option<u64>,
+    producer: Option<ProducerInfo>,
+    next_sequences: HashMap<PartitionKey, u64>,
+    topics_partitioning: HashMap<(String, String), TopicPartitioning>,
+    last_balanced_partition_id: u32,
+}
+
+impl IdempotentProducer {
+    /// Initializes the producer on the server, or bumps its epoch if it has been initialized before,
+    /// which fences the batches sent by the previous epoch and resets the sequence numbers.
+    pub async fn init(&mut self, client: &dyn Client) -> Result<ProducerInfo, IggyError> {
+        let producer = client.init_producer(self.producer_id).await?;
+        self.producer_id = Some(producer.id);
+        self.producer = Some(producer);
+        self.next_sequences.clear();
+        Ok(producer)
+    }
+
+    /// Resolves the partition and the producer sequence of the next batch, initializing the producer if needed.
+    pub async fn next_batch(
+        &mut self,
+        client: &dyn Client,
+        stream: &Identifier,
+        topic: &Identifier,
+        partitioning: &Partitioning,
+    ) -> Result<(PartitionKey, ProducerSequence), IggyError> {
+        let producer = match self.producer {
+            Some(producer) => producer,
+            None => self.init(client).await?,
+        };
+        let partition = self
+            .resolve_partition(client, stream, topic, partitioning)
+            .await?;
+        let sequence = self
+            .next_sequences
+            .get(&partition)
+            .copied()
+            .unwrap_or_default();
+        Ok((
+            partition,
+            ProducerSequence {
+                producer_id: producer.id,
+                producer_epoch: producer.epoch,
+                sequence,
+            },
+        ))
+    }
+
+    /// Marks the batch as appended, so that the next one sent to the same partition gets the following sequence number.
+    pub fn complete_batch(&mut self, partition: PartitionKey, producer: &ProducerSequence) {
+        self.next_sequences.insert(partition, producer.sequence + 1);
+    }
+
+    /// Invalidates the producer once the batch could not be sent, as it's unknown whether it has been appended.
+    /// The next batch re-initializes the producer with the bumped epoch and fetches the topics partitioning again.
+    pub fn reset(&mut self) {
+        self.producer = None;
+        self.topics_partitioning.clear();
+    }
+
+    async fn resolve_partition(
+        &mut self,
+        client: &dyn Client,
+        stream: &Identifier,
+        topic: &Identifier,
+        partitioning: &Partitioning,
+    ) -> Result<PartitionKey, IggyError> {
+        let topic_partitioning = self.get_topic_partitioning(client, stream, topic).await?;
+        let partition_id = match partitioning.kind {
+            PartitioningKind::PartitionId => u32::from_le_bytes(
+                partitioning.value[..4]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ),
+            // The server can't balance the idempotent messages, as the retried batch could end up in another partition.
+            PartitioningKind::Balanced => {
+                self.last_balanced_partition_id =
+                    self.last_balanced_partition_id % topic_partitioning.partitions_count + 1;
+                self.last_balanced_partition_id
+            }
+            PartitioningKind::MessagesKey => calculate_partition_id_by_messages_key(
+                &partitioning.value,
+                topic_partitioning.partitions_count,
+                &topic_partitioning.partitioning_hash,
+            ),
+        };
+        Ok(PartitionKey {
+            stream_id: topic_partitioning.stream_id,
+            topic_id: topic_partitioning.topic_id,
+            partition_id,
+        })
+    }
+
+    async fn get_topic_partitioning(
+        &mut self,
+        client: &dyn Client,
+        stream: &Identifier,
+        topic: &Identifier,
+    ) -> Result<TopicPartitioning, IggyError> {
+        let key = (stream.as_string(), topic.as_string());
+        if let Some(topic_partitioning) = self.topics_partitioning.get(&key)
+            && topic_partitioning.fetched_at.elapsed() < TOPIC_PARTITIONING_REFRESH_INTERVAL
+        {
+            return Ok(*topic_partitioning);
+        }
+
+        let stream_id = match stream.get_u32_value() {
+            Ok(stream_id) => stream_id,
+            Err(_) => {
+                client
+                    .get_stream(stream)
+                    .await?
+                    .ok_or_else(|| IggyError::StreamNameNotFound(stream.as_string()))?
+                    .id
+            }
+        };
+        let topic_details = client
+            .get_topic(stream, topic)
+            .await?
+            .ok_or_else(|| IggyError::TopicNameNotFound(topic.as_string(), stream.as_string()))?;
+        if topic_details.partitions_count == 0 {
+            return Err(IggyError::NoPartitions(topic_details.id, stream_id));
+        }
+
+        let topic_partitioning = TopicPartitioning {
+            stream_id,
+            topic_id: topic_details.id,
+            partitions_count: topic_details.partitions_count,
+            partitioning_hash: topic_details.partitioning_hash,
+            fetched_at: Instant::now(),
+        };
+        self.topics_partitioning.insert(key, topic_partitioning);
+        Ok(topic_partitioning)
+    }
+}
+
+/// Calculates the partition ID for the messages key the same way as the server does for the topic.
+fn calculate_partition_id_by_messages_key(
+    messages_key: &[u8],
+    partitions_count: u32,
+    partitioning_hash: &PartitioningHash,
+) -> u32 {
+    partitioning_hash.calculate_partition_id(messages_key, partitions_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partition_id_for_messages_key_should_be_within_partitions_range() {
+        for key in ["a", "b", "c", "key-1", "key-2"] {
+            let partition_id = calculate_partition_id_by_messages_key(
+                key.as_bytes(),
+                3,
+                &PartitioningHash::default(),
+            );
+            assert!((1..=3).contains(&partition_id));
+            assert_eq!(
+                partition_id,
+                calculate_partition_id_by_messages_key(
+                    key.as_bytes(),
+                    3,
+                    &PartitioningHash::default()
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn completed_batch_should_increment_sequence_of_its_partition_only() {
+        let mut producer = IdempotentProducer::default();
+        let partition = |partition_id| PartitionKey {
+            stream_id: 1,
+            topic_id: 1,
+            partition_id,
+        };
+        let sequence = ProducerSequence {
+            producer_id: 1,
+            producer_epoch: 0,
+            sequence: 0,
+        };
+
+        producer.complete_batch(partition(1), &sequence);
+
+        assert_eq!(producer.next_sequences.get(&partition(1)), Some(&1));
+        assert_eq!(producer.next_sequences.get(&partition(2)), None);
+    }
+}
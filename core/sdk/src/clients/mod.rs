@@ -21,15 +21,20 @@ mod binary_consumer_offset;
 mod binary_message;
 mod binary_partitions;
 mod binary_personal_access_tokens;
+mod binary_producers;
+mod binary_roles;
+mod binary_schemas;
 mod binary_segments;
 mod binary_streams;
 mod binary_system;
 mod binary_topics;
+mod binary_transactions;
 mod binary_users;
 pub mod client;
 pub mod client_builder;
 pub mod consumer;
 pub mod consumer_builder;
+mod idempotent_producer;
 pub mod producer;
 pub mod producer_builder;
 
@@ -15,6 +15,7 @@
  * specific language governing permissions and limitations
  * under the License.
  */
+use super::idempotent_producer::IdempotentProducer;
 use super::{MAX_BATCH_SIZE, ORDERING};
 
 use bytes::Bytes;
@@ -24,10 +25,12 @@ use iggy_common::locking::{IggySharedMut, IggySharedMutFn};
 use iggy_common::{
     CompressionAlgorithm, DiagnosticEvent, EncryptorKind, IdKind, Identifier, IggyDuration,
     IggyError, IggyExpiry, IggyMessage, IggyTimestamp, MaxTopicSize, Partitioner, Partitioning,
+    ProducerSequence, TopicOptions,
 };
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU64};
 use std::time::Duration;
+use tokio::sync::Mutex;
 use tokio::time::{Interval, sleep};
 use tracing::{error, info, trace, warn};
 
@@ -58,6 +61,7 @@ pub struct IggyProducer {
     last_sent_at: Arc<AtomicU64>,
     send_retries_count: Option<u32>,
     send_retries_interval: Option<IggyDuration>,
+    idempotence: Option<Arc<Mutex<IdempotentProducer>>>,
 }
 
 impl IggyProducer {
@@ -106,6 +110,10 @@ impl IggyProducer {
             last_sent_at: Arc::new(AtomicU64::new(0)),
             send_retries_count,
             send_retries_interval,
+            // The retried batches are deduplicated by the server using the producer sequence numbers.
+            idempotence: send_retries_count
+                .filter(|retries| *retries > 0)
+                .map(|_| Arc::new(Mutex::new(IdempotentProducer::default()))),
         }
     }
 
@@ -175,10 +183,19 @@ impl IggyProducer {
                     id,
                     self.topic_message_expiry,
                     self.topic_max_size,
+                    TopicOptions::default(),
                 )
                 .await?;
         }
 
+        if let Some(idempotence) = &self.idempotence {
+            let producer = idempotence.lock().await.init(&**client).await?;
+            info!(
+                "Initialized idempotent producer with ID: {}, epoch: {}.",
+                producer.id, producer.epoch
+            );
+        }
+
         self.initialized = true;
         info!("Producer has been initialized for stream: {stream_id} and topic: {topic_id}.");
         Ok(())
@@ -416,15 +433,42 @@ impl IggyProducer {
 
         self.wait_until_connected(max_retries, stream, topic, &mut timer)
             .await?;
-        self.send_with_retries(
-            max_retries,
-            stream,
-            topic,
-            partitioning,
-            messages,
-            &mut timer,
-        )
-        .await
+        let Some(idempotence) = &self.idempotence else {
+            return self
+                .send_with_retries(
+                    max_retries,
+                    stream,
+                    topic,
+                    partitioning,
+                    None,
+                    messages,
+                    &mut timer,
+                )
+                .await;
+        };
+
+        // Holding the lock for the whole send keeps the batches of the same partition in the sequence order.
+        let mut idempotence = idempotence.lock().await;
+        let (partition, producer) = idempotence
+            .next_batch(&**client, stream, topic, partitioning)
+            .await?;
+        let partitioning = Arc::new(Partitioning::partition_id(partition.partition_id));
+        let result = self
+            .send_with_retries(
+                max_retries,
+                stream,
+                topic,
+                &partitioning,
+                Some(&producer),
+                messages,
+                &mut timer,
+            )
+            .await;
+        match result {
+            Ok(()) => idempotence.complete_batch(partition, &producer),
+            Err(_) => idempotence.reset(),
+        }
+        result
     }
 
     async fn wait_until_connected(
@@ -461,22 +505,33 @@ impl IggyProducer {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn send_with_retries(
         &self,
         max_retries: u32,
         stream: &Identifier,
         topic: &Identifier,
         partitioning: &Arc<Partitioning>,
+        producer: Option<&ProducerSequence>,
         messages: &mut [IggyMessage],
         timer: &mut Option<Interval>,
     ) -> Result<(), IggyError> {
         let client = self.client.read().await;
         let mut retries = 0;
         loop {
-            match client
-                .send_messages(stream, topic, partitioning, messages)
-                .await
-            {
+            let result = match producer {
+                Some(producer) => {
+                    client
+                        .send_idempotent_messages(stream, topic, partitioning, producer, messages)
+                        .await
+                }
+                None => {
+                    client
+                        .send_messages(stream, topic, partitioning, messages)
+                        .await
+                }
+            };
+            match result {
                 Ok(_) => return Ok(()),
                 Err(error) => {
                     retries += 1;
@@ -505,6 +560,20 @@ impl IggyProducer {
         }
     }
 
+    /// Begins a new transaction, within which the messages can be sent to the multiple partitions and topics,
+    /// and then committed or aborted atomically.
+    ///
+    /// The transaction which is dropped without being committed or aborted is aborted in the background.
+    pub async fn begin_transaction(&self) -> Result<IggyProducerTransaction<'_>, IggyError> {
+        let transaction = self.client.read().await.begin_transaction().await?;
+        trace!("Began transaction with ID: {}", transaction.id);
+        Ok(IggyProducerTransaction {
+            producer: self,
+            id: transaction.id,
+            completed: false,
+        })
+    }
+
     fn get_partitioning(
         &self,
         stream: &Identifier,
@@ -526,3 +595,114 @@ impl IggyProducer {
         }
     }
 }
+
+/// The transaction begun by the producer. The messages sent within it are visible to the consumers
+/// polling with the `read_committed` isolation level only once the transaction has been committed.
+pub struct IggyProducerTransaction<'a> {
+    producer: &'a IggyProducer,
+    id: u64,
+    completed: bool,
+}
+
+impl IggyProducerTransaction<'_> {
+    /// Returns the unique ID of the transaction.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Sends the messages within the transaction to the stream and topic of the producer.
+    pub async fn send(&self, messages: Vec<IggyMessage>) -> Result<(), IggyError> {
+        self.send_to(
+            &self.producer.stream_id,
+            &self.producer.topic_id,
+            messages,
+            None,
+        )
+        .await
+    }
+
+    /// Sends the messages within the transaction to the stream and topic of the producer using the provided partitioning.
+    pub async fn send_with_partitioning(
+        &self,
+        messages: Vec<IggyMessage>,
+        partitioning: Option<Arc<Partitioning>>,
+    ) -> Result<(), IggyError> {
+        self.send_to(
+            &self.producer.stream_id,
+            &self.producer.topic_id,
+            messages,
+            partitioning,
+        )
+        .await
+    }
+
+    /// Sends the messages within the transaction to the provided stream and topic, which might differ from the ones of the producer.
+    pub async fn send_to(
+        &self,
+        stream: &Identifier,
+        topic: &Identifier,
+        mut messages: Vec<IggyMessage>,
+        partitioning: Option<Arc<Partitioning>>,
+    ) -> Result<(), IggyError> {
+        if messages.is_empty() {
+            trace!("No messages to send.");
+            return Ok(());
+        }
+
+        self.producer.encrypt_messages(&mut messages)?;
+        let partitioning =
+            self.producer
+                .get_partitioning(stream, topic, &messages, partitioning)?;
+        let batch_size = self.producer.batch_size.unwrap_or(MAX_BATCH_SIZE);
+        let client = self.producer.client.read().await;
+        for batch in messages.chunks_mut(batch_size) {
+            client
+                .send_transaction_messages(self.id, stream, topic, &partitioning, batch)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Commits the transaction, so that all the messages sent within it become visible at once.
+    pub async fn commit(mut self) -> Result<(), IggyError> {
+        self.completed = true;
+        self.producer
+            .client
+            .read()
+            .await
+            .commit_transaction(self.id)
+            .await?;
+        trace!("Committed transaction with ID: {}", self.id);
+        Ok(())
+    }
+
+    /// Aborts the transaction, so that none of the messages sent within it become visible.
+    pub async fn abort(mut self) -> Result<(), IggyError> {
+        self.completed = true;
+        self.producer
+            .client
+            .read()
+            .await
+            .abort_transaction(self.id)
+            .await?;
+        trace!("Aborted transaction with ID: {}", self.id);
+        Ok(())
+    }
+}
+
+impl Drop for IggyProducerTransaction<'_> {
+    fn drop(&mut self) {
+        if self.completed {
+            return;
+        }
+
+        let id = self.id;
+        let client = self.producer.client.clone();
+        warn!("Transaction with ID: {id} has been dropped without being completed, aborting...");
+        tokio::spawn(async move {
+            if let Err(error) = client.read().await.abort_transaction(id).await {
+                error!("Failed to abort transaction with ID: {id}. {error}");
+            }
+        });
+    }
+}
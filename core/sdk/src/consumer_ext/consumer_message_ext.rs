@@ -89,7 +89,24 @@ impl IggyConsumerMessageExt for IggyConsumer {
                             let partition_id = received_message.partition_id;
                             let current_offset = received_message.current_offset;
                             let message_offset = received_message.message.header.offset;
-                            if let Err(err) = message_consumer.consume(received_message).await {
+                            let failed_message = self.has_dead_letter_topic().then(|| received_message.clone());
+                            let mut result = message_consumer.consume(received_message).await;
+                            if let (Some(failed_message), Err(_)) = (&failed_message, &result) {
+                                // Retry the message until it's either processed or moved to the dead-letter topic.
+                                while let Err(err) = &result {
+                                    match self.handle_failed_message(failed_message, err).await {
+                                        Ok(false) => result = message_consumer.consume(failed_message.clone()).await,
+                                        Ok(true) => break,
+                                        Err(error) => {
+                                            error!("Failed to handle failed message at offset: {message_offset}/{current_offset}, partition: {partition_id} for consumer: {name} on topic: {topic} and stream: {stream} due to error: {error}",
+                                                name = self.name(), topic = self.topic(), stream = self.stream());
+                                            break;
+                                        }
+                                    }
+                                }
+                            }
+
+                            if let Err(err) = result {
                                 error!("Error while handling message at offset: {message_offset}/{current_offset}, partition: {partition_id} for consumer: {name} on topic: {topic} and stream: {stream} due to error: {err}",
                                     name = self.name(), topic = self.topic(), stream = self.stream());
                             } else {
@@ -23,7 +23,11 @@ use async_trait::async_trait;
 use iggy_binary_protocol::ConsumerGroupClient;
 use iggy_common::Identifier;
 use iggy_common::create_consumer_group::CreateConsumerGroup;
-use iggy_common::{ConsumerGroup, ConsumerGroupDetails};
+use iggy_common::reset_consumer_group_offsets::ResetConsumerGroupOffsets;
+use iggy_common::{
+    ConsumerGroup, ConsumerGroupAssignment, ConsumerGroupAssignmentStrategy, ConsumerGroupDetails,
+    ConsumerGroupOffsetReset, ConsumerOffsetsResetTarget,
+};
 
 #[async_trait]
 impl ConsumerGroupClient for HttpClient {
@@ -76,6 +80,7 @@ impl ConsumerGroupClient for HttpClient {
         topic_id: &Identifier,
         name: &str,
         group_id: Option<u32>,
+        assignment_strategy: ConsumerGroupAssignmentStrategy,
     ) -> Result<ConsumerGroupDetails, IggyError> {
         let response = self
             .post(
@@ -85,6 +90,7 @@ impl ConsumerGroupClient for HttpClient {
                     topic_id: topic_id.clone(),
                     name: name.to_string(),
                     group_id,
+                    assignment_strategy,
                 },
             )
             .await?;
@@ -127,6 +133,60 @@ impl ConsumerGroupClient for HttpClient {
     ) -> Result<(), IggyError> {
         Err(IggyError::FeatureUnavailable)
     }
+
+    async fn sync_consumer_group(
+        &self,
+        _: &Identifier,
+        _: &Identifier,
+        _: &Identifier,
+        _: u32,
+    ) -> Result<ConsumerGroupAssignment, IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn record_delivery_failure(
+        &self,
+        _: &Identifier,
+        _: &Identifier,
+        _: &Identifier,
+        _: u32,
+        _: u64,
+    ) -> Result<u32, IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn reset_consumer_group_offsets(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        target: &ConsumerOffsetsResetTarget,
+        dry_run: bool,
+        force: bool,
+    ) -> Result<Vec<ConsumerGroupOffsetReset>, IggyError> {
+        let response = self
+            .post(
+                &format!(
+                    "{}/{}/reset-offsets",
+                    get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+                    group_id
+                ),
+                &ResetConsumerGroupOffsets {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    group_id: group_id.clone(),
+                    target: target.clone(),
+                    dry_run,
+                    force,
+                },
+            )
+            .await?;
+        let resets = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(resets)
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
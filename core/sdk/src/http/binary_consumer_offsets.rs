@@ -24,7 +24,8 @@ use async_trait::async_trait;
 use iggy_binary_protocol::ConsumerOffsetClient;
 use iggy_common::get_consumer_offset::GetConsumerOffset;
 use iggy_common::store_consumer_offset::StoreConsumerOffset;
-use iggy_common::{Consumer, ConsumerOffsetInfo};
+use iggy_common::store_consumer_offsets::StoreConsumerOffsets;
+use iggy_common::{Consumer, ConsumerOffsetInfo, ConsumerPartitionOffset};
 
 #[async_trait]
 impl ConsumerOffsetClient for HttpClient {
@@ -35,6 +36,7 @@ impl ConsumerOffsetClient for HttpClient {
         topic_id: &Identifier,
         partition_id: Option<u32>,
         offset: u64,
+        metadata: Option<&str>,
     ) -> Result<(), IggyError> {
         self.put(
             &get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
@@ -44,6 +46,30 @@ impl ConsumerOffsetClient for HttpClient {
                 topic_id: topic_id.clone(),
                 partition_id,
                 offset,
+                metadata: metadata.map(|metadata| metadata.to_string()),
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn store_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        offsets: &[ConsumerPartitionOffset],
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!(
+                "{}/bulk",
+                get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+            ),
+            &StoreConsumerOffsets {
+                consumer: consumer.clone(),
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                offsets: offsets.to_vec(),
             },
         )
         .await?;
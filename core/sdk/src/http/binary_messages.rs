@@ -19,12 +19,12 @@
 use crate::http::http_client::HttpClient;
 use crate::http::http_transport::HttpTransport;
 use crate::prelude::{
-    Consumer, FlushUnsavedBuffer, Identifier, IggyError, IggyMessage, Partitioning, PollMessages,
-    PolledMessages, PollingStrategy, SendMessages,
+    Consumer, FlushUnsavedBuffer, Identifier, IggyError, IggyMessage, MessageFilter, Partitioning,
+    PollMessages, PolledMessages, PollingStrategy, SendMessages,
 };
 use async_trait::async_trait;
 use iggy_binary_protocol::MessageClient;
-use iggy_common::IggyMessagesBatch;
+use iggy_common::{IggyDuration, IggyMessagesBatch, IsolationLevel};
 
 #[async_trait]
 impl MessageClient for HttpClient {
@@ -49,6 +49,84 @@ impl MessageClient for HttpClient {
                     strategy: *strategy,
                     count,
                     auto_commit,
+                    filter: None,
+                    isolation_level: IsolationLevel::ReadUncommitted,
+                    max_wait: IggyDuration::default(),
+                    min_bytes: 0,
+                },
+            )
+            .await?;
+        let messages = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(messages)
+    }
+
+    async fn poll_messages_with_filter(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        filter: &MessageFilter,
+    ) -> Result<PolledMessages, IggyError> {
+        let response = self
+            .get_with_query(
+                &get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+                &PollMessages {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partition_id,
+                    consumer: consumer.clone(),
+                    strategy: *strategy,
+                    count,
+                    auto_commit,
+                    filter: Some(filter.clone()),
+                    isolation_level: IsolationLevel::ReadUncommitted,
+                    max_wait: IggyDuration::default(),
+                    min_bytes: 0,
+                },
+            )
+            .await?;
+        let messages = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(messages)
+    }
+
+    async fn long_poll_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        filter: Option<&MessageFilter>,
+        max_wait: IggyDuration,
+        min_bytes: u32,
+    ) -> Result<PolledMessages, IggyError> {
+        let response = self
+            .get_with_query(
+                &get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+                &PollMessages {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partition_id,
+                    consumer: consumer.clone(),
+                    strategy: *strategy,
+                    count,
+                    auto_commit,
+                    filter: filter.cloned(),
+                    isolation_level: IsolationLevel::ReadUncommitted,
+                    max_wait,
+                    min_bytes,
                 },
             )
             .await?;
@@ -74,6 +152,7 @@ impl MessageClient for HttpClient {
                 stream_id: stream_id.clone(),
                 topic_id: topic_id.clone(),
                 partitioning: partitioning.clone(),
+                producer: None,
                 batch,
             },
         )
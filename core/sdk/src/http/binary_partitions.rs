@@ -23,6 +23,7 @@ use async_trait::async_trait;
 use iggy_binary_protocol::PartitionClient;
 use iggy_common::create_partitions::CreatePartitions;
 use iggy_common::delete_partitions::DeletePartitions;
+use iggy_common::move_partition::MovePartition;
 
 #[async_trait]
 impl PartitionClient for HttpClient {
@@ -61,6 +62,29 @@ impl PartitionClient for HttpClient {
         .await?;
         Ok(())
     }
+
+    async fn move_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        data_directory: &str,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!(
+                "{}/{partition_id}/data_directory",
+                get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+            ),
+            &MovePartition {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id,
+                data_directory: data_directory.to_string(),
+            },
+        )
+        .await?;
+        Ok(())
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
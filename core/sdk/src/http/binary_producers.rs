@@ -0,0 +1,74 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::http_client::HttpClient;
+use crate::http::http_transport::HttpTransport;
+use crate::prelude::{Identifier, IggyError, IggyMessage, Partitioning, SendMessages};
+use async_trait::async_trait;
+use iggy_binary_protocol::ProducerClient;
+use iggy_common::init_producer::InitProducer;
+use iggy_common::{IggyMessagesBatch, ProducerInfo, ProducerSequence};
+
+const PATH: &str = "/producers";
+
+#[async_trait]
+impl ProducerClient for HttpClient {
+    async fn init_producer(&self, producer_id: Option<u64>) -> Result<ProducerInfo, IggyError> {
+        let response = self
+            .post(
+                PATH,
+                &InitProducer {
+                    producer_id: producer_id.unwrap_or_default(),
+                },
+            )
+            .await?;
+        let producer = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(producer)
+    }
+
+    async fn send_idempotent_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        producer: &ProducerSequence,
+        messages: &mut [IggyMessage],
+    ) -> Result<(), IggyError> {
+        let batch = IggyMessagesBatch::from(&*messages);
+        self.post(
+            &format!(
+                "streams/{}/topics/{}/messages",
+                stream_id.as_cow_str(),
+                topic_id.as_cow_str()
+            ),
+            &SendMessages {
+                metadata_length: 0, // this field is used only for TCP/QUIC
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partitioning: partitioning.clone(),
+                producer: Some(*producer),
+                batch,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+}
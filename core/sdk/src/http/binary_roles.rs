@@ -0,0 +1,86 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::http_client::HttpClient;
+use crate::http::http_transport::HttpTransport;
+use crate::prelude::{Identifier, IggyError};
+use async_trait::async_trait;
+use iggy_binary_protocol::RoleClient;
+use iggy_common::assign_roles::AssignRoles;
+use iggy_common::create_role::CreateRole;
+use iggy_common::update_role::UpdateRole;
+use iggy_common::{Role, RoleRule};
+
+const PATH: &str = "/roles";
+
+#[async_trait]
+impl RoleClient for HttpClient {
+    async fn get_roles(&self) -> Result<Vec<Role>, IggyError> {
+        let response = self.get(PATH).await?;
+        let roles = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(roles)
+    }
+
+    async fn create_role(&self, name: &str, rules: Vec<RoleRule>) -> Result<(), IggyError> {
+        self.post(
+            PATH,
+            &CreateRole {
+                name: name.to_string(),
+                rules,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_role(&self, name: &str, rules: Vec<RoleRule>) -> Result<(), IggyError> {
+        self.put(
+            &format!("{PATH}/{name}"),
+            &UpdateRole {
+                name: name.to_string(),
+                rules,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_role(&self, name: &str) -> Result<(), IggyError> {
+        self.delete(&format!("{PATH}/{name}")).await?;
+        Ok(())
+    }
+
+    async fn assign_roles(
+        &self,
+        user_id: &Identifier,
+        roles: Vec<String>,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!("/users/{}/roles", &user_id.as_cow_str()),
+            &AssignRoles {
+                user_id: user_id.clone(),
+                roles,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+}
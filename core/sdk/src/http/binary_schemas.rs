@@ -0,0 +1,99 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::http_client::HttpClient;
+use crate::http::http_transport::HttpTransport;
+use crate::prelude::{Identifier, IggyError};
+use async_trait::async_trait;
+use bytes::Bytes;
+use iggy_binary_protocol::SchemaClient;
+use iggy_common::register_schema::RegisterSchema;
+use iggy_common::{Schema, SchemaCompatibility, SchemaType};
+
+const PATH: &str = "/schemas";
+
+#[async_trait]
+impl SchemaClient for HttpClient {
+    async fn get_schema(&self, schema_id: u32) -> Result<Option<Schema>, IggyError> {
+        let response = self.get(&format!("{PATH}/{schema_id}")).await;
+        if let Err(error) = response {
+            if matches!(error, IggyError::ResourceNotFound(_)) {
+                return Ok(None);
+            }
+
+            return Err(error);
+        }
+
+        let schema = response?
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(Some(schema))
+    }
+
+    async fn get_schemas(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<Schema>, IggyError> {
+        let response = self
+            .get(&get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()))
+            .await?;
+        let schemas = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(schemas)
+    }
+
+    async fn register_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        schema_type: SchemaType,
+        compatibility: SchemaCompatibility,
+        definition: Bytes,
+    ) -> Result<Schema, IggyError> {
+        let response = self
+            .post(
+                &get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+                &RegisterSchema {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    schema_type,
+                    compatibility,
+                    definition,
+                },
+            )
+            .await?;
+        let schema = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(schema)
+    }
+
+    async fn delete_schema(&self, schema_id: u32) -> Result<(), IggyError> {
+        self.delete(&format!("{PATH}/{schema_id}")).await?;
+        Ok(())
+    }
+}
+
+fn get_path(stream_id: &str, topic_id: &str) -> String {
+    format!("streams/{stream_id}/topics/{topic_id}/schemas")
+}
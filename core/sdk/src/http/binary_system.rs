@@ -21,8 +21,12 @@ use crate::http::http_transport::HttpTransport;
 use crate::prelude::{IggyDuration, IggyError};
 use async_trait::async_trait;
 use iggy_binary_protocol::SystemClient;
+use iggy_common::AuditRecord;
+use iggy_common::ServerMetadata;
 use iggy_common::Snapshot;
 use iggy_common::Stats;
+use iggy_common::export_metadata::ExportMetadata;
+use iggy_common::get_audit_log::GetAuditLog;
 use iggy_common::get_snapshot::GetSnapshot;
 use iggy_common::{ClientInfo, ClientInfoDetails};
 use iggy_common::{SnapshotCompression, SystemSnapshotType};
@@ -31,6 +35,8 @@ const PING: &str = "/ping";
 const CLIENTS: &str = "/clients";
 const STATS: &str = "/stats";
 const SNAPSHOT: &str = "/snapshot";
+const AUDIT_LOG: &str = "/audit-log";
+const METADATA: &str = "/metadata";
 
 #[async_trait]
 impl SystemClient for HttpClient {
@@ -103,4 +109,38 @@ impl SystemClient for HttpClient {
         let snapshot = Snapshot::new(file.to_vec());
         Ok(snapshot)
     }
+
+    async fn get_audit_log(
+        &self,
+        start_id: u64,
+        count: u32,
+    ) -> Result<Vec<AuditRecord>, IggyError> {
+        let response = self
+            .get_with_query(AUDIT_LOG, &GetAuditLog { start_id, count })
+            .await?;
+        let records = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(records)
+    }
+
+    async fn export_metadata(
+        &self,
+        include_password_hashes: bool,
+    ) -> Result<ServerMetadata, IggyError> {
+        let response = self
+            .get_with_query(
+                METADATA,
+                &ExportMetadata {
+                    include_password_hashes,
+                },
+            )
+            .await?;
+        let metadata = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(metadata)
+    }
 }
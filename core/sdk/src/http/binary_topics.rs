@@ -18,7 +18,9 @@
 
 use crate::http::http_client::HttpClient;
 use crate::http::http_transport::HttpTransport;
-use crate::prelude::{CompressionAlgorithm, Identifier, IggyError, IggyExpiry, MaxTopicSize};
+use crate::prelude::{
+    CompressionAlgorithm, Identifier, IggyError, IggyExpiry, MaxTopicSize, TopicOptions,
+};
 use async_trait::async_trait;
 use iggy_binary_protocol::TopicClient;
 use iggy_common::create_topic::CreateTopic;
@@ -72,6 +74,7 @@ impl TopicClient for HttpClient {
         topic_id: Option<u32>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+        options: TopicOptions,
     ) -> Result<TopicDetails, IggyError> {
         let response = self
             .post(
@@ -85,6 +88,10 @@ impl TopicClient for HttpClient {
                     topic_id,
                     message_expiry,
                     max_topic_size,
+                    cleanup_policy: options.cleanup_policy,
+                    partitioning_hash: options.partitioning_hash,
+                    segment_max_age: options.segment_max_age,
+                    max_partition_size: options.max_partition_size,
                 },
             )
             .await?;
@@ -104,6 +111,7 @@ impl TopicClient for HttpClient {
         replication_factor: Option<u8>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+        options: TopicOptions,
     ) -> Result<(), IggyError> {
         self.put(
             &get_details_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
@@ -115,6 +123,10 @@ impl TopicClient for HttpClient {
                 replication_factor,
                 message_expiry,
                 max_topic_size,
+                cleanup_policy: options.cleanup_policy,
+                partitioning_hash: options.partitioning_hash,
+                segment_max_age: options.segment_max_age,
+                max_partition_size: options.max_partition_size,
             },
         )
         .await?;
@@ -0,0 +1,133 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::http_client::HttpClient;
+use crate::http::http_transport::HttpTransport;
+use crate::prelude::{
+    Consumer, Identifier, IggyError, IggyMessage, Partitioning, PollMessages, PolledMessages,
+    PollingStrategy, SendMessages,
+};
+use async_trait::async_trait;
+use iggy_binary_protocol::TransactionClient;
+use iggy_common::abort_transaction::AbortTransaction;
+use iggy_common::begin_transaction::BeginTransaction;
+use iggy_common::commit_transaction::CommitTransaction;
+use iggy_common::{IggyDuration, IggyMessagesBatch, IsolationLevel, TransactionInfo};
+
+const PATH: &str = "/transactions";
+
+#[async_trait]
+impl TransactionClient for HttpClient {
+    async fn begin_transaction(&self) -> Result<TransactionInfo, IggyError> {
+        let response = self.post(PATH, &BeginTransaction {}).await?;
+        let transaction = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(transaction)
+    }
+
+    async fn send_transaction_messages(
+        &self,
+        transaction_id: u64,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [IggyMessage],
+    ) -> Result<(), IggyError> {
+        let batch = IggyMessagesBatch::from(&*messages);
+        self.post(
+            &get_messages_path(
+                transaction_id,
+                &stream_id.as_cow_str(),
+                &topic_id.as_cow_str(),
+            ),
+            &SendMessages {
+                metadata_length: 0, // this field is used only for TCP/QUIC
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partitioning: partitioning.clone(),
+                producer: None,
+                batch,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn commit_transaction(&self, transaction_id: u64) -> Result<(), IggyError> {
+        self.post(
+            &format!("{PATH}/{transaction_id}/commit"),
+            &CommitTransaction { transaction_id },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn abort_transaction(&self, transaction_id: u64) -> Result<(), IggyError> {
+        self.post(
+            &format!("{PATH}/{transaction_id}/abort"),
+            &AbortTransaction { transaction_id },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn poll_committed_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+    ) -> Result<PolledMessages, IggyError> {
+        let response = self
+            .get_with_query(
+                &format!(
+                    "streams/{}/topics/{}/messages",
+                    stream_id.as_cow_str(),
+                    topic_id.as_cow_str()
+                ),
+                &PollMessages {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partition_id,
+                    consumer: consumer.clone(),
+                    strategy: *strategy,
+                    count,
+                    auto_commit,
+                    filter: None,
+                    isolation_level: IsolationLevel::ReadCommitted,
+                    max_wait: IggyDuration::default(),
+                    min_bytes: 0,
+                },
+            )
+            .await?;
+        let messages = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(messages)
+    }
+}
+
+fn get_messages_path(transaction_id: u64, stream_id: &str, topic_id: &str) -> String {
+    format!("{PATH}/{transaction_id}/streams/{stream_id}/topics/{topic_id}/messages")
+}
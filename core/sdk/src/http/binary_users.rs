@@ -23,10 +23,12 @@ use async_trait::async_trait;
 use iggy_binary_protocol::UserClient;
 use iggy_common::change_password::ChangePassword;
 use iggy_common::create_user::CreateUser;
+use iggy_common::import_user::ImportUser;
 use iggy_common::login_user::LoginUser;
+use iggy_common::login_with_token::LoginWithToken;
 use iggy_common::update_permissions::UpdatePermissions;
 use iggy_common::update_user::UpdateUser;
-use iggy_common::{IdentityInfo, Permissions, UserInfo, UserInfoDetails, UserStatus};
+use iggy_common::{IdentityInfo, Permissions, UserInfo, UserInfoDetails, UserQuotas, UserStatus};
 
 const PATH: &str = "/users";
 
@@ -64,6 +66,7 @@ impl UserClient for HttpClient {
         password: &str,
         status: UserStatus,
         permissions: Option<Permissions>,
+        quotas: Option<UserQuotas>,
     ) -> Result<UserInfoDetails, IggyError> {
         let response = self
             .post(
@@ -73,6 +76,34 @@ impl UserClient for HttpClient {
                     password: password.to_string(),
                     status,
                     permissions,
+                    quotas,
+                },
+            )
+            .await?;
+        let user = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(user)
+    }
+
+    async fn import_user(
+        &self,
+        username: &str,
+        password_hash: &str,
+        status: UserStatus,
+        permissions: Option<Permissions>,
+        quotas: Option<UserQuotas>,
+    ) -> Result<UserInfoDetails, IggyError> {
+        let response = self
+            .post(
+                &format!("{PATH}/import"),
+                &ImportUser {
+                    username: username.to_string(),
+                    password_hash: password_hash.to_string(),
+                    status,
+                    permissions,
+                    quotas,
                 },
             )
             .await?;
@@ -94,6 +125,7 @@ impl UserClient for HttpClient {
         user_id: &Identifier,
         username: Option<&str>,
         status: Option<UserStatus>,
+        quotas: Option<UserQuotas>,
     ) -> Result<(), IggyError> {
         self.put(
             &format!("{PATH}/{}", &user_id.as_cow_str()),
@@ -101,6 +133,7 @@ impl UserClient for HttpClient {
                 user_id: user_id.clone(),
                 username: username.map(|s| s.to_string()),
                 status,
+                quotas,
             },
         )
         .await?;
@@ -161,6 +194,23 @@ impl UserClient for HttpClient {
         Ok(identity_info)
     }
 
+    async fn login_with_token(&self, token: &str) -> Result<IdentityInfo, IggyError> {
+        let response = self
+            .post(
+                &format!("{PATH}/login-with-token"),
+                &LoginWithToken {
+                    token: token.to_string(),
+                },
+            )
+            .await?;
+        let identity_info = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        self.set_token_from_identity(&identity_info).await?;
+        Ok(identity_info)
+    }
+
     async fn logout_user(&self) -> Result<(), IggyError> {
         self.delete(&format!("{PATH}/logout")).await?;
         self.set_access_token(None).await;
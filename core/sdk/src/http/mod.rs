@@ -21,10 +21,14 @@ pub mod binary_consumer_offsets;
 pub mod binary_messages;
 pub mod binary_partitions;
 pub mod binary_personal_access_tokens;
+pub mod binary_producers;
+pub mod binary_roles;
+pub mod binary_schemas;
 pub mod binary_segments;
 pub mod binary_streams;
 pub mod binary_system;
 pub mod binary_topics;
+pub mod binary_transactions;
 pub mod binary_users;
 #[allow(deprecated)]
 pub mod http_client;
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+mod client_certificate;
 #[allow(deprecated)]
 pub mod client_provider;
 #[allow(deprecated)]
@@ -24,5 +25,6 @@ pub mod consumer_ext;
 pub mod http;
 pub mod prelude;
 pub mod quic;
+mod server_addresses;
 pub mod stream_builder;
 pub mod tcp;
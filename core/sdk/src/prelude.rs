@@ -32,10 +32,13 @@ pub use crate::client_provider::ClientProviderConfig;
 pub use crate::clients::client::IggyClient;
 pub use crate::clients::client_builder::IggyClientBuilder;
 pub use crate::clients::consumer::{
-    AutoCommit, AutoCommitAfter, AutoCommitWhen, IggyConsumer, ReceivedMessage,
+    AutoCommit, AutoCommitAfter, AutoCommitWhen, ConsumerRebalanceListener,
+    DEAD_LETTER_ATTEMPTS_HEADER, DEAD_LETTER_ERROR_HEADER, DEAD_LETTER_ORIGINAL_OFFSET_HEADER,
+    DEAD_LETTER_ORIGINAL_PARTITION_HEADER, DEAD_LETTER_ORIGINAL_STREAM_HEADER,
+    DEAD_LETTER_ORIGINAL_TOPIC_HEADER, IggyConsumer, ReceivedMessage,
 };
 pub use crate::clients::consumer_builder::IggyConsumerBuilder;
-pub use crate::clients::producer::IggyProducer;
+pub use crate::clients::producer::{IggyProducer, IggyProducerTransaction};
 pub use crate::clients::producer_builder::IggyProducerBuilder;
 pub use crate::consumer_ext::IggyConsumerMessageExt;
 pub use crate::stream_builder::IggyConsumerConfig;
@@ -46,27 +49,35 @@ pub use crate::tcp::tcp_client::TcpClient;
 //
 pub use iggy_binary_protocol::{
     Client, ConsumerGroupClient, ConsumerOffsetClient, MessageClient, PartitionClient,
-    PersonalAccessTokenClient, StreamClient, SystemClient, TopicClient, UserClient,
+    PersonalAccessTokenClient, ProducerClient, RoleClient, SchemaClient, StreamClient,
+    SystemClient, TopicClient, TransactionClient, UserClient,
 };
 //
 pub use iggy_common::{
     Aes256GcmEncryptor, Args, ArgsOptional, AutoLogin, BytesSerializable, CacheMetrics,
-    CacheMetricsKey, ClientError, ClientInfoDetails, CompressionAlgorithm, Confirmation, Consumer,
-    ConsumerGroupDetails, ConsumerKind, EncryptorKind, FlushUnsavedBuffer, GlobalPermissions,
-    HeaderKey, HeaderValue, IdKind, Identifier, IdentityInfo, IggyByteSize, IggyDuration,
-    IggyError, IggyExpiry, IggyIndexView, IggyMessage, IggyMessageHeader, IggyMessageHeaderView,
-    IggyMessageView, IggyMessageViewIterator, IggyTimestamp, MaxTopicSize, Partition, Partitioner,
-    Partitioning, Permissions, PersonalAccessTokenExpiry, PollMessages, PolledMessages,
-    PollingKind, PollingStrategy, SendMessages, Sizeable, SnapshotCompression, Stats, Stream,
-    StreamPermissions, SystemSnapshotType, TcpClientConfig, TcpClientConfigBuilder,
-    TcpClientReconnectionConfig, Topic, TopicPermissions, UserId, UserStatus, Validatable,
-    defaults, locking,
+    CacheMetricsKey, CleanupPolicy, ClientError, ClientInfoDetails, CompressionAlgorithm,
+    Confirmation, Consumer, ConsumerGroupAssignment, ConsumerGroupAssignmentStrategy,
+    ConsumerGroupDetails, ConsumerGroupMetadata, ConsumerGroupOffsetReset, ConsumerKind,
+    ConsumerOffsetInfo, ConsumerOffsetsResetTarget, ConsumerPartitionOffset, EncryptorKind,
+    FlushUnsavedBuffer, GlobalPermissions, HeaderKey, HeaderValue, IdKind, Identifier,
+    IdentityInfo, IggyByteSize, IggyDuration, IggyError, IggyExpiry, IggyIndexView, IggyMessage,
+    IggyMessageHeader, IggyMessageHeaderView, IggyMessageView, IggyMessageViewIterator,
+    IggyTimestamp, IsolationLevel, MaxTopicSize, MessageFilter, MessagesKeyPartitioner,
+    MetadataOperation, Partition, Partitioner, Partitioning, PartitioningHash,
+    PartitioningHashAlgorithm, PartitioningHashMode, Permissions, PersonalAccessTokenExpiry,
+    PersonalAccessTokenMetadata, PollMessages, PolledMessages, PollingKind, PollingStrategy,
+    ProducerInfo, ProducerSequence, ResourcePattern, Role, RoleAction, RoleRule, RuleEffect,
+    Schema, SchemaCompatibility, SchemaType, SendMessages, ServerMetadata, Sizeable,
+    SnapshotCompression, Stats, Stream, StreamMetadata, StreamPermissions, SystemSnapshotType,
+    TcpClientConfig, TcpClientConfigBuilder, TcpClientReconnectionConfig, Topic, TopicMetadata,
+    TopicOptions, TopicPermissions, TransactionInfo, UserId, UserMetadata, UserQuotas, UserStatus,
+    Validatable, defaults, locking,
 };
 pub use iggy_common::{
     IGGY_MESSAGE_CHECKSUM_OFFSET_RANGE, IGGY_MESSAGE_HEADER_SIZE,
     IGGY_MESSAGE_HEADERS_LENGTH_OFFSET_RANGE, IGGY_MESSAGE_ID_OFFSET_RANGE,
     IGGY_MESSAGE_OFFSET_OFFSET_RANGE, IGGY_MESSAGE_ORIGIN_TIMESTAMP_OFFSET_RANGE,
     IGGY_MESSAGE_PAYLOAD_LENGTH_OFFSET_RANGE, IGGY_MESSAGE_TIMESTAMP_OFFSET_RANGE, INDEX_SIZE,
-    MAX_PAYLOAD_SIZE, MAX_USER_HEADERS_SIZE, SEC_IN_MICRO,
+    MAX_PAYLOAD_SIZE, MAX_USER_HEADERS_SIZE, SCHEMA_ID_HEADER, SEC_IN_MICRO,
     defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USER_ID, DEFAULT_ROOT_USERNAME},
 };
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::client_certificate::load_client_certificate;
 use crate::prelude::AutoLogin;
 use iggy_binary_protocol::{
     BinaryClient, BinaryTransport, Client, PersonalAccessTokenClient, UserClient,
@@ -26,6 +27,7 @@ use crate::prelude::IggyError;
 use crate::prelude::IggyTimestamp;
 use crate::quic::quick_config::QuicClientConfig;
 use crate::quic::skip_server_verification::SkipServerVerification;
+use crate::server_addresses::ServerAddresses;
 use async_broadcast::{Receiver, Sender, broadcast};
 use async_trait::async_trait;
 use bytes::Bytes;
@@ -33,6 +35,7 @@ use iggy_common::{ClientState, Command, Credentials, DiagnosticEvent};
 use quinn::crypto::rustls::QuicClientConfig as QuinnQuicClientConfig;
 use quinn::{ClientConfig, Connection, Endpoint, IdleTimeout, RecvStream, VarInt};
 use rustls::crypto::CryptoProvider;
+use rustls_platform_verifier::BuilderVerifierExt;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Duration;
@@ -45,12 +48,13 @@ const RESPONSE_INITIAL_BYTES_LENGTH: usize = 8;
 const NAME: &str = "Iggy";
 
 /// QUIC client for interacting with the Iggy API.
+/// Multiple comma-separated server addresses are failed over in the round-robin order.
 #[derive(Debug)]
 pub struct QuicClient {
     pub(crate) endpoint: Endpoint,
     pub(crate) connection: Mutex<Option<Connection>>,
     pub(crate) config: Arc<QuicClientConfig>,
-    pub(crate) server_address: SocketAddr,
+    pub(crate) server_addresses: ServerAddresses,
     pub(crate) state: Mutex<ClientState>,
     events: (Sender<DiagnosticEvent>, Receiver<DiagnosticEvent>),
     connected_at: Mutex<Option<IggyTimestamp>>,
@@ -125,9 +129,17 @@ impl BinaryTransport for QuicClient {
         }
 
         self.disconnect().await?;
+        if self.server_addresses.len() > 1
+            && matches!(error, IggyError::Disconnected | IggyError::EmptyResponse)
+        {
+            let server_address = self.server_addresses.fail_over();
+            info!("Failing over to the server: {server_address}...");
+        }
+
         info!(
             "Reconnecting to the server: {}, by client: {}",
-            self.config.server_address, self.config.client_address
+            self.server_addresses.current(),
+            self.config.client_address
         );
         self.connect().await?;
         self.send_raw(code, payload).await
@@ -161,13 +173,11 @@ impl QuicClient {
 
     /// Create a new QUIC client for the provided configuration.
     pub fn create(config: Arc<QuicClientConfig>) -> Result<Self, IggyError> {
-        let server_address = config
-            .server_address
-            .parse::<SocketAddr>()
-            .map_err(|error| {
-                error!("Invalid server address: {error}");
-                IggyError::InvalidServerAddress
-            })?;
+        let server_addresses = ServerAddresses::new(&config.server_address);
+        let server_address = server_addresses
+            .iter()
+            .map(parse_server_address)
+            .collect::<Result<Vec<_>, _>>()?[0];
         let client_address = if server_address.is_ipv6()
             && config.client_address == QuicClientConfig::default().client_address
         {
@@ -194,7 +204,7 @@ impl QuicClient {
         Ok(Self {
             config,
             endpoint,
-            server_address,
+            server_addresses,
             connection: Mutex::new(None),
             state: Mutex::new(ClientState::Disconnected),
             events: broadcast(1000),
@@ -278,24 +288,31 @@ impl QuicClient {
         }
 
         let mut retry_count = 0;
+        let mut failed_addresses = 0;
         let connection;
         let remote_address;
         loop {
-            info!(
-                "{NAME} client is connecting to server: {}...",
-                self.config.server_address
-            );
+            let server_address = self.server_addresses.current().to_owned();
+            info!("{NAME} client is connecting to server: {server_address}...");
             let connection_result = self
                 .endpoint
-                .connect(self.server_address, &self.config.server_name)
+                .connect(
+                    parse_server_address(&server_address)?,
+                    &self.config.server_name,
+                )
                 .unwrap()
                 .await;
 
             if connection_result.is_err() {
-                error!(
-                    "Failed to connect to server: {}",
-                    self.config.server_address
-                );
+                error!("Failed to connect to server: {server_address}");
+                failed_addresses += 1;
+                let next_server_address = self.server_addresses.fail_over();
+                if failed_addresses < self.server_addresses.len() {
+                    info!("Failing over to the next server: {next_server_address}...");
+                    continue;
+                }
+
+                failed_addresses = 0;
                 if !self.config.reconnection.enabled {
                     warn!("Automatic reconnection is disabled.");
                     return Err(IggyError::CannotEstablishConnection);
@@ -315,7 +332,7 @@ impl QuicClient {
                     retry_count += 1;
                     info!(
                         "Retrying to connect to server ({retry_count}/{max_retries_str}): {} in: {interval_str}",
-                        self.config.server_address,
+                        self.server_addresses.current(),
                     );
                     sleep(self.config.reconnection.interval.get_duration()).await;
                     continue;
@@ -331,6 +348,7 @@ impl QuicClient {
                 IggyError::CannotEstablishConnection
             })?;
             remote_address = connection.remote_address();
+            self.server_addresses.mark_healthy();
             break;
         }
 
@@ -342,6 +360,12 @@ impl QuicClient {
         self.publish_event(DiagnosticEvent::Connected).await;
 
         match &self.config.auto_login {
+            AutoLogin::Disabled if self.config.client_cert_file.is_some() => {
+                info!("{NAME} client has been authenticated with the client certificate.");
+                self.set_state(ClientState::Authenticated).await;
+                self.publish_event(DiagnosticEvent::SignedIn).await;
+                Ok(())
+            }
             AutoLogin::Disabled => {
                 info!("Automatic sign-in is disabled.");
                 Ok(())
@@ -371,6 +395,14 @@ impl QuicClient {
                         );
                         Ok(())
                     }
+                    Credentials::Token(token) => {
+                        self.login_with_token(token).await?;
+                        info!(
+                            "{NAME} client: {} has signed in with a token.",
+                            self.config.client_address
+                        );
+                        Ok(())
+                    }
                 }
             }
         }
@@ -516,15 +548,29 @@ fn configure(config: &QuicClientConfig) -> Result<ClientConfig, IggyError> {
             );
         }
     }
-    let mut client_config = match config.validate_certificate {
-        true => ClientConfig::with_platform_verifier(),
-        false => {
-            match QuinnQuicClientConfig::try_from(
-                rustls::ClientConfig::builder()
+    let client_certificate = match (&config.client_cert_file, &config.client_key_file) {
+        (Some(cert_file), Some(key_file)) => Some(load_client_certificate(cert_file, key_file)?),
+        _ => None,
+    };
+    let mut client_config = match (config.validate_certificate, client_certificate) {
+        (true, None) => ClientConfig::with_platform_verifier(),
+        (validate_certificate, client_certificate) => {
+            let builder = match validate_certificate {
+                true => rustls::ClientConfig::builder().with_platform_verifier(),
+                false => rustls::ClientConfig::builder()
                     .dangerous()
-                    .with_custom_certificate_verifier(SkipServerVerification::new())
-                    .with_no_client_auth(),
-            ) {
+                    .with_custom_certificate_verifier(SkipServerVerification::new()),
+            };
+            let crypto = match client_certificate {
+                Some((certificates, key)) => builder
+                    .with_client_auth_cert(certificates, key)
+                    .map_err(|error| {
+                        error!("Failed to configure the client certificate. {error}");
+                        IggyError::InvalidTlsCertificate
+                    })?,
+                None => builder.with_no_client_auth(),
+            };
+            match QuinnQuicClientConfig::try_from(crypto) {
                 Ok(config) => ClientConfig::new(Arc::new(config)),
                 Err(error) => {
                     error!("Failed to create QUIC client configuration: {error}");
@@ -536,3 +582,10 @@ fn configure(config: &QuicClientConfig) -> Result<ClientConfig, IggyError> {
     client_config.transport_config(Arc::new(transport));
     Ok(client_config)
 }
+
+fn parse_server_address(server_address: &str) -> Result<SocketAddr, IggyError> {
+    server_address.parse::<SocketAddr>().map_err(|error| {
+        error!("Invalid server address: {server_address}. {error}");
+        IggyError::InvalidServerAddress
+    })
+}
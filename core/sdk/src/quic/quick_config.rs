@@ -18,6 +18,7 @@
 
 use crate::prelude::AutoLogin;
 use crate::prelude::IggyDuration;
+use iggy_common::SERVER_ADDRESSES_SEPARATOR;
 use std::str::FromStr;
 
 /// Configuration for the QUIC client.
@@ -25,7 +26,7 @@ use std::str::FromStr;
 pub struct QuicClientConfig {
     /// The address to bind the QUIC client to.
     pub client_address: String,
-    /// The address of the QUIC server to connect to.
+    /// The address of the QUIC server to connect to, or the comma-separated addresses of the servers to fail over between.
     pub server_address: String,
     /// The server name to use.
     pub server_name: String,
@@ -51,6 +52,10 @@ pub struct QuicClientConfig {
     pub max_idle_timeout: u64,
     /// Whether to validate the server certificate.
     pub validate_certificate: bool,
+    /// The path to the client certificate file, used to authenticate the client (mTLS) instead of the login.
+    pub client_cert_file: Option<String>,
+    /// The path to the client private key file, required along with the client certificate.
+    pub client_key_file: Option<String>,
     /// Interval of heartbeats sent by the client
     pub heartbeat_interval: IggyDuration,
 }
@@ -92,6 +97,8 @@ impl Default for QuicClientConfig {
             keep_alive_interval: 5000,
             max_idle_timeout: 10000,
             validate_certificate: false,
+            client_cert_file: None,
+            client_key_file: None,
         }
     }
 }
@@ -100,7 +107,7 @@ impl Default for QuicClientConfig {
 ///
 /// Allows configuring the QUIC client with custom settings or using defaults:
 /// - `client_address`: Default is "127.0.0.1:0" (binds to any available port).
-/// - `server_address`: Default is "127.0.0.1:8080".
+/// - `server_address`: Default is "127.0.0.1:8080", multiple comma-separated addresses enable failover.
 /// - `server_name`: Default is "localhost".
 /// - `auto_login`: Default is AutoLogin::Disabled.
 /// - `reconnection`: Default is enabled unlimited retries and 1 second interval.
@@ -113,6 +120,8 @@ impl Default for QuicClientConfig {
 /// - `keep_alive_interval`: Default is 5000 milliseconds.
 /// - `max_idle_timeout`: Default is 10,000 milliseconds.
 /// - `validate_certificate`: Default is false (certificate validation is disabled).
+/// - `client_cert_file`: Default is None.
+/// - `client_key_file`: Default is None.
 #[derive(Debug, Default)]
 pub struct QuicClientConfigBuilder {
     config: QuicClientConfig,
@@ -136,6 +145,12 @@ impl QuicClientConfigBuilder {
         self
     }
 
+    /// Sets the addresses of the servers to fail over between, starting with the first one.
+    pub fn with_server_addresses(mut self, server_addresses: &[String]) -> Self {
+        self.config.server_address = server_addresses.join(&SERVER_ADDRESSES_SEPARATOR.to_string());
+        self
+    }
+
     /// Sets the auto sign in during connection.
     pub fn with_auto_sign_in(mut self, auto_sign_in: AutoLogin) -> Self {
         self.config.auto_login = auto_sign_in;
@@ -219,6 +234,13 @@ impl QuicClientConfigBuilder {
         self
     }
 
+    /// Sets the paths to the client certificate and private key files (mTLS).
+    pub fn with_client_certificate(mut self, cert_file: String, key_file: String) -> Self {
+        self.config.client_cert_file = Some(cert_file);
+        self.config.client_key_file = Some(key_file);
+        self
+    }
+
     /// Sets the heartbeat interval. Defaults to 5000ms.
     pub fn with_heartbeat_interval(mut self, interval: IggyDuration) -> Self {
         self.config.heartbeat_interval = interval;
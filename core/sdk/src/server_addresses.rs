@@ -0,0 +1,128 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy_common::split_server_addresses;
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+
+/// The addresses of the servers the client fails over between, with the health of each address
+/// tracked as the number of consecutive failed connection attempts.
+#[derive(Debug)]
+pub(crate) struct ServerAddresses {
+    addresses: Vec<ServerAddress>,
+    current: AtomicUsize,
+}
+
+#[derive(Debug)]
+struct ServerAddress {
+    address: String,
+    failures: AtomicU32,
+}
+
+impl ServerAddresses {
+    /// Creates the addresses from the comma-separated `server_address`, starting with the first one.
+    pub(crate) fn new(server_address: &str) -> Self {
+        let mut addresses = split_server_addresses(server_address);
+        if addresses.is_empty() {
+            addresses.push(server_address);
+        }
+
+        Self {
+            addresses: addresses
+                .into_iter()
+                .map(|address| ServerAddress {
+                    address: address.to_owned(),
+                    failures: AtomicU32::new(0),
+                })
+                .collect(),
+            current: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the address of the server the client is (or will be) connected to.
+    pub(crate) fn current(&self) -> &str {
+        &self.addresses[self.current.load(Ordering::Acquire)].address
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.addresses.len()
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &str> {
+        self.addresses
+            .iter()
+            .map(|address| address.address.as_str())
+    }
+
+    /// Resets the failures of the current address after establishing the connection.
+    pub(crate) fn mark_healthy(&self) {
+        self.addresses[self.current.load(Ordering::Acquire)]
+            .failures
+            .store(0, Ordering::Release);
+    }
+
+    /// Marks the current address as failed and switches to the next one in the round-robin order,
+    /// skipping the addresses with more consecutive failures than the healthiest one.
+    pub(crate) fn fail_over(&self) -> &str {
+        let current = self.current.load(Ordering::Acquire);
+        self.addresses[current]
+            .failures
+            .fetch_add(1, Ordering::AcqRel);
+
+        let count = self.addresses.len();
+        let next = (1..=count)
+            .map(|offset| (current + offset) % count)
+            .min_by_key(|index| self.addresses[*index].failures.load(Ordering::Acquire))
+            .unwrap_or(current);
+        self.current.store(next, Ordering::Release);
+        &self.addresses[next].address
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_address_should_be_kept_on_fail_over() {
+        let addresses = ServerAddresses::new("127.0.0.1:8090");
+        assert_eq!(addresses.len(), 1);
+        assert_eq!(addresses.fail_over(), "127.0.0.1:8090");
+        assert_eq!(addresses.current(), "127.0.0.1:8090");
+    }
+
+    #[test]
+    fn fail_over_should_round_robin_across_addresses() {
+        let addresses = ServerAddresses::new("10.0.0.1:8090, 10.0.0.2:8090,10.0.0.3:8090");
+        assert_eq!(addresses.len(), 3);
+        assert_eq!(addresses.current(), "10.0.0.1:8090");
+        assert_eq!(addresses.fail_over(), "10.0.0.2:8090");
+        assert_eq!(addresses.fail_over(), "10.0.0.3:8090");
+        assert_eq!(addresses.fail_over(), "10.0.0.1:8090");
+        assert_eq!(addresses.fail_over(), "10.0.0.2:8090");
+    }
+
+    #[test]
+    fn fail_over_should_prefer_healthy_addresses() {
+        let addresses = ServerAddresses::new("10.0.0.1:8090,10.0.0.2:8090,10.0.0.3:8090");
+        assert_eq!(addresses.fail_over(), "10.0.0.2:8090");
+        addresses.mark_healthy();
+        addresses.current.store(2, Ordering::Release);
+        // 10.0.0.1 has failed once, so the healthy 10.0.0.2 is picked first.
+        assert_eq!(addresses.fail_over(), "10.0.0.2:8090");
+    }
+}
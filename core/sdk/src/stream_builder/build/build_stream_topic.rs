@@ -18,7 +18,7 @@
 
 use crate::prelude::{
     CompressionAlgorithm, IdKind, Identifier, IggyClient, IggyError, IggyExpiry, MaxTopicSize,
-    StreamClient, TopicClient,
+    StreamClient, TopicClient, TopicOptions,
 };
 
 use crate::stream_builder::IggyConsumerConfig;
@@ -97,6 +97,7 @@ pub(crate) async fn build_iggy_stream_topic_if_not_exists(
                 id,
                 IggyExpiry::ServerDefault,
                 MaxTopicSize::ServerDefault,
+                TopicOptions::default(),
             )
             .await?;
     }
@@ -17,6 +17,8 @@
  */
 
 pub(crate) mod tcp_client;
+pub(crate) mod tcp_connection;
+pub(crate) mod tcp_connection_pool;
 pub(crate) mod tcp_connection_stream;
 pub(crate) mod tcp_connection_stream_kind;
 pub(crate) mod tcp_stream;
@@ -16,18 +16,23 @@
  * under the License.
  */
 
+use crate::client_certificate::load_client_certificate;
 use crate::prelude::Client;
 use crate::prelude::TcpClientConfig;
+use crate::server_addresses::ServerAddresses;
+use crate::tcp::tcp_connection::TcpConnection;
+use crate::tcp::tcp_connection_pool::TcpConnectionPool;
 use crate::tcp::tcp_connection_stream::TcpConnectionStream;
 use crate::tcp::tcp_connection_stream_kind::ConnectionStreamKind;
 use crate::tcp::tcp_tls_connection_stream::TcpTlsConnectionStream;
 use async_broadcast::{Receiver, Sender, broadcast};
 use async_trait::async_trait;
-use bytes::{BufMut, Bytes, BytesMut};
+use bytes::Bytes;
 use iggy_binary_protocol::{BinaryClient, BinaryTransport, PersonalAccessTokenClient, UserClient};
 use iggy_common::{
     AutoLogin, ClientState, Command, ConnectionString, Credentials, DiagnosticEvent, IggyDuration,
-    IggyError, IggyErrorDiscriminants, IggyTimestamp,
+    IggyError, IggyTimestamp, LOGIN_USER_CODE, LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE,
+    LOGIN_WITH_TOKEN_CODE, LOGOUT_USER_CODE, PING_CODE,
 };
 use rustls::pki_types::{CertificateDer, ServerName, pem::PemObject};
 use std::net::SocketAddr;
@@ -39,17 +44,20 @@ use tokio::time::sleep;
 use tokio_rustls::{TlsConnector, TlsStream};
 use tracing::{error, info, trace, warn};
 
-const REQUEST_INITIAL_BYTES_LENGTH: usize = 4;
-const RESPONSE_INITIAL_BYTES_LENGTH: usize = 8;
 const NAME: &str = "Iggy";
 
 /// TCP client for interacting with the Iggy API.
-/// It requires a valid server address.
+/// It requires a valid server address, multiple comma-separated addresses are failed over in the round-robin order.
+/// The requests can be pipelined on the connection and the messages can be sent and polled using the connection pool.
+/// The server answers the requests sent using a connection in order, so the long polls without consumer group
+/// use their own connections and don't delay the other requests, while the ones by a consumer group do.
 #[derive(Debug)]
 pub struct TcpClient {
-    pub(crate) stream: Mutex<Option<ConnectionStreamKind>>,
+    pub(crate) connection: Mutex<Option<Arc<TcpConnection>>>,
+    pool: TcpConnectionPool,
     pub(crate) config: Arc<TcpClientConfig>,
     pub(crate) state: Mutex<ClientState>,
+    server_addresses: ServerAddresses,
     client_address: Mutex<Option<SocketAddr>>,
     events: (Sender<DiagnosticEvent>, Receiver<DiagnosticEvent>),
     connected_at: Mutex<Option<IggyTimestamp>>,
@@ -104,38 +112,19 @@ impl BinaryTransport for TcpClient {
     }
 
     async fn send_raw_with_response(&self, code: u32, payload: Bytes) -> Result<Bytes, IggyError> {
-        let result = self.send_raw(code, payload.clone()).await;
-        if result.is_ok() {
+        if let Some(result) = self.send_long_poll(code, &payload).await {
             return result;
         }
 
-        let error = result.unwrap_err();
-        if !matches!(
-            error,
-            IggyError::Disconnected
-                | IggyError::EmptyResponse
-                | IggyError::Unauthenticated
-                | IggyError::StaleClient
-        ) {
-            return Err(error);
-        }
-
-        if !self.config.reconnection.enabled {
-            return Err(IggyError::Disconnected);
-        }
-
-        self.disconnect().await?;
-
-        {
-            let client_address = self.get_client_address_value().await;
-            info!(
-                "Reconnecting to the server: {} by client: {client_address}...",
-                self.config.server_address
-            );
+        if let Some(result) = self.send_pooled(code, &payload).await {
+            return result;
         }
 
-        self.connect().await?;
-        self.send_raw(code, payload).await
+        let response = self
+            .send_raw_with_reconnection(code, payload.clone())
+            .await?;
+        self.sync_pool(code, payload).await;
+        Ok(response)
     }
 
     fn get_heartbeat_interval(&self) -> IggyDuration {
@@ -186,57 +175,289 @@ impl TcpClient {
     /// Create a new TCP client based on the provided configuration.
     pub fn create(config: Arc<TcpClientConfig>) -> Result<Self, IggyError> {
         Ok(Self {
-            config,
+            server_addresses: ServerAddresses::new(&config.server_address),
             client_address: Mutex::new(None),
-            stream: Mutex::new(None),
+            connection: Mutex::new(None),
+            pool: TcpConnectionPool::new(config.connection_pool_size),
+            config,
             state: Mutex::new(ClientState::Disconnected),
             events: broadcast(1000),
             connected_at: Mutex::new(None),
         })
     }
 
-    async fn handle_response(
+    async fn send_raw_with_reconnection(
         &self,
-        status: u32,
-        length: u32,
-        stream: &mut ConnectionStreamKind,
+        code: u32,
+        payload: Bytes,
     ) -> Result<Bytes, IggyError> {
-        if status != 0 {
-            // TEMP: See https://github.com/apache/iggy/pull/604 for context.
-            if status == IggyErrorDiscriminants::TopicIdAlreadyExists as u32
-                || status == IggyErrorDiscriminants::TopicNameAlreadyExists as u32
-                || status == IggyErrorDiscriminants::StreamIdAlreadyExists as u32
-                || status == IggyErrorDiscriminants::StreamNameAlreadyExists as u32
-                || status == IggyErrorDiscriminants::UserAlreadyExists as u32
-                || status == IggyErrorDiscriminants::PersonalAccessTokenAlreadyExists as u32
-                || status == IggyErrorDiscriminants::ConsumerGroupIdAlreadyExists as u32
-                || status == IggyErrorDiscriminants::ConsumerGroupNameAlreadyExists as u32
+        let connection = self.connection.lock().await.clone();
+        let result = self.send_raw(code, payload.clone()).await;
+        if result.is_ok() {
+            return result;
+        }
+
+        let error = result.unwrap_err();
+        if !matches!(
+            error,
+            IggyError::Disconnected
+                | IggyError::EmptyResponse
+                | IggyError::Unauthenticated
+                | IggyError::StaleClient
+        ) {
+            return Err(error);
+        }
+
+        if !self.config.reconnection.enabled {
+            return Err(IggyError::Disconnected);
+        }
+
+        // The pipelined requests failing at once reconnect only if no other request has done it already.
+        let reconnected = match (&connection, self.connection.lock().await.as_ref()) {
+            (Some(failed), Some(current)) => !Arc::ptr_eq(failed, current),
+            _ => false,
+        };
+        if !reconnected {
+            self.disconnect().await?;
+
+            if self.server_addresses.len() > 1
+                && matches!(error, IggyError::Disconnected | IggyError::EmptyResponse)
             {
-                tracing::debug!(
-                    "Received a server resource already exists response: {} ({})",
-                    status,
-                    IggyError::from_code_as_string(status)
-                )
-            } else {
-                error!(
-                    "Received an invalid response with status: {} ({}).",
-                    status,
-                    IggyError::from_code_as_string(status),
+                let server_address = self.server_addresses.fail_over();
+                info!("Failing over to the server: {server_address}...");
+            }
+
+            let client_address = self.get_client_address_value().await;
+            info!(
+                "Reconnecting to the server: {} by client: {client_address}...",
+                self.server_addresses.current()
+            );
+        }
+
+        self.connect().await?;
+        self.send_raw(code, payload).await
+    }
+
+    /// Sends the request using the connection pool, unless it's disabled or the request must be sent
+    /// using the primary connection, in which case `None` is returned.
+    async fn send_pooled(&self, code: u32, payload: &Bytes) -> Option<Result<Bytes, IggyError>> {
+        if !self.pool.is_enabled() || !TcpConnectionPool::is_pooled_request(code, payload) {
+            return None;
+        }
+
+        if self.get_state().await != ClientState::Authenticated {
+            return None;
+        }
+
+        let (index, connection) = match self.pool.get(|| self.open_pooled_connection()).await {
+            Ok(connection) => connection,
+            Err(error) => {
+                warn!("Failed to open the pooled TCP connection: {error}, using the primary one.");
+                return None;
+            }
+        };
+
+        match connection.send(code, payload).await {
+            Err(
+                error @ (IggyError::Disconnected
+                | IggyError::EmptyResponse
+                | IggyError::TcpError
+                | IggyError::Unauthenticated
+                | IggyError::StaleClient),
+            ) => {
+                warn!(
+                    "Pooled TCP connection: {} has failed: {error}, using the primary one.",
+                    connection.client_address()
                 );
+                self.pool.discard(index, &connection).await;
+                None
             }
+            result => Some(result),
+        }
+    }
 
-            return Err(IggyError::from_code(status));
+    /// Sends the long poll using a connection held until its response, so that it doesn't delay the other requests.
+    /// Returns `None` if the request isn't the long poll or it must be sent using the primary connection.
+    async fn send_long_poll(&self, code: u32, payload: &Bytes) -> Option<Result<Bytes, IggyError>> {
+        if !TcpConnectionPool::is_long_poll(code, payload) {
+            return None;
         }
 
-        trace!("Status: OK. Response length: {}", length);
-        if length <= 1 {
-            return Ok(Bytes::new());
+        if self.get_state().await != ClientState::Authenticated {
+            return None;
         }
 
-        let mut response_buffer = BytesMut::with_capacity(length as usize);
-        response_buffer.put_bytes(0, length as usize);
-        stream.read(&mut response_buffer).await?;
-        Ok(response_buffer.freeze())
+        let connection = match self
+            .pool
+            .acquire_long_poll_connection(|| self.open_pooled_connection())
+            .await
+        {
+            Ok(connection) => connection,
+            Err(error) => {
+                warn!(
+                    "Failed to open the TCP connection for the long poll: {error}, using the primary one."
+                );
+                return None;
+            }
+        };
+
+        match connection.send(code, payload).await {
+            Err(
+                error @ (IggyError::Disconnected
+                | IggyError::EmptyResponse
+                | IggyError::TcpError
+                | IggyError::Unauthenticated
+                | IggyError::StaleClient),
+            ) => {
+                warn!(
+                    "TCP connection: {} of the long poll has failed: {error}, using the primary one.",
+                    connection.client_address()
+                );
+                None
+            }
+            result => {
+                self.pool.release_long_poll_connection(connection);
+                Some(result)
+            }
+        }
+    }
+
+    async fn open_pooled_connection(&self) -> Result<TcpConnection, IggyError> {
+        let server_address = self.server_addresses.current().to_owned();
+        let stream = TcpStream::connect(&server_address).await.map_err(|error| {
+            error!("Failed to connect to server: {server_address}. Error: {error}");
+            IggyError::CannotEstablishConnection
+        })?;
+        let connection = self.establish_connection(stream).await?;
+        if let Some((code, payload)) = self.pool.login_request() {
+            connection.send(code, &payload).await?;
+        }
+
+        info!(
+            "{NAME} client: {} has opened the pooled connection to server: {server_address}",
+            connection.client_address()
+        );
+        Ok(connection)
+    }
+
+    /// Keeps the pooled connections signed in as the same user and alive along with the primary connection.
+    async fn sync_pool(&self, code: u32, payload: Bytes) {
+        match code {
+            LOGIN_USER_CODE | LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE | LOGIN_WITH_TOKEN_CODE => {
+                self.pool.set_login_request(Some((code, payload)));
+                self.pool.clear().await;
+            }
+            LOGOUT_USER_CODE => {
+                self.pool.set_login_request(None);
+                self.pool.clear().await;
+            }
+            PING_CODE => {
+                for (index, connection) in self.pool.open_connections().await {
+                    if let Err(error) = connection.send(PING_CODE, &payload).await {
+                        warn!(
+                            "Failed to ping using the pooled TCP connection: {}. {error}",
+                            connection.client_address()
+                        );
+                        self.pool.discard(index, &connection).await;
+                    }
+                }
+                for connection in self.pool.idle_long_poll_connections() {
+                    if let Err(error) = connection.send(PING_CODE, &payload).await {
+                        warn!(
+                            "Failed to ping using the TCP connection of the long polls: {}. {error}",
+                            connection.client_address()
+                        );
+                        self.pool.discard_long_poll_connection(&connection);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    async fn establish_connection(&self, stream: TcpStream) -> Result<TcpConnection, IggyError> {
+        let client_address = stream.local_addr().map_err(|error| {
+            error!("Failed to get the local address of the client: {error}",);
+            IggyError::CannotEstablishConnection
+        })?;
+        let remote_address = stream.peer_addr().map_err(|error| {
+            error!("Failed to get the remote address of the server: {error}",);
+            IggyError::CannotEstablishConnection
+        })?;
+
+        if let Err(e) = stream.set_nodelay(self.config.nodelay) {
+            error!("Failed to set the nodelay option on the client: {e}, continuing...",);
+        }
+
+        let pipelining = self.config.pipelining;
+        if !self.config.tls_enabled {
+            let stream =
+                ConnectionStreamKind::Tcp(TcpConnectionStream::new(client_address, stream));
+            return Ok(TcpConnection::new(
+                stream,
+                client_address,
+                remote_address,
+                pipelining,
+            ));
+        }
+
+        let mut root_cert_store = rustls::RootCertStore::empty();
+        if let Some(certificate_path) = &self.config.tls_ca_file {
+            for cert in CertificateDer::pem_file_iter(certificate_path).map_err(|error| {
+                error!("Failed to read the CA file: {certificate_path}. {error}",);
+                IggyError::InvalidTlsCertificatePath
+            })? {
+                let certificate = cert.map_err(|error| {
+                    error!(
+                        "Failed to read a certificate from the CA file: {certificate_path}. {error}",
+                    );
+                    IggyError::InvalidTlsCertificate
+                })?;
+                root_cert_store.add(certificate).map_err(|error| {
+                    error!("Failed to add a certificate to the root certificate store. {error}",);
+                    IggyError::InvalidTlsCertificate
+                })?;
+            }
+        } else {
+            root_cert_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+        }
+
+        let config = rustls::ClientConfig::builder().with_root_certificates(root_cert_store);
+        let config = match (
+            &self.config.tls_client_cert_file,
+            &self.config.tls_client_key_file,
+        ) {
+            (Some(cert_file), Some(key_file)) => {
+                let (certificates, key) = load_client_certificate(cert_file, key_file)?;
+                config
+                    .with_client_auth_cert(certificates, key)
+                    .map_err(|error| {
+                        error!("Failed to configure the client certificate. {error}");
+                        IggyError::InvalidTlsCertificate
+                    })?
+            }
+            _ => config.with_no_client_auth(),
+        };
+        let connector = TlsConnector::from(Arc::new(config));
+        let tls_domain = self.config.tls_domain.to_owned();
+        let domain = ServerName::try_from(tls_domain).map_err(|error| {
+            error!("Failed to create a server name from the domain. {error}",);
+            IggyError::InvalidTlsDomain
+        })?;
+        let stream = connector.connect(domain, stream).await.map_err(|error| {
+            error!("Failed to establish a TLS connection to the server: {error}",);
+            IggyError::CannotEstablishConnection
+        })?;
+        let stream = ConnectionStreamKind::TcpTls(TcpTlsConnectionStream::new(
+            client_address,
+            TlsStream::Client(stream),
+        ));
+        Ok(TcpConnection::new(
+            stream,
+            client_address,
+            remote_address,
+            pipelining,
+        ))
     }
 
     async fn connect(&self) -> Result<(), IggyError> {
@@ -275,21 +496,24 @@ impl TcpClient {
 
         let tls_enabled = self.config.tls_enabled;
         let mut retry_count = 0;
-        let connection_stream: ConnectionStreamKind;
+        let mut failed_addresses = 0;
         let remote_address;
         let client_address;
-        loop {
-            info!(
-                "{NAME} client is connecting to server: {}...",
-                self.config.server_address
-            );
+        let connection = loop {
+            let server_address = self.server_addresses.current().to_owned();
+            info!("{NAME} client is connecting to server: {server_address}...");
 
-            let connection = TcpStream::connect(&self.config.server_address).await;
+            let connection = TcpStream::connect(&server_address).await;
             if let Err(err) = &connection {
-                error!(
-                    "Failed to connect to server: {}. Error: {}",
-                    self.config.server_address, err
-                );
+                error!("Failed to connect to server: {server_address}. Error: {err}");
+                failed_addresses += 1;
+                let next_server_address = self.server_addresses.fail_over();
+                if failed_addresses < self.server_addresses.len() {
+                    info!("Failing over to the next server: {next_server_address}...");
+                    continue;
+                }
+
+                failed_addresses = 0;
                 if !self.config.reconnection.enabled {
                     warn!("Automatic reconnection is disabled.");
                     return Err(IggyError::CannotEstablishConnection);
@@ -309,7 +533,7 @@ impl TcpClient {
                     retry_count += 1;
                     info!(
                         "Retrying to connect to server ({retry_count}/{max_retries_str}): {} in: {interval_str}",
-                        self.config.server_address,
+                        self.server_addresses.current(),
                     );
                     sleep(self.config.reconnection.interval.get_duration()).await;
                     continue;
@@ -324,82 +548,31 @@ impl TcpClient {
                 error!("Failed to establish TCP connection to the server: {error}",);
                 IggyError::CannotEstablishConnection
             })?;
-            client_address = stream.local_addr().map_err(|error| {
-                error!("Failed to get the local address of the client: {error}",);
-                IggyError::CannotEstablishConnection
-            })?;
-            remote_address = stream.peer_addr().map_err(|error| {
-                error!("Failed to get the remote address of the server: {error}",);
-                IggyError::CannotEstablishConnection
-            })?;
+            let connection = self.establish_connection(stream).await?;
+            client_address = connection.client_address();
+            remote_address = connection.remote_address();
             self.client_address.lock().await.replace(client_address);
-
-            if let Err(e) = stream.set_nodelay(self.config.nodelay) {
-                error!("Failed to set the nodelay option on the client: {e}, continuing...",);
-            }
-
-            if !tls_enabled {
-                connection_stream =
-                    ConnectionStreamKind::Tcp(TcpConnectionStream::new(client_address, stream));
-                break;
-            }
-
-            let mut root_cert_store = rustls::RootCertStore::empty();
-            if let Some(certificate_path) = &self.config.tls_ca_file {
-                for cert in CertificateDer::pem_file_iter(certificate_path).map_err(|error| {
-                    error!("Failed to read the CA file: {certificate_path}. {error}",);
-                    IggyError::InvalidTlsCertificatePath
-                })? {
-                    let certificate = cert.map_err(|error| {
-                        error!(
-                            "Failed to read a certificate from the CA file: {certificate_path}. {error}",
-                        );
-                        IggyError::InvalidTlsCertificate
-                    })?;
-                    root_cert_store.add(certificate).map_err(|error| {
-                        error!(
-                            "Failed to add a certificate to the root certificate store. {error}",
-                        );
-                        IggyError::InvalidTlsCertificate
-                    })?;
-                }
-            } else {
-                root_cert_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
-            }
-
-            let config = rustls::ClientConfig::builder()
-                .with_root_certificates(root_cert_store)
-                .with_no_client_auth();
-            let connector = TlsConnector::from(Arc::new(config));
-            let stream = TcpStream::connect(client_address).await.map_err(|error| {
-                error!("Failed to establish TCP connection to the server: {error}",);
-                IggyError::CannotEstablishConnection
-            })?;
-            let tls_domain = self.config.tls_domain.to_owned();
-            let domain = ServerName::try_from(tls_domain).map_err(|error| {
-                error!("Failed to create a server name from the domain. {error}",);
-                IggyError::InvalidTlsDomain
-            })?;
-            let stream = connector.connect(domain, stream).await.map_err(|error| {
-                error!("Failed to establish a TLS connection to the server: {error}",);
-                IggyError::CannotEstablishConnection
-            })?;
-            connection_stream = ConnectionStreamKind::TcpTls(TcpTlsConnectionStream::new(
-                client_address,
-                TlsStream::Client(stream),
-            ));
-            break;
-        }
+            self.server_addresses.mark_healthy();
+            break connection;
+        };
 
         let now = IggyTimestamp::now();
         info!(
             "{NAME} client: {client_address} has connected to server: {remote_address} at: {now}",
         );
-        self.stream.lock().await.replace(connection_stream);
+        self.connection.lock().await.replace(Arc::new(connection));
         self.set_state(ClientState::Connected).await;
         self.connected_at.lock().await.replace(now);
         self.publish_event(DiagnosticEvent::Connected).await;
         match &self.config.auto_login {
+            AutoLogin::Disabled if tls_enabled && self.config.tls_client_cert_file.is_some() => {
+                info!(
+                    "{NAME} client: {client_address} has been authenticated with the client certificate."
+                );
+                self.set_state(ClientState::Authenticated).await;
+                self.publish_event(DiagnosticEvent::SignedIn).await;
+                Ok(())
+            }
             AutoLogin::Disabled => {
                 info!("Automatic sign-in is disabled.");
                 Ok(())
@@ -422,6 +595,11 @@ impl TcpClient {
                         );
                         Ok(())
                     }
+                    Credentials::Token(token) => {
+                        self.login_with_token(token).await?;
+                        info!("{NAME} client: {client_address} has signed in with a token.");
+                        Ok(())
+                    }
                 }
             }
         }
@@ -435,7 +613,8 @@ impl TcpClient {
         let client_address = self.get_client_address_value().await;
         info!("{NAME} client: {client_address} is disconnecting from server...");
         self.set_state(ClientState::Disconnected).await;
-        self.stream.lock().await.take();
+        self.connection.lock().await.take();
+        self.pool.clear().await;
         self.publish_event(DiagnosticEvent::Disconnected).await;
         let now = IggyTimestamp::now();
         info!("{NAME} client: {client_address} has disconnected from server at: {now}.");
@@ -449,10 +628,11 @@ impl TcpClient {
 
         let client_address = self.get_client_address_value().await;
         info!("Shutting down the {NAME} TCP client: {client_address}");
-        let stream = self.stream.lock().await.take();
-        if let Some(mut stream) = stream {
-            stream.shutdown().await?;
+        let connection = self.connection.lock().await.take();
+        if let Some(connection) = connection {
+            connection.shutdown().await?;
         }
+        self.pool.clear().await;
         self.set_state(ClientState::Shutdown).await;
         self.publish_event(DiagnosticEvent::Shutdown).await;
         info!("{NAME} TCP client: {client_address} has been shutdown.");
@@ -476,41 +656,9 @@ impl TcpClient {
             _ => {}
         }
 
-        let mut stream = self.stream.lock().await;
-        if let Some(stream) = stream.as_mut() {
-            let payload_length = payload.len() + REQUEST_INITIAL_BYTES_LENGTH;
-            trace!("Sending a TCP request of size {payload_length} with code: {code}");
-            stream.write(&(payload_length as u32).to_le_bytes()).await?;
-            stream.write(&code.to_le_bytes()).await?;
-            stream.write(&payload).await?;
-            stream.flush().await?;
-            trace!("Sent a TCP request with code: {code}, waiting for a response...");
-            let mut response_buffer = [0u8; RESPONSE_INITIAL_BYTES_LENGTH];
-            let read_bytes = stream.read(&mut response_buffer).await.map_err(|error| {
-                error!(
-                    "Failed to read response for TCP request with code: {code}: {error}",
-                    code = code,
-                    error = error
-                );
-                IggyError::Disconnected
-            })?;
-
-            if read_bytes != RESPONSE_INITIAL_BYTES_LENGTH {
-                error!("Received an invalid or empty response.");
-                return Err(IggyError::EmptyResponse);
-            }
-
-            let status = u32::from_le_bytes(
-                response_buffer[..4]
-                    .try_into()
-                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
-            );
-            let length = u32::from_le_bytes(
-                response_buffer[4..]
-                    .try_into()
-                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
-            );
-            return self.handle_response(status, length, stream).await;
+        let connection = self.connection.lock().await.clone();
+        if let Some(connection) = connection {
+            return connection.send(code, &payload).await;
         }
 
         error!("Cannot send data. Client is not connected.");
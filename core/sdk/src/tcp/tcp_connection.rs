@@ -0,0 +1,335 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::tcp::tcp_connection_stream_kind::ConnectionStreamKind;
+use bytes::{BufMut, Bytes, BytesMut};
+use iggy_common::{CORRELATION_ID_FLAG, IggyError, IggyErrorDiscriminants};
+use std::collections::{HashMap, HashSet};
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use tokio::sync::{Mutex, Notify};
+use tracing::{debug, error, trace};
+
+const REQUEST_INITIAL_BYTES_LENGTH: usize = 4;
+const RESPONSE_INITIAL_BYTES_LENGTH: usize = 8;
+const CORRELATION_ID_BYTES_LENGTH: usize = 4;
+
+type Response = Result<Bytes, IggyError>;
+
+/// The connection to the server, on which the requests are either sent one by one, or pipelined
+/// with the correlation IDs, so that multiple requests can be in flight at the same time.
+///
+/// The pipelined requests are written without waiting for the responses to the previous ones,
+/// and whichever request holds the reader reads the next response, completing the other request
+/// if it's not its own, so that the responses are received in the order they're sent by the server.
+#[derive(Debug)]
+pub(crate) struct TcpConnection {
+    stream: ConnectionStreamKind,
+    client_address: SocketAddr,
+    remote_address: SocketAddr,
+    pipelining: bool,
+    broken: AtomicBool,
+    next_correlation_id: AtomicU32,
+    reader: Mutex<()>,
+    in_flight: std::sync::Mutex<InFlightRequests>,
+    completed: Notify,
+}
+
+#[derive(Debug, Default)]
+struct InFlightRequests {
+    pending: HashSet<u32>,
+    completed: HashMap<u32, Response>,
+}
+
+impl TcpConnection {
+    pub fn new(
+        stream: ConnectionStreamKind,
+        client_address: SocketAddr,
+        remote_address: SocketAddr,
+        pipelining: bool,
+    ) -> Self {
+        Self {
+            stream,
+            client_address,
+            remote_address,
+            pipelining,
+            broken: AtomicBool::new(false),
+            next_correlation_id: AtomicU32::new(0),
+            reader: Mutex::new(()),
+            in_flight: std::sync::Mutex::new(InFlightRequests::default()),
+            completed: Notify::new(),
+        }
+    }
+
+    pub fn client_address(&self) -> SocketAddr {
+        self.client_address
+    }
+
+    pub fn remote_address(&self) -> SocketAddr {
+        self.remote_address
+    }
+
+    /// Returns true if reading or writing failed (or was cancelled) and the connection can't be used anymore.
+    pub fn is_broken(&self) -> bool {
+        self.broken.load(Ordering::Acquire)
+    }
+
+    /// Sends the request and waits for its response.
+    pub async fn send(&self, code: u32, payload: &Bytes) -> Result<Bytes, IggyError> {
+        if self.is_broken() {
+            return Err(IggyError::Disconnected);
+        }
+
+        if !self.pipelining {
+            let _reader = self.reader.lock().await;
+            self.write_request(code, None, payload).await?;
+            let (_, response) = self.read_response(code, false).await?;
+            return response;
+        }
+
+        let correlation_id = self.next_correlation_id.fetch_add(1, Ordering::Relaxed);
+        let request = InFlightRequest::new(self, correlation_id);
+        self.write_request(code, Some(correlation_id), payload)
+            .await?;
+        trace!(
+            "Sent a TCP request with correlation ID: {correlation_id}, waiting for a response..."
+        );
+        loop {
+            let completed = self.completed.notified();
+            tokio::pin!(completed);
+            completed.as_mut().enable();
+            if let Some(response) = request.take_completed() {
+                return response;
+            }
+
+            tokio::select! {
+                _reader = self.reader.lock() => {
+                    if let Some(response) = request.take_completed() {
+                        return response;
+                    }
+
+                    let (response_correlation_id, response) = self.read_response(code, true).await?;
+                    if response_correlation_id == correlation_id {
+                        return response;
+                    }
+
+                    self.complete(response_correlation_id, response);
+                }
+                _ = &mut completed => {}
+            }
+        }
+    }
+
+    pub async fn shutdown(&self) -> Result<(), IggyError> {
+        self.stream.shutdown().await
+    }
+
+    async fn write_request(
+        &self,
+        code: u32,
+        correlation_id: Option<u32>,
+        payload: &Bytes,
+    ) -> Result<(), IggyError> {
+        let mut header =
+            BytesMut::with_capacity(REQUEST_INITIAL_BYTES_LENGTH * 2 + CORRELATION_ID_BYTES_LENGTH);
+        match correlation_id {
+            Some(correlation_id) => {
+                let length =
+                    payload.len() + REQUEST_INITIAL_BYTES_LENGTH + CORRELATION_ID_BYTES_LENGTH;
+                header.put_u32_le(length as u32);
+                header.put_u32_le(code | CORRELATION_ID_FLAG);
+                header.put_u32_le(correlation_id);
+            }
+            None => {
+                let length = payload.len() + REQUEST_INITIAL_BYTES_LENGTH;
+                header.put_u32_le(length as u32);
+                header.put_u32_le(code);
+            }
+        }
+
+        trace!(
+            "Sending a TCP request of size {} with code: {code}",
+            header.len() + payload.len() - REQUEST_INITIAL_BYTES_LENGTH
+        );
+        let guard = BrokenOnDrop::new(&self.broken);
+        self.stream.write(&[&header, payload]).await?;
+        guard.disarm();
+        Ok(())
+    }
+
+    async fn read_response(
+        &self,
+        code: u32,
+        correlated: bool,
+    ) -> Result<(u32, Response), IggyError> {
+        let guard = BrokenOnDrop::new(&self.broken);
+        let mut correlation_id = 0;
+        if correlated {
+            let mut correlation_id_buffer = [0u8; CORRELATION_ID_BYTES_LENGTH];
+            self.read_response_header(code, &mut correlation_id_buffer)
+                .await?;
+            correlation_id = u32::from_le_bytes(correlation_id_buffer);
+        }
+
+        let mut response_buffer = [0u8; RESPONSE_INITIAL_BYTES_LENGTH];
+        self.read_response_header(code, &mut response_buffer)
+            .await?;
+        let status = u32::from_le_bytes(
+            response_buffer[..4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let length = u32::from_le_bytes(
+            response_buffer[4..]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let response = self.handle_response(status, length).await?;
+        guard.disarm();
+        Ok((correlation_id, response))
+    }
+
+    async fn read_response_header(&self, code: u32, buffer: &mut [u8]) -> Result<(), IggyError> {
+        let read_bytes = self.stream.read(buffer).await.map_err(|error| {
+            error!("Failed to read response for TCP request with code: {code}: {error}");
+            IggyError::Disconnected
+        })?;
+
+        if read_bytes != buffer.len() {
+            error!("Received an invalid or empty response.");
+            return Err(IggyError::EmptyResponse);
+        }
+
+        Ok(())
+    }
+
+    async fn handle_response(&self, status: u32, length: u32) -> Result<Response, IggyError> {
+        if status != 0 {
+            // TEMP: See https://github.com/apache/iggy/pull/604 for context.
+            if status == IggyErrorDiscriminants::TopicIdAlreadyExists as u32
+                || status == IggyErrorDiscriminants::TopicNameAlreadyExists as u32
+                || status == IggyErrorDiscriminants::StreamIdAlreadyExists as u32
+                || status == IggyErrorDiscriminants::StreamNameAlreadyExists as u32
+                || status == IggyErrorDiscriminants::UserAlreadyExists as u32
+                || status == IggyErrorDiscriminants::PersonalAccessTokenAlreadyExists as u32
+                || status == IggyErrorDiscriminants::ConsumerGroupIdAlreadyExists as u32
+                || status == IggyErrorDiscriminants::ConsumerGroupNameAlreadyExists as u32
+            {
+                debug!(
+                    "Received a server resource already exists response: {} ({})",
+                    status,
+                    IggyError::from_code_as_string(status)
+                )
+            } else {
+                error!(
+                    "Received an invalid response with status: {} ({}).",
+                    status,
+                    IggyError::from_code_as_string(status),
+                );
+            }
+
+            return Ok(Err(IggyError::from_code(status)));
+        }
+
+        trace!("Status: OK. Response length: {}", length);
+        if length <= 1 {
+            return Ok(Ok(Bytes::new()));
+        }
+
+        let mut response_buffer = BytesMut::with_capacity(length as usize);
+        response_buffer.put_bytes(0, length as usize);
+        self.stream.read(&mut response_buffer).await?;
+        Ok(Ok(response_buffer.freeze()))
+    }
+
+    /// Stores the response read by another request, unless its request is not waiting for it anymore.
+    fn complete(&self, correlation_id: u32, response: Response) {
+        let mut in_flight = self.in_flight.lock().unwrap();
+        if in_flight.pending.contains(&correlation_id) {
+            in_flight.completed.insert(correlation_id, response);
+            self.completed.notify_waiters();
+        } else {
+            debug!("Discarded the TCP response with unknown correlation ID: {correlation_id}");
+        }
+    }
+}
+
+/// The pipelined request awaiting its response, which is discarded if the request gets cancelled.
+struct InFlightRequest<'a> {
+    connection: &'a TcpConnection,
+    correlation_id: u32,
+}
+
+impl<'a> InFlightRequest<'a> {
+    fn new(connection: &'a TcpConnection, correlation_id: u32) -> Self {
+        connection
+            .in_flight
+            .lock()
+            .unwrap()
+            .pending
+            .insert(correlation_id);
+        Self {
+            connection,
+            correlation_id,
+        }
+    }
+
+    fn take_completed(&self) -> Option<Response> {
+        self.connection
+            .in_flight
+            .lock()
+            .unwrap()
+            .completed
+            .remove(&self.correlation_id)
+    }
+}
+
+impl Drop for InFlightRequest<'_> {
+    fn drop(&mut self) {
+        let mut in_flight = self.connection.in_flight.lock().unwrap();
+        in_flight.pending.remove(&self.correlation_id);
+        in_flight.completed.remove(&self.correlation_id);
+    }
+}
+
+/// Marks the connection as broken, unless disarmed once the partial write or read has completed.
+struct BrokenOnDrop<'a> {
+    broken: &'a AtomicBool,
+    armed: bool,
+}
+
+impl<'a> BrokenOnDrop<'a> {
+    fn new(broken: &'a AtomicBool) -> Self {
+        Self {
+            broken,
+            armed: true,
+        }
+    }
+
+    fn disarm(mut self) {
+        self.armed = false;
+    }
+}
+
+impl Drop for BrokenOnDrop<'_> {
+    fn drop(&mut self) {
+        if self.armed {
+            self.broken.store(true, Ordering::Release);
+        }
+    }
+}
@@ -0,0 +1,256 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::tcp::tcp_connection::TcpConnection;
+use bytes::Bytes;
+use iggy_common::{
+    BytesSerializable, ConsumerKind, IggyError, POLL_MESSAGES_CODE, PollMessages,
+    SEND_MESSAGES_CODE,
+};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio::sync::Mutex;
+use tracing::warn;
+
+/// The additional connections to the server used to send and poll the messages, so that the concurrent
+/// producers and consumers sharing the client don't contend for a single connection.
+///
+/// The connections are opened on the first use and signed in by replaying the login request of the client.
+/// All the other requests, including the ones bound to the session (e.g. polling the messages by a consumer group),
+/// are sent using the primary connection.
+///
+/// The server handles the requests sent using a single connection in order, except for the pipelined polls and queries,
+/// so a slow request delays all the requests sent after it without pipelining. That's why the long polls without
+/// consumer group use the dedicated connections, each held by a single long poll until its response, even if the pool
+/// is disabled. The long polls by a consumer group are bound to the session and can't leave the primary connection,
+/// so they delay the other requests of the client, unless the pipelining is enabled.
+#[derive(Debug)]
+pub(crate) struct TcpConnectionPool {
+    connections: Vec<Mutex<Option<Arc<TcpConnection>>>>,
+    next: AtomicUsize,
+    long_poll_connections: std::sync::Mutex<Vec<Arc<TcpConnection>>>,
+    max_idle_long_poll_connections: usize,
+    login_request: std::sync::Mutex<Option<(u32, Bytes)>>,
+}
+
+impl TcpConnectionPool {
+    /// Creates the pool of the connections in addition to the primary one.
+    pub fn new(connection_pool_size: u32) -> Self {
+        let size = connection_pool_size.saturating_sub(1) as usize;
+        Self {
+            connections: (0..size).map(|_| Mutex::new(None)).collect(),
+            next: AtomicUsize::new(0),
+            long_poll_connections: std::sync::Mutex::new(Vec::new()),
+            max_idle_long_poll_connections: size.max(1),
+            login_request: std::sync::Mutex::new(None),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.connections.is_empty()
+    }
+
+    /// Returns true if the request is not bound to the session and can be sent using any connection.
+    pub fn is_pooled_request(code: u32, payload: &Bytes) -> bool {
+        match code {
+            SEND_MESSAGES_CODE => true,
+            POLL_MESSAGES_CODE => payload.first() == Some(&ConsumerKind::Consumer.as_code()),
+            _ => false,
+        }
+    }
+
+    /// Returns true if the request is the long poll, which isn't bound to the session.
+    pub fn is_long_poll(code: u32, payload: &Bytes) -> bool {
+        code == POLL_MESSAGES_CODE
+            && Self::is_pooled_request(code, payload)
+            && PollMessages::from_bytes(payload.clone())
+                .is_ok_and(|command| command.max_wait.as_micros() != 0)
+    }
+
+    pub fn login_request(&self) -> Option<(u32, Bytes)> {
+        self.login_request.lock().unwrap().clone()
+    }
+
+    pub fn set_login_request(&self, login_request: Option<(u32, Bytes)>) {
+        *self.login_request.lock().unwrap() = login_request;
+    }
+
+    /// Returns the next connection in the round-robin order, opening it if needed.
+    pub async fn get<F, Fut>(&self, open: F) -> Result<(usize, Arc<TcpConnection>), IggyError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<TcpConnection, IggyError>>,
+    {
+        let index = self.next.fetch_add(1, Ordering::Relaxed) % self.connections.len();
+        let mut connection = self.connections[index].lock().await;
+        if let Some(connection) = connection.as_ref().filter(|c| !c.is_broken()) {
+            return Ok((index, connection.clone()));
+        }
+
+        let opened = Arc::new(open().await?);
+        connection.replace(opened.clone());
+        Ok((index, opened))
+    }
+
+    /// Returns the idle connection for the long poll, opening a new one if there's none.
+    /// The connection should be released once the response has been received.
+    pub async fn acquire_long_poll_connection<F, Fut>(
+        &self,
+        open: F,
+    ) -> Result<Arc<TcpConnection>, IggyError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<TcpConnection, IggyError>>,
+    {
+        let idle_connection = {
+            let mut connections = self.long_poll_connections.lock().unwrap();
+            connections.retain(|connection| !connection.is_broken());
+            connections.pop()
+        };
+        match idle_connection {
+            Some(connection) => Ok(connection),
+            None => Ok(Arc::new(open().await?)),
+        }
+    }
+
+    /// Keeps the connection of the completed long poll for the next ones, unless there are enough idle ones.
+    pub fn release_long_poll_connection(&self, connection: Arc<TcpConnection>) {
+        let mut connections = self.long_poll_connections.lock().unwrap();
+        if !connection.is_broken() && connections.len() < self.max_idle_long_poll_connections {
+            connections.push(connection);
+        }
+    }
+
+    /// Returns the currently idle connections of the long polls.
+    pub fn idle_long_poll_connections(&self) -> Vec<Arc<TcpConnection>> {
+        self.long_poll_connections.lock().unwrap().clone()
+    }
+
+    /// Removes the failed connection of the long polls, if it's idle.
+    pub fn discard_long_poll_connection(&self, failed: &Arc<TcpConnection>) {
+        self.long_poll_connections
+            .lock()
+            .unwrap()
+            .retain(|connection| !Arc::ptr_eq(connection, failed));
+    }
+
+    /// Returns the currently open connections.
+    pub async fn open_connections(&self) -> Vec<(usize, Arc<TcpConnection>)> {
+        let mut connections = Vec::new();
+        for (index, connection) in self.connections.iter().enumerate() {
+            if let Some(connection) = connection.lock().await.as_ref() {
+                connections.push((index, connection.clone()));
+            }
+        }
+        connections
+    }
+
+    /// Removes the failed connection, so that a new one is opened on the next use.
+    pub async fn discard(&self, index: usize, failed: &Arc<TcpConnection>) {
+        let mut connection = self.connections[index].lock().await;
+        if connection
+            .as_ref()
+            .is_some_and(|connection| Arc::ptr_eq(connection, failed))
+        {
+            connection.take();
+        }
+    }
+
+    /// Closes all the connections, e.g. after disconnecting or signing out the client.
+    pub async fn clear(&self) {
+        let mut connections = std::mem::take(&mut *self.long_poll_connections.lock().unwrap());
+        for connection in &self.connections {
+            if let Some(connection) = connection.lock().await.take() {
+                connections.push(connection);
+            }
+        }
+
+        for connection in connections {
+            if let Err(error) = connection.shutdown().await {
+                warn!(
+                    "Failed to shutdown the pooled TCP connection: {}. {error}",
+                    connection.client_address()
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy_common::{Consumer, Identifier, IggyDuration, LOGIN_USER_CODE};
+    use std::str::FromStr;
+
+    #[test]
+    fn only_sending_and_polling_messages_without_consumer_group_should_be_pooled() {
+        let poll_messages = |consumer: Consumer| {
+            PollMessages {
+                consumer,
+                ..PollMessages::default()
+            }
+            .to_bytes()
+        };
+
+        assert!(TcpConnectionPool::is_pooled_request(
+            SEND_MESSAGES_CODE,
+            &Bytes::new()
+        ));
+        assert!(TcpConnectionPool::is_pooled_request(
+            POLL_MESSAGES_CODE,
+            &poll_messages(Consumer::default())
+        ));
+        assert!(!TcpConnectionPool::is_pooled_request(
+            POLL_MESSAGES_CODE,
+            &poll_messages(Consumer::group(Identifier::numeric(1).unwrap()))
+        ));
+        assert!(!TcpConnectionPool::is_pooled_request(
+            LOGIN_USER_CODE,
+            &Bytes::new()
+        ));
+    }
+
+    #[test]
+    fn only_polling_messages_with_max_wait_without_consumer_group_should_be_long_poll() {
+        let poll_messages = |consumer: Consumer, max_wait: &str| {
+            PollMessages {
+                consumer,
+                max_wait: IggyDuration::from_str(max_wait).unwrap(),
+                ..PollMessages::default()
+            }
+            .to_bytes()
+        };
+
+        assert!(TcpConnectionPool::is_long_poll(
+            POLL_MESSAGES_CODE,
+            &poll_messages(Consumer::default(), "500ms")
+        ));
+        assert!(!TcpConnectionPool::is_long_poll(
+            POLL_MESSAGES_CODE,
+            &poll_messages(Consumer::default(), "0")
+        ));
+        assert!(!TcpConnectionPool::is_long_poll(
+            POLL_MESSAGES_CODE,
+            &poll_messages(Consumer::group(Identifier::numeric(1).unwrap()), "500ms")
+        ));
+        assert!(!TcpConnectionPool::is_long_poll(
+            SEND_MESSAGES_CODE,
+            &Bytes::new()
+        ));
+    }
+}
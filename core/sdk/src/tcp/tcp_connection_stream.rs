@@ -23,13 +23,14 @@ use std::net::SocketAddr;
 use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader, BufWriter};
 use tokio::net::TcpStream;
 use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::sync::Mutex;
 use tracing::error;
 
 #[derive(Debug)]
 pub struct TcpConnectionStream {
     client_address: SocketAddr,
-    reader: BufReader<OwnedReadHalf>,
-    writer: BufWriter<OwnedWriteHalf>,
+    reader: Mutex<BufReader<OwnedReadHalf>>,
+    writer: Mutex<BufWriter<OwnedWriteHalf>>,
 }
 
 impl TcpConnectionStream {
@@ -37,36 +38,42 @@ impl TcpConnectionStream {
         let (reader, writer) = stream.into_split();
         Self {
             client_address,
-            reader: BufReader::new(reader),
-            writer: BufWriter::new(writer),
+            reader: Mutex::new(BufReader::new(reader)),
+            writer: Mutex::new(BufWriter::new(writer)),
         }
     }
 }
 
 #[async_trait]
 impl ConnectionStream for TcpConnectionStream {
-    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, IggyError> {
-        self.reader.read_exact(buf).await.map_err(|error| {
-            error!(
-                "Failed to read data by client: {} from the TCP connection: {error}",
-                self.client_address
-            );
-            IggyError::TcpError
-        })
+    async fn read(&self, buf: &mut [u8]) -> Result<usize, IggyError> {
+        self.reader
+            .lock()
+            .await
+            .read_exact(buf)
+            .await
+            .map_err(|error| {
+                error!(
+                    "Failed to read data by client: {} from the TCP connection: {error}",
+                    self.client_address
+                );
+                IggyError::TcpError
+            })
     }
 
-    async fn write(&mut self, buf: &[u8]) -> Result<(), IggyError> {
-        self.writer.write_all(buf).await.map_err(|error| {
-            error!(
-                "Failed to write data by client: {} to the TCP connection: {error}",
-                self.client_address
-            );
-            IggyError::TcpError
-        })
-    }
+    async fn write(&self, bufs: &[&[u8]]) -> Result<(), IggyError> {
+        let mut writer = self.writer.lock().await;
+        for buf in bufs {
+            writer.write_all(buf).await.map_err(|error| {
+                error!(
+                    "Failed to write data by client: {} to the TCP connection: {error}",
+                    self.client_address
+                );
+                IggyError::TcpError
+            })?;
+        }
 
-    async fn flush(&mut self) -> Result<(), IggyError> {
-        self.writer.flush().await.map_err(|error| {
+        writer.flush().await.map_err(|error| {
             error!(
                 "Failed to flush data by client: {} to the TCP connection: {error}",
                 self.client_address
@@ -75,8 +82,8 @@ impl ConnectionStream for TcpConnectionStream {
         })
     }
 
-    async fn shutdown(&mut self) -> Result<(), IggyError> {
-        self.writer.shutdown().await.map_err(|error| {
+    async fn shutdown(&self) -> Result<(), IggyError> {
+        self.writer.lock().await.shutdown().await.map_err(|error| {
             error!(
                 "Failed to shutdown the TCP connection by client: {} to the TCP connection: {error}",
                 self.client_address
@@ -28,28 +28,21 @@ pub(crate) enum ConnectionStreamKind {
 }
 
 impl ConnectionStreamKind {
-    pub async fn read(&mut self, buf: &mut [u8]) -> Result<usize, IggyError> {
+    pub async fn read(&self, buf: &mut [u8]) -> Result<usize, IggyError> {
         match self {
             Self::Tcp(c) => c.read(buf).await,
             Self::TcpTls(c) => c.read(buf).await,
         }
     }
 
-    pub async fn write(&mut self, buf: &[u8]) -> Result<(), IggyError> {
+    pub async fn write(&self, bufs: &[&[u8]]) -> Result<(), IggyError> {
         match self {
-            Self::Tcp(c) => c.write(buf).await,
-            Self::TcpTls(c) => c.write(buf).await,
+            Self::Tcp(c) => c.write(bufs).await,
+            Self::TcpTls(c) => c.write(bufs).await,
         }
     }
 
-    pub async fn flush(&mut self) -> Result<(), IggyError> {
-        match self {
-            Self::Tcp(c) => c.flush().await,
-            Self::TcpTls(c) => c.flush().await,
-        }
-    }
-
-    pub async fn shutdown(&mut self) -> Result<(), IggyError> {
+    pub async fn shutdown(&self) -> Result<(), IggyError> {
         match self {
             Self::Tcp(c) => c.shutdown().await,
             Self::TcpTls(c) => c.shutdown().await,
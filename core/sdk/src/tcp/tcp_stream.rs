@@ -19,10 +19,12 @@
 use async_trait::async_trait;
 use iggy_common::IggyError;
 
+/// The connection stream with the separate read and write halves, so that the requests can be written
+/// while the responses to the previous ones are still being read.
 #[async_trait]
 pub trait ConnectionStream {
-    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, IggyError>;
-    async fn write(&mut self, buf: &[u8]) -> Result<(), IggyError>;
-    async fn flush(&mut self) -> Result<(), IggyError>;
-    async fn shutdown(&mut self) -> Result<(), IggyError>;
+    async fn read(&self, buf: &mut [u8]) -> Result<usize, IggyError>;
+    /// Writes and flushes all the buffers at once, so that the concurrent requests are never interleaved.
+    async fn write(&self, bufs: &[&[u8]]) -> Result<(), IggyError>;
+    async fn shutdown(&self) -> Result<(), IggyError>;
 }
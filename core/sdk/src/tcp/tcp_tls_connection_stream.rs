@@ -20,50 +20,60 @@ use crate::tcp::tcp_stream::ConnectionStream;
 use async_trait::async_trait;
 use iggy_common::IggyError;
 use std::net::SocketAddr;
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::io::{AsyncReadExt, AsyncWriteExt, ReadHalf, WriteHalf};
 use tokio::net::TcpStream;
+use tokio::sync::Mutex;
 use tokio_rustls::TlsStream;
 use tracing::error;
 
 #[derive(Debug)]
 pub struct TcpTlsConnectionStream {
     client_address: SocketAddr,
-    stream: TlsStream<TcpStream>,
+    reader: Mutex<ReadHalf<TlsStream<TcpStream>>>,
+    writer: Mutex<WriteHalf<TlsStream<TcpStream>>>,
 }
 
 impl TcpTlsConnectionStream {
     pub fn new(client_address: SocketAddr, stream: TlsStream<TcpStream>) -> Self {
+        let (reader, writer) = tokio::io::split(stream);
         Self {
             client_address,
-            stream,
+            reader: Mutex::new(reader),
+            writer: Mutex::new(writer),
         }
     }
 }
 
 #[async_trait]
 impl ConnectionStream for TcpTlsConnectionStream {
-    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, IggyError> {
-        self.stream.read(buf).await.map_err(|error| {
-            error!(
-                "Failed to read data by client: {} from the TCP TLS connection: {error}",
-                self.client_address
-            );
-            IggyError::TcpError
-        })
+    async fn read(&self, buf: &mut [u8]) -> Result<usize, IggyError> {
+        self.reader
+            .lock()
+            .await
+            .read_exact(buf)
+            .await
+            .map_err(|error| {
+                error!(
+                    "Failed to read data by client: {} from the TCP TLS connection: {error}",
+                    self.client_address
+                );
+                IggyError::TcpError
+            })
     }
 
-    async fn write(&mut self, buf: &[u8]) -> Result<(), IggyError> {
-        self.stream.write_all(buf).await.map_err(|error| {
-            error!(
-                "Failed to write data by client: {} to the TCP TLS connection: {error}",
-                self.client_address
-            );
-            IggyError::TcpError
-        })
-    }
+    async fn write(&self, bufs: &[&[u8]]) -> Result<(), IggyError> {
+        let mut writer = self.writer.lock().await;
+        for buf in bufs {
+            writer.write_all(buf).await.map_err(|error| {
+                error!(
+                    "Failed to write data by client: {} to the TCP TLS connection: {error}",
+                    self.client_address
+                );
+                IggyError::TcpError
+            })?;
+        }
 
-    async fn flush(&mut self) -> Result<(), IggyError> {
-        self.stream.flush().await.map_err(|error| {
+        writer.flush().await.map_err(|error| {
             error!(
                 "Failed to flush data by client: {} to the TCP TLS connection: {error}",
                 self.client_address
@@ -72,8 +82,8 @@ impl ConnectionStream for TcpTlsConnectionStream {
         })
     }
 
-    async fn shutdown(&mut self) -> Result<(), IggyError> {
-        self.stream.shutdown().await.map_err(|error| {
+    async fn shutdown(&self) -> Result<(), IggyError> {
+        self.writer.lock().await.shutdown().await.map_err(|error| {
             error!(
                 "Failed to shutdown the TCP TLS connection by client: {} to the TCP TLS connection: {error}",
                 self.client_address
@@ -20,7 +20,7 @@ use crate::archiver::{Archiver, COMPONENT};
 use crate::configs::server::DiskArchiverConfig;
 use crate::server_error::ArchiverError;
 use error_set::ErrContext;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tokio::fs;
 use tracing::{debug, info};
 
@@ -33,6 +33,14 @@ impl DiskArchiver {
     pub fn new(config: DiskArchiverConfig) -> Self {
         DiskArchiver { config }
     }
+
+    /// Returns the path of the archived file, which is always placed within the archive directory,
+    /// even if the original file path is absolute.
+    fn get_archived_file_path(&self, file: &str, base_directory: Option<&str>) -> PathBuf {
+        Path::new(&self.config.path)
+            .join(base_directory.unwrap_or_default())
+            .join(file.trim_start_matches('/'))
+    }
 }
 
 impl Archiver for DiskArchiver {
@@ -57,8 +65,7 @@ impl Archiver for DiskArchiver {
         base_directory: Option<String>,
     ) -> Result<bool, ArchiverError> {
         debug!("Checking if file: {file} is archived on disk.");
-        let base_directory = base_directory.as_deref().unwrap_or_default();
-        let path = Path::new(&self.config.path).join(base_directory).join(file);
+        let path = self.get_archived_file_path(file, base_directory.as_deref());
         let is_archived = path.exists();
         debug!("File: {file} is archived: {is_archived}");
         Ok(is_archived)
@@ -79,8 +86,7 @@ impl Archiver for DiskArchiver {
                 });
             }
 
-            let base_directory = base_directory.as_deref().unwrap_or_default();
-            let destination = Path::new(&self.config.path).join(base_directory).join(file);
+            let destination = self.get_archived_file_path(file, base_directory.as_deref());
             let destination_path = destination.to_str().unwrap_or_default().to_owned();
             fs::create_dir_all(destination.parent().unwrap())
                 .await
@@ -95,4 +101,53 @@ impl Archiver for DiskArchiver {
 
         Ok(())
     }
+
+    async fn fetch(
+        &self,
+        file: &str,
+        destination: &str,
+        base_directory: Option<String>,
+    ) -> Result<(), ArchiverError> {
+        debug!("Fetching file: {file} from disk to: {destination}");
+        let source = self.get_archived_file_path(file, base_directory.as_deref());
+        if !source.exists() {
+            return Err(ArchiverError::ArchivedFileNotFound {
+                file_path: file.to_string(),
+            });
+        }
+
+        let destination = Path::new(destination);
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent).await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to create directory for fetched file: {file}")
+            })?;
+        }
+        fs::copy(&source, destination).await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to copy archived file: {file} to destination: {destination:?}")
+        })?;
+        debug!("Fetched file: {file} from disk to: {destination:?}");
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        files: &[&str],
+        base_directory: Option<String>,
+    ) -> Result<(), ArchiverError> {
+        debug!("Deleting archived files on disk: {:?}", files);
+        for file in files {
+            let path = self.get_archived_file_path(file, base_directory.as_deref());
+            if !path.exists() {
+                debug!("Archived file: {file} does not exist on disk, skipping.");
+                continue;
+            }
+
+            fs::remove_file(&path).await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to delete archived file: {file}")
+            })?;
+            debug!("Deleted archived file: {file}");
+        }
+
+        Ok(())
+    }
 }
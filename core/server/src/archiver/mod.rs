@@ -65,6 +65,17 @@ pub trait Archiver: Send {
         files: &[&str],
         base_directory: Option<String>,
     ) -> impl Future<Output = Result<(), ArchiverError>> + Send;
+    fn fetch(
+        &self,
+        file: &str,
+        destination: &str,
+        base_directory: Option<String>,
+    ) -> impl Future<Output = Result<(), ArchiverError>> + Send;
+    fn delete(
+        &self,
+        files: &[&str],
+        base_directory: Option<String>,
+    ) -> impl Future<Output = Result<(), ArchiverError>> + Send;
 }
 
 #[derive(Debug)]
@@ -112,4 +123,27 @@ impl ArchiverKind {
             Self::S3(d) => d.archive(files, base_directory).await,
         }
     }
+
+    pub async fn fetch(
+        &self,
+        file: &str,
+        destination: &str,
+        base_directory: Option<String>,
+    ) -> Result<(), ArchiverError> {
+        match self {
+            Self::Disk(d) => d.fetch(file, destination, base_directory).await,
+            Self::S3(d) => d.fetch(file, destination, base_directory).await,
+        }
+    }
+
+    pub async fn delete(
+        &self,
+        files: &[&str],
+        base_directory: Option<String>,
+    ) -> Result<(), ArchiverError> {
+        match self {
+            Self::Disk(d) => d.delete(files, base_directory).await,
+            Self::S3(d) => d.delete(files, base_directory).await,
+        }
+    }
 }
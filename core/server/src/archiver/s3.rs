@@ -194,4 +194,91 @@ impl Archiver for S3Archiver {
         }
         Ok(())
     }
+
+    async fn fetch(
+        &self,
+        file: &str,
+        destination: &str,
+        base_directory: Option<String>,
+    ) -> Result<(), ArchiverError> {
+        debug!("Fetching file: {file} from S3 to: {destination}");
+        let base_directory = base_directory.as_deref().unwrap_or_default();
+        let source = Path::new(&base_directory).join(file);
+        let source_path = source.to_str().unwrap_or_default().to_owned();
+        let destination = Path::new(destination);
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent).await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to create directory for fetched file: {file}")
+            })?;
+        }
+
+        let mut output = fs::File::create(destination).await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to create file: {destination:?} for fetched file: {file}")
+        })?;
+        let status = match self
+            .bucket
+            .get_object_to_writer(source_path, &mut output)
+            .await
+        {
+            Ok(status) => status,
+            Err(error) => {
+                error!("Cannot fetch file: {file} from S3: {error}");
+                drop(output);
+                fs::remove_file(destination).await?;
+                return Err(ArchiverError::CannotFetchArchivedFile {
+                    file_path: file.to_string(),
+                });
+            }
+        };
+
+        if status == 200 {
+            debug!("Fetched file: {file} from S3 to: {destination:?}");
+            return Ok(());
+        }
+
+        error!("Cannot fetch file: {file} from S3, received an invalid status code: {status}.");
+        drop(output);
+        fs::remove_file(destination).await?;
+        if status == 404 {
+            return Err(ArchiverError::ArchivedFileNotFound {
+                file_path: file.to_string(),
+            });
+        }
+
+        Err(ArchiverError::CannotFetchArchivedFile {
+            file_path: file.to_string(),
+        })
+    }
+
+    async fn delete(
+        &self,
+        files: &[&str],
+        base_directory: Option<String>,
+    ) -> Result<(), ArchiverError> {
+        let base_directory = base_directory.as_deref().unwrap_or_default();
+        for file in files {
+            debug!("Deleting archived file: {file} on S3.");
+            let destination = Path::new(&base_directory).join(file);
+            let destination_path = destination.to_str().unwrap_or_default().to_owned();
+            let response = self.bucket.delete_object(destination_path).await;
+            if let Err(error) = response {
+                error!("Cannot delete archived file: {file} on S3: {error}");
+                return Err(ArchiverError::CannotDeleteArchivedFile {
+                    file_path: file.to_string(),
+                });
+            }
+
+            let status = response.unwrap().status_code();
+            if status != 200 && status != 204 {
+                error!(
+                    "Cannot delete archived file: {file} on S3, received an invalid status code: {status}."
+                );
+                return Err(ArchiverError::CannotDeleteArchivedFile {
+                    file_path: file.to_string(),
+                });
+            }
+            debug!("Deleted archived file: {file} on S3.");
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,163 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::audit::AuditTarget;
+use crate::binary::command::ServerCommand;
+
+impl ServerCommand {
+    /// Returns the target of the command if it's audited, which covers the administrative
+    /// and authentication commands, or `None` otherwise.
+    pub fn audit_target(&self) -> Option<AuditTarget> {
+        let target = AuditTarget::default();
+        let target = match self {
+            ServerCommand::CreateStream(command) => target
+                .with_optional("stream_id", command.stream_id)
+                .with("name", &command.name),
+            ServerCommand::UpdateStream(command) => target
+                .with("stream_id", &command.stream_id)
+                .with("name", &command.name),
+            ServerCommand::DeleteStream(command) => target.with("stream_id", &command.stream_id),
+            ServerCommand::PurgeStream(command) => target.with("stream_id", &command.stream_id),
+            ServerCommand::CreateTopic(command) => target
+                .with("stream_id", &command.stream_id)
+                .with_optional("topic_id", command.topic_id)
+                .with("partitions_count", command.partitions_count)
+                .with("name", &command.name),
+            ServerCommand::UpdateTopic(command) => target
+                .with("stream_id", &command.stream_id)
+                .with("topic_id", &command.topic_id)
+                .with("name", &command.name),
+            ServerCommand::DeleteTopic(command) => target
+                .with("stream_id", &command.stream_id)
+                .with("topic_id", &command.topic_id),
+            ServerCommand::PurgeTopic(command) => target
+                .with("stream_id", &command.stream_id)
+                .with("topic_id", &command.topic_id),
+            ServerCommand::CreatePartitions(command) => target
+                .with("stream_id", &command.stream_id)
+                .with("topic_id", &command.topic_id)
+                .with("partitions_count", command.partitions_count),
+            ServerCommand::DeletePartitions(command) => target
+                .with("stream_id", &command.stream_id)
+                .with("topic_id", &command.topic_id)
+                .with("partitions_count", command.partitions_count),
+            ServerCommand::CreateConsumerGroup(command) => target
+                .with("stream_id", &command.stream_id)
+                .with("topic_id", &command.topic_id)
+                .with_optional("group_id", command.group_id)
+                .with("name", &command.name),
+            ServerCommand::DeleteConsumerGroup(command) => target
+                .with("stream_id", &command.stream_id)
+                .with("topic_id", &command.topic_id)
+                .with("group_id", &command.group_id),
+            ServerCommand::RegisterSchema(command) => target
+                .with("stream_id", &command.stream_id)
+                .with("topic_id", &command.topic_id)
+                .with("schema_type", command.schema_type)
+                .with("compatibility", command.compatibility),
+            ServerCommand::DeleteSchema(command) => target.with("schema_id", command.schema_id),
+            ServerCommand::CreateUser(command) => target.with("username", &command.username),
+            ServerCommand::ImportUser(command) => target.with("username", &command.username),
+            ServerCommand::UpdateUser(command) => target
+                .with("user_id", &command.user_id)
+                .with_optional("username", command.username.as_ref()),
+            ServerCommand::DeleteUser(command) => target.with("user_id", &command.user_id),
+            ServerCommand::UpdatePermissions(command) => target.with("user_id", &command.user_id),
+            ServerCommand::ChangePassword(command) => target.with("user_id", &command.user_id),
+            ServerCommand::CreateRole(command) => target.with("name", &command.name),
+            ServerCommand::UpdateRole(command) => target.with("name", &command.name),
+            ServerCommand::DeleteRole(command) => target.with("name", &command.name),
+            ServerCommand::AssignRoles(command) => target
+                .with("user_id", &command.user_id)
+                .with("roles", command.roles.join(",")),
+            ServerCommand::LoginUser(command) => target.with("username", &command.username),
+            ServerCommand::LogoutUser(_) => target,
+            ServerCommand::LoginWithToken(_) => target,
+            ServerCommand::CreatePersonalAccessToken(command) => target.with("name", &command.name),
+            ServerCommand::DeletePersonalAccessToken(command) => target.with("name", &command.name),
+            ServerCommand::LoginWithPersonalAccessToken(_) => target,
+            _ => return None,
+        };
+        Some(target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy_common::Identifier;
+    use iggy_common::assign_roles::AssignRoles;
+    use iggy_common::delete_schema::DeleteSchema;
+    use iggy_common::delete_topic::DeleteTopic;
+    use iggy_common::login_user::LoginUser;
+    use iggy_common::ping::Ping;
+    use iggy_common::register_schema::RegisterSchema;
+    use iggy_common::{SchemaCompatibility, SchemaType};
+
+    #[test]
+    fn should_return_target_of_audited_commands_only() {
+        let command = ServerCommand::DeleteTopic(DeleteTopic {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("orders").unwrap(),
+        });
+        assert_eq!(
+            command.audit_target().unwrap().to_string(),
+            "stream_id: 1, topic_id: orders"
+        );
+
+        let command = ServerCommand::LoginUser(LoginUser {
+            username: "user".to_string(),
+            password: "secret".to_string(),
+            version: None,
+            context: None,
+        });
+        assert_eq!(
+            command.audit_target().unwrap().to_string(),
+            "username: user"
+        );
+
+        let command = ServerCommand::AssignRoles(AssignRoles {
+            user_id: Identifier::numeric(2).unwrap(),
+            roles: vec!["tenant".to_string(), "auditor".to_string()],
+        });
+        assert_eq!(
+            command.audit_target().unwrap().to_string(),
+            "user_id: 2, roles: tenant,auditor"
+        );
+
+        assert!(ServerCommand::Ping(Ping {}).audit_target().is_none());
+    }
+
+    #[test]
+    fn should_return_target_of_schema_commands() {
+        let command = ServerCommand::RegisterSchema(RegisterSchema {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("orders").unwrap(),
+            schema_type: SchemaType::Avro,
+            compatibility: SchemaCompatibility::Backward,
+            definition: Default::default(),
+        });
+        assert_eq!(
+            command.audit_target().unwrap().to_string(),
+            "stream_id: 1, topic_id: orders, schema_type: avro, compatibility: backward"
+        );
+
+        let command = ServerCommand::DeleteSchema(DeleteSchema { schema_id: 3 });
+        assert_eq!(command.audit_target().unwrap().to_string(), "schema_id: 3");
+    }
+}
@@ -0,0 +1,492 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::audit::{AuditEvent, COMPONENT};
+use crate::configs::system::SystemConfig;
+use crate::streaming::persistence::persister::PersisterKind;
+use crate::streaming::utils::file;
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use error_set::ErrContext;
+use iggy_common::{
+    AuditRecord, IggyByteSize, IggyError, IggyExpiry, IggyTimestamp, get_name_from_code,
+};
+use std::collections::VecDeque;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::{debug, error, info, warn};
+
+/// Append-only, hash-chained log of the audited commands.
+/// The records within the retention are kept in memory to be queried, while each new record is appended to the file.
+#[derive(Debug)]
+pub struct AuditLog {
+    enabled: bool,
+    path: String,
+    retention: IggyExpiry,
+    persister: Arc<PersisterKind>,
+    records: Mutex<AuditRecords>,
+}
+
+#[derive(Debug, Default)]
+struct AuditRecords {
+    entries: VecDeque<AuditRecord>,
+    next_id: u64,
+    last_checksum: u32,
+}
+
+impl AuditLog {
+    pub fn new(config: &SystemConfig, persister: Arc<PersisterKind>) -> Self {
+        Self {
+            enabled: config.audit.enabled,
+            path: config.get_audit_log_file_path(),
+            retention: config.audit.retention,
+            persister,
+            records: Mutex::new(AuditRecords::default()),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Loads the records from the file and verifies their checksums along with the chain.
+    pub async fn init(&self) -> Result<(), IggyError> {
+        if !self.enabled {
+            info!("Audit log is disabled.");
+            return Ok(());
+        }
+
+        if !Path::new(&self.path).exists() {
+            info!("Audit log file does not exist, creating a new one");
+            self.persister
+                .overwrite(&self.path, &[])
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to create audit log file, path: {}",
+                        self.path
+                    )
+                })?;
+        }
+
+        let bytes = tokio::fs::read(&self.path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to read audit log file, path: {}",
+                    self.path
+                )
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        info!(
+            "Loading audit log, file size: {}",
+            IggyByteSize::from(bytes.len() as u64).as_human_string()
+        );
+        let file_size = bytes.len();
+        let (entries, valid_size) = load_records(Bytes::from(bytes))?;
+        // The last record might have been written only partially, if the server was stopped in the meantime,
+        // so it's truncated before any other record is appended.
+        if valid_size != file_size {
+            warn!(
+                "Truncating partially written audit record, path: {}, size: {file_size}, valid size: {valid_size}.",
+                self.path
+            );
+            let file = tokio::fs::OpenOptions::new()
+                .write(true)
+                .open(&self.path)
+                .await
+                .map_err(|_| IggyError::CannotReadFile)?;
+            file.set_len(valid_size as u64)
+                .await
+                .map_err(|_| IggyError::CannotWriteToFile)?;
+        }
+        let mut records = self.records.lock().await;
+        if let Some(last) = entries.back() {
+            records.next_id = last.id + 1;
+            records.last_checksum = last.checksum;
+        }
+        info!(
+            "Loaded {} audit records, next ID: {}",
+            entries.len(),
+            records.next_id
+        );
+        records.entries = entries;
+        Ok(())
+    }
+
+    /// Appends the record of the handled command. The failure to persist the record is logged,
+    /// as the command has been already handled.
+    pub async fn record(&self, event: AuditEvent) {
+        if !self.enabled {
+            return;
+        }
+
+        let mut records = self.records.lock().await;
+        let mut record = AuditRecord {
+            id: records.next_id,
+            timestamp: IggyTimestamp::now(),
+            user_id: match event.user_id {
+                0 => None,
+                user_id => Some(user_id),
+            },
+            address: event.address.to_string(),
+            transport: event.transport,
+            command: get_name_from_code(event.code)
+                .unwrap_or("unknown")
+                .to_string(),
+            target: event.target.to_string(),
+            error_code: event.error_code,
+            previous_checksum: records.last_checksum,
+            checksum: 0,
+        };
+        record.checksum = record.calculate_checksum();
+        if let Err(error) = self.persister.append(&self.path, &to_bytes(&record)).await {
+            error!(
+                "{COMPONENT} (error: {error}) - failed to append audit record with ID: {}, path: {}",
+                record.id, self.path
+            );
+            return;
+        }
+
+        debug!("Appended audit record: {record:?}");
+        records.next_id += 1;
+        records.last_checksum = record.checksum;
+        records.entries.push_back(record);
+    }
+
+    /// Returns up to `count` records, starting from the record with the given ID.
+    pub async fn get_records(&self, start_id: u64, count: u32) -> Vec<AuditRecord> {
+        let records = self.records.lock().await;
+        let Some(first) = records.entries.front() else {
+            return Vec::new();
+        };
+
+        let skip = start_id.saturating_sub(first.id) as usize;
+        records
+            .entries
+            .iter()
+            .skip(skip)
+            .take(count as usize)
+            .cloned()
+            .collect()
+    }
+
+    /// Deletes the records older than the retention and rewrites the file.
+    /// The latest record is always kept, so that the IDs and the chain continue after the restart.
+    pub async fn delete_expired_records(&self, now: IggyTimestamp) -> Result<usize, IggyError> {
+        if !self.enabled {
+            return Ok(0);
+        }
+
+        let IggyExpiry::ExpireDuration(retention) = self.retention else {
+            return Ok(0);
+        };
+
+        let mut records = self.records.lock().await;
+        let mut deleted_records = 0;
+        while records.entries.len() > 1
+            && records.entries.front().is_some_and(|record| {
+                record.timestamp.as_micros() + retention.as_micros() <= now.as_micros()
+            })
+        {
+            records.entries.pop_front();
+            deleted_records += 1;
+        }
+
+        if deleted_records == 0 {
+            return Ok(0);
+        }
+
+        let mut bytes = BytesMut::new();
+        for record in records.entries.iter() {
+            bytes.extend(to_bytes(record));
+        }
+        // The records are written to the temporary file first, which then replaces the log,
+        // so that the log is not lost if the server is stopped in the meantime.
+        let temp_path = format!("{}.tmp", self.path);
+        if Path::new(&temp_path).exists() {
+            // Overwriting doesn't truncate the file, so the previous content must be removed first.
+            self.persister
+                .delete(&temp_path)
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to delete temporary audit log file, path: {temp_path}")
+                })?;
+        }
+        self.persister
+            .overwrite(&temp_path, &bytes)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to write temporary audit log file, path: {temp_path}")
+            })?;
+        file::rename(&temp_path, &self.path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to replace audit log file, path: {} with: {temp_path}",
+                    self.path
+                )
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+        Ok(deleted_records)
+    }
+}
+
+/// Loads the records along with the size of the bytes they span, which is less than the size of all the bytes
+/// if the last record is incomplete. Any invalid checksum or broken chain means that the log is corrupted.
+fn load_records(mut bytes: Bytes) -> Result<(VecDeque<AuditRecord>, usize), IggyError> {
+    let mut records: VecDeque<AuditRecord> = VecDeque::new();
+    let mut valid_size = 0;
+    while bytes.has_remaining() {
+        if bytes.remaining() < 4 {
+            break;
+        }
+
+        let length = u32::from_le_bytes(bytes[..4].try_into().unwrap()) as usize;
+        if bytes.remaining() < 4 + length {
+            break;
+        }
+
+        bytes.advance(4);
+        let record = from_bytes(bytes.split_to(length))?;
+        let checksum = record.calculate_checksum();
+        if checksum != record.checksum {
+            return Err(IggyError::InvalidAuditRecordChecksum(
+                checksum,
+                record.checksum,
+                record.id,
+            ));
+        }
+
+        if let Some(previous) = records.back()
+            && !record.is_chained_to(previous)
+        {
+            error!(
+                "Audit log is corrupted, record with ID: {} is not chained to the previous record with ID: {}",
+                record.id, previous.id
+            );
+            return Err(IggyError::AuditLogCorrupted);
+        }
+
+        valid_size += 4 + length;
+        records.push_back(record);
+    }
+    Ok((records, valid_size))
+}
+
+fn to_bytes(record: &AuditRecord) -> Bytes {
+    let values = [
+        &record.address,
+        &record.transport,
+        &record.command,
+        &record.target,
+    ];
+    let length = 8 + 8 + 4 + 4 + 4 + 4 + values.iter().map(|v| 4 + v.len()).sum::<usize>();
+    let mut bytes = BytesMut::with_capacity(4 + length);
+    bytes.put_u32_le(length as u32);
+    bytes.put_u64_le(record.id);
+    bytes.put_u64_le(record.timestamp.into());
+    bytes.put_u32_le(record.user_id.unwrap_or_default());
+    bytes.put_u32_le(record.error_code.unwrap_or_default());
+    bytes.put_u32_le(record.previous_checksum);
+    bytes.put_u32_le(record.checksum);
+    for value in values {
+        bytes.put_u32_le(value.len() as u32);
+        bytes.put_slice(value.as_bytes());
+    }
+    bytes.freeze()
+}
+
+fn from_bytes(mut bytes: Bytes) -> Result<AuditRecord, IggyError> {
+    if bytes.remaining() < 32 {
+        return Err(IggyError::AuditLogCorrupted);
+    }
+
+    let id = bytes.get_u64_le();
+    let timestamp = IggyTimestamp::from(bytes.get_u64_le());
+    let user_id = bytes.get_u32_le();
+    let error_code = bytes.get_u32_le();
+    let previous_checksum = bytes.get_u32_le();
+    let checksum = bytes.get_u32_le();
+    let mut values = Vec::with_capacity(4);
+    for _ in 0..4 {
+        if bytes.remaining() < 4 {
+            return Err(IggyError::AuditLogCorrupted);
+        }
+
+        let length = bytes.get_u32_le() as usize;
+        if bytes.remaining() < length {
+            return Err(IggyError::AuditLogCorrupted);
+        }
+
+        let value = String::from_utf8(bytes.split_to(length).to_vec())
+            .map_err(|_| IggyError::InvalidUtf8)?;
+        values.push(value);
+    }
+
+    let target = values.pop().unwrap_or_default();
+    let command = values.pop().unwrap_or_default();
+    let transport = values.pop().unwrap_or_default();
+    let address = values.pop().unwrap_or_default();
+    Ok(AuditRecord {
+        id,
+        timestamp,
+        user_id: (user_id > 0).then_some(user_id),
+        address,
+        transport,
+        command,
+        target,
+        error_code: (error_code > 0).then_some(error_code),
+        previous_checksum,
+        checksum,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::AuditTarget;
+    use crate::streaming::persistence::persister::FilePersister;
+    use iggy_common::{CREATE_STREAM_CODE, IggyDuration, LOGIN_USER_CODE};
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn records_should_be_chained_and_loaded_after_restart() {
+        let (_dir, config) = config("none");
+        let audit_log = init(&config).await;
+        audit_log.record(event(1, CREATE_STREAM_CODE, None)).await;
+        audit_log
+            .record(event(
+                0,
+                LOGIN_USER_CODE,
+                Some(IggyError::InvalidCredentials),
+            ))
+            .await;
+
+        let audit_log = init(&config).await;
+        let records = audit_log.get_records(0, 10).await;
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].user_id, Some(1));
+        assert_eq!(records[0].command, "stream.create");
+        assert!(records[0].is_success());
+        assert_eq!(records[1].user_id, None);
+        assert_eq!(records[1].error_code, Some(42));
+        assert!(records[1].is_chained_to(&records[0]));
+
+        audit_log.record(event(1, CREATE_STREAM_CODE, None)).await;
+        let records = audit_log.get_records(1, 1).await;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].id, 1);
+    }
+
+    #[tokio::test]
+    async fn tampered_record_should_fail_to_load() {
+        let (_dir, config) = config("none");
+        let audit_log = init(&config).await;
+        audit_log.record(event(1, CREATE_STREAM_CODE, None)).await;
+        audit_log.record(event(1, CREATE_STREAM_CODE, None)).await;
+
+        let mut bytes = std::fs::read(config.get_audit_log_file_path()).unwrap();
+        let user_id_position = 4 + 8 + 8;
+        bytes[user_id_position] = 2;
+        std::fs::write(config.get_audit_log_file_path(), bytes).unwrap();
+
+        let audit_log = AuditLog::new(&config, Arc::new(PersisterKind::File(FilePersister)));
+        assert!(matches!(
+            audit_log.init().await,
+            Err(IggyError::InvalidAuditRecordChecksum(_, _, 0))
+        ));
+    }
+
+    #[tokio::test]
+    async fn partially_written_last_record_should_be_truncated() {
+        let (_dir, config) = config("none");
+        let audit_log = init(&config).await;
+        audit_log.record(event(1, CREATE_STREAM_CODE, None)).await;
+        audit_log.record(event(1, CREATE_STREAM_CODE, None)).await;
+
+        let path = config.get_audit_log_file_path();
+        let bytes = std::fs::read(&path).unwrap();
+        let first_record_size = 4 + u32::from_le_bytes(bytes[..4].try_into().unwrap()) as usize;
+        std::fs::write(&path, &bytes[..bytes.len() - 3]).unwrap();
+
+        let audit_log = init(&config).await;
+        assert_eq!(
+            std::fs::metadata(&path).unwrap().len(),
+            first_record_size as u64
+        );
+        audit_log.record(event(1, CREATE_STREAM_CODE, None)).await;
+
+        let audit_log = init(&config).await;
+        let records = audit_log.get_records(0, 10).await;
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].id, 1);
+        assert!(records[1].is_chained_to(&records[0]));
+    }
+
+    #[tokio::test]
+    async fn expired_records_should_be_deleted_except_the_latest_one() {
+        let (_dir, config) = config("1s");
+        let audit_log = init(&config).await;
+        audit_log.record(event(1, CREATE_STREAM_CODE, None)).await;
+        audit_log.record(event(1, CREATE_STREAM_CODE, None)).await;
+
+        let now = IggyTimestamp::from(
+            IggyTimestamp::now().as_micros() + IggyDuration::from_str("2s").unwrap().as_micros(),
+        );
+        let deleted_records = audit_log.delete_expired_records(now).await.unwrap();
+        assert_eq!(deleted_records, 1);
+        assert!(!Path::new(&format!("{}.tmp", config.get_audit_log_file_path())).exists());
+
+        let audit_log = init(&config).await;
+        audit_log.record(event(1, CREATE_STREAM_CODE, None)).await;
+        let records = audit_log.get_records(0, 10).await;
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].id, 1);
+        assert_eq!(records[1].id, 2);
+    }
+
+    fn config(retention: &str) -> (tempfile::TempDir, SystemConfig) {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = SystemConfig {
+            path: dir.path().to_str().unwrap().to_string(),
+            ..Default::default()
+        };
+        config.audit.enabled = true;
+        config.audit.retention = IggyExpiry::from_str(retention).unwrap();
+        std::fs::create_dir_all(config.get_audit_path()).unwrap();
+        (dir, config)
+    }
+
+    async fn init(config: &SystemConfig) -> AuditLog {
+        let audit_log = AuditLog::new(config, Arc::new(PersisterKind::File(FilePersister)));
+        audit_log.init().await.unwrap();
+        audit_log
+    }
+
+    fn event(user_id: u32, code: u32, error: Option<IggyError>) -> AuditEvent {
+        AuditEvent {
+            user_id,
+            address: "127.0.0.1:1234".parse().unwrap(),
+            transport: "TCP".to_string(),
+            code,
+            target: AuditTarget::default().with("stream_id", 1),
+            error_code: error.map(|error| error.as_code()),
+        }
+    }
+}
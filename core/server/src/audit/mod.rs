@@ -0,0 +1,124 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy_common::UserId;
+use std::fmt::{Display, Formatter};
+use std::net::SocketAddr;
+
+pub mod command;
+pub mod log;
+
+pub const COMPONENT: &str = "AUDIT";
+
+/// The keys of the identifiers included in the target of the audit records, in the order of appearance.
+/// Only these keys are taken from the HTTP requests, so that e.g. the passwords are never recorded.
+pub const TARGET_KEYS: &[&str] = &[
+    "stream_id",
+    "topic_id",
+    "group_id",
+    "schema_id",
+    "user_id",
+    "partitions_count",
+    "schema_type",
+    "compatibility",
+    "name",
+    "username",
+    "roles",
+];
+
+/// The audited command handled by the server, recorded in the audit log.
+#[derive(Debug)]
+pub struct AuditEvent {
+    pub user_id: UserId,
+    pub address: SocketAddr,
+    pub transport: String,
+    pub code: u32,
+    pub target: AuditTarget,
+    pub error_code: Option<u32>,
+}
+
+/// The identifiers of the resource affected by the audited command, displayed as `key: value` pairs.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AuditTarget {
+    values: Vec<(&'static str, String)>,
+}
+
+impl AuditTarget {
+    pub fn with(mut self, key: &'static str, value: impl Display) -> Self {
+        self.add(key, value.to_string());
+        self
+    }
+
+    pub fn with_optional(self, key: &'static str, value: Option<impl Display>) -> Self {
+        match value {
+            Some(value) => self.with(key, value),
+            None => self,
+        }
+    }
+
+    /// Adds the value only if the key is one of the `TARGET_KEYS`, keeping their order.
+    pub fn add(&mut self, key: &str, value: String) {
+        let Some(key) = TARGET_KEYS.iter().find(|target_key| **target_key == key) else {
+            return;
+        };
+
+        if self
+            .values
+            .iter()
+            .any(|(existing_key, _)| existing_key == key)
+        {
+            return;
+        }
+
+        self.values.push((key, value));
+        self.values
+            .sort_by_key(|(key, _)| TARGET_KEYS.iter().position(|target_key| target_key == key));
+    }
+}
+
+impl Display for AuditTarget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (index, (key, value)) in self.values.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{key}: {value}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn target_should_keep_only_known_keys_in_order() {
+        let mut target = AuditTarget::default();
+        target.add("name", "orders".to_string());
+        target.add("password", "secret".to_string());
+        target.add("topic_id", "2".to_string());
+        target.add("stream_id", "1".to_string());
+        target.add("stream_id", "3".to_string());
+
+        assert_eq!(
+            target.to_string(),
+            "stream_id: 1, topic_id: 2, name: orders"
+        );
+    }
+}
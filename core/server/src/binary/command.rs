@@ -22,10 +22,15 @@ use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use bytes::{BufMut, Bytes, BytesMut};
 use enum_dispatch::enum_dispatch;
+use iggy_common::abort_transaction::AbortTransaction;
+use iggy_common::assign_roles::AssignRoles;
+use iggy_common::begin_transaction::BeginTransaction;
 use iggy_common::change_password::ChangePassword;
+use iggy_common::commit_transaction::CommitTransaction;
 use iggy_common::create_consumer_group::CreateConsumerGroup;
 use iggy_common::create_partitions::CreatePartitions;
 use iggy_common::create_personal_access_token::CreatePersonalAccessToken;
+use iggy_common::create_role::CreateRole;
 use iggy_common::create_stream::CreateStream;
 use iggy_common::create_topic::CreateTopic;
 use iggy_common::create_user::CreateUser;
@@ -33,9 +38,13 @@ use iggy_common::delete_consumer_group::DeleteConsumerGroup;
 use iggy_common::delete_consumer_offset::DeleteConsumerOffset;
 use iggy_common::delete_partitions::DeletePartitions;
 use iggy_common::delete_personal_access_token::DeletePersonalAccessToken;
+use iggy_common::delete_role::DeleteRole;
+use iggy_common::delete_schema::DeleteSchema;
 use iggy_common::delete_stream::DeleteStream;
 use iggy_common::delete_topic::DeleteTopic;
 use iggy_common::delete_user::DeleteUser;
+use iggy_common::export_metadata::ExportMetadata;
+use iggy_common::get_audit_log::GetAuditLog;
 use iggy_common::get_client::GetClient;
 use iggy_common::get_clients::GetClients;
 use iggy_common::get_consumer_group::GetConsumerGroup;
@@ -43,6 +52,9 @@ use iggy_common::get_consumer_groups::GetConsumerGroups;
 use iggy_common::get_consumer_offset::GetConsumerOffset;
 use iggy_common::get_me::GetMe;
 use iggy_common::get_personal_access_tokens::GetPersonalAccessTokens;
+use iggy_common::get_roles::GetRoles;
+use iggy_common::get_schema::GetSchema;
+use iggy_common::get_schemas::GetSchemas;
 use iggy_common::get_snapshot::GetSnapshot;
 use iggy_common::get_stats::GetStats;
 use iggy_common::get_stream::GetStream;
@@ -51,16 +63,27 @@ use iggy_common::get_topic::GetTopic;
 use iggy_common::get_topics::GetTopics;
 use iggy_common::get_user::GetUser;
 use iggy_common::get_users::GetUsers;
+use iggy_common::import_user::ImportUser;
+use iggy_common::init_producer::InitProducer;
 use iggy_common::join_consumer_group::JoinConsumerGroup;
 use iggy_common::leave_consumer_group::LeaveConsumerGroup;
 use iggy_common::login_user::LoginUser;
 use iggy_common::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use iggy_common::login_with_token::LoginWithToken;
 use iggy_common::logout_user::LogoutUser;
+use iggy_common::move_partition::MovePartition;
 use iggy_common::ping::Ping;
 use iggy_common::purge_stream::PurgeStream;
 use iggy_common::purge_topic::PurgeTopic;
+use iggy_common::record_delivery_failure::RecordDeliveryFailure;
+use iggy_common::register_schema::RegisterSchema;
+use iggy_common::reset_consumer_group_offsets::ResetConsumerGroupOffsets;
+use iggy_common::send_transaction_messages::SendTransactionMessages;
 use iggy_common::store_consumer_offset::StoreConsumerOffset;
+use iggy_common::store_consumer_offsets::StoreConsumerOffsets;
+use iggy_common::sync_consumer_group::SyncConsumerGroup;
 use iggy_common::update_permissions::UpdatePermissions;
+use iggy_common::update_role::UpdateRole;
 use iggy_common::update_stream::UpdateStream;
 use iggy_common::update_topic::UpdateTopic;
 use iggy_common::update_user::UpdateUser;
@@ -75,6 +98,8 @@ define_server_command_enum! {
     GetClient(GetClient), GET_CLIENT_CODE, GET_CLIENT, true;
     GetClients(GetClients), GET_CLIENTS_CODE, GET_CLIENTS, false;
     GetSnapshot(GetSnapshot), GET_SNAPSHOT_FILE_CODE, GET_SNAPSHOT_FILE, false;
+    GetAuditLog(GetAuditLog), GET_AUDIT_LOG_CODE, GET_AUDIT_LOG, true;
+    ExportMetadata(ExportMetadata), EXPORT_METADATA_CODE, EXPORT_METADATA, true;
     PollMessages(PollMessages), POLL_MESSAGES_CODE, POLL_MESSAGES, true;
     FlushUnsavedBuffer(FlushUnsavedBuffer), FLUSH_UNSAVED_BUFFER_CODE, FLUSH_UNSAVED_BUFFER, true;
     GetUser(GetUser), GET_USER_CODE, GET_USER, true;
@@ -84,15 +109,23 @@ define_server_command_enum! {
     UpdateUser(UpdateUser), UPDATE_USER_CODE, UPDATE_USER, true;
     UpdatePermissions(UpdatePermissions), UPDATE_PERMISSIONS_CODE, UPDATE_PERMISSIONS, true;
     ChangePassword(ChangePassword), CHANGE_PASSWORD_CODE, CHANGE_PASSWORD, true;
+    GetRoles(GetRoles), GET_ROLES_CODE, GET_ROLES, false;
+    CreateRole(CreateRole), CREATE_ROLE_CODE, CREATE_ROLE, true;
+    UpdateRole(UpdateRole), UPDATE_ROLE_CODE, UPDATE_ROLE, true;
+    DeleteRole(DeleteRole), DELETE_ROLE_CODE, DELETE_ROLE, true;
+    AssignRoles(AssignRoles), ASSIGN_ROLES_CODE, ASSIGN_ROLES, true;
     LoginUser(LoginUser), LOGIN_USER_CODE, LOGIN_USER, true;
     LogoutUser(LogoutUser), LOGOUT_USER_CODE, LOGOUT_USER, false;
+    LoginWithToken(LoginWithToken), LOGIN_WITH_TOKEN_CODE, LOGIN_WITH_TOKEN, false;
     GetPersonalAccessTokens(GetPersonalAccessTokens), GET_PERSONAL_ACCESS_TOKENS_CODE, GET_PERSONAL_ACCESS_TOKENS, false;
     CreatePersonalAccessToken(CreatePersonalAccessToken), CREATE_PERSONAL_ACCESS_TOKEN_CODE, CREATE_PERSONAL_ACCESS_TOKEN, true;
     DeletePersonalAccessToken(DeletePersonalAccessToken), DELETE_PERSONAL_ACCESS_TOKEN_CODE, DELETE_PERSONAL_ACCESS_TOKEN, false;
     LoginWithPersonalAccessToken(LoginWithPersonalAccessToken), LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE, LOGIN_WITH_PERSONAL_ACCESS_TOKEN, true;
+    ImportUser(ImportUser), IMPORT_USER_CODE, IMPORT_USER, true;
     SendMessages(SendMessages), SEND_MESSAGES_CODE, SEND_MESSAGES, false;
     GetConsumerOffset(GetConsumerOffset), GET_CONSUMER_OFFSET_CODE, GET_CONSUMER_OFFSET, true;
     StoreConsumerOffset(StoreConsumerOffset), STORE_CONSUMER_OFFSET_CODE, STORE_CONSUMER_OFFSET, true;
+    StoreConsumerOffsets(StoreConsumerOffsets), STORE_CONSUMER_OFFSETS_CODE, STORE_CONSUMER_OFFSETS, true;
     DeleteConsumerOffset(DeleteConsumerOffset), DELETE_CONSUMER_OFFSET_CODE, DELETE_CONSUMER_OFFSET, true;
     GetStream(GetStream), GET_STREAM_CODE, GET_STREAM, true;
     GetStreams(GetStreams), GET_STREAMS_CODE, GET_STREAMS, false;
@@ -108,12 +141,55 @@ define_server_command_enum! {
     PurgeTopic(PurgeTopic), PURGE_TOPIC_CODE, PURGE_TOPIC, true;
     CreatePartitions(CreatePartitions), CREATE_PARTITIONS_CODE, CREATE_PARTITIONS, true;
     DeletePartitions(DeletePartitions), DELETE_PARTITIONS_CODE, DELETE_PARTITIONS, true;
+    MovePartition(MovePartition), MOVE_PARTITION_CODE, MOVE_PARTITION, true;
     GetConsumerGroup(GetConsumerGroup), GET_CONSUMER_GROUP_CODE, GET_CONSUMER_GROUP, true;
     GetConsumerGroups(GetConsumerGroups), GET_CONSUMER_GROUPS_CODE, GET_CONSUMER_GROUPS, false;
     CreateConsumerGroup(CreateConsumerGroup), CREATE_CONSUMER_GROUP_CODE, CREATE_CONSUMER_GROUP, true;
     DeleteConsumerGroup(DeleteConsumerGroup), DELETE_CONSUMER_GROUP_CODE, DELETE_CONSUMER_GROUP, true;
     JoinConsumerGroup(JoinConsumerGroup), JOIN_CONSUMER_GROUP_CODE, JOIN_CONSUMER_GROUP, true;
     LeaveConsumerGroup(LeaveConsumerGroup), LEAVE_CONSUMER_GROUP_CODE, LEAVE_CONSUMER_GROUP, true;
+    SyncConsumerGroup(SyncConsumerGroup), SYNC_CONSUMER_GROUP_CODE, SYNC_CONSUMER_GROUP, false;
+    RecordDeliveryFailure(RecordDeliveryFailure), RECORD_DELIVERY_FAILURE_CODE, RECORD_DELIVERY_FAILURE, false;
+    ResetConsumerGroupOffsets(ResetConsumerGroupOffsets), RESET_CONSUMER_GROUP_OFFSETS_CODE, RESET_CONSUMER_GROUP_OFFSETS, true;
+    BeginTransaction(BeginTransaction), BEGIN_TRANSACTION_CODE, BEGIN_TRANSACTION, false;
+    SendTransactionMessages(SendTransactionMessages), SEND_TRANSACTION_MESSAGES_CODE, SEND_TRANSACTION_MESSAGES, false;
+    CommitTransaction(CommitTransaction), COMMIT_TRANSACTION_CODE, COMMIT_TRANSACTION, true;
+    AbortTransaction(AbortTransaction), ABORT_TRANSACTION_CODE, ABORT_TRANSACTION, true;
+    InitProducer(InitProducer), INIT_PRODUCER_CODE, INIT_PRODUCER, true;
+    GetSchema(GetSchema), GET_SCHEMA_CODE, GET_SCHEMA, true;
+    GetSchemas(GetSchemas), GET_SCHEMAS_CODE, GET_SCHEMAS, true;
+    RegisterSchema(RegisterSchema), REGISTER_SCHEMA_CODE, REGISTER_SCHEMA, true;
+    DeleteSchema(DeleteSchema), DELETE_SCHEMA_CODE, DELETE_SCHEMA, true;
+}
+
+impl ServerCommand {
+    /// Returns `true` if the pipelined command can be handled concurrently with the next requests on the connection,
+    /// which applies to polling the messages, that might wait for the new ones, and to the other queries.
+    /// The rest of the commands are handled in the order of the requests, e.g. so that the messages sent
+    /// to the partition are appended in the same order.
+    pub fn can_be_handled_concurrently(&self) -> bool {
+        matches!(
+            self,
+            ServerCommand::Ping(_)
+                | ServerCommand::GetStats(_)
+                | ServerCommand::GetMe(_)
+                | ServerCommand::GetClient(_)
+                | ServerCommand::GetClients(_)
+                | ServerCommand::PollMessages(_)
+                | ServerCommand::GetUser(_)
+                | ServerCommand::GetUsers(_)
+                | ServerCommand::GetRoles(_)
+                | ServerCommand::GetConsumerOffset(_)
+                | ServerCommand::GetStream(_)
+                | ServerCommand::GetStreams(_)
+                | ServerCommand::GetTopic(_)
+                | ServerCommand::GetTopics(_)
+                | ServerCommand::GetConsumerGroup(_)
+                | ServerCommand::GetConsumerGroups(_)
+                | ServerCommand::GetSchema(_)
+                | ServerCommand::GetSchemas(_)
+        )
+    }
 }
 
 #[enum_dispatch]
@@ -183,6 +259,16 @@ mod tests {
             GET_CLIENTS_CODE,
             &GetClients::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetAuditLog(GetAuditLog::default()),
+            GET_AUDIT_LOG_CODE,
+            &GetAuditLog::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::ExportMetadata(ExportMetadata::default()),
+            EXPORT_METADATA_CODE,
+            &ExportMetadata::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetUser(GetUser::default()),
             GET_USER_CODE,
@@ -198,6 +284,11 @@ mod tests {
             CREATE_USER_CODE,
             &CreateUser::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::ImportUser(ImportUser::default()),
+            IMPORT_USER_CODE,
+            &ImportUser::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::DeleteUser(DeleteUser::default()),
             DELETE_USER_CODE,
@@ -218,6 +309,31 @@ mod tests {
             CHANGE_PASSWORD_CODE,
             &ChangePassword::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetRoles(GetRoles::default()),
+            GET_ROLES_CODE,
+            &GetRoles::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::CreateRole(CreateRole::default()),
+            CREATE_ROLE_CODE,
+            &CreateRole::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::UpdateRole(UpdateRole::default()),
+            UPDATE_ROLE_CODE,
+            &UpdateRole::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::DeleteRole(DeleteRole::default()),
+            DELETE_ROLE_CODE,
+            &DeleteRole::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::AssignRoles(AssignRoles::default()),
+            ASSIGN_ROLES_CODE,
+            &AssignRoles::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::LoginUser(LoginUser::default()),
             LOGIN_USER_CODE,
@@ -228,6 +344,11 @@ mod tests {
             LOGOUT_USER_CODE,
             &LogoutUser::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::LoginWithToken(LoginWithToken::default()),
+            LOGIN_WITH_TOKEN_CODE,
+            &LoginWithToken::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetPersonalAccessTokens(GetPersonalAccessTokens::default()),
             GET_PERSONAL_ACCESS_TOKENS_CODE,
@@ -258,6 +379,11 @@ mod tests {
             STORE_CONSUMER_OFFSET_CODE,
             &StoreConsumerOffset::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::StoreConsumerOffsets(StoreConsumerOffsets::default()),
+            STORE_CONSUMER_OFFSETS_CODE,
+            &StoreConsumerOffsets::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetConsumerOffset(GetConsumerOffset::default()),
             GET_CONSUMER_OFFSET_CODE,
@@ -333,6 +459,11 @@ mod tests {
             DELETE_PARTITIONS_CODE,
             &DeletePartitions::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::MovePartition(MovePartition::default()),
+            MOVE_PARTITION_CODE,
+            &MovePartition::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetConsumerGroup(GetConsumerGroup::default()),
             GET_CONSUMER_GROUP_CODE,
@@ -363,11 +494,66 @@ mod tests {
             LEAVE_CONSUMER_GROUP_CODE,
             &LeaveConsumerGroup::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::SyncConsumerGroup(SyncConsumerGroup::default()),
+            SYNC_CONSUMER_GROUP_CODE,
+            &SyncConsumerGroup::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::RecordDeliveryFailure(RecordDeliveryFailure::default()),
+            RECORD_DELIVERY_FAILURE_CODE,
+            &RecordDeliveryFailure::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::ResetConsumerGroupOffsets(ResetConsumerGroupOffsets::default()),
+            RESET_CONSUMER_GROUP_OFFSETS_CODE,
+            &ResetConsumerGroupOffsets::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::FlushUnsavedBuffer(FlushUnsavedBuffer::default()),
             FLUSH_UNSAVED_BUFFER_CODE,
             &FlushUnsavedBuffer::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::BeginTransaction(BeginTransaction::default()),
+            BEGIN_TRANSACTION_CODE,
+            &BeginTransaction::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::CommitTransaction(CommitTransaction::default()),
+            COMMIT_TRANSACTION_CODE,
+            &CommitTransaction::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::AbortTransaction(AbortTransaction::default()),
+            ABORT_TRANSACTION_CODE,
+            &AbortTransaction::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::InitProducer(InitProducer::default()),
+            INIT_PRODUCER_CODE,
+            &InitProducer::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetSchema(GetSchema::default()),
+            GET_SCHEMA_CODE,
+            &GetSchema::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetSchemas(GetSchemas::default()),
+            GET_SCHEMAS_CODE,
+            &GetSchemas::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::RegisterSchema(RegisterSchema::default()),
+            REGISTER_SCHEMA_CODE,
+            &RegisterSchema::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::DeleteSchema(DeleteSchema::default()),
+            DELETE_SCHEMA_CODE,
+            &DeleteSchema::default(),
+        );
     }
 
     fn assert_serialized_as_bytes_and_deserialized_from_bytes(
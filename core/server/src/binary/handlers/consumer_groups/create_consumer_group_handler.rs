@@ -53,6 +53,7 @@ impl ServerCommandHandler for CreateConsumerGroup {
                     &self.topic_id,
                     self.group_id,
                     &self.name,
+                    self.assignment_strategy,
                 )
                 .await
                 .with_error_context(|error| {
@@ -22,5 +22,8 @@ pub mod get_consumer_group_handler;
 pub mod get_consumer_groups_handler;
 pub mod join_consumer_group_handler;
 pub mod leave_consumer_group_handler;
+pub mod record_delivery_failure_handler;
+pub mod reset_consumer_group_offsets_handler;
+pub mod sync_consumer_group_handler;
 
 pub const COMPONENT: &str = "CONSUMER_GROUP_HANDLER";
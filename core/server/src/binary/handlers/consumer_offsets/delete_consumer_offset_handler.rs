@@ -20,6 +20,8 @@ use crate::binary::command::{BinaryServerCommand, ServerCommand, ServerCommandHa
 use crate::binary::handlers::consumer_offsets::COMPONENT;
 use crate::binary::handlers::utils::receive_and_validate;
 use crate::binary::sender::SenderKind;
+use crate::state::command::EntryCommand;
+use crate::state::models::DeleteConsumerOffsetWithIds;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use anyhow::Result;
@@ -42,10 +44,10 @@ impl ServerCommandHandler for DeleteConsumerOffset {
     ) -> Result<(), IggyError> {
         debug!("session: {session}, command: {self}");
         let system = system.read().await;
-        system
+        let deleted_offset = system
             .delete_consumer_offset(
                 session,
-                self.consumer,
+                self.consumer.clone(),
                 &self.stream_id,
                 &self.topic_id,
                 self.partition_id,
@@ -54,6 +56,24 @@ impl ServerCommandHandler for DeleteConsumerOffset {
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to delete consumer offset for topic with ID: {} in stream with ID: {} partition ID: {:#?}, session: {}",
                 self.topic_id, self.stream_id, self.partition_id, session
             ))?;
+        system
+            .state
+            .apply(
+                session.get_user_id(),
+                &EntryCommand::DeleteConsumerOffset(DeleteConsumerOffsetWithIds {
+                    stream_id: deleted_offset.stream_id,
+                    topic_id: deleted_offset.topic_id,
+                    consumer_id: deleted_offset.consumer_id,
+                    partition_id: deleted_offset.partition_id,
+                    command: self,
+                }),
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to apply delete consumer offset, session: {session}"
+                )
+            })?;
         sender.send_empty_ok_response().await?;
         Ok(())
     }
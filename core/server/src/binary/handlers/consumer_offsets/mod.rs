@@ -19,5 +19,6 @@
 pub mod delete_consumer_offset_handler;
 pub mod get_consumer_offset_handler;
 pub mod store_consumer_offset_handler;
+pub mod store_consumer_offsets_handler;
 
 pub const COMPONENT: &str = "CONSUMER_OFFSET_HANDLER";
@@ -50,6 +50,7 @@ impl ServerCommandHandler for StoreConsumerOffset {
                 &self.topic_id,
                 self.partition_id,
                 self.offset,
+                self.metadata.clone(),
             )
             .await
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to store consumer offset for stream_id: {}, topic_id: {}, partition_id: {:?}, offset: {}, session: {}",
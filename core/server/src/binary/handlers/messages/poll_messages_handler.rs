@@ -25,7 +25,7 @@ use crate::streaming::systems::messages::PollingArgs;
 use crate::streaming::systems::system::SharedSystem;
 use anyhow::Result;
 use error_set::ErrContext;
-use iggy_common::{IggyError, PollMessages};
+use iggy_common::{IggyError, IsolationLevel, PollMessages};
 use std::io::IoSlice;
 use tracing::{debug, trace};
 
@@ -33,6 +33,9 @@ use tracing::{debug, trace};
 pub struct IggyPollMetadata {
     pub partition_id: u32,
     pub current_offset: u64,
+    pub next_offset: Option<u64>,
+    /// The entries of the due delayed messages included in the poll, to be removed once the messages are returned.
+    pub due_delayed_messages: Vec<(u64, u64)>,
 }
 
 impl IggyPollMetadata {
@@ -40,6 +43,8 @@ impl IggyPollMetadata {
         Self {
             partition_id,
             current_offset,
+            next_offset: None,
+            due_delayed_messages: Vec::new(),
         }
     }
 }
@@ -58,40 +63,59 @@ impl ServerCommandHandler for PollMessages {
     ) -> Result<(), IggyError> {
         debug!("session: {session}, command: {self}");
 
-        let system = system.read().await;
-        let (metadata, messages) = system
-            .poll_messages(
+        let (system, metadata, messages) = system
+            .long_poll_messages(
                 session,
                 &self.consumer,
                 &self.stream_id,
                 &self.topic_id,
                 self.partition_id,
-                PollingArgs::new(self.strategy, self.count, self.auto_commit),
+                PollingArgs::new(self.strategy, self.count, self.auto_commit)
+                    .with_filter(self.filter.clone())
+                    .with_isolation_level(self.isolation_level)
+                    .with_long_polling(self.max_wait, self.min_bytes),
             )
             .await
             .with_error_context(|error| format!(
                 "{COMPONENT} (error: {error}) - failed to poll messages for consumer: {}, stream_id: {}, topic_id: {}, partition_id: {:?}, session: {session}.",
                 self.consumer, self.stream_id, self.topic_id, self.partition_id
             ))?;
+        let throttle_delay = system.throttle_consumed(session, messages.size() as u64);
         drop(system);
 
+        if !throttle_delay.is_zero() {
+            trace!(
+                "Throttling the polled messages response for session: {session} by {} ms",
+                throttle_delay.as_millis()
+            );
+            tokio::time::sleep(throttle_delay).await;
+        }
+
         // Collect all chunks first into a Vec to extend their lifetimes.
         // This ensures the Bytes (in reality Arc<[u8]>) references from each IggyMessagesBatch stay alive
         // throughout the async vectored I/O operation, preventing "borrowed value does not live
         // long enough" errors while optimizing transmission by using larger chunks.
 
         // 4 bytes for partition_id + 8 bytes for current_offset + 4 bytes for messages_count + size of all batches.
-        let response_length = 4 + 8 + 4 + messages.size();
+        // Filtered and read_committed polls additionally include 8 bytes for next_offset right after messages_count.
+        let next_offset = (self.filter.is_some()
+            || self.isolation_level == IsolationLevel::ReadCommitted)
+            .then(|| metadata.next_offset.unwrap_or_default().to_le_bytes());
+        let next_offset_length = next_offset.map_or(0, |bytes| bytes.len() as u32);
+        let response_length = 4 + 8 + 4 + next_offset_length + messages.size();
         let response_length_bytes = response_length.to_le_bytes();
 
         let partition_id = metadata.partition_id.to_le_bytes();
         let current_offset = metadata.current_offset.to_le_bytes();
         let count = messages.count().to_le_bytes();
 
-        let mut io_slices = Vec::with_capacity(messages.containers_count() + 3);
+        let mut io_slices = Vec::with_capacity(messages.containers_count() + 4);
         io_slices.push(IoSlice::new(&partition_id));
         io_slices.push(IoSlice::new(&current_offset));
         io_slices.push(IoSlice::new(&count));
+        if let Some(next_offset) = &next_offset {
+            io_slices.push(IoSlice::new(next_offset));
+        }
 
         io_slices.extend(messages.iter().map(|m| IoSlice::new(m)));
 
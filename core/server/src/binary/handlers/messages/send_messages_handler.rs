@@ -26,7 +26,7 @@ use anyhow::Result;
 use iggy_common::INDEX_SIZE;
 use iggy_common::Identifier;
 use iggy_common::Sizeable;
-use iggy_common::{IggyError, Partitioning, SendMessages, Validatable};
+use iggy_common::{IggyError, Partitioning, ProducerSequence, SendMessages, Validatable};
 use tracing::instrument;
 
 impl ServerCommandHandler for SendMessages {
@@ -49,72 +49,118 @@ impl ServerCommandHandler for SendMessages {
         system: &SharedSystem,
     ) -> Result<(), IggyError> {
         let total_payload_size = length as usize - std::mem::size_of::<u32>();
-        let metadata_len_field_size = std::mem::size_of::<u32>();
+        let batch = read_messages(sender, total_payload_size, &mut self).await?;
+        let messages_count = batch.count();
+        let messages_size = batch.size() as u64;
 
-        let mut metadata_length_buffer = [0u8; 4];
-        sender.read(&mut metadata_length_buffer).await?;
-        let metadata_size = u32::from_le_bytes(metadata_length_buffer);
-
-        let mut metadata_buffer = PooledBuffer::with_capacity(metadata_size as usize);
-        unsafe { metadata_buffer.set_len(metadata_size as usize) };
-        sender.read(&mut metadata_buffer).await?;
-
-        let mut element_size = 0;
-
-        let stream_id = Identifier::from_raw_bytes(&metadata_buffer)?;
-        element_size += stream_id.get_size_bytes().as_bytes_usize();
-        self.stream_id = stream_id;
-
-        let topic_id = Identifier::from_raw_bytes(&metadata_buffer[element_size..])?;
-        element_size += topic_id.get_size_bytes().as_bytes_usize();
-        self.topic_id = topic_id;
-
-        let partitioning = Partitioning::from_raw_bytes(&metadata_buffer[element_size..])?;
-        element_size += partitioning.get_size_bytes().as_bytes_usize();
-        self.partitioning = partitioning;
+        let system = system.read().await;
+        if let Some(producer) = &self.producer {
+            system
+                .append_idempotent_messages(
+                    session,
+                    &self.stream_id,
+                    &self.topic_id,
+                    &self.partitioning,
+                    producer,
+                    batch,
+                    None,
+                )
+                .await?;
+        } else {
+            system
+                .append_messages(
+                    session,
+                    &self.stream_id,
+                    &self.topic_id,
+                    &self.partitioning,
+                    batch,
+                    None,
+                )
+                .await?;
+        }
+        let throttle_delay = system.throttle_produced(session, messages_count, messages_size);
+        drop(system);
 
-        let messages_count = u32::from_le_bytes(
-            metadata_buffer[element_size..element_size + 4]
-                .try_into()
-                .unwrap(),
-        );
-        let indexes_size = messages_count as usize * INDEX_SIZE;
+        if throttle_delay.is_zero() {
+            sender.send_empty_ok_response().await?;
+            return Ok(());
+        }
 
-        let mut indexes_buffer = PooledBuffer::with_capacity(indexes_size);
-        unsafe { indexes_buffer.set_len(indexes_size) };
-        sender.read(&mut indexes_buffer).await?;
+        // The throttled response is delayed and carries the applied delay in milliseconds as a hint.
+        tokio::time::sleep(throttle_delay).await;
+        let throttle_time_ms = throttle_delay.as_millis() as u32;
+        sender
+            .send_ok_response(&throttle_time_ms.to_le_bytes())
+            .await?;
+        Ok(())
+    }
+}
 
-        let messages_size =
-            total_payload_size - metadata_size as usize - indexes_size - metadata_len_field_size;
-        let mut messages_buffer = PooledBuffer::with_capacity(messages_size);
-        unsafe { messages_buffer.set_len(messages_size) };
-        sender.read(&mut messages_buffer).await?;
+/// Reads the metadata (stored in the provided command), indexes and messages of `SendMessages`
+/// directly from the sender, so that the messages don't have to be copied once again.
+pub(crate) async fn read_messages(
+    sender: &mut SenderKind,
+    total_payload_size: usize,
+    command: &mut SendMessages,
+) -> Result<IggyMessagesBatchMut, IggyError> {
+    let metadata_len_field_size = std::mem::size_of::<u32>();
+
+    let mut metadata_length_buffer = [0u8; 4];
+    sender.read(&mut metadata_length_buffer).await?;
+    let metadata_size = u32::from_le_bytes(metadata_length_buffer);
+
+    let mut metadata_buffer = PooledBuffer::with_capacity(metadata_size as usize);
+    unsafe { metadata_buffer.set_len(metadata_size as usize) };
+    sender.read(&mut metadata_buffer).await?;
+
+    let mut element_size = 0;
+
+    let stream_id = Identifier::from_raw_bytes(&metadata_buffer)?;
+    element_size += stream_id.get_size_bytes().as_bytes_usize();
+    command.stream_id = stream_id;
+
+    let topic_id = Identifier::from_raw_bytes(&metadata_buffer[element_size..])?;
+    element_size += topic_id.get_size_bytes().as_bytes_usize();
+    command.topic_id = topic_id;
+
+    let partitioning = Partitioning::from_raw_bytes(&metadata_buffer[element_size..])?;
+    element_size += partitioning.get_size_bytes().as_bytes_usize();
+    command.partitioning = partitioning;
+
+    let messages_count = u32::from_le_bytes(
+        metadata_buffer[element_size..element_size + 4]
+            .try_into()
+            .unwrap(),
+    );
+    element_size += 4;
+
+    // The producer sequence is sent only by the idempotent producers.
+    if metadata_buffer.len() >= element_size + ProducerSequence::SIZE {
+        let producer = &metadata_buffer[element_size..element_size + ProducerSequence::SIZE];
+        command.producer = Some(ProducerSequence {
+            producer_id: u64::from_le_bytes(producer[..8].try_into().unwrap()),
+            producer_epoch: u32::from_le_bytes(producer[8..12].try_into().unwrap()),
+            sequence: u64::from_le_bytes(producer[12..20].try_into().unwrap()),
+        });
+    }
+    let indexes_size = messages_count as usize * INDEX_SIZE;
 
-        let indexes = IggyIndexesMut::from_bytes(indexes_buffer, 0);
-        let batch = IggyMessagesBatchMut::from_indexes_and_messages(
-            messages_count,
-            indexes,
-            messages_buffer,
-        );
+    let mut indexes_buffer = PooledBuffer::with_capacity(indexes_size);
+    unsafe { indexes_buffer.set_len(indexes_size) };
+    sender.read(&mut indexes_buffer).await?;
 
-        batch.validate()?;
+    let messages_size =
+        total_payload_size - metadata_size as usize - indexes_size - metadata_len_field_size;
+    let mut messages_buffer = PooledBuffer::with_capacity(messages_size);
+    unsafe { messages_buffer.set_len(messages_size) };
+    sender.read(&mut messages_buffer).await?;
 
-        let system = system.read().await;
-        system
-            .append_messages(
-                session,
-                &self.stream_id,
-                &self.topic_id,
-                &self.partitioning,
-                batch,
-                None,
-            )
-            .await?;
-        drop(system);
+    let indexes = IggyIndexesMut::from_bytes(indexes_buffer, 0);
+    let batch =
+        IggyMessagesBatchMut::from_indexes_and_messages(messages_count, indexes, messages_buffer);
 
-        sender.send_empty_ok_response().await?;
-        Ok(())
-    }
+    batch.validate()?;
+    Ok(batch)
 }
 
 impl BinaryServerCommand for SendMessages {
@@ -21,9 +21,13 @@ pub mod consumer_offsets;
 pub mod messages;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod producers;
+pub mod roles;
+pub mod schemas;
 pub mod segments;
 pub mod streams;
 pub mod system;
 pub mod topics;
+pub mod transactions;
 pub mod users;
 mod utils;
@@ -18,5 +18,6 @@
 
 pub mod create_partitions_handler;
 pub mod delete_partitions_handler;
+pub mod move_partition_handler;
 
 pub const COMPONENT: &str = "PARTITIONS_HANDLER";
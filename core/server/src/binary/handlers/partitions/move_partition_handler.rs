@@ -0,0 +1,86 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::command::{BinaryServerCommand, ServerCommand, ServerCommandHandler};
+use crate::binary::handlers::utils::receive_and_validate;
+use crate::binary::{handlers::partitions::COMPONENT, sender::SenderKind};
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy_common::IggyError;
+use iggy_common::move_partition::MovePartition;
+use tracing::{debug, instrument};
+
+impl ServerCommandHandler for MovePartition {
+    fn code(&self) -> u32 {
+        iggy_common::MOVE_PARTITION_CODE
+    }
+
+    #[instrument(skip_all, name = "trace_move_partition", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id, iggy_stream_id = self.stream_id.as_string(), iggy_topic_id = self.topic_id.as_string(), iggy_partition_id = self.partition_id))]
+    async fn handle(
+        self,
+        sender: &mut SenderKind,
+        _length: u32,
+        session: &Session,
+        system: &SharedSystem,
+    ) -> Result<(), IggyError> {
+        debug!("session: {session}, command: {self}");
+        let partition = {
+            let system = system.read().await;
+            system
+                .find_partition_to_move(
+                    session,
+                    &self.stream_id,
+                    &self.topic_id,
+                    self.partition_id,
+                    &self.data_directory,
+                )
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to find partition with ID: {} for topic with ID: {} in stream with ID: {} to move, session: {session}",
+                        self.partition_id, self.topic_id, self.stream_id
+                    )
+                })?
+        };
+
+        Partition::move_to_data_directory(&partition, &self.data_directory)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to move partition with ID: {} for topic with ID: {} in stream with ID: {} to data directory: {}, session: {session}",
+                    self.partition_id, self.topic_id, self.stream_id, self.data_directory
+                )
+            })?;
+        sender.send_empty_ok_response().await?;
+        Ok(())
+    }
+}
+
+impl BinaryServerCommand for MovePartition {
+    async fn from_sender(sender: &mut SenderKind, code: u32, length: u32) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        match receive_and_validate(sender, code, length).await? {
+            ServerCommand::MovePartition(move_partition) => Ok(move_partition),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
@@ -0,0 +1,94 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::command::{BinaryServerCommand, ServerCommand, ServerCommandHandler};
+use crate::binary::handlers::utils::receive_and_validate;
+use crate::binary::{handlers::producers::COMPONENT, sender::SenderKind};
+use crate::state::command::EntryCommand;
+use crate::state::models::InitProducerWithEpoch;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use bytes::{BufMut, BytesMut};
+use error_set::ErrContext;
+use iggy_common::IggyError;
+use iggy_common::init_producer::InitProducer;
+use tracing::{debug, instrument};
+
+impl ServerCommandHandler for InitProducer {
+    fn code(&self) -> u32 {
+        iggy_common::INIT_PRODUCER_CODE
+    }
+
+    #[instrument(skip_all, name = "trace_init_producer", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id))]
+    async fn handle(
+        self,
+        sender: &mut SenderKind,
+        _length: u32,
+        session: &Session,
+        system: &SharedSystem,
+    ) -> Result<(), IggyError> {
+        debug!("session: {session}, command: {self}");
+        let system = system.read().await;
+        let producer = system
+            .init_producer(session, self.producer_id)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to initialize producer with ID: {}, session: {session}",
+                    self.producer_id
+                )
+            })?;
+        system
+            .state
+            .apply(
+                session.get_user_id(),
+                &EntryCommand::InitProducer(InitProducerWithEpoch {
+                    producer_id: producer.id,
+                    epoch: producer.epoch,
+                    command: self,
+                }),
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to apply init producer with ID: {}, session: {session}",
+                    producer.id
+                )
+            })?;
+
+        let mut response = BytesMut::with_capacity(12);
+        response.put_u64_le(producer.id);
+        response.put_u32_le(producer.epoch);
+        sender.send_ok_response(&response).await?;
+        Ok(())
+    }
+}
+
+impl BinaryServerCommand for InitProducer {
+    async fn from_sender(
+        sender: &mut SenderKind,
+        code: u32,
+        length: u32,
+    ) -> Result<Self, IggyError> {
+        match receive_and_validate(sender, code, length).await? {
+            ServerCommand::InitProducer(init_producer) => Ok(init_producer),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::command::{BinaryServerCommand, ServerCommand, ServerCommandHandler};
+use crate::binary::handlers::roles::COMPONENT;
+use crate::binary::handlers::utils::receive_and_validate;
+use crate::binary::mapper;
+use crate::binary::sender::SenderKind;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use error_set::ErrContext;
+use iggy_common::IggyError;
+use iggy_common::get_roles::GetRoles;
+use tracing::debug;
+
+impl ServerCommandHandler for GetRoles {
+    fn code(&self) -> u32 {
+        iggy_common::GET_ROLES_CODE
+    }
+
+    async fn handle(
+        self,
+        sender: &mut SenderKind,
+        _length: u32,
+        session: &Session,
+        system: &SharedSystem,
+    ) -> Result<(), IggyError> {
+        debug!("session: {session}, command: {self}");
+        let system = system.read().await;
+        let roles = system.get_roles(session).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get roles, session: {session}")
+        })?;
+        let roles = mapper::map_roles(&roles);
+        sender.send_ok_response(&roles).await?;
+        Ok(())
+    }
+}
+
+impl BinaryServerCommand for GetRoles {
+    async fn from_sender(sender: &mut SenderKind, code: u32, length: u32) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        match receive_and_validate(sender, code, length).await? {
+            ServerCommand::GetRoles(get_roles) => Ok(get_roles),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
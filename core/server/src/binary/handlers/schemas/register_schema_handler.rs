@@ -0,0 +1,103 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::command::{BinaryServerCommand, ServerCommand, ServerCommandHandler};
+use crate::binary::handlers::utils::receive_and_validate;
+use crate::binary::{handlers::schemas::COMPONENT, sender::SenderKind};
+use crate::state::command::EntryCommand;
+use crate::state::models::RegisterSchemaWithIds;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy_common::register_schema::RegisterSchema;
+use iggy_common::{BytesSerializable, IggyError};
+use tracing::{debug, instrument};
+
+impl ServerCommandHandler for RegisterSchema {
+    fn code(&self) -> u32 {
+        iggy_common::REGISTER_SCHEMA_CODE
+    }
+
+    #[instrument(skip_all, name = "trace_register_schema", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id, iggy_stream_id = self.stream_id.as_string(), iggy_topic_id = self.topic_id.as_string()))]
+    async fn handle(
+        self,
+        sender: &mut SenderKind,
+        _length: u32,
+        session: &Session,
+        system: &SharedSystem,
+    ) -> Result<(), IggyError> {
+        debug!("session: {session}, command: {self}");
+
+        let mut system = system.write().await;
+        let schema = system
+            .register_schema(
+                session,
+                &self.stream_id,
+                &self.topic_id,
+                self.schema_type,
+                self.compatibility,
+                self.definition.clone(),
+            )
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to register schema for stream_id: {}, topic_id: {}, session: {session}",
+                    self.stream_id, self.topic_id
+                )
+            })?;
+        let response = schema.to_bytes();
+        let schema_id = schema.id;
+        let version = schema.version;
+        let stream_id = schema.stream_id;
+        let topic_id = schema.topic_id;
+
+        let system = system.downgrade();
+        system
+            .state
+            .apply(
+                session.get_user_id(),
+                &EntryCommand::RegisterSchema(RegisterSchemaWithIds {
+                    schema_id,
+                    version,
+                    stream_id,
+                    topic_id,
+                    command: self,
+                }),
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to apply register schema with ID: {schema_id} for stream_id: {stream_id}, topic_id: {topic_id}, session: {session}"
+                )
+            })?;
+        sender.send_ok_response(&response).await?;
+        Ok(())
+    }
+}
+
+impl BinaryServerCommand for RegisterSchema {
+    async fn from_sender(sender: &mut SenderKind, code: u32, length: u32) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        match receive_and_validate(sender, code, length).await? {
+            ServerCommand::RegisterSchema(register_schema) => Ok(register_schema),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
@@ -58,6 +58,10 @@ impl ServerCommandHandler for CreateTopic {
                     self.compression_algorithm,
                     self.max_topic_size,
                     self.replication_factor,
+                    self.cleanup_policy,
+                    self.partitioning_hash,
+                    self.segment_max_age,
+                    self.max_partition_size,
                 )
                 .await
                 .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to create topic for stream_id: {stream_id}, topic_id: {:?}",
@@ -65,6 +69,8 @@ impl ServerCommandHandler for CreateTopic {
                 ))?;
         self.message_expiry = topic.message_expiry;
         self.max_topic_size = topic.max_topic_size;
+        self.segment_max_age = topic.segment_max_age;
+        self.max_partition_size = topic.max_partition_size;
         let topic_id = topic.topic_id;
         let response = mapper::map_topic(topic).await;
 
@@ -55,6 +55,10 @@ impl ServerCommandHandler for UpdateTopic {
                     self.compression_algorithm,
                     self.max_topic_size,
                     self.replication_factor,
+                    self.cleanup_policy,
+                    self.partitioning_hash,
+                    self.segment_max_age,
+                    self.max_partition_size,
                 )
                 .await
                 .with_error_context(|error| format!(
@@ -63,6 +67,8 @@ impl ServerCommandHandler for UpdateTopic {
                 ))?;
         self.message_expiry = topic.message_expiry;
         self.max_topic_size = topic.max_topic_size;
+        self.segment_max_age = topic.segment_max_age;
+        self.max_partition_size = topic.max_partition_size;
 
         let topic_id = self.topic_id.clone();
         let stream_id = self.stream_id.clone();
@@ -0,0 +1,97 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::command::{BinaryServerCommand, ServerCommandHandler};
+use crate::binary::handlers::messages::send_messages_handler::read_messages;
+use crate::binary::{handlers::transactions::COMPONENT, sender::SenderKind};
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy_common::IggyError;
+use iggy_common::send_transaction_messages::SendTransactionMessages;
+use tracing::instrument;
+
+impl ServerCommandHandler for SendTransactionMessages {
+    fn code(&self) -> u32 {
+        iggy_common::SEND_TRANSACTION_MESSAGES_CODE
+    }
+
+    #[instrument(skip_all, name = "trace_send_transaction_messages", fields(
+        iggy_user_id = session.get_user_id(),
+        iggy_client_id = session.client_id,
+        iggy_transaction_id = self.transaction_id,
+        iggy_stream_id = self.messages.stream_id.as_string(),
+        iggy_topic_id = self.messages.topic_id.as_string(),
+        partitioning = %self.messages.partitioning
+    ))]
+    async fn handle(
+        mut self,
+        sender: &mut SenderKind,
+        length: u32,
+        session: &Session,
+        system: &SharedSystem,
+    ) -> Result<(), IggyError> {
+        let transaction_id_size = std::mem::size_of::<u64>();
+        let mut transaction_id_buffer = [0u8; 8];
+        sender.read(&mut transaction_id_buffer).await?;
+        self.transaction_id = u64::from_le_bytes(transaction_id_buffer);
+        if self.transaction_id == 0 {
+            return Err(IggyError::InvalidTransactionId);
+        }
+
+        let total_payload_size = length as usize - std::mem::size_of::<u32>() - transaction_id_size;
+        let batch = read_messages(sender, total_payload_size, &mut self.messages).await?;
+
+        let system = system.read().await;
+        system
+            .append_transaction_messages(
+                session,
+                self.transaction_id,
+                &self.messages.stream_id,
+                &self.messages.topic_id,
+                &self.messages.partitioning,
+                batch,
+                None,
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to append messages of transaction with ID: {}, session: {session}",
+                    self.transaction_id
+                )
+            })?;
+        drop(system);
+
+        sender.send_empty_ok_response().await?;
+        Ok(())
+    }
+}
+
+impl BinaryServerCommand for SendTransactionMessages {
+    async fn from_sender(
+        _sender: &mut SenderKind,
+        _code: u32,
+        _length: u32,
+    ) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        Ok(Self::default())
+    }
+}
@@ -54,6 +54,7 @@ impl ServerCommandHandler for CreateUser {
                     &self.password,
                     self.status,
                     self.permissions.clone(),
+                    self.quotas,
                 )
                 .await
                 .with_error_context(|error| {
@@ -78,6 +79,7 @@ impl ServerCommandHandler for CreateUser {
                     password: crypto::hash_password(&self.password),
                     status: self.status,
                     permissions: self.permissions.clone(),
+                    quotas: self.quotas,
                 }
             }),
         )
@@ -50,6 +50,7 @@ impl ServerCommandHandler for UpdateUser {
                     &self.user_id,
                     self.username.clone(),
                     self.status,
+                    self.quotas,
                 )
                 .await
                 .with_error_context(|error| {
@@ -25,7 +25,10 @@ use crate::streaming::topics::topic::Topic;
 use crate::streaming::users::user::User;
 use bytes::{BufMut, Bytes, BytesMut};
 use iggy_common::locking::{IggySharedMut, IggySharedMutFn};
-use iggy_common::{BytesSerializable, ConsumerOffsetInfo, Sizeable, Stats, UserId};
+use iggy_common::{
+    AuditRecord, BytesSerializable, ConsumerGroupAssignment, ConsumerGroupOffsetReset,
+    ConsumerOffsetInfo, Role, Schema, Sizeable, Stats, UserId,
+};
 use tokio::sync::RwLock;
 
 pub fn map_stats(stats: &Stats) -> Bytes {
@@ -77,10 +80,27 @@ pub fn map_stats(stats: &Stats) -> Bytes {
 }
 
 pub fn map_consumer_offset(offset: &ConsumerOffsetInfo) -> Bytes {
-    let mut bytes = BytesMut::with_capacity(20);
+    let metadata = offset.metadata.as_deref().unwrap_or_default();
+    let mut bytes = BytesMut::with_capacity(30 + metadata.len());
     bytes.put_u32_le(offset.partition_id);
     bytes.put_u64_le(offset.current_offset);
     bytes.put_u64_le(offset.stored_offset);
+    bytes.put_u64_le(offset.committed_at.as_micros());
+    bytes.put_u16_le(metadata.len() as u16);
+    bytes.put_slice(metadata.as_bytes());
+    bytes.freeze()
+}
+
+pub fn map_consumer_group_offset_resets(resets: &[ConsumerGroupOffsetReset]) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(30 * resets.len());
+    for reset in resets {
+        bytes.put_u32_le(reset.partition_id);
+        bytes.put_u64_le(reset.current_offset);
+        for offset in [reset.old_offset, reset.new_offset] {
+            bytes.put_u8(offset.is_some() as u8);
+            bytes.put_u64_le(offset.unwrap_or_default());
+        }
+    }
     bytes.freeze()
 }
 
@@ -104,6 +124,14 @@ pub async fn map_clients(clients: &[IggySharedMut<Client>]) -> Bytes {
     bytes.freeze()
 }
 
+pub fn map_audit_records(records: &[AuditRecord]) -> Bytes {
+    let mut bytes = BytesMut::new();
+    for record in records {
+        extend_audit_record(record, &mut bytes);
+    }
+    bytes.freeze()
+}
+
 pub fn map_user(user: &User) -> Bytes {
     let mut bytes = BytesMut::new();
     extend_user(user, &mut bytes);
@@ -116,6 +144,37 @@ pub fn map_user(user: &User) -> Bytes {
     } else {
         bytes.put_u32_le(0);
     }
+    if let Some(quotas) = &user.quotas {
+        bytes.put_u8(1);
+        bytes.put_slice(&quotas.to_bytes());
+    } else {
+        bytes.put_u8(0);
+    }
+    bytes.put_u32_le(user.roles.len() as u32);
+    for role in &user.roles {
+        bytes.put_u8(role.len() as u8);
+        bytes.put_slice(role.as_bytes());
+    }
+    bytes.freeze()
+}
+
+pub fn map_roles(roles: &[&Role]) -> Bytes {
+    let mut bytes = BytesMut::new();
+    for role in roles {
+        let role = role.to_bytes();
+        bytes.put_u32_le(role.len() as u32);
+        bytes.put_slice(&role);
+    }
+    bytes.freeze()
+}
+
+pub fn map_schemas(schemas: &[&Schema]) -> Bytes {
+    let mut bytes = BytesMut::new();
+    for schema in schemas {
+        let schema = schema.to_bytes();
+        bytes.put_u32_le(schema.len() as u32);
+        bytes.put_slice(&schema);
+    }
     bytes.freeze()
 }
 
@@ -199,6 +258,27 @@ pub async fn map_consumer_group(consumer_group: &ConsumerGroup) -> Bytes {
     bytes.freeze()
 }
 
+pub fn map_consumer_group_assignment(assignment: &ConsumerGroupAssignment) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(
+        16 + 4
+            * (assignment.partitions.len()
+                + assignment.assigned_partitions.len()
+                + assignment.revoked_partitions.len()),
+    );
+    bytes.put_u32_le(assignment.generation);
+    for partitions in [
+        &assignment.partitions,
+        &assignment.assigned_partitions,
+        &assignment.revoked_partitions,
+    ] {
+        bytes.put_u32_le(partitions.len() as u32);
+        for partition_id in partitions {
+            bytes.put_u32_le(*partition_id);
+        }
+    }
+    bytes.freeze()
+}
+
 pub async fn map_consumer_groups(consumer_groups: &[&RwLock<ConsumerGroup>]) -> Bytes {
     let mut bytes = BytesMut::new();
     for consumer_group in consumer_groups {
@@ -226,6 +306,10 @@ fn extend_topic(topic: &Topic, bytes: &mut BytesMut) {
     bytes.put_u8(topic.compression_algorithm.as_code());
     bytes.put_u64_le(topic.max_topic_size.into());
     bytes.put_u8(topic.replication_factor);
+    bytes.put_u8(topic.cleanup_policy.as_code());
+    bytes.put_slice(&topic.partitioning_hash.as_code());
+    bytes.put_u64_le(topic.segment_max_age.into());
+    bytes.put_u64_le(topic.max_partition_size.into());
     bytes.put_u64_le(topic.get_size_bytes().as_bytes_u64());
     bytes.put_u64_le(topic.get_messages_count());
     bytes.put_u8(topic.name.len() as u8);
@@ -263,6 +347,24 @@ fn extend_client(client: &Client, bytes: &mut BytesMut) {
     bytes.put_u32_le(client.consumer_groups.len() as u32);
 }
 
+fn extend_audit_record(record: &AuditRecord, bytes: &mut BytesMut) {
+    bytes.put_u64_le(record.id);
+    bytes.put_u64_le(record.timestamp.into());
+    bytes.put_u32_le(record.user_id.unwrap_or(0));
+    bytes.put_u32_le(record.error_code.unwrap_or(0));
+    bytes.put_u32_le(record.previous_checksum);
+    bytes.put_u32_le(record.checksum);
+    for value in [
+        &record.address,
+        &record.transport,
+        &record.command,
+        &record.target,
+    ] {
+        bytes.put_u32_le(value.len() as u32);
+        bytes.put_slice(value.as_bytes());
+    }
+}
+
 fn extend_user(user: &User, bytes: &mut BytesMut) {
     bytes.put_u32_le(user.id);
     bytes.put_u64_le(user.created_at.into());
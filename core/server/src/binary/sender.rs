@@ -19,6 +19,8 @@
 use std::future::Future;
 use std::io::IoSlice;
 
+use crate::tcp::sender::shared_writer;
+use crate::tcp::tcp_pipelined_sender::TcpPipelinedSender;
 use crate::tcp::tcp_sender::TcpSender;
 use crate::tcp::tcp_tls_sender::TcpTlsSender;
 use crate::{quic::quic_sender::QuicSender, server_error::ServerError};
@@ -40,7 +42,9 @@ macro_rules! forward_async_methods {
                 match self {
                     Self::Tcp(d) => d.$method_name($( $arg ),*).await,
                     Self::TcpTls(s) => s.$method_name($( $arg ),*).await,
+                    Self::TcpMtls(s) => s.$method_name($( $arg ),*).await,
                     Self::Quic(s) => s.$method_name($( $arg ),*).await,
+                    Self::TcpPipelined(s) => s.$method_name($( $arg ),*).await,
                 }
             }
         )*
@@ -63,22 +67,60 @@ pub trait Sender {
         &mut self,
         error: IggyError,
     ) -> impl Future<Output = Result<(), IggyError>> + Send;
+    /// Sets the correlation ID of the pipelined request, which precedes the response to it.
+    fn set_correlation_id(&mut self, correlation_id: Option<u32>);
     fn shutdown(&mut self) -> impl Future<Output = Result<(), ServerError>> + Send;
 }
 
 pub enum SenderKind {
     Tcp(TcpSender),
     TcpTls(TcpTlsSender),
+    TcpMtls(Box<TcpTlsSender<tokio_rustls::server::TlsStream<TcpStream>>>),
     Quic(QuicSender),
+    TcpPipelined(TcpPipelinedSender),
 }
 
 impl SenderKind {
     pub fn get_tcp_sender(stream: TcpStream) -> Self {
-        Self::Tcp(TcpSender { stream })
+        let (reader, writer) = stream.into_split();
+        Self::Tcp(TcpSender {
+            reader,
+            writer: shared_writer(writer),
+            correlation_id: None,
+        })
     }
 
     pub fn get_tcp_tls_sender(stream: TlsStream<TcpStream>) -> Self {
-        Self::TcpTls(TcpTlsSender { stream })
+        let (reader, writer) = tokio::io::split(stream);
+        Self::TcpTls(TcpTlsSender {
+            reader,
+            writer: shared_writer(writer),
+            correlation_id: None,
+        })
+    }
+
+    pub fn get_tcp_mtls_sender(stream: tokio_rustls::server::TlsStream<TcpStream>) -> Self {
+        let (reader, writer) = tokio::io::split(stream);
+        Self::TcpMtls(Box::new(TcpTlsSender {
+            reader,
+            writer: shared_writer(writer),
+            correlation_id: None,
+        }))
+    }
+
+    /// Returns the sender of the response to the pipelined request with the given correlation ID,
+    /// which can be handled concurrently, as it shares only the write half of the TCP connection.
+    pub fn get_tcp_pipelined_sender(&self, correlation_id: u32) -> Option<Self> {
+        let writer = match self {
+            Self::Tcp(s) => s.writer.clone(),
+            Self::TcpTls(s) => s.writer.clone(),
+            Self::TcpMtls(s) => s.writer.clone(),
+            Self::Quic(_) | Self::TcpPipelined(_) => return None,
+        };
+        Some(Self::TcpPipelined(TcpPipelinedSender {
+            writer,
+            correlation_id: Some(correlation_id),
+        }))
     }
 
     pub fn get_quic_sender(send_stream: SendStream, recv_stream: RecvStream) -> Self {
@@ -88,6 +130,16 @@ impl SenderKind {
         })
     }
 
+    pub fn set_correlation_id(&mut self, correlation_id: Option<u32>) {
+        match self {
+            Self::Tcp(s) => s.set_correlation_id(correlation_id),
+            Self::TcpTls(s) => s.set_correlation_id(correlation_id),
+            Self::TcpMtls(s) => s.set_correlation_id(correlation_id),
+            Self::Quic(s) => s.set_correlation_id(correlation_id),
+            Self::TcpPipelined(s) => s.set_correlation_id(correlation_id),
+        }
+    }
+
     forward_async_methods! {
         async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError>;
         async fn send_empty_ok_response(&mut self) -> Result<(), IggyError>;
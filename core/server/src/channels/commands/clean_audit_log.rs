@@ -0,0 +1,111 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::channels::server_command::BackgroundServerCommand;
+use crate::configs::system::AuditConfig;
+use crate::streaming::systems::system::SharedSystem;
+use flume::Sender;
+use iggy_common::IggyDuration;
+use iggy_common::IggyExpiry;
+use iggy_common::IggyTimestamp;
+use tokio::time;
+use tracing::{error, info, instrument};
+
+pub struct AuditLogCleaner {
+    enabled: bool,
+    interval: IggyDuration,
+    sender: Sender<CleanAuditLogCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CleanAuditLogCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct CleanAuditLogExecutor;
+
+impl AuditLogCleaner {
+    pub fn new(config: &AuditConfig, sender: Sender<CleanAuditLogCommand>) -> Self {
+        Self {
+            enabled: config.enabled && matches!(config.retention, IggyExpiry::ExpireDuration(_)),
+            interval: config.cleaner_interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Audit log cleaner is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!(
+            "Audit log cleaner is enabled, expired audit records will be deleted every: {interval}."
+        );
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender.send(CleanAuditLogCommand).unwrap_or_else(|error| {
+                    error!("Failed to send CleanAuditLogCommand. Error: {}", error);
+                });
+            }
+        });
+    }
+}
+
+impl BackgroundServerCommand<CleanAuditLogCommand> for CleanAuditLogExecutor {
+    #[instrument(skip_all, name = "trace_clean_audit_log")]
+    async fn execute(&mut self, system: &SharedSystem, _command: CleanAuditLogCommand) {
+        let audit = system.read().await.audit.clone();
+        match audit.delete_expired_records(IggyTimestamp::now()).await {
+            Ok(deleted_records) => {
+                info!("Deleted {deleted_records} expired audit records.");
+            }
+            Err(error) => {
+                error!("Failed to delete expired audit records. Error: {error}");
+            }
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<CleanAuditLogCommand>,
+    ) {
+        let audit_log_cleaner = AuditLogCleaner::new(&config.system.audit, sender);
+        audit_log_cleaner.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<CleanAuditLogCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Audit log cleaner receiver stopped.");
+        });
+    }
+}
@@ -26,7 +26,9 @@ use error_set::ErrContext;
 use flume::Sender;
 use iggy_common::IggyDuration;
 use iggy_common::IggyError;
+use iggy_common::IggyExpiry;
 use iggy_common::IggyTimestamp;
+use iggy_common::MaxTopicSize;
 use iggy_common::locking::IggySharedMutFn;
 use std::sync::Arc;
 use tokio::time;
@@ -35,6 +37,9 @@ use tracing::{debug, error, info, instrument, trace};
 pub struct MessagesMaintainer {
     cleaner_enabled: bool,
     archiver_enabled: bool,
+    compactor_enabled: bool,
+    offloader_enabled: bool,
+    tombstone_retention: IggyDuration,
     interval: IggyDuration,
     sender: Sender<MaintainMessagesCommand>,
 }
@@ -43,6 +48,9 @@ pub struct MessagesMaintainer {
 pub struct MaintainMessagesCommand {
     clean_messages: bool,
     archive_messages: bool,
+    compact_messages: bool,
+    offload_messages: bool,
+    tombstone_retention: IggyDuration,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -51,18 +59,26 @@ pub struct MaintainMessagesExecutor;
 impl MessagesMaintainer {
     pub fn new(
         config: &MessagesMaintenanceConfig,
+        tiered_storage_enabled: bool,
         sender: Sender<MaintainMessagesCommand>,
     ) -> Self {
         Self {
             cleaner_enabled: config.cleaner_enabled,
             archiver_enabled: config.archiver_enabled,
+            compactor_enabled: config.compactor_enabled,
+            offloader_enabled: tiered_storage_enabled,
+            tombstone_retention: config.tombstone_retention,
             interval: config.interval,
             sender,
         }
     }
 
     pub fn start(&self) {
-        if !self.cleaner_enabled && !self.archiver_enabled {
+        if !self.cleaner_enabled
+            && !self.archiver_enabled
+            && !self.compactor_enabled
+            && !self.offloader_enabled
+        {
             info!("Messages maintainer is disabled.");
             return;
         }
@@ -70,12 +86,17 @@ impl MessagesMaintainer {
         let interval = self.interval;
         let sender = self.sender.clone();
         info!(
-            "Message maintainer, cleaner is {}, archiver is {}, interval: {interval}",
+            "Message maintainer, cleaner is {}, archiver is {}, compactor is {}, offloader is {}, interval: {interval}",
             map_toggle_str(self.cleaner_enabled),
-            map_toggle_str(self.archiver_enabled)
+            map_toggle_str(self.archiver_enabled),
+            map_toggle_str(self.compactor_enabled),
+            map_toggle_str(self.offloader_enabled)
         );
         let clean_messages = self.cleaner_enabled;
         let archive_messages = self.archiver_enabled;
+        let compact_messages = self.compactor_enabled;
+        let offload_messages = self.offloader_enabled;
+        let tombstone_retention = self.tombstone_retention;
         tokio::spawn(async move {
             let mut interval_timer = time::interval(interval.get_duration());
             loop {
@@ -84,6 +105,9 @@ impl MessagesMaintainer {
                     .send(MaintainMessagesCommand {
                         clean_messages,
                         archive_messages,
+                        compact_messages,
+                        offload_messages,
+                        tombstone_retention,
                     })
                     .unwrap_or_else(|err| {
                         error!("Failed to send MaintainMessagesCommand. Error: {}", err);
@@ -97,6 +121,7 @@ impl BackgroundServerCommand<MaintainMessagesCommand> for MaintainMessagesExecut
     #[instrument(skip_all, name = "trace_maintain_messages")]
     async fn execute(&mut self, system: &SharedSystem, command: MaintainMessagesCommand) {
         let system = system.read().await;
+        system.abort_expired_transactions().await;
         let streams = system.get_streams();
         for stream in streams {
             let topics = stream.get_topics();
@@ -106,6 +131,14 @@ impl BackgroundServerCommand<MaintainMessagesCommand> for MaintainMessagesExecut
                 } else {
                     None
                 };
+                if let Err(error) = roll_segments(topic).await {
+                    error!(
+                        "Failed to roll segments for stream ID: {}, topic ID: {}. Error: {error}",
+                        topic.stream_id, topic.topic_id
+                    );
+                    continue;
+                }
+
                 let expired_segments = handle_expired_segments(
                     topic,
                     archiver.clone(),
@@ -135,16 +168,62 @@ impl BackgroundServerCommand<MaintainMessagesCommand> for MaintainMessagesExecut
                     continue;
                 }
 
+                let oversized_segments = handle_oversized_segments(topic).await;
+                if oversized_segments.is_err() {
+                    error!(
+                        "Failed to delete oversized segments for stream ID: {}, topic ID: {}",
+                        topic.stream_id, topic.topic_id
+                    );
+                    continue;
+                }
+
+                let compacted_segments = if command.compact_messages {
+                    handle_compacted_segments(topic, command.tombstone_retention).await
+                } else {
+                    Ok(HandledSegments::none())
+                };
+                if compacted_segments.is_err() {
+                    error!(
+                        "Failed to compact segments for stream ID: {}, topic ID: {}",
+                        topic.stream_id, topic.topic_id
+                    );
+                    continue;
+                }
+
+                let offloaded_segments = if command.offload_messages {
+                    handle_offloaded_segments(topic).await
+                } else {
+                    Ok(HandledSegments::none())
+                };
+                if offloaded_segments.is_err() {
+                    error!(
+                        "Failed to offload segments for stream ID: {}, topic ID: {}",
+                        topic.stream_id, topic.topic_id
+                    );
+                    continue;
+                }
+
                 let deleted_expired_segments = expired_segments.unwrap();
                 let deleted_oldest_segments = oldest_segments.unwrap();
+                let deleted_oversized_segments = oversized_segments.unwrap();
+                let compacted_segments = compacted_segments.unwrap();
+                let offloaded_segments = offloaded_segments.unwrap();
+                // The markers are pruned after the expired offloaded segments are deleted too.
+                system.prune_transaction_markers(topic).await;
                 let deleted_segments = HandledSegments {
                     segments_count: deleted_expired_segments.segments_count
-                        + deleted_oldest_segments.segments_count,
+                        + deleted_oldest_segments.segments_count
+                        + deleted_oversized_segments.segments_count
+                        + compacted_segments.segments_count
+                        + offloaded_segments.segments_count,
                     messages_count: deleted_expired_segments.messages_count
-                        + deleted_oldest_segments.messages_count,
+                        + deleted_oldest_segments.messages_count
+                        + deleted_oversized_segments.messages_count
+                        + compacted_segments.messages_count
+                        + offloaded_segments.messages_count,
                 };
 
-                if deleted_segments.segments_count == 0 {
+                if deleted_segments.segments_count == 0 && deleted_segments.messages_count == 0 {
                     trace!(
                         "No segments were deleted for stream ID: {}, topic ID: {}",
                         topic.stream_id, topic.topic_id
@@ -179,12 +258,17 @@ impl BackgroundServerCommand<MaintainMessagesCommand> for MaintainMessagesExecut
         if (!config.data_maintenance.archiver.enabled
             || !config.data_maintenance.messages.archiver_enabled)
             && !config.data_maintenance.messages.cleaner_enabled
+            && !config.data_maintenance.messages.compactor_enabled
+            && !config.system.tiered_storage.enabled
         {
             return;
         }
 
-        let messages_maintainer =
-            MessagesMaintainer::new(&config.data_maintenance.messages, sender);
+        let messages_maintainer = MessagesMaintainer::new(
+            &config.data_maintenance.messages,
+            config.system.tiered_storage.enabled,
+            sender,
+        );
         messages_maintainer.start();
     }
 
@@ -197,6 +281,8 @@ impl BackgroundServerCommand<MaintainMessagesCommand> for MaintainMessagesExecut
         if (!config.data_maintenance.archiver.enabled
             || !config.data_maintenance.messages.archiver_enabled)
             && !config.data_maintenance.messages.cleaner_enabled
+            && !config.data_maintenance.messages.compactor_enabled
+            && !config.system.tiered_storage.enabled
         {
             return;
         }
@@ -211,6 +297,182 @@ impl BackgroundServerCommand<MaintainMessagesCommand> for MaintainMessagesExecut
     }
 }
 
+async fn roll_segments(topic: &Topic) -> Result<u32, IggyError> {
+    if !matches!(topic.segment_max_age, IggyExpiry::ExpireDuration(_)) {
+        return Ok(0);
+    }
+
+    let now = IggyTimestamp::now();
+    let mut rolled_segments = 0;
+    for partition in topic.partitions.values() {
+        let mut partition = partition.write().await;
+        let rolled = partition
+            .roll_segment_by_age(topic.segment_max_age, now)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "CHANNEL_COMMAND - failed to roll segment for stream ID: {}, topic ID: {}, partition ID: {}. {error}",
+                    topic.stream_id, topic.topic_id, partition.partition_id
+                )
+            })?;
+        if rolled {
+            rolled_segments += 1;
+        }
+    }
+
+    if rolled_segments > 0 {
+        info!(
+            "Rolled {rolled_segments} segments older than: {} for stream ID: {}, topic ID: {}",
+            topic.segment_max_age, topic.stream_id, topic.topic_id
+        );
+    }
+
+    Ok(rolled_segments)
+}
+
+async fn handle_oversized_segments(topic: &Topic) -> Result<HandledSegments, IggyError> {
+    if !matches!(topic.max_partition_size, MaxTopicSize::Custom(_)) {
+        return Ok(HandledSegments::none());
+    }
+
+    let mut oversized_segments = Vec::new();
+    for partition in topic.partitions.values() {
+        let partition = partition.read().await;
+        let start_offsets =
+            partition.get_oversized_segments_start_offsets(topic.max_partition_size);
+        if !start_offsets.is_empty() {
+            oversized_segments.push(SegmentsToHandle {
+                partition_id: partition.partition_id,
+                start_offsets,
+            });
+        }
+    }
+
+    if oversized_segments.is_empty() {
+        debug!(
+            "No partitions exceed the max size: {} for stream ID: {}, topic ID: {}",
+            topic.max_partition_size, topic.stream_id, topic.topic_id
+        );
+        return Ok(HandledSegments::none());
+    }
+
+    info!(
+        "Found {} partitions exceeding the max size: {} for stream ID: {}, topic ID: {}",
+        oversized_segments.len(),
+        topic.max_partition_size,
+        topic.stream_id,
+        topic.topic_id
+    );
+    delete_segments(topic, &oversized_segments).await
+}
+
+async fn handle_compacted_segments(
+    topic: &Topic,
+    tombstone_retention: IggyDuration,
+) -> Result<HandledSegments, IggyError> {
+    if !topic.cleanup_policy.is_compact() {
+        return Ok(HandledSegments::none());
+    }
+
+    let tombstone_expiry_timestamp = IggyTimestamp::now()
+        .as_micros()
+        .saturating_sub(tombstone_retention.as_micros());
+    let mut segments_count = 0;
+    let mut messages_count = 0;
+    for partition in topic.partitions.values() {
+        // The keys of the newly appended messages are collected in parts, each under its own short-lived lock,
+        // so that the appends and polls aren't blocked for the whole scan.
+        let end_offset = partition.read().await.current_offset;
+        loop {
+            let message_keys = partition
+                .read()
+                .await
+                .read_uncollected_message_keys(end_offset)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "CHANNEL_COMMAND - failed to read message keys for stream ID: {}, topic ID: {}. {error}",
+                        topic.stream_id, topic.topic_id
+                    )
+                })?;
+            let Some(message_keys) = message_keys else {
+                break;
+            };
+
+            partition
+                .write()
+                .await
+                .add_collected_message_keys(message_keys);
+        }
+
+        let mut partition = partition.write().await;
+        let compacted_segments = partition
+            .compact_segments(tombstone_expiry_timestamp)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "CHANNEL_COMMAND - failed to compact segments for stream ID: {}, topic ID: {}, partition ID: {}. {error}",
+                    topic.stream_id, topic.topic_id, partition.partition_id
+                )
+            })?;
+        segments_count += compacted_segments.deleted_segments_count;
+        messages_count += compacted_segments.removed_messages_count;
+    }
+
+    if messages_count > 0 {
+        info!(
+            "Compacted {messages_count} messages and deleted {segments_count} empty segments for stream ID: {}, topic ID: {}",
+            topic.stream_id, topic.topic_id
+        );
+    }
+
+    Ok(HandledSegments {
+        segments_count,
+        messages_count,
+    })
+}
+
+async fn handle_offloaded_segments(topic: &Topic) -> Result<HandledSegments, IggyError> {
+    let now = IggyTimestamp::now();
+    let mut segments_count = 0;
+    let mut messages_count = 0;
+    for partition in topic.partitions.values() {
+        let mut partition = partition.write().await;
+        let offloaded_segments = partition.offload_segments(now).await.with_error_context(|error| {
+            format!(
+                "CHANNEL_COMMAND - failed to offload segments for stream ID: {}, topic ID: {}, partition ID: {}. {error}",
+                topic.stream_id, topic.topic_id, partition.partition_id
+            )
+        })?;
+        partition
+            .expire_offloaded_segments(now)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "CHANNEL_COMMAND - failed to expire offloaded segments for stream ID: {}, topic ID: {}, partition ID: {}. {error}",
+                    topic.stream_id, topic.topic_id, partition.partition_id
+                )
+            })?;
+        segments_count += offloaded_segments.len() as u32;
+        messages_count += offloaded_segments
+            .iter()
+            .map(|segment| segment.messages_count)
+            .sum::<u64>();
+    }
+
+    if segments_count > 0 {
+        info!(
+            "Offloaded {segments_count} segments with {messages_count} messages for stream ID: {}, topic ID: {}",
+            topic.stream_id, topic.topic_id
+        );
+    }
+
+    Ok(HandledSegments {
+        segments_count,
+        messages_count,
+    })
+}
+
 async fn handle_expired_segments(
     topic: &Topic,
     archiver: Option<Arc<ArchiverKind>>,
@@ -17,6 +17,7 @@
  */
 
 pub mod archive_state;
+pub mod clean_audit_log;
 pub mod clean_personal_access_tokens;
 pub mod maintain_messages;
 pub mod print_sysinfo;
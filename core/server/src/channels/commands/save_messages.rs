@@ -78,8 +78,8 @@ impl MessagesSaver {
 impl BackgroundServerCommand<SaveMessagesCommand> for SaveMessagesExecutor {
     #[instrument(skip_all, name = "trace_save_messages")]
     async fn execute(&mut self, system: &SharedSystem, _command: SaveMessagesCommand) {
-        let saved_messages_count = system.read().await.persist_messages().await;
-        match saved_messages_count {
+        let system = system.read().await;
+        match system.persist_messages().await {
             Ok(n) => {
                 if n > 0 {
                     info!("Saved {n} buffered messages on disk.");
@@ -89,6 +89,9 @@ impl BackgroundServerCommand<SaveMessagesCommand> for SaveMessagesExecutor {
                 error!("Couldn't save buffered messages on disk. Error: {e}");
             }
         }
+        if let Err(e) = system.persist_consumer_offsets().await {
+            error!("Couldn't save committed consumer offsets in the state. Error: {e}");
+        }
     }
 
     fn start_command_sender(
@@ -0,0 +1,57 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Mutual TLS configuration, used to authenticate the clients by their certificates instead of the login command.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ClientAuthConfig {
+    pub enabled: bool,
+    pub ca_file: String,
+    pub identity: ClientCertificateIdentity,
+}
+
+/// The part of the client certificate that is mapped to the username.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Display, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum ClientCertificateIdentity {
+    #[default]
+    #[display("common_name")]
+    CommonName,
+    #[display("san_dns")]
+    SanDns,
+    #[display("san_email")]
+    SanEmail,
+    #[display("san_uri")]
+    SanUri,
+}
+
+impl FromStr for ClientCertificateIdentity {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "common_name" => Ok(ClientCertificateIdentity::CommonName),
+            "san_dns" => Ok(ClientCertificateIdentity::SanDns),
+            "san_email" => Ok(ClientCertificateIdentity::SanEmail),
+            "san_uri" => Ok(ClientCertificateIdentity::SanUri),
+            _ => Err(format!("Unknown client certificate identity: {}", s)),
+        }
+    }
+}
@@ -18,6 +18,7 @@
 
 use super::system::MemoryPoolConfig;
 use super::tcp::TcpSocketConfig;
+use crate::configs::client_auth::ClientAuthConfig;
 use crate::configs::http::{
     HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig,
 };
@@ -29,9 +30,10 @@ use crate::configs::server::{
     TelemetryTracesConfig,
 };
 use crate::configs::system::{
-    BackupConfig, CompatibilityConfig, CompressionConfig, EncryptionConfig, LoggingConfig,
-    MessageDeduplicationConfig, PartitionConfig, RecoveryConfig, RuntimeConfig, SegmentConfig,
-    StateConfig, StreamConfig, SystemConfig, TopicConfig,
+    AuditConfig, BackupConfig, CompatibilityConfig, CompressionConfig, EncryptionConfig,
+    ExternalJwtConfig, LoggingConfig, LongPollingConfig, MessageDeduplicationConfig,
+    PartitionConfig, QuotasConfig, RecoveryConfig, RuntimeConfig, SchemaConfig, SegmentConfig,
+    StateConfig, StreamConfig, SystemConfig, TieredStorageConfig, TopicConfig, TransactionConfig,
 };
 use crate::configs::tcp::{TcpConfig, TcpTlsConfig};
 use iggy_common::IggyByteSize;
@@ -81,6 +83,13 @@ impl Default for MessagesMaintenanceConfig {
         MessagesMaintenanceConfig {
             archiver_enabled: SERVER_CONFIG.data_maintenance.messages.archiver_enabled,
             cleaner_enabled: SERVER_CONFIG.data_maintenance.messages.cleaner_enabled,
+            compactor_enabled: SERVER_CONFIG.data_maintenance.messages.compactor_enabled,
+            tombstone_retention: SERVER_CONFIG
+                .data_maintenance
+                .messages
+                .tombstone_retention
+                .parse()
+                .unwrap(),
             interval: SERVER_CONFIG
                 .data_maintenance
                 .messages
@@ -123,6 +132,11 @@ impl Default for QuicConfig {
             keep_alive_interval: SERVER_CONFIG.quic.keep_alive_interval.parse().unwrap(),
             max_idle_timeout: SERVER_CONFIG.quic.max_idle_timeout.parse().unwrap(),
             certificate: QuicCertificateConfig::default(),
+            client_auth: ClientAuthConfig {
+                enabled: SERVER_CONFIG.quic.client_auth.enabled,
+                ca_file: SERVER_CONFIG.quic.client_auth.ca_file.parse().unwrap(),
+                identity: SERVER_CONFIG.quic.client_auth.identity.parse().unwrap(),
+            },
         }
     }
 }
@@ -155,6 +169,11 @@ impl Default for TcpTlsConfig {
             enabled: SERVER_CONFIG.tcp.tls.enabled,
             certificate: SERVER_CONFIG.tcp.tls.certificate.parse().unwrap(),
             password: SERVER_CONFIG.tcp.tls.password.parse().unwrap(),
+            client_auth: ClientAuthConfig {
+                enabled: SERVER_CONFIG.tcp.tls.client_auth.enabled,
+                ca_file: SERVER_CONFIG.tcp.tls.client_auth.ca_file.parse().unwrap(),
+                identity: SERVER_CONFIG.tcp.tls.client_auth.identity.parse().unwrap(),
+            },
         }
     }
 }
@@ -310,6 +329,7 @@ impl Default for SystemConfig {
     fn default() -> SystemConfig {
         SystemConfig {
             path: SERVER_CONFIG.system.path.parse().unwrap(),
+            data_directories: Vec::new(),
             backup: BackupConfig::default(),
             runtime: RuntimeConfig::default(),
             logging: LoggingConfig::default(),
@@ -321,6 +341,13 @@ impl Default for SystemConfig {
             state: StateConfig::default(),
             compression: CompressionConfig::default(),
             message_deduplication: MessageDeduplicationConfig::default(),
+            transaction: TransactionConfig::default(),
+            long_polling: LongPollingConfig::default(),
+            schema: SchemaConfig::default(),
+            tiered_storage: TieredStorageConfig::default(),
+            audit: AuditConfig::default(),
+            quotas: QuotasConfig::default(),
+            external_jwt: ExternalJwtConfig::default(),
             recovery: RecoveryConfig::default(),
             memory_pool: MemoryPoolConfig::default(),
         }
@@ -377,6 +404,7 @@ impl Default for CompressionConfig {
                 .default_algorithm
                 .parse()
                 .unwrap(),
+            zstd_level: SERVER_CONFIG.system.compression.zstd_level as i32,
         }
     }
 }
@@ -439,6 +467,7 @@ impl Default for PartitionConfig {
                 as u32,
             enforce_fsync: SERVER_CONFIG.system.partition.enforce_fsync,
             validate_checksum: SERVER_CONFIG.system.partition.validate_checksum,
+            max_size: SERVER_CONFIG.system.partition.max_size.parse().unwrap(),
         }
     }
 }
@@ -449,6 +478,7 @@ impl Default for SegmentConfig {
             size: SERVER_CONFIG.system.segment.size.parse().unwrap(),
             cache_indexes: SERVER_CONFIG.system.segment.cache_indexes.parse().unwrap(),
             message_expiry: SERVER_CONFIG.system.segment.message_expiry.parse().unwrap(),
+            max_age: SERVER_CONFIG.system.segment.max_age.parse().unwrap(),
             archive_expired: SERVER_CONFIG.system.segment.archive_expired,
             server_confirmation: SERVER_CONFIG
                 .system
@@ -486,6 +516,113 @@ impl Default for MessageDeduplicationConfig {
     }
 }
 
+impl Default for TransactionConfig {
+    fn default() -> TransactionConfig {
+        TransactionConfig {
+            timeout: SERVER_CONFIG.system.transaction.timeout.parse().unwrap(),
+        }
+    }
+}
+
+impl Default for LongPollingConfig {
+    fn default() -> LongPollingConfig {
+        LongPollingConfig {
+            max_wait: SERVER_CONFIG.system.long_polling.max_wait.parse().unwrap(),
+        }
+    }
+}
+
+impl Default for SchemaConfig {
+    fn default() -> SchemaConfig {
+        SchemaConfig {
+            validate_messages: SERVER_CONFIG.system.schema.validate_messages,
+        }
+    }
+}
+
+impl Default for TieredStorageConfig {
+    fn default() -> TieredStorageConfig {
+        TieredStorageConfig {
+            enabled: SERVER_CONFIG.system.tiered_storage.enabled,
+            local_retention: SERVER_CONFIG
+                .system
+                .tiered_storage
+                .local_retention
+                .parse()
+                .unwrap(),
+            remote_retention: SERVER_CONFIG
+                .system
+                .tiered_storage
+                .remote_retention
+                .parse()
+                .unwrap(),
+            cache_segments: SERVER_CONFIG.system.tiered_storage.cache_segments as u32,
+        }
+    }
+}
+
+impl Default for AuditConfig {
+    fn default() -> AuditConfig {
+        AuditConfig {
+            enabled: SERVER_CONFIG.system.audit.enabled,
+            enforce_fsync: SERVER_CONFIG.system.audit.enforce_fsync,
+            retention: SERVER_CONFIG.system.audit.retention.parse().unwrap(),
+            cleaner_interval: SERVER_CONFIG.system.audit.cleaner_interval.parse().unwrap(),
+        }
+    }
+}
+
+impl Default for QuotasConfig {
+    fn default() -> QuotasConfig {
+        QuotasConfig {
+            max_throttle_delay: SERVER_CONFIG
+                .system
+                .quotas
+                .max_throttle_delay
+                .parse()
+                .unwrap(),
+            client_max_produced_bytes_per_second: SERVER_CONFIG
+                .system
+                .quotas
+                .client_max_produced_bytes_per_second
+                .parse()
+                .unwrap(),
+            client_max_produced_messages_per_second: SERVER_CONFIG
+                .system
+                .quotas
+                .client_max_produced_messages_per_second
+                as u64,
+            client_max_consumed_bytes_per_second: SERVER_CONFIG
+                .system
+                .quotas
+                .client_max_consumed_bytes_per_second
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for ExternalJwtConfig {
+    fn default() -> ExternalJwtConfig {
+        ExternalJwtConfig {
+            enabled: SERVER_CONFIG.system.external_jwt.enabled,
+            jwks_cache_ttl: SERVER_CONFIG
+                .system
+                .external_jwt
+                .jwks_cache_ttl
+                .parse()
+                .unwrap(),
+            clock_skew: SERVER_CONFIG
+                .system
+                .external_jwt
+                .clock_skew
+                .parse()
+                .unwrap(),
+            issuers: Vec::new(),
+        }
+    }
+}
+
 impl Default for RecoveryConfig {
     fn default() -> RecoveryConfig {
         RecoveryConfig {
@@ -16,13 +16,17 @@
  * under the License.
  */
 
+use crate::configs::client_auth::ClientAuthConfig;
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
 use crate::configs::server::{
     ArchiverConfig, DataMaintenanceConfig, DiskArchiverConfig, HeartbeatConfig,
     MessagesMaintenanceConfig, S3ArchiverConfig, StateMaintenanceConfig, TelemetryConfig,
     TelemetryLogsConfig, TelemetryTracesConfig,
 };
-use crate::configs::system::MessageDeduplicationConfig;
+use crate::configs::system::{
+    AuditConfig, ExternalJwtConfig, LongPollingConfig, MessageDeduplicationConfig, QuotasConfig,
+    SchemaConfig, TieredStorageConfig, TransactionConfig,
+};
 use crate::configs::{
     http::{HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig},
     server::{MessageSaverConfig, ServerConfig},
@@ -100,7 +104,7 @@ impl Display for QuicConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, address: {}, max_concurrent_bidi_streams: {}, datagram_send_buffer_size: {}, initial_mtu: {}, send_window: {}, receive_window: {}, keep_alive_interval: {}, max_idle_timeout: {}, certificate: {} }}",
+            "{{ enabled: {}, address: {}, max_concurrent_bidi_streams: {}, datagram_send_buffer_size: {}, initial_mtu: {}, send_window: {}, receive_window: {}, keep_alive_interval: {}, max_idle_timeout: {}, certificate: {}, client_auth: {} }}",
             self.enabled,
             self.address,
             self.max_concurrent_bidi_streams,
@@ -110,7 +114,8 @@ impl Display for QuicConfig {
             self.receive_window,
             self.keep_alive_interval,
             self.max_idle_timeout,
-            self.certificate
+            self.certificate,
+            self.client_auth
         )
     }
 }
@@ -129,8 +134,8 @@ impl Display for CompressionConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ allowed_override: {}, default_algorithm: {} }}",
-            self.allow_override, self.default_algorithm
+            "{{ allowed_override: {}, default_algorithm: {}, zstd_level: {} }}",
+            self.allow_override, self.default_algorithm, self.zstd_level
         )
     }
 }
@@ -186,8 +191,12 @@ impl Display for MessagesMaintenanceConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ archiver_enabled: {}, cleaner_enabled: {}, interval: {} }}",
-            self.archiver_enabled, self.cleaner_enabled, self.interval
+            "{{ archiver_enabled: {}, cleaner_enabled: {}, compactor_enabled: {}, tombstone_retention: {}, interval: {} }}",
+            self.archiver_enabled,
+            self.cleaner_enabled,
+            self.compactor_enabled,
+            self.tombstone_retention,
+            self.interval
         )
     }
 }
@@ -265,12 +274,13 @@ impl Display for PartitionConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ path: {}, messages_required_to_save: {}, size_of_messages_required_to_save: {}, enforce_fsync: {}, validate_checksum: {} }}",
+            "{{ path: {}, messages_required_to_save: {}, size_of_messages_required_to_save: {}, enforce_fsync: {}, validate_checksum: {}, max_size: {} }}",
             self.path,
             self.messages_required_to_save,
             self.size_of_messages_required_to_save,
             self.enforce_fsync,
-            self.validate_checksum
+            self.validate_checksum,
+            self.max_size
         )
     }
 }
@@ -285,14 +295,83 @@ impl Display for MessageDeduplicationConfig {
     }
 }
 
+impl Display for TransactionConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{ timeout: {} }}", self.timeout)
+    }
+}
+
+impl Display for LongPollingConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{ max_wait: {} }}", self.max_wait)
+    }
+}
+
+impl Display for SchemaConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{ validate_messages: {} }}", self.validate_messages)
+    }
+}
+
+impl Display for TieredStorageConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, local_retention: {}, remote_retention: {}, cache_segments: {} }}",
+            self.enabled, self.local_retention, self.remote_retention, self.cache_segments
+        )
+    }
+}
+
+impl Display for AuditConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, enforce_fsync: {}, retention: {}, cleaner_interval: {} }}",
+            self.enabled, self.enforce_fsync, self.retention, self.cleaner_interval
+        )
+    }
+}
+
+impl Display for QuotasConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ max_throttle_delay: {}, client_max_produced_bytes_per_second: {}, client_max_produced_messages_per_second: {}, client_max_consumed_bytes_per_second: {} }}",
+            self.max_throttle_delay,
+            self.client_max_produced_bytes_per_second
+                .as_human_string_with_zero_as_unlimited(),
+            self.client_max_produced_messages_per_second,
+            self.client_max_consumed_bytes_per_second
+                .as_human_string_with_zero_as_unlimited()
+        )
+    }
+}
+
+impl Display for ExternalJwtConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let issuers = self
+            .issuers
+            .iter()
+            .map(|issuer| issuer.issuer.as_str())
+            .collect::<Vec<_>>();
+        write!(
+            f,
+            "{{ enabled: {}, jwks_cache_ttl: {}, clock_skew: {}, issuers: {:?} }}",
+            self.enabled, self.jwks_cache_ttl, self.clock_skew, issuers
+        )
+    }
+}
+
 impl Display for SegmentConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ size_bytes: {}, cache_indexes: {}, message_expiry: {}, archive_expired: {}, server_confirmation: {} }}",
+            "{{ size_bytes: {}, cache_indexes: {}, message_expiry: {}, max_age: {}, archive_expired: {}, server_confirmation: {} }}",
             self.size,
             self.cache_indexes,
             self.message_expiry,
+            self.max_age,
             self.archive_expired,
             self.server_confirmation,
         )
@@ -326,8 +405,18 @@ impl Display for TcpTlsConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, certificate: {} }}",
-            self.enabled, self.certificate
+            "{{ enabled: {}, certificate: {}, client_auth: {} }}",
+            self.enabled, self.certificate, self.client_auth
+        )
+    }
+}
+
+impl Display for ClientAuthConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, ca_file: {}, identity: {} }}",
+            self.enabled, self.ca_file, self.identity
         )
     }
 }
@@ -391,8 +480,9 @@ impl Display for SystemConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ path: {}, logging: {}, stream: {}, topic: {}, partition: {}, segment: {}, encryption: {}, state: {} }}",
+            "{{ path: {}, data_directories: {:?}, logging: {}, stream: {}, topic: {}, partition: {}, segment: {}, encryption: {}, state: {} }}",
             self.path,
+            self.data_directories,
             self.logging,
             self.stream,
             self.topic,
@@ -17,6 +17,7 @@
  */
 
 pub mod cache_indexes;
+pub mod client_auth;
 pub mod config_provider;
 pub mod defaults;
 pub mod displays;
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::configs::client_auth::ClientAuthConfig;
 use iggy_common::IggyByteSize;
 use iggy_common::IggyDuration;
 use serde::{Deserialize, Serialize};
@@ -37,6 +38,7 @@ pub struct QuicConfig {
     #[serde_as(as = "DisplayFromStr")]
     pub max_idle_timeout: IggyDuration,
     pub certificate: QuicCertificateConfig,
+    pub client_auth: ClientAuthConfig,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
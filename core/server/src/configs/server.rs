@@ -68,6 +68,9 @@ pub struct ArchiverConfig {
 pub struct MessagesMaintenanceConfig {
     pub archiver_enabled: bool,
     pub cleaner_enabled: bool,
+    pub compactor_enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub tombstone_retention: IggyDuration,
     #[serde_as(as = "DisplayFromStr")]
     pub interval: IggyDuration,
 }
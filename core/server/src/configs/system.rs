@@ -29,6 +29,8 @@ use serde_with::serde_as;
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SystemConfig {
     pub path: String,
+    #[serde(default)]
+    pub data_directories: Vec<String>,
     pub backup: BackupConfig,
     pub state: StateConfig,
     pub runtime: RuntimeConfig,
@@ -40,6 +42,13 @@ pub struct SystemConfig {
     pub encryption: EncryptionConfig,
     pub compression: CompressionConfig,
     pub message_deduplication: MessageDeduplicationConfig,
+    pub transaction: TransactionConfig,
+    pub long_polling: LongPollingConfig,
+    pub schema: SchemaConfig,
+    pub tiered_storage: TieredStorageConfig,
+    pub audit: AuditConfig,
+    pub quotas: QuotasConfig,
+    pub external_jwt: ExternalJwtConfig,
     pub recovery: RecoveryConfig,
     pub memory_pool: MemoryPoolConfig,
 }
@@ -69,6 +78,7 @@ pub struct RuntimeConfig {
 pub struct CompressionConfig {
     pub allow_override: bool,
     pub default_algorithm: CompressionAlgorithm,
+    pub zstd_level: i32,
 }
 
 #[serde_as]
@@ -103,6 +113,7 @@ pub struct TopicConfig {
     pub delete_oldest_segments: bool,
 }
 
+#[serde_as]
 #[derive(Debug, Deserialize, Serialize)]
 pub struct PartitionConfig {
     pub path: String,
@@ -110,6 +121,8 @@ pub struct PartitionConfig {
     pub size_of_messages_required_to_save: IggyByteSize,
     pub enforce_fsync: bool,
     pub validate_checksum: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_size: MaxTopicSize,
 }
 
 #[serde_as]
@@ -121,6 +134,88 @@ pub struct MessageDeduplicationConfig {
     pub expiry: IggyDuration,
 }
 
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TransactionConfig {
+    #[serde_as(as = "DisplayFromStr")]
+    pub timeout: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LongPollingConfig {
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_wait: IggyDuration,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SchemaConfig {
+    pub validate_messages: bool,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TieredStorageConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub local_retention: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub remote_retention: IggyExpiry,
+    pub cache_segments: u32,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AuditConfig {
+    pub enabled: bool,
+    pub enforce_fsync: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub retention: IggyExpiry,
+    #[serde_as(as = "DisplayFromStr")]
+    pub cleaner_interval: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct QuotasConfig {
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_throttle_delay: IggyDuration,
+    pub client_max_produced_bytes_per_second: IggyByteSize,
+    pub client_max_produced_messages_per_second: u64,
+    pub client_max_consumed_bytes_per_second: IggyByteSize,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ExternalJwtConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub jwks_cache_ttl: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub clock_skew: IggyDuration,
+    #[serde(default)]
+    pub issuers: Vec<ExternalJwtIssuerConfig>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ExternalJwtIssuerConfig {
+    pub issuer: String,
+    #[serde(default)]
+    pub audiences: Vec<String>,
+    pub jwks_file: Option<String>,
+    pub jwks_url: Option<String>,
+    pub user_claim: Option<String>,
+    #[serde(default)]
+    pub claim_rules: Vec<ExternalJwtClaimRuleConfig>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ExternalJwtClaimRuleConfig {
+    pub claim: String,
+    pub value: String,
+    pub username: String,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct RecoveryConfig {
     pub recreate_missing_state: bool,
@@ -140,6 +235,8 @@ pub struct SegmentConfig {
     pub cache_indexes: CacheIndexesConfig,
     #[serde_as(as = "DisplayFromStr")]
     pub message_expiry: IggyExpiry,
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_age: IggyExpiry,
     pub archive_expired: bool,
     #[serde_as(as = "DisplayFromStr")]
     pub server_confirmation: Confirmation,
@@ -154,6 +251,17 @@ pub struct StateConfig {
     pub retry_delay: IggyDuration,
 }
 
+impl CompressionConfig {
+    /// Returns the compression algorithm to be used for the messages of the topic.
+    pub fn resolve_algorithm(&self, topic_algorithm: CompressionAlgorithm) -> CompressionAlgorithm {
+        if self.allow_override && topic_algorithm != CompressionAlgorithm::None {
+            return topic_algorithm;
+        }
+
+        self.default_algorithm
+    }
+}
+
 impl SystemConfig {
     pub fn get_system_path(&self) -> String {
         self.path.to_string()
@@ -163,6 +271,14 @@ impl SystemConfig {
         format!("{}/state", self.get_system_path())
     }
 
+    pub fn get_audit_path(&self) -> String {
+        format!("{}/audit", self.get_system_path())
+    }
+
+    pub fn get_audit_log_file_path(&self) -> String {
+        format!("{}/log", self.get_audit_path())
+    }
+
     pub fn get_state_messages_file_path(&self) -> String {
         format!("{}/log", self.get_state_path())
     }
@@ -222,6 +338,35 @@ impl SystemConfig {
         )
     }
 
+    pub fn get_data_directories(&self) -> Vec<String> {
+        let mut data_directories = vec![self.get_system_path()];
+        for data_directory in &self.data_directories {
+            if !data_directories.contains(data_directory) {
+                data_directories.push(data_directory.to_string());
+            }
+        }
+        data_directories
+    }
+
+    pub fn get_partition_path_in(
+        &self,
+        data_directory: &str,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> String {
+        format!(
+            "{}/{}/{}/{}/{}/{}/{}",
+            data_directory,
+            self.stream.path,
+            stream_id,
+            self.topic.path,
+            topic_id,
+            self.partition.path,
+            partition_id
+        )
+    }
+
     pub fn get_offsets_path(&self, stream_id: u32, topic_id: u32, partition_id: u32) -> String {
         format!(
             "{}/offsets",
@@ -253,6 +398,73 @@ impl SystemConfig {
         )
     }
 
+    pub fn get_producers_path(&self, stream_id: u32, topic_id: u32, partition_id: u32) -> String {
+        format!(
+            "{}/producers",
+            self.get_partition_path(stream_id, topic_id, partition_id)
+        )
+    }
+
+    pub fn get_delivery_attempts_path(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> String {
+        format!(
+            "{}/delivery_attempts",
+            self.get_partition_path(stream_id, topic_id, partition_id)
+        )
+    }
+
+    pub fn get_delayed_messages_path(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> String {
+        format!(
+            "{}/delayed",
+            self.get_partition_path(stream_id, topic_id, partition_id)
+        )
+    }
+
+    pub fn get_offloaded_segments_path(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> String {
+        format!(
+            "{}/offloaded_segments",
+            self.get_partition_path(stream_id, topic_id, partition_id)
+        )
+    }
+
+    pub fn get_transaction_markers_path(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> String {
+        format!(
+            "{}/transaction_markers",
+            self.get_partition_path(stream_id, topic_id, partition_id)
+        )
+    }
+
+    pub fn get_tiered_cache_path(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> String {
+        format!(
+            "{}/tiered_cache",
+            self.get_partition_path(stream_id, topic_id, partition_id)
+        )
+    }
+
     pub fn get_segment_path(
         &self,
         stream_id: u32,
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::configs::client_auth::ClientAuthConfig;
 use iggy_common::{IggyByteSize, IggyDuration};
 use serde::{Deserialize, Serialize};
 use serde_with::DisplayFromStr;
@@ -35,6 +36,7 @@ pub struct TcpTlsConfig {
     pub enabled: bool,
     pub certificate: String,
     pub password: String,
+    pub client_auth: ClientAuthConfig,
 }
 
 #[serde_as]
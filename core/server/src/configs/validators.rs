@@ -22,15 +22,18 @@ use super::server::{
     ArchiverConfig, DataMaintenanceConfig, MessageSaverConfig, MessagesMaintenanceConfig,
     StateMaintenanceConfig, TelemetryConfig,
 };
-use super::system::{CompressionConfig, MemoryPoolConfig, PartitionConfig};
+use super::system::{
+    AuditConfig, CompressionConfig, ExternalJwtConfig, MemoryPoolConfig, PartitionConfig,
+    TieredStorageConfig, TransactionConfig,
+};
 use crate::archiver::ArchiverKindType;
 use crate::configs::COMPONENT;
+use crate::configs::client_auth::ClientAuthConfig;
 use crate::configs::server::{PersonalAccessTokenConfig, ServerConfig};
 use crate::configs::system::SegmentConfig;
 use crate::server_error::ConfigError;
 use crate::streaming::segments::*;
 use error_set::ErrContext;
-use iggy_common::CompressionAlgorithm;
 use iggy_common::IggyExpiry;
 use iggy_common::MaxTopicSize;
 use iggy_common::Validatable;
@@ -65,9 +68,53 @@ impl Validatable<ConfigError> for ServerConfig {
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to validate compression config")
             })?;
+        self.system
+            .transaction
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate transaction config")
+            })?;
+        self.system
+            .tiered_storage
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate tiered storage config")
+            })?;
+        self.system.audit.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate audit config")
+        })?;
+        self.system
+            .external_jwt
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate external JWT config")
+            })?;
         self.telemetry.validate().with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to validate telemetry config")
         })?;
+        self.tcp
+            .tls
+            .client_auth
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate TCP client auth config")
+            })?;
+        self.quic
+            .client_auth
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate QUIC client auth config")
+            })?;
+
+        if self.tcp.tls.client_auth.enabled && !self.tcp.tls.enabled {
+            eprintln!("TCP client certificate authentication requires TLS to be enabled");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.system.tiered_storage.enabled && !self.data_maintenance.archiver.enabled {
+            eprintln!("Tiered storage requires the archiver to be enabled");
+            return Err(ConfigError::InvalidConfiguration);
+        }
 
         let topic_size = match self.system.topic.max_size {
             MaxTopicSize::Custom(size) => Ok(size.as_bytes_u64()),
@@ -79,6 +126,38 @@ impl Validatable<ConfigError> for ServerConfig {
             return Err(ConfigError::InvalidConfiguration);
         }
 
+        if let IggyExpiry::ServerDefault = self.system.segment.max_age {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if let MaxTopicSize::ServerDefault = self.system.partition.max_size {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if let MaxTopicSize::Custom(size) = self.system.partition.max_size
+            && size < self.system.segment.size
+        {
+            eprintln!(
+                "Configured system.partition.max_size {size} is lower than system.segment.size {}",
+                self.system.segment.size
+            );
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        for (index, data_directory) in self.system.data_directories.iter().enumerate() {
+            if data_directory.trim().is_empty() {
+                eprintln!("Configured system.data_directories contains an empty path");
+                return Err(ConfigError::InvalidConfiguration);
+            }
+
+            if self.system.data_directories[..index].contains(data_directory) {
+                eprintln!(
+                    "Configured system.data_directories contains duplicated path: {data_directory}"
+                );
+                return Err(ConfigError::InvalidConfiguration);
+            }
+        }
+
         if self.http.enabled {
             if let IggyExpiry::ServerDefault = self.http.jwt.access_token_expiry {
                 return Err(ConfigError::InvalidConfiguration);
@@ -95,13 +174,107 @@ impl Validatable<ConfigError> for ServerConfig {
 
 impl Validatable<ConfigError> for CompressionConfig {
     fn validate(&self) -> Result<(), ConfigError> {
-        let compression_alg = &self.default_algorithm;
-        if *compression_alg != CompressionAlgorithm::None {
-            // TODO(numinex): Change this message once server side compression is fully developed.
-            println!(
-                "Server started with server-side compression enabled, using algorithm: {}, this feature is not implemented yet!",
-                compression_alg
+        if !zstd::compression_level_range().contains(&self.zstd_level) {
+            eprintln!(
+                "Invalid zstd compression level: {}, it must be in range: {:?}",
+                self.zstd_level,
+                zstd::compression_level_range()
             );
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for TransactionConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.timeout.is_zero() {
+            eprintln!("Configured system.transaction.timeout cannot be zero");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for AuditConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.cleaner_interval.is_zero() {
+            eprintln!("Configured system.audit.cleaner_interval cannot be zero");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for ExternalJwtConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.issuers.is_empty() {
+            eprintln!("Configured system.external_jwt.issuers cannot be empty");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        for issuer in &self.issuers {
+            if issuer.jwks_file.is_none() == issuer.jwks_url.is_none() {
+                eprintln!(
+                    "Configured external JWT issuer: {} must have either the JWKS file or URL",
+                    issuer.issuer
+                );
+                return Err(ConfigError::InvalidConfiguration);
+            }
+
+            if issuer.user_claim.is_none() && issuer.claim_rules.is_empty() {
+                eprintln!(
+                    "Configured external JWT issuer: {} must have either the user claim or claim rules",
+                    issuer.issuer
+                );
+                return Err(ConfigError::InvalidConfiguration);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for ClientAuthConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.ca_file.is_empty() {
+            eprintln!("Configured client_auth.ca_file cannot be empty");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for TieredStorageConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.cache_segments == 0 {
+            eprintln!("Configured system.tiered_storage.cache_segments cannot be zero");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if let IggyExpiry::ServerDefault = self.remote_retention {
+            eprintln!("Configured system.tiered_storage.remote_retention cannot be server default");
+            return Err(ConfigError::InvalidConfiguration);
         }
 
         Ok(())
@@ -269,7 +442,7 @@ impl Validatable<ConfigError> for ArchiverConfig {
 
 impl Validatable<ConfigError> for MessagesMaintenanceConfig {
     fn validate(&self) -> Result<(), ConfigError> {
-        if self.archiver_enabled && self.interval.is_zero() {
+        if (self.archiver_enabled || self.compactor_enabled) && self.interval.is_zero() {
             return Err(ConfigError::InvalidConfiguration);
         }
 
@@ -0,0 +1,229 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::audit::{AuditEvent, AuditTarget};
+use crate::http::error::ErrorCode;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::shared::{AppState, RequestDetails};
+use axum::body::{Body, to_bytes};
+use axum::extract::{MatchedPath, Query, State};
+use axum::http::{Method, Request, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use iggy_common::*;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+
+const HTTP_TRANSPORT: &str = "HTTP";
+const MAX_AUDITED_BODY_SIZE: usize = 1024 * 1024;
+
+/// The audited routes with the codes of the corresponding commands.
+const AUDITED_ROUTES: &[(Method, &str, u32)] = &[
+    (Method::POST, "/streams", CREATE_STREAM_CODE),
+    (Method::PUT, "/streams/{stream_id}", UPDATE_STREAM_CODE),
+    (Method::DELETE, "/streams/{stream_id}", DELETE_STREAM_CODE),
+    (
+        Method::DELETE,
+        "/streams/{stream_id}/purge",
+        PURGE_STREAM_CODE,
+    ),
+    (
+        Method::POST,
+        "/streams/{stream_id}/topics",
+        CREATE_TOPIC_CODE,
+    ),
+    (
+        Method::PUT,
+        "/streams/{stream_id}/topics/{topic_id}",
+        UPDATE_TOPIC_CODE,
+    ),
+    (
+        Method::DELETE,
+        "/streams/{stream_id}/topics/{topic_id}",
+        DELETE_TOPIC_CODE,
+    ),
+    (
+        Method::DELETE,
+        "/streams/{stream_id}/topics/{topic_id}/purge",
+        PURGE_TOPIC_CODE,
+    ),
+    (
+        Method::POST,
+        "/streams/{stream_id}/topics/{topic_id}/partitions",
+        CREATE_PARTITIONS_CODE,
+    ),
+    (
+        Method::DELETE,
+        "/streams/{stream_id}/topics/{topic_id}/partitions",
+        DELETE_PARTITIONS_CODE,
+    ),
+    (
+        Method::POST,
+        "/streams/{stream_id}/topics/{topic_id}/consumer-groups",
+        CREATE_CONSUMER_GROUP_CODE,
+    ),
+    (
+        Method::DELETE,
+        "/streams/{stream_id}/topics/{topic_id}/consumer-groups/{group_id}",
+        DELETE_CONSUMER_GROUP_CODE,
+    ),
+    (
+        Method::POST,
+        "/streams/{stream_id}/topics/{topic_id}/schemas",
+        REGISTER_SCHEMA_CODE,
+    ),
+    (Method::DELETE, "/schemas/{schema_id}", DELETE_SCHEMA_CODE),
+    (Method::POST, "/users", CREATE_USER_CODE),
+    (Method::PUT, "/users/{user_id}", UPDATE_USER_CODE),
+    (Method::DELETE, "/users/{user_id}", DELETE_USER_CODE),
+    (
+        Method::PUT,
+        "/users/{user_id}/permissions",
+        UPDATE_PERMISSIONS_CODE,
+    ),
+    (
+        Method::PUT,
+        "/users/{user_id}/password",
+        CHANGE_PASSWORD_CODE,
+    ),
+    (Method::POST, "/users/login", LOGIN_USER_CODE),
+    (Method::POST, "/roles", CREATE_ROLE_CODE),
+    (Method::PUT, "/roles/{name}", UPDATE_ROLE_CODE),
+    (Method::DELETE, "/roles/{name}", DELETE_ROLE_CODE),
+    (Method::PUT, "/users/{user_id}/roles", ASSIGN_ROLES_CODE),
+    (Method::DELETE, "/users/logout", LOGOUT_USER_CODE),
+    (
+        Method::POST,
+        "/personal-access-tokens",
+        CREATE_PERSONAL_ACCESS_TOKEN_CODE,
+    ),
+    (
+        Method::DELETE,
+        "/personal-access-tokens/{name}",
+        DELETE_PERSONAL_ACCESS_TOKEN_CODE,
+    ),
+    (
+        Method::POST,
+        "/personal-access-tokens/login",
+        LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE,
+    ),
+];
+
+/// Records the audited requests in the audit log, with the target taken from the path, query and body of the request.
+pub async fn audit(
+    State(state): State<Arc<AppState>>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    let Some(code) = request
+        .extensions()
+        .get::<MatchedPath>()
+        .and_then(|path| get_audited_code(request.method(), path.as_str()))
+    else {
+        return next.run(request).await;
+    };
+
+    let audit = state.system.read().await.audit.clone();
+    if !audit.is_enabled() {
+        return next.run(request).await;
+    }
+
+    let user_id = request
+        .extensions()
+        .get::<Identity>()
+        .map(|identity| identity.user_id)
+        .unwrap_or_default();
+    let address = request
+        .extensions()
+        .get::<RequestDetails>()
+        .map(|details| details.ip_address)
+        .unwrap_or_else(|| SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0)));
+    let mut target = AuditTarget::default();
+    if let Some(path) = request.extensions().get::<MatchedPath>() {
+        for (segment, value) in path
+            .as_str()
+            .split('/')
+            .zip(request.uri().path().split('/'))
+        {
+            if let Some(key) = segment
+                .strip_prefix('{')
+                .and_then(|segment| segment.strip_suffix('}'))
+            {
+                target.add(key, value.to_string());
+            }
+        }
+    }
+    if let Ok(Query(query)) = Query::<HashMap<String, String>>::try_from_uri(request.uri()) {
+        for (key, value) in query {
+            target.add(&key, value);
+        }
+    }
+
+    let (parts, body) = request.into_parts();
+    let mut event = AuditEvent {
+        user_id,
+        address,
+        transport: HTTP_TRANSPORT.to_string(),
+        code,
+        target,
+        error_code: None,
+    };
+    let Ok(body) = to_bytes(body, MAX_AUDITED_BODY_SIZE).await else {
+        event.error_code = Some(IggyError::InvalidCommand.as_code());
+        audit.record(event).await;
+        return StatusCode::PAYLOAD_TOO_LARGE.into_response();
+    };
+
+    if let Ok(Value::Object(fields)) = serde_json::from_slice::<Value>(&body) {
+        for (key, value) in fields {
+            match value {
+                Value::String(value) => event.target.add(&key, value),
+                Value::Number(value) => event.target.add(&key, value.to_string()),
+                Value::Array(values) => {
+                    let values = values
+                        .iter()
+                        .filter_map(|value| value.as_str())
+                        .collect::<Vec<_>>();
+                    event.target.add(&key, values.join(","));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let response = next.run(Request::from_parts(parts, Body::from(body))).await;
+    if !response.status().is_success() {
+        let error_code = response
+            .extensions()
+            .get::<ErrorCode>()
+            .map(|error_code| error_code.0)
+            .unwrap_or(IggyError::Error.as_code());
+        event.error_code = Some(error_code);
+    }
+    audit.record(event).await;
+    response
+}
+
+fn get_audited_code(method: &Method, path: &str) -> Option<u32> {
+    AUDITED_ROUTES
+        .iter()
+        .find(|(route_method, route_path, _)| route_method == method && *route_path == path)
+        .map(|(_, _, code)| *code)
+}
@@ -26,14 +26,15 @@ use crate::state::models::CreateConsumerGroupWithId;
 use crate::streaming::session::Session;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
-use axum::routing::get;
+use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
 use error_set::ErrContext;
 use iggy_common::Identifier;
 use iggy_common::Validatable;
 use iggy_common::create_consumer_group::CreateConsumerGroup;
 use iggy_common::delete_consumer_group::DeleteConsumerGroup;
-use iggy_common::{ConsumerGroup, ConsumerGroupDetails};
+use iggy_common::reset_consumer_group_offsets::ResetConsumerGroupOffsets;
+use iggy_common::{ConsumerGroup, ConsumerGroupDetails, ConsumerGroupOffsetReset};
 use std::sync::Arc;
 use tracing::instrument;
 
@@ -47,6 +48,10 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/{stream_id}/topics/{topic_id}/consumer-groups/{group_id}",
             get(get_consumer_group).delete(delete_consumer_group),
         )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/consumer-groups/{group_id}/reset-offsets",
+            post(reset_consumer_group_offsets),
+        )
         .with_state(state)
 }
 
@@ -111,6 +116,7 @@ async fn create_consumer_group(
                 &command.topic_id,
                 command.group_id,
                 &command.name,
+                command.assignment_strategy,
             )
             .await
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to create consumer group, stream ID: {}, topic ID: {}, group ID: {:?}", stream_id, topic_id, command.group_id))?;
@@ -167,3 +173,34 @@ async fn delete_consumer_group(
 
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[instrument(skip_all, name = "trace_reset_consumer_group_offsets", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id, iggy_group_id = group_id))]
+async fn reset_consumer_group_offsets(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, group_id)): Path<(String, String, String)>,
+    Json(mut command): Json<ResetConsumerGroupOffsets>,
+) -> Result<Json<Vec<ConsumerGroupOffsetReset>>, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.group_id = Identifier::from_str_value(&group_id)?;
+    command.validate()?;
+    let system = state.system.read().await;
+    let reset = system
+            .reset_consumer_group_offsets(
+                &Session::stateless(identity.user_id, identity.ip_address),
+                &command.stream_id,
+                &command.topic_id,
+                &command.group_id,
+                command.target,
+                command.dry_run,
+                command.force,
+            )
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to reset consumer group offsets, stream ID: {stream_id}, topic ID: {topic_id}, group ID: {group_id}"))?;
+    for entry in reset.to_entry_commands() {
+        system.state.apply(identity.user_id, &entry).await?;
+    }
+
+    Ok(Json(reset.resets))
+}
@@ -20,10 +20,12 @@ use crate::http::COMPONENT;
 use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::shared::AppState;
+use crate::state::command::EntryCommand;
+use crate::state::models::DeleteConsumerOffsetWithIds;
 use crate::streaming::session::Session;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::{delete, get};
+use axum::routing::{delete, get, put};
 use axum::{Extension, Json, Router};
 use error_set::ErrContext;
 use iggy_common::Consumer;
@@ -33,6 +35,7 @@ use iggy_common::Validatable;
 use iggy_common::delete_consumer_offset::DeleteConsumerOffset;
 use iggy_common::get_consumer_offset::GetConsumerOffset;
 use iggy_common::store_consumer_offset::StoreConsumerOffset;
+use iggy_common::store_consumer_offsets::StoreConsumerOffsets;
 use std::sync::Arc;
 
 pub fn router(state: Arc<AppState>) -> Router {
@@ -41,6 +44,10 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/{stream_id}/topics/{topic_id}/consumer-offsets",
             get(get_consumer_offset).put(store_consumer_offset),
         )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/consumer-offsets/bulk",
+            put(store_consumer_offsets),
+        )
         .route(
             "/streams/{stream_id}/topics/{topic_id}/consumer-offsets/{consumer_id}",
             delete(delete_consumer_offset),
@@ -88,19 +95,45 @@ async fn store_consumer_offset(
     command.stream_id = Identifier::from_str_value(&stream_id)?;
     command.topic_id = Identifier::from_str_value(&topic_id)?;
     command.validate()?;
-    let consumer = Consumer::new(command.0.consumer.id);
+    let command = command.0;
+    let consumer = Consumer::new(command.consumer.id);
     let system = state.system.read().await;
     system
         .store_consumer_offset(
             &Session::stateless(identity.user_id, identity.ip_address),
             consumer,
-            &command.0.stream_id,
-            &command.0.topic_id,
-            command.0.partition_id,
-            command.0.offset,
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+            command.offset,
+            command.metadata,
+        )
+        .await
+        .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to store consumer offset, stream ID: {}, topic ID: {}, partition ID: {:?}", stream_id, topic_id, command.partition_id))?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn store_consumer_offsets(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    mut command: Json<StoreConsumerOffsets>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+    let command = command.0;
+    let system = state.system.read().await;
+    system
+        .store_consumer_offsets(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.consumer,
+            &command.stream_id,
+            &command.topic_id,
+            command.offsets.clone(),
         )
         .await
-        .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to store consumer offset, stream ID: {}, topic ID: {}, partition ID: {:?}", stream_id, topic_id, command.0.partition_id))?;
+        .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to store consumer offsets, stream ID: {}, topic ID: {}, offsets count: {}", stream_id, topic_id, command.offsets.len()))?;
     Ok(StatusCode::NO_CONTENT)
 }
 
@@ -112,15 +145,34 @@ async fn delete_consumer_offset(
 ) -> Result<StatusCode, CustomError> {
     let consumer = Consumer::new(consumer_id.try_into()?);
     let system = state.system.read().await;
-    system
+    let deleted_offset = system
         .delete_consumer_offset(
             &Session::stateless(identity.user_id, identity.ip_address),
-            consumer,
+            consumer.clone(),
             &query.stream_id,
             &query.topic_id,
             query.partition_id,
         )
         .await
         .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to delete consumer offset, stream ID: {}, topic ID: {}, partition ID: {:?}", stream_id, topic_id, query.partition_id))?;
+    let query = query.0;
+    system
+        .state
+        .apply(
+            identity.user_id,
+            &EntryCommand::DeleteConsumerOffset(DeleteConsumerOffsetWithIds {
+                stream_id: deleted_offset.stream_id,
+                topic_id: deleted_offset.topic_id,
+                consumer_id: deleted_offset.consumer_id,
+                partition_id: deleted_offset.partition_id,
+                command: DeleteConsumerOffset { consumer, ..query },
+            }),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply delete consumer offset, stream ID: {stream_id}, topic ID: {topic_id}"
+            )
+        })?;
     Ok(StatusCode::NO_CONTENT)
 }
@@ -32,6 +32,10 @@ pub enum CustomError {
     ResourceNotFound,
 }
 
+/// The code of the error returned by the handler, attached to the response extensions.
+#[derive(Debug, Clone, Copy)]
+pub struct ErrorCode(pub u32);
+
 #[derive(Debug, Serialize)]
 pub struct ErrorResponse {
     pub id: u32,
@@ -42,7 +46,11 @@ pub struct ErrorResponse {
 
 impl IntoResponse for CustomError {
     fn into_response(self) -> Response {
-        match self {
+        let error_code = match &self {
+            CustomError::Error(error) => error.as_code(),
+            CustomError::ResourceNotFound => IggyError::ResourceNotFound(String::new()).as_code(),
+        };
+        let mut response = match self {
             CustomError::Error(error) => {
                 error!("There was an error: {error}");
                 let status_code = match error {
@@ -55,6 +63,10 @@ impl IntoResponse for CustomError {
                     IggyError::ConsumerGroupNameNotFound(_, _) => StatusCode::NOT_FOUND,
                     IggyError::ConsumerGroupMemberNotFound(_, _, _) => StatusCode::NOT_FOUND,
                     IggyError::ConsumerOffsetNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::TransactionNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::ProducerNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::RoleNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::SchemaNotFound(_) => StatusCode::NOT_FOUND,
                     IggyError::ResourceNotFound(_) => StatusCode::NOT_FOUND,
                     IggyError::Unauthenticated => StatusCode::UNAUTHORIZED,
                     IggyError::AccessTokenMissing => StatusCode::UNAUTHORIZED,
@@ -75,7 +87,9 @@ impl IntoResponse for CustomError {
                 }),
             ),
         }
-        .into_response()
+        .into_response();
+        response.extensions_mut().insert(ErrorCode(error_code));
+        response
     }
 }
 
@@ -107,6 +121,14 @@ impl ErrorResponse {
                 IggyError::ConsumerGroupNameAlreadyExists(_, _) => Some("name".to_string()),
                 IggyError::UserAlreadyExists => Some("username".to_string()),
                 IggyError::PersonalAccessTokenAlreadyExists(_, _) => Some("name".to_string()),
+                IggyError::InvalidRoleName => Some("name".to_string()),
+                IggyError::RoleAlreadyExists(_) => Some("name".to_string()),
+                IggyError::InvalidResourcePattern(_) => Some("resource".to_string()),
+                IggyError::SchemaNotFound(_) => Some("schema_id".to_string()),
+                IggyError::InvalidSchemaType => Some("schema_type".to_string()),
+                IggyError::InvalidSchemaCompatibility => Some("compatibility".to_string()),
+                IggyError::InvalidSchemaDefinition(_) => Some("definition".to_string()),
+                IggyError::IncompatibleSchema(_) => Some("definition".to_string()),
                 _ => None,
             },
         }
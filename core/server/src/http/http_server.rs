@@ -17,6 +17,7 @@
  */
 
 use crate::configs::http::{HttpConfig, HttpCorsConfig};
+use crate::http::audit::audit;
 use crate::http::diagnostics::request_diagnostics;
 use crate::http::jwt::cleaner::start_expired_tokens_cleaner;
 use crate::http::jwt::jwt_manager::JwtManager;
@@ -49,15 +50,20 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
         .merge(system::router(app_state.clone(), &config.metrics))
         .merge(personal_access_tokens::router(app_state.clone()))
         .merge(users::router(app_state.clone()))
+        .merge(roles::router(app_state.clone()))
         .merge(streams::router(app_state.clone()))
         .merge(topics::router(app_state.clone()))
         .merge(consumer_groups::router(app_state.clone()))
         .merge(consumer_offsets::router(app_state.clone()))
         .merge(partitions::router(app_state.clone()))
         .merge(messages::router(app_state.clone()))
+        .merge(transactions::router(app_state.clone()))
+        .merge(producers::router(app_state.clone()))
+        .merge(schemas::router(app_state.clone()))
         .layer(DefaultBodyLimit::max(
             config.max_request_size.as_bytes_u64() as usize,
         ))
+        .layer(middleware::from_fn_with_state(app_state.clone(), audit))
         .layer(middleware::from_fn_with_state(app_state.clone(), jwt_auth));
 
     if config.cors.enabled {
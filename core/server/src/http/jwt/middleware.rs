@@ -39,6 +39,7 @@ const PUBLIC_PATHS: &[&str] = &[
     "/ping",
     "/stats",
     "/users/login",
+    "/users/login-with-token",
     "/users/refresh-token",
     "/personal-access-tokens/login",
 ];
@@ -70,6 +71,40 @@ pub async fn jwt_auth(
     }
 
     let jwt_token = &bearer[BEARER.len()..];
+    let request_details = request.extensions().get::<RequestDetails>().unwrap();
+    let ip_address = request_details.ip_address;
+    let external_jwt = state.system.read().await.external_jwt.clone();
+    if external_jwt.is_external_token(jwt_token) {
+        let external_identity = state
+            .system
+            .validate_external_token(jwt_token)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate external JWT")
+            })
+            .map_err(|_| UNAUTHORIZED)?;
+        let system = state.system.read().await;
+        let user = system
+            .authenticate_with_external_identity(&external_identity)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to authenticate with external JWT")
+            })
+            .map_err(|_| UNAUTHORIZED)?;
+        let identity = Identity {
+            token_id: external_identity.token_id,
+            token_expiry: external_identity.expiry,
+            user_id: user.id,
+            ip_address,
+        };
+        drop(system);
+        if state.jwt_manager.is_token_revoked(&identity.token_id).await {
+            return Err(StatusCode::UNAUTHORIZED);
+        }
+
+        request.extensions_mut().insert(identity);
+        return Ok(next.run(request).await);
+    }
+
     let token_header = jsonwebtoken::decode_header(jwt_token)
         .with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to decode JWT header")
@@ -90,12 +125,11 @@ pub async fn jwt_auth(
         return Err(StatusCode::UNAUTHORIZED);
     }
 
-    let request_details = request.extensions().get::<RequestDetails>().unwrap();
     let identity = Identity {
         token_id: jwt_claims.claims.jti,
         token_expiry: jwt_claims.claims.exp,
         user_id: jwt_claims.claims.sub,
-        ip_address: request_details.ip_address,
+        ip_address,
     };
     request.extensions_mut().insert(identity);
     Ok(next.run(request).await)
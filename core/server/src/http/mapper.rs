@@ -82,6 +82,10 @@ pub fn map_topics(topics: &[&Topic]) -> Vec<iggy_common::Topic> {
             compression_algorithm: topic.compression_algorithm,
             max_topic_size: topic.max_topic_size,
             replication_factor: topic.replication_factor,
+            cleanup_policy: topic.cleanup_policy,
+            partitioning_hash: topic.partitioning_hash,
+            segment_max_age: topic.segment_max_age,
+            max_partition_size: topic.max_partition_size,
         };
         topics_data.push(topic);
     }
@@ -102,6 +106,10 @@ pub async fn map_topic(topic: &Topic) -> TopicDetails {
         compression_algorithm: topic.compression_algorithm,
         max_topic_size: topic.max_topic_size,
         replication_factor: topic.replication_factor,
+        cleanup_policy: topic.cleanup_policy,
+        partitioning_hash: topic.partitioning_hash,
+        segment_max_age: topic.segment_max_age,
+        max_partition_size: topic.max_partition_size,
     };
     for partition in topic.get_partitions() {
         let partition = partition.read().await;
@@ -125,6 +133,8 @@ pub fn map_user(user: &User) -> UserInfoDetails {
         created_at: user.created_at,
         status: user.status,
         permissions: user.permissions.clone(),
+        quotas: user.quotas,
+        roles: user.roles.clone(),
     }
 }
 
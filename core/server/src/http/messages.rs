@@ -25,7 +25,7 @@ use crate::streaming::session::Session;
 use crate::streaming::systems::messages::PollingArgs;
 use crate::streaming::utils::PooledBuffer;
 use axum::extract::{Path, Query, State};
-use axum::http::StatusCode;
+use axum::http::{HeaderMap, HeaderValue, StatusCode};
 use axum::routing::get;
 use axum::{Extension, Json, Router};
 use error_set::ErrContext;
@@ -34,6 +34,7 @@ use iggy_common::IggyMessagesBatch;
 use iggy_common::Validatable;
 use iggy_common::{Consumer, PollMessages, PolledMessages, SendMessages};
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::instrument;
 
 pub fn router(state: Arc<AppState>) -> Router {
@@ -49,26 +50,33 @@ pub fn router(state: Arc<AppState>) -> Router {
         .with_state(state)
 }
 
+/// The response header containing the delay (in milliseconds) applied to the request exceeding the quotas.
+const THROTTLE_TIME_HEADER: &str = "iggy-throttle-time-ms";
+
 async fn poll_messages(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
     Path((stream_id, topic_id)): Path<(String, String)>,
     mut query: Query<PollMessages>,
-) -> Result<Json<PolledMessages>, CustomError> {
+) -> Result<(HeaderMap, Json<PolledMessages>), CustomError> {
     query.stream_id = Identifier::from_str_value(&stream_id)?;
     query.topic_id = Identifier::from_str_value(&topic_id)?;
     query.validate()?;
 
     let consumer = Consumer::new(query.0.consumer.id);
-    let system = state.system.read().await;
-    let (metadata, messages) = system
-        .poll_messages(
-            &Session::stateless(identity.user_id, identity.ip_address),
+    let session = Session::stateless(identity.user_id, identity.ip_address);
+    let (system, metadata, messages) = state
+        .system
+        .long_poll_messages(
+            &session,
             &consumer,
             &query.0.stream_id,
             &query.0.topic_id,
             query.0.partition_id,
-            PollingArgs::new(query.0.strategy, query.0.count, query.0.auto_commit),
+            PollingArgs::new(query.0.strategy, query.0.count, query.0.auto_commit)
+                .with_filter(query.0.filter.clone())
+                .with_isolation_level(query.0.isolation_level)
+                .with_long_polling(query.0.max_wait, query.0.min_bytes),
         )
         .await
         .with_error_context(|error| {
@@ -77,8 +85,11 @@ async fn poll_messages(
                 stream_id, topic_id, query.0.partition_id
             )
         })?;
+    let throttle_delay = system.throttle_consumed(&session, messages.size() as u64);
+    drop(system);
+
     let polled_messages = messages.into_polled_messages(metadata);
-    Ok(Json(polled_messages))
+    Ok((throttle(throttle_delay).await, Json(polled_messages)))
 }
 
 async fn send_messages(
@@ -86,35 +97,70 @@ async fn send_messages(
     Extension(identity): Extension<Identity>,
     Path((stream_id, topic_id)): Path<(String, String)>,
     Json(mut command): Json<SendMessages>,
-) -> Result<StatusCode, CustomError> {
+) -> Result<(StatusCode, HeaderMap), CustomError> {
     command.stream_id = Identifier::from_str_value(&stream_id)?;
     command.topic_id = Identifier::from_str_value(&topic_id)?;
     command.partitioning.length = command.partitioning.value.len() as u8;
     command.validate()?;
 
     let batch = make_mutable(command.batch);
+    let messages_count = batch.count();
+    let messages_size = batch.size() as u64;
     let command_stream_id = command.stream_id;
     let command_topic_id = command.topic_id;
     let partitioning = command.partitioning;
+    let session = Session::stateless(identity.user_id, identity.ip_address);
     let system = state.system.read().await;
 
-    system
-        .append_messages(
-            &Session::stateless(identity.user_id, identity.ip_address),
-            &command_stream_id,
-            &command_topic_id,
-            &partitioning,
-            batch,
-            None,
-        )
-        .await
-        .with_error_context(|error| {
-            format!(
-                "{COMPONENT} (error: {error}) - failed to append messages, stream ID: {}, topic ID: {}",
-                stream_id, topic_id
+    if let Some(producer) = &command.producer {
+        system
+            .append_idempotent_messages(
+                &session,
+                &command_stream_id,
+                &command_topic_id,
+                &partitioning,
+                producer,
+                batch,
+                None,
             )
-        })?;
-    Ok(StatusCode::CREATED)
+            .await
+    } else {
+        system
+            .append_messages(
+                &session,
+                &command_stream_id,
+                &command_topic_id,
+                &partitioning,
+                batch,
+                None,
+            )
+            .await
+    }
+    .with_error_context(|error| {
+        format!(
+            "{COMPONENT} (error: {error}) - failed to append messages, stream ID: {}, topic ID: {}",
+            stream_id, topic_id
+        )
+    })?;
+    let throttle_delay = system.throttle_produced(&session, messages_count, messages_size);
+    drop(system);
+
+    Ok((StatusCode::CREATED, throttle(throttle_delay).await))
+}
+
+/// Delays the response exceeding the quotas and returns the headers with the applied delay.
+async fn throttle(delay: Duration) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    if delay.is_zero() {
+        return headers;
+    }
+
+    tokio::time::sleep(delay).await;
+    headers.insert(
+        THROTTLE_TIME_HEADER,
+        HeaderValue::from(delay.as_millis() as u64),
+    );
+    headers
 }
 
 #[instrument(skip_all, name = "trace_flush_unsaved_buffer", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id, iggy_partition_id = partition_id, iggy_fsync = fsync))]
@@ -138,7 +184,7 @@ async fn flush_unsaved_buffer(
     Ok(StatusCode::OK)
 }
 
-fn make_mutable(batch: IggyMessagesBatch) -> IggyMessagesBatchMut {
+pub(crate) fn make_mutable(batch: IggyMessagesBatch) -> IggyMessagesBatchMut {
     let (_, indexes, messages) = batch.decompose();
     let (_, indexes_buffer) = indexes.decompose();
     let indexes_buffer_mut = PooledBuffer::from_existing(indexes_buffer.into());
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+pub mod audit;
 pub mod consumer_groups;
 pub mod consumer_offsets;
 pub mod diagnostics;
@@ -27,10 +28,14 @@ pub mod messages;
 pub mod metrics;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod producers;
+pub mod roles;
+pub mod schemas;
 mod shared;
 pub mod streams;
 pub mod system;
 pub mod topics;
+pub mod transactions;
 pub mod users;
 
 pub const COMPONENT: &str = "HTTP";
@@ -21,16 +21,18 @@ use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::shared::AppState;
 use crate::state::command::EntryCommand;
+use crate::streaming::partitions::partition::Partition;
 use crate::streaming::session::Session;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::post;
+use axum::routing::{post, put};
 use axum::{Extension, Json, Router};
 use error_set::ErrContext;
 use iggy_common::Identifier;
 use iggy_common::Validatable;
 use iggy_common::create_partitions::CreatePartitions;
 use iggy_common::delete_partitions::DeletePartitions;
+use iggy_common::move_partition::MovePartition;
 use std::sync::Arc;
 use tracing::instrument;
 
@@ -40,6 +42,10 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/{stream_id}/topics/{topic_id}/partitions",
             post(create_partitions).delete(delete_partitions),
         )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/partitions/{partition_id}/data_directory",
+            put(move_partition),
+        )
         .with_state(state)
 }
 
@@ -131,3 +137,43 @@ async fn delete_partitions(
         })?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[instrument(skip_all, name = "trace_move_partition", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id, iggy_partition_id = partition_id))]
+async fn move_partition(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, partition_id)): Path<(String, String, u32)>,
+    Json(mut command): Json<MovePartition>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.partition_id = partition_id;
+    command.validate()?;
+
+    let partition = {
+        let system = state.system.read().await;
+        system
+            .find_partition_to_move(
+                &Session::stateless(identity.user_id, identity.ip_address),
+                &command.stream_id,
+                &command.topic_id,
+                command.partition_id,
+                &command.data_directory,
+            )
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to find partition with ID: {partition_id} to move, stream ID: {stream_id}, topic ID: {topic_id}"
+                )
+            })?
+    };
+
+    Partition::move_to_data_directory(&partition, &command.data_directory)
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to move partition with ID: {partition_id} to data directory: {}, stream ID: {stream_id}, topic ID: {topic_id}",
+                command.data_directory
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
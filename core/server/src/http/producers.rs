@@ -0,0 +1,80 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::COMPONENT;
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::shared::AppState;
+use crate::state::command::EntryCommand;
+use crate::state::models::InitProducerWithEpoch;
+use crate::streaming::session::Session;
+use axum::extract::State;
+use axum::routing::post;
+use axum::{Extension, Json, Router};
+use error_set::ErrContext;
+use iggy_common::ProducerInfo;
+use iggy_common::Validatable;
+use iggy_common::init_producer::InitProducer;
+use std::sync::Arc;
+use tracing::instrument;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/producers", post(init_producer))
+        .with_state(state)
+}
+
+#[instrument(skip_all, name = "trace_init_producer", fields(iggy_user_id = identity.user_id))]
+async fn init_producer(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<InitProducer>,
+) -> Result<Json<ProducerInfo>, CustomError> {
+    command.validate()?;
+    let system = state.system.read().await;
+    let producer = system
+        .init_producer(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            command.producer_id,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to initialize producer with ID: {}",
+                command.producer_id
+            )
+        })?;
+    system
+        .state
+        .apply(
+            identity.user_id,
+            &EntryCommand::InitProducer(InitProducerWithEpoch {
+                producer_id: producer.id,
+                epoch: producer.epoch,
+                command,
+            }),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply init producer with ID: {}",
+                producer.id
+            )
+        })?;
+    Ok(Json(producer))
+}
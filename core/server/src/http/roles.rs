@@ -0,0 +1,200 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::COMPONENT;
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::shared::AppState;
+use crate::state::command::EntryCommand;
+use crate::streaming::session::Session;
+use ::iggy_common::assign_roles::AssignRoles;
+use ::iggy_common::create_role::CreateRole;
+use ::iggy_common::delete_role::DeleteRole;
+use ::iggy_common::update_role::UpdateRole;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{get, put};
+use axum::{Extension, Json, Router};
+use error_set::ErrContext;
+use iggy_common::Identifier;
+use iggy_common::Role;
+use iggy_common::Validatable;
+use std::sync::Arc;
+use tracing::instrument;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/roles", get(get_roles).post(create_role))
+        .route("/roles/{name}", put(update_role).delete(delete_role))
+        .route("/users/{user_id}/roles", put(assign_roles))
+        .with_state(state)
+}
+
+async fn get_roles(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<Vec<Role>>, CustomError> {
+    let system = state.system.read().await;
+    let mut roles = system
+        .get_roles(&Session::stateless(identity.user_id, identity.ip_address))
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get roles, user ID: {}",
+                identity.user_id
+            )
+        })?
+        .into_iter()
+        .cloned()
+        .collect::<Vec<_>>();
+    roles.sort_by(|x, y| x.name.cmp(&y.name));
+    Ok(Json(roles))
+}
+
+#[instrument(skip_all, name = "trace_create_role", fields(iggy_user_id = identity.user_id))]
+async fn create_role(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<CreateRole>,
+) -> Result<StatusCode, CustomError> {
+    command.validate()?;
+
+    let mut system = state.system.write().await;
+    system
+        .create_role(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.name,
+            command.rules.clone(),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to create role, name: {}",
+                command.name
+            )
+        })?;
+
+    let system = system.downgrade();
+    let name = command.name.clone();
+    system
+        .state
+        .apply(identity.user_id, &EntryCommand::CreateRole(command))
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to apply create role, name: {name}")
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[instrument(skip_all, name = "trace_update_role", fields(iggy_user_id = identity.user_id, iggy_role = name))]
+async fn update_role(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(name): Path<String>,
+    Json(mut command): Json<UpdateRole>,
+) -> Result<StatusCode, CustomError> {
+    command.name = name.clone();
+    command.validate()?;
+
+    let mut system = state.system.write().await;
+    system
+        .update_role(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.name,
+            command.rules.clone(),
+        )
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to update role, name: {name}")
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(identity.user_id, &EntryCommand::UpdateRole(command))
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to apply update role, name: {name}")
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[instrument(skip_all, name = "trace_delete_role", fields(iggy_user_id = identity.user_id, iggy_role = name))]
+async fn delete_role(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(name): Path<String>,
+) -> Result<StatusCode, CustomError> {
+    let command = DeleteRole { name: name.clone() };
+    command.validate()?;
+
+    let mut system = state.system.write().await;
+    system
+        .delete_role(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.name,
+        )
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to delete role, name: {name}")
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(identity.user_id, &EntryCommand::DeleteRole(command))
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to apply delete role, name: {name}")
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[instrument(skip_all, name = "trace_assign_roles", fields(iggy_user_id = identity.user_id, iggy_updated_user_id = user_id))]
+async fn assign_roles(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(user_id): Path<String>,
+    Json(mut command): Json<AssignRoles>,
+) -> Result<StatusCode, CustomError> {
+    command.user_id = Identifier::from_str_value(&user_id)?;
+    command.validate()?;
+
+    let mut system = state.system.write().await;
+    system
+        .assign_roles(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.user_id,
+            command.roles.clone(),
+        )
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to assign roles, user ID: {user_id}")
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(identity.user_id, &EntryCommand::AssignRoles(command))
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply assign roles, user ID: {user_id}"
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
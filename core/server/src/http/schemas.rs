@@ -0,0 +1,177 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::COMPONENT;
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::shared::AppState;
+use crate::state::command::EntryCommand;
+use crate::state::models::RegisterSchemaWithIds;
+use crate::streaming::session::Session;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{Extension, Json, Router};
+use error_set::ErrContext;
+use iggy_common::Identifier;
+use iggy_common::Schema;
+use iggy_common::Validatable;
+use iggy_common::delete_schema::DeleteSchema;
+use iggy_common::register_schema::RegisterSchema;
+use std::sync::Arc;
+use tracing::instrument;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/schemas",
+            get(get_schemas).post(register_schema),
+        )
+        .route(
+            "/schemas/{schema_id}",
+            get(get_schema).delete(delete_schema),
+        )
+        .with_state(state)
+}
+
+async fn get_schema(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(schema_id): Path<u32>,
+) -> Result<Json<Schema>, CustomError> {
+    let system = state.system.read().await;
+    let Ok(schema) = system.get_schema(
+        &Session::stateless(identity.user_id, identity.ip_address),
+        schema_id,
+    ) else {
+        return Err(CustomError::ResourceNotFound);
+    };
+    let Some(schema) = schema else {
+        return Err(CustomError::ResourceNotFound);
+    };
+
+    Ok(Json(schema.clone()))
+}
+
+async fn get_schemas(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+) -> Result<Json<Vec<Schema>>, CustomError> {
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let topic_id = Identifier::from_str_value(&topic_id)?;
+    let system = state.system.read().await;
+    let schemas = system
+        .get_schemas(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &stream_id,
+            &topic_id,
+        )
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get schemas, stream ID: {stream_id}, topic ID: {topic_id}"
+            )
+        })?
+        .into_iter()
+        .cloned()
+        .collect();
+    Ok(Json(schemas))
+}
+
+#[instrument(skip_all, name = "trace_register_schema", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn register_schema(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<RegisterSchema>,
+) -> Result<(StatusCode, Json<Schema>), CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+
+    let mut system = state.system.write().await;
+    let schema = system
+        .register_schema(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.stream_id,
+            &command.topic_id,
+            command.schema_type,
+            command.compatibility,
+            command.definition.clone(),
+        )
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to register schema, stream ID: {stream_id}, topic ID: {topic_id}"
+            )
+        })?
+        .clone();
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(
+            identity.user_id,
+            &EntryCommand::RegisterSchema(RegisterSchemaWithIds {
+                schema_id: schema.id,
+                version: schema.version,
+                stream_id: schema.stream_id,
+                topic_id: schema.topic_id,
+                command,
+            }),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply register schema with ID: {}, stream ID: {stream_id}, topic ID: {topic_id}",
+                schema.id
+            )
+        })?;
+    Ok((StatusCode::CREATED, Json(schema)))
+}
+
+#[instrument(skip_all, name = "trace_delete_schema", fields(iggy_user_id = identity.user_id, iggy_schema_id = schema_id))]
+async fn delete_schema(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(schema_id): Path<u32>,
+) -> Result<StatusCode, CustomError> {
+    let mut system = state.system.write().await;
+    system
+        .delete_schema(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            schema_id,
+        )
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to delete schema with ID: {schema_id}")
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(
+            identity.user_id,
+            &EntryCommand::DeleteSchema(DeleteSchema { schema_id }),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply delete schema with ID: {schema_id}"
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
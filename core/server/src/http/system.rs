@@ -24,7 +24,7 @@ use crate::http::mapper;
 use crate::http::shared::AppState;
 use crate::streaming::session::Session;
 use axum::body::Body;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::{HeaderMap, header};
 use axum::response::IntoResponse;
 use axum::routing::{get, post};
@@ -34,9 +34,11 @@ use chrono::Local;
 use error_set::ErrContext;
 use iggy_common::Stats;
 use iggy_common::Validatable;
+use iggy_common::export_metadata::ExportMetadata;
+use iggy_common::get_audit_log::GetAuditLog;
 use iggy_common::get_snapshot::GetSnapshot;
 use iggy_common::locking::IggySharedMutFn;
-use iggy_common::{ClientInfo, ClientInfoDetails};
+use iggy_common::{AuditRecord, ClientInfo, ClientInfoDetails, ServerMetadata};
 use std::sync::Arc;
 
 const NAME: &str = "Iggy API";
@@ -49,7 +51,9 @@ pub fn router(state: Arc<AppState>, metrics_config: &HttpMetricsConfig) -> Route
         .route("/stats", get(get_stats))
         .route("/clients", get(get_clients))
         .route("/clients/{client_id}", get(get_client))
-        .route("/snapshot", post(get_snapshot));
+        .route("/snapshot", post(get_snapshot))
+        .route("/audit-log", get(get_audit_log))
+        .route("/metadata", get(export_metadata));
     if metrics_config.enabled {
         router = router.route(&metrics_config.endpoint, get(get_metrics));
     }
@@ -59,6 +63,7 @@ pub fn router(state: Arc<AppState>, metrics_config: &HttpMetricsConfig) -> Route
 
 async fn get_metrics(State(state): State<Arc<AppState>>) -> Result<String, CustomError> {
     let system = state.system.read().await;
+    system.refresh_metrics().await;
     Ok(system.metrics.get_formatted_output())
 }
 
@@ -118,6 +123,51 @@ async fn get_clients(
     Ok(Json(clients))
 }
 
+async fn get_audit_log(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Query(query): Query<GetAuditLog>,
+) -> Result<Json<Vec<AuditRecord>>, CustomError> {
+    query.validate()?;
+    let system = state.system.read().await;
+    let records = system
+        .get_audit_log(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            query.start_id,
+            query.count,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get audit log, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(Json(records))
+}
+
+async fn export_metadata(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Query(query): Query<ExportMetadata>,
+) -> Result<Json<ServerMetadata>, CustomError> {
+    query.validate()?;
+    let system = state.system.read().await;
+    let metadata = system
+        .export_metadata(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            query.include_password_hashes,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to export metadata, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(Json(metadata))
+}
+
 async fn get_snapshot(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -123,6 +123,10 @@ async fn create_topic(
             command.compression_algorithm,
             command.max_topic_size,
             command.replication_factor,
+            command.cleanup_policy,
+            command.partitioning_hash,
+            command.segment_max_age,
+            command.max_partition_size,
         )
         .await
         .with_error_context(|error| {
@@ -133,6 +137,8 @@ async fn create_topic(
         })?;
     command.message_expiry = topic.message_expiry;
     command.max_topic_size = topic.max_topic_size;
+    command.segment_max_age = topic.segment_max_age;
+    command.max_partition_size = topic.max_partition_size;
     let topic_id = topic.topic_id;
     let response = Json(mapper::map_topic(topic).await);
 
@@ -174,6 +180,10 @@ async fn update_topic(
                 command.compression_algorithm,
                 command.max_topic_size,
                 command.replication_factor,
+                command.cleanup_policy,
+                command.partitioning_hash,
+                command.segment_max_age,
+                command.max_partition_size,
             )
             .await
             .with_error_context(|error| {
@@ -184,6 +194,8 @@ async fn update_topic(
             })?;
     command.message_expiry = topic.message_expiry;
     command.max_topic_size = topic.max_topic_size;
+    command.segment_max_age = topic.segment_max_age;
+    command.max_partition_size = topic.max_partition_size;
 
     let system = system.downgrade();
     system
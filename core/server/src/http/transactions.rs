@@ -0,0 +1,145 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::COMPONENT;
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::messages::make_mutable;
+use crate::http::shared::AppState;
+use crate::streaming::session::Session;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::post;
+use axum::{Extension, Json, Router};
+use error_set::ErrContext;
+use iggy_common::Identifier;
+use iggy_common::Validatable;
+use iggy_common::begin_transaction::BeginTransaction;
+use iggy_common::{SendMessages, TransactionInfo};
+use std::sync::Arc;
+use tracing::instrument;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/transactions", post(begin_transaction))
+        .route(
+            "/transactions/{transaction_id}/streams/{stream_id}/topics/{topic_id}/messages",
+            post(send_transaction_messages),
+        )
+        .route(
+            "/transactions/{transaction_id}/commit",
+            post(commit_transaction),
+        )
+        .route(
+            "/transactions/{transaction_id}/abort",
+            post(abort_transaction),
+        )
+        .with_state(state)
+}
+
+#[instrument(skip_all, name = "trace_begin_transaction", fields(iggy_user_id = identity.user_id))]
+async fn begin_transaction(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<BeginTransaction>,
+) -> Result<Json<TransactionInfo>, CustomError> {
+    command.validate()?;
+    let system = state.system.read().await;
+    let transaction_id = system
+        .begin_transaction(&Session::stateless(identity.user_id, identity.ip_address))
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to begin transaction")
+        })?;
+    Ok(Json(TransactionInfo { id: transaction_id }))
+}
+
+#[instrument(skip_all, name = "trace_send_transaction_messages", fields(iggy_user_id = identity.user_id, iggy_transaction_id = transaction_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn send_transaction_messages(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((transaction_id, stream_id, topic_id)): Path<(u64, String, String)>,
+    Json(mut command): Json<SendMessages>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.partitioning.length = command.partitioning.value.len() as u8;
+    command.validate()?;
+
+    let batch = make_mutable(command.batch);
+    let system = state.system.read().await;
+    system
+        .append_transaction_messages(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            transaction_id,
+            &command.stream_id,
+            &command.topic_id,
+            &command.partitioning,
+            batch,
+            None,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to append messages of transaction with ID: {transaction_id}, stream ID: {stream_id}, topic ID: {topic_id}"
+            )
+        })?;
+    Ok(StatusCode::CREATED)
+}
+
+#[instrument(skip_all, name = "trace_commit_transaction", fields(iggy_user_id = identity.user_id, iggy_transaction_id = transaction_id))]
+async fn commit_transaction(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(transaction_id): Path<u64>,
+) -> Result<StatusCode, CustomError> {
+    let system = state.system.read().await;
+    system
+        .commit_transaction(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            transaction_id,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to commit transaction with ID: {transaction_id}"
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[instrument(skip_all, name = "trace_abort_transaction", fields(iggy_user_id = identity.user_id, iggy_transaction_id = transaction_id))]
+async fn abort_transaction(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(transaction_id): Path<u64>,
+) -> Result<StatusCode, CustomError> {
+    let system = state.system.read().await;
+    system
+        .abort_transaction(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            transaction_id,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to abort transaction with ID: {transaction_id}"
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
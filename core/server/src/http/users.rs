@@ -29,7 +29,9 @@ use crate::streaming::utils::crypto;
 use ::iggy_common::change_password::ChangePassword;
 use ::iggy_common::create_user::CreateUser;
 use ::iggy_common::delete_user::DeleteUser;
+use ::iggy_common::import_user::ImportUser;
 use ::iggy_common::login_user::LoginUser;
+use ::iggy_common::login_with_token::LoginWithToken;
 use ::iggy_common::update_permissions::UpdatePermissions;
 use ::iggy_common::update_user::UpdateUser;
 use axum::extract::{Path, State};
@@ -54,7 +56,9 @@ pub fn router(state: Arc<AppState>) -> Router {
         )
         .route("/users/{user_id}/permissions", put(update_permissions))
         .route("/users/{user_id}/password", put(change_password))
+        .route("/users/import", post(import_user))
         .route("/users/login", post(login_user))
+        .route("/users/login-with-token", post(login_with_token))
         .route("/users/logout", delete(logout_user))
         .route("/users/refresh-token", post(refresh_token))
         .with_state(state)
@@ -115,6 +119,7 @@ async fn create_user(
             &command.password,
             command.status,
             command.permissions.clone(),
+            command.quotas,
         )
         .await
         .with_error_context(|error| {
@@ -139,6 +144,7 @@ async fn create_user(
                     password: crypto::hash_password(&command.password),
                     status: command.status,
                     permissions: command.permissions.clone(),
+                    quotas: command.quotas,
                 },
             }),
         )
@@ -153,6 +159,61 @@ async fn create_user(
     Ok(response)
 }
 
+#[instrument(skip_all, name = "trace_import_user", fields(iggy_user_id = identity.user_id))]
+async fn import_user(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<ImportUser>,
+) -> Result<Json<UserInfoDetails>, CustomError> {
+    command.validate()?;
+
+    let mut system = state.system.write().await;
+    let user = system
+        .import_user(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.username,
+            command.password_hash.clone(),
+            command.status,
+            command.permissions.clone(),
+            command.quotas,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to import user, username: {}",
+                command.username
+            )
+        })?;
+    let user_id = user.id;
+    let response = Json(mapper::map_user(user));
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(
+            identity.user_id,
+            &EntryCommand::CreateUser(CreateUserWithId {
+                user_id,
+                command: CreateUser {
+                    username: command.username.to_owned(),
+                    password: command.password_hash,
+                    status: command.status,
+                    permissions: command.permissions.clone(),
+                    quotas: command.quotas,
+                },
+            }),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply import user, username: {}",
+                command.username
+            )
+        })?;
+
+    Ok(response)
+}
+
 #[instrument(skip_all, name = "trace_update_user", fields(iggy_user_id = identity.user_id, iggy_updated_user_id = user_id))]
 async fn update_user(
     State(state): State<Arc<AppState>>,
@@ -170,6 +231,7 @@ async fn update_user(
             &command.user_id,
             command.username.clone(),
             command.status,
+            command.quotas,
         )
         .await
         .with_error_context(|error| {
@@ -335,6 +397,30 @@ async fn login_user(
     Ok(Json(map_generated_access_token_to_identity_info(tokens)))
 }
 
+#[instrument(skip_all, name = "trace_login_with_token")]
+async fn login_with_token(
+    State(state): State<Arc<AppState>>,
+    Json(command): Json<LoginWithToken>,
+) -> Result<Json<IdentityInfo>, CustomError> {
+    command.validate()?;
+    let identity = state
+        .system
+        .validate_external_token(&command.token)
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate token")
+        })?;
+    let system = state.system.read().await;
+    let user = system
+        .login_with_external_identity(&identity, None)
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to login with token")
+        })?;
+    let tokens = state.jwt_manager.generate(user.id)?;
+    Ok(Json(map_generated_access_token_to_identity_info(tokens)))
+}
+
 #[instrument(skip_all, name = "trace_logout_user", fields(iggy_user_id = identity.user_id))]
 async fn logout_user(
     State(state): State<Arc<AppState>>,
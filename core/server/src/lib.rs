@@ -28,6 +28,7 @@ compile_error!("iggy-server doesn't support windows.");
 
 pub mod archiver;
 pub mod args;
+pub mod audit;
 pub mod binary;
 pub mod channels;
 pub(crate) mod compat;
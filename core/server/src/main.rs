@@ -22,6 +22,7 @@ use dotenvy::dotenv;
 use figlet_rs::FIGfont;
 use server::args::Args;
 use server::channels::commands::archive_state::ArchiveStateExecutor;
+use server::channels::commands::clean_audit_log::CleanAuditLogExecutor;
 use server::channels::commands::clean_personal_access_tokens::CleanPersonalAccessTokensExecutor;
 use server::channels::commands::maintain_messages::MaintainMessagesExecutor;
 use server::channels::commands::print_sysinfo::SysInfoPrintExecutor;
@@ -114,6 +115,7 @@ async fn main() -> Result<(), ServerError> {
         .install_handler(MaintainMessagesExecutor)
         .install_handler(ArchiveStateExecutor)
         .install_handler(CleanPersonalAccessTokensExecutor)
+        .install_handler(CleanAuditLogExecutor)
         .install_handler(SysInfoPrintExecutor)
         .install_handler(VerifyHeartbeatsExecutor);
 
@@ -16,21 +16,30 @@
  * under the License.
  */
 
+use crate::audit::AuditEvent;
 use crate::binary::command::{ServerCommand, ServerCommandHandler};
 use crate::binary::sender::SenderKind;
+use crate::configs::client_auth::ClientCertificateIdentity;
 use crate::server_error::ConnectionError;
 use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::diagnostics::metrics::RequestMetrics;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use anyhow::anyhow;
 use iggy_common::IggyError;
 use quinn::{Connection, Endpoint, RecvStream, SendStream};
-use tracing::{error, info, trace};
+use rustls::pki_types::CertificateDer;
+use std::time::Instant;
+use tracing::{error, info, trace, warn};
 
 const LISTENERS_COUNT: u32 = 10;
 const INITIAL_BYTES_LENGTH: usize = 4;
 
-pub fn start(endpoint: Endpoint, system: SharedSystem) {
+pub fn start(
+    endpoint: Endpoint,
+    system: SharedSystem,
+    client_identity: Option<ClientCertificateIdentity>,
+) {
     for _ in 0..LISTENERS_COUNT {
         let endpoint = endpoint.clone();
         let system = system.clone();
@@ -51,7 +60,9 @@ pub fn start(endpoint: Endpoint, system: SharedSystem) {
                 }
                 let incoming_connection = incoming_connection.unwrap();
                 tokio::spawn(async move {
-                    if let Err(error) = handle_connection(incoming_connection, system).await {
+                    if let Err(error) =
+                        handle_connection(incoming_connection, system, client_identity).await
+                    {
                         error!("Connection has failed: {error}");
                     }
                 });
@@ -63,6 +74,7 @@ pub fn start(endpoint: Endpoint, system: SharedSystem) {
 async fn handle_connection(
     incoming_connection: quinn::Connecting,
     system: SharedSystem,
+    client_identity: Option<ClientCertificateIdentity>,
 ) -> Result<(), ConnectionError> {
     let connection = incoming_connection.await?;
     let address = connection.remote_address();
@@ -74,12 +86,18 @@ async fn handle_connection(
         .await;
 
     let client_id = session.client_id;
+    if let Some(identity) = client_identity {
+        authenticate_with_certificate(&connection, &system, identity, &session).await;
+    }
+
+    let request_metrics = system.read().await.metrics.request_metrics();
     while let Some(stream) = accept_stream(&connection, &system, client_id).await? {
         let system = system.clone();
         let session = session.clone();
+        let request_metrics = request_metrics.clone();
 
         let handle_stream_task = async move {
-            if let Err(err) = handle_stream(stream, system, session).await {
+            if let Err(err) = handle_stream(stream, system, session, request_metrics).await {
                 error!("Error when handling QUIC stream: {:?}", err)
             }
         };
@@ -88,6 +106,37 @@ async fn handle_connection(
     Ok(())
 }
 
+async fn authenticate_with_certificate(
+    connection: &Connection,
+    system: &SharedSystem,
+    identity: ClientCertificateIdentity,
+    session: &Session,
+) {
+    let certificate = connection
+        .peer_identity()
+        .and_then(|peer_identity| peer_identity.downcast::<Vec<CertificateDer>>().ok())
+        .and_then(|certificates| certificates.first().cloned());
+    let Some(certificate) = certificate else {
+        warn!(
+            "Client: {} has not presented the certificate.",
+            session.client_id
+        );
+        return;
+    };
+
+    if let Err(error) = system
+        .read()
+        .await
+        .login_with_client_certificate(&certificate, identity, session)
+        .await
+    {
+        warn!(
+            "Unable to authenticate client: {} with certificate. {error}",
+            session.client_id
+        );
+    }
+}
+
 type BiStream = (SendStream, RecvStream);
 
 async fn accept_stream(
@@ -114,6 +163,7 @@ async fn handle_stream(
     stream: BiStream,
     system: SharedSystem,
     session: impl AsRef<Session> + std::fmt::Debug,
+    request_metrics: RequestMetrics,
 ) -> anyhow::Result<()> {
     let (send_stream, mut recv_stream) = stream;
 
@@ -145,10 +195,25 @@ async fn handle_stream(
 
     trace!("Received a QUIC command: {command}, payload size: {length}");
 
-    match command
+    let audit_target = command.audit_target();
+    let user_id = session.as_ref().get_user_id();
+    let started_at = Instant::now();
+    let result = command
         .handle(&mut sender, length, session.as_ref(), &system)
-        .await
-    {
+        .await;
+    request_metrics.record(code, started_at.elapsed());
+    if let Some(target) = audit_target {
+        let event = AuditEvent {
+            user_id,
+            address: session.as_ref().ip_address,
+            transport: Transport::Quic.to_string(),
+            code,
+            target,
+            error_code: result.as_ref().err().map(IggyError::as_code),
+        };
+        system.read().await.audit.record(event).await;
+    }
+    match result {
         Ok(_) => {
             trace!(
                 "Command was handled successfully, session: {:?}. QUIC response was sent.",
@@ -57,6 +57,10 @@ impl Sender for QuicSender {
             .await
     }
 
+    fn set_correlation_id(&mut self, _correlation_id: Option<u32>) {
+        // Every QUIC request is sent using its own stream, so there's nothing to correlate.
+    }
+
     async fn shutdown(&mut self) -> Result<(), ServerError> {
         Ok(())
     }
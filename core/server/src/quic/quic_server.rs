@@ -23,6 +23,7 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use error_set::ErrContext;
+use quinn::crypto::rustls::QuicServerConfig;
 use quinn::{Endpoint, IdleTimeout, VarInt};
 use rustls::pki_types::{CertificateDer, PrivateKeyDer};
 use tracing::info;
@@ -32,12 +33,17 @@ use crate::quic::COMPONENT;
 use crate::quic::listener;
 use crate::server_error::QuicError;
 use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::users::client_certificate;
 
 /// Starts the QUIC server.
 /// Returns the address the server is listening on.
 pub fn start(config: QuicConfig, system: SharedSystem) -> SocketAddr {
     info!("Initializing Iggy QUIC server...");
     let address = config.address.parse().unwrap();
+    let client_identity = config
+        .client_auth
+        .enabled
+        .then_some(config.client_auth.identity);
     let quic_config = configure_quic(config);
     if let Err(error) = quic_config {
         panic!("Error when configuring QUIC: {:?}", error);
@@ -45,7 +51,7 @@ pub fn start(config: QuicConfig, system: SharedSystem) -> SocketAddr {
 
     let endpoint = Endpoint::server(quic_config.unwrap(), address).unwrap();
     let addr = endpoint.local_addr().unwrap();
-    listener::start(endpoint, system);
+    listener::start(endpoint, system, client_identity);
     info!("Iggy QUIC server has started on: {:?}", addr);
     addr
 }
@@ -56,11 +62,14 @@ fn configure_quic(config: QuicConfig) -> Result<quinn::ServerConfig, QuicError>
         false => load_certificates(&config.certificate.cert_file, &config.certificate.key_file)?,
     };
 
-    let mut server_config = quinn::ServerConfig::with_single_cert(certificate, key)
-        .with_error_context(|error| {
-            format!("{COMPONENT} (error: {error}) - failed to create server config")
-        })
-        .map_err(|_| QuicError::ConfigCreationError)?;
+    let mut server_config = match config.client_auth.enabled {
+        true => configure_client_auth(certificate, key, &config.client_auth.ca_file)?,
+        false => quinn::ServerConfig::with_single_cert(certificate, key)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to create server config")
+            })
+            .map_err(|_| QuicError::ConfigCreationError)?,
+    };
     let mut transport = quinn::TransportConfig::default();
     transport.initial_mtu(config.initial_mtu.as_bytes_u64() as u16);
     transport.send_window(config.send_window.as_bytes_u64());
@@ -95,6 +104,37 @@ fn configure_quic(config: QuicConfig) -> Result<quinn::ServerConfig, QuicError>
     Ok(server_config)
 }
 
+fn configure_client_auth(
+    certificate: Vec<CertificateDer<'static>>,
+    key: PrivateKeyDer<'static>,
+    ca_file: &str,
+) -> Result<quinn::ServerConfig, QuicError> {
+    let verifier = client_certificate::load_client_verifier(ca_file)
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to load client CA file: {ca_file}")
+        })
+        .map_err(|_| QuicError::CertLoadError)?;
+    let crypto = rustls::ServerConfig::builder_with_provider(Arc::new(
+        rustls::crypto::ring::default_provider(),
+    ))
+    .with_protocol_versions(&[&rustls::version::TLS13])
+    .and_then(|builder| {
+        builder
+            .with_client_cert_verifier(verifier)
+            .with_single_cert(certificate, key)
+    })
+    .with_error_context(|error| {
+        format!("{COMPONENT} (error: {error}) - failed to create TLS server config")
+    })
+    .map_err(|_| QuicError::ConfigCreationError)?;
+    let crypto = QuicServerConfig::try_from(crypto)
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to create QUIC server config")
+        })
+        .map_err(|_| QuicError::ConfigCreationError)?;
+    Ok(quinn::ServerConfig::with_crypto(Arc::new(crypto)))
+}
+
 fn generate_self_signed_cert<'a>() -> Result<(Vec<CertificateDer<'a>>, PrivateKeyDer<'a>), QuicError>
 {
     let certificate = rcgen::generate_simple_self_signed(vec!["localhost".into()]).unwrap();
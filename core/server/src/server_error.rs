@@ -61,6 +61,15 @@ error_set!(
 
         #[display("Cannot archive file: {}", file_path)]
         CannotArchiveFile { file_path: String },
+
+        #[display("Archived file not found: {}", file_path)]
+        ArchivedFileNotFound { file_path: String },
+
+        #[display("Cannot fetch archived file: {}", file_path)]
+        CannotFetchArchivedFile { file_path: String },
+
+        #[display("Cannot delete archived file: {}", file_path)]
+        CannotDeleteArchivedFile { file_path: String },
     } || IoError;
 
     ConnectionError = {
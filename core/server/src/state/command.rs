@@ -17,17 +17,22 @@
  */
 
 use crate::state::models::{
-    CreateConsumerGroupWithId, CreatePersonalAccessTokenWithHash, CreateStreamWithId,
-    CreateTopicWithId, CreateUserWithId,
+    CommitTransactionWithPartitions, CreateConsumerGroupWithId, CreatePersonalAccessTokenWithHash,
+    CreateStreamWithId, CreateTopicWithId, CreateUserWithId, DeleteConsumerOffsetWithIds,
+    InitProducerWithEpoch, RegisterSchemaWithIds, StoreConsumerOffsetsWithIds,
 };
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use iggy_common::BytesSerializable;
 use iggy_common::IggyError;
+use iggy_common::assign_roles::AssignRoles;
 use iggy_common::change_password::ChangePassword;
 use iggy_common::create_partitions::CreatePartitions;
+use iggy_common::create_role::CreateRole;
 use iggy_common::delete_consumer_group::DeleteConsumerGroup;
 use iggy_common::delete_partitions::DeletePartitions;
 use iggy_common::delete_personal_access_token::DeletePersonalAccessToken;
+use iggy_common::delete_role::DeleteRole;
+use iggy_common::delete_schema::DeleteSchema;
 use iggy_common::delete_segments::DeleteSegments;
 use iggy_common::delete_stream::DeleteStream;
 use iggy_common::delete_topic::DeleteTopic;
@@ -35,15 +40,19 @@ use iggy_common::delete_user::DeleteUser;
 use iggy_common::purge_stream::PurgeStream;
 use iggy_common::purge_topic::PurgeTopic;
 use iggy_common::update_permissions::UpdatePermissions;
+use iggy_common::update_role::UpdateRole;
 use iggy_common::update_stream::UpdateStream;
 use iggy_common::update_topic::UpdateTopic;
 use iggy_common::update_user::UpdateUser;
 use iggy_common::{
-    CHANGE_PASSWORD_CODE, CREATE_CONSUMER_GROUP_CODE, CREATE_PARTITIONS_CODE,
-    CREATE_PERSONAL_ACCESS_TOKEN_CODE, CREATE_STREAM_CODE, CREATE_TOPIC_CODE, CREATE_USER_CODE,
-    Command, DELETE_CONSUMER_GROUP_CODE, DELETE_PARTITIONS_CODE, DELETE_PERSONAL_ACCESS_TOKEN_CODE,
-    DELETE_STREAM_CODE, DELETE_TOPIC_CODE, DELETE_USER_CODE, PURGE_STREAM_CODE, PURGE_TOPIC_CODE,
-    UPDATE_PERMISSIONS_CODE, UPDATE_STREAM_CODE, UPDATE_TOPIC_CODE, UPDATE_USER_CODE,
+    ASSIGN_ROLES_CODE, CHANGE_PASSWORD_CODE, COMMIT_TRANSACTION_CODE, CREATE_CONSUMER_GROUP_CODE,
+    CREATE_PARTITIONS_CODE, CREATE_PERSONAL_ACCESS_TOKEN_CODE, CREATE_ROLE_CODE,
+    CREATE_STREAM_CODE, CREATE_TOPIC_CODE, CREATE_USER_CODE, Command, DELETE_CONSUMER_GROUP_CODE,
+    DELETE_CONSUMER_OFFSET_CODE, DELETE_PARTITIONS_CODE, DELETE_PERSONAL_ACCESS_TOKEN_CODE,
+    DELETE_ROLE_CODE, DELETE_SCHEMA_CODE, DELETE_STREAM_CODE, DELETE_TOPIC_CODE, DELETE_USER_CODE,
+    INIT_PRODUCER_CODE, PURGE_STREAM_CODE, PURGE_TOPIC_CODE, REGISTER_SCHEMA_CODE,
+    STORE_CONSUMER_OFFSETS_CODE, UPDATE_PERMISSIONS_CODE, UPDATE_ROLE_CODE, UPDATE_STREAM_CODE,
+    UPDATE_TOPIC_CODE, UPDATE_USER_CODE,
 };
 use std::fmt::{Display, Formatter};
 
@@ -69,6 +78,16 @@ pub enum EntryCommand {
     UpdatePermissions(UpdatePermissions),
     CreatePersonalAccessToken(CreatePersonalAccessTokenWithHash),
     DeletePersonalAccessToken(DeletePersonalAccessToken),
+    InitProducer(InitProducerWithEpoch),
+    StoreConsumerOffsets(StoreConsumerOffsetsWithIds),
+    DeleteConsumerOffset(DeleteConsumerOffsetWithIds),
+    CreateRole(CreateRole),
+    UpdateRole(UpdateRole),
+    DeleteRole(DeleteRole),
+    AssignRoles(AssignRoles),
+    RegisterSchema(RegisterSchemaWithIds),
+    DeleteSchema(DeleteSchema),
+    CommitTransaction(CommitTransactionWithPartitions),
 }
 
 impl BytesSerializable for EntryCommand {
@@ -98,6 +117,16 @@ impl BytesSerializable for EntryCommand {
             EntryCommand::DeletePersonalAccessToken(command) => {
                 (command.code(), command.to_bytes())
             }
+            EntryCommand::InitProducer(command) => (command.code(), command.to_bytes()),
+            EntryCommand::StoreConsumerOffsets(command) => (command.code(), command.to_bytes()),
+            EntryCommand::DeleteConsumerOffset(command) => (command.code(), command.to_bytes()),
+            EntryCommand::CreateRole(command) => (command.code(), command.to_bytes()),
+            EntryCommand::UpdateRole(command) => (command.code(), command.to_bytes()),
+            EntryCommand::DeleteRole(command) => (command.code(), command.to_bytes()),
+            EntryCommand::AssignRoles(command) => (command.code(), command.to_bytes()),
+            EntryCommand::RegisterSchema(command) => (command.code(), command.to_bytes()),
+            EntryCommand::DeleteSchema(command) => (command.code(), command.to_bytes()),
+            EntryCommand::CommitTransaction(command) => (command.code(), command.to_bytes()),
         };
 
         let mut bytes = BytesMut::with_capacity(4 + 4 + command.len());
@@ -160,6 +189,28 @@ impl BytesSerializable for EntryCommand {
             DELETE_PERSONAL_ACCESS_TOKEN_CODE => Ok(EntryCommand::DeletePersonalAccessToken(
                 DeletePersonalAccessToken::from_bytes(payload)?,
             )),
+            INIT_PRODUCER_CODE => Ok(EntryCommand::InitProducer(
+                InitProducerWithEpoch::from_bytes(payload)?,
+            )),
+            STORE_CONSUMER_OFFSETS_CODE => Ok(EntryCommand::StoreConsumerOffsets(
+                StoreConsumerOffsetsWithIds::from_bytes(payload)?,
+            )),
+            DELETE_CONSUMER_OFFSET_CODE => Ok(EntryCommand::DeleteConsumerOffset(
+                DeleteConsumerOffsetWithIds::from_bytes(payload)?,
+            )),
+            CREATE_ROLE_CODE => Ok(EntryCommand::CreateRole(CreateRole::from_bytes(payload)?)),
+            UPDATE_ROLE_CODE => Ok(EntryCommand::UpdateRole(UpdateRole::from_bytes(payload)?)),
+            DELETE_ROLE_CODE => Ok(EntryCommand::DeleteRole(DeleteRole::from_bytes(payload)?)),
+            ASSIGN_ROLES_CODE => Ok(EntryCommand::AssignRoles(AssignRoles::from_bytes(payload)?)),
+            REGISTER_SCHEMA_CODE => Ok(EntryCommand::RegisterSchema(
+                RegisterSchemaWithIds::from_bytes(payload)?,
+            )),
+            DELETE_SCHEMA_CODE => Ok(EntryCommand::DeleteSchema(DeleteSchema::from_bytes(
+                payload,
+            )?)),
+            COMMIT_TRANSACTION_CODE => Ok(EntryCommand::CommitTransaction(
+                CommitTransactionWithPartitions::from_bytes(payload)?,
+            )),
             _ => Err(IggyError::InvalidCommand),
         }
     }
@@ -196,6 +247,22 @@ impl Display for EntryCommand {
             EntryCommand::DeletePersonalAccessToken(command) => {
                 write!(f, "DeletePersonalAccessToken({})", command)
             }
+            EntryCommand::InitProducer(command) => write!(f, "InitProducer({})", command),
+            EntryCommand::StoreConsumerOffsets(command) => {
+                write!(f, "StoreConsumerOffsets({})", command)
+            }
+            EntryCommand::DeleteConsumerOffset(command) => {
+                write!(f, "DeleteConsumerOffset({})", command)
+            }
+            EntryCommand::CreateRole(command) => write!(f, "CreateRole({})", command),
+            EntryCommand::UpdateRole(command) => write!(f, "UpdateRole({})", command),
+            EntryCommand::DeleteRole(command) => write!(f, "DeleteRole({})", command),
+            EntryCommand::AssignRoles(command) => write!(f, "AssignRoles({})", command),
+            EntryCommand::RegisterSchema(command) => write!(f, "RegisterSchema({})", command),
+            EntryCommand::DeleteSchema(command) => write!(f, "DeleteSchema({})", command),
+            EntryCommand::CommitTransaction(command) => {
+                write!(f, "CommitTransaction({})", command)
+            }
         }
     }
 }
@@ -17,17 +17,24 @@
  */
 
 use crate::state::COMPONENT;
+use crate::streaming::transactions::transaction_manager::TransactionPartition;
 use bytes::{BufMut, Bytes, BytesMut};
 use error_set::ErrContext;
 use iggy_common::BytesSerializable;
 use iggy_common::Command;
 use iggy_common::IggyError;
+use iggy_common::IggyTimestamp;
 use iggy_common::Validatable;
+use iggy_common::commit_transaction::CommitTransaction;
 use iggy_common::create_consumer_group::CreateConsumerGroup;
 use iggy_common::create_personal_access_token::CreatePersonalAccessToken;
 use iggy_common::create_stream::CreateStream;
 use iggy_common::create_topic::CreateTopic;
 use iggy_common::create_user::CreateUser;
+use iggy_common::delete_consumer_offset::DeleteConsumerOffset;
+use iggy_common::init_producer::InitProducer;
+use iggy_common::register_schema::RegisterSchema;
+use iggy_common::store_consumer_offsets::StoreConsumerOffsets;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::fmt::{Display, Formatter};
@@ -63,6 +70,50 @@ pub struct CreatePersonalAccessTokenWithHash {
     pub command: CreatePersonalAccessToken,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct InitProducerWithEpoch {
+    pub producer_id: u64,
+    pub epoch: u32,
+    pub command: InitProducer,
+}
+
+/// The committed consumer offsets, both the single and the bulk ones, with the partition IDs already resolved.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct StoreConsumerOffsetsWithIds {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub consumer_id: u32,
+    pub committed_at: IggyTimestamp,
+    pub command: StoreConsumerOffsets,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeleteConsumerOffsetWithIds {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub consumer_id: u32,
+    pub partition_id: u32,
+    pub command: DeleteConsumerOffset,
+}
+
+/// The registered schema with its ID and version assigned, and the stream and topic IDs already resolved.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct RegisterSchemaWithIds {
+    pub schema_id: u32,
+    pub version: u32,
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub command: RegisterSchema,
+}
+
+/// The decision to commit the transaction, stored before any of its markers is written, along with the partitions
+/// the messages were sent to, so that the markers missing after the restart can be appended.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct CommitTransactionWithPartitions {
+    pub partitions: Vec<TransactionPartition>,
+    pub command: CommitTransaction,
+}
+
 impl Validatable<IggyError> for CreateStreamWithId {
     fn validate(&self) -> Result<(), IggyError> {
         self.command.validate()
@@ -123,6 +174,66 @@ impl Command for CreatePersonalAccessTokenWithHash {
     }
 }
 
+impl Validatable<IggyError> for InitProducerWithEpoch {
+    fn validate(&self) -> Result<(), IggyError> {
+        self.command.validate()
+    }
+}
+
+impl Command for InitProducerWithEpoch {
+    fn code(&self) -> u32 {
+        self.command.code()
+    }
+}
+
+impl Validatable<IggyError> for StoreConsumerOffsetsWithIds {
+    fn validate(&self) -> Result<(), IggyError> {
+        self.command.validate()
+    }
+}
+
+impl Command for StoreConsumerOffsetsWithIds {
+    fn code(&self) -> u32 {
+        self.command.code()
+    }
+}
+
+impl Validatable<IggyError> for RegisterSchemaWithIds {
+    fn validate(&self) -> Result<(), IggyError> {
+        self.command.validate()
+    }
+}
+
+impl Command for RegisterSchemaWithIds {
+    fn code(&self) -> u32 {
+        self.command.code()
+    }
+}
+
+impl Validatable<IggyError> for DeleteConsumerOffsetWithIds {
+    fn validate(&self) -> Result<(), IggyError> {
+        self.command.validate()
+    }
+}
+
+impl Command for DeleteConsumerOffsetWithIds {
+    fn code(&self) -> u32 {
+        self.command.code()
+    }
+}
+
+impl Validatable<IggyError> for CommitTransactionWithPartitions {
+    fn validate(&self) -> Result<(), IggyError> {
+        self.command.validate()
+    }
+}
+
+impl Command for CommitTransactionWithPartitions {
+    fn code(&self) -> u32 {
+        self.command.code()
+    }
+}
+
 impl Display for CreateStreamWithId {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(
@@ -173,6 +284,57 @@ impl Display for CreatePersonalAccessTokenWithHash {
     }
 }
 
+impl Display for InitProducerWithEpoch {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "InitProducerWithEpoch {{ command: {}, producer_id: {}, epoch: {} }}",
+            self.command, self.producer_id, self.epoch
+        )
+    }
+}
+
+impl Display for StoreConsumerOffsetsWithIds {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "StoreConsumerOffsetsWithIds {{ command: {}, stream_id: {}, topic_id: {}, consumer_id: {}, committed_at: {} }}",
+            self.command, self.stream_id, self.topic_id, self.consumer_id, self.committed_at
+        )
+    }
+}
+
+impl Display for RegisterSchemaWithIds {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "RegisterSchemaWithIds {{ command: {}, schema_id: {}, version: {}, stream_id: {}, topic_id: {} }}",
+            self.command, self.schema_id, self.version, self.stream_id, self.topic_id
+        )
+    }
+}
+
+impl Display for DeleteConsumerOffsetWithIds {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "DeleteConsumerOffsetWithIds {{ command: {}, stream_id: {}, topic_id: {}, consumer_id: {}, partition_id: {} }}",
+            self.command, self.stream_id, self.topic_id, self.consumer_id, self.partition_id
+        )
+    }
+}
+
+impl Display for CommitTransactionWithPartitions {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CommitTransactionWithPartitions {{ command: {}, partitions: {} }}",
+            self.command,
+            self.partitions.len()
+        )
+    }
+}
+
 impl BytesSerializable for CreateStreamWithId {
     fn to_bytes(&self) -> Bytes {
         let mut bytes = BytesMut::new();
@@ -387,3 +549,242 @@ impl BytesSerializable for CreatePersonalAccessTokenWithHash {
         Ok(Self { hash, command })
     }
 }
+
+impl BytesSerializable for InitProducerWithEpoch {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::new();
+        bytes.put_u64_le(self.producer_id);
+        bytes.put_u32_le(self.epoch);
+        let command_bytes = self.command.to_bytes();
+        bytes.put_u32_le(command_bytes.len() as u32);
+        bytes.put_slice(&command_bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        let mut position = 0;
+        let producer_id = u64::from_le_bytes(
+            bytes[position..8]
+                .try_into()
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to parse producer ID")
+                })
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 8;
+        let epoch = u32::from_le_bytes(
+            bytes[position..position + 4]
+                .try_into()
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to parse producer epoch")
+                })
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 4;
+        let command_length = u32::from_le_bytes(
+            bytes[position..position + 4]
+                .try_into()
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to parse producer command length"
+                    )
+                })
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 4;
+        let command_bytes = bytes.slice(position..position + command_length as usize);
+        let command = InitProducer::from_bytes(command_bytes).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to parse producer command")
+        })?;
+        Ok(Self {
+            producer_id,
+            epoch,
+            command,
+        })
+    }
+}
+
+impl BytesSerializable for StoreConsumerOffsetsWithIds {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(self.stream_id);
+        bytes.put_u32_le(self.topic_id);
+        bytes.put_u32_le(self.consumer_id);
+        bytes.put_u64_le(self.committed_at.as_micros());
+        let command_bytes = self.command.to_bytes();
+        bytes.put_u32_le(command_bytes.len() as u32);
+        bytes.put_slice(&command_bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        let stream_id = read_u32(&bytes, 0, "stream ID")?;
+        let topic_id = read_u32(&bytes, 4, "topic ID")?;
+        let consumer_id = read_u32(&bytes, 8, "consumer ID")?;
+        let committed_at = read_u64(&bytes, 12, "consumer offsets commit time")?.into();
+        let command_length = read_u32(&bytes, 20, "consumer offsets command length")?;
+        let command_bytes = bytes.slice(24..24 + command_length as usize);
+        let command =
+            StoreConsumerOffsets::from_bytes(command_bytes).with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to parse consumer offsets command")
+            })?;
+        Ok(Self {
+            stream_id,
+            topic_id,
+            consumer_id,
+            committed_at,
+            command,
+        })
+    }
+}
+
+impl BytesSerializable for DeleteConsumerOffsetWithIds {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(self.stream_id);
+        bytes.put_u32_le(self.topic_id);
+        bytes.put_u32_le(self.consumer_id);
+        bytes.put_u32_le(self.partition_id);
+        let command_bytes = self.command.to_bytes();
+        bytes.put_u32_le(command_bytes.len() as u32);
+        bytes.put_slice(&command_bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        let stream_id = read_u32(&bytes, 0, "stream ID")?;
+        let topic_id = read_u32(&bytes, 4, "topic ID")?;
+        let consumer_id = read_u32(&bytes, 8, "consumer ID")?;
+        let partition_id = read_u32(&bytes, 12, "partition ID")?;
+        let command_length = read_u32(&bytes, 16, "delete consumer offset command length")?;
+        let command_bytes = bytes.slice(20..20 + command_length as usize);
+        let command =
+            DeleteConsumerOffset::from_bytes(command_bytes).with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to parse delete consumer offset command"
+                )
+            })?;
+        Ok(Self {
+            stream_id,
+            topic_id,
+            consumer_id,
+            partition_id,
+            command,
+        })
+    }
+}
+
+impl BytesSerializable for RegisterSchemaWithIds {
+    fn to_bytes(&self) -> Bytes {
+        let command_bytes = self.command.to_bytes();
+        let mut bytes = BytesMut::with_capacity(20 + command_bytes.len());
+        bytes.put_u32_le(self.schema_id);
+        bytes.put_u32_le(self.version);
+        bytes.put_u32_le(self.stream_id);
+        bytes.put_u32_le(self.topic_id);
+        bytes.put_u32_le(command_bytes.len() as u32);
+        bytes.put_slice(&command_bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        let schema_id = read_u32(&bytes, 0, "schema ID")?;
+        let version = read_u32(&bytes, 4, "schema version")?;
+        let stream_id = read_u32(&bytes, 8, "stream ID")?;
+        let topic_id = read_u32(&bytes, 12, "topic ID")?;
+        let command_length = read_u32(&bytes, 16, "register schema command length")?;
+        let command_bytes = bytes
+            .get(20..20 + command_length as usize)
+            .map(|command_bytes| bytes.slice_ref(command_bytes))
+            .ok_or(IggyError::InvalidCommand)?;
+        let command = RegisterSchema::from_bytes(command_bytes).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to parse register schema command")
+        })?;
+        Ok(Self {
+            schema_id,
+            version,
+            stream_id,
+            topic_id,
+            command,
+        })
+    }
+}
+
+impl BytesSerializable for CommitTransactionWithPartitions {
+    fn to_bytes(&self) -> Bytes {
+        let command_bytes = self.command.to_bytes();
+        let mut bytes =
+            BytesMut::with_capacity(8 + self.partitions.len() * 12 + command_bytes.len());
+        bytes.put_u32_le(self.partitions.len() as u32);
+        for partition in &self.partitions {
+            bytes.put_u32_le(partition.stream_id);
+            bytes.put_u32_le(partition.topic_id);
+            bytes.put_u32_le(partition.partition_id);
+        }
+        bytes.put_u32_le(command_bytes.len() as u32);
+        bytes.put_slice(&command_bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        let partitions_count = read_u32(&bytes, 0, "transaction partitions count")?;
+        let mut partitions = Vec::with_capacity(partitions_count as usize);
+        let mut position = 4;
+        for _ in 0..partitions_count {
+            partitions.push(TransactionPartition {
+                stream_id: read_u32(&bytes, position, "stream ID")?,
+                topic_id: read_u32(&bytes, position + 4, "topic ID")?,
+                partition_id: read_u32(&bytes, position + 8, "partition ID")?,
+            });
+            position += 12;
+        }
+        let command_length = read_u32(&bytes, position, "commit transaction command length")?;
+        position += 4;
+        let command_bytes = bytes
+            .get(position..position + command_length as usize)
+            .map(|command_bytes| bytes.slice_ref(command_bytes))
+            .ok_or(IggyError::InvalidCommand)?;
+        let command = CommitTransaction::from_bytes(command_bytes).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to parse commit transaction command")
+        })?;
+        Ok(Self {
+            partitions,
+            command,
+        })
+    }
+}
+
+fn read_u32(bytes: &Bytes, position: usize, field: &str) -> Result<u32, IggyError> {
+    let value = bytes
+        .get(position..position + 4)
+        .ok_or(IggyError::InvalidNumberEncoding)
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to parse {field}")
+        })?;
+    Ok(u32::from_le_bytes(value.try_into().unwrap()))
+}
+
+fn read_u64(bytes: &Bytes, position: usize, field: &str) -> Result<u64, IggyError> {
+    let value = bytes
+        .get(position..position + 8)
+        .ok_or(IggyError::InvalidNumberEncoding)
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to parse {field}")
+        })?;
+    Ok(u64::from_le_bytes(value.try_into().unwrap()))
+}
@@ -18,21 +18,38 @@
 
 use crate::state::{COMPONENT, EntryCommand, StateEntry};
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
+use crate::streaming::transactions::transaction_manager::TransactionPartition;
 use ahash::AHashMap;
 use error_set::ErrContext;
+use iggy_common::CleanupPolicy;
 use iggy_common::CompressionAlgorithm;
+use iggy_common::ConsumerGroupAssignmentStrategy;
+use iggy_common::ConsumerKind;
 use iggy_common::IggyError;
 use iggy_common::IggyExpiry;
 use iggy_common::IggyTimestamp;
 use iggy_common::MaxTopicSize;
-use iggy_common::{IdKind, Identifier, Permissions, UserStatus};
+use iggy_common::PartitioningHash;
+use iggy_common::Schema;
+use iggy_common::{
+    ConsumerGroupMetadata, PersonalAccessTokenMetadata, ServerMetadata, StreamMetadata,
+    TopicMetadata, UserMetadata,
+};
+use iggy_common::{IdKind, Identifier, Permissions, Role, UserQuotas, UserStatus};
 use std::fmt::Display;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 #[derive(Debug)]
 pub struct SystemState {
     pub streams: AHashMap<u32, StreamState>,
     pub users: AHashMap<u32, UserState>,
+    pub producers: AHashMap<u64, ProducerState>,
+    pub roles: AHashMap<String, Role>,
+    /// The highest ID of the registered schemas, including the already deleted ones.
+    pub last_schema_id: u32,
+    /// The partitions of the committed transactions, whose markers might be missing, if the server was stopped
+    /// before all of them were written. The partitions deleted in the meantime are excluded.
+    pub committed_transactions: AHashMap<u64, Vec<TransactionPartition>>,
 }
 
 #[derive(Debug)]
@@ -49,10 +66,15 @@ pub struct TopicState {
     pub name: String,
     pub partitions: AHashMap<u32, PartitionState>,
     pub consumer_groups: AHashMap<u32, ConsumerGroupState>,
+    pub schemas: AHashMap<u32, Schema>,
     pub compression_algorithm: CompressionAlgorithm,
     pub message_expiry: IggyExpiry,
     pub max_topic_size: MaxTopicSize,
     pub replication_factor: Option<u8>,
+    pub cleanup_policy: CleanupPolicy,
+    pub partitioning_hash: PartitioningHash,
+    pub segment_max_age: IggyExpiry,
+    pub max_partition_size: MaxTopicSize,
     pub created_at: IggyTimestamp,
 }
 
@@ -60,6 +82,14 @@ pub struct TopicState {
 pub struct PartitionState {
     pub id: u32,
     pub created_at: IggyTimestamp,
+    pub consumer_offsets: AHashMap<(ConsumerKind, u32), ConsumerOffsetState>,
+}
+
+#[derive(Debug)]
+pub struct ConsumerOffsetState {
+    pub offset: u64,
+    pub metadata: Option<String>,
+    pub committed_at: IggyTimestamp,
 }
 
 #[derive(Debug)]
@@ -77,19 +107,33 @@ pub struct UserState {
     pub status: UserStatus,
     pub created_at: IggyTimestamp,
     pub permissions: Option<Permissions>,
+    pub quotas: Option<UserQuotas>,
+    pub roles: Vec<String>,
     pub personal_access_tokens: AHashMap<String, PersonalAccessTokenState>,
 }
 
+#[derive(Debug)]
+pub struct ProducerState {
+    pub id: u64,
+    pub epoch: u32,
+    pub user_id: u32,
+}
+
 #[derive(Debug)]
 pub struct ConsumerGroupState {
     pub id: u32,
     pub name: String,
+    pub assignment_strategy: ConsumerGroupAssignmentStrategy,
 }
 
 impl SystemState {
     pub async fn init(entries: Vec<StateEntry>) -> Result<Self, IggyError> {
         let mut streams = AHashMap::new();
         let mut users = AHashMap::new();
+        let mut producers = AHashMap::new();
+        let mut roles = AHashMap::new();
+        let mut last_schema_id = 0;
+        let mut committed_transactions: AHashMap<u64, Vec<TransactionPartition>> = AHashMap::new();
         for entry in entries {
             debug!("Processing state entry: {entry}",);
             match entry.command().with_error_context(|error| {
@@ -119,13 +163,21 @@ impl SystemState {
                 EntryCommand::DeleteStream(command) => {
                     let stream_id = find_stream_id(&streams, &command.stream_id);
                     streams.remove(&stream_id);
+                    remove_committed_transactions(&mut committed_transactions, |partition| {
+                        partition.stream_id == stream_id
+                    });
                 }
                 EntryCommand::PurgeStream(command) => {
                     let stream_id = find_stream_id(&streams, &command.stream_id);
-                    streams
-                        .get(&stream_id)
+                    let stream = streams
+                        .get_mut(&stream_id)
                         .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
-                    // It only affects the segments which are not part of the state
+                    // Besides the segments which are not part of the state, it removes the consumer offsets
+                    for topic in stream.topics.values_mut() {
+                        for partition in topic.partitions.values_mut() {
+                            partition.consumer_offsets.clear();
+                        }
+                    }
                 }
                 EntryCommand::CreateTopic(command) => {
                     let stream_id = find_stream_id(&streams, &command.command.stream_id);
@@ -138,10 +190,15 @@ impl SystemState {
                         id: topic_id,
                         name: command.name,
                         consumer_groups: AHashMap::new(),
+                        schemas: AHashMap::new(),
                         compression_algorithm: command.compression_algorithm,
                         message_expiry: command.message_expiry,
                         max_topic_size: command.max_topic_size,
                         replication_factor: command.replication_factor,
+                        cleanup_policy: command.cleanup_policy,
+                        partitioning_hash: command.partitioning_hash,
+                        segment_max_age: command.segment_max_age,
+                        max_partition_size: command.max_partition_size,
                         created_at: entry.timestamp,
                         partitions: if command.partitions_count > 0 {
                             let mut partitions = AHashMap::new();
@@ -151,6 +208,7 @@ impl SystemState {
                                     PartitionState {
                                         id: i,
                                         created_at: entry.timestamp,
+                                        consumer_offsets: AHashMap::new(),
                                     },
                                 );
                             }
@@ -176,6 +234,10 @@ impl SystemState {
                     topic.message_expiry = command.message_expiry;
                     topic.max_topic_size = command.max_topic_size;
                     topic.replication_factor = command.replication_factor;
+                    topic.cleanup_policy = command.cleanup_policy;
+                    topic.partitioning_hash = command.partitioning_hash;
+                    topic.segment_max_age = command.segment_max_age;
+                    topic.max_partition_size = command.max_partition_size;
                 }
                 EntryCommand::DeleteTopic(command) => {
                     let stream_id = find_stream_id(&streams, &command.stream_id);
@@ -184,18 +246,24 @@ impl SystemState {
                         .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
                     let topic_id = find_topic_id(&stream.topics, &command.topic_id);
                     stream.topics.remove(&topic_id);
+                    remove_committed_transactions(&mut committed_transactions, |partition| {
+                        partition.stream_id == stream_id && partition.topic_id == topic_id
+                    });
                 }
                 EntryCommand::PurgeTopic(command) => {
                     let stream_id = find_stream_id(&streams, &command.stream_id);
                     let stream = streams
-                        .get(&stream_id)
+                        .get_mut(&stream_id)
                         .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
                     let topic_id = find_topic_id(&stream.topics, &command.topic_id);
-                    stream
+                    let topic = stream
                         .topics
-                        .get(&topic_id)
+                        .get_mut(&topic_id)
                         .unwrap_or_else(|| panic!("{}", format!("Topic: {topic_id} not found")));
-                    // It only affects the segments which are not part of the state
+                    // Besides the segments which are not part of the state, it removes the consumer offsets
+                    for partition in topic.partitions.values_mut() {
+                        partition.consumer_offsets.clear();
+                    }
                 }
                 EntryCommand::CreatePartitions(command) => {
                     let stream_id = find_stream_id(&streams, &command.stream_id);
@@ -223,6 +291,7 @@ impl SystemState {
                             PartitionState {
                                 id: last_partition_id + i,
                                 created_at: entry.timestamp,
+                                consumer_offsets: AHashMap::new(),
                             },
                         );
                     }
@@ -250,6 +319,13 @@ impl SystemState {
                     for i in 0..command.partitions_count {
                         topic.partitions.remove(&(last_partition_id - i));
                     }
+                    let first_deleted_partition_id =
+                        (last_partition_id + 1).saturating_sub(command.partitions_count);
+                    remove_committed_transactions(&mut committed_transactions, |partition| {
+                        partition.stream_id == stream_id
+                            && partition.topic_id == topic_id
+                            && partition.partition_id >= first_deleted_partition_id
+                    });
                 }
                 EntryCommand::DeleteSegments(command) => {
                     let stream_id = find_stream_id(&streams, &command.stream_id);
@@ -292,6 +368,7 @@ impl SystemState {
                     let consumer_group = ConsumerGroupState {
                         id: consumer_group_id,
                         name: command.name,
+                        assignment_strategy: command.assignment_strategy,
                     };
                     topic
                         .consumer_groups
@@ -310,6 +387,11 @@ impl SystemState {
                     let consumer_group_id =
                         find_consumer_group_id(&topic.consumer_groups, &command.group_id);
                     topic.consumer_groups.remove(&consumer_group_id);
+                    for partition in topic.partitions.values_mut() {
+                        partition
+                            .consumer_offsets
+                            .remove(&(ConsumerKind::ConsumerGroup, consumer_group_id));
+                    }
                 }
                 EntryCommand::CreateUser(command) => {
                     let user_id = command.user_id;
@@ -321,6 +403,8 @@ impl SystemState {
                         status: command.status,
                         created_at: entry.timestamp,
                         permissions: command.permissions,
+                        quotas: command.quotas,
+                        roles: Vec::new(),
                         personal_access_tokens: AHashMap::new(),
                     };
                     users.insert(user.id, user);
@@ -336,6 +420,9 @@ impl SystemState {
                     if let Some(status) = &command.status {
                         user.status = *status;
                     }
+                    if let Some(quotas) = command.quotas {
+                        user.quotas = Some(quotas).filter(|quotas| !quotas.is_unlimited());
+                    }
                 }
                 EntryCommand::DeleteUser(command) => {
                     let user_id = find_user_id(&users, &command.user_id);
@@ -404,15 +491,210 @@ impl SystemState {
                         .unwrap_or_else(|| panic!("{}", format!("User: {user_id} not found")));
                     user.personal_access_tokens.remove(&command.name);
                 }
+                EntryCommand::InitProducer(command) => {
+                    let producer = ProducerState {
+                        id: command.producer_id,
+                        epoch: command.epoch,
+                        user_id: entry.user_id,
+                    };
+                    producers.insert(producer.id, producer);
+                }
+                EntryCommand::StoreConsumerOffsets(command) => {
+                    let Some(topic) = streams
+                        .get_mut(&command.stream_id)
+                        .and_then(|stream| stream.topics.get_mut(&command.topic_id))
+                    else {
+                        warn!("Topic for the stored consumer offsets: {command} not found.");
+                        continue;
+                    };
+
+                    let kind = command.command.consumer.kind;
+                    for offset in command.command.offsets {
+                        let Some(partition) = topic.partitions.get_mut(&offset.partition_id) else {
+                            continue;
+                        };
+
+                        partition.consumer_offsets.insert(
+                            (kind, command.consumer_id),
+                            ConsumerOffsetState {
+                                offset: offset.offset,
+                                metadata: offset.metadata,
+                                committed_at: command.committed_at,
+                            },
+                        );
+                    }
+                }
+                EntryCommand::DeleteConsumerOffset(command) => {
+                    let Some(partition) = streams
+                        .get_mut(&command.stream_id)
+                        .and_then(|stream| stream.topics.get_mut(&command.topic_id))
+                        .and_then(|topic| topic.partitions.get_mut(&command.partition_id))
+                    else {
+                        continue;
+                    };
+
+                    partition
+                        .consumer_offsets
+                        .remove(&(command.command.consumer.kind, command.consumer_id));
+                }
+                EntryCommand::CreateRole(command) => {
+                    roles.insert(
+                        command.name.clone(),
+                        Role::new(&command.name, command.rules),
+                    );
+                }
+                EntryCommand::UpdateRole(command) => {
+                    let role = roles.get_mut(&command.name).unwrap_or_else(|| {
+                        panic!("{}", format!("Role: {} not found", command.name))
+                    });
+                    role.rules = command.rules;
+                }
+                EntryCommand::DeleteRole(command) => {
+                    roles.remove(&command.name);
+                    for user in users.values_mut() {
+                        user.roles.retain(|role| role != &command.name);
+                    }
+                }
+                EntryCommand::AssignRoles(command) => {
+                    let user_id = find_user_id(&users, &command.user_id);
+                    let user = users
+                        .get_mut(&user_id)
+                        .unwrap_or_else(|| panic!("{}", format!("User: {user_id} not found")));
+                    user.roles = command.roles;
+                }
+                EntryCommand::RegisterSchema(command) => {
+                    let stream = streams.get_mut(&command.stream_id).unwrap_or_else(|| {
+                        panic!("{}", format!("Stream: {} not found", command.stream_id))
+                    });
+                    let topic = stream.topics.get_mut(&command.topic_id).unwrap_or_else(|| {
+                        panic!("{}", format!("Topic: {} not found", command.topic_id))
+                    });
+                    let schema = Schema {
+                        id: command.schema_id,
+                        stream_id: command.stream_id,
+                        topic_id: command.topic_id,
+                        version: command.version,
+                        schema_type: command.command.schema_type,
+                        compatibility: command.command.compatibility,
+                        definition: command.command.definition,
+                        created_at: entry.timestamp,
+                    };
+                    last_schema_id = last_schema_id.max(schema.id);
+                    topic.schemas.insert(schema.id, schema);
+                }
+                EntryCommand::CommitTransaction(command) => {
+                    committed_transactions
+                        .insert(command.command.transaction_id, command.partitions);
+                }
+                EntryCommand::DeleteSchema(command) => {
+                    for topic in streams
+                        .values_mut()
+                        .flat_map(|stream| stream.topics.values_mut())
+                    {
+                        topic.schemas.remove(&command.schema_id);
+                    }
+                }
             }
         }
 
-        let state = SystemState { streams, users };
+        let state = SystemState {
+            streams,
+            users,
+            producers,
+            roles,
+            last_schema_id,
+            committed_transactions,
+        };
         debug!("+++ State +++");
         debug!("{state}");
         debug!("+++ State +++");
         Ok(state)
     }
+
+    /// Returns the metadata of the streams, topics, consumer groups, users and roles sorted by their IDs and names.
+    /// The password hashes of the users are included only if requested.
+    pub fn to_metadata(&self, include_password_hashes: bool) -> ServerMetadata {
+        let mut streams = self
+            .streams
+            .values()
+            .map(|stream| {
+                let mut topics = stream
+                    .topics
+                    .values()
+                    .map(|topic| {
+                        let mut consumer_groups = topic
+                            .consumer_groups
+                            .values()
+                            .map(|group| ConsumerGroupMetadata {
+                                id: group.id,
+                                name: group.name.clone(),
+                                assignment_strategy: group.assignment_strategy,
+                            })
+                            .collect::<Vec<_>>();
+                        consumer_groups.sort_by_key(|group| group.id);
+                        TopicMetadata {
+                            id: topic.id,
+                            name: topic.name.clone(),
+                            partitions_count: topic.partitions.len() as u32,
+                            compression_algorithm: topic.compression_algorithm,
+                            message_expiry: topic.message_expiry,
+                            max_topic_size: topic.max_topic_size,
+                            replication_factor: topic.replication_factor,
+                            cleanup_policy: topic.cleanup_policy,
+                            partitioning_hash: topic.partitioning_hash,
+                            segment_max_age: topic.segment_max_age,
+                            max_partition_size: topic.max_partition_size,
+                            consumer_groups,
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                topics.sort_by_key(|topic| topic.id);
+                StreamMetadata {
+                    id: stream.id,
+                    name: stream.name.clone(),
+                    topics,
+                }
+            })
+            .collect::<Vec<_>>();
+        streams.sort_by_key(|stream| stream.id);
+
+        let mut roles = self.roles.values().cloned().collect::<Vec<_>>();
+        roles.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut users = self
+            .users
+            .values()
+            .map(|user| {
+                let mut personal_access_tokens = user
+                    .personal_access_tokens
+                    .values()
+                    .map(|token| PersonalAccessTokenMetadata {
+                        name: token.name.clone(),
+                        expiry_at: token.expiry_at,
+                    })
+                    .collect::<Vec<_>>();
+                personal_access_tokens.sort_by(|a, b| a.name.cmp(&b.name));
+                UserMetadata {
+                    id: user.id,
+                    username: user.username.clone(),
+                    status: user.status,
+                    permissions: user.permissions.clone(),
+                    quotas: user.quotas,
+                    roles: user.roles.clone(),
+                    password_hash: include_password_hashes.then(|| user.password_hash.clone()),
+                    password: None,
+                    personal_access_tokens,
+                }
+            })
+            .collect::<Vec<_>>();
+        users.sort_by_key(|user| user.id);
+
+        ServerMetadata {
+            streams,
+            roles,
+            users,
+        }
+    }
 }
 
 fn find_stream_id(streams: &AHashMap<u32, StreamState>, stream_id: &Identifier) -> u32 {
@@ -490,6 +772,16 @@ fn find_user_id(users: &AHashMap<u32, UserState>, user_id: &Identifier) -> u32 {
     }
 }
 
+fn remove_committed_transactions(
+    committed_transactions: &mut AHashMap<u64, Vec<TransactionPartition>>,
+    predicate: impl Fn(&TransactionPartition) -> bool,
+) {
+    committed_transactions.retain(|_, partitions| {
+        partitions.retain(|partition| !predicate(partition));
+        !partitions.is_empty()
+    });
+}
+
 impl Display for SystemState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "Streams:")?;
@@ -502,13 +794,37 @@ impl Display for SystemState {
             write!(f, "\n================\n")?;
             write!(f, "{}", user.1)?;
         }
+        write!(f, "Producers:")?;
+        for producer in self.producers.iter() {
+            write!(f, "\n================\n")?;
+            write!(f, "{}", producer.1)?;
+        }
+        write!(f, "Roles:")?;
+        for role in self.roles.values() {
+            write!(f, "\n================\n")?;
+            write!(f, "Role -> {role}")?;
+        }
         Ok(())
     }
 }
 
+impl Display for ProducerState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Producer -> ID: {}, Epoch: {}, User ID: {}",
+            self.id, self.epoch, self.user_id
+        )
+    }
+}
+
 impl Display for ConsumerGroupState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "ConsumerGroup -> ID: {}, Name: {}", self.id, self.name)
+        write!(
+            f,
+            "ConsumerGroup -> ID: {}, Name: {}, Assignment strategy: {}",
+            self.id, self.name, self.assignment_strategy
+        )
     }
 }
 
@@ -521,8 +837,12 @@ impl Display for UserState {
         };
         write!(
             f,
-            "User -> ID: {}, Username: {}, Status: {}, Permissions: {}",
-            self.id, self.username, self.status, permissions
+            "User -> ID: {}, Username: {}, Status: {}, Permissions: {}, Roles: {}",
+            self.id,
+            self.username,
+            self.status,
+            permissions,
+            self.roles.join(",")
         )
     }
 }
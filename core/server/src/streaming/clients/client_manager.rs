@@ -109,6 +109,17 @@ impl ClientManager {
         self.clients.values().cloned().collect()
     }
 
+    /// Returns the number of the clients authenticated as the user, except the provided one.
+    pub async fn get_user_clients_count(&self, user_id: UserId, except_client_id: u32) -> usize {
+        let mut count = 0;
+        for (client_id, client) in self.clients.iter() {
+            if *client_id != except_client_id && client.read().await.user_id == Some(user_id) {
+                count += 1;
+            }
+        }
+        count
+    }
+
     pub async fn delete_clients_for_user(&mut self, user_id: UserId) -> Result<(), IggyError> {
         let mut clients_to_remove = Vec::new();
         for client in self.clients.values() {
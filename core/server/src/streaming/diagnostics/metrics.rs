@@ -16,12 +16,20 @@
  * under the License.
  */
 
+use iggy_common::{ConsumerKind, get_name_from_code};
+use prometheus_client::encoding::EncodeLabelSet;
 use prometheus_client::encoding::text::encode;
 use prometheus_client::metrics::counter::Counter;
+use prometheus_client::metrics::family::Family;
 use prometheus_client::metrics::gauge::Gauge;
+use prometheus_client::metrics::histogram::{Histogram, exponential_buckets};
 use prometheus_client::registry::Registry;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
 use tracing::error;
 
+type HistogramFamily<L> = Family<L, Histogram, fn() -> Histogram>;
+
 #[derive(Debug)]
 pub(crate) struct Metrics {
     registry: Registry,
@@ -33,6 +41,61 @@ pub(crate) struct Metrics {
     messages: Gauge,
     users: Gauge,
     clients: Gauge,
+    partition_messages_in: Family<PartitionLabels, Counter>,
+    partition_bytes_in: Family<PartitionLabels, Counter>,
+    partition_messages_out: Family<PartitionLabels, Counter>,
+    partition_bytes_out: Family<PartitionLabels, Counter>,
+    partition_segments: Family<PartitionLabels, Gauge>,
+    partition_current_offset: Family<PartitionLabels, Gauge>,
+    consumer_lag: Family<ConsumerLabels, Gauge>,
+    request_duration_seconds: HistogramFamily<RequestLabels>,
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct PartitionLabels {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct ConsumerLabels {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+    pub consumer_kind: &'static str,
+    pub consumer_id: u32,
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct RequestLabels {
+    pub command: &'static str,
+}
+
+/// The state of the partition exported as the labeled series, collected when the metrics are scraped.
+#[derive(Debug, Default)]
+pub(crate) struct PartitionMetrics {
+    pub messages_in: u64,
+    pub bytes_in: u64,
+    pub messages_out: u64,
+    pub bytes_out: u64,
+    pub segments_count: u32,
+    pub current_offset: u64,
+}
+
+/// The handle of the request duration histograms, cloned by each connection to record the handled commands.
+#[derive(Debug, Clone)]
+pub(crate) struct RequestMetrics {
+    request_duration_seconds: HistogramFamily<RequestLabels>,
+}
+
+impl RequestMetrics {
+    pub fn record(&self, code: u32, duration: Duration) {
+        let command = get_name_from_code(code).unwrap_or("unknown");
+        self.request_duration_seconds
+            .get_or_create(&RequestLabels { command })
+            .observe(duration.as_secs_f64());
+    }
 }
 
 impl Metrics {
@@ -47,6 +110,17 @@ impl Metrics {
             messages: Gauge::default(),
             users: Gauge::default(),
             clients: Gauge::default(),
+            partition_messages_in: Family::default(),
+            partition_bytes_in: Family::default(),
+            partition_messages_out: Family::default(),
+            partition_bytes_out: Family::default(),
+            partition_segments: Family::default(),
+            partition_current_offset: Family::default(),
+            consumer_lag: Family::default(),
+            request_duration_seconds: HistogramFamily::new_with_constructor(|| {
+                // From 100 µs up to ~3.3 s.
+                Histogram::new(exponential_buckets(0.0001, 2.0, 16))
+            }),
         };
 
         metrics.register_counter("http_requests", metrics.http_requests.clone());
@@ -57,6 +131,46 @@ impl Metrics {
         metrics.register_gauge("messages", metrics.messages.clone());
         metrics.register_gauge("users", metrics.users.clone());
         metrics.register_gauge("clients", metrics.clients.clone());
+        metrics.registry.register(
+            "partition_messages_in",
+            "total count of messages appended to the partition",
+            metrics.partition_messages_in.clone(),
+        );
+        metrics.registry.register(
+            "partition_bytes_in",
+            "total size of messages appended to the partition",
+            metrics.partition_bytes_in.clone(),
+        );
+        metrics.registry.register(
+            "partition_messages_out",
+            "total count of messages polled from the partition",
+            metrics.partition_messages_out.clone(),
+        );
+        metrics.registry.register(
+            "partition_bytes_out",
+            "total size of messages polled from the partition",
+            metrics.partition_bytes_out.clone(),
+        );
+        metrics.registry.register(
+            "partition_segments",
+            "count of segments in the partition",
+            metrics.partition_segments.clone(),
+        );
+        metrics.registry.register(
+            "partition_current_offset",
+            "offset of the last message in the partition",
+            metrics.partition_current_offset.clone(),
+        );
+        metrics.registry.register(
+            "consumer_lag",
+            "count of messages between the partition current offset and the stored consumer offset",
+            metrics.consumer_lag.clone(),
+        );
+        metrics.registry.register(
+            "request_duration_seconds",
+            "duration of handling the binary protocol commands",
+            metrics.request_duration_seconds.clone(),
+        );
 
         metrics
     }
@@ -79,6 +193,72 @@ impl Metrics {
         buffer
     }
 
+    pub fn request_metrics(&self) -> RequestMetrics {
+        RequestMetrics {
+            request_duration_seconds: self.request_duration_seconds.clone(),
+        }
+    }
+
+    /// Removes the partitions and consumers series, so that the deleted ones are no longer exported
+    /// once the current state is set again.
+    pub fn clear_partitions(&self) {
+        self.partition_messages_in.clear();
+        self.partition_bytes_in.clear();
+        self.partition_messages_out.clear();
+        self.partition_bytes_out.clear();
+        self.partition_segments.clear();
+        self.partition_current_offset.clear();
+        self.consumer_lag.clear();
+    }
+
+    pub fn set_partition(&self, labels: &PartitionLabels, partition: &PartitionMetrics) {
+        // The counters are kept by the partitions, so their values are just copied.
+        self.partition_messages_in
+            .get_or_create(labels)
+            .inner()
+            .store(partition.messages_in, Ordering::Relaxed);
+        self.partition_bytes_in
+            .get_or_create(labels)
+            .inner()
+            .store(partition.bytes_in, Ordering::Relaxed);
+        self.partition_messages_out
+            .get_or_create(labels)
+            .inner()
+            .store(partition.messages_out, Ordering::Relaxed);
+        self.partition_bytes_out
+            .get_or_create(labels)
+            .inner()
+            .store(partition.bytes_out, Ordering::Relaxed);
+        self.partition_segments
+            .get_or_create(labels)
+            .set(partition.segments_count as i64);
+        self.partition_current_offset
+            .get_or_create(labels)
+            .set(partition.current_offset as i64);
+    }
+
+    pub fn set_consumer_lag(
+        &self,
+        labels: &PartitionLabels,
+        kind: ConsumerKind,
+        consumer_id: u32,
+        lag: u64,
+    ) {
+        let consumer_kind = match kind {
+            ConsumerKind::Consumer => "consumer",
+            ConsumerKind::ConsumerGroup => "consumer_group",
+        };
+        self.consumer_lag
+            .get_or_create(&ConsumerLabels {
+                stream_id: labels.stream_id,
+                topic_id: labels.topic_id,
+                partition_id: labels.partition_id,
+                consumer_kind,
+                consumer_id,
+            })
+            .set(lag as i64);
+    }
+
     pub fn increment_http_requests(&self) {
         self.http_requests.inc();
     }
@@ -139,3 +319,54 @@ impl Metrics {
         self.clients.dec_by(count as i64);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy_common::SEND_MESSAGES_CODE;
+
+    #[test]
+    fn should_export_labeled_partition_and_consumer_lag_series() {
+        let metrics = Metrics::init();
+        let labels = PartitionLabels {
+            stream_id: 1,
+            topic_id: 2,
+            partition_id: 3,
+        };
+        metrics.set_partition(
+            &labels,
+            &PartitionMetrics {
+                messages_in: 10,
+                bytes_in: 1000,
+                segments_count: 1,
+                current_offset: 9,
+                ..Default::default()
+            },
+        );
+        metrics.set_consumer_lag(&labels, ConsumerKind::ConsumerGroup, 4, 5);
+
+        let output = metrics.get_formatted_output();
+        assert!(output.contains(
+            "partition_messages_in_total{stream_id=\"1\",topic_id=\"2\",partition_id=\"3\"} 10"
+        ));
+        assert!(output.contains(
+            "partition_current_offset{stream_id=\"1\",topic_id=\"2\",partition_id=\"3\"} 9"
+        ));
+        assert!(output.contains("consumer_lag{stream_id=\"1\",topic_id=\"2\",partition_id=\"3\",consumer_kind=\"consumer_group\",consumer_id=\"4\"} 5"));
+
+        metrics.clear_partitions();
+        let output = metrics.get_formatted_output();
+        assert!(!output.contains("partition_id=\"3\""));
+    }
+
+    #[test]
+    fn should_record_request_duration_by_command_name() {
+        let metrics = Metrics::init();
+        metrics
+            .request_metrics()
+            .record(SEND_MESSAGES_CODE, Duration::from_millis(1));
+
+        let output = metrics.get_formatted_output();
+        assert!(output.contains("request_duration_seconds_count{command=\"message.send\"} 1"));
+    }
+}
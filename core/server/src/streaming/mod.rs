@@ -24,11 +24,15 @@ pub mod partitions;
 pub mod persistence;
 pub mod personal_access_tokens;
 pub mod polling_consumer;
+pub mod producers;
+pub mod quotas;
+pub mod schemas;
 pub mod segments;
 pub mod session;
 pub mod storage;
 pub mod streams;
 pub mod systems;
 pub mod topics;
+pub mod transactions;
 pub mod users;
 pub mod utils;
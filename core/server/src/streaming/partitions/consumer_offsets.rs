@@ -16,66 +16,130 @@
  * under the License.
  */
 
+use crate::state::system::ConsumerOffsetState;
 use crate::streaming::partitions::COMPONENT;
 use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
 use crate::streaming::polling_consumer::PollingConsumer;
+use ahash::AHashMap;
 use dashmap::DashMap;
 use error_set::ErrContext;
 use iggy_common::ConsumerKind;
+use iggy_common::ConsumerOffsetsResetTarget;
 use iggy_common::IggyError;
-use tracing::trace;
+use iggy_common::IggyTimestamp;
+use tracing::{info, trace, warn};
 
 impl Partition {
     pub async fn get_consumer_offset(
         &self,
         consumer: PollingConsumer,
-    ) -> Result<Option<u64>, IggyError> {
+    ) -> Result<Option<ConsumerOffset>, IggyError> {
         trace!(
             "Getting consumer offset for {}, partition: {}, current: {}...",
             consumer, self.partition_id, self.current_offset
         );
 
-        match consumer {
-            PollingConsumer::Consumer(consumer_id, _) => {
-                let consumer_offset = self.consumer_offsets.get(&consumer_id);
-                if let Some(consumer_offset) = consumer_offset {
-                    return Ok(Some(consumer_offset.offset));
-                }
-            }
+        let consumer_offset = match consumer {
+            PollingConsumer::Consumer(consumer_id, _) => self.consumer_offsets.get(&consumer_id),
             PollingConsumer::ConsumerGroup(consumer_group_id, _) => {
-                let consumer_offset = self.consumer_offsets.get(&consumer_group_id);
-                if let Some(consumer_offset) = consumer_offset {
-                    return Ok(Some(consumer_offset.offset));
-                }
+                self.consumer_group_offsets.get(&consumer_group_id)
             }
+        };
+        Ok(consumer_offset.map(|consumer_offset| consumer_offset.clone()))
+    }
+
+    /// Checks if the offset can be stored, i.e. it does not exceed the current offset of the partition.
+    pub fn validate_consumer_offset(&self, offset: u64) -> Result<(), IggyError> {
+        if offset > self.current_offset {
+            return Err(IggyError::InvalidOffset(offset));
         }
 
-        Ok(None)
+        Ok(())
+    }
+
+    /// Resolves the offset to be stored after resetting the given stored offset to the target,
+    /// `None` means that the consumer starts from the first message of the partition.
+    /// The explicit target offsets for the other partitions leave the stored offset intact.
+    pub async fn resolve_reset_consumer_offset(
+        &self,
+        target: &ConsumerOffsetsResetTarget,
+        stored_offset: Option<u64>,
+    ) -> Result<Option<u64>, IggyError> {
+        // The offsets are resolved as the offset of the next message to consume, which follows the stored one.
+        let first_offset = self
+            .offloaded_segments
+            .first()
+            .map(|segment| segment.start_offset)
+            .or_else(|| self.segments.first().map(|segment| segment.start_offset()))
+            .unwrap_or_default();
+        let first_timestamp = self
+            .offloaded_segments
+            .first()
+            .map(|segment| segment.start_timestamp)
+            .or_else(|| {
+                self.segments
+                    .first()
+                    .map(|segment| segment.start_timestamp())
+            })
+            .unwrap_or_default();
+        let end_offset = self.current_offset + 1;
+        let next_offset = match target {
+            ConsumerOffsetsResetTarget::Offsets(offsets) => {
+                let Some(offset) = offsets
+                    .iter()
+                    .find(|offset| offset.partition_id == self.partition_id)
+                else {
+                    return Ok(stored_offset);
+                };
+
+                self.validate_consumer_offset(offset.offset)?;
+                return Ok(Some(offset.offset));
+            }
+            _ if !self.should_increment_offset => return Ok(None),
+            ConsumerOffsetsResetTarget::Earliest => first_offset,
+            ConsumerOffsetsResetTarget::Latest => end_offset,
+            ConsumerOffsetsResetTarget::Timestamp(timestamp) if *timestamp <= first_timestamp => {
+                first_offset
+            }
+            ConsumerOffsetsResetTarget::Timestamp(timestamp) => self
+                .get_messages_by_timestamp(IggyTimestamp::from(*timestamp), 1)
+                .await?
+                .first_offset()
+                .unwrap_or(end_offset),
+            ConsumerOffsetsResetTarget::Shift(shift) => stored_offset
+                .map_or(first_offset, |offset| offset + 1)
+                .saturating_add_signed(*shift)
+                .clamp(first_offset, end_offset),
+        };
+        Ok(next_offset.checked_sub(1))
     }
 
     pub async fn store_consumer_offset(
         &self,
         consumer: PollingConsumer,
         offset: u64,
+        metadata: Option<String>,
+        committed_at: IggyTimestamp,
     ) -> Result<(), IggyError> {
         trace!(
             "Storing offset: {} for {}, partition: {}, current: {}...",
             offset, consumer, self.partition_id, self.current_offset
         );
-        if offset > self.current_offset {
-            return Err(IggyError::InvalidOffset(offset));
-        }
+        self.validate_consumer_offset(offset)?;
 
         match consumer {
             PollingConsumer::Consumer(consumer_id, _) => {
-                self.store_offset(ConsumerKind::Consumer, consumer_id, offset)
+                self.store_offset(ConsumerKind::Consumer, consumer_id, offset, metadata, committed_at)
                     .await
                     .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to store consumer offset, consumer ID: {}, offset: {}", consumer_id, offset))?;
             }
             PollingConsumer::ConsumerGroup(consumer_id, _) => {
-                self.store_offset(ConsumerKind::ConsumerGroup, consumer_id, offset)
+                self.store_offset(ConsumerKind::ConsumerGroup, consumer_id, offset, metadata, committed_at)
                     .await
                     .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to store consumer group offset, consumer ID: {}, offset: {}", consumer_id, offset))?;
+                self.clear_delivery_attempts(consumer_id, offset)
+                    .await
+                    .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to clear delivery attempts, consumer ID: {}, offset: {}", consumer_id, offset))?;
             }
         };
 
@@ -87,19 +151,23 @@ impl Partition {
         kind: ConsumerKind,
         consumer_id: u32,
         offset: u64,
+        metadata: Option<String>,
+        committed_at: IggyTimestamp,
     ) -> Result<(), IggyError> {
         let consumer_offsets = self.get_consumer_offsets(kind);
         if let Some(mut consumer_offset) = consumer_offsets.get_mut(&consumer_id) {
             consumer_offset.offset = offset;
-            let path = consumer_offset.path.clone();
-            drop(consumer_offset);
+            consumer_offset.metadata = metadata;
+            consumer_offset.committed_at = committed_at;
+            let consumer_offset = consumer_offset.clone();
             self.storage
                 .partition
-                .save_consumer_offset(offset, &path)
+                .save_consumer_offset(&consumer_offset)
                 .await
                 .with_error_context(|error| {
                     format!(
-                        "{COMPONENT} (error: {error}) - failed to save consumer offset, consumer ID: {consumer_id}, offset: {offset}, path: {path}",
+                        "{COMPONENT} (error: {error}) - failed to save consumer offset, consumer ID: {consumer_id}, offset: {offset}, path: {}",
+                        consumer_offset.path
                     )
                 })?;
             return Ok(());
@@ -109,10 +177,11 @@ impl Partition {
             ConsumerKind::Consumer => &self.consumer_offsets_path,
             ConsumerKind::ConsumerGroup => &self.consumer_group_offsets_path,
         };
-        let consumer_offset = ConsumerOffset::new(kind, consumer_id, offset, path);
+        let consumer_offset =
+            ConsumerOffset::new(kind, consumer_id, offset, metadata, committed_at, path);
         self.storage
             .partition
-            .save_consumer_offset(offset, &consumer_offset.path)
+            .save_consumer_offset(&consumer_offset)
             .await
             .with_error_context(|error| {
                 format!(
@@ -124,7 +193,48 @@ impl Partition {
         Ok(())
     }
 
-    pub async fn load_consumer_offsets(&mut self) -> Result<(), IggyError> {
+    /// Reverts the stored offset of the consumer to the previous one, or removes it if there was none,
+    /// which is used to roll back the offsets stored for many partitions at once when any of them fails.
+    pub async fn revert_consumer_offset(
+        &self,
+        consumer: PollingConsumer,
+        previous_offset: Option<ConsumerOffset>,
+    ) -> Result<(), IggyError> {
+        let (kind, consumer_id) = match consumer {
+            PollingConsumer::Consumer(consumer_id, _) => (ConsumerKind::Consumer, consumer_id),
+            PollingConsumer::ConsumerGroup(consumer_id, _) => {
+                (ConsumerKind::ConsumerGroup, consumer_id)
+            }
+        };
+        trace!(
+            "Reverting offset for {consumer}, partition: {}, previous: {:?}...",
+            self.partition_id,
+            previous_offset.as_ref().map(|offset| offset.offset)
+        );
+
+        let Some(previous_offset) = previous_offset else {
+            let Some((_, offset)) = self.get_consumer_offsets(kind).remove(&consumer_id) else {
+                return Ok(());
+            };
+            return self.storage.partition.delete_consumer_offset(&offset.path).await
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to delete reverted consumer offset, kind: {kind}, consumer ID: {consumer_id}, partition ID: {}", self.partition_id));
+        };
+
+        self.store_offset(
+            kind,
+            consumer_id,
+            previous_offset.offset,
+            previous_offset.metadata,
+            previous_offset.committed_at,
+        )
+        .await
+        .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to revert consumer offset, kind: {kind}, consumer ID: {consumer_id}, partition ID: {}", self.partition_id))
+    }
+
+    pub async fn load_consumer_offsets(
+        &mut self,
+        state: &AHashMap<(ConsumerKind, u32), ConsumerOffsetState>,
+    ) -> Result<(), IggyError> {
         trace!(
             "Loading consumer offsets for partition with ID: {} for topic with ID: {} and stream with ID: {}...",
             self.partition_id, self.topic_id, self.stream_id
@@ -138,6 +248,65 @@ impl Partition {
             })?;
         self.load_consumer_offsets_from_storage(ConsumerKind::ConsumerGroup)
             .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to load consumer group offsets from storage"
+                )
+            })?;
+        self.restore_consumer_offsets(state).await
+    }
+
+    /// Restores the offsets committed through the state log, which are missing or outdated in the local storage.
+    async fn restore_consumer_offsets(
+        &self,
+        state: &AHashMap<(ConsumerKind, u32), ConsumerOffsetState>,
+    ) -> Result<(), IggyError> {
+        for ((kind, consumer_id), offset_state) in state {
+            let consumer_offsets = self.get_consumer_offsets(*kind);
+            if consumer_offsets.get(consumer_id).is_some_and(|offset| {
+                offset.committed_at.as_micros() >= offset_state.committed_at.as_micros()
+            }) {
+                continue;
+            }
+
+            if offset_state.offset > self.current_offset {
+                warn!(
+                    "Cannot restore offset: {} for {} with ID: {} greater than the current offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}.",
+                    offset_state.offset,
+                    kind,
+                    consumer_id,
+                    self.current_offset,
+                    self.partition_id,
+                    self.topic_id,
+                    self.stream_id
+                );
+                continue;
+            }
+
+            self.store_offset(
+                *kind,
+                *consumer_id,
+                offset_state.offset,
+                offset_state.metadata.clone(),
+                offset_state.committed_at,
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to restore consumer offset, kind: {kind}, consumer ID: {consumer_id}"
+                )
+            })?;
+            info!(
+                "Restored offset: {} for {} with ID: {} for partition with ID: {} for topic with ID: {} and stream with ID: {} from the state.",
+                offset_state.offset,
+                kind,
+                consumer_id,
+                self.partition_id,
+                self.topic_id,
+                self.stream_id
+            );
+        }
+        Ok(())
     }
 
     async fn load_consumer_offsets_from_storage(
@@ -199,6 +368,8 @@ impl Partition {
                     .ok_or(IggyError::ConsumerOffsetNotFound(consumer_id))?;
                 self.storage.partition.delete_consumer_offset(&offset.path).await
                     .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to delete consumer offset, consumer ID: {consumer_id}, partition ID: {partition_id}"))?;
+                self.delete_delayed_messages(ConsumerKind::Consumer, consumer_id).await
+                    .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to delete delayed messages, consumer ID: {consumer_id}, partition ID: {partition_id}"))?;
             }
             PollingConsumer::ConsumerGroup(consumer_id, _) => {
                 let (_, offset) = self
@@ -207,6 +378,8 @@ impl Partition {
                     .ok_or(IggyError::ConsumerOffsetNotFound(consumer_id))?;
                 self.storage.partition.delete_consumer_offset(&offset.path).await
                     .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to delete consumer group offset, consumer ID: {consumer_id}, partition ID: {partition_id}"))?;
+                self.delete_delayed_messages(ConsumerKind::ConsumerGroup, consumer_id).await
+                    .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to delete delayed messages, consumer group ID: {consumer_id}, partition ID: {partition_id}"))?;
             }
         };
         trace!("Deleted consumer offset for consumer: {consumer}, partition ID: {partition_id}.");
@@ -0,0 +1,326 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::system::SystemConfig;
+use crate::streaming::partitions::COMPONENT;
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::segments::{INDEX_EXTENSION, LOG_EXTENSION};
+use crate::streaming::utils::file;
+use error_set::ErrContext;
+use iggy_common::IggyError;
+use iggy_common::locking::{IggySharedMut, IggySharedMutFn};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tracing::{error, info, warn};
+
+const MOVING_SUFFIX: &str = "moving";
+const OLD_SUFFIX: &str = "old";
+const SEGMENT_EXTENSIONS: &[&str] = &[LOG_EXTENSION, INDEX_EXTENSION];
+
+/// Selects the data directory for each of the new partitions, starting with the one having the most free space.
+/// Every selected directory is expected to take at least the size of a single segment.
+pub(crate) fn select_data_directories(config: &SystemConfig, count: u32) -> Vec<String> {
+    if config.data_directories.is_empty() {
+        return vec![config.get_system_path(); count as usize];
+    }
+
+    let mut data_directories = config
+        .get_data_directories()
+        .into_iter()
+        .map(|data_directory| {
+            let available_space = file::available_space(&data_directory).unwrap_or_else(|error| {
+                warn!("Cannot get available space of data directory: {data_directory}. {error}");
+                0
+            });
+            (data_directory, available_space)
+        })
+        .collect::<Vec<_>>();
+    let segment_size = config.segment.size.as_bytes_u64();
+    let mut selected_data_directories = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let (data_directory, available_space) = data_directories
+            .iter_mut()
+            .rev()
+            .max_by_key(|(_, available_space)| *available_space)
+            .unwrap();
+        *available_space = available_space.saturating_sub(segment_size);
+        selected_data_directories.push(data_directory.clone());
+    }
+    selected_data_directories
+}
+
+/// Removes the partition directory along with the data directory it's linked to.
+pub(crate) async fn remove_partition_directory(path: &str) -> Result<(), std::io::Error> {
+    let metadata = fs::symlink_metadata(path).await?;
+    if !metadata.is_symlink() {
+        return fs::remove_dir_all(path).await;
+    }
+
+    let target = fs::read_link(path).await?;
+    if let Err(error) = fs::remove_dir_all(&target).await
+        && error.kind() != std::io::ErrorKind::NotFound
+    {
+        return Err(error);
+    }
+    fs::remove_file(path).await
+}
+
+/// Completes or reverts the partition moves interrupted by the server shutdown, based on the staging entries
+/// left in the partitions directory: `{id}.moving` is the new location and `{id}.old` the previous one.
+pub(crate) async fn recover_partition_moves(partitions_path: &str) -> Result<(), std::io::Error> {
+    for suffix in [MOVING_SUFFIX, OLD_SUFFIX] {
+        let mut dir_entries = fs::read_dir(partitions_path).await?;
+        while let Some(dir_entry) = dir_entries.next_entry().await? {
+            let name = dir_entry.file_name().into_string().unwrap_or_default();
+            let Some(partition_id) = name.strip_suffix(&format!(".{suffix}")) else {
+                continue;
+            };
+
+            let staging_path = dir_entry.path();
+            let partition_path = Path::new(partitions_path).join(partition_id);
+            if fs::symlink_metadata(&partition_path).await.is_err() {
+                fs::rename(&staging_path, &partition_path).await?;
+                warn!(
+                    "Recovered partition directory: {} from interrupted move.",
+                    partition_path.display()
+                );
+                continue;
+            }
+
+            if fs::symlink_metadata(&staging_path).await?.is_symlink() {
+                fs::remove_file(&staging_path).await?;
+            } else {
+                fs::remove_dir_all(&staging_path).await?;
+            }
+            warn!(
+                "Removed partition directory: {} left by interrupted move.",
+                staging_path.display()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+impl Partition {
+    /// Creates the partition directory in the data directory and links it from the partition path.
+    pub(crate) async fn link_data_directory(&self) -> Result<(), IggyError> {
+        if self.data_directory == self.config.get_system_path()
+            || fs::symlink_metadata(&self.partition_path).await.is_ok()
+        {
+            return Ok(());
+        }
+
+        let path = self.config.get_partition_path_in(
+            &self.data_directory,
+            self.stream_id,
+            self.topic_id,
+            self.partition_id,
+        );
+        let result = async {
+            fs::create_dir_all(&path).await?;
+            let path = fs::canonicalize(&path).await?;
+            if let Some(parent) = Path::new(&self.partition_path).parent() {
+                fs::create_dir_all(parent).await?;
+            }
+            fs::symlink(path, &self.partition_path).await
+        }
+        .await;
+        if let Err(error) = result {
+            error!(
+                "Cannot link partition directory: {} to data directory: {}. {error}",
+                self.partition_path, self.data_directory
+            );
+            return Err(IggyError::CannotCreatePartitionDirectory(
+                self.partition_id,
+                self.stream_id,
+                self.topic_id,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Sets the data directory based on the location the partition path is linked to.
+    pub(crate) async fn load_data_directory(&mut self) {
+        self.data_directory = self.config.get_system_path();
+        let Ok(target) = fs::read_link(&self.partition_path).await else {
+            return;
+        };
+
+        for data_directory in &self.config.data_directories {
+            if let Ok(path) = fs::canonicalize(data_directory).await
+                && target.starts_with(&path)
+            {
+                self.data_directory = data_directory.to_string();
+                return;
+            }
+        }
+
+        warn!(
+            "Partition directory: {} is linked to: {}, which is not in any of the configured data directories.",
+            self.partition_path,
+            target.display()
+        );
+        self.data_directory = target.to_string_lossy().to_string();
+    }
+
+    /// Moves the partition to another data directory while it stays available. The segments are copied first
+    /// without locking the partition, then the appends are paused only to copy the remaining changes
+    /// and to switch the partition path to the new location.
+    pub async fn move_to_data_directory(
+        partition: &IggySharedMut<Partition>,
+        data_directory: &str,
+    ) -> Result<(), IggyError> {
+        let (_relocation_guard, stream_id, topic_id, partition_id, partition_path, config) = {
+            let partition = partition.read().await;
+            let relocation_guard =
+                partition
+                    .relocation_lock
+                    .clone()
+                    .try_lock_owned()
+                    .map_err(|_| {
+                        IggyError::PartitionMoveInProgress(
+                            partition.partition_id,
+                            partition.topic_id,
+                            partition.stream_id,
+                        )
+                    })?;
+            if partition.data_directory == data_directory {
+                return Ok(());
+            }
+
+            (
+                relocation_guard,
+                partition.stream_id,
+                partition.topic_id,
+                partition.partition_id,
+                partition.partition_path.clone(),
+                partition.config.clone(),
+            )
+        };
+
+        let cannot_move_partition = |error: std::io::Error| {
+            error!(
+                "{COMPONENT} - failed to move partition with ID: {partition_id} for topic with ID: {topic_id} for stream with ID: {stream_id} to data directory: {data_directory}. {error}"
+            );
+            IggyError::CannotMovePartition(
+                partition_id,
+                topic_id,
+                stream_id,
+                data_directory.to_string(),
+            )
+        };
+        let source_path = fs::canonicalize(&partition_path)
+            .await
+            .map_err(cannot_move_partition)?;
+        let target_path = if data_directory == config.get_system_path() {
+            format!("{partition_path}.{MOVING_SUFFIX}")
+        } else {
+            config.get_partition_path_in(data_directory, stream_id, topic_id, partition_id)
+        };
+        info!(
+            "Moving partition with ID: {partition_id} for topic with ID: {topic_id} for stream with ID: {stream_id} from: {} to: {target_path}...",
+            source_path.display()
+        );
+        let copied_bytes = file::sync_directory(
+            &source_path.to_string_lossy(),
+            &target_path,
+            SEGMENT_EXTENSIONS,
+        )
+        .await
+        .map_err(cannot_move_partition)?;
+        info!(
+            "Copied {copied_bytes} bytes of partition with ID: {partition_id} for topic with ID: {topic_id} for stream with ID: {stream_id}, switching to: {target_path}..."
+        );
+
+        let mut partition = partition.write().await;
+        partition
+            .flush_unsaved_buffer(true)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to flush unsaved buffer before moving partition with ID: {partition_id}")
+            })?;
+        for segment in partition.segments.iter_mut() {
+            segment.release_files().await;
+        }
+
+        let switched = partition
+            .switch_data_directory(&source_path, &target_path)
+            .await;
+        for segment in partition.segments.iter_mut() {
+            segment.reopen_files().await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to reopen segment files after moving partition with ID: {partition_id}")
+            })?;
+        }
+        partition.cached_segments.lock().await.clear();
+        switched.map_err(cannot_move_partition)?;
+
+        if partition.data_directory == config.get_system_path() {
+            let old_path = format!("{partition_path}.{OLD_SUFFIX}");
+            fs::remove_dir_all(old_path)
+                .await
+                .map_err(cannot_move_partition)?;
+        } else {
+            fs::remove_dir_all(&source_path)
+                .await
+                .map_err(cannot_move_partition)?;
+        }
+        partition.data_directory = data_directory.to_string();
+        info!(
+            "Moved partition with ID: {partition_id} for topic with ID: {topic_id} for stream with ID: {stream_id} to data directory: {data_directory}."
+        );
+        Ok(())
+    }
+
+    async fn switch_data_directory(
+        &self,
+        source_path: &Path,
+        target_path: &str,
+    ) -> Result<(), std::io::Error> {
+        if fs::symlink_metadata(&self.partition_path).await.is_err() {
+            fs::remove_dir_all(target_path).await?;
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "partition has been deleted",
+            ));
+        }
+
+        file::sync_directory(
+            &source_path.to_string_lossy(),
+            target_path,
+            SEGMENT_EXTENSIONS,
+        )
+        .await?;
+        let moving_path = format!("{}.{MOVING_SUFFIX}", self.partition_path);
+        if target_path == moving_path {
+            fs::remove_file(&self.partition_path).await?;
+            return fs::rename(&moving_path, &self.partition_path).await;
+        }
+
+        if fs::symlink_metadata(&moving_path).await.is_ok() {
+            fs::remove_file(&moving_path).await?;
+        }
+        fs::symlink(fs::canonicalize(target_path).await?, &moving_path).await?;
+        if self.data_directory == self.config.get_system_path() {
+            let old_path = PathBuf::from(format!("{}.{OLD_SUFFIX}", self.partition_path));
+            fs::rename(&self.partition_path, old_path).await?;
+        }
+        fs::rename(&moving_path, &self.partition_path).await
+    }
+}
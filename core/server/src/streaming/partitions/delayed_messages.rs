@@ -0,0 +1,321 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::partitions::COMPONENT;
+use crate::streaming::partitions::partition::{DelayedMessages, Partition};
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::segments::{IggyMessagesBatchMut, IggyMessagesBatchSet};
+use error_set::ErrContext;
+use iggy_common::{ConsumerKind, IggyError, IggyTimestamp};
+use std::collections::BTreeSet;
+use std::path::Path;
+use tokio::fs::create_dir_all;
+use tracing::{error, trace};
+
+impl Partition {
+    /// Returns up to `count` delayed messages skipped by the consumer, which are due by now and have already been
+    /// passed by the consumer offset, along with the tracked entries to be removed by `remove_delayed_messages`,
+    /// once the messages are actually returned to the consumer. The due messages ahead of the consumer offset
+    /// are returned by the regular polling, so their entries are removed as well.
+    pub async fn get_due_delayed_messages(
+        &self,
+        consumer: PollingConsumer,
+        now: IggyTimestamp,
+        count: u32,
+    ) -> Result<(IggyMessagesBatchSet, Vec<(u64, u64)>), IggyError> {
+        let key = delayed_messages_key(consumer);
+        let stored_offset = match consumer {
+            PollingConsumer::Consumer(consumer_id, _) => self
+                .consumer_offsets
+                .get(&consumer_id)
+                .map(|offset| offset.offset),
+            PollingConsumer::ConsumerGroup(group_id, _) => self
+                .consumer_group_offsets
+                .get(&group_id)
+                .map(|offset| offset.offset),
+        };
+        let Some((due_entries, due_offsets)) =
+            self.delayed_messages
+                .get(&key)
+                .and_then(|delayed_messages| {
+                    find_due_messages(
+                        &delayed_messages.messages,
+                        now.as_micros(),
+                        stored_offset,
+                        count,
+                    )
+                })
+        else {
+            return Ok((IggyMessagesBatchSet::empty(), Vec::new()));
+        };
+
+        let mut messages = IggyMessagesBatchSet::empty();
+        for offset in due_offsets {
+            let batch_set = self.get_messages_by_offset(offset, 1).await?;
+            // The message might have been already removed, e.g. by the retention policy or the compaction.
+            if batch_set.first_offset() == Some(offset) {
+                messages.add_batch_set(batch_set);
+            }
+        }
+        trace!(
+            "Found {} due delayed messages for {consumer}, partition: {}.",
+            messages.count(),
+            self.partition_id
+        );
+        Ok((messages, due_entries))
+    }
+
+    /// Removes the entries of the delayed messages, which have been returned to the consumer. They are saved
+    /// with the next flush, as the messages would only be delivered again if the removal was lost.
+    pub fn remove_delayed_messages(&self, consumer: PollingConsumer, entries: &[(u64, u64)]) {
+        if entries.is_empty() {
+            return;
+        }
+
+        let key = delayed_messages_key(consumer);
+        let Some(mut delayed_messages) = self.delayed_messages.get_mut(&key) else {
+            return;
+        };
+        let count = delayed_messages.messages.len();
+        for entry in entries {
+            delayed_messages.messages.remove(entry);
+        }
+        if delayed_messages.messages.len() != count {
+            self.unsaved_delayed_messages.insert(key);
+        }
+    }
+
+    /// Saves the delayed messages of the consumers, whose entries have been removed since the last flush.
+    pub(crate) async fn persist_delayed_messages(&self) -> Result<(), IggyError> {
+        let keys = self
+            .unsaved_delayed_messages
+            .iter()
+            .map(|key| *key)
+            .collect::<Vec<_>>();
+        for key in keys {
+            self.unsaved_delayed_messages.remove(&key);
+            let delayed_messages = self
+                .delayed_messages
+                .get(&key)
+                .map(|delayed_messages| delayed_messages.clone())
+                .unwrap_or_else(|| DelayedMessages::new(key.0, key.1));
+            if let Err(error) = self.save_delayed_messages(&delayed_messages).await {
+                self.unsaved_delayed_messages.insert(key);
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    /// Marks the partition as having the messages scheduled for the later delivery, if the batch contains any.
+    /// The directory of the delayed messages is created at the same time, so that the mark survives the restart.
+    pub(crate) async fn mark_scheduled_messages(
+        &mut self,
+        batch: &IggyMessagesBatchMut,
+    ) -> Result<(), IggyError> {
+        if self.has_scheduled_messages
+            || !batch.iter().any(|message| message.deliver_at().is_some())
+        {
+            return Ok(());
+        }
+
+        if create_dir_all(&self.delayed_messages_path).await.is_err() {
+            error!(
+                "Failed to create delayed messages directory for partition with ID: {} for stream with ID: {} and topic with ID: {}.",
+                self.partition_id, self.stream_id, self.topic_id
+            );
+            return Err(IggyError::CannotCreatePartitionDirectory(
+                self.partition_id,
+                self.stream_id,
+                self.topic_id,
+            ));
+        }
+
+        self.has_scheduled_messages = true;
+        Ok(())
+    }
+
+    /// Stores the messages scheduled for the later delivery, which have been skipped by the consumer.
+    /// They are saved right away, unlike the removals, because the consumer offset already points past them.
+    pub async fn track_delayed_messages(
+        &self,
+        consumer: PollingConsumer,
+        messages: Vec<(u64, u64)>,
+    ) -> Result<(), IggyError> {
+        if messages.is_empty() {
+            return Ok(());
+        }
+
+        let key = delayed_messages_key(consumer);
+        let delayed_messages = {
+            let mut delayed_messages = self
+                .delayed_messages
+                .entry(key)
+                .or_insert_with(|| DelayedMessages::new(key.0, key.1));
+            let count = delayed_messages.messages.len();
+            delayed_messages.messages.extend(messages);
+            if delayed_messages.messages.len() == count {
+                return Ok(());
+            }
+            delayed_messages.clone()
+        };
+        self.unsaved_delayed_messages.remove(&key);
+        self.save_delayed_messages(&delayed_messages).await
+    }
+
+    /// Removes the delayed messages of the consumer, e.g. when its offset is deleted, so it starts over anyway.
+    pub(crate) async fn delete_delayed_messages(
+        &self,
+        kind: ConsumerKind,
+        consumer_id: u32,
+    ) -> Result<(), IggyError> {
+        self.unsaved_delayed_messages.remove(&(kind, consumer_id));
+        if self.delayed_messages.remove(&(kind, consumer_id)).is_none() {
+            return Ok(());
+        }
+
+        self.save_delayed_messages(&DelayedMessages::new(kind, consumer_id))
+            .await
+    }
+
+    pub async fn load_delayed_messages(&mut self) -> Result<(), IggyError> {
+        trace!(
+            "Loading delayed messages for partition with ID: {} for topic with ID: {} and stream with ID: {}...",
+            self.partition_id, self.topic_id, self.stream_id
+        );
+        // The directory is created once the first message scheduled for the later delivery is appended.
+        self.has_scheduled_messages = Path::new(&self.delayed_messages_path).exists();
+        if !self.has_scheduled_messages {
+            return Ok(());
+        }
+
+        let delayed_messages = self
+            .storage
+            .partition
+            .load_delayed_messages(&self.delayed_messages_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to load delayed messages, path: {}",
+                    self.delayed_messages_path
+                )
+            })?;
+        for delayed_messages in delayed_messages {
+            self.delayed_messages.insert(
+                (delayed_messages.kind, delayed_messages.consumer_id),
+                delayed_messages,
+            );
+        }
+        Ok(())
+    }
+
+    async fn save_delayed_messages(
+        &self,
+        delayed_messages: &DelayedMessages,
+    ) -> Result<(), IggyError> {
+        let path = format!(
+            "{}/{}_{}",
+            self.delayed_messages_path, delayed_messages.kind, delayed_messages.consumer_id
+        );
+        self.storage
+            .partition
+            .save_delayed_messages(delayed_messages, &path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to save delayed messages of {} with ID: {}, partition: {self}",
+                    delayed_messages.kind, delayed_messages.consumer_id
+                )
+            })
+    }
+}
+
+fn delayed_messages_key(consumer: PollingConsumer) -> (ConsumerKind, u32) {
+    match consumer {
+        PollingConsumer::Consumer(consumer_id, _) => (ConsumerKind::Consumer, consumer_id),
+        PollingConsumer::ConsumerGroup(group_id, _) => (ConsumerKind::ConsumerGroup, group_id),
+    }
+}
+
+/// The entries of the due delayed messages to be removed and the offsets of the messages to be returned.
+type DueMessages = (Vec<(u64, u64)>, Vec<u64>);
+
+/// Finds up to `count` messages which are due by now and have already been passed by the consumer offset,
+/// or `None` if no messages are due. Returns the entries to be removed, including the due messages ahead
+/// of the consumer offset, since they will be returned by the regular polling, and the sorted offsets
+/// of the messages to be returned.
+fn find_due_messages(
+    messages: &BTreeSet<(u64, u64)>,
+    now: u64,
+    stored_offset: Option<u64>,
+    count: u32,
+) -> Option<DueMessages> {
+    let mut due = messages.range(..(now.saturating_add(1), 0)).peekable();
+    due.peek()?;
+
+    let mut entries = Vec::new();
+    let mut offsets = Vec::new();
+    for &message in due {
+        let (_, offset) = message;
+        if stored_offset.is_some_and(|stored_offset| offset <= stored_offset) {
+            if offsets.len() == count as usize {
+                break;
+            }
+            offsets.push(offset);
+        }
+        entries.push(message);
+    }
+    offsets.sort_unstable();
+    Some((entries, offsets))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_due_messages_should_be_found() {
+        let messages = BTreeSet::from([(100, 5), (200, 3), (300, 7)]);
+
+        let (entries, offsets) = find_due_messages(&messages, 200, Some(10), 10).unwrap();
+
+        assert_eq!(offsets, vec![3, 5]);
+        assert_eq!(entries, vec![(100, 5), (200, 3)]);
+        assert!(find_due_messages(&messages, 99, Some(10), 10).is_none());
+    }
+
+    #[test]
+    fn due_messages_ahead_of_consumer_offset_should_be_left_to_regular_polling() {
+        let messages = BTreeSet::from([(100, 5), (100, 12)]);
+
+        let (entries, offsets) = find_due_messages(&messages, 100, Some(10), 10).unwrap();
+
+        assert_eq!(offsets, vec![5]);
+        assert_eq!(entries, vec![(100, 5), (100, 12)]);
+    }
+
+    #[test]
+    fn due_messages_exceeding_count_should_be_left_for_next_poll() {
+        let messages = BTreeSet::from([(100, 5), (200, 3), (300, 7)]);
+
+        let (entries, offsets) = find_due_messages(&messages, 300, Some(10), 2).unwrap();
+
+        assert_eq!(offsets, vec![3, 5]);
+        assert_eq!(entries, vec![(100, 5), (200, 3)]);
+    }
+}
@@ -0,0 +1,175 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::partitions::COMPONENT;
+use crate::streaming::partitions::partition::{DeliveryAttempts, Partition};
+use error_set::ErrContext;
+use iggy_common::IggyError;
+use std::path::Path;
+use tokio::fs::create_dir_all;
+use tracing::{error, trace};
+
+impl Partition {
+    /// Increments the number of failed delivery attempts of the message with the given offset for the consumer group,
+    /// and returns the updated counter. The counter is reset when a different message fails.
+    pub async fn record_delivery_failure(
+        &self,
+        consumer_group_id: u32,
+        offset: u64,
+    ) -> Result<u32, IggyError> {
+        if offset > self.current_offset {
+            return Err(IggyError::InvalidOffset(offset));
+        }
+
+        // The counter is updated within the entry, so that the concurrent failures of the same message are all counted.
+        let delivery_attempts = *self
+            .delivery_attempts
+            .entry(consumer_group_id)
+            .and_modify(|current| {
+                *current = next_delivery_attempts(Some(current), consumer_group_id, offset)
+            })
+            .or_insert_with(|| next_delivery_attempts(None, consumer_group_id, offset));
+        let path = format!("{}/{consumer_group_id}", self.delivery_attempts_path);
+        self.storage
+            .partition
+            .save_delivery_attempts(&delivery_attempts, &path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to save delivery attempts for consumer group with ID: {consumer_group_id}, partition: {self}",
+                )
+            })?;
+        trace!(
+            "Recorded failed delivery attempt: {} of message at offset: {offset} for consumer group with ID: {consumer_group_id}, partition with ID: {}.",
+            delivery_attempts.attempts, self.partition_id
+        );
+        Ok(delivery_attempts.attempts)
+    }
+
+    /// Removes the delivery attempts of the consumer group, once the offset of the failed message has been stored.
+    pub(crate) async fn clear_delivery_attempts(
+        &self,
+        consumer_group_id: u32,
+        stored_offset: u64,
+    ) -> Result<(), IggyError> {
+        let Some((_, delivery_attempts)) = self
+            .delivery_attempts
+            .remove_if(&consumer_group_id, |_, attempts| {
+                attempts.offset <= stored_offset
+            })
+        else {
+            return Ok(());
+        };
+
+        let path = format!("{}/{consumer_group_id}", self.delivery_attempts_path);
+        self.storage
+            .partition
+            .delete_delivery_attempts(&path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to delete delivery attempts for message at offset: {} of consumer group with ID: {consumer_group_id}, partition: {self}",
+                    delivery_attempts.offset
+                )
+            })
+    }
+
+    pub async fn load_delivery_attempts(&mut self) -> Result<(), IggyError> {
+        trace!(
+            "Loading delivery attempts for partition with ID: {} for topic with ID: {} and stream with ID: {}...",
+            self.partition_id, self.topic_id, self.stream_id
+        );
+        // The partitions created before the delivery attempts were introduced have no such directory.
+        if !Path::new(&self.delivery_attempts_path).exists()
+            && create_dir_all(&self.delivery_attempts_path).await.is_err()
+        {
+            error!(
+                "Failed to create delivery attempts directory for partition with ID: {} for stream with ID: {} and topic with ID: {}.",
+                self.partition_id, self.stream_id, self.topic_id
+            );
+            return Err(IggyError::CannotCreatePartitionDirectory(
+                self.partition_id,
+                self.stream_id,
+                self.topic_id,
+            ));
+        }
+
+        let delivery_attempts = self
+            .storage
+            .partition
+            .load_delivery_attempts(&self.delivery_attempts_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to load delivery attempts, path: {}",
+                    self.delivery_attempts_path
+                )
+            })?;
+        for delivery_attempts in delivery_attempts {
+            self.delivery_attempts
+                .insert(delivery_attempts.consumer_group_id, delivery_attempts);
+        }
+        Ok(())
+    }
+}
+
+/// Only the message blocking the consumer group is tracked, so the failure of a different message starts over.
+fn next_delivery_attempts(
+    current: Option<&DeliveryAttempts>,
+    consumer_group_id: u32,
+    offset: u64,
+) -> DeliveryAttempts {
+    let attempts = match current {
+        Some(current) if current.offset == offset => current.attempts.saturating_add(1),
+        _ => 1,
+    };
+    DeliveryAttempts {
+        consumer_group_id,
+        offset,
+        attempts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failed_delivery_of_the_same_message_should_increment_attempts() {
+        let first = next_delivery_attempts(None, 1, 10);
+        assert_eq!(first.attempts, 1);
+
+        let second = next_delivery_attempts(Some(&first), 1, 10);
+        assert_eq!(second.attempts, 2);
+        assert_eq!(second.offset, 10);
+        assert_eq!(second.consumer_group_id, 1);
+    }
+
+    #[test]
+    fn failed_delivery_of_another_message_should_reset_attempts() {
+        let current = DeliveryAttempts {
+            consumer_group_id: 1,
+            offset: 10,
+            attempts: 3,
+        };
+
+        let next = next_delivery_attempts(Some(&current), 1, 11);
+        assert_eq!(next.attempts, 1);
+        assert_eq!(next.offset, 11);
+    }
+}
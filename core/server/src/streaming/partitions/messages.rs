@@ -20,11 +20,21 @@ use crate::streaming::partitions::COMPONENT;
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::segments::*;
+use ahash::AHashMap;
 use error_set::ErrContext;
-use iggy_common::{Confirmation, IggyError, IggyTimestamp, Sizeable};
+use iggy_common::{
+    Confirmation, IggyError, IggyTimestamp, IsolationLevel, MessageFilter, Sizeable,
+};
 use std::sync::atomic::Ordering;
 use tracing::trace;
 
+/// The maximum number of messages examined by a single filtered poll, so that a selective
+/// filter cannot turn one request into a scan of the whole partition.
+const MAX_FILTER_SCANNED_MESSAGES: u64 = 10_000;
+
+/// The minimum number of messages read at once while looking for more filter matches.
+const MIN_FILTER_READ_COUNT: u32 = 1_000;
+
 impl Partition {
     /// Retrieves messages by timestamp (up to a specified count).
     pub async fn get_messages_by_timestamp(
@@ -44,13 +54,28 @@ impl Partition {
 
         let query_ts = timestamp.as_micros();
 
+        // The offloaded segments precede the local ones, so they are read first.
+        let mut batches = self
+            .get_offloaded_messages_by_timestamp(query_ts, count)
+            .await?;
+        if batches.count() >= count {
+            return Ok(batches);
+        }
+
         let filtered_segments: Vec<&Segment> = self
             .segments
             .iter()
             .filter(|segment| segment.end_timestamp() >= query_ts)
             .collect();
 
-        Self::get_messages_from_segments_by_timestamp(filtered_segments, query_ts, count).await
+        let messages = Self::get_messages_from_segments_by_timestamp(
+            filtered_segments,
+            query_ts,
+            count - batches.count(),
+        )
+        .await?;
+        batches.add_batch_set(messages);
+        Ok(batches)
     }
 
     // Retrieves messages by offset (up to a specified count).
@@ -68,18 +93,37 @@ impl Partition {
             return Ok(IggyMessagesBatchSet::empty());
         }
 
+        let mut batches = IggyMessagesBatchSet::empty();
+        let mut start_offset = start_offset;
+        if self.is_offloaded_offset(start_offset) {
+            batches = self
+                .get_offloaded_messages_by_offset(start_offset, count)
+                .await?;
+            if batches.count() >= count {
+                return Ok(batches);
+            }
+            start_offset = self.segments[0].start_offset();
+        }
+
         let start_segment_idx = match self
             .segments
             .iter()
             .rposition(|segment| segment.start_offset() <= start_offset)
         {
             Some(idx) => idx,
-            None => return Ok(IggyMessagesBatchSet::empty()),
+            None => return Ok(batches),
         };
 
         let relevant_segments: Vec<&Segment> = self.segments[start_segment_idx..].iter().collect();
 
-        Self::get_messages_from_segments(relevant_segments, start_offset, count).await
+        let messages = Self::get_messages_from_segments(
+            relevant_segments,
+            start_offset,
+            count - batches.count(),
+        )
+        .await?;
+        batches.add_batch_set(messages);
+        Ok(batches)
     }
 
     // Retrieves the first messages (up to a specified count).
@@ -136,6 +180,106 @@ impl Partition {
         self.get_messages_by_offset(offset, count).await
     }
 
+    /// Applies the header filter and the isolation level to the already fetched messages and keeps
+    /// reading the subsequent messages until `count` of them are visible, the end of the partition
+    /// (or its last stable offset, for `read_committed`) is reached or the scan limit is exceeded.
+    /// If `delayed_messages` are provided, the messages scheduled for the later delivery are skipped
+    /// and collected there along with the time of their delivery.
+    ///
+    /// Returns the visible messages and the offset from which the next poll should continue,
+    /// or `None` if no messages were examined at all.
+    pub async fn filter_messages(
+        &self,
+        messages: IggyMessagesBatchSet,
+        filter: Option<&MessageFilter>,
+        isolation_level: IsolationLevel,
+        count: u32,
+        mut delayed_messages: Option<&mut Vec<(u64, u64)>>,
+    ) -> Result<(IggyMessagesBatchSet, Option<u64>), IggyError> {
+        let read_committed = isolation_level == IsolationLevel::ReadCommitted;
+        let max_offset = if read_committed {
+            self.last_stable_offset()
+        } else {
+            Some(self.current_offset)
+        };
+        let Some(max_offset) = max_offset else {
+            return Ok((IggyMessagesBatchSet::empty(), None));
+        };
+
+        let now = IggyTimestamp::now().as_micros();
+        let mut result = IggyMessagesBatchSet::empty();
+        let mut next_offset = None;
+        let mut scanned_count = 0u64;
+        let mut messages = messages;
+
+        loop {
+            for batch in messages.iter() {
+                let remaining_count = count - result.count();
+                if remaining_count == 0 {
+                    break;
+                }
+
+                let outcomes = if read_committed {
+                    self.get_transaction_outcomes(batch, max_offset)
+                } else {
+                    AHashMap::new()
+                };
+                let (filtered, last_scanned_offset) =
+                    batch.filter_by(max_offset, remaining_count, |message| {
+                        let visible = (!read_committed || Self::is_committed(message, &outcomes))
+                            && filter.is_none_or(|filter| filter.matches(message.user_headers()));
+                        let Some(delayed_messages) = delayed_messages.as_deref_mut() else {
+                            return visible;
+                        };
+                        match message
+                            .deliver_at()
+                            .map(|deliver_at| deliver_at.as_micros())
+                        {
+                            Some(deliver_at) if visible && deliver_at > now => {
+                                delayed_messages.push((deliver_at, message.header().offset()));
+                                false
+                            }
+                            _ => visible,
+                        }
+                    });
+                let Some(last_scanned_offset) = last_scanned_offset else {
+                    continue;
+                };
+
+                scanned_count += last_scanned_offset + 1 - batch.first_offset().unwrap_or_default();
+                next_offset = Some(last_scanned_offset + 1);
+                if !filtered.is_empty() {
+                    result.add_batch(filtered);
+                }
+            }
+
+            let Some(offset) = next_offset else {
+                break;
+            };
+
+            if result.count() >= count
+                || offset > max_offset
+                || scanned_count >= MAX_FILTER_SCANNED_MESSAGES
+            {
+                break;
+            }
+
+            trace!(
+                "Filtered {} messages out of {scanned_count} scanned for partition: {}, continuing from offset: {offset}...",
+                result.count(),
+                self.partition_id
+            );
+            messages = self
+                .get_messages_by_offset(offset, count.max(MIN_FILTER_READ_COUNT))
+                .await?;
+            if messages.is_empty() {
+                break;
+            }
+        }
+
+        Ok((result, next_offset))
+    }
+
     /// Retrieves messages from multiple segments.
     async fn get_messages_from_segments(
         segments: Vec<&Segment>,
@@ -250,6 +394,7 @@ impl Partition {
             self.current_offset + 1
         };
 
+        self.mark_scheduled_messages(&batch).await?;
         let batch_messages_count = batch.count();
         let batch_messages_size = batch.get_size_bytes();
 
@@ -277,6 +422,9 @@ impl Partition {
             self.current_offset = last_offset;
         }
 
+        self.throughput
+            .record_in(batch_messages_count, batch_messages_size.as_bytes_u64());
+        self.messages_appended.notify_waiters();
         self.unsaved_messages_count += batch_messages_count;
         self.unsaved_messages_size += batch_messages_size;
 
@@ -322,6 +470,7 @@ impl Partition {
             })?;
             self.unsaved_messages_count = 0;
             self.unsaved_messages_size = 0.into();
+            self.persist_producer_sequences().await?;
         }
 
         Ok(())
@@ -334,7 +483,7 @@ impl Partition {
     pub async fn flush_unsaved_buffer(&mut self, fsync: bool) -> Result<(), IggyError> {
         let _fsync = fsync;
         if self.unsaved_messages_count == 0 {
-            return Ok(());
+            return self.persist_delayed_messages().await;
         }
 
         let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
@@ -353,19 +502,33 @@ impl Partition {
 
         self.unsaved_messages_count = 0;
         self.unsaved_messages_size = 0.into();
-        Ok(())
+        self.persist_producer_sequences().await?;
+        self.persist_delayed_messages().await
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::configs::system::{MessageDeduplicationConfig, SystemConfig};
+    use crate::archiver::ArchiverKind;
+    use crate::configs::server::DiskArchiverConfig;
+    use crate::configs::system::{
+        MessageDeduplicationConfig, SegmentConfig, SystemConfig, TieredStorageConfig,
+    };
+    use crate::streaming::partitions::segments::CompactedSegments;
+    use crate::streaming::partitions::transactions::PartitionTransactions;
     use crate::streaming::persistence::persister::{FileWithSyncPersister, PersisterKind};
     use crate::streaming::storage::SystemStorage;
+    use crate::streaming::transactions::markers::{self, TransactionOutcome};
     use crate::streaming::utils::MemoryPool;
-    use bytes::Bytes;
-    use iggy_common::{IggyExpiry, IggyMessage};
+    use bytes::{Bytes, BytesMut};
+    use futures::future::join_all;
+    use iggy_common::{
+        Confirmation, HeaderKey, HeaderValue, IggyByteSize, IggyDuration, IggyExpiry, IggyMessage,
+        PollingStrategy, ProducerSequence,
+    };
+    use std::collections::HashMap;
+    use std::str::FromStr;
     use std::sync::Arc;
     use std::sync::atomic::{AtomicU32, AtomicU64};
     use tempfile::TempDir;
@@ -702,45 +865,716 @@ mod tests {
         assert_eq!(loaded_messages.count(), 4);
     }
 
+    #[tokio::test]
+    async fn filter_should_return_only_messages_with_matching_headers() {
+        let (mut partition, _tempdir) = create_partition(false).await;
+        let messages = (0..10)
+            .map(|id| create_message_with_region(id + 1, if id % 3 == 0 { "eu" } else { "us" }))
+            .collect::<Vec<_>>();
+        append(&mut partition, &messages).await;
+
+        let filter = MessageFilter::equals(
+            HeaderKey::new("region").unwrap(),
+            HeaderValue::from_str("eu").unwrap(),
+        );
+        let messages = partition.get_messages_by_offset(0, 10).await.unwrap();
+        let (filtered, next_offset) = partition
+            .filter_messages(
+                messages,
+                Some(&filter),
+                IsolationLevel::ReadUncommitted,
+                10,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(filtered.count(), 4);
+        assert_eq!(next_offset, Some(10));
+        for batch in filtered.iter() {
+            for message in batch.iter() {
+                assert_eq!(message.header().offset() % 3, 0);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn filter_should_stop_scanning_after_requested_count_is_matched() {
+        let (mut partition, _tempdir) = create_partition(false).await;
+        let messages = (0..10)
+            .map(|id| create_message_with_region(id + 1, if id % 2 == 0 { "eu" } else { "us" }))
+            .collect::<Vec<_>>();
+        append(&mut partition, &messages).await;
+
+        let filter = MessageFilter::equals(
+            HeaderKey::new("region").unwrap(),
+            HeaderValue::from_str("us").unwrap(),
+        );
+        let messages = partition.get_messages_by_offset(0, 2).await.unwrap();
+        let (filtered, next_offset) = partition
+            .filter_messages(
+                messages,
+                Some(&filter),
+                IsolationLevel::ReadUncommitted,
+                2,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(filtered.count(), 2);
+        assert_eq!(filtered.last_offset(), Some(3));
+        assert_eq!(next_offset, Some(4));
+    }
+
+    #[tokio::test]
+    async fn filter_without_matches_should_report_next_offset_after_scanned_messages() {
+        let (mut partition, _tempdir) = create_partition(false).await;
+        let messages = (0..5)
+            .map(|id| create_message_with_region(id + 1, "us"))
+            .collect::<Vec<_>>();
+        append(&mut partition, &messages).await;
+
+        let filter = MessageFilter::exists(HeaderKey::new("tenant").unwrap());
+        let messages = partition.get_messages_by_offset(1, 2).await.unwrap();
+        let (filtered, next_offset) = partition
+            .filter_messages(
+                messages,
+                Some(&filter),
+                IsolationLevel::ReadUncommitted,
+                2,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert!(filtered.is_empty());
+        assert_eq!(next_offset, Some(5));
+    }
+
+    #[tokio::test]
+    async fn read_committed_poll_should_stop_before_open_transaction() {
+        let (mut partition, _tempdir) = create_partition(false).await;
+        append(&mut partition, &[create_message(1, "a")]).await;
+        append_transaction(&mut partition, 10, &[create_transaction_message(2, 10)]).await;
+        append(&mut partition, &[create_message(3, "c")]).await;
+
+        let (committed, next_offset) = read_committed(&partition, 0).await;
+
+        assert_eq!(partition.last_stable_offset(), Some(0));
+        assert_eq!(committed, vec![0]);
+        assert_eq!(next_offset, Some(1));
+    }
+
+    #[tokio::test]
+    async fn read_committed_poll_should_return_committed_messages_without_marker() {
+        let (mut partition, _tempdir) = create_persisted_partition().await;
+        append(&mut partition, &[create_message(1, "a")]).await;
+        append_transaction(&mut partition, 10, &[create_transaction_message(2, 10)]).await;
+        append_transaction(&mut partition, 11, &[create_transaction_message(3, 11)]).await;
+        append(&mut partition, &[create_message(4, "d")]).await;
+        end_transaction(&mut partition, 10, TransactionOutcome::Committed).await;
+        end_transaction(&mut partition, 11, TransactionOutcome::Aborted).await;
+
+        let (committed, next_offset) = read_committed(&partition, 0).await;
+
+        assert_eq!(partition.last_stable_offset(), Some(5));
+        assert_eq!(committed, vec![0, 1, 3]);
+        assert_eq!(next_offset, Some(6));
+        assert_eq!(read_offsets(&partition, 0).await.len(), 6);
+    }
+
+    #[tokio::test]
+    async fn read_committed_poll_should_resolve_transaction_outcome_from_markers() {
+        let (mut partition, _tempdir) = create_persisted_partition().await;
+        append_transaction(&mut partition, 10, &[create_transaction_message(1, 10)]).await;
+        append_transaction(&mut partition, 11, &[create_transaction_message(2, 11)]).await;
+        append_transaction(&mut partition, 12, &[create_transaction_message(3, 12)]).await;
+        end_transaction(&mut partition, 10, TransactionOutcome::Committed).await;
+        end_transaction(&mut partition, 11, TransactionOutcome::Aborted).await;
+        // Simulates the restart, after which the outcomes are loaded from the markers index.
+        partition.transactions = PartitionTransactions::default();
+        partition.load_transaction_markers().await.unwrap();
+
+        let (committed, _) = read_committed(&partition, 0).await;
+
+        assert_eq!(committed, vec![0]);
+    }
+
+    #[tokio::test]
+    async fn completed_transaction_marker_should_be_appended_only_once() {
+        let (mut partition, _tempdir) = create_persisted_partition().await;
+        append_transaction(&mut partition, 10, &[create_transaction_message(1, 10)]).await;
+        end_transaction(&mut partition, 10, TransactionOutcome::Committed).await;
+        end_transaction(&mut partition, 10, TransactionOutcome::Committed).await;
+
+        assert!(partition.is_transaction_completed(10));
+        assert!(!partition.is_transaction_completed(11));
+        assert_eq!(read_offsets(&partition, 0).await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn transaction_markers_below_first_offset_should_be_pruned() {
+        let (mut partition, _tempdir) = create_partition_with_closed_segments().await;
+        append_transaction(&mut partition, 10, &[create_transaction_message(1, 10)]).await;
+        end_transaction(&mut partition, 10, TransactionOutcome::Committed).await;
+        append_transaction(&mut partition, 11, &[create_transaction_message(2, 11)]).await;
+        end_transaction(&mut partition, 11, TransactionOutcome::Aborted).await;
+        append(&mut partition, &[create_message(3, "c")]).await;
+        let deleted_start_offsets = partition
+            .get_segments()
+            .iter()
+            .map(|segment| segment.start_offset())
+            .filter(|start_offset| *start_offset < 4)
+            .collect::<Vec<_>>();
+        for start_offset in deleted_start_offsets {
+            partition.delete_segment(start_offset).await.unwrap();
+        }
+
+        // The marker of the transaction begun after the oldest one, which is not completed yet, is kept.
+        assert_eq!(
+            partition.prune_transaction_markers(Some(11)).await.unwrap(),
+            1
+        );
+        assert!(partition.is_transaction_completed(10));
+        assert_eq!(partition.prune_transaction_markers(None).await.unwrap(), 1);
+        assert_eq!(partition.prune_transaction_markers(None).await.unwrap(), 0);
+        // Simulates the restart, after which the pruned transactions are still treated as completed.
+        partition.transactions = PartitionTransactions::default();
+        partition.load_transaction_markers().await.unwrap();
+
+        assert!(partition.is_transaction_completed(10));
+        assert!(partition.is_transaction_completed(11));
+        assert!(!partition.is_transaction_completed(12));
+    }
+
+    #[tokio::test]
+    async fn due_delayed_messages_should_be_kept_until_removed() {
+        let (mut partition, _tempdir) = create_persisted_partition().await;
+        append(&mut partition, &create_messages()).await;
+        append(&mut partition, &[create_scheduled_message(10)]).await;
+        let consumer = PollingConsumer::Consumer(1, partition.partition_id);
+        partition
+            .store_consumer_offset(consumer, 3, None, IggyTimestamp::now())
+            .await
+            .unwrap();
+        partition
+            .track_delayed_messages(consumer, vec![(1, 2)])
+            .await
+            .unwrap();
+
+        let (messages, entries) = partition
+            .get_due_delayed_messages(consumer, IggyTimestamp::now(), 10)
+            .await
+            .unwrap();
+        assert_eq!(messages.first_offset(), Some(2));
+        assert_eq!(entries, vec![(1, 2)]);
+
+        let (messages, _) = partition
+            .get_due_delayed_messages(consumer, IggyTimestamp::now(), 10)
+            .await
+            .unwrap();
+        assert_eq!(messages.first_offset(), Some(2));
+
+        partition.remove_delayed_messages(consumer, &entries);
+        let (messages, entries) = partition
+            .get_due_delayed_messages(consumer, IggyTimestamp::now(), 10)
+            .await
+            .unwrap();
+        assert!(messages.is_empty());
+        assert!(entries.is_empty());
+        assert_eq!(partition.unsaved_delayed_messages.len(), 1);
+
+        partition.flush_unsaved_buffer(false).await.unwrap();
+        assert!(partition.unsaved_delayed_messages.is_empty());
+    }
+
+    #[tokio::test]
+    async fn concurrent_delivery_failures_of_the_same_message_should_all_be_counted() {
+        let (mut partition, _tempdir) = create_persisted_partition().await;
+        append(&mut partition, &create_messages()).await;
+
+        join_all((0..10).map(|_| partition.record_delivery_failure(1, 2)))
+            .await
+            .into_iter()
+            .for_each(|result| {
+                result.unwrap();
+            });
+
+        assert_eq!(partition.delivery_attempts.get(&1).unwrap().attempts, 10);
+    }
+
+    #[tokio::test]
+    async fn only_appending_scheduled_message_should_enable_delayed_delivery() {
+        let (mut partition, _tempdir) = create_persisted_partition().await;
+        let delayed_messages_path = partition.delayed_messages_path.clone();
+
+        append(&mut partition, &create_messages()).await;
+        assert!(!partition.has_scheduled_messages);
+        assert!(!std::path::Path::new(&delayed_messages_path).exists());
+
+        append(&mut partition, &[create_scheduled_message(10)]).await;
+        assert!(partition.has_scheduled_messages);
+        assert!(std::path::Path::new(&delayed_messages_path).exists());
+    }
+
+    #[tokio::test]
+    async fn producer_sequence_should_be_saved_once_messages_are_persisted() {
+        let (mut partition, _tempdir) = create_persisted_partition().await;
+        let producer = ProducerSequence {
+            producer_id: 1,
+            producer_epoch: 0,
+            sequence: 0,
+        };
+        let messages = create_messages();
+        let messages_size = messages
+            .iter()
+            .map(|m| m.get_size_bytes().as_bytes_u32())
+            .sum();
+        let batch = IggyMessagesBatchMut::from_messages(&messages, messages_size);
+        let producer_path = format!("{}/{}", partition.producers_path, producer.producer_id);
+
+        assert!(
+            partition
+                .append_idempotent_messages(&producer, batch, None)
+                .await
+                .unwrap()
+        );
+        assert!(!std::path::Path::new(&producer_path).exists());
+
+        partition.flush_unsaved_buffer(false).await.unwrap();
+        assert!(std::path::Path::new(&producer_path).exists());
+        assert!(partition.unsaved_producer_sequences.is_empty());
+    }
+
+    #[tokio::test]
+    async fn messages_should_not_be_appended_to_completed_transaction() {
+        let (mut partition, _tempdir) = create_persisted_partition().await;
+        append_transaction(&mut partition, 10, &[create_transaction_message(1, 10)]).await;
+        end_transaction(&mut partition, 10, TransactionOutcome::Committed).await;
+
+        let message = create_transaction_message(2, 10);
+        let message_size = message.get_size_bytes().as_bytes_u32();
+        let batch = IggyMessagesBatchMut::from_messages(&[message], message_size);
+        let result = partition.append_transaction_messages(10, batch, None).await;
+
+        assert!(matches!(result, Err(IggyError::TransactionNotFound(10))));
+    }
+
+    #[tokio::test]
+    async fn compaction_should_keep_only_the_newest_message_per_key_in_closed_segments() {
+        let (mut partition, _tempdir) = create_partition_with_closed_segments().await;
+        append(
+            &mut partition,
+            &[
+                create_keyed_message(1, "a", "a1"),
+                create_keyed_message(2, "b", "b1"),
+            ],
+        )
+        .await;
+        append(
+            &mut partition,
+            &[
+                create_keyed_message(3, "a", "a2"),
+                create_keyed_message(4, "c", "c1"),
+            ],
+        )
+        .await;
+        append(&mut partition, &[with_key(IggyMessage::tombstone(5), "b")]).await;
+        append(&mut partition, &[create_keyed_message(6, "c", "c2")]).await;
+        assert_eq!(partition.get_segments_count(), 4);
+
+        let compacted = compact(&mut partition, 0).await;
+
+        assert_eq!(compacted.removed_messages_count, 3);
+        assert_eq!(compacted.deleted_segments_count, 0);
+        assert_eq!(partition.get_messages_count(), 3);
+        assert_eq!(read_offsets(&partition, 0).await, vec![2, 4, 5]);
+        assert_eq!(read_offsets(&partition, 3).await, vec![4, 5]);
+        assert_eq!(partition.current_offset, 5);
+
+        // Only the last message of the second segment has been removed, which must be still known after restart.
+        let segment = &mut partition.get_segments_mut()[1];
+        segment.load_from_disk().await.unwrap();
+        assert!(segment.is_compacted());
+        assert_eq!(read_offsets(&partition, 2).await, vec![2, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn compaction_should_remove_expired_tombstones_and_empty_segments() {
+        let (mut partition, _tempdir) = create_partition_with_closed_segments().await;
+        append(&mut partition, &[create_keyed_message(1, "a", "a1")]).await;
+        append(&mut partition, &[create_keyed_message(2, "b", "b1")]).await;
+        append(&mut partition, &[with_key(IggyMessage::tombstone(3), "b")]).await;
+        append(&mut partition, &[create_keyed_message(4, "a", "a2")]).await;
+
+        let compacted = compact(&mut partition, u64::MAX).await;
+
+        assert_eq!(compacted.removed_messages_count, 3);
+        assert_eq!(compacted.deleted_segments_count, 2);
+        assert_eq!(partition.get_segments_count(), 2);
+        assert_eq!(read_offsets(&partition, 0).await, vec![3]);
+
+        let messages = partition.get_messages_by_offset(0, 10).await.unwrap();
+        let message = messages.get(0).unwrap();
+        assert_eq!(message.header().id(), 4);
+        assert_eq!(message.payload(), b"a2");
+    }
+
+    #[tokio::test]
+    async fn compaction_should_keep_messages_without_key() {
+        let (mut partition, _tempdir) = create_partition_with_closed_segments().await;
+        append(&mut partition, &[create_message(1, "a1")]).await;
+        append(&mut partition, &[create_message(1, "a2")]).await;
+        append(&mut partition, &[IggyMessage::tombstone(1)]).await;
+        append(&mut partition, &[create_message(2, "b1")]).await;
+
+        let compacted = compact(&mut partition, u64::MAX).await;
+
+        assert_eq!(compacted.removed_messages_count, 0);
+        assert_eq!(read_offsets(&partition, 0).await, vec![0, 1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn compaction_should_rewrite_only_segments_with_removable_messages() {
+        let (mut partition, _tempdir) = create_partition_with_closed_segments().await;
+        append(
+            &mut partition,
+            &[
+                create_keyed_message(1, "a", "a1"),
+                create_keyed_message(2, "b", "b1"),
+            ],
+        )
+        .await;
+        append(
+            &mut partition,
+            &[
+                create_keyed_message(3, "a", "a2"),
+                create_keyed_message(4, "c", "c1"),
+            ],
+        )
+        .await;
+
+        let compacted = compact(&mut partition, 0).await;
+        assert_eq!(compacted.removed_messages_count, 1);
+        assert!(partition.get_segments()[0].is_compacted());
+        assert!(!partition.get_segments()[1].is_compacted());
+
+        let compacted = compact(&mut partition, 0).await;
+        assert_eq!(compacted.removed_messages_count, 0);
+
+        // Only the keys of the newly appended messages are collected, which supersede the already compacted ones.
+        append(
+            &mut partition,
+            &[
+                create_keyed_message(5, "b", "b2"),
+                create_keyed_message(6, "d", "d1"),
+            ],
+        )
+        .await;
+        let compacted = compact(&mut partition, 0).await;
+        assert_eq!(compacted.removed_messages_count, 1);
+        assert_eq!(compacted.deleted_segments_count, 0);
+        assert!(!partition.get_segments()[1].is_compacted());
+        assert_eq!(read_offsets(&partition, 0).await, vec![2, 3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn offloaded_segments_should_be_fetched_from_archive_when_polled() {
+        let (mut partition, _tempdir) =
+            create_partition_with_tiered_storage(IggyExpiry::NeverExpire).await;
+        append(&mut partition, &[create_message(1, "a")]).await;
+        append(&mut partition, &[create_message(2, "b")]).await;
+        append(&mut partition, &[create_message(3, "c")]).await;
+        let segments_count = partition.get_segments_count();
+
+        let offloaded_segments = partition
+            .offload_segments(IggyTimestamp::now())
+            .await
+            .unwrap();
+
+        assert_eq!(offloaded_segments.len(), segments_count as usize - 1);
+        assert_eq!(partition.get_segments_count(), 1);
+        assert_eq!(read_offsets(&partition, 0).await, vec![0, 1, 2]);
+        assert_eq!(read_offsets(&partition, 1).await, vec![1, 2]);
+        let messages = partition
+            .get_messages_by_timestamp(IggyTimestamp::from(0), 10)
+            .await
+            .unwrap();
+        assert_eq!(messages.count(), 3);
+        assert_eq!(partition.cached_segments.lock().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn concurrent_fetches_of_offloaded_segment_should_share_the_result() {
+        let (mut partition, _tempdir) =
+            create_partition_with_tiered_storage(IggyExpiry::NeverExpire).await;
+        append(&mut partition, &[create_message(1, "a")]).await;
+        append(&mut partition, &[create_message(2, "b")]).await;
+        append(&mut partition, &[create_message(3, "c")]).await;
+        partition
+            .offload_segments(IggyTimestamp::now())
+            .await
+            .unwrap();
+        let consumer = PollingConsumer::Consumer(1, partition.partition_id);
+        let strategy = PollingStrategy::offset(1);
+
+        let mut first_fetches = partition
+            .get_offloaded_segment_fetches(consumer, &strategy, 1)
+            .await;
+        let mut second_fetches = partition
+            .get_offloaded_segment_fetches(consumer, &strategy, 1)
+            .await;
+        assert_eq!(first_fetches.len(), 1);
+        assert_eq!(second_fetches.len(), 1);
+        let (first_segment, second_segment) = tokio::join!(
+            first_fetches.pop().unwrap().run(),
+            second_fetches.pop().unwrap().run()
+        );
+
+        assert!(Arc::ptr_eq(
+            &first_segment.unwrap(),
+            &second_segment.unwrap()
+        ));
+        assert_eq!(partition.cached_segments.lock().await.len(), 1);
+        assert!(
+            partition
+                .get_offloaded_segment_fetches(consumer, &strategy, 1)
+                .await
+                .is_empty()
+        );
+        assert_eq!(read_offsets(&partition, 1).await, vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn expired_offloaded_segments_should_no_longer_be_polled() {
+        let remote_retention = IggyExpiry::ExpireDuration(IggyDuration::from(1_000_000));
+        let (mut partition, _tempdir) =
+            create_partition_with_tiered_storage(remote_retention).await;
+        append(&mut partition, &[create_message(1, "a")]).await;
+        append(&mut partition, &[create_message(2, "b")]).await;
+        partition
+            .offload_segments(IggyTimestamp::now())
+            .await
+            .unwrap();
+        assert_eq!(read_offsets(&partition, 0).await, vec![0, 1]);
+
+        let expired_segments = partition
+            .expire_offloaded_segments(IggyTimestamp::from(
+                IggyTimestamp::now().as_micros() + 2_000_000,
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(expired_segments.len(), 1);
+        assert!(partition.offloaded_segments.is_empty());
+        assert!(partition.cached_segments.lock().await.is_empty());
+        assert!(!partition.is_offloaded_offset(0));
+        assert_eq!(read_offsets(&partition, 1).await, vec![1]);
+    }
+
+    async fn read_offsets(partition: &Partition, start_offset: u64) -> Vec<u64> {
+        let messages = partition
+            .get_messages_by_offset(start_offset, 10)
+            .await
+            .unwrap();
+        messages
+            .iter()
+            .flat_map(|batch| batch.iter().map(|message| message.header().offset()))
+            .collect()
+    }
+
+    async fn append(partition: &mut Partition, messages: &[IggyMessage]) {
+        let messages_size = messages
+            .iter()
+            .map(|m| m.get_size_bytes().as_bytes_u32())
+            .sum();
+        let batch = IggyMessagesBatchMut::from_messages(messages, messages_size);
+        partition.append_messages(batch, None).await.unwrap();
+    }
+
+    async fn append_transaction(
+        partition: &mut Partition,
+        transaction_id: u64,
+        messages: &[IggyMessage],
+    ) {
+        let messages_size = messages
+            .iter()
+            .map(|m| m.get_size_bytes().as_bytes_u32())
+            .sum();
+        let batch = IggyMessagesBatchMut::from_messages(messages, messages_size);
+        partition
+            .append_transaction_messages(transaction_id, batch, None)
+            .await
+            .unwrap();
+    }
+
+    async fn end_transaction(
+        partition: &mut Partition,
+        transaction_id: u64,
+        outcome: TransactionOutcome,
+    ) {
+        let marker = markers::create_marker(transaction_id, outcome);
+        let marker_size = marker.get_size_bytes().as_bytes_u32();
+        let batch = IggyMessagesBatchMut::from_messages(&[marker], marker_size);
+        partition
+            .end_transaction(transaction_id, outcome, batch)
+            .await
+            .unwrap();
+    }
+
+    async fn read_committed(partition: &Partition, start_offset: u64) -> (Vec<u64>, Option<u64>) {
+        let messages = partition
+            .get_messages_by_offset(start_offset, 10)
+            .await
+            .unwrap();
+        let (messages, next_offset) = partition
+            .filter_messages(messages, None, IsolationLevel::ReadCommitted, 10, None)
+            .await
+            .unwrap();
+        let offsets = messages
+            .iter()
+            .flat_map(|batch| batch.iter().map(|message| message.header().offset()))
+            .collect();
+        (offsets, next_offset)
+    }
+
+    async fn compact(
+        partition: &mut Partition,
+        tombstone_expiry_timestamp: u64,
+    ) -> CompactedSegments {
+        while let Some(message_keys) = partition
+            .read_uncollected_message_keys(partition.current_offset)
+            .await
+            .unwrap()
+        {
+            partition.add_collected_message_keys(message_keys);
+        }
+        partition
+            .compact_segments(tombstone_expiry_timestamp)
+            .await
+            .unwrap()
+    }
+
+    fn create_keyed_message(id: u128, key: &str, payload: &str) -> IggyMessage {
+        with_key(create_message(id, payload), key)
+    }
+
+    fn with_key(mut message: IggyMessage, key: &str) -> IggyMessage {
+        let mut user_headers = BytesMut::new();
+        write_message_key_header(key.as_bytes(), &mut user_headers);
+        message.header.user_headers_length = user_headers.len() as u32;
+        message.user_headers = Some(user_headers.freeze());
+        message
+    }
+
+    fn create_transaction_message(id: u128, transaction_id: u64) -> IggyMessage {
+        let mut message = create_message(id, &format!("message {id}"));
+        let mut user_headers = BytesMut::new();
+        markers::write_transaction_header(transaction_id, &mut user_headers);
+        message.header.user_headers_length = user_headers.len() as u32;
+        message.user_headers = Some(user_headers.freeze());
+        message
+    }
+
     async fn create_partition(deduplication_enabled: bool) -> (Partition, TempDir) {
-        let stream_id = 1;
-        let topic_id = 2;
-        let partition_id = 3;
-        let with_segment = true;
         let temp_dir = TempDir::new().unwrap();
-        let config = Arc::new(SystemConfig {
+        let config = SystemConfig {
             path: temp_dir.path().to_path_buf().to_str().unwrap().to_string(),
             message_deduplication: MessageDeduplicationConfig {
                 enabled: deduplication_enabled,
                 ..Default::default()
             },
             ..Default::default()
-        });
+        };
+        (create_partition_with_config(config).await, temp_dir)
+    }
+
+    async fn create_persisted_partition() -> (Partition, TempDir) {
+        let (mut partition, temp_dir) = create_partition(false).await;
+        partition.persist().await.unwrap();
+        (partition, temp_dir)
+    }
+
+    async fn create_partition_with_closed_segments() -> (Partition, TempDir) {
+        let temp_dir = TempDir::new().unwrap();
+        let config = SystemConfig {
+            path: temp_dir.path().to_path_buf().to_str().unwrap().to_string(),
+            segment: SegmentConfig {
+                size: IggyByteSize::from(1),
+                server_confirmation: Confirmation::Wait,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut partition = create_partition_with_config(config).await;
+        partition.persist().await.unwrap();
+        (partition, temp_dir)
+    }
+
+    async fn create_partition_with_tiered_storage(
+        remote_retention: IggyExpiry,
+    ) -> (Partition, TempDir) {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().to_str().unwrap().to_string();
+        let config = SystemConfig {
+            path: format!("{path}/local_data"),
+            segment: SegmentConfig {
+                size: IggyByteSize::from(1),
+                server_confirmation: Confirmation::Wait,
+                ..Default::default()
+            },
+            tiered_storage: TieredStorageConfig {
+                enabled: true,
+                local_retention: IggyDuration::from(0),
+                remote_retention,
+                cache_segments: 1,
+            },
+            ..Default::default()
+        };
+        let mut partition = create_partition_with_config(config).await;
+        let mut storage = SystemStorage::new(
+            partition.config.clone(),
+            Arc::new(PersisterKind::FileWithSync(FileWithSyncPersister {})),
+        );
+        storage.archiver = Some(Arc::new(ArchiverKind::get_disk_archiver(
+            DiskArchiverConfig {
+                path: format!("{path}/archive"),
+            },
+        )));
+        partition.storage = Arc::new(storage);
+        partition.persist().await.unwrap();
+        (partition, temp_dir)
+    }
+
+    async fn create_partition_with_config(config: SystemConfig) -> Partition {
+        let stream_id = 1;
+        let topic_id = 2;
+        let partition_id = 3;
+        let with_segment = true;
+        let config = Arc::new(config);
         let storage = Arc::new(SystemStorage::new(
             config.clone(),
             Arc::new(PersisterKind::FileWithSync(FileWithSyncPersister {})),
         ));
         MemoryPool::init_pool(config.clone());
 
-        (
-            Partition::create(
-                stream_id,
-                topic_id,
-                partition_id,
-                with_segment,
-                config,
-                storage,
-                IggyExpiry::NeverExpire,
-                Arc::new(AtomicU64::new(0)),
-                Arc::new(AtomicU64::new(0)),
-                Arc::new(AtomicU64::new(0)),
-                Arc::new(AtomicU64::new(0)),
-                Arc::new(AtomicU32::new(0)),
-                IggyTimestamp::now(),
-            )
-            .await,
-            temp_dir,
+        Partition::create(
+            stream_id,
+            topic_id,
+            partition_id,
+            with_segment,
+            config,
+            storage,
+            IggyExpiry::NeverExpire,
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU32::new(0)),
+            IggyTimestamp::now(),
         )
+        .await
     }
 
     fn create_messages() -> Vec<IggyMessage> {
@@ -761,4 +1595,28 @@ mod tests {
             .build()
             .expect("Failed to create message with ID")
     }
+
+    fn create_scheduled_message(id: u128) -> IggyMessage {
+        IggyMessage::builder()
+            .id(id)
+            .payload(Bytes::from(format!("message {id}")))
+            .deliver_at(IggyTimestamp::from(
+                IggyTimestamp::now().as_micros() + 60_000_000,
+            ))
+            .build()
+            .expect("Failed to create scheduled message")
+    }
+
+    fn create_message_with_region(id: u128, region: &str) -> IggyMessage {
+        let user_headers = HashMap::from([(
+            HeaderKey::new("region").unwrap(),
+            HeaderValue::from_str(region).unwrap(),
+        )]);
+        IggyMessage::builder()
+            .id(id)
+            .payload(Bytes::from(format!("message {id}")))
+            .user_headers(user_headers)
+            .build()
+            .expect("Failed to create message with headers")
+    }
 }
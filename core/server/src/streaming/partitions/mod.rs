@@ -17,10 +17,16 @@
  */
 
 pub mod consumer_offsets;
+pub mod data_directories;
+pub mod delayed_messages;
+pub mod delivery_attempts;
 pub mod messages;
+pub mod offloaded_segments;
 pub mod partition;
 pub mod persistence;
+pub mod producers;
 pub mod segments;
 pub mod storage;
+pub mod transactions;
 
 pub const COMPONENT: &str = "STREAMING_PARTITIONS";
@@ -0,0 +1,542 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::archiver::ArchiverKind;
+use crate::configs::system::SystemConfig;
+use crate::streaming::partitions::COMPONENT;
+use crate::streaming::partitions::partition::{OffloadedSegment, Partition};
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::segments::{IggyMessagesBatchSet, Segment};
+use error_set::ErrContext;
+use iggy_common::{IggyError, IggyExpiry, IggyTimestamp, PollingKind, PollingStrategy};
+use std::collections::VecDeque;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::fs::{remove_dir_all, remove_file};
+use tokio::sync::Mutex;
+use tracing::{error, info, trace, warn};
+
+impl Partition {
+    /// Offloads the closed segments, whose last message is older than the local retention, to the remote tier
+    /// and deletes them locally, while keeping them listed as the offloaded segments available for polling.
+    pub async fn offload_segments(
+        &mut self,
+        now: IggyTimestamp,
+    ) -> Result<Vec<OffloadedSegment>, IggyError> {
+        let Some(archiver) = self.storage.archiver.clone() else {
+            return Ok(Vec::new());
+        };
+
+        let local_retention = self.config.tiered_storage.local_retention.as_micros();
+        let mut segments_to_offload = Vec::new();
+        // The last segment is always kept locally, even if it's closed, as it holds the current offset.
+        let segments_count = self.segments.len().saturating_sub(1);
+        for segment in self.segments[..segments_count]
+            .iter()
+            .filter(|segment| segment.is_closed())
+        {
+            let Some(offloaded_segment) = describe_segment(segment).await? else {
+                continue;
+            };
+            // The segments are ordered, so the subsequent ones can't be older.
+            if offloaded_segment.end_timestamp + local_retention > now.as_micros() {
+                break;
+            }
+            segments_to_offload.push(offloaded_segment);
+        }
+
+        let mut offloaded_segments = Vec::with_capacity(segments_to_offload.len());
+        for offloaded_segment in segments_to_offload {
+            let start_offset = offloaded_segment.start_offset;
+            let segment = self.get_segment(start_offset).unwrap();
+            let files = [segment.index_file_path(), segment.messages_file_path()];
+            if let Err(error) = archiver.archive(&files, None).await {
+                error!(
+                    "Failed to offload segment with start offset: {start_offset} for partition with ID: {}, topic with ID: {}, stream with ID: {}. {error}",
+                    self.partition_id, self.topic_id, self.stream_id
+                );
+                break;
+            }
+
+            self.offloaded_segments
+                .retain(|segment| segment.start_offset != start_offset);
+            self.offloaded_segments.push(offloaded_segment);
+            self.save_offloaded_segments().await?;
+            self.delete_segment(start_offset).await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to delete offloaded segment with start offset: {start_offset}, partition: {self}")
+            })?;
+            info!(
+                "Offloaded segment with start offset: {start_offset} for partition with ID: {}, topic with ID: {}, stream with ID: {}.",
+                self.partition_id, self.topic_id, self.stream_id
+            );
+            offloaded_segments.push(offloaded_segment);
+        }
+        Ok(offloaded_segments)
+    }
+
+    /// Removes the offloaded segments, whose last message is older than the remote retention, from the remote tier.
+    pub async fn expire_offloaded_segments(
+        &mut self,
+        now: IggyTimestamp,
+    ) -> Result<Vec<OffloadedSegment>, IggyError> {
+        let IggyExpiry::ExpireDuration(remote_retention) =
+            self.config.tiered_storage.remote_retention
+        else {
+            return Ok(Vec::new());
+        };
+        let Some(archiver) = self.storage.archiver.clone() else {
+            return Ok(Vec::new());
+        };
+
+        let expired_count = self
+            .offloaded_segments
+            .iter()
+            .take_while(|segment| {
+                segment.end_timestamp + remote_retention.as_micros() <= now.as_micros()
+            })
+            .count();
+        if expired_count == 0 {
+            return Ok(Vec::new());
+        }
+
+        let expired_segments = self
+            .offloaded_segments
+            .drain(..expired_count)
+            .collect::<Vec<_>>();
+        self.save_offloaded_segments().await?;
+        let last_expired_offset = expired_segments.last().unwrap().end_offset;
+        let mut cached_segments = self.cached_segments.lock().await;
+        for segment in cached_segments.iter() {
+            if segment.end_offset() <= last_expired_offset {
+                remove_cached_segment(segment).await;
+            }
+        }
+        cached_segments.retain(|segment| segment.end_offset() > last_expired_offset);
+        drop(cached_segments);
+        self.segment_fetches
+            .retain(|start_offset, _| *start_offset > last_expired_offset);
+
+        for expired_segment in &expired_segments {
+            let path = self.get_offloaded_segment_path(expired_segment);
+            let index_path = Segment::get_index_path(&path);
+            let messages_path = Segment::get_messages_file_path(&path);
+            if let Err(error) = archiver.delete(&[&index_path, &messages_path], None).await {
+                error!(
+                    "Failed to delete expired offloaded segment with start offset: {} for partition with ID: {}, topic with ID: {}, stream with ID: {}. {error}",
+                    expired_segment.start_offset, self.partition_id, self.topic_id, self.stream_id
+                );
+            }
+        }
+        info!(
+            "Expired {} offloaded segments for partition with ID: {}, topic with ID: {}, stream with ID: {}.",
+            expired_segments.len(),
+            self.partition_id,
+            self.topic_id,
+            self.stream_id
+        );
+        Ok(expired_segments)
+    }
+
+    /// Returns `true` if the offset precedes the local segments and belongs to the offloaded ones.
+    pub(crate) fn is_offloaded_offset(&self, offset: u64) -> bool {
+        self.offloaded_segments
+            .last()
+            .is_some_and(|segment| offset <= segment.end_offset)
+            && self
+                .segments
+                .first()
+                .is_none_or(|segment| offset < segment.start_offset())
+    }
+
+    /// Retrieves messages by offset (up to a specified count) from the offloaded segments,
+    /// which are fetched into the local cache on demand.
+    pub(crate) async fn get_offloaded_messages_by_offset(
+        &self,
+        offset: u64,
+        count: u32,
+    ) -> Result<IggyMessagesBatchSet, IggyError> {
+        let mut remaining_count = count;
+        let mut current_offset = offset;
+        let mut batches = IggyMessagesBatchSet::empty();
+        for offloaded_segment in self.get_readable_offloaded_segments() {
+            if remaining_count == 0 {
+                break;
+            }
+            if offloaded_segment.end_offset < current_offset {
+                continue;
+            }
+
+            let segment = self.get_cached_segment(offloaded_segment).await?;
+            let messages = segment
+                .get_messages_by_offset(current_offset, remaining_count)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to get messages from offloaded segment: {segment}, offset: {current_offset}, count: {remaining_count}"
+                    )
+                })?;
+            remaining_count = remaining_count.saturating_sub(messages.count());
+            current_offset = offloaded_segment.end_offset + 1;
+            batches.add_batch_set(messages);
+        }
+        Ok(batches)
+    }
+
+    /// Retrieves messages by timestamp (up to a specified count) from the offloaded segments,
+    /// which are fetched into the local cache on demand.
+    pub(crate) async fn get_offloaded_messages_by_timestamp(
+        &self,
+        timestamp: u64,
+        count: u32,
+    ) -> Result<IggyMessagesBatchSet, IggyError> {
+        let mut remaining_count = count;
+        let mut batches = IggyMessagesBatchSet::empty();
+        for offloaded_segment in self.get_readable_offloaded_segments() {
+            if remaining_count == 0 {
+                break;
+            }
+            if offloaded_segment.end_timestamp < timestamp {
+                continue;
+            }
+
+            let segment = self.get_cached_segment(offloaded_segment).await?;
+            let messages = segment
+                .get_messages_by_timestamp(timestamp, remaining_count)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to get messages from offloaded segment: {segment} by timestamp: {timestamp}, count: {remaining_count}"
+                    )
+                })?;
+            remaining_count = remaining_count.saturating_sub(messages.count());
+            batches.add_batch_set(messages);
+        }
+        Ok(batches)
+    }
+
+    pub async fn load_offloaded_segments(&mut self) -> Result<(), IggyError> {
+        trace!(
+            "Loading offloaded segments for partition with ID: {} for topic with ID: {} and stream with ID: {}...",
+            self.partition_id, self.topic_id, self.stream_id
+        );
+        // The cache is tracked only in memory, so the segments cached before the restart are fetched again.
+        if Path::new(&self.tiered_cache_path).exists() {
+            remove_dir_all(&self.tiered_cache_path)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to delete tiered cache directory: {}",
+                        self.tiered_cache_path
+                    )
+                })
+                .map_err(|_| IggyError::CannotDeleteFile)?;
+        }
+
+        self.offloaded_segments = self
+            .storage
+            .partition
+            .load_offloaded_segments(&self.offloaded_segments_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to load offloaded segments, path: {}",
+                    self.offloaded_segments_path
+                )
+            })?;
+        Ok(())
+    }
+
+    async fn save_offloaded_segments(&self) -> Result<(), IggyError> {
+        self.storage
+            .partition
+            .save_offloaded_segments(&self.offloaded_segments, &self.offloaded_segments_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to save offloaded segments, partition: {self}"
+                )
+            })
+    }
+
+    /// Returns the offloaded segments, which haven't been restored as the local ones.
+    fn get_readable_offloaded_segments(&self) -> impl Iterator<Item = &OffloadedSegment> {
+        let local_start_offset = self
+            .segments
+            .first()
+            .map_or(u64::MAX, |segment| segment.start_offset());
+        self.offloaded_segments
+            .iter()
+            .filter(move |segment| segment.start_offset < local_start_offset)
+    }
+
+    /// Returns the offloaded segments, which will be read by the poll and are missing in the local cache,
+    /// so that they can be fetched from the remote tier once the partition lock is released.
+    pub(crate) async fn get_offloaded_segment_fetches(
+        &self,
+        consumer: PollingConsumer,
+        strategy: &PollingStrategy,
+        count: u32,
+    ) -> Vec<OffloadedSegmentFetch> {
+        if self.offloaded_segments.is_empty() || self.storage.archiver.is_none() {
+            return Vec::new();
+        }
+
+        let start_offset = match strategy.kind {
+            PollingKind::Offset => Some(strategy.value),
+            PollingKind::Timestamp => None,
+            PollingKind::First => Some(0),
+            PollingKind::Last => Some((self.current_offset + 1).saturating_sub(count as u64)),
+            PollingKind::Next => {
+                let (consumer_offsets, consumer_id) = match consumer {
+                    PollingConsumer::Consumer(consumer_id, _) => {
+                        (&self.consumer_offsets, consumer_id)
+                    }
+                    PollingConsumer::ConsumerGroup(group_id, _) => {
+                        (&self.consumer_group_offsets, group_id)
+                    }
+                };
+                Some(
+                    consumer_offsets
+                        .get(&consumer_id)
+                        .map_or(0, |consumer_offset| consumer_offset.offset + 1),
+                )
+            }
+        };
+        if start_offset.is_some_and(|offset| offset > self.current_offset) {
+            return Vec::new();
+        }
+
+        let mut remaining_count = count as u64;
+        let mut fetches = Vec::new();
+        let cached_segments = self.cached_segments.lock().await;
+        for offloaded_segment in self.get_readable_offloaded_segments() {
+            if remaining_count == 0 {
+                break;
+            }
+
+            let messages_count = match start_offset {
+                Some(offset) if offloaded_segment.end_offset < offset => continue,
+                Some(offset) => {
+                    offloaded_segment.end_offset + 1 - offset.max(offloaded_segment.start_offset)
+                }
+                None if offloaded_segment.end_timestamp < strategy.value => continue,
+                None => offloaded_segment.messages_count,
+            };
+            remaining_count = remaining_count.saturating_sub(messages_count);
+            if cached_segments
+                .iter()
+                .any(|segment| segment.start_offset() == offloaded_segment.start_offset)
+            {
+                continue;
+            }
+            if let Some(fetch) = self.get_offloaded_segment_fetch(offloaded_segment) {
+                fetches.push(fetch);
+            }
+        }
+        fetches
+    }
+
+    /// Returns the offloaded segment from the local cache, or fetches it from the remote tier,
+    /// evicting the least recently used segments if the cache is full.
+    async fn get_cached_segment(
+        &self,
+        offloaded_segment: &OffloadedSegment,
+    ) -> Result<Arc<Segment>, IggyError> {
+        let start_offset = offloaded_segment.start_offset;
+        if let Some(segment) = touch_cached_segment(&self.cached_segments, start_offset).await {
+            return Ok(segment);
+        }
+
+        let Some(fetch) = self.get_offloaded_segment_fetch(offloaded_segment) else {
+            error!(
+                "Cannot fetch offloaded segment with start offset: {start_offset} for partition with ID: {}, archiver is disabled.",
+                self.partition_id
+            );
+            return Err(IggyError::CannotFetchOffloadedSegment(
+                start_offset,
+                self.partition_id,
+            ));
+        };
+        fetch.run().await
+    }
+
+    /// Prepares the fetch of the offloaded segment, which shares the in-flight fetch of the same segment.
+    fn get_offloaded_segment_fetch(
+        &self,
+        offloaded_segment: &OffloadedSegment,
+    ) -> Option<OffloadedSegmentFetch> {
+        let archiver = self.storage.archiver.clone()?;
+        let start_offset = offloaded_segment.start_offset;
+        Some(OffloadedSegmentFetch {
+            stream_id: self.stream_id,
+            topic_id: self.topic_id,
+            partition_id: self.partition_id,
+            offloaded_segment: *offloaded_segment,
+            path: self.get_offloaded_segment_path(offloaded_segment),
+            cache_path: format!("{}/{:0>20}", self.tiered_cache_path, start_offset),
+            archiver,
+            fetch_lock: self
+                .segment_fetches
+                .entry(start_offset)
+                .or_default()
+                .clone(),
+            cached_segments: self.cached_segments.clone(),
+            config: self.config.clone(),
+        })
+    }
+
+    /// Returns the path of the offloaded segment, which identifies its files in the remote tier.
+    fn get_offloaded_segment_path(&self, offloaded_segment: &OffloadedSegment) -> String {
+        self.config.get_segment_path(
+            self.stream_id,
+            self.topic_id,
+            self.partition_id,
+            offloaded_segment.start_offset,
+        )
+    }
+}
+
+/// Fetches the offloaded segment from the remote tier into the local cache. It doesn't borrow the partition,
+/// so it can run without holding the partition lock, while the concurrent fetches of the same segment
+/// wait for the first one and reuse its result.
+pub(crate) struct OffloadedSegmentFetch {
+    stream_id: u32,
+    topic_id: u32,
+    partition_id: u32,
+    offloaded_segment: OffloadedSegment,
+    path: String,
+    cache_path: String,
+    archiver: Arc<ArchiverKind>,
+    fetch_lock: Arc<Mutex<()>>,
+    cached_segments: Arc<Mutex<VecDeque<Arc<Segment>>>>,
+    config: Arc<SystemConfig>,
+}
+
+impl OffloadedSegmentFetch {
+    pub(crate) async fn run(self) -> Result<Arc<Segment>, IggyError> {
+        let start_offset = self.offloaded_segment.start_offset;
+        let _fetch_guard = self.fetch_lock.lock().await;
+        if let Some(segment) = touch_cached_segment(&self.cached_segments, start_offset).await {
+            return Ok(segment);
+        }
+
+        trace!(
+            "Fetching offloaded segment with start offset: {start_offset} for partition with ID: {}...",
+            self.partition_id
+        );
+        let files = [
+            (
+                Segment::get_index_path(&self.path),
+                Segment::get_index_path(&self.cache_path),
+            ),
+            (
+                Segment::get_messages_file_path(&self.path),
+                Segment::get_messages_file_path(&self.cache_path),
+            ),
+        ];
+        for (file, destination) in &files {
+            if let Err(error) = self.archiver.fetch(file, destination, None).await {
+                error!(
+                    "Failed to fetch offloaded segment file: {file} for partition with ID: {}, topic with ID: {}, stream with ID: {}. {error}",
+                    self.partition_id, self.topic_id, self.stream_id
+                );
+                return Err(IggyError::CannotFetchOffloadedSegment(
+                    start_offset,
+                    self.partition_id,
+                ));
+            }
+        }
+
+        let segment = Segment::open_cached(
+            self.stream_id,
+            self.topic_id,
+            self.partition_id,
+            &self.offloaded_segment,
+            &self.cache_path,
+            self.config.clone(),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to open cached segment with start offset: {start_offset}, partition ID: {}",
+                self.partition_id
+            )
+        })?;
+        let segment = Arc::new(segment);
+        let mut evicted_segments = Vec::new();
+        let mut cached_segments = self.cached_segments.lock().await;
+        cached_segments.push_back(segment.clone());
+        while cached_segments.len() > self.config.tiered_storage.cache_segments as usize {
+            if let Some(evicted_segment) = cached_segments.pop_front() {
+                evicted_segments.push(evicted_segment);
+            }
+        }
+        drop(cached_segments);
+        for evicted_segment in evicted_segments {
+            remove_cached_segment(&evicted_segment).await;
+        }
+        Ok(segment)
+    }
+}
+
+/// Returns the cached segment with the given start offset, marking it as the most recently used one.
+async fn touch_cached_segment(
+    cached_segments: &Mutex<VecDeque<Arc<Segment>>>,
+    start_offset: u64,
+) -> Option<Arc<Segment>> {
+    let mut cached_segments = cached_segments.lock().await;
+    let position = cached_segments
+        .iter()
+        .position(|segment| segment.start_offset() == start_offset)?;
+    let segment = cached_segments.remove(position)?;
+    cached_segments.push_back(segment.clone());
+    Some(segment)
+}
+
+/// Describes the closed segment to be offloaded, or returns `None` if it has no messages.
+async fn describe_segment(segment: &Segment) -> Result<Option<OffloadedSegment>, IggyError> {
+    let first_messages = segment
+        .get_messages_by_offset(segment.start_offset(), 1)
+        .await?;
+    let last_messages = segment
+        .get_messages_by_offset(segment.end_offset(), 1)
+        .await?;
+    let (Some(start_timestamp), Some(end_timestamp)) = (
+        first_messages.first_timestamp(),
+        last_messages.last_timestamp(),
+    ) else {
+        return Ok(None);
+    };
+
+    Ok(Some(OffloadedSegment {
+        start_offset: segment.start_offset(),
+        end_offset: segment.end_offset(),
+        start_timestamp,
+        end_timestamp,
+        size_bytes: segment.get_messages_size().as_bytes_u64(),
+        messages_count: segment.get_messages_count() as u64,
+    }))
+}
+
+async fn remove_cached_segment(segment: &Segment) {
+    for path in [segment.index_file_path(), segment.messages_file_path()] {
+        if let Err(error) = remove_file(path).await {
+            warn!("Failed to remove cached segment file: {path}. {error}");
+        }
+    }
+}
@@ -18,18 +18,24 @@
 
 use crate::configs::system::SystemConfig;
 use crate::streaming::deduplication::message_deduplicator::MessageDeduplicator;
+use crate::streaming::partitions::transactions::PartitionTransactions;
 use crate::streaming::segments::*;
 use crate::streaming::storage::SystemStorage;
-use dashmap::DashMap;
+use crate::streaming::transactions::markers::TransactionOutcome;
+use ahash::{AHashMap, AHashSet};
+use dashmap::{DashMap, DashSet};
 use iggy_common::ConsumerKind;
 use iggy_common::IggyByteSize;
 use iggy_common::IggyDuration;
 use iggy_common::IggyExpiry;
 use iggy_common::IggyTimestamp;
+use iggy_common::ProducerSequence;
 use iggy_common::Sizeable;
+use std::collections::{BTreeSet, VecDeque};
 use std::fmt;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use tokio::sync::{Mutex, Notify};
 
 #[derive(Debug)]
 pub struct Partition {
@@ -37,9 +43,17 @@ pub struct Partition {
     pub topic_id: u32,
     pub partition_id: u32,
     pub partition_path: String,
+    /// The data directory the segments of the partition are stored in, linked from the partition path if it's not the system path.
+    pub data_directory: String,
     pub offsets_path: String,
     pub consumer_offsets_path: String,
     pub consumer_group_offsets_path: String,
+    pub producers_path: String,
+    pub delivery_attempts_path: String,
+    pub delayed_messages_path: String,
+    pub offloaded_segments_path: String,
+    pub transaction_markers_path: String,
+    pub tiered_cache_path: String,
     pub current_offset: u64,
     pub message_deduplicator: Option<MessageDeduplicator>,
     pub unsaved_messages_count: u32,
@@ -58,6 +72,27 @@ pub struct Partition {
     pub(crate) consumer_offsets: DashMap<u32, ConsumerOffset>,
     pub(crate) consumer_group_offsets: DashMap<u32, ConsumerOffset>,
     pub(crate) segments: Vec<Segment>,
+    pub(crate) transactions: PartitionTransactions,
+    pub(crate) producer_sequences: AHashMap<u64, ProducerSequence>,
+    /// The producers, whose sequences are saved once their messages have been persisted.
+    pub(crate) unsaved_producer_sequences: AHashSet<u64>,
+    pub(crate) delivery_attempts: DashMap<u32, DeliveryAttempts>,
+    pub(crate) delayed_messages: DashMap<(ConsumerKind, u32), DelayedMessages>,
+    /// The consumers, whose delayed messages have been removed and are saved with the next flush.
+    pub(crate) unsaved_delayed_messages: DashSet<(ConsumerKind, u32)>,
+    /// Set once any message scheduled for the later delivery has been appended, the polls of the other
+    /// partitions don't look for the delayed messages at all.
+    pub(crate) has_scheduled_messages: bool,
+    pub(crate) offloaded_segments: Vec<OffloadedSegment>,
+    /// Shared with the fetches of the offloaded segments, which run without holding the partition lock.
+    pub(crate) cached_segments: Arc<Mutex<VecDeque<Arc<Segment>>>>,
+    /// Held while the offloaded segment with the given start offset is being fetched into the cache.
+    pub(crate) segment_fetches: DashMap<u64, Arc<Mutex<()>>>,
+    pub(crate) throughput: PartitionThroughput,
+    /// Notifies the long polling requests waiting for the messages to be appended.
+    pub(crate) messages_appended: Arc<Notify>,
+    /// Held while the partition is being moved to another data directory.
+    pub(crate) relocation_lock: Arc<Mutex<()>>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) storage: Arc<SystemStorage>,
 }
@@ -67,18 +102,127 @@ pub struct ConsumerOffset {
     pub kind: ConsumerKind,
     pub consumer_id: u32,
     pub offset: u64,
+    /// The optional opaque metadata committed along with the offset.
+    pub metadata: Option<String>,
+    /// The time of the last commit, zero for the offsets stored before it was tracked.
+    pub committed_at: IggyTimestamp,
     pub path: Arc<String>,
 }
 
 impl ConsumerOffset {
-    pub fn new(kind: ConsumerKind, consumer_id: u32, offset: u64, path: &str) -> ConsumerOffset {
+    pub fn new(
+        kind: ConsumerKind,
+        consumer_id: u32,
+        offset: u64,
+        metadata: Option<String>,
+        committed_at: IggyTimestamp,
+        path: &str,
+    ) -> ConsumerOffset {
         ConsumerOffset {
             kind,
             consumer_id,
             offset,
+            metadata,
+            committed_at,
             path: Arc::new(format!("{path}/{consumer_id}")),
         }
     }
+
+    /// The size of the offset file without the metadata: offset, commit time and metadata length.
+    pub const BASE_SIZE: usize = 18;
+}
+
+/// The number of failed delivery attempts of the message with the given offset for the consumer group.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DeliveryAttempts {
+    pub consumer_group_id: u32,
+    pub offset: u64,
+    pub attempts: u32,
+}
+
+impl DeliveryAttempts {
+    pub const SIZE: usize = 12;
+}
+
+/// The messages scheduled for the later delivery, which have been skipped by the consumer polling the next messages.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct DelayedMessages {
+    pub kind: ConsumerKind,
+    pub consumer_id: u32,
+    /// The time of the scheduled delivery and the offset of each skipped message.
+    pub messages: BTreeSet<(u64, u64)>,
+}
+
+impl DelayedMessages {
+    pub fn new(kind: ConsumerKind, consumer_id: u32) -> Self {
+        Self {
+            kind,
+            consumer_id,
+            messages: BTreeSet::new(),
+        }
+    }
+}
+
+/// The closed segment offloaded to the remote tier, which is fetched back into the local cache when polled.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct OffloadedSegment {
+    pub start_offset: u64,
+    pub end_offset: u64,
+    pub start_timestamp: u64,
+    pub end_timestamp: u64,
+    pub size_bytes: u64,
+    pub messages_count: u64,
+}
+
+impl OffloadedSegment {
+    pub const SIZE: usize = 48;
+}
+
+/// The marker of the completed transaction appended to the partition, indexed by the transaction ID.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct TransactionMarker {
+    pub transaction_id: u64,
+    pub offset: u64,
+    pub outcome: TransactionOutcome,
+}
+
+impl TransactionMarker {
+    pub const SIZE: usize = 17;
+}
+
+/// The markers stored in the partition file, preceded by the ID of the latest transaction, whose marker
+/// has been pruned along with the deleted segments.
+#[derive(Debug, Default, PartialEq)]
+pub struct TransactionMarkers {
+    pub pruned_transaction_id: u64,
+    pub markers: Vec<TransactionMarker>,
+}
+
+impl TransactionMarkers {
+    pub const HEADER_SIZE: usize = 8;
+}
+
+/// The number and size of the messages appended to and polled from the partition since the server has started.
+#[derive(Debug, Default)]
+pub struct PartitionThroughput {
+    pub messages_in: AtomicU64,
+    pub bytes_in: AtomicU64,
+    pub messages_out: AtomicU64,
+    pub bytes_out: AtomicU64,
+}
+
+impl PartitionThroughput {
+    pub fn record_in(&self, messages_count: u32, size_bytes: u64) {
+        self.messages_in
+            .fetch_add(messages_count as u64, Ordering::Relaxed);
+        self.bytes_in.fetch_add(size_bytes, Ordering::Relaxed);
+    }
+
+    pub fn record_out(&self, messages_count: u32, size_bytes: u64) {
+        self.messages_out
+            .fetch_add(messages_count as u64, Ordering::Relaxed);
+        self.bytes_out.fetch_add(size_bytes, Ordering::Relaxed);
+    }
 }
 
 impl Partition {
@@ -99,11 +243,22 @@ impl Partition {
         created_at: IggyTimestamp,
     ) -> Partition {
         let partition_path = config.get_partition_path(stream_id, topic_id, partition_id);
+        let data_directory = config.get_system_path();
         let offsets_path = config.get_offsets_path(stream_id, topic_id, partition_id);
         let consumer_offsets_path =
             config.get_consumer_offsets_path(stream_id, topic_id, partition_id);
         let consumer_group_offsets_path =
             config.get_consumer_group_offsets_path(stream_id, topic_id, partition_id);
+        let producers_path = config.get_producers_path(stream_id, topic_id, partition_id);
+        let delivery_attempts_path =
+            config.get_delivery_attempts_path(stream_id, topic_id, partition_id);
+        let delayed_messages_path =
+            config.get_delayed_messages_path(stream_id, topic_id, partition_id);
+        let offloaded_segments_path =
+            config.get_offloaded_segments_path(stream_id, topic_id, partition_id);
+        let transaction_markers_path =
+            config.get_transaction_markers_path(stream_id, topic_id, partition_id);
+        let tiered_cache_path = config.get_tiered_cache_path(stream_id, topic_id, partition_id);
 
         let message_deduplicator = match config.message_deduplication.enabled {
             true => Some(MessageDeduplicator::new(
@@ -128,9 +283,16 @@ impl Partition {
             topic_id,
             partition_id,
             partition_path,
+            data_directory,
             offsets_path,
             consumer_offsets_path,
             consumer_group_offsets_path,
+            producers_path,
+            delivery_attempts_path,
+            delayed_messages_path,
+            offloaded_segments_path,
+            transaction_markers_path,
+            tiered_cache_path,
             message_expiry,
             message_deduplicator,
             segments: vec![],
@@ -140,6 +302,19 @@ impl Partition {
             should_increment_offset: false,
             consumer_offsets: DashMap::new(),
             consumer_group_offsets: DashMap::new(),
+            transactions: PartitionTransactions::default(),
+            producer_sequences: AHashMap::new(),
+            unsaved_producer_sequences: AHashSet::new(),
+            delivery_attempts: DashMap::new(),
+            delayed_messages: DashMap::new(),
+            unsaved_delayed_messages: DashSet::new(),
+            has_scheduled_messages: false,
+            offloaded_segments: Vec::new(),
+            cached_segments: Arc::new(Mutex::new(VecDeque::new())),
+            segment_fetches: DashMap::new(),
+            throughput: PartitionThroughput::default(),
+            messages_appended: Arc::new(Notify::new()),
+            relocation_lock: Arc::new(Mutex::new(())),
             config,
             storage,
             created_at,
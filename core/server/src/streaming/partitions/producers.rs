@@ -0,0 +1,212 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::partitions::COMPONENT;
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::segments::IggyMessagesBatchMut;
+use error_set::ErrContext;
+use iggy_common::{Confirmation, IggyError, ProducerSequence};
+use std::path::Path;
+use tokio::fs::create_dir_all;
+use tracing::{error, trace};
+
+impl Partition {
+    /// Appends the messages sent by the idempotent producer, unless the batch with the same sequence number
+    /// has already been appended. Returns `false` if the batch has been ignored as a duplicate.
+    pub async fn append_idempotent_messages(
+        &mut self,
+        producer: &ProducerSequence,
+        batch: IggyMessagesBatchMut,
+        confirmation: Option<Confirmation>,
+    ) -> Result<bool, IggyError> {
+        let last_sequence = self.producer_sequences.get(&producer.producer_id);
+        if is_duplicate(last_sequence, producer, self.partition_id)? {
+            trace!(
+                "Ignoring duplicate batch with sequence: {} of producer with ID: {} for partition with ID: {}.",
+                producer.sequence, producer.producer_id, self.partition_id
+            );
+            return Ok(false);
+        }
+
+        self.append_messages(batch, confirmation).await?;
+        self.producer_sequences
+            .insert(producer.producer_id, *producer);
+        self.unsaved_producer_sequences.insert(producer.producer_id);
+        // The messages might have been persisted right away, otherwise the sequence is saved with the next flush.
+        if self.unsaved_messages_count == 0 {
+            self.persist_producer_sequences().await?;
+        }
+        Ok(true)
+    }
+
+    /// Saves the sequences of the producers, whose messages have been persisted. The sequence can't be saved
+    /// before its messages, otherwise the retried batch lost along with the unsaved messages would be ignored.
+    pub(crate) async fn persist_producer_sequences(&mut self) -> Result<(), IggyError> {
+        for producer_id in std::mem::take(&mut self.unsaved_producer_sequences) {
+            let Some(producer) = self.producer_sequences.get(&producer_id) else {
+                continue;
+            };
+
+            let path = format!("{}/{}", self.producers_path, producer_id);
+            self.storage
+                .partition
+                .save_producer_sequence(producer, &path)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to save sequence of producer with ID: {producer_id}, partition: {self}",
+                    )
+                })?;
+        }
+        Ok(())
+    }
+
+    pub async fn load_producer_sequences(&mut self) -> Result<(), IggyError> {
+        trace!(
+            "Loading producer sequences for partition with ID: {} for topic with ID: {} and stream with ID: {}...",
+            self.partition_id, self.topic_id, self.stream_id
+        );
+        // The partitions created before the idempotent producers were introduced have no such directory.
+        if !Path::new(&self.producers_path).exists()
+            && create_dir_all(&self.producers_path).await.is_err()
+        {
+            error!(
+                "Failed to create producers directory for partition with ID: {} for stream with ID: {} and topic with ID: {}.",
+                self.partition_id, self.stream_id, self.topic_id
+            );
+            return Err(IggyError::CannotCreatePartitionDirectory(
+                self.partition_id,
+                self.stream_id,
+                self.topic_id,
+            ));
+        }
+
+        let producer_sequences = self
+            .storage
+            .partition
+            .load_producer_sequences(&self.producers_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to load producer sequences, path: {}",
+                    self.producers_path
+                )
+            })?;
+        for producer in producer_sequences {
+            self.producer_sequences
+                .insert(producer.producer_id, producer);
+        }
+        Ok(())
+    }
+}
+
+/// Checks the sequence number of the batch against the last one appended by the same producer.
+/// Returns `true` if the batch has already been appended, or an error if it is out of order.
+/// The producer sends the batches of the partition one by one, so only the last appended batch can be retried,
+/// while any older sequence means that the producer has lost track of the partition, e.g. by referring to it
+/// by another stream or topic identifier, and its batch must not be dropped as a duplicate.
+/// The sequence numbers start from 0 for each new producer and epoch.
+fn is_duplicate(
+    last_sequence: Option<&ProducerSequence>,
+    producer: &ProducerSequence,
+    partition_id: u32,
+) -> Result<bool, IggyError> {
+    let expected_sequence = match last_sequence {
+        Some(last) if last.producer_epoch == producer.producer_epoch => {
+            if producer.sequence == last.sequence {
+                return Ok(true);
+            }
+            last.sequence + 1
+        }
+        Some(last) if last.producer_epoch > producer.producer_epoch => {
+            return Err(IggyError::ProducerFenced(
+                producer.producer_id,
+                producer.producer_epoch,
+                last.producer_epoch,
+            ));
+        }
+        _ => 0,
+    };
+
+    if producer.sequence != expected_sequence {
+        return Err(IggyError::OutOfOrderSequenceNumber(
+            producer.producer_id,
+            producer.sequence,
+            expected_sequence,
+            partition_id,
+        ));
+    }
+
+    Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sequence(producer_epoch: u32, sequence: u64) -> ProducerSequence {
+        ProducerSequence {
+            producer_id: 1,
+            producer_epoch,
+            sequence,
+        }
+    }
+
+    #[test]
+    fn first_batch_of_producer_should_start_from_sequence_0() {
+        assert!(!is_duplicate(None, &sequence(0, 0), 1).unwrap());
+        assert!(matches!(
+            is_duplicate(None, &sequence(0, 1), 1),
+            Err(IggyError::OutOfOrderSequenceNumber(1, 1, 0, 1))
+        ));
+    }
+
+    #[test]
+    fn retried_batch_should_be_detected_as_duplicate() {
+        let last = sequence(0, 5);
+        assert!(is_duplicate(Some(&last), &sequence(0, 5), 1).unwrap());
+        assert!(!is_duplicate(Some(&last), &sequence(0, 6), 1).unwrap());
+        assert!(matches!(
+            is_duplicate(Some(&last), &sequence(0, 8), 1),
+            Err(IggyError::OutOfOrderSequenceNumber(1, 8, 6, 1))
+        ));
+    }
+
+    #[test]
+    fn batch_far_behind_last_sequence_should_be_rejected_instead_of_ignored() {
+        let last = sequence(0, 5);
+        assert!(matches!(
+            is_duplicate(Some(&last), &sequence(0, 0), 1),
+            Err(IggyError::OutOfOrderSequenceNumber(1, 0, 6, 1))
+        ));
+        assert!(matches!(
+            is_duplicate(Some(&last), &sequence(0, 4), 1),
+            Err(IggyError::OutOfOrderSequenceNumber(1, 4, 6, 1))
+        ));
+    }
+
+    #[test]
+    fn batch_of_previous_epoch_should_be_fenced() {
+        let last = sequence(2, 5);
+        assert!(matches!(
+            is_duplicate(Some(&last), &sequence(1, 6), 1),
+            Err(IggyError::ProducerFenced(1, 1, 2))
+        ));
+        assert!(!is_duplicate(Some(&last), &sequence(3, 0), 1).unwrap());
+    }
+}
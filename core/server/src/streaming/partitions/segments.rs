@@ -21,9 +21,12 @@ use std::sync::atomic::Ordering;
 use crate::streaming::partitions::COMPONENT;
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::segments::*;
+use ahash::AHashMap;
 use error_set::ErrContext;
 use iggy_common::IggyError;
+use iggy_common::IggyExpiry;
 use iggy_common::IggyTimestamp;
+use iggy_common::MaxTopicSize;
 use tracing::info;
 
 pub struct DeletedSegment {
@@ -31,6 +34,12 @@ pub struct DeletedSegment {
     pub messages_count: u32,
 }
 
+#[derive(Debug, Default)]
+pub struct CompactedSegments {
+    pub deleted_segments_count: u32,
+    pub removed_messages_count: u64,
+}
+
 impl Partition {
     pub fn get_segments_count(&self) -> u32 {
         self.segments.len() as u32
@@ -68,6 +77,150 @@ impl Partition {
         expired_segments
     }
 
+    /// Rolls the open segment once its first message is older than the `segment_max_age`,
+    /// so that the next messages are appended to a new segment and the rolled one can expire.
+    /// Returns whether the segment has been rolled.
+    pub async fn roll_segment_by_age(
+        &mut self,
+        segment_max_age: IggyExpiry,
+        now: IggyTimestamp,
+    ) -> Result<bool, IggyError> {
+        let IggyExpiry::ExpireDuration(max_age) = segment_max_age else {
+            return Ok(false);
+        };
+
+        let Some(segment) = self.segments.last() else {
+            return Ok(false);
+        };
+        if segment.is_closed()
+            || segment.get_messages_count() == 0
+            || segment.start_timestamp() + max_age.as_micros() > now.as_micros()
+        {
+            return Ok(false);
+        }
+
+        self.flush_unsaved_buffer(false)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to flush unsaved messages before rolling segment, partition: {self}")
+            })?;
+        let segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
+        info!(
+            "Rolling segment with start offset: {} for partition with ID: {}, stream with ID: {}, topic with ID: {}, as it's older than: {max_age}",
+            segment.start_offset(),
+            self.partition_id,
+            self.stream_id,
+            self.topic_id
+        );
+        segment.roll().await;
+        Ok(true)
+    }
+
+    /// Returns the start offsets of the oldest closed segments, which have to be deleted
+    /// for the partition to fit within the `max_partition_size` again.
+    pub fn get_oversized_segments_start_offsets(
+        &self,
+        max_partition_size: MaxTopicSize,
+    ) -> Vec<u64> {
+        let MaxTopicSize::Custom(max_size) = max_partition_size else {
+            return Vec::new();
+        };
+
+        let mut size = self.size_bytes.load(Ordering::SeqCst);
+        let mut oversized_segments = Vec::new();
+        for segment in &self.segments {
+            if size <= max_size.as_bytes_u64() || !segment.is_closed() {
+                break;
+            }
+
+            size = size.saturating_sub(segment.get_messages_size().as_bytes_u64());
+            oversized_segments.push(segment.start_offset());
+        }
+        oversized_segments
+    }
+
+    /// Reads the keys of the next messages appended up to the `end_offset`, which haven't been collected yet.
+    /// Only a part of a single segment is read, so that the partition is never locked for the whole scan.
+    pub async fn read_uncollected_message_keys(
+        &self,
+        end_offset: u64,
+    ) -> Result<Option<CollectedMessageKeys>, IggyError> {
+        let Some(segment) = self
+            .segments
+            .iter()
+            .find(|segment| segment.has_uncollected_message_keys(end_offset))
+        else {
+            return Ok(None);
+        };
+
+        segment
+            .read_uncollected_message_keys()
+            .await
+            .map(Some)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to read message keys, segment: {segment}"
+                )
+            })
+    }
+
+    pub fn add_collected_message_keys(&mut self, collected: CollectedMessageKeys) {
+        if let Some(segment) = self.get_segment_mut(collected.segment_start_offset) {
+            segment.add_collected_message_keys(collected);
+        }
+    }
+
+    /// Compacts the closed segments, keeping only the newest message for each collected message key.
+    /// Only the segments, from which any message would be removed, are rewritten. The segments left
+    /// without any messages are deleted, except the first one, so that the partition still starts at the same offset.
+    pub async fn compact_segments(
+        &mut self,
+        tombstone_expiry_timestamp: u64,
+    ) -> Result<CompactedSegments, IggyError> {
+        let mut removable_segments = Vec::new();
+        {
+            let mut latest_offsets = AHashMap::new();
+            for segment in &self.segments {
+                segment.add_latest_offsets(&mut latest_offsets);
+            }
+            for segment in &self.segments {
+                if let Some(latest_offsets) =
+                    segment.get_removable_message_keys(&latest_offsets, tombstone_expiry_timestamp)
+                {
+                    removable_segments.push((segment.start_offset(), latest_offsets));
+                }
+            }
+        }
+
+        let mut compacted_segments = CompactedSegments::default();
+        let mut empty_segments = Vec::new();
+        let first_start_offset = self.segments.first().map(|segment| segment.start_offset());
+        for (start_offset, latest_offsets) in removable_segments {
+            let Some(segment) = self.get_segment_mut(start_offset) else {
+                continue;
+            };
+
+            let compacted_segment = segment
+                .compact(&latest_offsets, tombstone_expiry_timestamp)
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to compact segment: {segment}")
+                })?;
+            compacted_segments.removed_messages_count +=
+                compacted_segment.removed_messages_count as u64;
+            if segment.get_messages_count() == 0 && Some(start_offset) != first_start_offset {
+                empty_segments.push(start_offset);
+            }
+        }
+
+        for start_offset in empty_segments {
+            self.delete_segment(start_offset).await?;
+            compacted_segments.deleted_segments_count += 1;
+        }
+
+        Ok(compacted_segments)
+    }
+
     pub async fn add_persisted_segment(&mut self, start_offset: u64) -> Result<(), IggyError> {
         info!(
             "Creating the new segment for partition with ID: {}, stream with ID: {}, topic with ID: {}...",
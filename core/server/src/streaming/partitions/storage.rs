@@ -20,20 +20,26 @@ use crate::compat::index_rebuilding::index_rebuilder::IndexRebuilder;
 use crate::configs::cache_indexes::CacheIndexesConfig;
 use crate::state::system::PartitionState;
 use crate::streaming::partitions::COMPONENT;
-use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
+use crate::streaming::partitions::data_directories::remove_partition_directory;
+use crate::streaming::partitions::partition::{
+    ConsumerOffset, DelayedMessages, DeliveryAttempts, OffloadedSegment, Partition,
+    TransactionMarker, TransactionMarkers,
+};
 use crate::streaming::persistence::persister::PersisterKind;
 use crate::streaming::segments::*;
 use crate::streaming::storage::PartitionStorage;
+use crate::streaming::transactions::markers::TransactionOutcome;
 use crate::streaming::utils::file;
 use error_set::ErrContext;
 use iggy_common::ConsumerKind;
 use iggy_common::IggyError;
+use iggy_common::IggyTimestamp;
+use iggy_common::ProducerSequence;
 use std::path::Path;
 use std::sync::Arc;
 use std::sync::atomic::Ordering;
 use tokio::fs;
 use tokio::fs::create_dir_all;
-use tokio::io::AsyncReadExt;
 use tracing::{error, info, trace, warn};
 
 #[derive(Debug)]
@@ -61,6 +67,7 @@ impl PartitionStorage for FilePartitionStorage {
             partition.partition_path
         );
         partition.created_at = state.created_at;
+        partition.load_data_directory().await;
         let dir_entries = fs::read_dir(&partition.partition_path).await;
         if fs::read_dir(&partition.partition_path)
                 .await
@@ -228,6 +235,7 @@ impl PartitionStorage for FilePartitionStorage {
                 break;
             }
             segment.set_end_offset(end_offsets[end_offset_index]);
+            segment.close().await;
         }
 
         if !partition.segments.is_empty() {
@@ -248,11 +256,41 @@ impl PartitionStorage for FilePartitionStorage {
         }
 
         partition
-            .load_consumer_offsets()
+            .load_consumer_offsets(&state.consumer_offsets)
             .await
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to load consumer offsets, partition: {partition}",)
             })?;
+        partition
+            .load_producer_sequences()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to load producer sequences, partition: {partition}",)
+            })?;
+        partition
+            .load_delivery_attempts()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to load delivery attempts, partition: {partition}",)
+            })?;
+        partition
+            .load_delayed_messages()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to load delayed messages, partition: {partition}",)
+            })?;
+        partition
+            .load_offloaded_segments()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to load offloaded segments, partition: {partition}",)
+            })?;
+        partition
+            .load_transaction_markers()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to load transaction markers, partition: {partition}",)
+            })?;
         info!(
             "Loaded partition with ID: {} for stream with ID: {} and topic with ID: {}, current offset: {}.",
             partition.partition_id,
@@ -269,6 +307,7 @@ impl PartitionStorage for FilePartitionStorage {
             "Saving partition with start ID: {} for stream with ID: {} and topic with ID: {}...",
             partition.partition_id, partition.stream_id, partition.topic_id
         );
+        partition.link_data_directory().await?;
         if !Path::new(&partition.partition_path).exists()
             && create_dir_all(&partition.partition_path).await.is_err()
         {
@@ -325,6 +364,36 @@ impl PartitionStorage for FilePartitionStorage {
             ));
         }
 
+        if !Path::new(&partition.producers_path).exists()
+            && create_dir_all(&partition.producers_path).await.is_err()
+        {
+            error!(
+                "Failed to create producers directory for partition with ID: {} for stream with ID: {} and topic with ID: {}.",
+                partition.partition_id, partition.stream_id, partition.topic_id
+            );
+            return Err(IggyError::CannotCreatePartition(
+                partition.partition_id,
+                partition.stream_id,
+                partition.topic_id,
+            ));
+        }
+
+        if !Path::new(&partition.delivery_attempts_path).exists()
+            && create_dir_all(&partition.delivery_attempts_path)
+                .await
+                .is_err()
+        {
+            error!(
+                "Failed to create delivery attempts directory for partition with ID: {} for stream with ID: {} and topic with ID: {}.",
+                partition.partition_id, partition.stream_id, partition.topic_id
+            );
+            return Err(IggyError::CannotCreatePartition(
+                partition.partition_id,
+                partition.stream_id,
+                partition.topic_id,
+            ));
+        }
+
         for segment in partition.get_segments_mut() {
             segment.persist().await.with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to persist segment: {segment}",)
@@ -378,7 +447,10 @@ impl PartitionStorage for FilePartitionStorage {
             ));
         }
 
-        if fs::remove_dir_all(&partition.partition_path).await.is_err() {
+        if remove_partition_directory(&partition.partition_path)
+            .await
+            .is_err()
+        {
             error!(
                 "Cannot delete partition directory: {} for partition with ID: {} for topic with ID: {} for stream with ID: {}.",
                 partition.partition_path,
@@ -399,15 +471,28 @@ impl PartitionStorage for FilePartitionStorage {
         Ok(())
     }
 
-    async fn save_consumer_offset(&self, offset: u64, path: &str) -> Result<(), IggyError> {
+    async fn save_consumer_offset(
+        &self,
+        consumer_offset: &ConsumerOffset,
+    ) -> Result<(), IggyError> {
+        let metadata = consumer_offset.metadata.as_deref().unwrap_or_default();
+        let mut bytes = Vec::with_capacity(ConsumerOffset::BASE_SIZE + metadata.len());
+        bytes.extend(consumer_offset.offset.to_le_bytes());
+        bytes.extend(consumer_offset.committed_at.as_micros().to_le_bytes());
+        bytes.extend((metadata.len() as u16).to_le_bytes());
+        bytes.extend(metadata.as_bytes());
+        let path = &consumer_offset.path;
         self.persister
-            .overwrite(path, &offset.to_le_bytes())
+            .overwrite(path, &bytes)
             .await
             .with_error_context(|error| format!(
                 "{COMPONENT} (error: {error}) - failed to overwrite consumer offset with value: {}, path: {}",
-                offset, path,
+                consumer_offset.offset, path,
             ))?;
-        trace!("Stored consumer offset value: {}, path: {}", offset, path);
+        trace!(
+            "Stored consumer offset value: {}, path: {}",
+            consumer_offset.offset, path
+        );
         Ok(())
     }
 
@@ -450,26 +535,48 @@ impl PartitionStorage for FilePartitionStorage {
 
             let path = Arc::new(path.unwrap().to_string());
             let consumer_id = consumer_id.unwrap();
-            let mut file = file::open(&path)
+            let bytes = fs::read(path.as_str())
                 .await
                 .with_error_context(|error| {
                     format!(
-                        "{COMPONENT} (error: {error}) - failed to open offset file, path: {path}"
+                        "{COMPONENT} (error: {error}) - failed to read offset file, path: {path}"
                     )
                 })
                 .map_err(|_| IggyError::CannotReadFile)?;
-            let offset = file
-                .read_u64_le()
-                .await
-                .with_error_context(|error| {
-                    format!("{COMPONENT} (error: {error}) - failed to read consumer offset from file, path: {path}")
-                })
-                .map_err(|_| IggyError::CannotReadFile)?;
+            // The offset files written before the commit metadata was introduced contain only the offset.
+            if bytes.len() < 8 || (bytes.len() > 8 && bytes.len() < ConsumerOffset::BASE_SIZE) {
+                error!(
+                    "Invalid consumer offset file: '{}', size: {}.",
+                    path,
+                    bytes.len()
+                );
+                continue;
+            }
+
+            let offset = u64::from_le_bytes(bytes[..8].try_into().unwrap());
+            let (committed_at, metadata) = if bytes.len() == 8 {
+                (IggyTimestamp::zero(), None)
+            } else {
+                let committed_at = u64::from_le_bytes(bytes[8..16].try_into().unwrap());
+                let metadata_length =
+                    u16::from_le_bytes(bytes[16..18].try_into().unwrap()) as usize;
+                let metadata = match bytes.get(18..18 + metadata_length) {
+                    Some([]) => None,
+                    Some(metadata) => Some(String::from_utf8_lossy(metadata).into_owned()),
+                    None => {
+                        error!("Invalid consumer offset metadata in file: '{}'.", path);
+                        None
+                    }
+                };
+                (committed_at.into(), metadata)
+            };
 
             consumer_offsets.push(ConsumerOffset {
                 kind,
                 consumer_id,
                 offset,
+                metadata,
+                committed_at,
                 path,
             });
         }
@@ -505,4 +612,465 @@ impl PartitionStorage for FilePartitionStorage {
         }
         Ok(())
     }
+
+    async fn save_producer_sequence(
+        &self,
+        producer: &ProducerSequence,
+        path: &str,
+    ) -> Result<(), IggyError> {
+        let mut bytes = Vec::with_capacity(ProducerSequence::SIZE);
+        bytes.extend(producer.producer_id.to_le_bytes());
+        bytes.extend(producer.producer_epoch.to_le_bytes());
+        bytes.extend(producer.sequence.to_le_bytes());
+        self.persister
+            .overwrite(path, &bytes)
+            .await
+            .with_error_context(|error| format!(
+                "{COMPONENT} (error: {error}) - failed to overwrite producer sequence: {}, producer ID: {}, path: {}",
+                producer.sequence, producer.producer_id, path,
+            ))?;
+        trace!(
+            "Stored producer sequence: {}, producer ID: {}, path: {}",
+            producer.sequence, producer.producer_id, path
+        );
+        Ok(())
+    }
+
+    async fn load_producer_sequences(
+        &self,
+        path: &str,
+    ) -> Result<Vec<ProducerSequence>, IggyError> {
+        trace!("Loading producer sequences from path: {path}...");
+        if !Path::new(path).exists() {
+            trace!("Producer sequences directory does not exist: {path}.");
+            return Ok(Vec::new());
+        }
+
+        let mut dir_entries = fs::read_dir(path)
+            .await
+            .map_err(|_| IggyError::CannotReadFile)?;
+        let mut producer_sequences = Vec::new();
+        while let Some(dir_entry) = dir_entries.next_entry().await.unwrap_or(None) {
+            let name = dir_entry.file_name().into_string().unwrap_or_default();
+            if name.parse::<u64>().is_err() {
+                error!("Invalid producer ID file with name: '{}'.", name);
+                continue;
+            }
+
+            let path = dir_entry.path();
+            let bytes = fs::read(&path)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to read producer sequence file, path: {}",
+                        path.display()
+                    )
+                })
+                .map_err(|_| IggyError::CannotReadFile)?;
+            if bytes.len() != ProducerSequence::SIZE {
+                error!(
+                    "Invalid producer sequence file: '{}', size: {}.",
+                    path.display(),
+                    bytes.len()
+                );
+                continue;
+            }
+
+            producer_sequences.push(ProducerSequence {
+                producer_id: u64::from_le_bytes(bytes[..8].try_into().unwrap()),
+                producer_epoch: u32::from_le_bytes(bytes[8..12].try_into().unwrap()),
+                sequence: u64::from_le_bytes(bytes[12..20].try_into().unwrap()),
+            });
+        }
+
+        Ok(producer_sequences)
+    }
+
+    async fn save_delivery_attempts(
+        &self,
+        delivery_attempts: &DeliveryAttempts,
+        path: &str,
+    ) -> Result<(), IggyError> {
+        let mut bytes = Vec::with_capacity(DeliveryAttempts::SIZE);
+        bytes.extend(delivery_attempts.offset.to_le_bytes());
+        bytes.extend(delivery_attempts.attempts.to_le_bytes());
+        self.persister
+            .overwrite(path, &bytes)
+            .await
+            .with_error_context(|error| format!(
+                "{COMPONENT} (error: {error}) - failed to overwrite delivery attempts: {}, offset: {}, consumer group ID: {}, path: {}",
+                delivery_attempts.attempts, delivery_attempts.offset, delivery_attempts.consumer_group_id, path,
+            ))?;
+        trace!(
+            "Stored delivery attempts: {}, offset: {}, consumer group ID: {}, path: {}",
+            delivery_attempts.attempts,
+            delivery_attempts.offset,
+            delivery_attempts.consumer_group_id,
+            path
+        );
+        Ok(())
+    }
+
+    async fn load_delivery_attempts(&self, path: &str) -> Result<Vec<DeliveryAttempts>, IggyError> {
+        trace!("Loading delivery attempts from path: {path}...");
+        if !Path::new(path).exists() {
+            trace!("Delivery attempts directory does not exist: {path}.");
+            return Ok(Vec::new());
+        }
+
+        let mut dir_entries = fs::read_dir(path)
+            .await
+            .map_err(|_| IggyError::CannotReadFile)?;
+        let mut delivery_attempts = Vec::new();
+        while let Some(dir_entry) = dir_entries.next_entry().await.unwrap_or(None) {
+            let name = dir_entry.file_name().into_string().unwrap_or_default();
+            let Ok(consumer_group_id) = name.parse::<u32>() else {
+                error!("Invalid consumer group ID file with name: '{}'.", name);
+                continue;
+            };
+
+            let path = dir_entry.path();
+            let bytes = fs::read(&path)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to read delivery attempts file, path: {}",
+                        path.display()
+                    )
+                })
+                .map_err(|_| IggyError::CannotReadFile)?;
+            if bytes.len() != DeliveryAttempts::SIZE {
+                error!(
+                    "Invalid delivery attempts file: '{}', size: {}.",
+                    path.display(),
+                    bytes.len()
+                );
+                continue;
+            }
+
+            delivery_attempts.push(DeliveryAttempts {
+                consumer_group_id,
+                offset: u64::from_le_bytes(bytes[..8].try_into().unwrap()),
+                attempts: u32::from_le_bytes(bytes[8..12].try_into().unwrap()),
+            });
+        }
+
+        Ok(delivery_attempts)
+    }
+
+    async fn delete_delivery_attempts(&self, path: &str) -> Result<(), IggyError> {
+        if !Path::new(path).exists() {
+            trace!("Delivery attempts file does not exist: {path}.");
+            return Ok(());
+        }
+
+        if fs::remove_file(path).await.is_err() {
+            error!("Cannot delete delivery attempts file: {path}.");
+            return Err(IggyError::CannotDeleteFile);
+        }
+        Ok(())
+    }
+
+    async fn save_delayed_messages(
+        &self,
+        delayed_messages: &DelayedMessages,
+        path: &str,
+    ) -> Result<(), IggyError> {
+        if delayed_messages.messages.is_empty() {
+            if Path::new(path).exists() && fs::remove_file(path).await.is_err() {
+                error!("Cannot delete delayed messages file: {path}.");
+                return Err(IggyError::CannotDeleteFile);
+            }
+            return Ok(());
+        }
+
+        let mut bytes = Vec::with_capacity(5 + delayed_messages.messages.len() * 16);
+        bytes.push(delayed_messages.kind.as_code());
+        bytes.extend(delayed_messages.consumer_id.to_le_bytes());
+        for (deliver_at, offset) in &delayed_messages.messages {
+            bytes.extend(deliver_at.to_le_bytes());
+            bytes.extend(offset.to_le_bytes());
+        }
+        self.persister
+            .overwrite(path, &bytes)
+            .await
+            .with_error_context(|error| format!(
+                "{COMPONENT} (error: {error}) - failed to overwrite delayed messages of {} with ID: {}, path: {}",
+                delayed_messages.kind, delayed_messages.consumer_id, path,
+            ))?;
+        trace!(
+            "Stored {} delayed messages of {} with ID: {}, path: {}",
+            delayed_messages.messages.len(),
+            delayed_messages.kind,
+            delayed_messages.consumer_id,
+            path
+        );
+        Ok(())
+    }
+
+    async fn load_delayed_messages(&self, path: &str) -> Result<Vec<DelayedMessages>, IggyError> {
+        trace!("Loading delayed messages from path: {path}...");
+        if !Path::new(path).exists() {
+            trace!("Delayed messages directory does not exist: {path}.");
+            return Ok(Vec::new());
+        }
+
+        let mut dir_entries = fs::read_dir(path)
+            .await
+            .map_err(|_| IggyError::CannotReadFile)?;
+        let mut delayed_messages = Vec::new();
+        while let Some(dir_entry) = dir_entries.next_entry().await.unwrap_or(None) {
+            let path = dir_entry.path();
+            let bytes = fs::read(&path)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to read delayed messages file, path: {}",
+                        path.display()
+                    )
+                })
+                .map_err(|_| IggyError::CannotReadFile)?;
+            let Some(kind) = bytes
+                .first()
+                .and_then(|code| ConsumerKind::from_code(*code).ok())
+            else {
+                error!("Invalid delayed messages file: '{}'.", path.display());
+                continue;
+            };
+            if bytes.len() < 5 || (bytes.len() - 5) % 16 != 0 {
+                error!(
+                    "Invalid delayed messages file: '{}', size: {}.",
+                    path.display(),
+                    bytes.len()
+                );
+                continue;
+            }
+
+            let mut messages =
+                DelayedMessages::new(kind, u32::from_le_bytes(bytes[1..5].try_into().unwrap()));
+            for entry in bytes[5..].chunks_exact(16) {
+                let deliver_at = u64::from_le_bytes(entry[..8].try_into().unwrap());
+                let offset = u64::from_le_bytes(entry[8..].try_into().unwrap());
+                messages.messages.insert((deliver_at, offset));
+            }
+            delayed_messages.push(messages);
+        }
+
+        Ok(delayed_messages)
+    }
+
+    async fn save_offloaded_segments(
+        &self,
+        offloaded_segments: &[OffloadedSegment],
+        path: &str,
+    ) -> Result<(), IggyError> {
+        let mut bytes = Vec::with_capacity(offloaded_segments.len() * OffloadedSegment::SIZE);
+        for segment in offloaded_segments {
+            bytes.extend(segment.start_offset.to_le_bytes());
+            bytes.extend(segment.end_offset.to_le_bytes());
+            bytes.extend(segment.start_timestamp.to_le_bytes());
+            bytes.extend(segment.end_timestamp.to_le_bytes());
+            bytes.extend(segment.size_bytes.to_le_bytes());
+            bytes.extend(segment.messages_count.to_le_bytes());
+        }
+        self.persister
+            .overwrite(path, &bytes)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to overwrite offloaded segments, path: {path}")
+            })?;
+        trace!(
+            "Stored {} offloaded segments, path: {path}",
+            offloaded_segments.len()
+        );
+        Ok(())
+    }
+
+    async fn load_offloaded_segments(
+        &self,
+        path: &str,
+    ) -> Result<Vec<OffloadedSegment>, IggyError> {
+        trace!("Loading offloaded segments from path: {path}...");
+        if !Path::new(path).exists() {
+            trace!("Offloaded segments file does not exist: {path}.");
+            return Ok(Vec::new());
+        }
+
+        let bytes = fs::read(path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to read offloaded segments file, path: {path}")
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        if bytes.len() % OffloadedSegment::SIZE != 0 {
+            error!(
+                "Invalid offloaded segments file: '{path}', size: {}.",
+                bytes.len()
+            );
+            return Err(IggyError::CannotReadFile);
+        }
+
+        let read_u64 = |bytes: &[u8], position: usize| {
+            u64::from_le_bytes(bytes[position..position + 8].try_into().unwrap())
+        };
+        let offloaded_segments = bytes
+            .chunks_exact(OffloadedSegment::SIZE)
+            .map(|entry| OffloadedSegment {
+                start_offset: read_u64(entry, 0),
+                end_offset: read_u64(entry, 8),
+                start_timestamp: read_u64(entry, 16),
+                end_timestamp: read_u64(entry, 24),
+                size_bytes: read_u64(entry, 32),
+                messages_count: read_u64(entry, 40),
+            })
+            .collect();
+        Ok(offloaded_segments)
+    }
+
+    async fn save_transaction_marker(
+        &self,
+        marker: &TransactionMarker,
+        path: &str,
+    ) -> Result<(), IggyError> {
+        let mut bytes =
+            Vec::with_capacity(TransactionMarkers::HEADER_SIZE + TransactionMarker::SIZE);
+        // The file is created along with the first marker, as the appending requires it to exist.
+        let exists = Path::new(path).exists();
+        if !exists {
+            bytes.extend(0u64.to_le_bytes());
+        }
+        write_transaction_marker(marker, &mut bytes);
+        let result = if exists {
+            self.persister.append(path, &bytes).await
+        } else {
+            self.persister.overwrite(path, &bytes).await
+        };
+        result
+            .with_error_context(|error| format!(
+                "{COMPONENT} (error: {error}) - failed to append marker of transaction with ID: {}, path: {}",
+                marker.transaction_id, path,
+            ))?;
+        trace!(
+            "Stored {:?} marker of transaction with ID: {} at offset: {}, path: {}",
+            marker.outcome, marker.transaction_id, marker.offset, path
+        );
+        Ok(())
+    }
+
+    async fn save_transaction_markers(
+        &self,
+        markers: &TransactionMarkers,
+        path: &str,
+    ) -> Result<(), IggyError> {
+        let mut bytes = Vec::with_capacity(
+            TransactionMarkers::HEADER_SIZE + markers.markers.len() * TransactionMarker::SIZE,
+        );
+        bytes.extend(markers.pruned_transaction_id.to_le_bytes());
+        for marker in &markers.markers {
+            write_transaction_marker(marker, &mut bytes);
+        }
+        // The markers are written to the temporary file first, which then replaces the previous one,
+        // so that the markers are not lost if the server is stopped in the meantime.
+        let temp_path = format!("{path}.tmp");
+        if Path::new(&temp_path).exists() {
+            self.persister
+                .delete(&temp_path)
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to delete temporary transaction markers file, path: {temp_path}")
+                })?;
+        }
+        self.persister
+            .overwrite(&temp_path, &bytes)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to write temporary transaction markers file, path: {temp_path}")
+            })?;
+        file::rename(&temp_path, path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to replace transaction markers file, path: {path} with: {temp_path}")
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+        trace!(
+            "Stored {} transaction markers pruned up to transaction with ID: {}, path: {path}",
+            markers.markers.len(),
+            markers.pruned_transaction_id
+        );
+        Ok(())
+    }
+
+    async fn load_transaction_markers(&self, path: &str) -> Result<TransactionMarkers, IggyError> {
+        trace!("Loading transaction markers from path: {path}...");
+        if !Path::new(path).exists() {
+            trace!("Transaction markers file does not exist: {path}.");
+            return Ok(TransactionMarkers::default());
+        }
+
+        let bytes = fs::read(path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to read transaction markers file, path: {path}")
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        if bytes.len() < TransactionMarkers::HEADER_SIZE {
+            // The file has been created only partially, so it's created again along with the next marker.
+            warn!(
+                "Deleting partially written transaction markers file, path: {path}, size: {}.",
+                bytes.len()
+            );
+            self.persister.delete(path).await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to delete transaction markers file, path: {path}")
+            })?;
+            return Ok(TransactionMarkers::default());
+        }
+
+        // The last entry might have been written only partially, if the server was stopped in the meantime,
+        // so it's truncated before any other marker is appended.
+        let entries_size = bytes.len() - TransactionMarkers::HEADER_SIZE;
+        let valid_size = bytes.len() - entries_size % TransactionMarker::SIZE;
+        if valid_size != bytes.len() {
+            warn!(
+                "Truncating partially written transaction marker, path: {path}, size: {}.",
+                bytes.len()
+            );
+            let file = fs::OpenOptions::new()
+                .write(true)
+                .open(path)
+                .await
+                .map_err(|_| IggyError::CannotReadFile)?;
+            file.set_len(valid_size as u64)
+                .await
+                .map_err(|_| IggyError::CannotWriteToFile)?;
+        }
+
+        let pruned_transaction_id =
+            u64::from_le_bytes(bytes[..TransactionMarkers::HEADER_SIZE].try_into().unwrap());
+        let entries = &bytes[TransactionMarkers::HEADER_SIZE..];
+        let mut markers = Vec::with_capacity(entries.len() / TransactionMarker::SIZE);
+        for entry in entries.chunks_exact(TransactionMarker::SIZE) {
+            let Some(outcome) = TransactionOutcome::from_code(entry[16]) else {
+                error!(
+                    "Invalid transaction marker outcome: {}, path: {path}.",
+                    entry[16]
+                );
+                continue;
+            };
+            markers.push(TransactionMarker {
+                transaction_id: u64::from_le_bytes(entry[..8].try_into().unwrap()),
+                offset: u64::from_le_bytes(entry[8..16].try_into().unwrap()),
+                outcome,
+            });
+        }
+        Ok(TransactionMarkers {
+            pruned_transaction_id,
+            markers,
+        })
+    }
+}
+
+fn write_transaction_marker(marker: &TransactionMarker, bytes: &mut Vec<u8>) {
+    bytes.extend(marker.transaction_id.to_le_bytes());
+    bytes.extend(marker.offset.to_le_bytes());
+    bytes.push(marker.outcome.as_code());
 }
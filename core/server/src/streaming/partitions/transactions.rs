@@ -0,0 +1,282 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::partitions::COMPONENT;
+use crate::streaming::partitions::partition::{Partition, TransactionMarker, TransactionMarkers};
+use crate::streaming::segments::IggyMessagesBatchMut;
+use crate::streaming::transactions::markers::{self, TransactionOutcome};
+use ahash::AHashMap;
+use error_set::ErrContext;
+use iggy_common::{Confirmation, IggyError, IggyMessageView};
+use tracing::{info, trace};
+
+#[derive(Debug, Default)]
+pub struct PartitionTransactions {
+    /// The open transactions with the offset of their first message appended to the partition.
+    open: AHashMap<u64, u64>,
+    /// The markers of the completed transactions appended to the partition, loaded on startup,
+    /// so that the outcome of any transaction is resolved without reading the partition.
+    markers: AHashMap<u64, TransactionMarker>,
+    /// The ID of the latest transaction, whose marker has been pruned along with its messages.
+    /// The transactions up to this ID are treated as completed in the partition.
+    pruned_transaction_id: u64,
+}
+
+impl Partition {
+    /// Appends the messages sent within the open transaction.
+    pub async fn append_transaction_messages(
+        &mut self,
+        transaction_id: u64,
+        batch: IggyMessagesBatchMut,
+        confirmation: Option<Confirmation>,
+    ) -> Result<(), IggyError> {
+        if self.is_transaction_completed(transaction_id) {
+            return Err(IggyError::TransactionNotFound(transaction_id));
+        }
+
+        if batch.is_empty() {
+            return Ok(());
+        }
+
+        let first_offset = if self.should_increment_offset {
+            self.current_offset + 1
+        } else {
+            0
+        };
+        let is_first_batch = !self.transactions.open.contains_key(&transaction_id);
+        if is_first_batch {
+            self.transactions.open.insert(transaction_id, first_offset);
+        }
+
+        if let Err(error) = self.append_messages(batch, confirmation).await {
+            if is_first_batch {
+                self.transactions.open.remove(&transaction_id);
+            }
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    /// Completes the transaction by appending its marker and storing its offset in the markers index.
+    /// The transaction, which has been already completed in the partition, is skipped, so that the outcome
+    /// can be safely replayed on startup.
+    pub async fn end_transaction(
+        &mut self,
+        transaction_id: u64,
+        outcome: TransactionOutcome,
+        marker: IggyMessagesBatchMut,
+    ) -> Result<(), IggyError> {
+        if self.is_transaction_completed(transaction_id) {
+            return Ok(());
+        }
+
+        trace!(
+            "Appending {outcome:?} marker of transaction with ID: {transaction_id} to partition with ID: {}...",
+            self.partition_id
+        );
+        self.append_messages(marker, None).await?;
+        self.transactions.open.remove(&transaction_id);
+        let marker = TransactionMarker {
+            transaction_id,
+            offset: self.current_offset,
+            outcome,
+        };
+        self.transactions.markers.insert(transaction_id, marker);
+        self.storage
+            .partition
+            .save_transaction_marker(&marker, &self.transaction_markers_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to save marker of transaction with ID: {transaction_id}, partition: {self}"
+                )
+            })
+    }
+
+    /// Checks whether the transaction has been already completed in the partition,
+    /// including the transactions, whose markers have been pruned.
+    pub fn is_transaction_completed(&self, transaction_id: u64) -> bool {
+        transaction_id <= self.transactions.pruned_transaction_id
+            || self.transactions.markers.contains_key(&transaction_id)
+    }
+
+    /// Removes the markers below the first offset of the partition, as the messages of their transactions
+    /// have been deleted along with the segments. The markers of the transactions begun at or after
+    /// the `oldest_transaction_id`, which is the oldest transaction not completed yet, are kept,
+    /// so that none of the pruned transactions is treated as completed before it actually is.
+    ///
+    /// Returns the number of the pruned markers.
+    pub async fn prune_transaction_markers(
+        &mut self,
+        oldest_transaction_id: Option<u64>,
+    ) -> Result<usize, IggyError> {
+        let first_offset = self
+            .offloaded_segments
+            .first()
+            .map(|segment| segment.start_offset)
+            .or_else(|| self.segments.first().map(|segment| segment.start_offset()))
+            .unwrap_or_default();
+        let is_prunable = |marker: &TransactionMarker| {
+            marker.offset < first_offset
+                && oldest_transaction_id.is_none_or(|id| marker.transaction_id < id)
+        };
+        let Some(pruned_transaction_id) = self
+            .transactions
+            .markers
+            .values()
+            .filter(|marker| is_prunable(marker))
+            .map(|marker| marker.transaction_id)
+            .max()
+        else {
+            return Ok(0);
+        };
+
+        let mut markers = self
+            .transactions
+            .markers
+            .values()
+            .filter(|marker| !is_prunable(marker))
+            .copied()
+            .collect::<Vec<_>>();
+        markers.sort_by_key(|marker| marker.offset);
+        let pruned_markers = self.transactions.markers.len() - markers.len();
+        let markers = TransactionMarkers {
+            pruned_transaction_id: pruned_transaction_id
+                .max(self.transactions.pruned_transaction_id),
+            markers,
+        };
+        self.storage
+            .partition
+            .save_transaction_markers(&markers, &self.transaction_markers_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to save pruned transaction markers, partition: {self}"
+                )
+            })?;
+        self.transactions.pruned_transaction_id = markers.pruned_transaction_id;
+        self.transactions.markers = markers
+            .markers
+            .into_iter()
+            .map(|marker| (marker.transaction_id, marker))
+            .collect();
+        info!(
+            "Pruned {pruned_markers} transaction markers below offset: {first_offset} for partition with ID: {}, topic with ID: {} and stream with ID: {}",
+            self.partition_id, self.topic_id, self.stream_id
+        );
+        Ok(pruned_markers)
+    }
+
+    pub async fn load_transaction_markers(&mut self) -> Result<(), IggyError> {
+        trace!(
+            "Loading transaction markers for partition with ID: {} for topic with ID: {} and stream with ID: {}...",
+            self.partition_id, self.topic_id, self.stream_id
+        );
+        let markers = self
+            .storage
+            .partition
+            .load_transaction_markers(&self.transaction_markers_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to load transaction markers, path: {}",
+                    self.transaction_markers_path
+                )
+            })?;
+        self.transactions.pruned_transaction_id = markers.pruned_transaction_id;
+        self.transactions.markers = markers
+            .markers
+            .into_iter()
+            .map(|marker| (marker.transaction_id, marker))
+            .collect();
+        Ok(())
+    }
+
+    /// Returns the offset of the last message, which can be read using the `read_committed` isolation level,
+    /// as all the following messages might still belong to the open transactions.
+    ///
+    /// Returns `None` if none of the messages can be read yet.
+    pub fn last_stable_offset(&self) -> Option<u64> {
+        match self.transactions.open.values().min() {
+            Some(0) => None,
+            Some(first_offset) => Some(first_offset - 1),
+            None => Some(self.current_offset),
+        }
+    }
+
+    /// Returns the outcomes of the transactions, to which the messages of the batch belong,
+    /// skipping the messages beyond the provided `max_offset`.
+    pub(crate) fn get_transaction_outcomes(
+        &self,
+        batch: &IggyMessagesBatchMut,
+        max_offset: u64,
+    ) -> AHashMap<u64, TransactionOutcome> {
+        let mut outcomes = AHashMap::new();
+        for message in batch.iter() {
+            let offset = message.header().offset();
+            if offset > max_offset {
+                break;
+            }
+
+            let Some(transaction_id) = message
+                .user_headers()
+                .and_then(markers::read_transaction_id)
+            else {
+                continue;
+            };
+
+            outcomes
+                .entry(transaction_id)
+                .or_insert_with(|| self.resolve_transaction_outcome(transaction_id));
+        }
+
+        outcomes
+    }
+
+    /// Checks whether the message is visible using the `read_committed` isolation level,
+    /// which is the case for the non-transactional and the committed messages, but not the markers.
+    pub(crate) fn is_committed(
+        message: &IggyMessageView,
+        outcomes: &AHashMap<u64, TransactionOutcome>,
+    ) -> bool {
+        let Some(user_headers) = message.user_headers() else {
+            return true;
+        };
+
+        if markers::read_marker(user_headers).is_some() {
+            return false;
+        }
+
+        match markers::read_transaction_id(user_headers) {
+            Some(transaction_id) => {
+                outcomes.get(&transaction_id) == Some(&TransactionOutcome::Committed)
+            }
+            None => true,
+        }
+    }
+
+    /// Resolves the outcome from the markers index. The transaction, which has no marker, is either still open
+    /// or was never completed before the restart, so its messages are treated as aborted.
+    fn resolve_transaction_outcome(&self, transaction_id: u64) -> TransactionOutcome {
+        self.transactions
+            .markers
+            .get(&transaction_id)
+            .map_or(TransactionOutcome::Aborted, |marker| marker.outcome)
+    }
+}
@@ -35,6 +35,14 @@ impl PollingConsumer {
         PollingConsumer::ConsumerGroup(consumer_group_id, member_id)
     }
 
+    /// Returns the ID under which the offset is stored, either the consumer ID or the consumer group ID.
+    pub fn id(&self) -> u32 {
+        match self {
+            PollingConsumer::Consumer(consumer_id, _) => *consumer_id,
+            PollingConsumer::ConsumerGroup(consumer_group_id, _) => *consumer_group_id,
+        }
+    }
+
     pub fn resolve_consumer_id(identifier: &Identifier) -> u32 {
         match identifier.kind {
             IdKind::Numeric => identifier.get_u32_value().unwrap(),
@@ -0,0 +1,167 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use ahash::AHashMap;
+use iggy_common::{IggyError, ProducerInfo, ProducerSequence, UserId};
+
+/// Keeps track of the idempotent producers, their current epochs and the users who own them,
+/// restored from the state on startup.
+#[derive(Debug, Default)]
+pub struct ProducerManager {
+    last_producer_id: u64,
+    producers: AHashMap<u64, Producer>,
+}
+
+#[derive(Debug)]
+struct Producer {
+    epoch: u32,
+    user_id: UserId,
+}
+
+impl ProducerManager {
+    /// Registers a new producer owned by the user if the provided ID is 0, otherwise bumps the epoch
+    /// of the existing one, so that its previous instance gets fenced. Only the owner can do the latter.
+    pub fn init_producer(
+        &mut self,
+        producer_id: u64,
+        user_id: UserId,
+    ) -> Result<ProducerInfo, IggyError> {
+        if producer_id == 0 {
+            self.last_producer_id += 1;
+            let id = self.last_producer_id;
+            self.producers.insert(id, Producer { epoch: 0, user_id });
+            return Ok(ProducerInfo { id, epoch: 0 });
+        }
+
+        let producer = self.get_owned_producer(producer_id, user_id)?;
+        producer.epoch = producer.epoch.wrapping_add(1);
+        Ok(ProducerInfo {
+            id: producer_id,
+            epoch: producer.epoch,
+        })
+    }
+
+    pub fn restore_producer(&mut self, producer_id: u64, epoch: u32, user_id: UserId) {
+        self.producers
+            .insert(producer_id, Producer { epoch, user_id });
+        self.last_producer_id = self.last_producer_id.max(producer_id);
+    }
+
+    /// Ensures that the producer exists, is owned by the user and the batch has been sent by its current instance.
+    pub fn validate_producer(
+        &self,
+        producer: &ProducerSequence,
+        user_id: UserId,
+    ) -> Result<(), IggyError> {
+        let current = self
+            .producers
+            .get(&producer.producer_id)
+            .ok_or(IggyError::ProducerNotFound(producer.producer_id))?;
+        if current.user_id != user_id {
+            return Err(IggyError::Unauthorized);
+        }
+
+        if producer.producer_epoch != current.epoch {
+            return Err(IggyError::ProducerFenced(
+                producer.producer_id,
+                producer.producer_epoch,
+                current.epoch,
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn get_owned_producer(
+        &mut self,
+        producer_id: u64,
+        user_id: UserId,
+    ) -> Result<&mut Producer, IggyError> {
+        let producer = self
+            .producers
+            .get_mut(&producer_id)
+            .ok_or(IggyError::ProducerNotFound(producer_id))?;
+        if producer.user_id != user_id {
+            return Err(IggyError::Unauthorized);
+        }
+
+        Ok(producer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reinitialized_producer_should_fence_previous_epoch() {
+        let mut manager = ProducerManager::default();
+        let producer = manager.init_producer(0, 1).unwrap();
+        assert_eq!(producer.epoch, 0);
+
+        let reinitialized = manager.init_producer(producer.id, 1).unwrap();
+        assert_eq!(reinitialized.id, producer.id);
+        assert_eq!(reinitialized.epoch, 1);
+
+        let sequence = |epoch| ProducerSequence {
+            producer_id: producer.id,
+            producer_epoch: epoch,
+            sequence: 0,
+        };
+        assert!(matches!(
+            manager.validate_producer(&sequence(0), 1),
+            Err(IggyError::ProducerFenced(_, 0, 1))
+        ));
+        assert!(manager.validate_producer(&sequence(1), 1).is_ok());
+    }
+
+    #[test]
+    fn restored_producers_should_not_be_assigned_the_same_id() {
+        let mut manager = ProducerManager::default();
+        manager.restore_producer(5, 2, 1);
+
+        let producer = manager.init_producer(0, 1).unwrap();
+
+        assert_eq!(producer.id, 6);
+        assert!(matches!(
+            manager.init_producer(10, 1),
+            Err(IggyError::ProducerNotFound(10))
+        ));
+    }
+
+    #[test]
+    fn producer_of_another_user_should_not_be_reinitialized_or_used() {
+        let mut manager = ProducerManager::default();
+        let producer = manager.init_producer(0, 1).unwrap();
+        let sequence = ProducerSequence {
+            producer_id: producer.id,
+            producer_epoch: producer.epoch,
+            sequence: 0,
+        };
+
+        assert!(matches!(
+            manager.init_producer(producer.id, 2),
+            Err(IggyError::Unauthorized)
+        ));
+        assert!(matches!(
+            manager.validate_producer(&sequence, 2),
+            Err(IggyError::Unauthorized)
+        ));
+        assert!(manager.validate_producer(&sequence, 1).is_ok());
+    }
+}
@@ -0,0 +1,267 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::system::QuotasConfig;
+use dashmap::DashMap;
+use iggy_common::{UserId, UserQuotas};
+use std::time::{Duration, Instant};
+
+/// Keeps track of the produced and consumed throughput of the users and clients,
+/// and calculates the delay by which the responses exceeding their quotas should be throttled.
+#[derive(Debug)]
+pub struct QuotaManager {
+    max_throttle_delay: Duration,
+    client_limits: Limits,
+    users: DashMap<UserId, Limiters>,
+    clients: DashMap<u32, Limiters>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct Limits {
+    produced_bytes: Option<u64>,
+    produced_messages: Option<u64>,
+    consumed_bytes: Option<u64>,
+}
+
+impl Limits {
+    fn is_unlimited(&self) -> bool {
+        self.produced_bytes.is_none()
+            && self.produced_messages.is_none()
+            && self.consumed_bytes.is_none()
+    }
+}
+
+impl From<&UserQuotas> for Limits {
+    fn from(quotas: &UserQuotas) -> Self {
+        Limits {
+            produced_bytes: quotas.max_produced_bytes_per_second,
+            produced_messages: quotas.max_produced_messages_per_second,
+            consumed_bytes: quotas.max_consumed_bytes_per_second,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Limiters {
+    limits: Limits,
+    produced_bytes: Option<RateLimiter>,
+    produced_messages: Option<RateLimiter>,
+    consumed_bytes: Option<RateLimiter>,
+}
+
+impl Limiters {
+    fn new(limits: Limits, now: Instant) -> Self {
+        Limiters {
+            limits,
+            produced_bytes: limits
+                .produced_bytes
+                .map(|rate| RateLimiter::new(rate, now)),
+            produced_messages: limits
+                .produced_messages
+                .map(|rate| RateLimiter::new(rate, now)),
+            consumed_bytes: limits
+                .consumed_bytes
+                .map(|rate| RateLimiter::new(rate, now)),
+        }
+    }
+
+    fn record_produced(&mut self, messages_count: u64, size_bytes: u64, now: Instant) -> Duration {
+        let bytes_delay = self
+            .produced_bytes
+            .as_mut()
+            .map_or(Duration::ZERO, |limiter| limiter.consume(size_bytes, now));
+        let messages_delay = self
+            .produced_messages
+            .as_mut()
+            .map_or(Duration::ZERO, |limiter| {
+                limiter.consume(messages_count, now)
+            });
+        bytes_delay.max(messages_delay)
+    }
+
+    fn record_consumed(&mut self, size_bytes: u64, now: Instant) -> Duration {
+        self.consumed_bytes
+            .as_mut()
+            .map_or(Duration::ZERO, |limiter| limiter.consume(size_bytes, now))
+    }
+}
+
+/// Token bucket refilled at the configured rate, allowing the bursts of up to one second worth of the rate.
+/// Exceeding the available tokens results in a debt, which determines the throttling delay.
+#[derive(Debug)]
+struct RateLimiter {
+    rate: u64,
+    available: f64,
+    updated_at: Instant,
+}
+
+impl RateLimiter {
+    fn new(rate: u64, now: Instant) -> Self {
+        RateLimiter {
+            rate,
+            available: rate as f64,
+            updated_at: now,
+        }
+    }
+
+    fn consume(&mut self, amount: u64, now: Instant) -> Duration {
+        let rate = self.rate as f64;
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.available = (self.available + elapsed * rate).min(rate) - amount as f64;
+        self.updated_at = now;
+        if self.available >= 0.0 {
+            return Duration::ZERO;
+        }
+
+        Duration::from_secs_f64(-self.available / rate)
+    }
+}
+
+impl QuotaManager {
+    pub fn new(config: &QuotasConfig) -> Self {
+        let limit = |value: u64| Some(value).filter(|value| *value > 0);
+        QuotaManager {
+            max_throttle_delay: config.max_throttle_delay.get_duration(),
+            client_limits: Limits {
+                produced_bytes: limit(config.client_max_produced_bytes_per_second.as_bytes_u64()),
+                produced_messages: limit(config.client_max_produced_messages_per_second),
+                consumed_bytes: limit(config.client_max_consumed_bytes_per_second.as_bytes_u64()),
+            },
+            users: DashMap::new(),
+            clients: DashMap::new(),
+        }
+    }
+
+    /// Records the produced messages and returns the delay by which the response should be throttled.
+    pub fn record_produced(
+        &self,
+        user_id: UserId,
+        client_id: u32,
+        quotas: Option<&UserQuotas>,
+        messages_count: u32,
+        size_bytes: u64,
+    ) -> Duration {
+        self.throttle(user_id, client_id, quotas, |limiters, now| {
+            limiters.record_produced(messages_count as u64, size_bytes, now)
+        })
+    }
+
+    /// Records the consumed messages and returns the delay by which the response should be throttled.
+    pub fn record_consumed(
+        &self,
+        user_id: UserId,
+        client_id: u32,
+        quotas: Option<&UserQuotas>,
+        size_bytes: u64,
+    ) -> Duration {
+        self.throttle(user_id, client_id, quotas, |limiters, now| {
+            limiters.record_consumed(size_bytes, now)
+        })
+    }
+
+    pub fn delete_user(&self, user_id: UserId) {
+        self.users.remove(&user_id);
+    }
+
+    pub fn delete_client(&self, client_id: u32) {
+        self.clients.remove(&client_id);
+    }
+
+    fn throttle(
+        &self,
+        user_id: UserId,
+        client_id: u32,
+        quotas: Option<&UserQuotas>,
+        record: impl Fn(&mut Limiters, Instant) -> Duration,
+    ) -> Duration {
+        let now = Instant::now();
+        let user_limits = quotas.map(Limits::from).unwrap_or_default();
+        let user_delay = Self::record(&self.users, user_id, user_limits, now, &record);
+        // The stateless (HTTP) sessions don't belong to any client.
+        let client_delay = if client_id > 0 {
+            Self::record(&self.clients, client_id, self.client_limits, now, &record)
+        } else {
+            Duration::ZERO
+        };
+        user_delay.max(client_delay).min(self.max_throttle_delay)
+    }
+
+    fn record<K: Eq + std::hash::Hash>(
+        limiters: &DashMap<K, Limiters>,
+        key: K,
+        limits: Limits,
+        now: Instant,
+        record: &impl Fn(&mut Limiters, Instant) -> Duration,
+    ) -> Duration {
+        if limits.is_unlimited() {
+            limiters.remove(&key);
+            return Duration::ZERO;
+        }
+
+        let mut entry = limiters
+            .entry(key)
+            .or_insert_with(|| Limiters::new(limits, now));
+        if entry.limits != limits {
+            *entry = Limiters::new(limits, now);
+        }
+        record(&mut entry, now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy_common::IggyDuration;
+
+    fn quota_manager() -> QuotaManager {
+        QuotaManager::new(&QuotasConfig {
+            max_throttle_delay: IggyDuration::new_from_secs(5),
+            client_max_produced_bytes_per_second: 0.into(),
+            client_max_produced_messages_per_second: 100,
+            client_max_consumed_bytes_per_second: 0.into(),
+        })
+    }
+
+    #[test]
+    fn produced_bytes_exceeding_user_quota_should_be_throttled() {
+        let quota_manager = quota_manager();
+        let quotas = UserQuotas {
+            max_produced_bytes_per_second: Some(1000),
+            ..Default::default()
+        };
+
+        let delay = quota_manager.record_produced(1, 0, Some(&quotas), 1, 1000);
+        assert!(delay.is_zero());
+
+        let delay = quota_manager.record_produced(1, 0, Some(&quotas), 1, 1500);
+        assert!(delay > Duration::from_millis(1400));
+        assert!(delay <= Duration::from_millis(1500));
+        assert!(
+            quota_manager
+                .record_produced(2, 0, None, 1, 10_000)
+                .is_zero()
+        );
+    }
+
+    #[test]
+    fn throttling_delay_should_not_exceed_the_max_one() {
+        let quota_manager = quota_manager();
+        let delay = quota_manager.record_produced(1, 1, None, 10_000, 100);
+        assert_eq!(delay, Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,798 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use ahash::{AHashMap, AHashSet};
+use iggy_common::IggyError;
+use serde_json::{Map, Value};
+
+const MAX_DEPTH: usize = 64;
+
+/// The Avro schema parsed from its JSON form, the named types (records, enums and fixed)
+/// are kept by their full names, so that the recursive types can reference themselves.
+#[derive(Debug)]
+pub struct AvroSchema {
+    root: AvroType,
+    named: AHashMap<String, NamedType>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum AvroType {
+    Null,
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Bytes,
+    String,
+    Array(Box<AvroType>),
+    Map(Box<AvroType>),
+    Union(Vec<AvroType>),
+    Named(String),
+}
+
+#[derive(Debug)]
+enum NamedType {
+    Record {
+        name: String,
+        fields: Vec<AvroField>,
+    },
+    Enum {
+        name: String,
+        symbols: Vec<String>,
+        has_default: bool,
+    },
+    Fixed {
+        name: String,
+        size: usize,
+    },
+}
+
+#[derive(Debug)]
+struct AvroField {
+    name: String,
+    aliases: Vec<String>,
+    schema: AvroType,
+    has_default: bool,
+}
+
+impl AvroSchema {
+    pub fn parse(definition: &[u8]) -> Result<Self, IggyError> {
+        let definition: Value = serde_json::from_slice(definition).map_err(|error| {
+            IggyError::InvalidSchemaDefinition(format!("invalid JSON: {error}"))
+        })?;
+        let mut named = AHashMap::new();
+        let root = parse_type(&definition, None, &mut named).map_err(|error| {
+            IggyError::InvalidSchemaDefinition(format!("invalid Avro schema: {error}"))
+        })?;
+        Ok(AvroSchema { root, named })
+    }
+
+    /// Validates the Avro binary encoded datum (without the container file or single object encoding framing).
+    pub fn validate(&self, payload: &[u8]) -> Result<(), String> {
+        let mut decoder = Decoder {
+            data: payload,
+            position: 0,
+        };
+        self.decode(&self.root, &mut decoder, 0)?;
+        if decoder.position != payload.len() {
+            return Err(format!(
+                "{} trailing bytes after the datum",
+                payload.len() - decoder.position
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks whether the datum written with the `writer` schema can be read with this one,
+    /// following the schema resolution rules of the Avro specification.
+    pub fn can_read(&self, writer: &AvroSchema) -> Result<(), String> {
+        Resolver {
+            reader: self,
+            writer,
+            visited: AHashSet::new(),
+        }
+        .resolve(&self.root, &writer.root, "root")
+    }
+
+    fn decode(&self, schema: &AvroType, decoder: &mut Decoder, depth: usize) -> Result<(), String> {
+        if depth > MAX_DEPTH {
+            return Err(format!("datum is nested deeper than {MAX_DEPTH} levels"));
+        }
+
+        match schema {
+            AvroType::Null => {}
+            AvroType::Boolean => {
+                if decoder.read(1)?[0] > 1 {
+                    return Err("invalid boolean value".to_string());
+                }
+            }
+            AvroType::Int => {
+                decoder.read_int()?;
+            }
+            AvroType::Long => {
+                decoder.read_long()?;
+            }
+            AvroType::Float => {
+                decoder.read(4)?;
+            }
+            AvroType::Double => {
+                decoder.read(8)?;
+            }
+            AvroType::Bytes => {
+                decoder.read_bytes()?;
+            }
+            AvroType::String => {
+                std::str::from_utf8(decoder.read_bytes()?)
+                    .map_err(|_| "string is not a valid UTF-8".to_string())?;
+            }
+            AvroType::Array(items) => {
+                Self::decode_blocks(decoder, |decoder| self.decode(items, decoder, depth + 1))?;
+            }
+            AvroType::Map(values) => {
+                Self::decode_blocks(decoder, |decoder| {
+                    self.decode(&AvroType::String, decoder, depth + 1)?;
+                    self.decode(values, decoder, depth + 1)
+                })?;
+            }
+            AvroType::Union(branches) => {
+                let index = decoder.read_long()?;
+                let branch = usize::try_from(index)
+                    .ok()
+                    .and_then(|index| branches.get(index))
+                    .ok_or_else(|| format!("invalid union branch index: {index}"))?;
+                self.decode(branch, decoder, depth + 1)?;
+            }
+            AvroType::Named(name) => match &self.named[name] {
+                NamedType::Record { fields, .. } => {
+                    for field in fields {
+                        self.decode(&field.schema, decoder, depth + 1)
+                            .map_err(|error| format!("field '{}': {error}", field.name))?;
+                    }
+                }
+                NamedType::Enum { symbols, .. } => {
+                    let index = decoder.read_int()?;
+                    if usize::try_from(index).map_or(true, |index| index >= symbols.len()) {
+                        return Err(format!("invalid enum symbol index: {index}"));
+                    }
+                }
+                NamedType::Fixed { size, .. } => {
+                    decoder.read(*size)?;
+                }
+            },
+        }
+        Ok(())
+    }
+
+    fn decode_blocks(
+        decoder: &mut Decoder,
+        mut decode_item: impl FnMut(&mut Decoder) -> Result<(), String>,
+    ) -> Result<(), String> {
+        loop {
+            let count = decoder.read_long()?;
+            if count == 0 {
+                return Ok(());
+            }
+            if count < 0 {
+                // The negative count is followed by the size of the block in bytes.
+                decoder.read_long()?;
+            }
+            for _ in 0..count.unsigned_abs() {
+                let position = decoder.position;
+                decode_item(decoder)?;
+                // The items which take no bytes (e.g. nulls) can't be told apart, so there's no point to decode the rest.
+                if decoder.position == position {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+struct Decoder<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn read(&mut self, length: usize) -> Result<&'a [u8], String> {
+        let end = self
+            .position
+            .checked_add(length)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| "unexpected end of the datum".to_string())?;
+        let bytes = &self.data[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+
+    fn read_long(&mut self) -> Result<i64, String> {
+        let mut value = 0u64;
+        for shift in (0..70).step_by(7) {
+            let byte = self.read(1)?[0];
+            value |= u64::from(byte & 0x7F) << shift;
+            if byte & 0x80 == 0 {
+                return Ok((value >> 1) as i64 ^ -((value & 1) as i64));
+            }
+        }
+        Err("variable length integer is too long".to_string())
+    }
+
+    fn read_int(&mut self) -> Result<i32, String> {
+        let value = self.read_long()?;
+        i32::try_from(value).map_err(|_| format!("value {value} is out of the int range"))
+    }
+
+    fn read_bytes(&mut self) -> Result<&'a [u8], String> {
+        let length = self.read_long()?;
+        let length = usize::try_from(length).map_err(|_| format!("invalid length: {length}"))?;
+        self.read(length)
+    }
+}
+
+struct Resolver<'a> {
+    reader: &'a AvroSchema,
+    writer: &'a AvroSchema,
+    visited: AHashSet<(&'a str, &'a str)>,
+}
+
+impl<'a> Resolver<'a> {
+    fn resolve(
+        &mut self,
+        reader: &'a AvroType,
+        writer: &'a AvroType,
+        path: &str,
+    ) -> Result<(), String> {
+        if let AvroType::Union(writer_branches) = writer {
+            for writer_branch in writer_branches {
+                self.resolve(reader, writer_branch, path)?;
+            }
+            return Ok(());
+        }
+
+        if let AvroType::Union(reader_branches) = reader {
+            for reader_branch in reader_branches {
+                let visited = self.visited.clone();
+                if self.resolve(reader_branch, writer, path).is_ok() {
+                    return Ok(());
+                }
+                self.visited = visited;
+            }
+            return Err(format!(
+                "{path}: {} is not matched by any branch of the union",
+                describe(writer)
+            ));
+        }
+
+        match (reader, writer) {
+            (AvroType::Array(reader_items), AvroType::Array(writer_items)) => {
+                self.resolve(reader_items, writer_items, &format!("{path}[]"))
+            }
+            (AvroType::Map(reader_values), AvroType::Map(writer_values)) => {
+                self.resolve(reader_values, writer_values, &format!("{path}{{}}"))
+            }
+            (AvroType::Named(reader_name), AvroType::Named(writer_name)) => {
+                self.resolve_named(reader_name, writer_name, path)
+            }
+            _ if reader == writer || is_promotable(writer, reader) => Ok(()),
+            _ => Err(format!(
+                "{path}: {} can't be read as {}",
+                describe(writer),
+                describe(reader)
+            )),
+        }
+    }
+
+    fn resolve_named(
+        &mut self,
+        reader_name: &'a str,
+        writer_name: &'a str,
+        path: &str,
+    ) -> Result<(), String> {
+        // The recursive types are assumed to be compatible, when they're already being resolved.
+        if !self.visited.insert((reader_name, writer_name)) {
+            return Ok(());
+        }
+
+        match (
+            &self.reader.named[reader_name],
+            &self.writer.named[writer_name],
+        ) {
+            (
+                NamedType::Record {
+                    name: reader_name,
+                    fields: reader_fields,
+                },
+                NamedType::Record {
+                    name: writer_name,
+                    fields: writer_fields,
+                },
+            ) if reader_name == writer_name => {
+                for reader_field in reader_fields {
+                    let field_path = format!("{path}.{}", reader_field.name);
+                    let writer_field = writer_fields.iter().find(|writer_field| {
+                        writer_field.name == reader_field.name
+                            || reader_field.aliases.contains(&writer_field.name)
+                    });
+                    match writer_field {
+                        Some(writer_field) => {
+                            self.resolve(&reader_field.schema, &writer_field.schema, &field_path)?
+                        }
+                        None if reader_field.has_default => {}
+                        None => {
+                            return Err(format!(
+                                "{field_path}: field is missing in the writer schema and has no default value"
+                            ));
+                        }
+                    }
+                }
+                Ok(())
+            }
+            (
+                NamedType::Enum {
+                    name: reader_name,
+                    symbols: reader_symbols,
+                    has_default,
+                },
+                NamedType::Enum {
+                    name: writer_name,
+                    symbols: writer_symbols,
+                    ..
+                },
+            ) if reader_name == writer_name => {
+                if *has_default {
+                    return Ok(());
+                }
+                match writer_symbols
+                    .iter()
+                    .find(|symbol| !reader_symbols.contains(symbol))
+                {
+                    Some(symbol) => Err(format!(
+                        "{path}: enum symbol '{symbol}' is unknown to the reader and there's no default symbol"
+                    )),
+                    None => Ok(()),
+                }
+            }
+            (
+                NamedType::Fixed {
+                    name: reader_name,
+                    size: reader_size,
+                },
+                NamedType::Fixed {
+                    name: writer_name,
+                    size: writer_size,
+                },
+            ) if reader_name == writer_name && reader_size == writer_size => Ok(()),
+            _ => Err(format!(
+                "{path}: {writer_name} can't be read as {reader_name}"
+            )),
+        }
+    }
+}
+
+fn is_promotable(writer: &AvroType, reader: &AvroType) -> bool {
+    matches!(
+        (writer, reader),
+        (
+            AvroType::Int,
+            AvroType::Long | AvroType::Float | AvroType::Double
+        ) | (AvroType::Long, AvroType::Float | AvroType::Double)
+            | (AvroType::Float, AvroType::Double)
+            | (AvroType::String, AvroType::Bytes)
+            | (AvroType::Bytes, AvroType::String)
+    )
+}
+
+fn describe(schema: &AvroType) -> String {
+    match schema {
+        AvroType::Null => "null".to_string(),
+        AvroType::Boolean => "boolean".to_string(),
+        AvroType::Int => "int".to_string(),
+        AvroType::Long => "long".to_string(),
+        AvroType::Float => "float".to_string(),
+        AvroType::Double => "double".to_string(),
+        AvroType::Bytes => "bytes".to_string(),
+        AvroType::String => "string".to_string(),
+        AvroType::Array(_) => "array".to_string(),
+        AvroType::Map(_) => "map".to_string(),
+        AvroType::Union(_) => "union".to_string(),
+        AvroType::Named(name) => name.clone(),
+    }
+}
+
+fn parse_type(
+    value: &Value,
+    namespace: Option<&str>,
+    named: &mut AHashMap<String, NamedType>,
+) -> Result<AvroType, String> {
+    match value {
+        Value::String(name) => parse_type_name(name, namespace, named),
+        Value::Array(branches) => {
+            if branches.is_empty() {
+                return Err("union has no branches".to_string());
+            }
+            let branches = branches
+                .iter()
+                .map(|branch| parse_type(branch, namespace, named))
+                .collect::<Result<Vec<_>, _>>()?;
+            if branches
+                .iter()
+                .any(|branch| matches!(branch, AvroType::Union(_)))
+            {
+                return Err("union can't contain another union".to_string());
+            }
+            Ok(AvroType::Union(branches))
+        }
+        Value::Object(object) => match object.get("type") {
+            Some(Value::String(kind)) => match kind.as_str() {
+                "record" | "error" => parse_record(object, namespace, named),
+                "enum" => parse_enum(object, namespace, named),
+                "fixed" => parse_fixed(object, namespace, named),
+                "array" => {
+                    let items = object
+                        .get("items")
+                        .ok_or("array has no 'items' attribute")?;
+                    Ok(AvroType::Array(Box::new(parse_type(
+                        items, namespace, named,
+                    )?)))
+                }
+                "map" => {
+                    let values = object
+                        .get("values")
+                        .ok_or("map has no 'values' attribute")?;
+                    Ok(AvroType::Map(Box::new(parse_type(
+                        values, namespace, named,
+                    )?)))
+                }
+                // The primitive types with the logical type are validated as their underlying type.
+                name => parse_type_name(name, namespace, named),
+            },
+            Some(kind) => parse_type(kind, namespace, named),
+            None => Err("type has no 'type' attribute".to_string()),
+        },
+        _ => Err(format!("invalid type: {value}")),
+    }
+}
+
+fn parse_type_name(
+    name: &str,
+    namespace: Option<&str>,
+    named: &AHashMap<String, NamedType>,
+) -> Result<AvroType, String> {
+    let primitive = match name {
+        "null" => AvroType::Null,
+        "boolean" => AvroType::Boolean,
+        "int" => AvroType::Int,
+        "long" => AvroType::Long,
+        "float" => AvroType::Float,
+        "double" => AvroType::Double,
+        "bytes" => AvroType::Bytes,
+        "string" => AvroType::String,
+        _ => {
+            let full_name = full_name(name, namespace);
+            return if named.contains_key(&full_name) {
+                Ok(AvroType::Named(full_name))
+            } else if named.contains_key(name) {
+                Ok(AvroType::Named(name.to_string()))
+            } else {
+                Err(format!("unknown type: {name}"))
+            };
+        }
+    };
+    Ok(primitive)
+}
+
+fn full_name(name: &str, namespace: Option<&str>) -> String {
+    match namespace {
+        Some(namespace) if !name.contains('.') && !namespace.is_empty() => {
+            format!("{namespace}.{name}")
+        }
+        _ => name.to_string(),
+    }
+}
+
+/// Registers the named type and returns its full name and namespace for the nested types.
+fn register_name(
+    object: &Map<String, Value>,
+    namespace: Option<&str>,
+    named: &mut AHashMap<String, NamedType>,
+    placeholder: NamedType,
+) -> Result<(String, Option<String>), String> {
+    let name = object
+        .get("name")
+        .and_then(Value::as_str)
+        .ok_or("named type has no 'name' attribute")?;
+    let namespace = match (name.rsplit_once('.'), object.get("namespace")) {
+        (Some((namespace, _)), _) => Some(namespace.to_string()),
+        (None, Some(Value::String(namespace))) => Some(namespace.clone()),
+        (None, _) => namespace.map(str::to_string),
+    };
+    let simple_name = name.rsplit('.').next().unwrap_or(name);
+    if !is_valid_name(simple_name) {
+        return Err(format!("invalid name: {name}"));
+    }
+    let full_name = full_name(name, namespace.as_deref());
+    if named.contains_key(&full_name) {
+        return Err(format!("type {full_name} is defined more than once"));
+    }
+    named.insert(full_name.clone(), placeholder);
+    Ok((full_name, namespace))
+}
+
+fn parse_record(
+    object: &Map<String, Value>,
+    namespace: Option<&str>,
+    named: &mut AHashMap<String, NamedType>,
+) -> Result<AvroType, String> {
+    let placeholder = NamedType::Record {
+        name: String::new(),
+        fields: Vec::new(),
+    };
+    let (full_name, namespace) = register_name(object, namespace, named, placeholder)?;
+    let mut fields = Vec::new();
+    for field in object
+        .get("fields")
+        .and_then(Value::as_array)
+        .ok_or_else(|| format!("record {full_name} has no 'fields' attribute"))?
+    {
+        let name = field
+            .get("name")
+            .and_then(Value::as_str)
+            .filter(|name| is_valid_name(name))
+            .ok_or_else(|| format!("record {full_name} has a field with invalid name"))?;
+        if fields.iter().any(|field: &AvroField| field.name == name) {
+            return Err(format!("record {full_name} has duplicated field {name}"));
+        }
+        let schema = field
+            .get("type")
+            .ok_or_else(|| format!("field {full_name}.{name} has no 'type' attribute"))?;
+        let aliases = field
+            .get("aliases")
+            .and_then(Value::as_array)
+            .map(|aliases| {
+                aliases
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+        fields.push(AvroField {
+            name: name.to_string(),
+            aliases,
+            schema: parse_type(schema, namespace.as_deref(), named)?,
+            has_default: field.get("default").is_some(),
+        });
+    }
+
+    let name = simple_name(&full_name);
+    named.insert(full_name.clone(), NamedType::Record { name, fields });
+    Ok(AvroType::Named(full_name))
+}
+
+fn parse_enum(
+    object: &Map<String, Value>,
+    namespace: Option<&str>,
+    named: &mut AHashMap<String, NamedType>,
+) -> Result<AvroType, String> {
+    let symbols: Vec<String> = object
+        .get("symbols")
+        .and_then(Value::as_array)
+        .ok_or("enum has no 'symbols' attribute")?
+        .iter()
+        .map(|symbol| {
+            symbol
+                .as_str()
+                .filter(|symbol| is_valid_name(symbol))
+                .map(str::to_string)
+                .ok_or_else(|| format!("invalid enum symbol: {symbol}"))
+        })
+        .collect::<Result<_, _>>()?;
+    let has_default = object.get("default").is_some();
+    let placeholder = NamedType::Fixed {
+        name: String::new(),
+        size: 0,
+    };
+    let (full_name, _) = register_name(object, namespace, named, placeholder)?;
+    let name = simple_name(&full_name);
+    named.insert(
+        full_name.clone(),
+        NamedType::Enum {
+            name,
+            symbols,
+            has_default,
+        },
+    );
+    Ok(AvroType::Named(full_name))
+}
+
+fn parse_fixed(
+    object: &Map<String, Value>,
+    namespace: Option<&str>,
+    named: &mut AHashMap<String, NamedType>,
+) -> Result<AvroType, String> {
+    let size = object
+        .get("size")
+        .and_then(Value::as_u64)
+        .ok_or("fixed has no valid 'size' attribute")? as usize;
+    let placeholder = NamedType::Fixed {
+        name: String::new(),
+        size,
+    };
+    let (full_name, _) = register_name(object, namespace, named, placeholder)?;
+    let name = simple_name(&full_name);
+    named.insert(full_name.clone(), NamedType::Fixed { name, size });
+    Ok(AvroType::Named(full_name))
+}
+
+fn simple_name(full_name: &str) -> String {
+    full_name
+        .rsplit('.')
+        .next()
+        .unwrap_or(full_name)
+        .to_string()
+}
+
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|char| char.is_ascii_alphanumeric() || char == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORDER_V1: &str = r#"{
+        "type": "record",
+        "name": "Order",
+        "namespace": "shop",
+        "fields": [
+            { "name": "id", "type": "int" },
+            { "name": "status", "type": { "type": "enum", "name": "Status", "symbols": ["NEW", "PAID"] } },
+            { "name": "tags", "type": { "type": "array", "items": "string" } },
+            { "name": "note", "type": ["null", "string"], "default": null }
+        ]
+    }"#;
+
+    fn schema(definition: &str) -> AvroSchema {
+        AvroSchema::parse(definition.as_bytes()).unwrap()
+    }
+
+    fn encode_long(value: i64, buffer: &mut Vec<u8>) {
+        let mut value = ((value << 1) ^ (value >> 63)) as u64;
+        while value >= 0x80 {
+            buffer.push((value as u8 & 0x7F) | 0x80);
+            value >>= 7;
+        }
+        buffer.push(value as u8);
+    }
+
+    fn encode_string(value: &str, buffer: &mut Vec<u8>) {
+        encode_long(value.len() as i64, buffer);
+        buffer.extend_from_slice(value.as_bytes());
+    }
+
+    fn order(note: Option<&str>) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        encode_long(150, &mut buffer);
+        encode_long(1, &mut buffer);
+        encode_long(2, &mut buffer);
+        encode_string("fast", &mut buffer);
+        encode_string("gift", &mut buffer);
+        encode_long(0, &mut buffer);
+        match note {
+            Some(note) => {
+                encode_long(1, &mut buffer);
+                encode_string(note, &mut buffer);
+            }
+            None => encode_long(0, &mut buffer),
+        }
+        buffer
+    }
+
+    #[test]
+    fn datum_should_be_validated() {
+        let schema = schema(ORDER_V1);
+        assert!(schema.validate(&order(None)).is_ok());
+        assert!(schema.validate(&order(Some("leave at the door"))).is_ok());
+
+        let valid = order(None);
+        assert!(schema.validate(&valid[..valid.len() - 1]).is_err());
+        let mut trailing = valid.clone();
+        trailing.push(0);
+        assert!(schema.validate(&trailing).is_err());
+        let mut invalid_symbol = valid;
+        invalid_symbol[2] = 4;
+        assert!(schema.validate(&invalid_symbol).is_err());
+    }
+
+    #[test]
+    fn recursive_schema_should_be_parsed_and_validated() {
+        let schema = schema(
+            r#"{
+            "type": "record",
+            "name": "Node",
+            "fields": [
+                { "name": "value", "type": "long" },
+                { "name": "next", "type": ["null", "Node"] }
+            ]
+        }"#,
+        );
+        let mut buffer = Vec::new();
+        encode_long(1, &mut buffer);
+        encode_long(1, &mut buffer);
+        encode_long(2, &mut buffer);
+        encode_long(0, &mut buffer);
+        assert!(schema.validate(&buffer).is_ok());
+        assert!(schema.can_read(&schema).is_ok());
+    }
+
+    #[test]
+    fn invalid_definition_should_be_rejected() {
+        assert!(AvroSchema::parse(br#"{"type": "record", "name": "A"}"#).is_err());
+        assert!(AvroSchema::parse(br#""Unknown""#).is_err());
+        assert!(AvroSchema::parse(br#"["int", ["string"]]"#).is_err());
+        assert!(AvroSchema::parse(b"[]").is_err());
+    }
+
+    #[test]
+    fn adding_field_with_default_should_be_compatible_both_ways() {
+        let v1 = schema(ORDER_V1);
+        let v2 = schema(
+            r#"{
+            "type": "record",
+            "name": "Order",
+            "namespace": "shop",
+            "fields": [
+                { "name": "id", "type": "long" },
+                { "name": "status", "type": { "type": "enum", "name": "Status", "symbols": ["NEW", "PAID", "SENT"] } },
+                { "name": "tags", "type": { "type": "array", "items": "string" } },
+                { "name": "note", "type": ["null", "string"], "default": null },
+                { "name": "priority", "type": "int", "default": 0 }
+            ]
+        }"#,
+        );
+        assert!(v2.can_read(&v1).is_ok());
+        // The old reader can't read the promoted long and the new enum symbol.
+        assert!(v1.can_read(&v2).is_err());
+    }
+
+    #[test]
+    fn adding_field_without_default_should_only_be_forward_compatible() {
+        let v1 = schema(ORDER_V1);
+        let v2 = schema(
+            r#"{
+            "type": "record",
+            "name": "Order",
+            "namespace": "shop",
+            "fields": [
+                { "name": "id", "type": "int" },
+                { "name": "status", "type": { "type": "enum", "name": "Status", "symbols": ["NEW", "PAID"] } },
+                { "name": "tags", "type": { "type": "array", "items": "string" } },
+                { "name": "note", "type": ["null", "string"], "default": null },
+                { "name": "customer", "type": "string" }
+            ]
+        }"#,
+        );
+        assert!(v2.can_read(&v1).is_err());
+        assert!(v1.can_read(&v2).is_ok());
+    }
+}
@@ -0,0 +1,333 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy_common::IggyError;
+use serde_json::{Map, Value};
+
+/// The JSON Schema definition with its compiled validator.
+#[derive(Debug)]
+pub struct JsonSchema {
+    definition: Value,
+    validator: jsonschema::Validator,
+}
+
+impl JsonSchema {
+    pub fn parse(definition: &[u8]) -> Result<Self, IggyError> {
+        let definition: Value = serde_json::from_slice(definition).map_err(|error| {
+            IggyError::InvalidSchemaDefinition(format!("invalid JSON: {error}"))
+        })?;
+        let validator = jsonschema::validator_for(&definition).map_err(|error| {
+            IggyError::InvalidSchemaDefinition(format!("invalid JSON Schema: {error}"))
+        })?;
+        Ok(JsonSchema {
+            definition,
+            validator,
+        })
+    }
+
+    pub fn validate(&self, payload: &[u8]) -> Result<(), String> {
+        let instance: Value = serde_json::from_slice(payload)
+            .map_err(|error| format!("payload is not a valid JSON: {error}"))?;
+        self.validator
+            .validate(&instance)
+            .map_err(|error| format!("{error} at '{}'", error.instance_path))
+    }
+
+    /// Checks whether every document valid against the `writer` schema is also valid against this one.
+    /// The check is structural, it compares the types, required and additional properties, items, enums and ranges,
+    /// while the constraints it doesn't understand (e.g. `pattern`, `$ref`) are considered compatible.
+    pub fn can_read(&self, writer: &JsonSchema) -> Result<(), String> {
+        can_read(&self.definition, &writer.definition, "#")
+    }
+}
+
+fn can_read(reader: &Value, writer: &Value, path: &str) -> Result<(), String> {
+    let reader = match reader {
+        Value::Bool(true) => return Ok(()),
+        Value::Object(reader) if reader.is_empty() => return Ok(()),
+        Value::Object(reader) => reader,
+        _ => return Err(format!("{path}: schema rejects all the documents")),
+    };
+    let unrestricted = Map::new();
+    let writer = match writer {
+        Value::Bool(false) => return Ok(()),
+        Value::Object(writer) => writer,
+        _ => &unrestricted,
+    };
+
+    check_types(reader, writer, path)?;
+    check_enum(reader, writer, path)?;
+    check_ranges(reader, writer, path)?;
+    check_properties(reader, writer, path)?;
+    if let Some(reader_items) = reader.get("items") {
+        let writer_items = writer.get("items").unwrap_or(&Value::Bool(true));
+        can_read(reader_items, writer_items, &format!("{path}/items"))?;
+    }
+    Ok(())
+}
+
+fn types(schema: &Map<String, Value>) -> Option<Vec<&str>> {
+    match schema.get("type")? {
+        Value::String(kind) => Some(vec![kind.as_str()]),
+        Value::Array(kinds) => Some(kinds.iter().filter_map(Value::as_str).collect()),
+        _ => None,
+    }
+}
+
+fn check_types(
+    reader: &Map<String, Value>,
+    writer: &Map<String, Value>,
+    path: &str,
+) -> Result<(), String> {
+    let Some(reader_types) = types(reader) else {
+        return Ok(());
+    };
+    let Some(writer_types) = types(writer) else {
+        return Err(format!(
+            "{path}: type is restricted to {reader_types:?}, while it was not restricted before"
+        ));
+    };
+    for writer_type in writer_types {
+        let accepted = reader_types.contains(&writer_type)
+            || (writer_type == "integer" && reader_types.contains(&"number"));
+        if !accepted {
+            return Err(format!(
+                "{path}: type '{writer_type}' is no longer accepted"
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn check_enum(
+    reader: &Map<String, Value>,
+    writer: &Map<String, Value>,
+    path: &str,
+) -> Result<(), String> {
+    let Some(Value::Array(reader_values)) = reader.get("enum") else {
+        return Ok(());
+    };
+    let Some(Value::Array(writer_values)) = writer.get("enum") else {
+        return Err(format!(
+            "{path}: values are restricted to enum, while they were not restricted before"
+        ));
+    };
+    if let Some(value) = writer_values
+        .iter()
+        .find(|value| !reader_values.contains(value))
+    {
+        return Err(format!("{path}: enum value {value} is no longer accepted"));
+    }
+    Ok(())
+}
+
+fn check_ranges(
+    reader: &Map<String, Value>,
+    writer: &Map<String, Value>,
+    path: &str,
+) -> Result<(), String> {
+    const MAXIMUMS: [&str; 4] = ["maximum", "maxLength", "maxItems", "maxProperties"];
+    const MINIMUMS: [&str; 4] = ["minimum", "minLength", "minItems", "minProperties"];
+    for (keywords, is_maximum) in [(MAXIMUMS, true), (MINIMUMS, false)] {
+        for keyword in keywords {
+            let Some(reader_limit) = reader.get(keyword).and_then(Value::as_f64) else {
+                continue;
+            };
+            let narrowed = match writer.get(keyword).and_then(Value::as_f64) {
+                Some(writer_limit) if is_maximum => reader_limit < writer_limit,
+                Some(writer_limit) => reader_limit > writer_limit,
+                None => true,
+            };
+            if narrowed {
+                return Err(format!("{path}: '{keyword}' is narrowed to {reader_limit}"));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_properties(
+    reader: &Map<String, Value>,
+    writer: &Map<String, Value>,
+    path: &str,
+) -> Result<(), String> {
+    let writer_required = required(writer);
+    if let Some(property) = required(reader)
+        .into_iter()
+        .find(|property| !writer_required.contains(property))
+    {
+        return Err(format!("{path}: property '{property}' is required"));
+    }
+
+    let empty = Map::new();
+    let reader_properties = properties(reader).unwrap_or(&empty);
+    let writer_properties = properties(writer).unwrap_or(&empty);
+    let reader_additional = reader.get("additionalProperties");
+    let writer_additional = writer.get("additionalProperties");
+    for (name, writer_property) in writer_properties {
+        let property_path = format!("{path}/properties/{name}");
+        match (reader_properties.get(name), reader_additional) {
+            (Some(reader_property), _) => {
+                can_read(reader_property, writer_property, &property_path)?
+            }
+            (None, Some(reader_additional)) => {
+                can_read(reader_additional, writer_property, &property_path)?
+            }
+            (None, None) => {}
+        }
+    }
+
+    // The properties, which are not declared by the writer, can only be produced as its additional properties.
+    if let Some(writer_additional @ Value::Object(_)) = writer_additional {
+        for (name, reader_property) in reader_properties {
+            if !writer_properties.contains_key(name) {
+                can_read(
+                    reader_property,
+                    writer_additional,
+                    &format!("{path}/properties/{name}"),
+                )?;
+            }
+        }
+    }
+
+    if let Some(reader_additional) = reader_additional {
+        let writer_additional = writer_additional.unwrap_or(&Value::Bool(true));
+        can_read(
+            reader_additional,
+            writer_additional,
+            &format!("{path}/additionalProperties"),
+        )?;
+    }
+    Ok(())
+}
+
+fn properties(schema: &Map<String, Value>) -> Option<&Map<String, Value>> {
+    schema.get("properties").and_then(Value::as_object)
+}
+
+fn required(schema: &Map<String, Value>) -> Vec<&str> {
+    schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|required| required.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORDER_V1: &str = r#"{
+        "type": "object",
+        "properties": {
+            "id": { "type": "integer" },
+            "status": { "type": "string", "enum": ["new", "paid"] }
+        },
+        "required": ["id"]
+    }"#;
+
+    fn schema(definition: &str) -> JsonSchema {
+        JsonSchema::parse(definition.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn payload_should_be_validated() {
+        let schema = schema(ORDER_V1);
+        assert!(schema.validate(br#"{"id": 1, "status": "new"}"#).is_ok());
+        assert!(schema.validate(br#"{"status": "new"}"#).is_err());
+        assert!(schema.validate(br#"{"id": 1, "status": "sent"}"#).is_err());
+        assert!(schema.validate(b"not json").is_err());
+    }
+
+    #[test]
+    fn invalid_definition_should_be_rejected() {
+        assert!(JsonSchema::parse(b"{").is_err());
+        assert!(JsonSchema::parse(br#"{"type": 5}"#).is_err());
+    }
+
+    #[test]
+    fn adding_optional_property_should_be_compatible_both_ways() {
+        let v1 = schema(ORDER_V1);
+        let v2 = schema(
+            r#"{
+            "type": "object",
+            "properties": {
+                "id": { "type": "integer" },
+                "status": { "type": "string", "enum": ["new", "paid"] },
+                "note": { "type": "string" }
+            },
+            "required": ["id"]
+        }"#,
+        );
+        assert!(v2.can_read(&v1).is_ok());
+        assert!(v1.can_read(&v2).is_ok());
+    }
+
+    #[test]
+    fn adding_required_property_should_not_be_backward_compatible() {
+        let v1 = schema(ORDER_V1);
+        let v2 = schema(
+            r#"{
+            "type": "object",
+            "properties": {
+                "id": { "type": "integer" },
+                "amount": { "type": "number" }
+            },
+            "required": ["id", "amount"]
+        }"#,
+        );
+        assert!(v2.can_read(&v1).is_err());
+        assert!(v1.can_read(&v2).is_ok());
+    }
+
+    #[test]
+    fn narrowing_type_or_enum_should_not_be_backward_compatible() {
+        let v1 = schema(ORDER_V1);
+        let v2 = schema(
+            r#"{
+            "type": "object",
+            "properties": {
+                "id": { "type": "integer" },
+                "status": { "type": "string", "enum": ["new"] }
+            },
+            "required": ["id"]
+        }"#,
+        );
+        assert!(v2.can_read(&v1).is_err());
+        assert!(v1.can_read(&v2).is_ok());
+
+        let integer = schema(r#"{"type": "integer"}"#);
+        let number = schema(r#"{"type": "number"}"#);
+        assert!(number.can_read(&integer).is_ok());
+        assert!(integer.can_read(&number).is_err());
+    }
+
+    #[test]
+    fn closing_content_model_should_not_be_backward_compatible() {
+        let open = schema(ORDER_V1);
+        let closed = schema(
+            r#"{
+            "type": "object",
+            "properties": { "id": { "type": "integer" } },
+            "required": ["id"],
+            "additionalProperties": false
+        }"#,
+        );
+        assert!(closed.can_read(&open).is_err());
+    }
+}
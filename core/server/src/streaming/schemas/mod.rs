@@ -0,0 +1,156 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+pub mod avro;
+pub mod json;
+pub mod protobuf;
+
+use crate::streaming::schemas::avro::AvroSchema;
+use crate::streaming::schemas::json::JsonSchema;
+use crate::streaming::schemas::protobuf::ProtobufSchema;
+use iggy_common::{IggyError, Schema, SchemaCompatibility, SchemaType};
+
+/// The schema registered for the topic along with its compiled validator.
+#[derive(Debug)]
+pub struct TopicSchema {
+    pub schema: Schema,
+    pub validator: SchemaValidator,
+}
+
+impl TopicSchema {
+    pub fn new(schema: Schema) -> Result<Self, IggyError> {
+        let validator = SchemaValidator::compile(schema.schema_type, &schema.definition)?;
+        Ok(TopicSchema { schema, validator })
+    }
+}
+
+#[derive(Debug)]
+pub enum SchemaValidator {
+    Json(JsonSchema),
+    Avro(AvroSchema),
+    Protobuf(ProtobufSchema),
+}
+
+impl SchemaValidator {
+    pub fn compile(schema_type: SchemaType, definition: &[u8]) -> Result<Self, IggyError> {
+        Ok(match schema_type {
+            SchemaType::Json => SchemaValidator::Json(JsonSchema::parse(definition)?),
+            SchemaType::Avro => SchemaValidator::Avro(AvroSchema::parse(definition)?),
+            SchemaType::Protobuf => SchemaValidator::Protobuf(ProtobufSchema::parse(definition)?),
+        })
+    }
+
+    pub fn schema_type(&self) -> SchemaType {
+        match self {
+            SchemaValidator::Json(_) => SchemaType::Json,
+            SchemaValidator::Avro(_) => SchemaType::Avro,
+            SchemaValidator::Protobuf(_) => SchemaType::Protobuf,
+        }
+    }
+
+    pub fn validate(&self, payload: &[u8]) -> Result<(), String> {
+        match self {
+            SchemaValidator::Json(schema) => schema.validate(payload),
+            SchemaValidator::Avro(schema) => schema.validate(payload),
+            SchemaValidator::Protobuf(schema) => schema.validate(payload),
+        }
+    }
+
+    /// Checks the compatibility of this (new) schema with the latest one registered for the topic.
+    pub fn check_compatibility(
+        &self,
+        latest: &SchemaValidator,
+        compatibility: SchemaCompatibility,
+    ) -> Result<(), IggyError> {
+        if compatibility == SchemaCompatibility::None {
+            return Ok(());
+        }
+
+        if self.schema_type() != latest.schema_type() {
+            return Err(IggyError::IncompatibleSchema(format!(
+                "schema type changed from {} to {}",
+                latest.schema_type(),
+                self.schema_type()
+            )));
+        }
+
+        if compatibility.is_backward() {
+            self.can_read(latest).map_err(|reason| {
+                IggyError::IncompatibleSchema(format!("not backward compatible, {reason}"))
+            })?;
+        }
+        if compatibility.is_forward() {
+            latest.can_read(self).map_err(|reason| {
+                IggyError::IncompatibleSchema(format!("not forward compatible, {reason}"))
+            })?;
+        }
+        Ok(())
+    }
+
+    fn can_read(&self, writer: &SchemaValidator) -> Result<(), String> {
+        match (self, writer) {
+            (SchemaValidator::Json(reader), SchemaValidator::Json(writer)) => {
+                reader.can_read(writer)
+            }
+            (SchemaValidator::Avro(reader), SchemaValidator::Avro(writer)) => {
+                reader.can_read(writer)
+            }
+            (SchemaValidator::Protobuf(reader), SchemaValidator::Protobuf(writer)) => {
+                reader.can_read(writer)
+            }
+            _ => Err("schema types differ".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schema_type_change_should_only_be_allowed_without_compatibility() {
+        let json = SchemaValidator::compile(SchemaType::Json, br#"{"type": "string"}"#).unwrap();
+        let avro = SchemaValidator::compile(SchemaType::Avro, br#""string""#).unwrap();
+        assert!(
+            avro.check_compatibility(&json, SchemaCompatibility::None)
+                .is_ok()
+        );
+        assert!(
+            avro.check_compatibility(&json, SchemaCompatibility::Backward)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn full_compatibility_should_require_both_directions() {
+        let v1 = SchemaValidator::compile(SchemaType::Avro, br#""int""#).unwrap();
+        let v2 = SchemaValidator::compile(SchemaType::Avro, br#""long""#).unwrap();
+        assert!(
+            v2.check_compatibility(&v1, SchemaCompatibility::Backward)
+                .is_ok()
+        );
+        assert!(
+            v2.check_compatibility(&v1, SchemaCompatibility::Forward)
+                .is_err()
+        );
+        assert!(
+            v2.check_compatibility(&v1, SchemaCompatibility::Full)
+                .is_err()
+        );
+    }
+}
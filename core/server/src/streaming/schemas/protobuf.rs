@@ -0,0 +1,578 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use ahash::{AHashMap, AHashSet};
+use iggy_common::IggyError;
+use prost::Message;
+use prost_types::field_descriptor_proto::{Label, Type};
+use prost_types::{DescriptorProto, FileDescriptorSet};
+
+const MAX_DEPTH: usize = 64;
+const MAX_FIELD_NUMBER: u64 = (1 << 29) - 1;
+
+const WIRE_TYPE_VARINT: u8 = 0;
+const WIRE_TYPE_I64: u8 = 1;
+const WIRE_TYPE_LEN: u8 = 2;
+const WIRE_TYPE_START_GROUP: u8 = 3;
+const WIRE_TYPE_END_GROUP: u8 = 4;
+const WIRE_TYPE_I32: u8 = 5;
+
+/// The protobuf schema decoded from the serialized `FileDescriptorSet` (e.g. `protoc --include_imports --descriptor_set_out`).
+/// The validated message is the first message declared in the last file of the set,
+/// while the other files only provide the imported message types.
+#[derive(Debug)]
+pub struct ProtobufSchema {
+    message: String,
+    messages: AHashMap<String, MessageDescriptor>,
+}
+
+#[derive(Debug, Default)]
+struct MessageDescriptor {
+    fields: AHashMap<u32, FieldDescriptor>,
+}
+
+#[derive(Debug)]
+struct FieldDescriptor {
+    name: String,
+    kind: Type,
+    type_name: String,
+    repeated: bool,
+    required: bool,
+}
+
+impl FieldDescriptor {
+    fn wire_type(&self) -> u8 {
+        match self.kind {
+            Type::Double | Type::Fixed64 | Type::Sfixed64 => WIRE_TYPE_I64,
+            Type::Float | Type::Fixed32 | Type::Sfixed32 => WIRE_TYPE_I32,
+            Type::String | Type::Bytes | Type::Message => WIRE_TYPE_LEN,
+            Type::Group => WIRE_TYPE_START_GROUP,
+            _ => WIRE_TYPE_VARINT,
+        }
+    }
+}
+
+impl ProtobufSchema {
+    pub fn parse(definition: &[u8]) -> Result<Self, IggyError> {
+        let invalid = |reason: String| {
+            IggyError::InvalidSchemaDefinition(format!("invalid protobuf descriptor set: {reason}"))
+        };
+        let descriptor_set =
+            FileDescriptorSet::decode(definition).map_err(|error| invalid(error.to_string()))?;
+        let mut messages = AHashMap::new();
+        for file in &descriptor_set.file {
+            for message in &file.message_type {
+                register_message(message, file.package(), &mut messages);
+            }
+        }
+
+        let message = descriptor_set
+            .file
+            .last()
+            .and_then(|file| {
+                file.message_type
+                    .first()
+                    .map(|message| qualify(file.package(), message.name()))
+            })
+            .ok_or_else(|| invalid("the last file declares no message".to_string()))?;
+        for (name, descriptor) in &messages {
+            for field in descriptor.fields.values() {
+                if matches!(field.kind, Type::Message | Type::Group)
+                    && !messages.contains_key(&field.type_name)
+                {
+                    return Err(invalid(format!(
+                        "type {} of field {name}.{} is not declared",
+                        field.type_name, field.name
+                    )));
+                }
+            }
+        }
+        Ok(ProtobufSchema { message, messages })
+    }
+
+    /// Validates the protobuf encoded message, the unknown fields are accepted, as they are by the protobuf parsers.
+    pub fn validate(&self, payload: &[u8]) -> Result<(), String> {
+        let mut reader = WireReader {
+            data: payload,
+            position: 0,
+        };
+        self.validate_message(&self.message, &mut reader, None, 0)
+    }
+
+    /// Checks whether the message written with the `writer` schema can be read with this one,
+    /// i.e. the fields with the same numbers are wire compatible and no required field is missing.
+    pub fn can_read(&self, writer: &ProtobufSchema) -> Result<(), String> {
+        let mut visited = AHashSet::new();
+        self.can_read_message(writer, &self.message, &writer.message, &mut visited)
+    }
+
+    fn validate_message(
+        &self,
+        name: &str,
+        reader: &mut WireReader,
+        group_number: Option<u64>,
+        depth: usize,
+    ) -> Result<(), String> {
+        if depth > MAX_DEPTH {
+            return Err(format!("message is nested deeper than {MAX_DEPTH} levels"));
+        }
+
+        let descriptor = &self.messages[name];
+        let mut present = AHashSet::new();
+        loop {
+            if reader.is_empty() {
+                if group_number.is_some() {
+                    return Err(format!("group {name} is not terminated"));
+                }
+                break;
+            }
+
+            let (number, wire_type) = reader.read_key()?;
+            if wire_type == WIRE_TYPE_END_GROUP {
+                if group_number == Some(number) {
+                    break;
+                }
+                return Err(format!("unexpected end of group {number}"));
+            }
+
+            let Some(field) = u32::try_from(number)
+                .ok()
+                .and_then(|number| descriptor.fields.get(&number))
+            else {
+                reader.skip(number, wire_type, depth)?;
+                continue;
+            };
+
+            let expected_wire_type = field.wire_type();
+            if wire_type == expected_wire_type {
+                self.validate_value(field, reader, number, depth)
+                    .map_err(|error| format!("field '{}': {error}", field.name))?;
+            } else if wire_type == WIRE_TYPE_LEN
+                && field.repeated
+                && matches!(
+                    expected_wire_type,
+                    WIRE_TYPE_VARINT | WIRE_TYPE_I64 | WIRE_TYPE_I32
+                )
+            {
+                let mut packed = WireReader {
+                    data: reader.read_length_delimited()?,
+                    position: 0,
+                };
+                while !packed.is_empty() {
+                    packed
+                        .skip(number, expected_wire_type, depth)
+                        .map_err(|error| format!("field '{}': {error}", field.name))?;
+                }
+            } else {
+                return Err(format!(
+                    "field '{}' (number {number}) has wire type {wire_type}, expected {expected_wire_type}",
+                    field.name
+                ));
+            }
+            present.insert(number as u32);
+        }
+
+        if let Some(field) = descriptor
+            .fields
+            .iter()
+            .find(|(number, field)| field.required && !present.contains(*number))
+            .map(|(_, field)| field)
+        {
+            return Err(format!("required field '{}' is missing", field.name));
+        }
+        Ok(())
+    }
+
+    fn validate_value(
+        &self,
+        field: &FieldDescriptor,
+        reader: &mut WireReader,
+        number: u64,
+        depth: usize,
+    ) -> Result<(), String> {
+        match field.kind {
+            Type::String => {
+                std::str::from_utf8(reader.read_length_delimited()?)
+                    .map_err(|_| "string is not a valid UTF-8".to_string())?;
+            }
+            Type::Message => {
+                let mut nested = WireReader {
+                    data: reader.read_length_delimited()?,
+                    position: 0,
+                };
+                self.validate_message(&field.type_name, &mut nested, None, depth + 1)?;
+            }
+            Type::Group => {
+                self.validate_message(&field.type_name, reader, Some(number), depth + 1)?;
+            }
+            _ => reader.skip(number, field.wire_type(), depth)?,
+        }
+        Ok(())
+    }
+
+    fn can_read_message<'a>(
+        &'a self,
+        writer: &'a ProtobufSchema,
+        reader_name: &'a str,
+        writer_name: &'a str,
+        visited: &mut AHashSet<(&'a str, &'a str)>,
+    ) -> Result<(), String> {
+        // The recursive messages are assumed to be compatible, when they're already being checked.
+        if !visited.insert((reader_name, writer_name)) {
+            return Ok(());
+        }
+
+        let reader_message = &self.messages[reader_name];
+        let writer_message = &writer.messages[writer_name];
+        for (number, reader_field) in &reader_message.fields {
+            let Some(writer_field) = writer_message.fields.get(number) else {
+                if reader_field.required {
+                    return Err(format!(
+                        "{reader_name}.{}: required field is missing in the writer schema",
+                        reader_field.name
+                    ));
+                }
+                continue;
+            };
+
+            let path = format!("{reader_name}.{}", reader_field.name);
+            match (writer_field.kind, reader_field.kind) {
+                (Type::Message, Type::Message) | (Type::Group, Type::Group) => {
+                    self.can_read_message(
+                        writer,
+                        &reader_field.type_name,
+                        &writer_field.type_name,
+                        visited,
+                    )
+                    .map_err(|error| format!("{path}: {error}"))?;
+                }
+                (writer_kind, reader_kind) if are_compatible(writer_kind, reader_kind) => {}
+                (writer_kind, reader_kind) => {
+                    return Err(format!(
+                        "{path}: field number {number} of type {} can't be read as {}",
+                        writer_kind.as_str_name(),
+                        reader_kind.as_str_name()
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn are_compatible(writer: Type, reader: Type) -> bool {
+    const VARINTS: [Type; 6] = [
+        Type::Int32,
+        Type::Int64,
+        Type::Uint32,
+        Type::Uint64,
+        Type::Bool,
+        Type::Enum,
+    ];
+    writer == reader
+        || (VARINTS.contains(&writer) && VARINTS.contains(&reader))
+        || matches!(
+            (writer, reader),
+            (Type::Sint32 | Type::Sint64, Type::Sint32 | Type::Sint64)
+                | (
+                    Type::Fixed32 | Type::Sfixed32,
+                    Type::Fixed32 | Type::Sfixed32
+                )
+                | (
+                    Type::Fixed64 | Type::Sfixed64,
+                    Type::Fixed64 | Type::Sfixed64
+                )
+                | (Type::String | Type::Message, Type::Bytes)
+                | (Type::Bytes, Type::String)
+        )
+}
+
+fn qualify(scope: &str, name: &str) -> String {
+    if scope.is_empty() {
+        name.to_string()
+    } else {
+        format!("{scope}.{name}")
+    }
+}
+
+fn register_message(
+    message: &DescriptorProto,
+    scope: &str,
+    messages: &mut AHashMap<String, MessageDescriptor>,
+) {
+    let name = qualify(scope, message.name());
+    let fields = message
+        .field
+        .iter()
+        .map(|field| {
+            let descriptor = FieldDescriptor {
+                name: field.name().to_string(),
+                kind: field.r#type(),
+                type_name: field.type_name().trim_start_matches('.').to_string(),
+                repeated: field.label() == Label::Repeated,
+                required: field.label() == Label::Required,
+            };
+            (field.number() as u32, descriptor)
+        })
+        .collect();
+    for nested in &message.nested_type {
+        register_message(nested, &name, messages);
+    }
+    messages.insert(name, MessageDescriptor { fields });
+}
+
+struct WireReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> WireReader<'a> {
+    fn is_empty(&self) -> bool {
+        self.position == self.data.len()
+    }
+
+    fn read(&mut self, length: usize) -> Result<&'a [u8], String> {
+        let end = self
+            .position
+            .checked_add(length)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| "unexpected end of the message".to_string())?;
+        let bytes = &self.data[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+
+    fn read_varint(&mut self) -> Result<u64, String> {
+        let mut value = 0u64;
+        for shift in (0..70).step_by(7) {
+            let byte = self.read(1)?[0];
+            value |= u64::from(byte & 0x7F) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err("varint is too long".to_string())
+    }
+
+    fn read_key(&mut self) -> Result<(u64, u8), String> {
+        let key = self.read_varint()?;
+        let number = key >> 3;
+        let wire_type = (key & 0x07) as u8;
+        if number == 0 || number > MAX_FIELD_NUMBER {
+            return Err(format!("invalid field number: {number}"));
+        }
+        Ok((number, wire_type))
+    }
+
+    fn read_length_delimited(&mut self) -> Result<&'a [u8], String> {
+        let length = self.read_varint()?;
+        let length = usize::try_from(length).map_err(|_| format!("invalid length: {length}"))?;
+        self.read(length)
+    }
+
+    fn skip(&mut self, number: u64, wire_type: u8, depth: usize) -> Result<(), String> {
+        match wire_type {
+            WIRE_TYPE_VARINT => {
+                self.read_varint()?;
+            }
+            WIRE_TYPE_I64 => {
+                self.read(8)?;
+            }
+            WIRE_TYPE_LEN => {
+                self.read_length_delimited()?;
+            }
+            WIRE_TYPE_I32 => {
+                self.read(4)?;
+            }
+            WIRE_TYPE_START_GROUP => {
+                if depth > MAX_DEPTH {
+                    return Err(format!("message is nested deeper than {MAX_DEPTH} levels"));
+                }
+                loop {
+                    let (nested_number, nested_wire_type) = self.read_key()?;
+                    if nested_wire_type == WIRE_TYPE_END_GROUP {
+                        if nested_number != number {
+                            return Err(format!("unexpected end of group {nested_number}"));
+                        }
+                        break;
+                    }
+                    self.skip(nested_number, nested_wire_type, depth + 1)?;
+                }
+            }
+            _ => return Err(format!("invalid wire type: {wire_type}")),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost_types::{FieldDescriptorProto, FileDescriptorProto};
+
+    fn field(name: &str, number: i32, kind: Type, label: Label) -> FieldDescriptorProto {
+        FieldDescriptorProto {
+            name: Some(name.to_string()),
+            number: Some(number),
+            r#type: Some(kind as i32),
+            label: Some(label as i32),
+            ..Default::default()
+        }
+    }
+
+    fn message_field(name: &str, number: i32, type_name: &str) -> FieldDescriptorProto {
+        FieldDescriptorProto {
+            type_name: Some(type_name.to_string()),
+            ..field(name, number, Type::Message, Label::Optional)
+        }
+    }
+
+    fn definition(order_fields: Vec<FieldDescriptorProto>) -> Vec<u8> {
+        let common = FileDescriptorProto {
+            name: Some("common.proto".to_string()),
+            package: Some("common".to_string()),
+            message_type: vec![DescriptorProto {
+                name: Some("Money".to_string()),
+                field: vec![
+                    field("units", 1, Type::Int64, Label::Optional),
+                    field("currency", 2, Type::String, Label::Optional),
+                ],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let order = FileDescriptorProto {
+            name: Some("order.proto".to_string()),
+            package: Some("shop".to_string()),
+            dependency: vec!["common.proto".to_string()],
+            message_type: vec![DescriptorProto {
+                name: Some("Order".to_string()),
+                field: order_fields,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        FileDescriptorSet {
+            file: vec![common, order],
+        }
+        .encode_to_vec()
+    }
+
+    fn order_v1() -> Vec<FieldDescriptorProto> {
+        vec![
+            field("id", 1, Type::Int32, Label::Optional),
+            message_field("total", 2, ".common.Money"),
+            field("tags", 3, Type::Int32, Label::Repeated),
+        ]
+    }
+
+    fn encode_varint(mut value: u64, buffer: &mut Vec<u8>) {
+        while value >= 0x80 {
+            buffer.push((value as u8 & 0x7F) | 0x80);
+            value >>= 7;
+        }
+        buffer.push(value as u8);
+    }
+
+    fn encode_key(number: u64, wire_type: u8, buffer: &mut Vec<u8>) {
+        encode_varint((number << 3) | u64::from(wire_type), buffer);
+    }
+
+    fn encode_bytes(number: u64, bytes: &[u8], buffer: &mut Vec<u8>) {
+        encode_key(number, WIRE_TYPE_LEN, buffer);
+        encode_varint(bytes.len() as u64, buffer);
+        buffer.extend_from_slice(bytes);
+    }
+
+    fn order(currency: &[u8]) -> Vec<u8> {
+        let mut money = Vec::new();
+        encode_key(1, WIRE_TYPE_VARINT, &mut money);
+        encode_varint(100, &mut money);
+        encode_bytes(2, currency, &mut money);
+
+        let mut buffer = Vec::new();
+        encode_key(1, WIRE_TYPE_VARINT, &mut buffer);
+        encode_varint(7, &mut buffer);
+        encode_bytes(2, &money, &mut buffer);
+        encode_bytes(3, &[1, 2, 3], &mut buffer);
+        // Unknown field
+        encode_key(15, WIRE_TYPE_I32, &mut buffer);
+        buffer.extend_from_slice(&[0, 0, 0, 0]);
+        buffer
+    }
+
+    #[test]
+    fn message_should_be_validated() {
+        let schema = ProtobufSchema::parse(&definition(order_v1())).unwrap();
+        assert!(schema.validate(&order(b"EUR")).is_ok());
+        assert!(schema.validate(&order(&[0xFF, 0xFE])).is_err());
+
+        let mut invalid_wire_type = Vec::new();
+        encode_bytes(1, b"seven", &mut invalid_wire_type);
+        assert!(schema.validate(&invalid_wire_type).is_err());
+
+        let valid = order(b"EUR");
+        assert!(schema.validate(&valid[..valid.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn missing_required_field_should_be_rejected() {
+        let mut fields = order_v1();
+        fields.push(field("customer", 4, Type::String, Label::Required));
+        let schema = ProtobufSchema::parse(&definition(fields)).unwrap();
+        assert!(schema.validate(&order(b"EUR")).is_err());
+    }
+
+    #[test]
+    fn invalid_definition_should_be_rejected() {
+        assert!(ProtobufSchema::parse(b"not a descriptor set").is_err());
+        assert!(ProtobufSchema::parse(&FileDescriptorSet::default().encode_to_vec()).is_err());
+        let fields = vec![message_field("total", 1, ".common.Unknown")];
+        assert!(ProtobufSchema::parse(&definition(fields)).is_err());
+    }
+
+    #[test]
+    fn compatible_field_changes_should_be_accepted() {
+        let v1 = ProtobufSchema::parse(&definition(order_v1())).unwrap();
+        let v2 = ProtobufSchema::parse(&definition(vec![
+            field("order_id", 1, Type::Int64, Label::Optional),
+            message_field("total", 2, ".common.Money"),
+            field("note", 4, Type::String, Label::Optional),
+        ]))
+        .unwrap();
+        assert!(v2.can_read(&v1).is_ok());
+        assert!(v1.can_read(&v2).is_ok());
+    }
+
+    #[test]
+    fn incompatible_field_changes_should_be_rejected() {
+        let v1 = ProtobufSchema::parse(&definition(order_v1())).unwrap();
+        let changed_type = ProtobufSchema::parse(&definition(vec![
+            field("id", 1, Type::String, Label::Optional),
+            message_field("total", 2, ".common.Money"),
+        ]))
+        .unwrap();
+        assert!(changed_type.can_read(&v1).is_err());
+
+        let mut fields = order_v1();
+        fields.push(field("customer", 4, Type::String, Label::Required));
+        let new_required = ProtobufSchema::parse(&definition(fields)).unwrap();
+        assert!(new_required.can_read(&v1).is_err());
+        assert!(v1.can_read(&new_required).is_ok());
+    }
+}
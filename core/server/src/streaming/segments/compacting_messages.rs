@@ -0,0 +1,405 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use super::IggyIndexesMut;
+use crate::streaming::segments::segment::Segment;
+use crate::streaming::utils::file;
+use ahash::AHashMap;
+use bytes::{BufMut, BytesMut};
+use error_set::ErrContext;
+use iggy_common::{HeaderKind, INDEX_SIZE, IggyError, IggyMessageView};
+use std::sync::atomic::Ordering;
+use tokio::fs::{File, OpenOptions};
+use tokio::io::AsyncWriteExt;
+use tracing::{info, trace};
+
+const COMPACTING_EXTENSION: &str = "compacting";
+const COMPACTION_BATCH_COUNT: u32 = 10_000;
+
+/// The reserved user header key, which is appended to the user headers of each message sent
+/// to the compacted topic with the messages key partitioning. Its value holds the messages key.
+pub const MESSAGE_KEY_HEADER_KEY: &str = "iggy-message-key";
+
+/// Appends the serialized message key user header to the provided buffer.
+pub fn write_message_key_header(key: &[u8], buffer: &mut BytesMut) {
+    buffer.put_u32_le(MESSAGE_KEY_HEADER_KEY.len() as u32);
+    buffer.put_slice(MESSAGE_KEY_HEADER_KEY.as_bytes());
+    buffer.put_u8(HeaderKind::Raw.as_code());
+    buffer.put_u32_le(key.len() as u32);
+    buffer.put_slice(key);
+}
+
+/// Returns the size of the serialized message key user header.
+pub fn message_key_header_size(key: &[u8]) -> usize {
+    4 + MESSAGE_KEY_HEADER_KEY.len() + 1 + 4 + key.len()
+}
+
+/// Reads the key of the stored message, by which the topic is compacted. The messages without the key
+/// (i.e. not sent with the messages key partitioning) are never removed by the compaction.
+pub fn read_message_key(user_headers: &[u8]) -> Option<&[u8]> {
+    let mut position = 0;
+    while position + 4 <= user_headers.len() {
+        let key_length =
+            u32::from_le_bytes(user_headers[position..position + 4].try_into().unwrap()) as usize;
+        let key = user_headers.get(position + 4..position + 4 + key_length)?;
+        // The key is followed by the 1-byte kind and the 4-byte length of the value.
+        position += 4 + key_length + 1;
+        let value_length = user_headers.get(position..position + 4)?;
+        let value_length = u32::from_le_bytes(value_length.try_into().unwrap()) as usize;
+        position += 4;
+        let value = user_headers.get(position..position + value_length)?;
+        if key == MESSAGE_KEY_HEADER_KEY.as_bytes() {
+            return Some(value);
+        }
+        position += value_length;
+    }
+    None
+}
+
+#[derive(Debug, Default)]
+pub struct CompactedSegment {
+    pub removed_messages_count: u32,
+    pub removed_bytes: u64,
+}
+
+/// The newest offset of each message key stored in the segment. The keys are collected incrementally, so that
+/// each message is read only once, and the segment is rewritten only if there's anything to remove from it.
+#[derive(Debug)]
+pub struct SegmentMessageKeys {
+    latest: AHashMap<Vec<u8>, MessageKeyOffset>,
+    /// Exceeds the number of the keys, if any message is superseded by a newer one in the same segment.
+    keyed_messages_count: u64,
+    /// The offset of the first message, whose key hasn't been collected yet.
+    next_offset: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct MessageKeyOffset {
+    offset: u64,
+    /// Set if the newest message with the key is a tombstone.
+    tombstone_timestamp: Option<u64>,
+}
+
+impl MessageKeyOffset {
+    fn of(message: &IggyMessageView) -> Self {
+        let header = message.header();
+        Self {
+            offset: header.offset(),
+            tombstone_timestamp: (header.payload_length() == 0).then(|| header.timestamp()),
+        }
+    }
+}
+
+/// The message keys read from the part of the segment, to be added to its collected keys.
+#[derive(Debug)]
+pub struct CollectedMessageKeys {
+    pub segment_start_offset: u64,
+    from_offset: u64,
+    next_offset: u64,
+    keys: Vec<(Vec<u8>, MessageKeyOffset)>,
+}
+
+impl SegmentMessageKeys {
+    pub fn new(start_offset: u64) -> Self {
+        Self {
+            latest: AHashMap::new(),
+            keyed_messages_count: 0,
+            next_offset: start_offset,
+        }
+    }
+
+    /// Checks whether the compaction would remove any message from the segment, given the newest offsets
+    /// of the message keys in the whole partition.
+    fn has_removable_messages(
+        &self,
+        latest_offsets: &AHashMap<&[u8], u64>,
+        tombstone_expiry_timestamp: u64,
+    ) -> bool {
+        self.keyed_messages_count > self.latest.len() as u64
+            || self.latest.iter().any(|(key, latest)| {
+                latest_offsets.get(key.as_slice()) != Some(&latest.offset)
+                    || latest
+                        .tombstone_timestamp
+                        .is_some_and(|timestamp| timestamp < tombstone_expiry_timestamp)
+            })
+    }
+}
+
+impl Segment {
+    /// Checks whether the key of any message appended up to the `end_offset` hasn't been collected yet.
+    pub fn has_uncollected_message_keys(&self, end_offset: u64) -> bool {
+        self.get_messages_count() > 0
+            && self.message_keys.next_offset <= self.end_offset
+            && self.message_keys.next_offset <= end_offset
+    }
+
+    /// Reads the keys of the next messages, which haven't been collected yet. The segment isn't modified,
+    /// so the messages can be read while the partition is shared with the appends and polls.
+    pub async fn read_uncollected_message_keys(&self) -> Result<CollectedMessageKeys, IggyError> {
+        let from_offset = self.message_keys.next_offset;
+        let messages = self
+            .get_messages_by_offset(from_offset, COMPACTION_BATCH_COUNT)
+            .await?;
+        // The messages at the end of the compacted segment might have been removed.
+        let last_offset = messages.last_offset().unwrap_or(self.end_offset);
+        let mut keys = Vec::new();
+        for batch in messages.iter() {
+            for message in batch.iter() {
+                if let Some(key) = message.user_headers().and_then(read_message_key) {
+                    keys.push((key.to_vec(), MessageKeyOffset::of(&message)));
+                }
+            }
+        }
+
+        Ok(CollectedMessageKeys {
+            segment_start_offset: self.start_offset,
+            from_offset,
+            next_offset: last_offset + 1,
+            keys,
+        })
+    }
+
+    /// Adds the keys read by `read_uncollected_message_keys`, unless the segment has changed in the meantime.
+    pub fn add_collected_message_keys(&mut self, collected: CollectedMessageKeys) {
+        if collected.from_offset != self.message_keys.next_offset {
+            return;
+        }
+
+        self.message_keys.keyed_messages_count += collected.keys.len() as u64;
+        self.message_keys.latest.extend(collected.keys);
+        self.message_keys.next_offset = collected.next_offset;
+    }
+
+    /// Adds the newest offset of each message key collected from the segment to the provided map.
+    /// Offsets from the subsequent segments should be added later, so they replace the older ones.
+    pub fn add_latest_offsets<'a>(&'a self, latest_offsets: &mut AHashMap<&'a [u8], u64>) {
+        for (key, latest) in &self.message_keys.latest {
+            latest_offsets.insert(key.as_slice(), latest.offset);
+        }
+    }
+
+    /// Returns the newest offsets in the partition of the keys stored in the segment, if the compaction
+    /// would remove any message from it, so that the segments with nothing to remove are never rewritten.
+    pub fn get_removable_message_keys(
+        &self,
+        latest_offsets: &AHashMap<&[u8], u64>,
+        tombstone_expiry_timestamp: u64,
+    ) -> Option<AHashMap<Vec<u8>, u64>> {
+        if !self.is_closed
+            || !self
+                .message_keys
+                .has_removable_messages(latest_offsets, tombstone_expiry_timestamp)
+        {
+            return None;
+        }
+
+        Some(
+            self.message_keys
+                .latest
+                .keys()
+                .filter_map(|key| {
+                    latest_offsets
+                        .get(key.as_slice())
+                        .map(|&offset| (key.clone(), offset))
+                })
+                .collect(),
+        )
+    }
+
+    /// Rewrites the closed segment, keeping only the newest message for each message key.
+    /// Tombstones (messages with a key and an empty payload) are kept until their timestamp is older
+    /// than `tombstone_expiry_timestamp`. The messages without the key are always kept. The offsets
+    /// of the retained messages are not changed, so they stay monotonic, but there might be gaps between them.
+    pub async fn compact(
+        &mut self,
+        latest_offsets: &AHashMap<Vec<u8>, u64>,
+        tombstone_expiry_timestamp: u64,
+    ) -> Result<CompactedSegment, IggyError> {
+        if !self.is_closed {
+            return Ok(CompactedSegment::default());
+        }
+
+        let messages_count = self.get_messages_count();
+        let messages_size = self.messages_size.load(Ordering::Acquire);
+        let compacted_messages_path = format!("{}.{COMPACTING_EXTENSION}", self.messages_path);
+        let compacted_index_path = format!("{}.{COMPACTING_EXTENSION}", self.index_path);
+        let mut messages_file = Self::create_compacted_file(&compacted_messages_path).await?;
+        let mut index_file = Self::create_compacted_file(&compacted_index_path).await?;
+
+        let cache_indexes = !self.indexes.is_empty();
+        let mut indexes = IggyIndexesMut::empty();
+        let mut position = 0;
+        let mut retained_count = 0;
+        let mut retained_keys = SegmentMessageKeys::new(self.start_offset);
+        let mut current_offset = self.start_offset;
+        while current_offset <= self.end_offset {
+            let messages = self
+                .get_messages_by_offset(current_offset, COMPACTION_BATCH_COUNT)
+                .await?;
+            let Some(last_offset) = messages.last_offset() else {
+                break;
+            };
+
+            for batch in messages.iter() {
+                let retained = batch.retain_messages(position, |message| {
+                    let Some(key) = message.user_headers().and_then(read_message_key) else {
+                        return true;
+                    };
+
+                    let header = message.header();
+                    let is_latest = latest_offsets
+                        .get(key)
+                        .is_none_or(|&offset| offset == header.offset());
+                    let is_expired_tombstone = header.payload_length() == 0
+                        && header.timestamp() < tombstone_expiry_timestamp;
+                    is_latest && !is_expired_tombstone
+                });
+                if retained.is_empty() {
+                    continue;
+                }
+
+                for message in retained.iter() {
+                    if let Some(key) = message.user_headers().and_then(read_message_key) {
+                        retained_keys
+                            .latest
+                            .insert(key.to_vec(), MessageKeyOffset::of(&message));
+                        retained_keys.keyed_messages_count += 1;
+                    }
+                }
+                Self::write_compacted(&mut messages_file, &compacted_messages_path, &retained)
+                    .await?;
+                Self::write_compacted(&mut index_file, &compacted_index_path, retained.indexes())
+                    .await?;
+                if cache_indexes {
+                    indexes.append_slice(retained.indexes());
+                }
+                position = retained.indexes().last_position();
+                retained_count += retained.count();
+            }
+            current_offset = last_offset + 1;
+        }
+        retained_keys.next_offset = current_offset;
+        self.message_keys = retained_keys;
+
+        if retained_count == messages_count {
+            drop(messages_file);
+            drop(index_file);
+            let _ = file::remove(&compacted_messages_path).await;
+            let _ = file::remove(&compacted_index_path).await;
+            trace!("Nothing to compact in {self}");
+            return Ok(CompactedSegment::default());
+        }
+
+        Self::sync_compacted(&messages_file, &compacted_messages_path).await?;
+        Self::sync_compacted(&index_file, &compacted_index_path).await?;
+        drop(messages_file);
+        drop(index_file);
+
+        // The marker is created before the files are replaced, so that the compacted segment is never
+        // read as the contiguous one. Reading the not yet compacted segment as the compacted one is fine.
+        if !self.is_compacted {
+            Self::create_compacted_marker(&self.compacted_path).await?;
+        }
+        if self.messages_writer.is_some() || self.index_writer.is_some() {
+            self.shutdown_writing().await;
+        }
+        self.shutdown_reading().await;
+        Self::replace_file(&compacted_messages_path, &self.messages_path).await?;
+        Self::replace_file(&compacted_index_path, &self.index_path).await?;
+
+        self.messages_size.store(position as u64, Ordering::Release);
+        self.indexes_size
+            .store(retained_count as u64 * INDEX_SIZE as u64, Ordering::Release);
+        self.last_index_position = position;
+        self.is_compacted = true;
+        if cache_indexes {
+            indexes.mark_saved();
+            self.indexes = indexes;
+        }
+        self.initialize_reading().await?;
+
+        let removed_bytes = messages_size - position as u64;
+        let removed_messages_count = messages_count - retained_count;
+        self.size_of_parent_stream
+            .fetch_sub(removed_bytes, Ordering::SeqCst);
+        self.size_of_parent_topic
+            .fetch_sub(removed_bytes, Ordering::SeqCst);
+        self.size_of_parent_partition
+            .fetch_sub(removed_bytes, Ordering::SeqCst);
+        self.messages_count_of_parent_stream
+            .fetch_sub(removed_messages_count as u64, Ordering::SeqCst);
+        self.messages_count_of_parent_topic
+            .fetch_sub(removed_messages_count as u64, Ordering::SeqCst);
+        self.messages_count_of_parent_partition
+            .fetch_sub(removed_messages_count as u64, Ordering::SeqCst);
+
+        info!(
+            "Compacted segment with start offset: {} for partition with ID: {}, topic with ID: {} and stream with ID: {}, removed {removed_messages_count} messages ({removed_bytes} bytes).",
+            self.start_offset, self.partition_id, self.topic_id, self.stream_id
+        );
+
+        Ok(CompactedSegment {
+            removed_messages_count,
+            removed_bytes,
+        })
+    }
+
+    async fn create_compacted_file(path: &str) -> Result<File, IggyError> {
+        OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(path)
+            .await
+            .with_error_context(|error| {
+                format!("Failed to create compacted segment file: {path}. {error}")
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)
+    }
+
+    async fn create_compacted_marker(path: &str) -> Result<(), IggyError> {
+        let file = Self::create_compacted_file(path).await?;
+        Self::sync_compacted(&file, path).await
+    }
+
+    async fn write_compacted(file: &mut File, path: &str, bytes: &[u8]) -> Result<(), IggyError> {
+        file.write_all(bytes)
+            .await
+            .with_error_context(|error| {
+                format!("Failed to write to compacted segment file: {path}. {error}")
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)
+    }
+
+    async fn sync_compacted(file: &File, path: &str) -> Result<(), IggyError> {
+        file.sync_all()
+            .await
+            .with_error_context(|error| {
+                format!("Failed to fsync compacted segment file: {path}. {error}")
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)
+    }
+
+    async fn replace_file(compacted_path: &str, path: &str) -> Result<(), IggyError> {
+        file::rename(compacted_path, path)
+            .await
+            .with_error_context(|error| {
+                format!("Failed to replace segment file: {path} with: {compacted_path}. {error}")
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)
+    }
+}
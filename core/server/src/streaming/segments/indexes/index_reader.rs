@@ -323,6 +323,41 @@ impl IndexReader {
         Ok(Some(low))
     }
 
+    /// Finds the position of the first index with relative offset greater than or equal to
+    /// the requested one. Used for segments with gaps in offsets.
+    pub async fn find_position_by_offset(
+        &self,
+        relative_offset: u32,
+    ) -> Result<Option<u32>, IggyError> {
+        let total_indexes = self.file_size() / INDEX_SIZE as u32;
+        if total_indexes == 0 {
+            return Ok(None);
+        }
+
+        match self.load_nth_index(total_indexes - 1).await? {
+            Some(last_idx) if last_idx.offset >= relative_offset => {}
+            _ => return Ok(None),
+        }
+
+        let mut low = 0;
+        let mut high = total_indexes;
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let mid_index = match self.load_nth_index(mid).await? {
+                Some(idx) => idx,
+                None => return Ok(None),
+            };
+
+            if mid_index.offset < relative_offset {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        Ok(Some(low))
+    }
+
     /// Returns the size of the index file in bytes.
     fn file_size(&self) -> u32 {
         self.index_size_bytes.load(Ordering::Acquire) as u32
@@ -209,6 +209,28 @@ impl IggyIndexesMut {
         result
     }
 
+    /// Finds the position of the first index with relative offset greater than or equal to
+    /// the requested one using binary search. Used for segments with gaps in offsets.
+    pub fn find_position_by_offset(&self, relative_offset: u32) -> Option<u32> {
+        let count = self.count();
+        if count == 0 || self.last()?.offset() < relative_offset {
+            return None;
+        }
+
+        let mut low = 0;
+        let mut high = count;
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.get(mid)?.offset() < relative_offset {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        Some(low)
+    }
+
     /// Clears the container, removing all indexes but preserving already allocated buffer capacity
     pub fn clear(&mut self) {
         self.saved_count = 0;
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+mod compacting_messages;
 mod indexes;
 mod messages;
 mod messages_accumulator;
@@ -24,6 +25,10 @@ mod segment;
 mod types;
 mod writing_messages;
 
+pub use compacting_messages::{
+    CollectedMessageKeys, CompactedSegment, MESSAGE_KEY_HEADER_KEY, SegmentMessageKeys,
+    message_key_header_size, read_message_key, write_message_key_header,
+};
 pub use indexes::IggyIndexesMut;
 pub use messages_accumulator::MessagesAccumulator;
 pub use segment::Segment;
@@ -34,4 +39,5 @@ pub use types::IggyMessagesBatchSet;
 
 pub const LOG_EXTENSION: &str = "log";
 pub const INDEX_EXTENSION: &str = "index";
+pub const COMPACTED_EXTENSION: &str = "compacted";
 pub const SEGMENT_MAX_SIZE_BYTES: u64 = 1024 * 1024 * 1024;
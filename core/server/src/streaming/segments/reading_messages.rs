@@ -19,7 +19,7 @@
 use super::{IggyIndexesMut, IggyMessagesBatchMut, IggyMessagesBatchSet};
 use crate::streaming::segments::segment::Segment;
 use error_set::ErrContext;
-use iggy_common::{IggyByteSize, IggyError};
+use iggy_common::{INDEX_SIZE, IggyByteSize, IggyError};
 use std::sync::atomic::Ordering;
 use tracing::trace;
 
@@ -37,6 +37,12 @@ impl Segment {
             return 0;
         }
 
+        // Once the segment is closed, all of its indexes are on disk and they remain
+        // authoritative even if there are gaps in offsets left by the compaction.
+        if self.is_closed || self.is_compacted {
+            return (self.indexes_size.load(Ordering::Relaxed) / INDEX_SIZE as u64) as u32;
+        }
+
         (self.end_offset - self.start_offset + 1) as u32
     }
 
@@ -248,7 +254,7 @@ impl Segment {
         let mut current_offset = self.start_offset;
         let mut processed_count = 0;
 
-        while current_offset <= end_offset {
+        while current_offset <= end_offset && processed_count < messages_count {
             let remaining_count = messages_count - processed_count;
             let batch_count = std::cmp::min(BATCH_COUNT, remaining_count);
 
@@ -256,13 +262,17 @@ impl Segment {
                 .get_messages_by_offset(current_offset, batch_count)
                 .await?;
 
+            let Some(last_offset) = messages_batch.last_offset() else {
+                break;
+            };
+
             for batch in messages_batch.iter() {
                 batch.validate_checksums().with_error_context(|error| {
                     format!("Failed to validate message checksum, error: {error} for {self}")
                 })?;
                 processed_count += batch.count();
             }
-            current_offset += batch_count as u64;
+            current_offset = last_offset + 1;
         }
 
         Ok(())
@@ -311,7 +321,23 @@ impl Segment {
             "Loading {count} messages from disk, start_offset: {start_offset}, end_offset: {}...",
             self.end_offset
         );
-        let relative_start_offset = (start_offset - self.start_offset) as u32;
+        let mut relative_start_offset = (start_offset - self.start_offset) as u32;
+        if self.is_compacted {
+            // Offsets are no longer contiguous, so they have to be translated into index positions.
+            let position = if !self.indexes.is_empty() {
+                self.indexes.find_position_by_offset(relative_start_offset)
+            } else {
+                self.index_reader
+                    .as_ref()
+                    .expect("Index reader not initialized")
+                    .find_position_by_offset(relative_start_offset)
+                    .await?
+            };
+            match position {
+                Some(position) => relative_start_offset = position,
+                None => return Ok(IggyMessagesBatchSet::empty()),
+            }
+        }
 
         let indexes_to_read = self
             .load_indexes_by_offset(relative_start_offset, count)
@@ -332,14 +358,17 @@ impl Segment {
                 format!("Failed to load messages from segment file: {self}. {error}")
             })?;
 
-        batch
-            .validate_checksums_and_offsets(start_offset)
-            .with_error_context(|error| {
-                format!(
-                    "Failed to validate messages read from disk! error: {error}, file: {}",
-                    self.messages_path
-                )
-            })?;
+        let validation_result = if self.is_compacted {
+            batch.validate_checksums_and_increasing_offsets(start_offset)
+        } else {
+            batch.validate_checksums_and_offsets(start_offset)
+        };
+        validation_result.with_error_context(|error| {
+            format!(
+                "Failed to validate messages read from disk! error: {error}, file: {}",
+                self.messages_path
+            )
+        })?;
 
         tracing::trace!(
             "Loaded {} messages ({} bytes) from disk (requested {count} messages), start_offset: {start_offset}, end_offset: {}",
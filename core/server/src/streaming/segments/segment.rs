@@ -20,6 +20,7 @@ use super::indexes::*;
 use super::messages::*;
 use super::messages_accumulator::MessagesAccumulator;
 use crate::configs::system::SystemConfig;
+use crate::streaming::partitions::partition::OffloadedSegment;
 use crate::streaming::segments::*;
 use error_set::ErrContext;
 use iggy_common::INDEX_SIZE;
@@ -29,7 +30,7 @@ use iggy_common::IggyExpiry;
 use iggy_common::IggyTimestamp;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
-use tokio::fs::remove_file;
+use tokio::fs::{metadata, remove_file, try_exists};
 use tracing::{info, warn};
 
 const SIZE_16MB: usize = 16 * 1024 * 1024;
@@ -45,6 +46,8 @@ pub struct Segment {
     pub(super) end_offset: u64,
     pub(super) index_path: String,
     pub(super) messages_path: String,
+    /// The marker file, which exists once the segment has been compacted.
+    pub(super) compacted_path: String,
     pub(super) last_index_position: u32,
     pub(super) max_size_bytes: IggyByteSize,
     pub(super) size_of_parent_stream: Arc<AtomicU64>,
@@ -54,6 +57,8 @@ pub struct Segment {
     pub(super) messages_count_of_parent_topic: Arc<AtomicU64>,
     pub(super) messages_count_of_parent_partition: Arc<AtomicU64>,
     pub(super) is_closed: bool,
+    pub(super) is_compacted: bool,
+    pub(super) message_keys: SegmentMessageKeys,
     pub(super) messages_writer: Option<MessagesWriter>,
     pub(super) messages_reader: Option<MessagesReader>,
     pub(super) index_writer: Option<IndexWriter>,
@@ -86,6 +91,7 @@ impl Segment {
         let path = config.get_segment_path(stream_id, topic_id, partition_id, start_offset);
         let messages_path = Self::get_messages_file_path(&path);
         let index_path = Self::get_index_path(&path);
+        let compacted_path = Self::get_compacted_path(&path);
         let message_expiry = match message_expiry {
             IggyExpiry::ServerDefault => config.segment.message_expiry,
             _ => message_expiry,
@@ -104,6 +110,7 @@ impl Segment {
             end_timestamp: IggyTimestamp::now().as_micros(),
             end_offset: start_offset,
             messages_path,
+            compacted_path,
             index_path,
             last_index_position: 0,
             max_size_bytes: config.segment.size,
@@ -111,6 +118,8 @@ impl Segment {
             indexes: IggyIndexesMut::with_capacity(indexes_capacity, 0),
             accumulator: MessagesAccumulator::default(),
             is_closed: false,
+            is_compacted: false,
+            message_keys: SegmentMessageKeys::new(start_offset),
             messages_writer: None,
             messages_reader: None,
             index_writer: None,
@@ -127,6 +136,58 @@ impl Segment {
         }
     }
 
+    /// Opens the offloaded segment, whose files have been fetched from the remote tier into the `path`,
+    /// for reading only. The cached segment doesn't count towards the size and messages count of its parents.
+    pub async fn open_cached(
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        offloaded_segment: &OffloadedSegment,
+        path: &str,
+        config: Arc<SystemConfig>,
+    ) -> Result<Segment, IggyError> {
+        let mut segment = Segment::create(
+            stream_id,
+            topic_id,
+            partition_id,
+            offloaded_segment.start_offset,
+            config,
+            IggyExpiry::NeverExpire,
+            Arc::default(),
+            Arc::default(),
+            Arc::default(),
+            Arc::default(),
+            Arc::default(),
+            Arc::default(),
+            false,
+        );
+        segment.messages_path = Self::get_messages_file_path(path);
+        segment.index_path = Self::get_index_path(path);
+        segment.compacted_path = Self::get_compacted_path(path);
+        segment.start_timestamp = offloaded_segment.start_timestamp;
+        segment.end_timestamp = offloaded_segment.end_timestamp;
+        segment.end_offset = offloaded_segment.end_offset;
+        segment.is_closed = true;
+
+        let messages_size = metadata(&segment.messages_path)
+            .await
+            .map_err(|_| IggyError::CannotReadFile)?
+            .len();
+        let indexes_size = metadata(&segment.index_path)
+            .await
+            .map_err(|_| IggyError::CannotReadFile)?
+            .len();
+        segment
+            .messages_size
+            .store(messages_size, Ordering::Release);
+        segment.indexes_size.store(indexes_size, Ordering::Release);
+        // The offloaded segment might have been compacted before, so its offsets are always
+        // translated into the index positions, which works for the contiguous offsets too.
+        segment.is_compacted = true;
+        segment.initialize_reading().await?;
+        Ok(segment)
+    }
+
     /// Load the segment state from disk.
     pub async fn load_from_disk(&mut self) -> Result<(), IggyError> {
         if self.messages_reader.is_none() || self.index_reader.is_none() {
@@ -160,6 +221,13 @@ impl Segment {
         };
 
         self.end_offset = self.start_offset + last_index_offset;
+        // Compacted segments keep the original offsets, so there might be gaps between them.
+        self.is_compacted = try_exists(&self.compacted_path).await.unwrap_or(false);
+        // The age of the segment is measured from its first message, also across the restarts.
+        if let (Some(first_index), Some(last_index)) = (self.indexes.get(0), self.indexes.last()) {
+            self.start_timestamp = first_index.timestamp();
+            self.end_timestamp = last_index.timestamp();
+        }
 
         info!(
             "Loaded {} indexes for segment with start offset: {} and partition with ID: {} for topic with ID: {} and stream with ID: {}.",
@@ -323,6 +391,26 @@ impl Segment {
         }
     }
 
+    /// Closes the segment files, waiting for the pending writes to be persisted, so that they can be moved.
+    pub async fn release_files(&mut self) {
+        self.shutdown_reading().await;
+        if let Some(log_writer) = self.messages_writer.take() {
+            let _ = log_writer.fsync().await;
+            log_writer.shutdown_persister_task().await;
+        }
+        if let Some(index_writer) = self.index_writer.take() {
+            let _ = index_writer.fsync().await;
+        }
+    }
+
+    /// Opens the segment files released with `release_files` again, the writers only if the segment isn't closed.
+    pub async fn reopen_files(&mut self) -> Result<(), IggyError> {
+        if !self.is_closed {
+            self.initialize_writing(true).await?;
+        }
+        self.initialize_reading().await
+    }
+
     pub async fn delete(&mut self) -> Result<(), IggyError> {
         let segment_size = self.get_messages_size();
         let segment_count_of_messages = self.get_messages_count() as u64;
@@ -347,6 +435,16 @@ impl Segment {
             .with_error_context(|error| {
                 format!("Failed to delete index file: {}. {error}", self.index_path)
             });
+        if self.is_compacted {
+            let _ = remove_file(&self.compacted_path)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "Failed to delete compacted marker file: {}. {error}",
+                        self.compacted_path
+                    )
+                });
+        }
 
         let segment_size_bytes = segment_size.as_bytes_u64();
         self.size_of_parent_stream
@@ -370,14 +468,18 @@ impl Segment {
         Ok(())
     }
 
-    fn get_messages_file_path(path: &str) -> String {
+    pub(crate) fn get_messages_file_path(path: &str) -> String {
         format!("{}.{}", path, LOG_EXTENSION)
     }
 
-    fn get_index_path(path: &str) -> String {
+    pub(crate) fn get_index_path(path: &str) -> String {
         format!("{}.{}", path, INDEX_EXTENSION)
     }
 
+    pub(crate) fn get_compacted_path(path: &str) -> String {
+        format!("{}.{}", path, COMPACTED_EXTENSION)
+    }
+
     pub fn update_message_expiry(&mut self, message_expiry: IggyExpiry) {
         self.message_expiry = message_expiry;
     }
@@ -386,6 +488,23 @@ impl Segment {
         self.is_closed
     }
 
+    /// Closes the segment loaded from disk which is not the last one in the partition,
+    /// as no more messages will be appended to it, regardless of its size.
+    pub async fn close(&mut self) {
+        if self.is_closed {
+            return;
+        }
+
+        if self.messages_writer.is_some() || self.index_writer.is_some() {
+            self.shutdown_writing().await;
+        }
+        self.is_closed = true;
+    }
+
+    pub fn is_compacted(&self) -> bool {
+        self.is_compacted
+    }
+
     pub fn start_offset(&self) -> u64 {
         self.start_offset
     }
@@ -394,6 +513,10 @@ impl Segment {
         self.end_offset
     }
 
+    pub fn start_timestamp(&self) -> u64 {
+        self.start_timestamp
+    }
+
     pub fn end_timestamp(&self) -> u64 {
         self.end_timestamp
     }
@@ -379,6 +379,59 @@ impl IggyMessagesBatchMut {
         self.slice_by_index(first_message_index, count)
     }
 
+    /// Returns a new batch containing up to `count` messages with offsets not exceeding `max_offset`,
+    /// which match the provided `predicate`, together with the offset of the last message that was examined.
+    ///
+    /// Scanning stops as soon as `count` messages have matched, so the returned offset
+    /// can be used to resume the scan from the next message. If no messages were examined,
+    /// the returned offset is `None`.
+    pub fn filter_by<F>(&self, max_offset: u64, count: u32, mut predicate: F) -> (Self, Option<u64>)
+    where
+        F: FnMut(&IggyMessageView) -> bool,
+    {
+        if self.is_empty() || count == 0 {
+            return (Self::empty(), None);
+        }
+
+        let mut indexes = IggyIndexesMut::with_capacity(count as usize, 0);
+        let mut messages = PooledBuffer::with_capacity(self.messages.len());
+        let mut last_scanned_offset = None;
+        let mut position = 0u32;
+
+        for index in 0..self.count() {
+            if indexes.count() == count {
+                break;
+            }
+
+            let Some((start, end)) = self.get_message_boundaries(index as usize) else {
+                break;
+            };
+
+            let message = IggyMessageView::new(&self.messages[start..end]);
+            let offset = message.header().offset();
+            if offset > max_offset {
+                break;
+            }
+
+            last_scanned_offset = Some(offset);
+            if !predicate(&message) {
+                continue;
+            }
+
+            let source_index = self.indexes.get(index).expect("Index should exist");
+            position += (end - start) as u32;
+            messages.put_slice(&self.messages[start..end]);
+            indexes.insert(source_index.offset(), position, source_index.timestamp());
+        }
+
+        let batch = Self {
+            count: indexes.count(),
+            indexes,
+            messages,
+        };
+        (batch, last_scanned_offset)
+    }
+
     /// Find the position of the index with timestamp closest to (but not exceeding) the target
     fn binary_search_timestamp(&self, target_timestamp: u64) -> Option<u32> {
         if self.count() == 0 {
@@ -453,6 +506,68 @@ impl IggyMessagesBatchMut {
         Ok(())
     }
 
+    /// Validates that all messages have correct checksums and strictly increasing offsets
+    /// starting at or after `absolute_start_offset`. Used for compacted segments, where
+    /// offsets are monotonic but no longer contiguous.
+    pub fn validate_checksums_and_increasing_offsets(
+        &self,
+        absolute_start_offset: u64,
+    ) -> Result<(), IggyError> {
+        let mut min_offset = absolute_start_offset;
+        for message in self.iter() {
+            let calculated_checksum = message.calculate_checksum();
+            let actual_checksum = message.header().checksum();
+            let offset = message.header().offset();
+            if offset < min_offset {
+                return Err(IggyError::InvalidOffset(offset));
+            }
+            if calculated_checksum != actual_checksum {
+                return Err(IggyError::InvalidMessageChecksum(
+                    actual_checksum,
+                    calculated_checksum,
+                    offset,
+                ));
+            }
+            min_offset = offset + 1;
+        }
+        Ok(())
+    }
+
+    /// Returns a new batch containing only the messages for which `keep` returns `true`.
+    ///
+    /// The relative offsets and timestamps of the retained indexes are preserved, while
+    /// their positions are recalculated as if the batch was written at `base_position`.
+    pub fn retain_messages<F>(&self, base_position: u32, mut keep: F) -> Self
+    where
+        F: FnMut(&IggyMessageView) -> bool,
+    {
+        let mut indexes = IggyIndexesMut::with_capacity(self.count() as usize, base_position);
+        let mut messages = PooledBuffer::with_capacity(self.messages.len());
+        let mut position = base_position;
+
+        for index in 0..self.count() {
+            let Some((start, end)) = self.get_message_boundaries(index as usize) else {
+                break;
+            };
+
+            let message = IggyMessageView::new(&self.messages[start..end]);
+            if !keep(&message) {
+                continue;
+            }
+
+            let source_index = self.indexes.get(index).expect("Index should exist");
+            position += (end - start) as u32;
+            messages.put_slice(&self.messages[start..end]);
+            indexes.insert(source_index.offset(), position, source_index.timestamp());
+        }
+
+        Self {
+            count: indexes.count(),
+            indexes,
+            messages,
+        }
+    }
+
     /// Gets the byte range for a message at the given index
     fn get_message_boundaries(&self, index: usize) -> Option<(usize, usize)> {
         let start = self.message_start_position(index)?;
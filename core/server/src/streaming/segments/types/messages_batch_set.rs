@@ -170,7 +170,10 @@ impl IggyMessagesBatchSet {
     /// A vector of IggyMessage objects with proper metadata
     pub fn into_polled_messages(&self, poll_metadata: IggyPollMetadata) -> PolledMessages {
         if self.is_empty() {
-            return PolledMessages::empty();
+            return PolledMessages {
+                next_offset: poll_metadata.next_offset,
+                ..PolledMessages::empty()
+            };
         }
 
         let mut messages = Vec::with_capacity(self.count() as usize);
@@ -200,6 +203,7 @@ impl IggyMessagesBatchSet {
             partition_id: poll_metadata.partition_id,
             current_offset: poll_metadata.current_offset,
             count: messages.len() as u32,
+            next_offset: poll_metadata.next_offset,
             messages,
         }
     }
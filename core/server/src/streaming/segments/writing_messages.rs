@@ -43,6 +43,7 @@ impl Segment {
         }
         let batch_messages_size = messages.size();
         let batch_messages_count = messages.count();
+        let is_empty = self.get_messages_size() == 0;
 
         let messages_accumulator = &mut self.accumulator;
         messages_accumulator
@@ -55,7 +56,7 @@ impl Segment {
             )
             .await;
 
-        if self.end_offset == 0 {
+        if is_empty {
             self.start_timestamp = messages_accumulator.first_timestamp();
         }
         self.end_timestamp = messages_accumulator.last_timestamp();
@@ -162,23 +163,34 @@ impl Segment {
                 max_segment_size_from_config
             );
 
-            // Since segment is closing, indexes should be dropped if index cache is disabled
-            // or when only open (last) segment is allowed to have indexes in memory.
-            if self.config.segment.cache_indexes == CacheIndexesConfig::OpenSegment
-                || self.config.segment.cache_indexes == CacheIndexesConfig::None
-            {
-                self.drop_indexes();
-            }
-            self.shutdown_writing().await;
-            info!(
-                "Closed segment with start offset: {}, end offset: {}, size: {} for partition with ID: {}.",
-                self.start_offset,
-                self.end_offset,
-                self.get_messages_size(),
-                self.partition_id
-            );
-            self.is_closed = true;
+            self.roll().await;
         }
         Ok(())
     }
+
+    /// Closes the open segment, so that the next messages are appended to a new one.
+    /// Besides the segment being full, it's rolled once it reaches the max age of its topic,
+    /// in which case all of its messages have to be persisted beforehand.
+    pub async fn roll(&mut self) {
+        if self.is_closed {
+            return;
+        }
+
+        // Since segment is closing, indexes should be dropped if index cache is disabled
+        // or when only open (last) segment is allowed to have indexes in memory.
+        if self.config.segment.cache_indexes == CacheIndexesConfig::OpenSegment
+            || self.config.segment.cache_indexes == CacheIndexesConfig::None
+        {
+            self.drop_indexes();
+        }
+        self.shutdown_writing().await;
+        info!(
+            "Closed segment with start offset: {}, end offset: {}, size: {} for partition with ID: {}.",
+            self.start_offset,
+            self.end_offset,
+            self.get_messages_size(),
+            self.partition_id
+        );
+        self.is_closed = true;
+    }
 }
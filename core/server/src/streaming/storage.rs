@@ -17,9 +17,13 @@
  */
 
 use super::persistence::persister::PersisterKind;
+use crate::archiver::ArchiverKind;
 use crate::configs::system::SystemConfig;
 use crate::state::system::{PartitionState, StreamState, TopicState};
-use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
+use crate::streaming::partitions::partition::{
+    ConsumerOffset, DelayedMessages, DeliveryAttempts, OffloadedSegment, Partition,
+    TransactionMarker, TransactionMarkers,
+};
 use crate::streaming::partitions::storage::FilePartitionStorage;
 use crate::streaming::streams::storage::FileStreamStorage;
 use crate::streaming::streams::stream::Stream;
@@ -29,6 +33,7 @@ use crate::streaming::topics::storage::FileTopicStorage;
 use crate::streaming::topics::topic::Topic;
 use iggy_common::ConsumerKind;
 use iggy_common::IggyError;
+use iggy_common::ProducerSequence;
 #[cfg(test)]
 use mockall::automock;
 use std::fmt::Debug;
@@ -123,8 +128,7 @@ pub trait PartitionStorage: Send {
     fn delete(&self, partition: &Partition) -> impl Future<Output = Result<(), IggyError>> + Send;
     fn save_consumer_offset(
         &self,
-        offset: u64,
-        path: &str,
+        consumer_offset: &ConsumerOffset,
     ) -> impl Future<Output = Result<(), IggyError>> + Send;
     fn load_consumer_offsets(
         &self,
@@ -139,6 +143,60 @@ pub trait PartitionStorage: Send {
         &self,
         path: &str,
     ) -> impl Future<Output = Result<(), IggyError>> + Send;
+    fn save_producer_sequence(
+        &self,
+        producer: &ProducerSequence,
+        path: &str,
+    ) -> impl Future<Output = Result<(), IggyError>> + Send;
+    fn load_producer_sequences(
+        &self,
+        path: &str,
+    ) -> impl Future<Output = Result<Vec<ProducerSequence>, IggyError>> + Send;
+    fn save_delivery_attempts(
+        &self,
+        delivery_attempts: &DeliveryAttempts,
+        path: &str,
+    ) -> impl Future<Output = Result<(), IggyError>> + Send;
+    fn load_delivery_attempts(
+        &self,
+        path: &str,
+    ) -> impl Future<Output = Result<Vec<DeliveryAttempts>, IggyError>> + Send;
+    fn delete_delivery_attempts(
+        &self,
+        path: &str,
+    ) -> impl Future<Output = Result<(), IggyError>> + Send;
+    fn save_delayed_messages(
+        &self,
+        delayed_messages: &DelayedMessages,
+        path: &str,
+    ) -> impl Future<Output = Result<(), IggyError>> + Send;
+    fn load_delayed_messages(
+        &self,
+        path: &str,
+    ) -> impl Future<Output = Result<Vec<DelayedMessages>, IggyError>> + Send;
+    fn save_offloaded_segments(
+        &self,
+        offloaded_segments: &[OffloadedSegment],
+        path: &str,
+    ) -> impl Future<Output = Result<(), IggyError>> + Send;
+    fn load_offloaded_segments(
+        &self,
+        path: &str,
+    ) -> impl Future<Output = Result<Vec<OffloadedSegment>, IggyError>> + Send;
+    fn save_transaction_marker(
+        &self,
+        marker: &TransactionMarker,
+        path: &str,
+    ) -> impl Future<Output = Result<(), IggyError>> + Send;
+    fn save_transaction_markers(
+        &self,
+        markers: &TransactionMarkers,
+        path: &str,
+    ) -> impl Future<Output = Result<(), IggyError>> + Send;
+    fn load_transaction_markers(
+        &self,
+        path: &str,
+    ) -> impl Future<Output = Result<TransactionMarkers, IggyError>> + Send;
 }
 
 #[derive(Debug)]
@@ -148,6 +206,7 @@ pub struct SystemStorage {
     pub topic: Arc<TopicStorageKind>,
     pub partition: Arc<PartitionStorageKind>,
     pub persister: Arc<PersisterKind>,
+    pub archiver: Option<Arc<ArchiverKind>>,
 }
 
 impl SystemStorage {
@@ -163,6 +222,7 @@ impl SystemStorage {
                 persister.clone(),
             ))),
             persister,
+            archiver: None,
         }
     }
 }
@@ -196,7 +256,7 @@ impl PartitionStorageKind {
             -> Result<(), IggyError>;
         async fn save(&self, partition: &mut Partition) -> Result<(), IggyError>;
         async fn delete(&self, partition: &Partition) -> Result<(), IggyError>;
-        async fn save_consumer_offset(&self, offset: u64, path: &str) -> Result<(), IggyError>;
+        async fn save_consumer_offset(&self, consumer_offset: &ConsumerOffset) -> Result<(), IggyError>;
         async fn load_consumer_offsets(
             &self,
             kind: ConsumerKind,
@@ -204,5 +264,46 @@ impl PartitionStorageKind {
         ) -> Result<Vec<ConsumerOffset>, IggyError>;
         async fn delete_consumer_offsets(&self, path: &str) -> Result<(), IggyError>;
         async fn delete_consumer_offset(&self, path: &str) -> Result<(), IggyError>;
+        async fn save_producer_sequence(
+            &self,
+            producer: &ProducerSequence,
+            path: &str
+        ) -> Result<(), IggyError>;
+        async fn load_producer_sequences(&self, path: &str)
+            -> Result<Vec<ProducerSequence>, IggyError>;
+        async fn save_delivery_attempts(
+            &self,
+            delivery_attempts: &DeliveryAttempts,
+            path: &str
+        ) -> Result<(), IggyError>;
+        async fn load_delivery_attempts(&self, path: &str)
+            -> Result<Vec<DeliveryAttempts>, IggyError>;
+        async fn delete_delivery_attempts(&self, path: &str) -> Result<(), IggyError>;
+        async fn save_delayed_messages(
+            &self,
+            delayed_messages: &DelayedMessages,
+            path: &str
+        ) -> Result<(), IggyError>;
+        async fn load_delayed_messages(&self, path: &str)
+            -> Result<Vec<DelayedMessages>, IggyError>;
+        async fn save_offloaded_segments(
+            &self,
+            offloaded_segments: &[OffloadedSegment],
+            path: &str
+        ) -> Result<(), IggyError>;
+        async fn load_offloaded_segments(&self, path: &str)
+            -> Result<Vec<OffloadedSegment>, IggyError>;
+        async fn save_transaction_marker(
+            &self,
+            marker: &TransactionMarker,
+            path: &str
+        ) -> Result<(), IggyError>;
+        async fn save_transaction_markers(
+            &self,
+            markers: &TransactionMarkers,
+            path: &str
+        ) -> Result<(), IggyError>;
+        async fn load_transaction_markers(&self, path: &str)
+            -> Result<TransactionMarkers, IggyError>;
     }
 }
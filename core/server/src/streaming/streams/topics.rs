@@ -20,10 +20,12 @@ use crate::streaming::streams::COMPONENT;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::topics::topic::Topic;
 use error_set::ErrContext;
+use iggy_common::CleanupPolicy;
 use iggy_common::CompressionAlgorithm;
 use iggy_common::IggyError;
 use iggy_common::IggyExpiry;
 use iggy_common::MaxTopicSize;
+use iggy_common::PartitioningHash;
 use iggy_common::locking::IggySharedMutFn;
 use iggy_common::{IdKind, Identifier};
 use std::sync::atomic::Ordering;
@@ -44,8 +46,13 @@ impl Stream {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: MaxTopicSize,
         replication_factor: u8,
+        cleanup_policy: CleanupPolicy,
+        partitioning_hash: PartitioningHash,
+        segment_max_age: IggyExpiry,
+        max_partition_size: MaxTopicSize,
     ) -> Result<u32, IggyError> {
         let max_topic_size = Topic::get_max_topic_size(max_topic_size, &self.config)?;
+        let max_partition_size = Topic::get_max_partition_size(max_partition_size, &self.config)?;
         if self.topics_ids.contains_key(name) {
             return Err(IggyError::TopicNameAlreadyExists(
                 name.to_owned(),
@@ -74,7 +81,7 @@ impl Stream {
             return Err(IggyError::TopicIdAlreadyExists(id, self.stream_id));
         }
 
-        let topic = Topic::create(
+        let mut topic = Topic::create(
             self.stream_id,
             id,
             name,
@@ -90,6 +97,10 @@ impl Stream {
             replication_factor,
         )
         .await?;
+        topic.cleanup_policy = cleanup_policy;
+        topic.partitioning_hash = partitioning_hash;
+        topic.segment_max_age = Topic::get_segment_max_age(segment_max_age, &self.config);
+        topic.max_partition_size = max_partition_size;
         topic.persist().await.with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to persist topic: {topic}")
         })?;
@@ -99,6 +110,7 @@ impl Stream {
         Ok(id)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn update_topic(
         &mut self,
         id: &Identifier,
@@ -107,9 +119,15 @@ impl Stream {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: MaxTopicSize,
         replication_factor: u8,
+        cleanup_policy: CleanupPolicy,
+        partitioning_hash: PartitioningHash,
+        segment_max_age: IggyExpiry,
+        max_partition_size: MaxTopicSize,
     ) -> Result<(), IggyError> {
         let message_expiry = Topic::get_message_expiry(message_expiry, &self.config);
         let max_topic_size = Topic::get_max_topic_size(max_topic_size, &self.config)?;
+        let segment_max_age = Topic::get_segment_max_age(segment_max_age, &self.config);
+        let max_partition_size = Topic::get_max_partition_size(max_partition_size, &self.config)?;
         let topic_id;
         {
             let topic = self.get_topic(id).with_error_context(|error| {
@@ -155,6 +173,10 @@ impl Stream {
             }
             topic.max_topic_size = max_topic_size;
             topic.replication_factor = replication_factor;
+            topic.cleanup_policy = cleanup_policy;
+            topic.partitioning_hash = partitioning_hash;
+            topic.segment_max_age = segment_max_age;
+            topic.max_partition_size = max_partition_size;
             topic.persist().await.with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to persist topic: {topic}")
             })?;
@@ -282,6 +304,7 @@ mod tests {
         },
     };
     use iggy_common::IggyByteSize;
+    use std::str::FromStr;
     use std::sync::Arc;
 
     #[tokio::test]
@@ -314,6 +337,10 @@ mod tests {
                 compression_algorithm,
                 max_topic_size,
                 1,
+                CleanupPolicy::Compact,
+                PartitioningHash::from_str("murmur2:jump").unwrap(),
+                IggyExpiry::ServerDefault,
+                MaxTopicSize::ServerDefault,
             )
             .await
             .unwrap();
@@ -324,6 +351,8 @@ mod tests {
         assert_eq!(topic.topic_id, topic_id);
         assert_eq!(topic.name, topic_name);
         assert_eq!(topic.compression_algorithm, compression_algorithm);
+        assert_eq!(topic.cleanup_policy, CleanupPolicy::Compact);
+        assert_eq!(topic.partitioning_hash.to_string(), "murmur2:jump");
 
         let topic = stream.get_topic(&Identifier::named(topic_name).unwrap());
         assert!(topic.is_ok());
@@ -0,0 +1,48 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::session::Session;
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::systems::system::System;
+use error_set::ErrContext;
+use iggy_common::{AuditRecord, IggyError};
+
+impl System {
+    pub async fn get_audit_log(
+        &self,
+        session: &Session,
+        start_id: u64,
+        count: u32,
+    ) -> Result<Vec<AuditRecord>, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .get_audit_log(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to get audit log for user with ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+
+        if !self.audit.is_enabled() {
+            return Err(IggyError::FeatureUnavailable);
+        }
+
+        Ok(self.audit.get_records(start_id, count).await)
+    }
+}
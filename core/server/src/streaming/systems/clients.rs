@@ -50,6 +50,7 @@ impl System {
             }
 
             self.metrics.decrement_clients(1);
+            self.quotas.delete_client(client_id);
             let client = client.unwrap();
             let client = client.read().await;
             consumer_groups = client
@@ -74,6 +75,8 @@ impl System {
                 )
                 .await
         }
+
+        self.abort_client_transactions(client_id).await;
     }
 
     pub async fn get_client(
@@ -24,6 +24,7 @@ use error_set::ErrContext;
 use iggy_common::Identifier;
 use iggy_common::IggyError;
 use iggy_common::locking::IggySharedMutFn;
+use iggy_common::{ConsumerGroupAssignment, ConsumerGroupAssignmentStrategy};
 use tokio::sync::RwLock;
 
 impl System {
@@ -80,6 +81,7 @@ impl System {
         topic_id: &Identifier,
         group_id: Option<u32>,
         name: &str,
+        assignment_strategy: ConsumerGroupAssignmentStrategy,
     ) -> Result<&RwLock<ConsumerGroup>, IggyError> {
         self.ensure_authenticated(session)?;
         {
@@ -98,7 +100,7 @@ impl System {
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
 
         topic
-            .create_consumer_group(group_id, name)
+            .create_consumer_group(group_id, name, assignment_strategy)
             .await
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to create consumer group with name: {name}")
@@ -273,6 +275,71 @@ impl System {
         })
     }
 
+    pub async fn sync_consumer_group(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        consumer_group_id: &Identifier,
+        generation: u32,
+    ) -> Result<ConsumerGroupAssignment, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self
+            .find_topic(session, stream_id, topic_id)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}",
+                )
+            })?;
+
+        self.permissioner.join_consumer_group(
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id,
+        ).with_error_context(|error| format!("{COMPONENT} (error: {error}) - permission denied to sync consumer group for user {} on stream ID: {}, topic ID: {}", session.get_user_id(), topic.stream_id, topic.topic_id))?;
+
+        let consumer_group = topic
+            .get_consumer_group(consumer_group_id)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - consumer group not found for group_id: {consumer_group_id}",
+                )
+            })?;
+        let mut consumer_group = consumer_group.write().await;
+        consumer_group
+            .sync_member(session.client_id, generation)
+            .await
+    }
+
+    pub async fn record_delivery_failure(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        consumer_group_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+    ) -> Result<u32, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self
+            .find_topic(session, stream_id, topic_id)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}",
+                )
+            })?;
+
+        self.permissioner.store_consumer_offset(
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id,
+        ).with_error_context(|error| format!("{COMPONENT} (error: {error}) - permission denied to record delivery failure for user {} on stream ID: {}, topic ID: {}", session.get_user_id(), topic.stream_id, topic.topic_id))?;
+
+        topic
+            .record_delivery_failure(consumer_group_id, partition_id, offset)
+            .await
+    }
+
     pub async fn leave_consumer_group_by_client(
         &self,
         stream_id: &Identifier,
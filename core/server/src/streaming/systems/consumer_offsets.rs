@@ -16,13 +16,127 @@
  * under the License.
  */
 
+use crate::state::command::EntryCommand;
+use crate::state::models::{DeleteConsumerOffsetWithIds, StoreConsumerOffsetsWithIds};
 use crate::streaming::session::Session;
 use crate::streaming::systems::COMPONENT;
 use crate::streaming::systems::system::System;
+use dashmap::DashMap;
 use error_set::ErrContext;
-use iggy_common::{Consumer, ConsumerOffsetInfo, Identifier, IggyError};
+use iggy_common::delete_consumer_offset::DeleteConsumerOffset;
+use iggy_common::store_consumer_offsets::StoreConsumerOffsets;
+use iggy_common::{
+    Consumer, ConsumerGroupOffsetReset, ConsumerKind, ConsumerOffsetInfo,
+    ConsumerOffsetsResetTarget, ConsumerPartitionOffset, Identifier, IggyError, IggyTimestamp,
+};
+use std::collections::BTreeMap;
+use tracing::trace;
+
+/// The consumer offsets committed since they were last appended to the state log, by the stream, topic and consumer.
+/// Appending a state entry on each commit would grow the never compacted state log without bound, as the consumers
+/// commit frequently, so the offsets are coalesced and appended periodically instead. The offsets are stored in the
+/// partition files right away anyway, thus only the state lags behind them.
+pub(crate) type UnsavedConsumerOffsets =
+    DashMap<(u32, u32, ConsumerKind, u32), UnsavedTopicConsumerOffsets>;
+
+#[derive(Debug)]
+pub(crate) struct UnsavedTopicConsumerOffsets {
+    user_id: u32,
+    committed_at: IggyTimestamp,
+    offsets: BTreeMap<u32, ConsumerPartitionOffset>,
+}
+
+/// The deleted consumer offset with the stream, topic, consumer and partition IDs resolved to the numeric ones.
+#[derive(Debug)]
+pub struct DeletedConsumerOffset {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub consumer_id: u32,
+    pub partition_id: u32,
+}
+
+/// The reset offsets of the consumer group with the stream, topic and consumer group IDs resolved to the numeric ones.
+#[derive(Debug)]
+pub struct ResetConsumerGroupOffsets {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub group_id: u32,
+    pub committed_at: IggyTimestamp,
+    pub target: ConsumerOffsetsResetTarget,
+    pub dry_run: bool,
+    pub resets: Vec<ConsumerGroupOffsetReset>,
+}
+
+impl ResetConsumerGroupOffsets {
+    /// Returns the state entries of the stored and deleted offsets, there are none for the dry run.
+    pub fn to_entry_commands(&self) -> Vec<EntryCommand> {
+        if self.dry_run {
+            return Vec::new();
+        }
+
+        let consumer = Consumer::group(Identifier::numeric(self.group_id).unwrap());
+        let stream_id = Identifier::numeric(self.stream_id).unwrap();
+        let topic_id = Identifier::numeric(self.topic_id).unwrap();
+        let mut offsets = Vec::new();
+        let mut entries = Vec::new();
+        for reset in &self.resets {
+            match reset.new_offset {
+                Some(offset) => {
+                    let metadata = match &self.target {
+                        ConsumerOffsetsResetTarget::Offsets(offsets) => offsets
+                            .iter()
+                            .find(|offset| offset.partition_id == reset.partition_id)
+                            .and_then(|offset| offset.metadata.clone()),
+                        _ => None,
+                    };
+                    offsets.push(ConsumerPartitionOffset::new(
+                        reset.partition_id,
+                        offset,
+                        metadata,
+                    ));
+                }
+                None if reset.old_offset.is_some() => {
+                    entries.push(EntryCommand::DeleteConsumerOffset(
+                        DeleteConsumerOffsetWithIds {
+                            stream_id: self.stream_id,
+                            topic_id: self.topic_id,
+                            consumer_id: self.group_id,
+                            partition_id: reset.partition_id,
+                            command: DeleteConsumerOffset {
+                                consumer: consumer.clone(),
+                                stream_id: stream_id.clone(),
+                                topic_id: topic_id.clone(),
+                                partition_id: Some(reset.partition_id),
+                            },
+                        },
+                    ));
+                }
+                None => {}
+            }
+        }
+
+        if !offsets.is_empty() {
+            entries.push(EntryCommand::StoreConsumerOffsets(
+                StoreConsumerOffsetsWithIds {
+                    stream_id: self.stream_id,
+                    topic_id: self.topic_id,
+                    consumer_id: self.group_id,
+                    committed_at: self.committed_at,
+                    command: StoreConsumerOffsets {
+                        consumer,
+                        stream_id,
+                        topic_id,
+                        offsets,
+                    },
+                },
+            ));
+        }
+        entries
+    }
+}
 
 impl System {
+    #[allow(clippy::too_many_arguments)]
     pub async fn store_consumer_offset(
         &self,
         session: &Session,
@@ -31,6 +145,7 @@ impl System {
         topic_id: &Identifier,
         partition_id: Option<u32>,
         offset: u64,
+        metadata: Option<String>,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
         let topic = self.find_topic(session, stream_id, topic_id)
@@ -41,9 +156,60 @@ impl System {
             topic.topic_id,
         )?;
 
-        topic
-            .store_consumer_offset(consumer, offset, partition_id, session.client_id)
-            .await
+        let committed_at = IggyTimestamp::now();
+        let kind = consumer.kind;
+        let (consumer_id, partition_id) = topic
+            .store_consumer_offset(
+                consumer,
+                offset,
+                metadata.clone(),
+                committed_at,
+                partition_id,
+                session.client_id,
+            )
+            .await?;
+        self.track_unsaved_consumer_offsets(
+            session.get_user_id(),
+            (topic.stream_id, topic.topic_id, kind, consumer_id),
+            committed_at,
+            vec![ConsumerPartitionOffset::new(partition_id, offset, metadata)],
+        );
+        Ok(())
+    }
+
+    pub async fn store_consumer_offsets(
+        &self,
+        session: &Session,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        offsets: Vec<ConsumerPartitionOffset>,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic with ID: {topic_id} was not found in stream with ID: {stream_id}"))?;
+        self.permissioner.store_consumer_offset(
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id,
+        ).with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - permission denied to store consumer offsets for user with ID: {}, consumer: {consumer} in topic with ID: {topic_id} and stream with ID: {stream_id}",
+                session.get_user_id(),
+            )
+        })?;
+
+        let committed_at = IggyTimestamp::now();
+        let consumer_id = topic
+            .store_consumer_offsets(consumer, &offsets, committed_at, session.client_id)
+            .await?;
+        self.track_unsaved_consumer_offsets(
+            session.get_user_id(),
+            (topic.stream_id, topic.topic_id, consumer.kind, consumer_id),
+            committed_at,
+            offsets,
+        );
+        Ok(())
     }
 
     pub async fn get_consumer_offset(
@@ -82,7 +248,7 @@ impl System {
         stream_id: &Identifier,
         topic_id: &Identifier,
         partition_id: Option<u32>,
-    ) -> Result<(), IggyError> {
+    ) -> Result<DeletedConsumerOffset, IggyError> {
         self.ensure_authenticated(session)?;
         let topic = self.find_topic(session, stream_id, topic_id)
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic with ID: {topic_id} was not found in stream with ID: {stream_id}"))?;
@@ -97,8 +263,157 @@ impl System {
             )
         })?;
 
-        topic
+        let kind = consumer.kind;
+        let (consumer_id, partition_id) = topic
             .delete_consumer_offset(consumer, partition_id, session.client_id)
-            .await
+            .await?;
+        self.discard_unsaved_consumer_offsets(
+            (topic.stream_id, topic.topic_id, kind, consumer_id),
+            &[partition_id],
+        );
+        Ok(DeletedConsumerOffset {
+            stream_id: topic.stream_id,
+            topic_id: topic.topic_id,
+            consumer_id,
+            partition_id,
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn reset_consumer_group_offsets(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        target: ConsumerOffsetsResetTarget,
+        dry_run: bool,
+        force: bool,
+    ) -> Result<ResetConsumerGroupOffsets, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic with ID: {topic_id} was not found in stream with ID: {stream_id}"))?;
+        self.permissioner.store_consumer_offset(
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id,
+        ).with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - permission denied to reset consumer group offsets for user with ID: {}, group ID: {group_id} in topic with ID: {topic_id} and stream with ID: {stream_id}",
+                session.get_user_id(),
+            )
+        })?;
+
+        let committed_at = IggyTimestamp::now();
+        let (group_id, resets) = topic
+            .reset_consumer_group_offsets(group_id, &target, dry_run, force, committed_at)
+            .await?;
+        if !dry_run {
+            let partition_ids = resets
+                .iter()
+                .map(|reset| reset.partition_id)
+                .collect::<Vec<_>>();
+            self.discard_unsaved_consumer_offsets(
+                (
+                    topic.stream_id,
+                    topic.topic_id,
+                    ConsumerKind::ConsumerGroup,
+                    group_id,
+                ),
+                &partition_ids,
+            );
+        }
+        Ok(ResetConsumerGroupOffsets {
+            stream_id: topic.stream_id,
+            topic_id: topic.topic_id,
+            group_id,
+            committed_at,
+            target,
+            dry_run,
+            resets,
+        })
+    }
+
+    /// Appends the consumer offsets committed since the last call to the state log, as a single entry
+    /// for each consumer in the topic. Returns the number of the appended entries.
+    pub async fn persist_consumer_offsets(&self) -> Result<usize, IggyError> {
+        let keys = self
+            .unsaved_consumer_offsets
+            .iter()
+            .map(|entry| *entry.key())
+            .collect::<Vec<_>>();
+        let mut persisted_entries = 0;
+        for key in keys {
+            let Some(((stream_id, topic_id, kind, consumer_id), unsaved)) =
+                self.unsaved_consumer_offsets.remove(&key)
+            else {
+                continue;
+            };
+
+            let entry = EntryCommand::StoreConsumerOffsets(StoreConsumerOffsetsWithIds {
+                stream_id,
+                topic_id,
+                consumer_id,
+                committed_at: unsaved.committed_at,
+                command: StoreConsumerOffsets {
+                    consumer: Consumer {
+                        kind,
+                        id: Identifier::numeric(consumer_id)?,
+                    },
+                    stream_id: Identifier::numeric(stream_id)?,
+                    topic_id: Identifier::numeric(topic_id)?,
+                    offsets: unsaved.offsets.into_values().collect(),
+                },
+            });
+            self.state
+                .apply(unsaved.user_id, &entry)
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to apply store consumer offsets: {entry}")
+                })?;
+            persisted_entries += 1;
+        }
+
+        if persisted_entries > 0 {
+            trace!("Appended {persisted_entries} consumer offsets entries to the state.");
+        }
+        Ok(persisted_entries)
+    }
+
+    fn track_unsaved_consumer_offsets(
+        &self,
+        user_id: u32,
+        key: (u32, u32, ConsumerKind, u32),
+        committed_at: IggyTimestamp,
+        offsets: Vec<ConsumerPartitionOffset>,
+    ) {
+        let mut unsaved = self.unsaved_consumer_offsets.entry(key).or_insert_with(|| {
+            UnsavedTopicConsumerOffsets {
+                user_id,
+                committed_at,
+                offsets: BTreeMap::new(),
+            }
+        });
+        unsaved.user_id = user_id;
+        unsaved.committed_at = committed_at;
+        for offset in offsets {
+            unsaved.offsets.insert(offset.partition_id, offset);
+        }
+    }
+
+    /// Discards the unsaved offsets replaced by the entry appended to the state log right away,
+    /// otherwise they would be appended later and override it.
+    fn discard_unsaved_consumer_offsets(
+        &self,
+        key: (u32, u32, ConsumerKind, u32),
+        partition_ids: &[u32],
+    ) {
+        if let Some(mut unsaved) = self.unsaved_consumer_offsets.get_mut(&key) {
+            for partition_id in partition_ids {
+                unsaved.offsets.remove(partition_id);
+            }
+        }
+        self.unsaved_consumer_offsets
+            .remove_if(&key, |_, unsaved| unsaved.offsets.is_empty());
     }
 }
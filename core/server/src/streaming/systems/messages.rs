@@ -17,18 +17,135 @@
  */
 
 use crate::binary::handlers::messages::poll_messages_handler::IggyPollMetadata;
-use crate::streaming::segments::{IggyIndexesMut, IggyMessagesBatchMut, IggyMessagesBatchSet};
+use crate::streaming::segments::{
+    self, IggyIndexesMut, IggyMessagesBatchMut, IggyMessagesBatchSet, MESSAGE_KEY_HEADER_KEY,
+};
 use crate::streaming::session::Session;
 use crate::streaming::systems::COMPONENT;
-use crate::streaming::systems::system::System;
+use crate::streaming::systems::system::{SharedSystem, System};
+use crate::streaming::topics::topic::Topic;
+use crate::streaming::transactions::markers::{
+    TRANSACTION_HEADER_KEY, TRANSACTION_MARKER_HEADER_KEY,
+};
 use crate::streaming::utils::PooledBuffer;
+use crate::streaming::utils::compression::{self, COMPRESSION_HEADER_KEY, COMPRESSION_HEADER_SIZE};
 use error_set::ErrContext;
+use futures::future::select_all;
 use iggy_common::{
-    BytesSerializable, Confirmation, Consumer, EncryptorKind, IGGY_MESSAGE_HEADER_SIZE, Identifier,
-    IggyError, Partitioning, PollingStrategy,
+    BytesSerializable, CompressionAlgorithm, Confirmation, Consumer, ConsumerKind, EncryptorKind,
+    IGGY_MESSAGE_HEADER_SIZE, Identifier, IggyDuration, IggyError, IggyMessageView, IsolationLevel,
+    MAX_PAYLOAD_SIZE, MessageFilter, Partitioning, PartitioningKind, PollingStrategy,
 };
+use tokio::sync::RwLockReadGuard;
+use tokio::time::{Instant, timeout};
 use tracing::{error, trace};
 
+/// The user header keys of the headers appended by the server, which can't be sent by the clients.
+const RESERVED_HEADER_KEYS: [&str; 4] = [
+    COMPRESSION_HEADER_KEY,
+    TRANSACTION_HEADER_KEY,
+    TRANSACTION_MARKER_HEADER_KEY,
+    MESSAGE_KEY_HEADER_KEY,
+];
+
+impl SharedSystem {
+    /// Polls the messages, and if there are fewer than `min_bytes` (but at least one) of them, waits up to `max_wait`
+    /// (capped by the configured `long_polling.max_wait`) for the messages to be appended before polling again.
+    /// The system is unlocked while waiting, and the returned guard is the one under which the messages were polled.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn long_poll_messages(
+        &self,
+        session: &Session,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        args: PollingArgs,
+    ) -> Result<
+        (
+            RwLockReadGuard<'_, System>,
+            IggyPollMetadata,
+            IggyMessagesBatchSet,
+        ),
+        IggyError,
+    > {
+        let mut system = self.read().await;
+        let max_wait = args
+            .max_wait
+            .get_duration()
+            .min(system.config.long_polling.max_wait.get_duration());
+        if max_wait.is_zero() {
+            let args = PollingArgs {
+                min_bytes: 0,
+                ..args
+            };
+            let (metadata, batch_set) = system
+                .poll_messages(session, consumer, stream_id, topic_id, partition_id, args)
+                .await?;
+            return Ok((system, metadata, batch_set));
+        }
+
+        // The consumer group member might be assigned any partition of the topic.
+        let notified_partition_id = match consumer.kind {
+            ConsumerKind::Consumer => partition_id,
+            ConsumerKind::ConsumerGroup => None,
+        };
+        let deadline = Instant::now() + max_wait;
+        loop {
+            let notifications = system
+                .find_topic(session, stream_id, topic_id)?
+                .get_messages_appended_notifications(notified_partition_id)
+                .await;
+            // The notifications are enabled before polling, so that the messages appended in the meantime are not missed.
+            let mut notified = notifications
+                .iter()
+                .map(|notification| Box::pin(notification.notified()))
+                .collect::<Vec<_>>();
+            for notified in &mut notified {
+                notified.as_mut().enable();
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let min_bytes = if remaining.is_zero() || notified.is_empty() {
+                0
+            } else {
+                args.min_bytes.max(1)
+            };
+            // The consumer group member polls the next of its partitions each time, so all of them are tried.
+            let probes = match consumer.kind {
+                ConsumerKind::Consumer => 1,
+                ConsumerKind::ConsumerGroup => notifications.len().max(1),
+            };
+            for _ in 0..probes {
+                let (metadata, batch_set) = system
+                    .poll_messages(
+                        session,
+                        consumer,
+                        stream_id,
+                        topic_id,
+                        partition_id,
+                        PollingArgs {
+                            min_bytes,
+                            ..args.clone()
+                        },
+                    )
+                    .await?;
+                if batch_set.size() >= min_bytes {
+                    return Ok((system, metadata, batch_set));
+                }
+            }
+
+            drop(system);
+            trace!(
+                "Waiting up to {} ms for the messages to be appended, stream: {stream_id}, topic: {topic_id}, consumer: {consumer}",
+                remaining.as_millis()
+            );
+            let _ = timeout(remaining, select_all(notified)).await;
+            system = self.read().await;
+        }
+    }
+}
+
 impl System {
     pub async fn poll_messages(
         &self,
@@ -44,6 +161,10 @@ impl System {
             return Err(IggyError::InvalidMessagesCount);
         }
 
+        if args.isolation_level == IsolationLevel::ReadCommitted {
+            self.abort_expired_transactions().await;
+        }
+
         let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
         self.permissioner
             .poll_messages(session.get_user_id(), topic.stream_id, topic.topic_id)
@@ -67,13 +188,37 @@ impl System {
         };
 
         let (metadata, batch_set) = topic
-            .get_messages(polling_consumer, partition_id, args.strategy, args.count)
+            .get_messages(
+                polling_consumer,
+                partition_id,
+                args.strategy,
+                args.count,
+                args.filter.as_ref(),
+                args.isolation_level,
+            )
             .await?;
 
-        if args.auto_commit && !batch_set.is_empty() {
-            let offset = batch_set
-                .last_offset()
-                .expect("Batch set should have at least one batch");
+        // For filtered polls, the offset of the last scanned (not necessarily returned) message
+        // is committed, so that the skipped messages are not scanned again by the next poll.
+        let offset_to_commit = match metadata.next_offset {
+            Some(next_offset) if next_offset > 0 && next_offset <= metadata.current_offset + 1 => {
+                Some(next_offset - 1)
+            }
+            Some(_) => None,
+            None => batch_set.last_offset(),
+        };
+
+        // The batch smaller than the requested minimum is discarded by the long polling, so neither the offset
+        // is committed nor the due delayed messages are removed, as they haven't been returned yet.
+        let is_returned = batch_set.size() >= args.min_bytes;
+        if is_returned {
+            topic
+                .remove_delayed_messages(polling_consumer, partition_id, &metadata.due_delayed_messages)
+                .await
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to remove delayed messages, polling consumer: {polling_consumer}, partition ID: {partition_id}"))?;
+        }
+
+        if let Some(offset) = offset_to_commit.filter(|_| args.auto_commit && is_returned) {
             trace!(
                 "Last offset: {} will be automatically stored for {}, stream: {}, topic: {}, partition: {}",
                 offset, consumer, stream_id, topic_id, partition_id
@@ -89,6 +234,7 @@ impl System {
         } else {
             batch_set
         };
+        let batch_set = Self::decompress_messages(batch_set)?;
 
         Ok((metadata, batch_set))
     }
@@ -114,14 +260,11 @@ impl System {
             topic.stream_id,
             topic.topic_id
         ))?;
+        Self::validate_reserved_headers(&messages)?;
+        self.validate_messages_schemas(topic, &messages)?;
         let messages_count = messages.count();
-
-        // Encrypt messages if encryptor is configured
-        let messages = if let Some(encryptor) = &self.encryptor {
-            self.encrypt_messages(messages, encryptor.as_ref())?
-        } else {
-            messages
-        };
+        let messages = Self::mark_message_keys(topic, partitioning, messages);
+        let messages = self.compress_and_encrypt_messages(topic, messages)?;
 
         topic
             .append_messages(partitioning, messages, confirmation)
@@ -155,6 +298,214 @@ impl System {
         Ok(())
     }
 
+    /// Rejects the messages with any of the user headers reserved for the server, as they would be
+    /// misinterpreted when the messages are read, e.g. as the compressed ones or the transaction markers.
+    pub(crate) fn validate_reserved_headers(
+        messages: &IggyMessagesBatchMut,
+    ) -> Result<(), IggyError> {
+        for message in messages.iter() {
+            let Some(user_headers) = message.user_headers() else {
+                continue;
+            };
+
+            if let Some(key) = find_reserved_header_key(user_headers) {
+                error!(
+                    "{COMPONENT} - message with ID: {} has reserved user header: {key}",
+                    message.header().id()
+                );
+                return Err(IggyError::ReservedHeaderKey(key.to_owned()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates the payloads of the messages against the schemas they reference, unless disabled.
+    pub(crate) fn validate_messages_schemas(
+        &self,
+        topic: &Topic,
+        messages: &IggyMessagesBatchMut,
+    ) -> Result<(), IggyError> {
+        if !self.config.schema.validate_messages {
+            return Ok(());
+        }
+
+        topic.validate_messages(messages).with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to validate messages for stream ID: {}, topic ID: {}",
+                topic.stream_id, topic.topic_id
+            )
+        })
+    }
+
+    /// Appends the message key user header to each message sent to the compacted topic with the messages key
+    /// partitioning, before it's compressed and encrypted, so that the compactor can retain the latest message per key.
+    pub(crate) fn mark_message_keys(
+        topic: &Topic,
+        partitioning: &Partitioning,
+        batch: IggyMessagesBatchMut,
+    ) -> IggyMessagesBatchMut {
+        if !topic.cleanup_policy.is_compact() || partitioning.kind != PartitioningKind::MessagesKey
+        {
+            return batch;
+        }
+
+        let key = &partitioning.value;
+        let key_header_size = segments::message_key_header_size(key);
+        let count = batch.count();
+        let mut marked_messages =
+            PooledBuffer::with_capacity(batch.size() as usize + count as usize * key_header_size);
+        let mut indexes = IggyIndexesMut::with_capacity(count as usize, 0);
+
+        for message in batch.iter() {
+            let mut header = message.header().to_header();
+            header.user_headers_length += key_header_size as u32;
+            marked_messages.extend_from_slice(&header.to_bytes());
+            marked_messages.extend_from_slice(message.payload());
+            if let Some(user_headers) = message.user_headers() {
+                marked_messages.extend_from_slice(user_headers);
+            }
+            segments::write_message_key_header(key, &mut marked_messages);
+            indexes.insert(0, marked_messages.len() as u32, 0);
+        }
+
+        IggyMessagesBatchMut::from_indexes_and_messages(count, indexes, marked_messages)
+    }
+
+    /// Compresses (using the algorithm of the topic) and encrypts (if encryptor is configured) the messages to be appended.
+    pub(crate) fn compress_and_encrypt_messages(
+        &self,
+        topic: &Topic,
+        messages: IggyMessagesBatchMut,
+    ) -> Result<IggyMessagesBatchMut, IggyError> {
+        // Compress messages before the encryption, as the encrypted payload can't be compressed
+        let compression_algorithm = self
+            .config
+            .compression
+            .resolve_algorithm(topic.compression_algorithm);
+        let messages = if compression_algorithm != CompressionAlgorithm::None {
+            Self::compress_messages(
+                messages,
+                compression_algorithm,
+                self.config.compression.zstd_level,
+            )?
+        } else {
+            messages
+        };
+
+        // Encrypt messages if encryptor is configured
+        if let Some(encryptor) = &self.encryptor {
+            self.encrypt_messages(messages, encryptor.as_ref())
+        } else {
+            Ok(messages)
+        }
+    }
+
+    /// Compresses the payload of each message and marks it with the compression user header.
+    /// The messages, for which the compression doesn't reduce the size, are stored as they are.
+    fn compress_messages(
+        batch: IggyMessagesBatchMut,
+        algorithm: CompressionAlgorithm,
+        zstd_level: i32,
+    ) -> Result<IggyMessagesBatchMut, IggyError> {
+        let count = batch.count();
+        let mut compressed_messages = PooledBuffer::with_capacity(batch.size() as usize);
+        let mut indexes = IggyIndexesMut::with_capacity(count as usize, 0);
+
+        for message in batch.iter() {
+            let payload = message.payload();
+            let compressed_payload = compression::compress(algorithm, zstd_level, payload)?;
+            if compressed_payload.len() + COMPRESSION_HEADER_SIZE >= payload.len() {
+                Self::write_message(&message, &mut compressed_messages);
+            } else {
+                let mut header = message.header().to_header();
+                header.payload_length = compressed_payload.len() as u32;
+                header.user_headers_length += COMPRESSION_HEADER_SIZE as u32;
+                compressed_messages.extend_from_slice(&header.to_bytes());
+                compressed_messages.extend_from_slice(&compressed_payload);
+                if let Some(user_headers) = message.user_headers() {
+                    compressed_messages.extend_from_slice(user_headers);
+                }
+                compression::write_compression_header(algorithm, &mut compressed_messages);
+            }
+            indexes.insert(0, compressed_messages.len() as u32, 0);
+        }
+
+        Ok(IggyMessagesBatchMut::from_indexes_and_messages(
+            count,
+            indexes,
+            compressed_messages,
+        ))
+    }
+
+    /// Decompresses the payload of each message marked with the compression user header,
+    /// regardless of the current compression algorithm of the topic.
+    fn decompress_messages(
+        batches: IggyMessagesBatchSet,
+    ) -> Result<IggyMessagesBatchSet, IggyError> {
+        let is_compressed = |message: &IggyMessageView| {
+            message
+                .user_headers()
+                .and_then(compression::split_compression_header)
+                .is_some()
+        };
+        if !batches
+            .iter()
+            .any(|batch| batch.iter().any(|message| is_compressed(&message)))
+        {
+            return Ok(batches);
+        }
+
+        let mut decompressed_batches = Vec::with_capacity(batches.containers_count());
+        for batch in batches.into_inner() {
+            if !batch.iter().any(|message| is_compressed(&message)) {
+                decompressed_batches.push(batch);
+                continue;
+            }
+
+            let count = batch.count();
+            let mut decompressed_messages = PooledBuffer::with_capacity(batch.size() as usize * 2);
+            let mut indexes = IggyIndexesMut::with_capacity(count as usize, 0);
+
+            for message in batch.iter() {
+                let user_headers = message.user_headers();
+                match user_headers.and_then(compression::split_compression_header) {
+                    Some((algorithm, user_headers)) => {
+                        let payload = compression::decompress(
+                            algorithm,
+                            message.payload(),
+                            MAX_PAYLOAD_SIZE as usize,
+                        )?;
+                        let mut header = message.header().to_header();
+                        header.payload_length = payload.len() as u32;
+                        header.user_headers_length = user_headers.len() as u32;
+                        decompressed_messages.extend_from_slice(&header.to_bytes());
+                        decompressed_messages.extend_from_slice(&payload);
+                        decompressed_messages.extend_from_slice(user_headers);
+                    }
+                    None => Self::write_message(&message, &mut decompressed_messages),
+                }
+                indexes.insert(0, decompressed_messages.len() as u32, 0);
+            }
+
+            decompressed_batches.push(IggyMessagesBatchMut::from_indexes_and_messages(
+                count,
+                indexes,
+                decompressed_messages,
+            ));
+        }
+
+        Ok(IggyMessagesBatchSet::from_vec(decompressed_batches))
+    }
+
+    pub(crate) fn write_message(message: &IggyMessageView, buffer: &mut PooledBuffer) {
+        message.header().write_to_buffer(buffer);
+        buffer.extend_from_slice(message.payload());
+        if let Some(user_headers) = message.user_headers() {
+            buffer.extend_from_slice(user_headers);
+        }
+    }
+
     async fn decrypt_messages(
         &self,
         batches: IggyMessagesBatchSet,
@@ -194,7 +545,7 @@ impl System {
         Ok(IggyMessagesBatchSet::from_vec(decrypted_batches))
     }
 
-    fn encrypt_messages(
+    pub(crate) fn encrypt_messages(
         &self,
         batch: IggyMessagesBatchMut,
         encryptor: &EncryptorKind,
@@ -238,11 +589,17 @@ impl System {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct PollingArgs {
     pub strategy: PollingStrategy,
     pub count: u32,
     pub auto_commit: bool,
+    pub filter: Option<MessageFilter>,
+    pub isolation_level: IsolationLevel,
+    /// Maximum time to wait for the messages to be appended when using `SharedSystem::long_poll_messages`.
+    pub max_wait: IggyDuration,
+    /// Minimum size of the messages to wait for, the smaller polled batches are not auto-committed, so that they can be polled again.
+    pub min_bytes: u32,
 }
 
 impl PollingArgs {
@@ -251,6 +608,153 @@ impl PollingArgs {
             strategy,
             count,
             auto_commit,
+            filter: None,
+            isolation_level: IsolationLevel::default(),
+            max_wait: IggyDuration::default(),
+            min_bytes: 0,
+        }
+    }
+
+    pub fn with_filter(self, filter: Option<MessageFilter>) -> Self {
+        Self { filter, ..self }
+    }
+
+    pub fn with_isolation_level(self, isolation_level: IsolationLevel) -> Self {
+        Self {
+            isolation_level,
+            ..self
+        }
+    }
+
+    pub fn with_long_polling(self, max_wait: IggyDuration, min_bytes: u32) -> Self {
+        Self {
+            max_wait,
+            min_bytes,
+            ..self
+        }
+    }
+}
+
+/// Returns the first reserved key of the serialized user headers. The malformed user headers are skipped,
+/// as they are rejected by the regular validation.
+fn find_reserved_header_key(user_headers: &[u8]) -> Option<&'static str> {
+    let mut position = 0;
+    while position + 4 <= user_headers.len() {
+        let key_length =
+            u32::from_le_bytes(user_headers[position..position + 4].try_into().unwrap()) as usize;
+        let key = user_headers.get(position + 4..position + 4 + key_length)?;
+        if let Some(reserved_key) = RESERVED_HEADER_KEYS
+            .iter()
+            .find(|reserved_key| reserved_key.as_bytes() == key)
+        {
+            return Some(reserved_key);
+        }
+
+        // The key is followed by the 1-byte kind and the 4-byte length of the value.
+        position += 4 + key_length + 1;
+        let value_length = user_headers.get(position..position + 4)?;
+        let value_length = u32::from_le_bytes(value_length.try_into().unwrap()) as usize;
+        position += 4 + value_length;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::SystemConfig;
+    use crate::streaming::utils::MemoryPool;
+    use bytes::Bytes;
+    use iggy_common::{HeaderKey, HeaderValue, IggyMessage, Sizeable};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    #[test]
+    fn messages_should_be_compressed_and_decompressed() {
+        MemoryPool::init_pool(Arc::new(SystemConfig::default()));
+        let user_headers = HashMap::from([(
+            HeaderKey::new("key").unwrap(),
+            HeaderValue::from_uint8(1).unwrap(),
+        )]);
+        let messages = vec![
+            IggyMessage::builder()
+                .id(1)
+                .payload(Bytes::from("iggy ".repeat(100)))
+                .user_headers(user_headers)
+                .build()
+                .unwrap(),
+            IggyMessage::builder()
+                .id(2)
+                .payload(Bytes::from("tiny"))
+                .build()
+                .unwrap(),
+        ];
+        let messages_size = messages
+            .iter()
+            .map(|message| message.get_size_bytes().as_bytes_u32())
+            .sum();
+
+        for algorithm in [
+            CompressionAlgorithm::Gzip,
+            CompressionAlgorithm::Zstd,
+            CompressionAlgorithm::Lz4,
+            CompressionAlgorithm::Snappy,
+        ] {
+            let batch = IggyMessagesBatchMut::from_messages(&messages, messages_size);
+            let compressed = System::compress_messages(batch, algorithm, 3).unwrap();
+            assert_eq!(compressed.count(), 2);
+            assert!(compressed.size() < messages_size);
+            let compressed_message = compressed.get(0).unwrap();
+            let (compressed_with, _) =
+                compression::split_compression_header(compressed_message.user_headers().unwrap())
+                    .unwrap();
+            assert_eq!(compressed_with, algorithm);
+            assert!(compressed.get(1).unwrap().user_headers().is_none());
+
+            let decompressed =
+                System::decompress_messages(IggyMessagesBatchSet::from(compressed)).unwrap();
+            assert_eq!(decompressed.count(), 2);
+            for (index, message) in messages.iter().enumerate() {
+                let decompressed_message = decompressed.get(index).unwrap();
+                assert_eq!(decompressed_message.payload(), message.payload.as_ref());
+                assert_eq!(
+                    decompressed_message.user_headers(),
+                    message.user_headers.as_deref()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn messages_with_reserved_user_headers_should_be_rejected() {
+        MemoryPool::init_pool(Arc::new(SystemConfig::default()));
+        let create_batch = |key: &str| {
+            let user_headers = HashMap::from([
+                (
+                    HeaderKey::new("key").unwrap(),
+                    HeaderValue::from_uint8(1).unwrap(),
+                ),
+                (
+                    HeaderKey::new(key).unwrap(),
+                    HeaderValue::from_uint64(1).unwrap(),
+                ),
+            ]);
+            let message = IggyMessage::builder()
+                .id(1)
+                .payload(Bytes::from("payload"))
+                .user_headers(user_headers)
+                .build()
+                .unwrap();
+            let message_size = message.get_size_bytes().as_bytes_u32();
+            IggyMessagesBatchMut::from_messages(&[message], message_size)
+        };
+
+        assert!(System::validate_reserved_headers(&create_batch("other")).is_ok());
+        for key in RESERVED_HEADER_KEYS {
+            assert!(matches!(
+                System::validate_reserved_headers(&create_batch(key)),
+                Err(IggyError::ReservedHeaderKey(reserved_key)) if reserved_key == key
+            ));
         }
     }
 }
@@ -0,0 +1,56 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::state::system::SystemState;
+use crate::streaming::session::Session;
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::systems::system::System;
+use error_set::ErrContext;
+use iggy_common::{IggyError, ServerMetadata};
+
+impl System {
+    pub async fn export_metadata(
+        &self,
+        session: &Session,
+        include_password_hashes: bool,
+    ) -> Result<ServerMetadata, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .export_metadata(session.get_user_id(), include_password_hashes)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to export metadata for user with ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+
+        let entries = self
+            .state
+            .load_entries()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to load state entries")
+            })?;
+        let state = SystemState::init(entries)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to initialize system state")
+            })?;
+        Ok(state.to_metadata(include_password_hashes))
+    }
+}
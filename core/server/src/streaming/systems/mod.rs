@@ -16,13 +16,19 @@
  * under the License.
  */
 
+pub mod audit;
 pub mod clients;
 pub mod consumer_groups;
 pub mod consumer_offsets;
 pub mod info;
 pub mod messages;
+pub mod metadata;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod producers;
+pub mod quotas;
+pub mod roles;
+pub mod schemas;
 pub mod segments;
 pub mod snapshot;
 pub mod stats;
@@ -30,6 +36,7 @@ pub mod storage;
 pub mod streams;
 pub mod system;
 pub mod topics;
+pub mod transactions;
 pub mod users;
 
 pub const COMPONENT: &str = "STREAMING_SYSTEMS";
@@ -16,12 +16,14 @@
  * under the License.
  */
 
+use crate::streaming::partitions::partition::Partition;
 use crate::streaming::session::Session;
 use crate::streaming::systems::COMPONENT;
 use crate::streaming::systems::system::System;
 use error_set::ErrContext;
 use iggy_common::Identifier;
 use iggy_common::IggyError;
+use iggy_common::locking::IggySharedMut;
 
 impl System {
     pub async fn create_partitions(
@@ -110,4 +112,36 @@ impl System {
         }
         Ok(())
     }
+
+    /// Returns the partition to be moved to the given data directory, which must be one of the configured ones.
+    /// The move itself is performed without holding the system lock, as copying the segments may take a while.
+    pub fn find_partition_to_move(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        data_directory: &str,
+    ) -> Result<IggySharedMut<Partition>, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .move_partition(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to move partition for user {}",
+                    session.get_user_id()
+                )
+            })?;
+        if !self
+            .config
+            .get_data_directories()
+            .iter()
+            .any(|configured_data_directory| configured_data_directory == data_directory)
+        {
+            return Err(IggyError::InvalidDataDirectory(data_directory.to_string()));
+        }
+
+        let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
+        topic.get_partition(partition_id)
+    }
 }
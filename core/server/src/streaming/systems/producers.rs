@@ -0,0 +1,109 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::state::system::ProducerState;
+use crate::streaming::segments::IggyMessagesBatchMut;
+use crate::streaming::session::Session;
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::systems::system::System;
+use error_set::ErrContext;
+use iggy_common::locking::IggySharedMutFn;
+use iggy_common::{
+    Confirmation, Identifier, IggyError, Partitioning, PartitioningKind, ProducerInfo,
+    ProducerSequence,
+};
+use tracing::{info, trace};
+
+impl System {
+    pub(crate) async fn load_producers(&self, producers: Vec<ProducerState>) {
+        info!("Loading {} idempotent producer(s)...", producers.len());
+        let mut producer_manager = self.producer_manager.write().await;
+        for producer in producers {
+            producer_manager.restore_producer(producer.id, producer.epoch, producer.user_id);
+        }
+    }
+
+    pub async fn init_producer(
+        &self,
+        session: &Session,
+        producer_id: u64,
+    ) -> Result<ProducerInfo, IggyError> {
+        self.ensure_authenticated(session)?;
+        let producer = self
+            .producer_manager
+            .write()
+            .await
+            .init_producer(producer_id, session.get_user_id())?;
+        trace!(
+            "Initialized producer with ID: {}, epoch: {} for user with ID: {}",
+            producer.id,
+            producer.epoch,
+            session.get_user_id()
+        );
+        Ok(producer)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn append_idempotent_messages(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        producer: &ProducerSequence,
+        messages: IggyMessagesBatchMut,
+        confirmation: Option<Confirmation>,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream_id: {stream_id}, topic_id: {topic_id}"))?;
+        self.permissioner.append_messages(
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id
+        ).with_error_context(|error| format!(
+            "{COMPONENT} (error: {error}) - permission denied to append messages for user {} on stream ID: {}, topic ID: {}",
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id
+        ))?;
+
+        // The balanced partitioning could route the retried batch to another partition.
+        if partitioning.kind == PartitioningKind::Balanced {
+            return Err(IggyError::InvalidIdempotentPartitioning);
+        }
+
+        self.producer_manager
+            .read()
+            .await
+            .validate_producer(producer, session.get_user_id())?;
+
+        Self::validate_reserved_headers(&messages)?;
+        self.validate_messages_schemas(topic, &messages)?;
+        let messages_count = messages.count();
+        let messages = Self::mark_message_keys(topic, partitioning, messages);
+        let messages = self.compress_and_encrypt_messages(topic, messages)?;
+        let appended = topic
+            .append_idempotent_messages(partitioning, producer, messages, confirmation)
+            .await?;
+
+        if appended {
+            self.metrics.increment_messages(messages_count as u64);
+        }
+        Ok(())
+    }
+}
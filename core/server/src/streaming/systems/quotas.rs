@@ -0,0 +1,57 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use std::time::Duration;
+
+impl System {
+    /// Records the produced messages against the quotas of the session's user and client,
+    /// and returns the delay by which the response should be throttled.
+    pub fn throttle_produced(
+        &self,
+        session: &Session,
+        messages_count: u32,
+        size_bytes: u64,
+    ) -> Duration {
+        let user_id = session.get_user_id();
+        let quotas = self
+            .users
+            .get(&user_id)
+            .and_then(|user| user.quotas.as_ref());
+        self.quotas.record_produced(
+            user_id,
+            session.client_id,
+            quotas,
+            messages_count,
+            size_bytes,
+        )
+    }
+
+    /// Records the consumed messages against the quotas of the session's user and client,
+    /// and returns the delay by which the response should be throttled.
+    pub fn throttle_consumed(&self, session: &Session, size_bytes: u64) -> Duration {
+        let user_id = session.get_user_id();
+        let quotas = self
+            .users
+            .get(&user_id)
+            .and_then(|user| user.quotas.as_ref());
+        self.quotas
+            .record_consumed(user_id, session.client_id, quotas, size_bytes)
+    }
+}
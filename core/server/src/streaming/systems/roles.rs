@@ -0,0 +1,167 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::session::Session;
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::systems::system::System;
+use error_set::ErrContext;
+use iggy_common::{Identifier, IggyError, Role, RoleRule};
+use tracing::{error, info};
+
+impl System {
+    pub(crate) fn load_roles(&mut self, roles: Vec<Role>) {
+        info!("Loading {} role(s)...", roles.len());
+        for role in roles {
+            self.permissioner.set_role(role.clone());
+            self.roles.insert(role.name.clone(), role);
+        }
+    }
+
+    pub fn get_roles(&self, session: &Session) -> Result<Vec<&Role>, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .get_users(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to get roles for user with id: {}",
+                    session.get_user_id()
+                )
+            })?;
+        Ok(self.roles.values().collect())
+    }
+
+    pub async fn create_role(
+        &mut self,
+        session: &Session,
+        name: &str,
+        rules: Vec<RoleRule>,
+    ) -> Result<&Role, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .update_permissions(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to create role for user with id: {}",
+                    session.get_user_id()
+                )
+            })?;
+
+        if self.roles.contains_key(name) {
+            error!("Role: {name} already exists.");
+            return Err(IggyError::RoleAlreadyExists(name.to_owned()));
+        }
+
+        let role = Role::new(name, rules);
+        self.permissioner.set_role(role.clone());
+        self.roles.insert(name.to_owned(), role);
+        info!("Created role: {name}.");
+        self.get_role(name)
+    }
+
+    pub async fn update_role(
+        &mut self,
+        session: &Session,
+        name: &str,
+        rules: Vec<RoleRule>,
+    ) -> Result<&Role, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .update_permissions(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to update role for user with id: {}",
+                    session.get_user_id()
+                )
+            })?;
+
+        let role = self
+            .roles
+            .get_mut(name)
+            .ok_or_else(|| IggyError::RoleNotFound(name.to_owned()))?;
+        role.rules = rules;
+        self.permissioner.set_role(role.clone());
+        info!("Updated role: {name}.");
+        self.get_role(name)
+    }
+
+    pub async fn delete_role(&mut self, session: &Session, name: &str) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .update_permissions(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to delete role for user with id: {}",
+                    session.get_user_id()
+                )
+            })?;
+
+        if self.roles.remove(name).is_none() {
+            return Err(IggyError::RoleNotFound(name.to_owned()));
+        }
+
+        for user in self.users.values_mut() {
+            user.roles.retain(|role| role != name);
+        }
+        self.permissioner.delete_role(name);
+        info!("Deleted role: {name}.");
+        Ok(())
+    }
+
+    pub async fn assign_roles(
+        &mut self,
+        session: &Session,
+        user_id: &Identifier,
+        roles: Vec<String>,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .update_permissions(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to assign roles for user with id: {}",
+                    session.get_user_id()
+                )
+            })?;
+
+        if let Some(role) = roles.iter().find(|role| !self.roles.contains_key(*role)) {
+            return Err(IggyError::RoleNotFound(role.to_owned()));
+        }
+
+        let user = self.get_user_mut(user_id).with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get mutable reference to the user with id: {user_id}"
+            )
+        })?;
+        if user.is_root() {
+            error!("Cannot change the root user roles.");
+            return Err(IggyError::CannotChangePermissions(user.id));
+        }
+
+        user.roles = roles;
+        let (id, username, roles) = (user.id, user.username.clone(), user.roles.clone());
+        self.permissioner.update_roles_for_user(id, roles);
+        info!("Assigned roles to user: {username} with ID: {id}.");
+        Ok(())
+    }
+
+    fn get_role(&self, name: &str) -> Result<&Role, IggyError> {
+        self.roles
+            .get(name)
+            .ok_or_else(|| IggyError::RoleNotFound(name.to_owned()))
+    }
+}
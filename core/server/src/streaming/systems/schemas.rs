@@ -0,0 +1,149 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::session::Session;
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::systems::system::System;
+use crate::streaming::topics::topic::Topic;
+use bytes::Bytes;
+use error_set::ErrContext;
+use iggy_common::{Identifier, IggyError, Schema, SchemaCompatibility, SchemaType};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static SCHEMA_ID: AtomicU32 = AtomicU32::new(1);
+
+impl System {
+    /// Sets the ID of the next schema, so that the IDs of the deleted schemas are never reused,
+    /// as they might still be referenced by the already appended messages.
+    pub(crate) fn load_schema_ids(&self, last_schema_id: u32) {
+        SCHEMA_ID.store(last_schema_id + 1, Ordering::SeqCst);
+    }
+
+    pub fn get_schema(
+        &self,
+        session: &Session,
+        schema_id: u32,
+    ) -> Result<Option<&Schema>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let Some(topic) = self.find_schema_topic(schema_id) else {
+            return Ok(None);
+        };
+
+        self.permissioner
+            .get_schema(session.get_user_id(), topic.stream_id, topic.topic_id)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to get schema with ID: {schema_id} for user with ID: {}",
+                    session.get_user_id(),
+                )
+            })?;
+
+        Ok(topic.get_schema(schema_id))
+    }
+
+    pub fn get_schemas(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<&Schema>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic with ID: {topic_id} was not found in stream with ID: {stream_id}"))?;
+
+        self.permissioner
+            .get_schemas(session.get_user_id(), topic.stream_id, topic.topic_id)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to get schemas in topic with ID: {topic_id} and stream with ID: {stream_id} for user with ID: {}",
+                    session.get_user_id(),
+                )
+            })?;
+
+        Ok(topic.get_schemas())
+    }
+
+    pub fn register_schema(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        schema_type: SchemaType,
+        compatibility: SchemaCompatibility,
+        definition: Bytes,
+    ) -> Result<&Schema, IggyError> {
+        self.ensure_authenticated(session)?;
+        {
+            let topic = self.find_topic(session, stream_id, topic_id)
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
+
+            self.permissioner.register_schema(
+                session.get_user_id(),
+                topic.stream_id,
+                topic.topic_id,
+            ).with_error_context(|error| format!("{COMPONENT} (error: {error}) - permission denied to register schema for user {} on stream ID: {}, topic ID: {}", session.get_user_id(), topic.stream_id, topic.topic_id))?;
+        }
+
+        let topic = self.get_stream_mut(stream_id)?
+            .get_topic_mut(topic_id)
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
+
+        let schema_id = SCHEMA_ID.fetch_add(1, Ordering::SeqCst);
+        topic
+            .register_schema(schema_id, schema_type, compatibility, definition)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to register schema of type: {schema_type} for stream ID: {stream_id}, topic_id: {topic_id}")
+            })
+    }
+
+    pub fn delete_schema(
+        &mut self,
+        session: &Session,
+        schema_id: u32,
+    ) -> Result<Schema, IggyError> {
+        self.ensure_authenticated(session)?;
+        let (stream_id, topic_id) = {
+            let topic = self
+                .find_schema_topic(schema_id)
+                .ok_or(IggyError::SchemaNotFound(schema_id))?;
+
+            self.permissioner.delete_schema(
+                session.get_user_id(),
+                topic.stream_id,
+                topic.topic_id,
+            ).with_error_context(|error| format!("{COMPONENT} (error: {error}) - permission denied to delete schema with ID: {schema_id} for user {} on stream ID: {}, topic ID: {}", session.get_user_id(), topic.stream_id, topic.topic_id))?;
+            (topic.stream_id, topic.topic_id)
+        };
+
+        self.get_stream_mut(&Identifier::numeric(stream_id)?)?
+            .get_topic_mut(&Identifier::numeric(topic_id)?)?
+            .delete_schema(schema_id)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to delete schema with ID: {schema_id}"
+                )
+            })
+    }
+
+    fn find_schema_topic(&self, schema_id: u32) -> Option<&Topic> {
+        self.streams
+            .values()
+            .flat_map(|stream| stream.topics.values())
+            .find(|topic| topic.schemas.contains_key(&schema_id))
+    }
+}
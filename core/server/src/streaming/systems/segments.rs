@@ -22,6 +22,7 @@ use error_set::ErrContext;
 use iggy_common::Identifier;
 use iggy_common::IggyError;
 use iggy_common::locking::IggySharedMutFn;
+use tracing::error;
 
 impl System {
     pub async fn delete_segments(
@@ -50,6 +51,11 @@ impl System {
             ))?;
         }
 
+        let oldest_transaction_id = self
+            .transaction_manager
+            .read()
+            .await
+            .oldest_transaction_id();
         let topic = self
             .get_stream_mut(stream_id)?
             .get_topic_mut(topic_id)
@@ -96,6 +102,15 @@ impl System {
 
             (segments_count, messages_count)
         };
+        if let Err(error) = partition
+            .prune_transaction_markers(oldest_transaction_id)
+            .await
+        {
+            error!(
+                "{COMPONENT} (error: {error}) - failed to prune transaction markers, stream ID: {}, topic ID: {}, partition ID: {partition_id}",
+                topic.stream_id, topic.topic_id
+            );
+        }
         topic.reassign_consumer_groups().await;
 
         self.metrics.decrement_segments(deleted_segments_count);
@@ -17,11 +17,13 @@
  */
 
 use crate::VERSION;
+use crate::streaming::diagnostics::metrics::{PartitionLabels, PartitionMetrics};
 use crate::streaming::systems::system::System;
 use crate::versioning::SemanticVersion;
 use iggy_common::locking::IggySharedMutFn;
 use iggy_common::{IggyDuration, IggyError, Stats};
 use std::sync::OnceLock;
+use std::sync::atomic::Ordering;
 use sysinfo::{Pid, ProcessesToUpdate, System as SysinfoSystem};
 use tokio::sync::Mutex;
 
@@ -110,4 +112,52 @@ impl System {
 
         Ok(stats)
     }
+
+    /// Sets the labeled partitions and consumers series to the current state, before the metrics are scraped.
+    pub async fn refresh_metrics(&self) {
+        self.metrics.clear_partitions();
+        for stream in self.streams.values() {
+            for topic in stream.topics.values() {
+                for partition in topic.partitions.values() {
+                    let partition = partition.read().await;
+                    let labels = PartitionLabels {
+                        stream_id: stream.stream_id,
+                        topic_id: topic.topic_id,
+                        partition_id: partition.partition_id,
+                    };
+                    let throughput = &partition.throughput;
+                    self.metrics.set_partition(
+                        &labels,
+                        &PartitionMetrics {
+                            messages_in: throughput.messages_in.load(Ordering::Relaxed),
+                            bytes_in: throughput.bytes_in.load(Ordering::Relaxed),
+                            messages_out: throughput.messages_out.load(Ordering::Relaxed),
+                            bytes_out: throughput.bytes_out.load(Ordering::Relaxed),
+                            segments_count: partition.get_segments_count(),
+                            current_offset: partition.current_offset,
+                        },
+                    );
+
+                    let has_messages = partition.get_messages_count() > 0;
+                    for offsets in [
+                        &partition.consumer_offsets,
+                        &partition.consumer_group_offsets,
+                    ] {
+                        for offset in offsets.iter() {
+                            let lag = match has_messages {
+                                true => partition.current_offset.saturating_sub(offset.offset),
+                                false => 0,
+                            };
+                            self.metrics.set_consumer_lag(
+                                &labels,
+                                offset.kind,
+                                offset.consumer_id,
+                                lag,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
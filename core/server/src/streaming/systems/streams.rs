@@ -157,6 +157,13 @@ impl System {
             self.metrics.increment_segments(stream.get_segments_count());
             self.metrics.increment_messages(stream.get_messages_count());
 
+            self.permissioner
+                .set_stream_name(stream.stream_id, &stream.name);
+            for topic in stream.get_topics() {
+                self.permissioner
+                    .set_topic_name(stream.stream_id, topic.topic_id, &topic.name);
+            }
+
             self.streams_ids
                 .insert(stream.name.clone(), stream.stream_id);
             self.streams.insert(stream.stream_id, stream);
@@ -170,17 +177,26 @@ impl System {
         self.streams.values().collect()
     }
 
+    /// Returns the streams which the user can read, as some of them might be denied or not allowed by the user's roles.
     pub fn find_streams(&self, session: &Session) -> Result<Vec<&Stream>, IggyError> {
         self.ensure_authenticated(session)?;
+        let user_id = session.get_user_id();
         self.permissioner
-            .get_streams(session.get_user_id())
+            .get_streams(user_id)
             .with_error_context(|error| {
                 format!(
-                    "{COMPONENT} (error: {error}) - permission denied to get streams for user {}",
-                    session.get_user_id(),
+                    "{COMPONENT} (error: {error}) - permission denied to get streams for user {user_id}",
                 )
             })?;
-        Ok(self.get_streams())
+        Ok(self
+            .get_streams()
+            .into_iter()
+            .filter(|stream| {
+                self.permissioner
+                    .get_stream(user_id, stream.stream_id)
+                    .is_ok()
+            })
+            .collect())
     }
 
     pub fn find_stream(
@@ -301,7 +317,8 @@ impl System {
         name: &str,
     ) -> Result<&Stream, IggyError> {
         self.ensure_authenticated(session)?;
-        self.permissioner.create_stream(session.get_user_id())?;
+        self.permissioner
+            .create_stream(session.get_user_id(), name)?;
         if self.streams_ids.contains_key(name) {
             return Err(IggyError::StreamNameAlreadyExists(name.to_owned()));
         }
@@ -330,6 +347,7 @@ impl System {
         let stream = Stream::create(id, name, self.config.clone(), self.storage.clone());
         stream.persist().await?;
         info!("Created stream with ID: {id}, name: '{name}'.");
+        self.permissioner.set_stream_name(id, name);
         self.streams_ids.insert(name.to_owned(), stream.stream_id);
         self.streams.insert(stream.stream_id, stream);
         self.metrics.increment_streams(1);
@@ -382,6 +400,7 @@ impl System {
         {
             self.streams_ids.remove(&old_name);
             self.streams_ids.insert(name.to_owned(), stream_id);
+            self.permissioner.set_stream_name(stream_id, name);
         }
 
         info!("Stream with ID '{id}' updated. Old name: '{old_name}' changed to: '{name}'.");
@@ -420,6 +439,9 @@ impl System {
         self.metrics.decrement_segments(stream.get_segments_count());
         self.streams.remove(&stream_id);
         self.streams_ids.remove(&stream_name);
+        self.permissioner.delete_stream_name(stream_id);
+        self.unsaved_consumer_offsets
+            .retain(|(offsets_stream_id, ..), _| *offsets_stream_id != stream_id);
         let current_stream_id = CURRENT_STREAM_ID.load(Ordering::SeqCst);
         if current_stream_id > stream_id {
             CURRENT_STREAM_ID.store(stream_id, Ordering::SeqCst);
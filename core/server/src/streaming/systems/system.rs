@@ -17,6 +17,7 @@
  */
 
 use crate::archiver::{ArchiverKind, ArchiverKindType};
+use crate::audit::log::AuditLog;
 use crate::configs::server::{DataMaintenanceConfig, PersonalAccessTokenConfig};
 use crate::configs::system::SystemConfig;
 use crate::map_toggle_str;
@@ -26,10 +27,15 @@ use crate::state::system::SystemState;
 use crate::streaming::clients::client_manager::ClientManager;
 use crate::streaming::diagnostics::metrics::Metrics;
 use crate::streaming::persistence::persister::*;
+use crate::streaming::producers::producer_manager::ProducerManager;
+use crate::streaming::quotas::QuotaManager;
 use crate::streaming::session::Session;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::systems::COMPONENT;
+use crate::streaming::systems::consumer_offsets::UnsavedConsumerOffsets;
+use crate::streaming::transactions::transaction_manager::TransactionManager;
+use crate::streaming::users::external_jwt::ExternalJwtValidator;
 use crate::streaming::users::permissioner::Permissioner;
 use crate::streaming::users::user::User;
 use crate::versioning::SemanticVersion;
@@ -37,7 +43,7 @@ use ahash::AHashMap;
 use error_set::ErrContext;
 use iggy_common::locking::IggySharedMut;
 use iggy_common::locking::IggySharedMutFn;
-use iggy_common::{Aes256GcmEncryptor, EncryptorKind, IggyError, UserId};
+use iggy_common::{Aes256GcmEncryptor, EncryptorKind, IggyError, Role, UserId};
 use std::path::Path;
 use std::sync::Arc;
 use tokio::fs::{create_dir_all, remove_dir_all};
@@ -81,12 +87,19 @@ pub struct System {
     pub(crate) streams: AHashMap<u32, Stream>,
     pub(crate) streams_ids: AHashMap<String, u32>,
     pub(crate) users: AHashMap<UserId, User>,
+    pub(crate) roles: AHashMap<String, Role>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) client_manager: IggySharedMut<ClientManager>,
+    pub(crate) transaction_manager: IggySharedMut<TransactionManager>,
+    pub(crate) producer_manager: IggySharedMut<ProducerManager>,
     pub(crate) encryptor: Option<Arc<EncryptorKind>>,
     pub(crate) metrics: Metrics,
     pub(crate) state: Arc<StateKind>,
     pub(crate) archiver: Option<Arc<ArchiverKind>>,
+    pub(crate) audit: Arc<AuditLog>,
+    pub(crate) quotas: QuotaManager,
+    pub(crate) external_jwt: Arc<ExternalJwtValidator>,
+    pub(crate) unsaved_consumer_offsets: UnsavedConsumerOffsets,
     pub personal_access_token: PersonalAccessTokenConfig,
 }
 
@@ -137,7 +150,7 @@ impl System {
 
     pub fn create(
         system_config: Arc<SystemConfig>,
-        storage: SystemStorage,
+        mut storage: SystemStorage,
         state: Arc<StateKind>,
         encryptor: Option<Arc<EncryptorKind>>,
         data_maintenance_config: DataMaintenanceConfig,
@@ -167,6 +180,14 @@ impl System {
             info!("Archiving is disabled.");
             None
         };
+        // The archiver serves as the remote tier for the offloaded segments.
+        storage.archiver = archiver.clone();
+        let audit = Arc::new(AuditLog::new(
+            &system_config,
+            Self::resolve_persister(system_config.audit.enforce_fsync),
+        ));
+        let quotas = QuotaManager::new(&system_config.quotas);
+        let external_jwt = Arc::new(ExternalJwtValidator::new(&system_config.external_jwt));
 
         System {
             config: system_config,
@@ -175,12 +196,19 @@ impl System {
             storage: Arc::new(storage),
             encryptor,
             client_manager: IggySharedMut::new(ClientManager::default()),
+            transaction_manager: IggySharedMut::new(TransactionManager::default()),
+            producer_manager: IggySharedMut::new(ProducerManager::default()),
             permissioner: Permissioner::default(),
             metrics: Metrics::init(),
             users: AHashMap::new(),
+            roles: AHashMap::new(),
             state,
             personal_access_token: pat_config,
             archiver,
+            audit,
+            quotas,
+            external_jwt,
+            unsaved_consumer_offsets: UnsavedConsumerOffsets::default(),
         }
     }
 
@@ -201,6 +229,23 @@ impl System {
             return Err(IggyError::CannotCreateStreamsDirectory(streams_path));
         }
 
+        for data_directory in &self.config.data_directories {
+            if !Path::new(data_directory).exists() && create_dir_all(data_directory).await.is_err()
+            {
+                return Err(IggyError::CannotCreateBaseDirectory(
+                    data_directory.to_string(),
+                ));
+            }
+        }
+
+        let audit_path = self.config.get_audit_path();
+        if self.audit.is_enabled()
+            && !Path::new(&audit_path).exists()
+            && create_dir_all(&audit_path).await.is_err()
+        {
+            return Err(IggyError::CannotCreateBaseDirectory(audit_path));
+        }
+
         let runtime_path = self.config.get_runtime_path();
         if Path::new(&runtime_path).exists() && remove_dir_all(&runtime_path).await.is_err() {
             return Err(IggyError::CannotRemoveRuntimeDirectory(runtime_path));
@@ -223,20 +268,30 @@ impl System {
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to initialize system state")
             })?;
+        self.audit.init().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to initialize audit log")
+        })?;
+        self.external_jwt.init().await;
         let now = Instant::now();
         self.load_version().await.with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to load version")
         })?;
+        self.load_roles(system_state.roles.into_values().collect());
         self.load_users(system_state.users.into_values().collect())
             .await
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to load users")
             })?;
+        self.load_producers(system_state.producers.into_values().collect())
+            .await;
         self.load_streams(system_state.streams.into_values().collect())
             .await
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to load streams")
             })?;
+        self.load_schema_ids(system_state.last_schema_id);
+        self.recover_committed_transactions(system_state.committed_transactions)
+            .await;
         if let Some(archiver) = self.archiver.as_ref() {
             archiver
                 .init()
@@ -250,6 +305,7 @@ impl System {
     #[instrument(skip_all, name = "trace_shutdown")]
     pub async fn shutdown(&mut self) -> Result<(), IggyError> {
         self.persist_messages().await?;
+        self.persist_consumer_offsets().await?;
         Ok(())
     }
 
@@ -22,7 +22,10 @@ use crate::streaming::systems::system::System;
 use crate::streaming::topics::topic::Topic;
 use error_set::ErrContext;
 use iggy_common::locking::IggySharedMutFn;
-use iggy_common::{CompressionAlgorithm, Identifier, IggyError, IggyExpiry, MaxTopicSize};
+use iggy_common::{
+    CleanupPolicy, CompressionAlgorithm, Identifier, IggyError, IggyExpiry, MaxTopicSize,
+    PartitioningHash,
+};
 
 impl System {
     pub fn find_topic(
@@ -53,6 +56,7 @@ impl System {
         topic
     }
 
+    /// Returns the topics in the stream which the user can read, as some of them might be denied or not allowed by the user's roles.
     pub fn find_topics(
         &self,
         session: &Session,
@@ -62,15 +66,23 @@ impl System {
         let stream = self.get_stream(stream_id).with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to get stream with ID: {stream_id}")
         })?;
+        let user_id = session.get_user_id();
         self.permissioner
-            .get_topics(session.get_user_id(), stream.stream_id)
+            .get_topics(user_id, stream.stream_id)
             .with_error_context(|error| {
                 format!(
-                    "{COMPONENT} (error: {error}) - permission denied to get topics in stream with ID: {stream_id} for user with ID: {}",
-                    session.get_user_id(),
+                    "{COMPONENT} (error: {error}) - permission denied to get topics in stream with ID: {stream_id} for user with ID: {user_id}",
                 )
             })?;
-        Ok(stream.get_topics())
+        Ok(stream
+            .get_topics()
+            .into_iter()
+            .filter(|topic| {
+                self.permissioner
+                    .get_topic(user_id, topic.stream_id, topic.topic_id)
+                    .is_ok()
+            })
+            .collect())
     }
 
     pub fn try_find_topic(
@@ -116,6 +128,10 @@ impl System {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: MaxTopicSize,
         replication_factor: Option<u8>,
+        cleanup_policy: CleanupPolicy,
+        partitioning_hash: PartitioningHash,
+        segment_max_age: IggyExpiry,
+        max_partition_size: MaxTopicSize,
     ) -> Result<&Topic, IggyError> {
         self.ensure_authenticated(session)?;
         {
@@ -142,12 +158,19 @@ impl System {
                 compression_algorithm,
                 max_topic_size,
                 replication_factor.unwrap_or(1),
+                cleanup_policy,
+                partitioning_hash,
+                segment_max_age,
+                max_partition_size,
             )
             .await
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to create topic with name: {name} in stream ID: {stream_id}")
             })?;
 
+        let numeric_stream_id = self.get_stream(stream_id)?.stream_id;
+        self.permissioner
+            .set_topic_name(numeric_stream_id, created_topic_id, name);
         self.metrics.increment_topics(1);
         self.metrics.increment_partitions(partitions_count);
         self.metrics.increment_segments(partitions_count);
@@ -175,8 +198,13 @@ impl System {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: MaxTopicSize,
         replication_factor: Option<u8>,
+        cleanup_policy: CleanupPolicy,
+        partitioning_hash: PartitioningHash,
+        segment_max_age: IggyExpiry,
+        max_partition_size: MaxTopicSize,
     ) -> Result<&Topic, IggyError> {
         self.ensure_authenticated(session)?;
+        let topic_ids;
         {
             let topic = self
                 .find_topic(session, stream_id, topic_id)
@@ -197,6 +225,7 @@ impl System {
                     topic.topic_id,
                 )
             })?;
+            topic_ids = (topic.stream_id, topic.topic_id);
         }
 
         self.get_stream_mut(stream_id)?
@@ -207,6 +236,10 @@ impl System {
                 compression_algorithm,
                 max_topic_size,
                 replication_factor.unwrap_or(1),
+                cleanup_policy,
+                partitioning_hash,
+                segment_max_age,
+                max_partition_size,
             )
             .await
             .with_error_context(|error| {
@@ -214,6 +247,8 @@ impl System {
                     "{COMPONENT} (error: {error}) - failed to update topic with ID: {topic_id} in stream with ID: {stream_id}",
                 )
             })?;
+        self.permissioner
+            .set_topic_name(topic_ids.0, topic_ids.1, name);
 
         // TODO: if message_expiry is changed, we need to check if we need to purge messages based on the new expiry
         // TODO: if max_size_bytes is changed, we need to check if we need to purge messages based on the new size
@@ -261,6 +296,12 @@ impl System {
             .await
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to delete topic with ID: {topic_id} in stream with ID: {stream_id}"))?;
 
+        self.permissioner
+            .delete_topic_name(stream_id_value, topic.topic_id);
+        self.unsaved_consumer_offsets
+            .retain(|(offsets_stream_id, offsets_topic_id, ..), _| {
+                (*offsets_stream_id, *offsets_topic_id) != (stream_id_value, topic.topic_id)
+            });
         self.metrics.decrement_topics(1);
         self.metrics
             .decrement_partitions(topic.get_partitions_count());
@@ -0,0 +1,369 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::state::command::EntryCommand;
+use crate::state::models::CommitTransactionWithPartitions;
+use crate::streaming::segments::{IggyIndexesMut, IggyMessagesBatchMut};
+use crate::streaming::session::Session;
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::systems::system::System;
+use crate::streaming::topics::topic::Topic;
+use crate::streaming::transactions::markers::{self, TRANSACTION_HEADER_SIZE, TransactionOutcome};
+use crate::streaming::transactions::transaction_manager::TransactionPartition;
+use crate::streaming::utils::PooledBuffer;
+use ahash::AHashMap;
+use error_set::ErrContext;
+use iggy_common::commit_transaction::CommitTransaction;
+use iggy_common::locking::IggySharedMutFn;
+use iggy_common::{BytesSerializable, Confirmation, Identifier, IggyError, Partitioning, Sizeable};
+use tracing::{error, info, trace, warn};
+
+impl System {
+    pub async fn begin_transaction(&self, session: &Session) -> Result<u64, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.abort_expired_transactions().await;
+        let transaction_id = self
+            .transaction_manager
+            .write()
+            .await
+            .begin_transaction(session.get_user_id(), session.client_id);
+        trace!(
+            "Begun transaction with ID: {transaction_id} for user with ID: {}",
+            session.get_user_id()
+        );
+        Ok(transaction_id)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn append_transaction_messages(
+        &self,
+        session: &Session,
+        transaction_id: u64,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: IggyMessagesBatchMut,
+        confirmation: Option<Confirmation>,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.abort_expired_transactions().await;
+        let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream_id: {stream_id}, topic_id: {topic_id}"))?;
+        self.permissioner.append_messages(
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id
+        ).with_error_context(|error| format!(
+            "{COMPONENT} (error: {error}) - permission denied to append messages for user {} on stream ID: {}, topic ID: {}",
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id
+        ))?;
+
+        if !topic.has_partitions() {
+            return Err(IggyError::NoPartitions(topic.topic_id, topic.stream_id));
+        }
+
+        let partition_id = topic.resolve_partition_id(partitioning)?;
+        topic.get_partition(partition_id)?;
+        Self::validate_reserved_headers(&messages)?;
+        self.validate_messages_schemas(topic, &messages)?;
+
+        // The partition must be registered before appending the messages, so that the marker
+        // is always written to it, even if the transaction is completed in the meantime.
+        self.transaction_manager.write().await.add_partition(
+            transaction_id,
+            session.get_user_id(),
+            TransactionPartition {
+                stream_id: topic.stream_id,
+                topic_id: topic.topic_id,
+                partition_id,
+            },
+        )?;
+
+        let messages_count = messages.count();
+        let messages = Self::mark_message_keys(topic, partitioning, messages);
+        let messages = Self::mark_transaction_messages(messages, transaction_id);
+        let messages = self.compress_and_encrypt_messages(topic, messages)?;
+        topic
+            .append_transaction_messages(transaction_id, partition_id, messages, confirmation)
+            .await?;
+
+        self.metrics.increment_messages(messages_count as u64);
+        Ok(())
+    }
+
+    pub async fn commit_transaction(
+        &self,
+        session: &Session,
+        transaction_id: u64,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let transaction = self
+            .transaction_manager
+            .write()
+            .await
+            .start_commit(transaction_id, session.get_user_id())?;
+
+        // The decision is stored before any marker is written, so that the missing markers are appended on startup.
+        let command = EntryCommand::CommitTransaction(CommitTransactionWithPartitions {
+            partitions: transaction.partitions.iter().copied().collect(),
+            command: CommitTransaction { transaction_id },
+        });
+        if let Err(error) = self.state.apply(session.get_user_id(), &command).await {
+            error!(
+                "{COMPONENT} (error: {error}) - failed to store commit of transaction with ID: {transaction_id}, aborting it"
+            );
+            _ = self
+                .end_transaction(
+                    transaction_id,
+                    &transaction.partitions,
+                    TransactionOutcome::Aborted,
+                )
+                .await;
+            self.transaction_manager
+                .write()
+                .await
+                .finish_commit(transaction_id);
+            return Err(error);
+        }
+
+        // The commit, whose markers failed to be written, remains tracked until they're appended on startup.
+        self.end_transaction(
+            transaction_id,
+            &transaction.partitions,
+            TransactionOutcome::Committed,
+        )
+        .await?;
+        self.transaction_manager
+            .write()
+            .await
+            .finish_commit(transaction_id);
+        Ok(())
+    }
+
+    pub async fn abort_transaction(
+        &self,
+        session: &Session,
+        transaction_id: u64,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let transaction = self
+            .transaction_manager
+            .write()
+            .await
+            .remove_transaction(transaction_id, session.get_user_id())?;
+        self.end_transaction(
+            transaction.id,
+            &transaction.partitions,
+            TransactionOutcome::Aborted,
+        )
+        .await
+    }
+
+    pub(crate) async fn abort_client_transactions(&self, client_id: u32) {
+        let transactions = self
+            .transaction_manager
+            .write()
+            .await
+            .remove_client_transactions(client_id);
+        for transaction in transactions {
+            info!(
+                "Aborting transaction with ID: {} of disconnected client with ID: {client_id}",
+                transaction.id
+            );
+            _ = self
+                .end_transaction(
+                    transaction.id,
+                    &transaction.partitions,
+                    TransactionOutcome::Aborted,
+                )
+                .await;
+        }
+    }
+
+    pub(crate) async fn abort_expired_transactions(&self) {
+        let transactions = self
+            .transaction_manager
+            .write()
+            .await
+            .remove_expired_transactions(self.config.transaction.timeout);
+        for transaction in transactions {
+            info!(
+                "Aborting transaction with ID: {}, which has not been completed within {}",
+                transaction.id, self.config.transaction.timeout
+            );
+            _ = self
+                .end_transaction(
+                    transaction.id,
+                    &transaction.partitions,
+                    TransactionOutcome::Aborted,
+                )
+                .await;
+        }
+    }
+
+    /// Prunes the transaction markers below the first offset of each partition of the topic,
+    /// once its segments have been deleted.
+    pub(crate) async fn prune_transaction_markers(&self, topic: &Topic) {
+        let oldest_transaction_id = self
+            .transaction_manager
+            .read()
+            .await
+            .oldest_transaction_id();
+        for partition in topic.partitions.values() {
+            let mut partition = partition.write().await;
+            if let Err(error) = partition
+                .prune_transaction_markers(oldest_transaction_id)
+                .await
+            {
+                error!(
+                    "{COMPONENT} (error: {error}) - failed to prune transaction markers, stream ID: {}, topic ID: {}, partition ID: {}",
+                    topic.stream_id, topic.topic_id, partition.partition_id
+                );
+            }
+        }
+    }
+
+    /// Appends the markers of the committed transactions, which are missing in any of their partitions,
+    /// as the server was stopped before all of them were written.
+    pub(crate) async fn recover_committed_transactions(
+        &self,
+        committed_transactions: AHashMap<u64, Vec<TransactionPartition>>,
+    ) {
+        for (transaction_id, partitions) in committed_transactions {
+            let mut missing_partitions = Vec::new();
+            for partition in partitions {
+                let Ok(is_completed) = self
+                    .is_transaction_completed(transaction_id, &partition)
+                    .await
+                else {
+                    continue;
+                };
+                if !is_completed {
+                    missing_partitions.push(partition);
+                }
+            }
+            if missing_partitions.is_empty() {
+                continue;
+            }
+
+            warn!(
+                "Appending missing markers of committed transaction with ID: {transaction_id} to {} partition(s)...",
+                missing_partitions.len()
+            );
+            if self
+                .end_transaction(
+                    transaction_id,
+                    &missing_partitions,
+                    TransactionOutcome::Committed,
+                )
+                .await
+                .is_err()
+            {
+                self.transaction_manager
+                    .write()
+                    .await
+                    .track_commit(transaction_id);
+            }
+        }
+    }
+
+    async fn is_transaction_completed(
+        &self,
+        transaction_id: u64,
+        partition: &TransactionPartition,
+    ) -> Result<bool, IggyError> {
+        let topic = self
+            .get_stream(&Identifier::numeric(partition.stream_id)?)?
+            .get_topic(&Identifier::numeric(partition.topic_id)?)?;
+        let partition = topic.get_partition(partition.partition_id)?;
+        let partition = partition.read().await;
+        Ok(partition.is_transaction_completed(transaction_id))
+    }
+
+    /// Writes the marker to each partition involved in the transaction. The partitions,
+    /// which have been deleted in the meantime, are skipped.
+    async fn end_transaction<'a>(
+        &self,
+        transaction_id: u64,
+        partitions: impl IntoIterator<Item = &'a TransactionPartition>,
+        outcome: TransactionOutcome,
+    ) -> Result<(), IggyError> {
+        let mut result = Ok(());
+        let mut partitions_count = 0;
+        for partition in partitions {
+            let Ok(topic) = Identifier::numeric(partition.stream_id)
+                .and_then(|stream_id| self.get_stream(&stream_id))
+                .and_then(|stream| stream.get_topic(&Identifier::numeric(partition.topic_id)?))
+            else {
+                continue;
+            };
+
+            let marker = markers::create_marker(transaction_id, outcome);
+            let marker_size = marker.get_size_bytes().as_bytes_u32();
+            let marker = IggyMessagesBatchMut::from_messages(&[marker], marker_size);
+            let marker = match &self.encryptor {
+                Some(encryptor) => self.encrypt_messages(marker, encryptor.as_ref())?,
+                None => marker,
+            };
+
+            partitions_count += 1;
+            if let Err(error) = topic
+                .end_transaction(transaction_id, partition.partition_id, outcome, marker)
+                .await
+            {
+                error!(
+                    "{COMPONENT} (error: {error}) - failed to write {outcome:?} marker of transaction with ID: {transaction_id}, stream ID: {}, topic ID: {}, partition ID: {}",
+                    partition.stream_id, partition.topic_id, partition.partition_id
+                );
+                result = Err(error);
+            }
+        }
+
+        trace!(
+            "Ended transaction with ID: {transaction_id} as {outcome:?} in {partitions_count} partition(s)"
+        );
+        result
+    }
+
+    /// Appends the transaction user header to each message, before it's compressed and encrypted.
+    fn mark_transaction_messages(
+        batch: IggyMessagesBatchMut,
+        transaction_id: u64,
+    ) -> IggyMessagesBatchMut {
+        let count = batch.count();
+        let mut marked_messages = PooledBuffer::with_capacity(
+            batch.size() as usize + count as usize * TRANSACTION_HEADER_SIZE,
+        );
+        let mut indexes = IggyIndexesMut::with_capacity(count as usize, 0);
+
+        for message in batch.iter() {
+            let mut header = message.header().to_header();
+            header.user_headers_length += TRANSACTION_HEADER_SIZE as u32;
+            marked_messages.extend_from_slice(&header.to_bytes());
+            marked_messages.extend_from_slice(message.payload());
+            if let Some(user_headers) = message.user_headers() {
+                marked_messages.extend_from_slice(user_headers);
+            }
+            markers::write_transaction_header(transaction_id, &mut marked_messages);
+            indexes.insert(0, marked_messages.len() as u32, 0);
+        }
+
+        IggyMessagesBatchMut::from_indexes_and_messages(count, indexes, marked_messages)
+    }
+}
@@ -16,24 +16,29 @@
  * under the License.
  */
 
+use crate::configs::client_auth::ClientCertificateIdentity;
 use crate::state::command::EntryCommand;
 use crate::state::models::CreateUserWithId;
 use crate::state::system::UserState;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
 use crate::streaming::session::Session;
 use crate::streaming::systems::COMPONENT;
-use crate::streaming::systems::system::System;
+use crate::streaming::systems::system::{SharedSystem, System};
+use crate::streaming::users::client_certificate;
+use crate::streaming::users::external_jwt::ExternalIdentity;
 use crate::streaming::users::user::User;
 use crate::streaming::utils::crypto;
 use crate::{IGGY_ROOT_PASSWORD_ENV, IGGY_ROOT_USERNAME_ENV};
 use error_set::ErrContext;
 use iggy_common::IggyError;
 use iggy_common::Permissions;
+use iggy_common::UserQuotas;
 use iggy_common::UserStatus;
 use iggy_common::create_user::CreateUser;
 use iggy_common::defaults::*;
 use iggy_common::locking::IggySharedMutFn;
 use iggy_common::{IdKind, Identifier};
+use rustls::pki_types::CertificateDer;
 use std::env;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU32, Ordering};
@@ -42,6 +47,20 @@ use tracing::{error, info, warn};
 static USER_ID: AtomicU32 = AtomicU32::new(1);
 const MAX_USERS: usize = u32::MAX as usize;
 
+impl SharedSystem {
+    /// Validates the token issued by one of the external issuers. The system lock isn't held meanwhile,
+    /// as the validation might need to fetch the signing keys of the issuer.
+    pub async fn validate_external_token(
+        &self,
+        token: &str,
+    ) -> Result<ExternalIdentity, IggyError> {
+        let validator = self.read().await.external_jwt.clone();
+        validator.validate(token).await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate external token")
+        })
+    }
+}
+
 impl System {
     pub(crate) async fn load_users(&mut self, users: Vec<UserState>) -> Result<(), IggyError> {
         info!("Loading users...");
@@ -53,6 +72,7 @@ impl System {
                 password: root.password.clone(),
                 status: root.status,
                 permissions: root.permissions.clone(),
+                quotas: None,
             };
             self.state
                 .apply(0, &EntryCommand::CreateUser(CreateUserWithId {
@@ -81,6 +101,8 @@ impl System {
             );
 
             user.created_at = user_state.created_at;
+            user.quotas = user_state.quotas;
+            user.roles = user_state.roles;
             user.personal_access_tokens = user_state
                 .personal_access_tokens
                 .into_values()
@@ -222,6 +244,29 @@ impl System {
         password: &str,
         status: UserStatus,
         permissions: Option<Permissions>,
+        quotas: Option<UserQuotas>,
+    ) -> Result<&User, IggyError> {
+        let password_hash = crypto::hash_password(password);
+        self.import_user(
+            session,
+            username,
+            password_hash,
+            status,
+            permissions,
+            quotas,
+        )
+        .await
+    }
+
+    /// Creates the user with the password hash exported from another server, e.g. along with the metadata.
+    pub async fn import_user(
+        &mut self,
+        session: &Session,
+        username: &str,
+        password_hash: String,
+        status: UserStatus,
+        permissions: Option<Permissions>,
+        quotas: Option<UserQuotas>,
     ) -> Result<&User, IggyError> {
         self.ensure_authenticated(session)?;
         self.permissioner
@@ -233,6 +278,11 @@ impl System {
                 )
             })?;
 
+        if !crypto::is_password_hash(&password_hash) {
+            error!("Invalid password hash for user: {username}.");
+            return Err(IggyError::InvalidPassword);
+        }
+
         if self.users.iter().any(|(_, user)| user.username == username) {
             error!("User: {username} already exists.");
             return Err(IggyError::UserAlreadyExists);
@@ -245,7 +295,14 @@ impl System {
 
         let user_id = USER_ID.fetch_add(1, Ordering::SeqCst);
         info!("Creating user: {username} with ID: {user_id}...");
-        let user = User::new(user_id, username, password, status, permissions.clone());
+        let mut user = User::with_password(
+            user_id,
+            username,
+            password_hash,
+            status,
+            permissions.clone(),
+        );
+        user.quotas = quotas.filter(|quotas| !quotas.is_unlimited());
         self.permissioner
             .init_permissions_for_user(user_id, permissions);
         self.users.insert(user.id, user);
@@ -293,6 +350,8 @@ impl System {
             .ok_or(IggyError::ResourceNotFound(user_id.to_string()))?;
         self.permissioner
             .delete_permissions_for_user(existing_user_id);
+        self.permissioner.delete_roles_for_user(existing_user_id);
+        self.quotas.delete_user(existing_user_id);
         let mut client_manager = self.client_manager.write().await;
         client_manager
             .delete_clients_for_user(existing_user_id)
@@ -313,6 +372,7 @@ impl System {
         user_id: &Identifier,
         username: Option<String>,
         status: Option<UserStatus>,
+        quotas: Option<UserQuotas>,
     ) -> Result<&User, IggyError> {
         self.ensure_authenticated(session)?;
         self.permissioner
@@ -344,6 +404,10 @@ impl System {
             user.status = status;
         }
 
+        if let Some(quotas) = quotas {
+            user.quotas = Some(quotas).filter(|quotas| !quotas.is_unlimited());
+        }
+
         info!("Updated user: {} with ID: {}.", user.username, user.id);
         Ok(user)
     }
@@ -440,6 +504,57 @@ impl System {
             .await
     }
 
+    /// Logs in the user mapped from the external token, which has been already validated.
+    pub async fn login_with_external_identity(
+        &self,
+        identity: &ExternalIdentity,
+        session: Option<&Session>,
+    ) -> Result<&User, IggyError> {
+        let user = self.authenticate_with_external_identity(identity)?;
+        self.login_user_with_credentials(&user.username, None, session)
+            .await
+    }
+
+    /// Returns the active user the identity of the already validated external token is mapped to.
+    pub fn authenticate_with_external_identity(
+        &self,
+        identity: &ExternalIdentity,
+    ) -> Result<&User, IggyError> {
+        let user = match self.get_user(&identity.username.as_str().try_into()?) {
+            Ok(user) => user,
+            Err(_) => {
+                error!(
+                    "Cannot authenticate user: {} with external token (not found).",
+                    identity.username
+                );
+                return Err(IggyError::InvalidCredentials);
+            }
+        };
+
+        if !user.is_active() {
+            warn!("User: {} with ID: {} is inactive.", user.username, user.id);
+            return Err(IggyError::UserInactive);
+        }
+
+        Ok(user)
+    }
+
+    /// Logs in the user mapped from the client certificate, which has been already verified during the TLS handshake.
+    pub async fn login_with_client_certificate(
+        &self,
+        certificate: &CertificateDer<'_>,
+        identity: ClientCertificateIdentity,
+        session: &Session,
+    ) -> Result<&User, IggyError> {
+        let Some(username) = client_certificate::get_username(certificate, identity) else {
+            error!("Cannot login user with client certificate (missing identity: {identity}).");
+            return Err(IggyError::InvalidCredentials);
+        };
+
+        self.login_user_with_credentials(&username, None, Some(session))
+            .await
+    }
+
     pub async fn login_user_with_credentials(
         &self,
         username: &str,
@@ -485,8 +600,24 @@ impl System {
             self.logout_user(session).await?;
         }
 
-        session.set_user_id(user.id);
         let mut client_manager = self.client_manager.write().await;
+        if let Some(max_connections) = user.quotas.and_then(|quotas| quotas.max_connections) {
+            let connections = client_manager
+                .get_user_clients_count(user.id, session.client_id)
+                .await;
+            if connections >= max_connections as usize {
+                warn!(
+                    "Connections quota: {max_connections} for user: {username} with ID: {} has been exceeded.",
+                    user.id
+                );
+                return Err(IggyError::ConnectionsQuotaExceeded(
+                    max_connections,
+                    user.id,
+                ));
+            }
+        }
+
+        session.set_user_id(user.id);
         client_manager
             .set_user_id(session.client_id, user.id)
             .await
@@ -16,8 +16,8 @@
  * under the License.
  */
 
-use ahash::AHashMap;
-use iggy_common::IggyError;
+use ahash::{AHashMap, AHashSet};
+use iggy_common::{ConsumerGroupAssignment, ConsumerGroupAssignmentStrategy, IggyError};
 use tokio::sync::RwLock;
 use tracing::trace;
 
@@ -27,7 +27,10 @@ pub struct ConsumerGroup {
     pub group_id: u32,
     pub name: String,
     pub partitions_count: u32,
+    pub assignment_strategy: ConsumerGroupAssignmentStrategy,
+    generation: u32,
     members: AHashMap<u32, RwLock<ConsumerGroupMember>>,
+    handovers: AHashMap<u32, PartitionHandover>,
 }
 
 #[derive(Debug)]
@@ -36,16 +39,36 @@ pub struct ConsumerGroupMember {
     partitions: AHashMap<u32, u32>,
     current_partition_index: Option<u32>,
     current_partition_id: Option<u32>,
+    assigned_partitions: Vec<(u32, u32)>,
+    revoked_partitions: Vec<(u32, u32)>,
+}
+
+/// The partition revoked from the member, which is assigned to the other member
+/// only once the revoking member acknowledges the rebalance (or leaves the group).
+#[derive(Debug, Clone, Copy)]
+struct PartitionHandover {
+    revoking_member_id: u32,
+    assigned_member_id: u32,
+    generation: u32,
 }
 
 impl ConsumerGroup {
-    pub fn new(topic_id: u32, group_id: u32, name: &str, partitions_count: u32) -> ConsumerGroup {
+    pub fn new(
+        topic_id: u32,
+        group_id: u32,
+        name: &str,
+        partitions_count: u32,
+        assignment_strategy: ConsumerGroupAssignmentStrategy,
+    ) -> ConsumerGroup {
         ConsumerGroup {
             topic_id,
             group_id,
             name: name.to_string(),
             partitions_count,
+            assignment_strategy,
+            generation: 0,
             members: AHashMap::new(),
+            handovers: AHashMap::new(),
         }
     }
 
@@ -55,6 +78,8 @@ impl ConsumerGroup {
 
     pub async fn reassign_partitions(&mut self, partitions_count: u32) {
         self.partitions_count = partitions_count;
+        self.handovers
+            .retain(|partition_id, _| *partition_id <= partitions_count);
         self.assign_partitions().await;
     }
 
@@ -90,6 +115,8 @@ impl ConsumerGroup {
                 partitions: AHashMap::new(),
                 current_partition_index: None,
                 current_partition_id: None,
+                assigned_partitions: Vec::new(),
+                revoked_partitions: Vec::new(),
             }),
         );
         trace!(
@@ -105,50 +132,295 @@ impl ConsumerGroup {
                 "Deleted member with ID: {} in consumer group: {} for topic with ID: {}",
                 member_id, self.group_id, self.topic_id
             );
+            // The partitions revoked from the deleted member no longer wait for its acknowledgement.
+            self.handovers
+                .retain(|_, handover| handover.revoking_member_id != member_id);
             self.assign_partitions().await;
         }
     }
 
+    /// Returns the current assignment of the member, and acknowledges the rebalances up to the provided generation,
+    /// which hands over the partitions revoked from the member to their new owners.
+    pub async fn sync_member(
+        &mut self,
+        member_id: u32,
+        generation: u32,
+    ) -> Result<ConsumerGroupAssignment, IggyError> {
+        let Some(member) = self.members.get_mut(&member_id) else {
+            return Err(IggyError::ConsumerGroupMemberNotFound(
+                member_id,
+                self.group_id,
+                self.topic_id,
+            ));
+        };
+
+        if generation > 0 {
+            let member = member.get_mut();
+            member
+                .assigned_partitions
+                .retain(|(_, assigned_at)| *assigned_at > generation);
+            member
+                .revoked_partitions
+                .retain(|(_, revoked_at)| *revoked_at > generation);
+            self.complete_handovers(member_id, generation);
+        }
+
+        let member = self.members[&member_id].read().await;
+        let mut partitions = member.get_partitions();
+        partitions.sort_unstable();
+        Ok(ConsumerGroupAssignment {
+            generation: self.generation,
+            partitions,
+            assigned_partitions: member
+                .assigned_partitions
+                .iter()
+                .map(|(partition_id, _)| *partition_id)
+                .collect(),
+            revoked_partitions: member
+                .revoked_partitions
+                .iter()
+                .map(|(partition_id, _)| *partition_id)
+                .collect(),
+        })
+    }
+
+    fn complete_handovers(&mut self, revoking_member_id: u32, generation: u32) {
+        let completed_handovers = self
+            .handovers
+            .iter()
+            .filter(|(_, handover)| {
+                handover.revoking_member_id == revoking_member_id
+                    && handover.generation <= generation
+            })
+            .map(|(partition_id, handover)| (*partition_id, *handover))
+            .collect::<Vec<_>>();
+        if completed_handovers.is_empty() {
+            return;
+        }
+
+        self.generation += 1;
+        for (partition_id, handover) in completed_handovers {
+            self.handovers.remove(&partition_id);
+            let Some(member) = self.members.get_mut(&handover.assigned_member_id) else {
+                continue;
+            };
+
+            let member = member.get_mut();
+            let mut partitions = member.get_partitions();
+            partitions.push(partition_id);
+            member.set_partitions(partitions);
+            member
+                .assigned_partitions
+                .push((partition_id, self.generation));
+            trace!(
+                "Handed over partition ID: {} from member with ID: {} to member with ID: {} for topic with ID: {} in consumer group: {}",
+                partition_id, revoking_member_id, member.id, self.topic_id, self.group_id
+            );
+        }
+    }
+
     async fn assign_partitions(&mut self) {
-        let mut members = self.members.values_mut().collect::<Vec<_>>();
-        if members.is_empty() {
+        if self.members.is_empty() {
             return;
         }
 
-        let members_count = members.len() as u32;
-        for member in members.iter_mut() {
-            let mut member = member.write().await;
-            member.current_partition_index = None;
-            member.current_partition_id = None;
-            member.partitions.clear();
+        let mut member_ids = self.members.keys().copied().collect::<Vec<_>>();
+        member_ids.sort_unstable();
+        let assignment = match self.assignment_strategy {
+            ConsumerGroupAssignmentStrategy::RoundRobin => {
+                calculate_round_robin_assignment(&member_ids, self.partitions_count)
+            }
+            ConsumerGroupAssignmentStrategy::CooperativeSticky => {
+                let mut current_assignment = AHashMap::with_capacity(member_ids.len());
+                for (member_id, member) in self.members.iter() {
+                    let member = member.read().await;
+                    current_assignment.insert(*member_id, member.get_partitions());
+                }
+                for (partition_id, handover) in self.handovers.iter() {
+                    if let Some(partitions) =
+                        current_assignment.get_mut(&handover.assigned_member_id)
+                    {
+                        partitions.push(*partition_id);
+                    }
+                }
+                calculate_sticky_assignment(&member_ids, &current_assignment, self.partitions_count)
+            }
+        };
+
+        self.generation += 1;
+        self.apply_assignment(assignment);
+    }
+
+    fn apply_assignment(&mut self, mut assignment: AHashMap<u32, Vec<u32>>) {
+        let generation = self.generation;
+        let cooperative = self.assignment_strategy.is_cooperative();
+        let mut owners = AHashMap::new();
+        for (member_id, member) in self.members.iter_mut() {
+            for partition_id in member.get_mut().get_partitions() {
+                owners.insert(partition_id, *member_id);
+            }
         }
 
-        for partition_index in 0..self.partitions_count {
-            let partition_id = partition_index + 1;
-            let member_index = partition_index % members_count;
-            let member = members.get(member_index as usize).unwrap();
-            let mut member = member.write().await;
-            let member_partition_index = member.partitions.len() as u32;
-            member
-                .partitions
-                .insert(member_partition_index, partition_id);
-            if member.current_partition_id.is_none() {
-                member.current_partition_id = Some(partition_id);
-                member.current_partition_index = Some(member_partition_index);
+        let mut handovers = AHashMap::new();
+        for (member_id, partitions) in assignment.iter_mut() {
+            partitions.retain(|partition_id| {
+                if !cooperative {
+                    return true;
+                }
+
+                if let Some(handover) = self.handovers.get(partition_id) {
+                    if handover.revoking_member_id != *member_id {
+                        handovers.insert(
+                            *partition_id,
+                            PartitionHandover {
+                                assigned_member_id: *member_id,
+                                ..*handover
+                            },
+                        );
+                        return false;
+                    }
+                    // The partition returns to the member it was revoked from, so there's nothing to wait for.
+                    return true;
+                }
+
+                match owners.get(partition_id) {
+                    Some(owner_id) if owner_id != member_id => {
+                        handovers.insert(
+                            *partition_id,
+                            PartitionHandover {
+                                revoking_member_id: *owner_id,
+                                assigned_member_id: *member_id,
+                                generation,
+                            },
+                        );
+                        false
+                    }
+                    _ => true,
+                }
+            });
+        }
+        self.handovers = handovers;
+
+        for (member_id, member) in self.members.iter_mut() {
+            let member = member.get_mut();
+            let current_partitions = member.get_partitions().into_iter().collect::<AHashSet<_>>();
+            let partitions = assignment.remove(member_id).unwrap_or_default();
+            let new_partitions = partitions.iter().copied().collect::<AHashSet<_>>();
+            if current_partitions == new_partitions {
+                continue;
+            }
+
+            for partition_id in current_partitions.difference(&new_partitions) {
+                member.revoked_partitions.push((*partition_id, generation));
             }
+            for partition_id in new_partitions.difference(&current_partitions) {
+                member.assigned_partitions.push((*partition_id, generation));
+            }
+            member.set_partitions(partitions);
             trace!(
-                "Assigned partition ID: {} to member with ID: {} for topic with ID: {} in consumer group: {}",
-                partition_id, member.id, self.topic_id, self.group_id
+                "Assigned partitions: {:?} to member with ID: {} for topic with ID: {} in consumer group: {}",
+                member.get_partitions(),
+                member.id,
+                self.topic_id,
+                self.group_id
             )
         }
     }
 }
 
+/// Assigns all the partitions to the members using round-robin, regardless of the current assignment.
+fn calculate_round_robin_assignment(
+    member_ids: &[u32],
+    partitions_count: u32,
+) -> AHashMap<u32, Vec<u32>> {
+    let mut assignment = member_ids
+        .iter()
+        .map(|member_id| (*member_id, Vec::new()))
+        .collect::<AHashMap<_, _>>();
+    for partition_index in 0..partitions_count {
+        let member_id = member_ids[(partition_index as usize) % member_ids.len()];
+        if let Some(partitions) = assignment.get_mut(&member_id) {
+            partitions.push(partition_index + 1);
+        }
+    }
+    assignment
+}
+
+/// Balances the partitions between the members, keeping as many of the currently assigned partitions as possible.
+fn calculate_sticky_assignment(
+    member_ids: &[u32],
+    current_assignment: &AHashMap<u32, Vec<u32>>,
+    partitions_count: u32,
+) -> AHashMap<u32, Vec<u32>> {
+    let members_count = member_ids.len() as u32;
+    let min_partitions = partitions_count / members_count;
+    let mut members_with_extra_partition = partitions_count % members_count;
+
+    // The members currently owning the most partitions are given the extra ones, so that fewer partitions are moved.
+    let mut members = member_ids.to_vec();
+    members.sort_by_key(|member_id| {
+        let owned = current_assignment.get(member_id).map_or(0, Vec::len);
+        (std::cmp::Reverse(owned), *member_id)
+    });
+
+    let mut assigned = AHashSet::new();
+    let mut quotas = AHashMap::with_capacity(members.len());
+    let mut assignment = AHashMap::with_capacity(members.len());
+    for member_id in members {
+        let quota = if members_with_extra_partition > 0 {
+            members_with_extra_partition -= 1;
+            min_partitions + 1
+        } else {
+            min_partitions
+        };
+
+        let mut partitions = current_assignment
+            .get(&member_id)
+            .cloned()
+            .unwrap_or_default();
+        partitions.sort_unstable();
+        partitions.retain(|partition_id| {
+            *partition_id >= 1
+                && *partition_id <= partitions_count
+                && assigned.insert(*partition_id)
+        });
+        for partition_id in partitions.drain((quota as usize).min(partitions.len())..) {
+            assigned.remove(&partition_id);
+        }
+        quotas.insert(member_id, quota as usize);
+        assignment.insert(member_id, partitions);
+    }
+
+    let mut unassigned_partitions =
+        (1..=partitions_count).filter(|partition_id| !assigned.contains(partition_id));
+    for member_id in member_ids {
+        let partitions = assignment.get_mut(member_id).unwrap();
+        while partitions.len() < quotas[member_id] {
+            let Some(partition_id) = unassigned_partitions.next() else {
+                break;
+            };
+            partitions.push(partition_id);
+        }
+    }
+    assignment
+}
+
 impl ConsumerGroupMember {
     pub fn get_partitions(&self) -> Vec<u32> {
         self.partitions.values().copied().collect()
     }
 
+    fn set_partitions(&mut self, mut partitions: Vec<u32>) {
+        partitions.sort_unstable();
+        self.partitions = partitions
+            .iter()
+            .enumerate()
+            .map(|(index, partition_id)| (index as u32, *partition_id))
+            .collect();
+        self.current_partition_id = partitions.first().copied();
+        self.current_partition_index = self.current_partition_id.map(|_| 0);
+    }
+
     pub fn calculate_partition_id(&mut self) -> Option<u32> {
         let partition_index = self.current_partition_index?;
         let Some(partition_id) = self.partitions.get(&partition_index) else {
@@ -181,13 +453,8 @@ mod tests {
     #[tokio::test]
     async fn should_calculate_partition_id_using_round_robin() {
         let member_id = 123;
-        let mut consumer_group = ConsumerGroup {
-            topic_id: 1,
-            group_id: 1,
-            name: "test".to_string(),
-            partitions_count: 3,
-            members: AHashMap::new(),
-        };
+        let mut consumer_group =
+            ConsumerGroup::new(1, 1, "test", 3, ConsumerGroupAssignmentStrategy::RoundRobin);
 
         consumer_group.add_member(member_id).await;
         for i in 0..1000 {
@@ -203,13 +470,8 @@ mod tests {
     #[tokio::test]
     async fn should_assign_all_partitions_to_the_only_single_member() {
         let member_id = 123;
-        let mut consumer_group = ConsumerGroup {
-            topic_id: 1,
-            group_id: 1,
-            name: "test".to_string(),
-            partitions_count: 3,
-            members: AHashMap::new(),
-        };
+        let mut consumer_group =
+            ConsumerGroup::new(1, 1, "test", 3, ConsumerGroupAssignmentStrategy::RoundRobin);
 
         consumer_group.add_member(member_id).await;
         let member = consumer_group.members.get(&member_id).unwrap();
@@ -228,13 +490,8 @@ mod tests {
     async fn should_assign_partitions_to_the_multiple_members() {
         let member1_id = 123;
         let member2_id = 456;
-        let mut consumer_group = ConsumerGroup {
-            topic_id: 1,
-            group_id: 1,
-            name: "test".to_string(),
-            partitions_count: 3,
-            members: AHashMap::new(),
-        };
+        let mut consumer_group =
+            ConsumerGroup::new(1, 1, "test", 3, ConsumerGroupAssignmentStrategy::RoundRobin);
 
         consumer_group.add_member(member1_id).await;
         consumer_group.add_member(member2_id).await;
@@ -265,13 +522,8 @@ mod tests {
     async fn should_assign_only_single_partition_to_the_only_single_member() {
         let member1_id = 123;
         let member2_id = 456;
-        let mut consumer_group = ConsumerGroup {
-            topic_id: 1,
-            group_id: 1,
-            name: "test".to_string(),
-            partitions_count: 1,
-            members: AHashMap::new(),
-        };
+        let mut consumer_group =
+            ConsumerGroup::new(1, 1, "test", 1, ConsumerGroupAssignmentStrategy::RoundRobin);
 
         consumer_group.add_member(member1_id).await;
         consumer_group.add_member(member2_id).await;
@@ -286,4 +538,66 @@ mod tests {
             assert_eq!(member2.partitions.len(), 1);
         }
     }
+
+    #[test]
+    fn sticky_assignment_should_move_only_the_partitions_required_to_balance_the_group() {
+        let current_assignment = AHashMap::from([(1, vec![1, 2, 3]), (2, vec![4, 5, 6])]);
+
+        let assignment = calculate_sticky_assignment(&[1, 2, 3], &current_assignment, 6);
+
+        assert_eq!(assignment[&1], vec![1, 2]);
+        assert_eq!(assignment[&2], vec![4, 5]);
+        assert_eq!(assignment[&3], vec![3, 6]);
+    }
+
+    #[tokio::test]
+    async fn cooperative_rebalance_should_hand_over_revoked_partition_after_acknowledgement() {
+        let member1_id = 1;
+        let member2_id = 2;
+        let mut consumer_group = ConsumerGroup::new(
+            1,
+            1,
+            "test",
+            2,
+            ConsumerGroupAssignmentStrategy::CooperativeSticky,
+        );
+
+        consumer_group.add_member(member1_id).await;
+        consumer_group.add_member(member2_id).await;
+
+        let member1_assignment = consumer_group.sync_member(member1_id, 0).await.unwrap();
+        let member2_assignment = consumer_group.sync_member(member2_id, 0).await.unwrap();
+        assert_eq!(member1_assignment.partitions, vec![1]);
+        assert_eq!(member1_assignment.revoked_partitions, vec![2]);
+        assert!(member2_assignment.partitions.is_empty());
+
+        let member1_assignment = consumer_group
+            .sync_member(member1_id, member1_assignment.generation)
+            .await
+            .unwrap();
+        let member2_assignment = consumer_group.sync_member(member2_id, 0).await.unwrap();
+        assert!(member1_assignment.revoked_partitions.is_empty());
+        assert_eq!(member2_assignment.partitions, vec![2]);
+        assert_eq!(member2_assignment.assigned_partitions, vec![2]);
+    }
+
+    #[tokio::test]
+    async fn cooperative_rebalance_should_hand_over_revoked_partition_when_member_leaves() {
+        let member1_id = 1;
+        let member2_id = 2;
+        let mut consumer_group = ConsumerGroup::new(
+            1,
+            1,
+            "test",
+            2,
+            ConsumerGroupAssignmentStrategy::CooperativeSticky,
+        );
+
+        consumer_group.add_member(member1_id).await;
+        consumer_group.add_member(member2_id).await;
+        consumer_group.delete_member(member1_id).await;
+
+        let member2_assignment = consumer_group.sync_member(member2_id, 0).await.unwrap();
+        assert_eq!(member2_assignment.partitions, vec![1, 2]);
+    }
 }
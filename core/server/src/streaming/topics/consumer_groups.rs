@@ -20,8 +20,8 @@ use crate::streaming::topics::COMPONENT;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
 use error_set::ErrContext;
-use iggy_common::IggyError;
 use iggy_common::locking::IggySharedMutFn;
+use iggy_common::{ConsumerGroupAssignmentStrategy, ConsumerKind, IggyError};
 use iggy_common::{IdKind, Identifier};
 use std::sync::atomic::Ordering;
 use tokio::sync::RwLock;
@@ -104,6 +104,7 @@ impl Topic {
         &mut self,
         group_id: Option<u32>,
         name: &str,
+        assignment_strategy: ConsumerGroupAssignmentStrategy,
     ) -> Result<&RwLock<ConsumerGroup>, IggyError> {
         if self.consumer_groups_ids.contains_key(name) {
             return Err(IggyError::ConsumerGroupNameAlreadyExists(
@@ -137,8 +138,13 @@ impl Topic {
             return Err(IggyError::ConsumerGroupIdAlreadyExists(id, self.topic_id));
         }
 
-        let consumer_group =
-            ConsumerGroup::new(self.topic_id, id, name, self.partitions.len() as u32);
+        let consumer_group = ConsumerGroup::new(
+            self.topic_id,
+            id,
+            name,
+            self.partitions.len() as u32,
+            assignment_strategy,
+        );
         self.consumer_groups.insert(id, RwLock::new(consumer_group));
         self.consumer_groups_ids.insert(name.to_owned(), id);
         info!(
@@ -184,6 +190,18 @@ impl Topic {
                         .delete_consumer_offset(&offset.path)
                         .await?;
                 }
+                partition
+                    .delete_delayed_messages(ConsumerKind::ConsumerGroup, group_id)
+                    .await?;
+                if partition.delivery_attempts.remove(&group_id).is_some() {
+                    self.storage
+                        .partition
+                        .delete_delivery_attempts(&format!(
+                            "{}/{group_id}",
+                            partition.delivery_attempts_path
+                        ))
+                        .await?;
+                }
             }
 
             info!(
@@ -228,6 +246,34 @@ impl Topic {
         );
         Ok(())
     }
+
+    pub async fn record_delivery_failure(
+        &self,
+        group_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+    ) -> Result<u32, IggyError> {
+        let consumer_group = self.get_consumer_group(group_id).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get consumer group with id: {group_id}")
+        })?;
+        let group_id = consumer_group.read().await.group_id;
+        let partition = self
+            .get_partition(partition_id)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to get partition with id: {partition_id}"
+                )
+            })?;
+        let partition = partition.read().await;
+        partition
+            .record_delivery_failure(group_id, offset)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to record delivery failure of message at offset: {offset}, consumer group ID: {group_id}, partition ID: {partition_id}"
+                )
+            })
+    }
 }
 
 #[cfg(test)]
@@ -247,7 +293,13 @@ mod tests {
         let name = "test";
         let mut topic = get_topic().await;
         let topic_id = topic.topic_id;
-        let result = topic.create_consumer_group(Some(group_id), name).await;
+        let result = topic
+            .create_consumer_group(
+                Some(group_id),
+                name,
+                ConsumerGroupAssignmentStrategy::default(),
+            )
+            .await;
         assert!(result.is_ok());
         {
             let created_consumer_group = result.unwrap().read().await;
@@ -275,10 +327,22 @@ mod tests {
         let group_id = 1;
         let name = "test";
         let mut topic = get_topic().await;
-        let result = topic.create_consumer_group(Some(group_id), name).await;
+        let result = topic
+            .create_consumer_group(
+                Some(group_id),
+                name,
+                ConsumerGroupAssignmentStrategy::default(),
+            )
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
-        let result = topic.create_consumer_group(Some(group_id), "test2").await;
+        let result = topic
+            .create_consumer_group(
+                Some(group_id),
+                "test2",
+                ConsumerGroupAssignmentStrategy::default(),
+            )
+            .await;
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert!(matches!(err, IggyError::ConsumerGroupIdAlreadyExists(_, _)));
@@ -290,11 +354,23 @@ mod tests {
         let group_id = 1;
         let name = "test";
         let mut topic = get_topic().await;
-        let result = topic.create_consumer_group(Some(group_id), name).await;
+        let result = topic
+            .create_consumer_group(
+                Some(group_id),
+                name,
+                ConsumerGroupAssignmentStrategy::default(),
+            )
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
         let group_id = group_id + 1;
-        let result = topic.create_consumer_group(Some(group_id), name).await;
+        let result = topic
+            .create_consumer_group(
+                Some(group_id),
+                name,
+                ConsumerGroupAssignmentStrategy::default(),
+            )
+            .await;
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert!(matches!(
@@ -309,7 +385,13 @@ mod tests {
         let group_id = 1;
         let name = "test";
         let mut topic = get_topic().await;
-        let result = topic.create_consumer_group(Some(group_id), name).await;
+        let result = topic
+            .create_consumer_group(
+                Some(group_id),
+                name,
+                ConsumerGroupAssignmentStrategy::default(),
+            )
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
         let result = topic
@@ -324,7 +406,13 @@ mod tests {
         let group_id = 1;
         let name = "test";
         let mut topic = get_topic().await;
-        let result = topic.create_consumer_group(Some(group_id), name).await;
+        let result = topic
+            .create_consumer_group(
+                Some(group_id),
+                name,
+                ConsumerGroupAssignmentStrategy::default(),
+            )
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
         let group_id = group_id + 1;
@@ -342,7 +430,11 @@ mod tests {
         let member_id = 1;
         let mut topic = get_topic().await;
         topic
-            .create_consumer_group(Some(group_id), name)
+            .create_consumer_group(
+                Some(group_id),
+                name,
+                ConsumerGroupAssignmentStrategy::default(),
+            )
             .await
             .unwrap();
         let result = topic
@@ -365,7 +457,11 @@ mod tests {
         let member_id = 1;
         let mut topic = get_topic().await;
         topic
-            .create_consumer_group(Some(group_id), name)
+            .create_consumer_group(
+                Some(group_id),
+                name,
+                ConsumerGroupAssignmentStrategy::default(),
+            )
             .await
             .unwrap();
         topic
@@ -22,16 +22,23 @@ use crate::streaming::topics::topic::Topic;
 use error_set::ErrContext;
 use iggy_common::IggyError;
 use iggy_common::locking::IggySharedMutFn;
-use iggy_common::{Consumer, ConsumerOffsetInfo};
+use iggy_common::{
+    Consumer, ConsumerGroupOffsetReset, ConsumerKind, ConsumerOffsetInfo,
+    ConsumerOffsetsResetTarget, ConsumerPartitionOffset, Identifier, IggyTimestamp,
+};
+use tracing::error;
 
 impl Topic {
+    /// Stores the offset for the resolved partition and returns the resolved consumer and partition IDs.
     pub async fn store_consumer_offset(
         &self,
         consumer: Consumer,
         offset: u64,
+        metadata: Option<String>,
+        committed_at: IggyTimestamp,
         partition_id: Option<u32>,
         client_id: u32,
-    ) -> Result<(), IggyError> {
+    ) -> Result<(u32, u32), IggyError> {
         let Some((polling_consumer, partition_id)) = self
             .resolve_consumer_with_partition_id(&consumer, client_id, partition_id, false)
             .await
@@ -48,9 +55,186 @@ impl Topic {
             })?;
         let partition = partition.read().await;
         partition
-            .store_consumer_offset(polling_consumer, offset)
+            .store_consumer_offset(polling_consumer, offset, metadata, committed_at)
             .await
-            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to store consumer offset, consumer: {polling_consumer}, offset: {offset}"))
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to store consumer offset, consumer: {polling_consumer}, offset: {offset}"))?;
+        Ok((polling_consumer.id(), partition_id))
+    }
+
+    /// Stores the offsets for many partitions at once, none of them is stored if any of the offsets is invalid.
+    /// Returns the resolved consumer ID.
+    pub async fn store_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        offsets: &[ConsumerPartitionOffset],
+        committed_at: IggyTimestamp,
+        client_id: u32,
+    ) -> Result<u32, IggyError> {
+        let polling_consumer = match consumer.kind {
+            ConsumerKind::Consumer => PollingConsumer::consumer(&consumer.id, 0),
+            ConsumerKind::ConsumerGroup => {
+                let consumer_group = self.get_consumer_group(&consumer.id)
+                    .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to get consumer group with ID: {}", consumer.id))?
+                    .read()
+                    .await;
+                PollingConsumer::consumer_group(consumer_group.group_id, client_id)
+            }
+        };
+
+        let mut partitions = Vec::with_capacity(offsets.len());
+        for offset in offsets {
+            let partition_id = offset.partition_id;
+            let partition = self
+                .get_partition(partition_id)
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to get partition with id: {partition_id}"
+                    )
+                })?;
+            partitions.push(partition);
+        }
+
+        // The partitions are locked until all the offsets are stored, so that none of them can be purged in between.
+        let mut locked_partitions = Vec::with_capacity(partitions.len());
+        for (partition, offset) in partitions.iter().zip(offsets) {
+            let partition = partition.read().await;
+            partition.validate_consumer_offset(offset.offset)?;
+            locked_partitions.push(partition);
+        }
+
+        // The offsets already stored are reverted if storing any of the next ones fails, so that either all or none of them are stored.
+        let mut previous_offsets = Vec::with_capacity(locked_partitions.len());
+        for (partition, offset) in locked_partitions.iter().zip(offsets) {
+            let polling_consumer = match polling_consumer {
+                PollingConsumer::Consumer(consumer_id, _) => {
+                    PollingConsumer::Consumer(consumer_id, offset.partition_id)
+                }
+                consumer_group => consumer_group,
+            };
+            let previous_offset = partition.get_consumer_offset(polling_consumer).await?;
+            previous_offsets.push((polling_consumer, previous_offset));
+            if let Err(error) = partition
+                .store_consumer_offset(
+                    polling_consumer,
+                    offset.offset,
+                    offset.metadata.clone(),
+                    committed_at,
+                )
+                .await
+            {
+                for ((polling_consumer, previous_offset), partition) in
+                    previous_offsets.into_iter().zip(&locked_partitions)
+                {
+                    if let Err(revert_error) = partition
+                        .revert_consumer_offset(polling_consumer, previous_offset)
+                        .await
+                    {
+                        error!(
+                            "Failed to revert consumer offset for {polling_consumer}, partition ID: {}. Error: {revert_error}",
+                            partition.partition_id
+                        );
+                    }
+                }
+                return Err(error).with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to store consumer offset, consumer: {polling_consumer}, offset: {}", offset.offset));
+            }
+        }
+
+        Ok(polling_consumer.id())
+    }
+
+    /// Resolves the offsets of the consumer group reset to the target for each partition and stores them, unless it's a dry run.
+    /// Returns the resolved consumer group ID along with the old and new offset of each partition.
+    pub async fn reset_consumer_group_offsets(
+        &self,
+        group_id: &Identifier,
+        target: &ConsumerOffsetsResetTarget,
+        dry_run: bool,
+        force: bool,
+        committed_at: IggyTimestamp,
+    ) -> Result<(u32, Vec<ConsumerGroupOffsetReset>), IggyError> {
+        let consumer_group = self.get_consumer_group(group_id)
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to get consumer group with ID: {group_id}"))?
+            .read()
+            .await;
+        if !force && !consumer_group.get_members().is_empty() {
+            return Err(IggyError::ConsumerGroupHasActiveMembers(
+                consumer_group.group_id,
+                self.topic_id,
+            ));
+        }
+
+        let polling_consumer = PollingConsumer::consumer_group(consumer_group.group_id, 0);
+        let mut partition_ids = match target {
+            ConsumerOffsetsResetTarget::Offsets(offsets) => offsets
+                .iter()
+                .map(|offset| offset.partition_id)
+                .collect::<Vec<_>>(),
+            _ => self.partitions.keys().copied().collect(),
+        };
+        partition_ids.sort_unstable();
+
+        // All the offsets are resolved before any of them is stored, so that none of them is stored if any is invalid.
+        let mut resets = Vec::with_capacity(partition_ids.len());
+        for partition_id in partition_ids {
+            let partition = self
+                .get_partition(partition_id)
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to get partition with id: {partition_id}"
+                    )
+                })?;
+            let partition = partition.read().await;
+            let old_offset = partition
+                .get_consumer_offset(polling_consumer)
+                .await?
+                .map(|consumer_offset| consumer_offset.offset);
+            let new_offset = partition
+                .resolve_reset_consumer_offset(target, old_offset)
+                .await
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to resolve consumer offset reset to: {target}, partition ID: {partition_id}"))?;
+            resets.push(ConsumerGroupOffsetReset {
+                partition_id,
+                current_offset: partition.current_offset,
+                old_offset,
+                new_offset,
+            });
+        }
+
+        if dry_run {
+            return Ok((consumer_group.group_id, resets));
+        }
+
+        for reset in &resets {
+            let partition = self.get_partition(reset.partition_id)?;
+            match reset.new_offset {
+                Some(offset) => {
+                    let metadata = match target {
+                        ConsumerOffsetsResetTarget::Offsets(offsets) => offsets
+                            .iter()
+                            .find(|offset| offset.partition_id == reset.partition_id)
+                            .and_then(|offset| offset.metadata.clone()),
+                        _ => None,
+                    };
+                    partition
+                        .read()
+                        .await
+                        .store_consumer_offset(polling_consumer, offset, metadata, committed_at)
+                        .await
+                        .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to store consumer offset, consumer: {polling_consumer}, offset: {offset}"))?;
+                }
+                None if reset.old_offset.is_some() => {
+                    partition
+                        .write()
+                        .await
+                        .delete_consumer_offset(polling_consumer)
+                        .await
+                        .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to delete consumer offset, consumer: {polling_consumer}, partition ID: {}", reset.partition_id))?;
+                }
+                None => {}
+            }
+        }
+
+        Ok((consumer_group.group_id, resets))
     }
 
     pub async fn store_consumer_offset_internal(
@@ -67,7 +251,9 @@ impl Topic {
                 )
             })?;
         let partition = partition.read().await;
-        partition.store_consumer_offset(consumer, offset).await.with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to store consumer offset, consumer: {consumer}, offset: {offset}"))
+        partition
+            .store_consumer_offset(consumer, offset, None, IggyTimestamp::now())
+            .await.with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to store consumer offset, consumer: {consumer}, offset: {offset}"))
     }
 
     pub async fn get_consumer_offset(
@@ -91,7 +277,7 @@ impl Topic {
                 )
             })?;
         let partition = partition.read().await;
-        let offset = partition
+        let consumer_offset = partition
             .get_consumer_offset(polling_consumer)
             .await
             .with_error_context(|error| {
@@ -99,14 +285,16 @@ impl Topic {
                     "{COMPONENT} (error: {error}) - failed to get consumer offset for consumer: {polling_consumer}"
                 )
             })?;
-        let Some(offset) = offset else {
+        let Some(consumer_offset) = consumer_offset else {
             return Ok(None);
         };
 
         Ok(Some(ConsumerOffsetInfo {
             partition_id: partition.partition_id,
             current_offset: partition.current_offset,
-            stored_offset: offset,
+            stored_offset: consumer_offset.offset,
+            metadata: consumer_offset.metadata,
+            committed_at: consumer_offset.committed_at,
         }))
     }
 
@@ -115,7 +303,7 @@ impl Topic {
         consumer: Consumer,
         partition_id: Option<u32>,
         client_id: u32,
-    ) -> Result<(), IggyError> {
+    ) -> Result<(u32, u32), IggyError> {
         let Some((polling_consumer, partition_id)) = self
             .resolve_consumer_with_partition_id(&consumer, client_id, partition_id, false)
             .await
@@ -139,6 +327,7 @@ impl Topic {
                     "{COMPONENT} (error: {error}) - failed to delete consumer offset for consumer: {polling_consumer}, in topic with ID: {}, partition ID: {partition_id}",
                     self.topic_id
                 )
-            })
+            })?;
+        Ok((polling_consumer.id(), partition_id))
     }
 }
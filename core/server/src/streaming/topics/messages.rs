@@ -21,13 +21,18 @@ use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::segments::{IggyMessagesBatchMut, IggyMessagesBatchSet};
 use crate::streaming::topics::COMPONENT;
 use crate::streaming::topics::topic::Topic;
-use crate::streaming::utils::hash;
+use crate::streaming::transactions::markers::TransactionOutcome;
 use ahash::AHashMap;
 use error_set::ErrContext;
 use iggy_common::locking::IggySharedMutFn;
 use iggy_common::{Confirmation, IggyTimestamp, PollingStrategy};
-use iggy_common::{IggyError, IggyExpiry, Partitioning, PartitioningKind, PollingKind};
+use iggy_common::{
+    IggyError, IggyExpiry, IsolationLevel, MessageFilter, Partitioning, PartitioningKind,
+    PollingKind, ProducerSequence,
+};
+use std::sync::Arc;
 use std::sync::atomic::Ordering;
+use tokio::sync::Notify;
 use tracing::trace;
 
 impl Topic {
@@ -35,12 +40,28 @@ impl Topic {
         self.messages_count.load(Ordering::SeqCst)
     }
 
+    /// Returns the notifications of the messages appended to the partition, or to any partition if it's not specified.
+    pub(crate) async fn get_messages_appended_notifications(
+        &self,
+        partition_id: Option<u32>,
+    ) -> Vec<Arc<Notify>> {
+        let mut notifications = Vec::new();
+        for (id, partition) in &self.partitions {
+            if partition_id.is_none_or(|partition_id| partition_id == *id) {
+                notifications.push(partition.read().await.messages_appended.clone());
+            }
+        }
+        notifications
+    }
+
     pub async fn get_messages(
         &self,
         consumer: PollingConsumer,
         partition_id: u32,
         strategy: PollingStrategy,
         count: u32,
+        filter: Option<&MessageFilter>,
+        isolation_level: IsolationLevel,
     ) -> Result<(IggyPollMetadata, IggyMessagesBatchSet), IggyError> {
         if !self.has_partitions() {
             return Err(IggyError::NoPartitions(self.topic_id, self.stream_id));
@@ -56,8 +77,45 @@ impl Topic {
         }
 
         let partition = partition.unwrap();
+        // The offloaded segments are fetched from the remote tier without holding the partition lock,
+        // so that the slow fetch doesn't block the appends and the other polls.
+        let fetches = partition
+            .read()
+            .await
+            .get_offloaded_segment_fetches(consumer, &strategy, count)
+            .await;
+        for fetch in fetches {
+            fetch.run().await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to fetch offloaded segment for partition: {partition_id}")
+            })?;
+        }
+
         let partition = partition.read().await;
         let value = strategy.value;
+        // Delayed delivery applies only to the consumers polling the next messages, as only those are tracked,
+        // and only to the partitions which have ever received the messages scheduled for the later delivery.
+        let now = IggyTimestamp::now();
+        let delayed = strategy.kind == PollingKind::Next && partition.has_scheduled_messages;
+        let (due_messages, due_delayed_messages) = if delayed {
+            partition
+                .get_due_delayed_messages(consumer, now, count)
+                .await
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to get due delayed messages for partition: {partition_id}"))?
+        } else {
+            (IggyMessagesBatchSet::empty(), Vec::new())
+        };
+        let count = count - due_messages.count();
+        if count == 0 {
+            // The due messages precede the consumer offset, so there's nothing to commit.
+            let mut metadata = IggyPollMetadata::new(partition_id, partition.current_offset);
+            metadata.due_delayed_messages = due_delayed_messages;
+            metadata.next_offset = Some(0);
+            partition
+                .throughput
+                .record_out(due_messages.count(), due_messages.size() as u64);
+            return Ok((metadata, due_messages));
+        }
+
         let messages = match strategy.kind {
             PollingKind::Offset => partition.get_messages_by_offset(value, count).await,
             PollingKind::Timestamp => {
@@ -71,9 +129,96 @@ impl Topic {
             PollingKind::Next => partition.get_next_messages(consumer, count).await,
         }?;
 
-        let metadata = IggyPollMetadata::new(partition_id, partition.current_offset);
+        let mut metadata = IggyPollMetadata::new(partition_id, partition.current_offset);
+        metadata.due_delayed_messages = due_delayed_messages;
+        let has_scheduled_messages = delayed
+            && messages.iter().any(|batch| {
+                batch.iter().any(|message| {
+                    message
+                        .deliver_at()
+                        .is_some_and(|deliver_at| deliver_at.as_micros() > now.as_micros())
+                })
+            });
+        if filter.is_none()
+            && isolation_level == IsolationLevel::ReadUncommitted
+            && !has_scheduled_messages
+        {
+            if due_messages.is_empty() {
+                partition
+                    .throughput
+                    .record_out(messages.count(), messages.size() as u64);
+                return Ok((metadata, messages));
+            }
 
-        Ok((metadata, messages))
+            // The due messages precede the consumer offset, so the offset to commit must be based on the rest,
+            // and there's nothing to commit if only the due messages are returned.
+            metadata.next_offset = Some(messages.last_offset().map_or(0, |offset| offset + 1));
+            let mut due_messages = due_messages;
+            due_messages.add_batch_set(messages);
+            partition
+                .throughput
+                .record_out(due_messages.count(), due_messages.size() as u64);
+            return Ok((metadata, due_messages));
+        }
+
+        // If none of the fetched messages could be examined yet (e.g. they belong to the open transaction),
+        // the next poll should start from the first of them.
+        let first_offset = messages.first_offset();
+        let mut delayed_messages = Vec::new();
+        let (messages, next_offset) = partition
+            .filter_messages(
+                messages,
+                filter,
+                isolation_level,
+                count,
+                delayed.then_some(&mut delayed_messages),
+            )
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to filter messages for partition: {partition_id}, isolation level: {isolation_level}"))?;
+        let next_offset = next_offset
+            .or(first_offset)
+            .unwrap_or_else(|| match strategy.kind {
+                PollingKind::Offset => value,
+                _ if partition.get_messages_count() == 0 => 0,
+                _ => partition.current_offset + 1,
+            });
+        metadata.next_offset = Some(next_offset);
+        partition
+            .track_delayed_messages(consumer, delayed_messages)
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to track delayed messages for partition: {partition_id}"))?;
+
+        let mut due_messages = due_messages;
+        due_messages.add_batch_set(messages);
+        partition
+            .throughput
+            .record_out(due_messages.count(), due_messages.size() as u64);
+        Ok((metadata, due_messages))
+    }
+
+    /// Removes the due delayed messages, which have been returned to the consumer by the poll.
+    pub async fn remove_delayed_messages(
+        &self,
+        consumer: PollingConsumer,
+        partition_id: u32,
+        entries: &[(u64, u64)],
+    ) -> Result<(), IggyError> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        let partition = self
+            .get_partition(partition_id)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to get partition with id: {partition_id}"
+                )
+            })?;
+        partition
+            .read()
+            .await
+            .remove_delayed_messages(consumer, entries);
+        Ok(())
     }
 
     pub async fn append_messages(
@@ -96,22 +241,90 @@ impl Topic {
             return Ok(());
         }
 
-        let partition_id = match partitioning.kind {
-            PartitioningKind::Balanced => self.get_next_partition_id(),
-            PartitioningKind::PartitionId => u32::from_le_bytes(
-                partitioning.value[..partitioning.length as usize]
-                    .try_into()
-                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
-            ),
-            PartitioningKind::MessagesKey => {
-                self.calculate_partition_id_by_messages_key_hash(&partitioning.value)
-            }
-        };
-
+        let partition_id = self.resolve_partition_id(partitioning)?;
         self.append_messages_to_partition(messages, partition_id, confirmation)
             .await
     }
 
+    /// Appends the messages sent by the idempotent producer, unless the batch has already been appended.
+    /// Returns `false` if the batch has been ignored as a duplicate.
+    pub async fn append_idempotent_messages(
+        &self,
+        partitioning: &Partitioning,
+        producer: &ProducerSequence,
+        messages: IggyMessagesBatchMut,
+        confirmation: Option<Confirmation>,
+    ) -> Result<bool, IggyError> {
+        if !self.has_partitions() {
+            return Err(IggyError::NoPartitions(self.topic_id, self.stream_id));
+        }
+
+        if self.is_full() && self.config.topic.delete_oldest_segments {
+            return Err(IggyError::TopicFull(self.topic_id, self.stream_id));
+        }
+
+        if messages.is_empty() {
+            return Ok(false);
+        }
+
+        let partition_id = self.resolve_partition_id(partitioning)?;
+        self.get_partition(partition_id)?
+            .write()
+            .await
+            .append_idempotent_messages(producer, messages, confirmation)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to append messages of producer with ID: {}, partition ID: {partition_id}",
+                    producer.producer_id
+                )
+            })
+    }
+
+    /// Appends the messages sent within the transaction to the partition resolved beforehand.
+    pub async fn append_transaction_messages(
+        &self,
+        transaction_id: u64,
+        partition_id: u32,
+        messages: IggyMessagesBatchMut,
+        confirmation: Option<Confirmation>,
+    ) -> Result<(), IggyError> {
+        if self.is_full() && self.config.topic.delete_oldest_segments {
+            return Err(IggyError::TopicFull(self.topic_id, self.stream_id));
+        }
+
+        if messages.is_empty() {
+            return Ok(());
+        }
+
+        self.get_partition(partition_id)?
+            .write()
+            .await
+            .append_transaction_messages(transaction_id, messages, confirmation)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to append messages of transaction with ID: {transaction_id}")
+            })
+    }
+
+    /// Completes the transaction in the partition by appending its marker.
+    pub async fn end_transaction(
+        &self,
+        transaction_id: u64,
+        partition_id: u32,
+        outcome: TransactionOutcome,
+        marker: IggyMessagesBatchMut,
+    ) -> Result<(), IggyError> {
+        self.get_partition(partition_id)?
+            .write()
+            .await
+            .end_transaction(transaction_id, outcome, marker)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to end transaction with ID: {transaction_id}, partition ID: {partition_id}")
+            })
+    }
+
     pub async fn flush_unsaved_buffer(
         &self,
         partition_id: u32,
@@ -154,6 +367,24 @@ impl Topic {
         Ok(())
     }
 
+    pub(crate) fn resolve_partition_id(
+        &self,
+        partitioning: &Partitioning,
+    ) -> Result<u32, IggyError> {
+        let partition_id = match partitioning.kind {
+            PartitioningKind::Balanced => self.get_next_partition_id(),
+            PartitioningKind::PartitionId => u32::from_le_bytes(
+                partitioning.value[..partitioning.length as usize]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ),
+            PartitioningKind::MessagesKey => {
+                self.calculate_partition_id_by_messages_key_hash(&partitioning.value)
+            }
+        };
+        Ok(partition_id)
+    }
+
     fn get_next_partition_id(&self) -> u32 {
         let mut partition_id = self.current_partition_id.fetch_add(1, Ordering::SeqCst);
         let partitions_count = self.partitions.len() as u32;
@@ -167,15 +398,12 @@ impl Topic {
     }
 
     fn calculate_partition_id_by_messages_key_hash(&self, messages_key: &[u8]) -> u32 {
-        let messages_key_hash = hash::calculate_32(messages_key);
-        let partitions_count = self.get_partitions_count();
-        let mut partition_id = messages_key_hash % partitions_count;
-        if partition_id == 0 {
-            partition_id = partitions_count;
-        }
+        let partition_id = self
+            .partitioning_hash
+            .calculate_partition_id(messages_key, self.get_partitions_count());
         trace!(
-            "Calculated partition ID: {} for messages key: {:?}, hash: {}",
-            partition_id, messages_key, messages_key_hash
+            "Calculated partition ID: {} for messages key: {:?}, partitioning hash: {}",
+            partition_id, messages_key, self.partitioning_hash
         );
         partition_id
     }
@@ -206,9 +434,10 @@ mod tests {
     use crate::streaming::persistence::persister::PersisterKind;
     use crate::streaming::storage::SystemStorage;
     use crate::streaming::utils::MemoryPool;
+    use crate::streaming::utils::hash;
     use bytes::Bytes;
     use iggy_common::CompressionAlgorithm;
-    use iggy_common::{IggyMessage, MaxTopicSize};
+    use iggy_common::{IggyMessage, MaxTopicSize, PartitioningHash};
     use std::sync::Arc;
     use std::sync::atomic::AtomicU32;
     use std::sync::atomic::AtomicU64;
@@ -319,6 +548,24 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn given_topic_partitioning_hash_calculate_partition_id_by_hash_should_use_it() {
+        let partitions_count = 5;
+        let mut topic = init_topic(partitions_count).await;
+        let partitioning_hash: PartitioningHash = "murmur2:jump".parse().unwrap();
+        topic.partitioning_hash = partitioning_hash;
+
+        for entity_id in 1..=1000 {
+            let key = Partitioning::messages_key_u32(entity_id);
+            let partition_id = topic.calculate_partition_id_by_messages_key_hash(&key.value);
+
+            assert_eq!(
+                partition_id,
+                partitioning_hash.calculate_partition_id(&key.value, partitions_count)
+            );
+        }
+    }
+
     async fn init_topic(partitions_count: u32) -> Topic {
         let tempdir = tempfile::TempDir::new().unwrap();
         let config = Arc::new(SystemConfig {
@@ -22,6 +22,7 @@ pub mod consumer_offsets;
 pub mod messages;
 pub mod partitions;
 pub mod persistence;
+pub mod schemas;
 pub mod segments;
 pub mod storage;
 pub mod topic;
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::streaming::partitions::data_directories::select_data_directories;
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::topics::COMPONENT;
 use crate::streaming::topics::topic::Topic;
@@ -45,8 +46,9 @@ impl Topic {
         }
 
         let mut partition_ids = Vec::with_capacity(count as usize);
+        let mut data_directories = select_data_directories(&self.config, count).into_iter();
         for partition_id in current_partitions_count + 1..=current_partitions_count + count {
-            let partition = Partition::create(
+            let mut partition = Partition::create(
                 self.stream_id,
                 self.topic_id,
                 partition_id,
@@ -62,6 +64,9 @@ impl Topic {
                 IggyTimestamp::now(),
             )
             .await;
+            if let Some(data_directory) = data_directories.next() {
+                partition.data_directory = data_directory;
+            }
             self.partitions
                 .insert(partition_id, IggySharedMut::new(partition));
             partition_ids.push(partition_id)
@@ -56,6 +56,8 @@ impl Topic {
             for segment in partition.get_segments_mut() {
                 saved_messages_number += segment.persist_messages(None).await.with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to persist messages in segment, partition ID: {partition_id}"))?;
             }
+            partition.persist_producer_sequences().await.with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to persist producer sequences, partition ID: {partition_id}"))?;
+            partition.persist_delayed_messages().await.with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to persist delayed messages, partition ID: {partition_id}"))?;
         }
 
         Ok(saved_messages_number)
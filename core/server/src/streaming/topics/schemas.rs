@@ -0,0 +1,116 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::schemas::{SchemaValidator, TopicSchema};
+use crate::streaming::segments::IggyMessagesBatchMut;
+use crate::streaming::topics::topic::Topic;
+use bytes::Bytes;
+use iggy_common::{IggyError, IggyTimestamp, Schema, SchemaCompatibility, SchemaType};
+use tracing::info;
+
+impl Topic {
+    /// Returns the schemas registered for the topic ordered by their versions.
+    pub fn get_schemas(&self) -> Vec<&Schema> {
+        let mut schemas = self
+            .schemas
+            .values()
+            .map(|schema| &schema.schema)
+            .collect::<Vec<_>>();
+        schemas.sort_by_key(|schema| schema.version);
+        schemas
+    }
+
+    pub fn get_schema(&self, schema_id: u32) -> Option<&Schema> {
+        self.schemas.get(&schema_id).map(|schema| &schema.schema)
+    }
+
+    /// Registers the next version of the schema, after checking its compatibility with the latest version.
+    pub fn register_schema(
+        &mut self,
+        schema_id: u32,
+        schema_type: SchemaType,
+        compatibility: SchemaCompatibility,
+        definition: Bytes,
+    ) -> Result<&Schema, IggyError> {
+        let validator = SchemaValidator::compile(schema_type, &definition)?;
+        let latest = self
+            .schemas
+            .values()
+            .max_by_key(|schema| schema.schema.version);
+        if let Some(latest) = latest {
+            validator.check_compatibility(&latest.validator, compatibility)?;
+        }
+
+        let version = latest.map_or(1, |latest| latest.schema.version + 1);
+        let schema = Schema {
+            id: schema_id,
+            stream_id: self.stream_id,
+            topic_id: self.topic_id,
+            version,
+            schema_type,
+            compatibility,
+            definition,
+            created_at: IggyTimestamp::now(),
+        };
+        info!(
+            "Registered schema with ID: {schema_id}, version: {version}, type: {schema_type} for topic with ID: {} and stream with ID: {}",
+            self.topic_id, self.stream_id
+        );
+        let schema = self
+            .schemas
+            .entry(schema_id)
+            .insert_entry(TopicSchema { schema, validator })
+            .into_mut();
+        Ok(&schema.schema)
+    }
+
+    pub fn delete_schema(&mut self, schema_id: u32) -> Result<Schema, IggyError> {
+        let schema = self
+            .schemas
+            .remove(&schema_id)
+            .ok_or(IggyError::SchemaNotFound(schema_id))?;
+        info!(
+            "Deleted schema with ID: {schema_id}, version: {} for topic with ID: {} and stream with ID: {}",
+            schema.schema.version, self.topic_id, self.stream_id
+        );
+        Ok(schema.schema)
+    }
+
+    /// Validates the payloads of the messages referencing the schema with the reserved user header.
+    /// The messages without the header are not validated.
+    pub fn validate_messages(&self, messages: &IggyMessagesBatchMut) -> Result<(), IggyError> {
+        for message in messages.iter() {
+            let Some(schema_id) = message.schema_id()? else {
+                continue;
+            };
+            let schema =
+                self.schemas
+                    .get(&schema_id)
+                    .ok_or(IggyError::SchemaNotRegisteredForTopic(
+                        schema_id,
+                        self.topic_id,
+                        self.stream_id,
+                    ))?;
+            schema
+                .validator
+                .validate(message.payload())
+                .map_err(|reason| IggyError::MessageSchemaViolation(schema_id, reason))?;
+        }
+        Ok(())
+    }
+}
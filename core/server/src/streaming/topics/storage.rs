@@ -17,7 +17,11 @@
  */
 
 use crate::state::system::TopicState;
+use crate::streaming::partitions::data_directories::{
+    recover_partition_moves, remove_partition_directory,
+};
 use crate::streaming::partitions::partition::Partition;
+use crate::streaming::schemas::TopicSchema;
 use crate::streaming::storage::TopicStorage;
 use crate::streaming::topics::COMPONENT;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
@@ -60,6 +64,18 @@ impl TopicStorage for FileTopicStorage {
         topic.max_topic_size = max_topic_size;
         topic.compression_algorithm = state.compression_algorithm;
         topic.replication_factor = state.replication_factor.unwrap_or(1);
+        topic.cleanup_policy = state.cleanup_policy;
+        topic.partitioning_hash = state.partitioning_hash;
+        topic.segment_max_age = Topic::get_segment_max_age(state.segment_max_age, &topic.config);
+        topic.max_partition_size =
+            Topic::get_max_partition_size(state.max_partition_size, &topic.config)?;
+
+        if let Err(error) = recover_partition_moves(&topic.partitions_path).await {
+            error!(
+                "Cannot recover interrupted partition moves in: {}. {error}",
+                topic.partitions_path
+            );
+        }
 
         let mut dir_entries = fs::read_dir(&topic.partitions_path).await
             .with_context(|| format!("Failed to read partition with ID: {} for stream with ID: {} for topic with ID: {} and path: {}",
@@ -88,7 +104,9 @@ impl TopicStorage for FileTopicStorage {
                 error!(
                     "Partition with ID: '{partition_id}' for stream with ID: '{stream_id}' and topic with ID: '{topic_id}' was not found in state, but exists on disk and will be removed."
                 );
-                if let Err(error) = fs::remove_dir_all(&dir_entry.path()).await {
+                if let Err(error) =
+                    remove_partition_directory(&dir_entry.path().to_string_lossy()).await
+                {
                     error!("Cannot remove partition directory: {error}");
                 } else {
                     warn!(
@@ -218,6 +236,7 @@ impl TopicStorage for FileTopicStorage {
                 consumer_group.id,
                 &consumer_group.name,
                 topic.get_partitions_count(),
+                consumer_group.assignment_strategy,
             );
             topic
                 .consumer_groups_ids
@@ -227,6 +246,21 @@ impl TopicStorage for FileTopicStorage {
                 .insert(consumer_group.group_id, RwLock::new(consumer_group));
         }
 
+        for schema in state.schemas.into_values() {
+            let schema_id = schema.id;
+            match TopicSchema::new(schema) {
+                Ok(schema) => {
+                    topic.schemas.insert(schema_id, schema);
+                }
+                Err(error) => {
+                    error!(
+                        "Failed to load schema with ID: {schema_id} for stream with ID: {} and topic with ID: {}. Error: {error}",
+                        topic.stream_id, topic.topic_id
+                    );
+                }
+            }
+        }
+
         info!("Loaded topic {topic}");
 
         Ok(())
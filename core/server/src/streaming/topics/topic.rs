@@ -19,14 +19,15 @@
 use crate::configs::system::SystemConfig;
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::schemas::TopicSchema;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use ahash::AHashMap;
 use core::fmt;
 use iggy_common::locking::IggySharedMut;
 use iggy_common::{
-    CompressionAlgorithm, Consumer, ConsumerKind, IggyByteSize, IggyError, IggyExpiry,
-    IggyTimestamp, MaxTopicSize, Sizeable,
+    CleanupPolicy, CompressionAlgorithm, Consumer, ConsumerKind, IggyByteSize, IggyError,
+    IggyExpiry, IggyTimestamp, MaxTopicSize, PartitioningHash, Sizeable,
 };
 
 use std::sync::Arc;
@@ -53,12 +54,17 @@ pub struct Topic {
     pub(crate) storage: Arc<SystemStorage>,
     pub(crate) consumer_groups: AHashMap<u32, RwLock<ConsumerGroup>>,
     pub(crate) consumer_groups_ids: AHashMap<String, u32>,
+    pub(crate) schemas: AHashMap<u32, TopicSchema>,
     pub(crate) current_consumer_group_id: AtomicU32,
     pub(crate) current_partition_id: AtomicU32,
     pub message_expiry: IggyExpiry,
     pub compression_algorithm: CompressionAlgorithm,
     pub max_topic_size: MaxTopicSize,
     pub replication_factor: u8,
+    pub cleanup_policy: CleanupPolicy,
+    pub partitioning_hash: PartitioningHash,
+    pub segment_max_age: IggyExpiry,
+    pub max_partition_size: MaxTopicSize,
     pub created_at: IggyTimestamp,
 }
 
@@ -126,12 +132,17 @@ impl Topic {
             segments_count_of_parent_stream,
             consumer_groups: AHashMap::new(),
             consumer_groups_ids: AHashMap::new(),
+            schemas: AHashMap::new(),
             current_consumer_group_id: AtomicU32::new(1),
             current_partition_id: AtomicU32::new(1),
             message_expiry: Topic::get_message_expiry(message_expiry, &config),
             max_topic_size: Topic::get_max_topic_size(max_topic_size, &config)?,
             compression_algorithm,
             replication_factor,
+            cleanup_policy: CleanupPolicy::default(),
+            partitioning_hash: PartitioningHash::default(),
+            segment_max_age: config.segment.max_age,
+            max_partition_size: config.partition.max_size,
             config,
             created_at: IggyTimestamp::now(),
         };
@@ -251,6 +262,32 @@ impl Topic {
             _ => message_expiry,
         }
     }
+
+    pub fn get_segment_max_age(segment_max_age: IggyExpiry, config: &SystemConfig) -> IggyExpiry {
+        match segment_max_age {
+            IggyExpiry::ServerDefault => config.segment.max_age,
+            _ => segment_max_age,
+        }
+    }
+
+    pub fn get_max_partition_size(
+        max_partition_size: MaxTopicSize,
+        config: &SystemConfig,
+    ) -> Result<MaxTopicSize, IggyError> {
+        match max_partition_size {
+            MaxTopicSize::ServerDefault => Ok(config.partition.max_size),
+            _ => {
+                if max_partition_size.as_bytes_u64() < config.segment.size.as_bytes_u64() {
+                    Err(IggyError::InvalidPartitionSize(
+                        max_partition_size,
+                        config.segment.size,
+                    ))
+                } else {
+                    Ok(max_partition_size)
+                }
+            }
+        }
+    }
 }
 
 impl Sizeable for Topic {
@@ -263,7 +300,7 @@ impl fmt::Display for Topic {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Topic {{ id: {}, stream ID: {}, name: {}, path: {}, partitions: {}, message_expiry: {}, max_topic_size: {}, replication_factor: {} }}",
+            "Topic {{ id: {}, stream ID: {}, name: {}, path: {}, partitions: {}, message_expiry: {}, max_topic_size: {}, replication_factor: {}, cleanup_policy: {}, partitioning_hash: {}, segment_max_age: {}, max_partition_size: {} }}",
             self.topic_id,
             self.stream_id,
             self.name,
@@ -272,6 +309,10 @@ impl fmt::Display for Topic {
             self.message_expiry,
             self.max_topic_size,
             self.replication_factor,
+            self.cleanup_policy,
+            self.partitioning_hash,
+            self.segment_max_age,
+            self.max_partition_size,
         )
     }
 }
@@ -0,0 +1,186 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::utils::compression;
+use bytes::{BufMut, Bytes, BytesMut};
+use iggy_common::{HeaderKind, IggyMessage, IggyMessageHeader, IggyTimestamp};
+
+/// The reserved user header key, which is appended to the user headers of each message sent
+/// within the transaction. Its value holds the ID of the transaction.
+pub const TRANSACTION_HEADER_KEY: &str = "iggy-transaction-id";
+
+/// The size of the serialized transaction user header.
+pub const TRANSACTION_HEADER_SIZE: usize = 4 + TRANSACTION_HEADER_KEY.len() + 1 + 4 + 8;
+
+/// The reserved user header key of the marker message, which is written to each partition
+/// involved in the transaction once it's completed. Its value holds the transaction outcome.
+pub const TRANSACTION_MARKER_HEADER_KEY: &str = "iggy-transaction-marker";
+
+/// The size of the serialized transaction marker user header.
+pub const TRANSACTION_MARKER_HEADER_SIZE: usize =
+    4 + TRANSACTION_MARKER_HEADER_KEY.len() + 1 + 4 + 1;
+
+/// `TransactionOutcome` is the result of the completed transaction, stored in its markers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionOutcome {
+    Committed,
+    Aborted,
+}
+
+impl TransactionOutcome {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            TransactionOutcome::Committed => 1,
+            TransactionOutcome::Aborted => 2,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Option<Self> {
+        match code {
+            1 => Some(TransactionOutcome::Committed),
+            2 => Some(TransactionOutcome::Aborted),
+            _ => None,
+        }
+    }
+}
+
+/// Appends the serialized transaction user header to the provided buffer.
+pub fn write_transaction_header(transaction_id: u64, buffer: &mut BytesMut) {
+    buffer.put_u32_le(TRANSACTION_HEADER_KEY.len() as u32);
+    buffer.put_slice(TRANSACTION_HEADER_KEY.as_bytes());
+    buffer.put_u8(HeaderKind::Uint64.as_code());
+    buffer.put_u32_le(8);
+    buffer.put_u64_le(transaction_id);
+}
+
+/// Creates the marker message of the completed transaction. The marker has a non-empty payload,
+/// so that it's never treated as a tombstone by the compactor.
+pub fn create_marker(transaction_id: u64, outcome: TransactionOutcome) -> IggyMessage {
+    let mut user_headers =
+        BytesMut::with_capacity(TRANSACTION_HEADER_SIZE + TRANSACTION_MARKER_HEADER_SIZE);
+    write_transaction_header(transaction_id, &mut user_headers);
+    user_headers.put_u32_le(TRANSACTION_MARKER_HEADER_KEY.len() as u32);
+    user_headers.put_slice(TRANSACTION_MARKER_HEADER_KEY.as_bytes());
+    user_headers.put_u8(HeaderKind::Uint8.as_code());
+    user_headers.put_u32_le(1);
+    user_headers.put_u8(outcome.as_code());
+
+    IggyMessage {
+        header: IggyMessageHeader {
+            checksum: 0,
+            id: transaction_id as u128,
+            offset: 0,
+            timestamp: 0,
+            origin_timestamp: IggyTimestamp::now().as_micros(),
+            user_headers_length: user_headers.len() as u32,
+            payload_length: 1,
+        },
+        payload: Bytes::from(vec![outcome.as_code()]),
+        user_headers: Some(user_headers.freeze()),
+    }
+}
+
+/// Reads the transaction ID of the stored message, if it was sent within the transaction.
+/// The compression user header, which might follow the transaction user header, is skipped.
+pub fn read_transaction_id(user_headers: &[u8]) -> Option<u64> {
+    let user_headers = compression::split_compression_header(user_headers)
+        .map(|(_, remaining)| remaining)
+        .unwrap_or(user_headers);
+    let (value, _) = split_header(
+        user_headers,
+        TRANSACTION_HEADER_KEY,
+        HeaderKind::Uint64.as_code(),
+        8,
+    )?;
+    Some(u64::from_le_bytes(value.try_into().ok()?))
+}
+
+/// Reads the transaction ID and outcome, if the stored message is the transaction marker.
+pub fn read_marker(user_headers: &[u8]) -> Option<(u64, TransactionOutcome)> {
+    let (value, remaining) = split_header(
+        user_headers,
+        TRANSACTION_MARKER_HEADER_KEY,
+        HeaderKind::Uint8.as_code(),
+        1,
+    )?;
+    let outcome = TransactionOutcome::from_code(value[0])?;
+    let transaction_id = read_transaction_id(remaining)?;
+    Some((transaction_id, outcome))
+}
+
+/// Checks whether the serialized user headers end with the header of the given key, kind
+/// and value length. If so, returns its value and the remaining user headers.
+fn split_header<'a>(
+    user_headers: &'a [u8],
+    key: &str,
+    kind: u8,
+    value_length: usize,
+) -> Option<(&'a [u8], &'a [u8])> {
+    let size = 4 + key.len() + 1 + 4 + value_length;
+    if user_headers.len() < size {
+        return None;
+    }
+
+    let (remaining, header) = user_headers.split_at(user_headers.len() - size);
+    let key_end = 4 + key.len();
+    if header[..4] != (key.len() as u32).to_le_bytes()
+        || &header[4..key_end] != key.as_bytes()
+        || header[key_end] != kind
+        || header[key_end + 1..key_end + 5] != (value_length as u32).to_le_bytes()
+    {
+        return None;
+    }
+
+    Some((&header[key_end + 5..], remaining))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy_common::CompressionAlgorithm;
+
+    #[test]
+    fn transaction_id_should_be_read_from_user_headers() {
+        let mut user_headers = BytesMut::new();
+        write_transaction_header(42, &mut user_headers);
+
+        assert_eq!(read_transaction_id(&user_headers), Some(42));
+        assert_eq!(read_marker(&user_headers), None);
+    }
+
+    #[test]
+    fn transaction_id_should_be_read_from_compressed_message_user_headers() {
+        let mut user_headers = BytesMut::new();
+        write_transaction_header(42, &mut user_headers);
+        compression::write_compression_header(CompressionAlgorithm::Zstd, &mut user_headers);
+
+        assert_eq!(read_transaction_id(&user_headers), Some(42));
+    }
+
+    #[test]
+    fn marker_should_be_read_from_user_headers() {
+        let marker = create_marker(7, TransactionOutcome::Aborted);
+        let user_headers = marker.user_headers.unwrap();
+
+        assert_eq!(
+            read_marker(&user_headers),
+            Some((7, TransactionOutcome::Aborted))
+        );
+        assert!(!marker.payload.is_empty());
+    }
+}
@@ -0,0 +1,227 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use ahash::{AHashMap, AHashSet};
+use iggy_common::{IggyDuration, IggyError, IggyTimestamp, UserId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug)]
+pub struct TransactionManager {
+    next_transaction_id: u64,
+    transactions: AHashMap<u64, Transaction>,
+    /// The committed transactions, whose markers have not been written to all their partitions yet.
+    committing: AHashSet<u64>,
+}
+
+#[derive(Debug)]
+pub struct Transaction {
+    pub id: u64,
+    pub user_id: UserId,
+    pub client_id: u32,
+    pub created_at: IggyTimestamp,
+    pub partitions: AHashSet<TransactionPartition>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TransactionPartition {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+}
+
+impl Default for TransactionManager {
+    fn default() -> Self {
+        // The IDs are based on the current time, so that they keep increasing across the restarts
+        // and the markers of the transactions begun before the restart are never mismatched.
+        Self {
+            next_transaction_id: IggyTimestamp::now().as_micros(),
+            transactions: AHashMap::new(),
+            committing: AHashSet::new(),
+        }
+    }
+}
+
+impl TransactionManager {
+    pub fn begin_transaction(&mut self, user_id: UserId, client_id: u32) -> u64 {
+        self.next_transaction_id += 1;
+        let id = self.next_transaction_id;
+        self.transactions.insert(
+            id,
+            Transaction {
+                id,
+                user_id,
+                client_id,
+                created_at: IggyTimestamp::now(),
+                partitions: AHashSet::new(),
+            },
+        );
+        id
+    }
+
+    /// Registers the partition, to which the messages are about to be appended within the transaction.
+    pub fn add_partition(
+        &mut self,
+        transaction_id: u64,
+        user_id: UserId,
+        partition: TransactionPartition,
+    ) -> Result<(), IggyError> {
+        let transaction = self
+            .transactions
+            .get_mut(&transaction_id)
+            .filter(|transaction| transaction.user_id == user_id)
+            .ok_or(IggyError::TransactionNotFound(transaction_id))?;
+        transaction.partitions.insert(partition);
+        Ok(())
+    }
+
+    pub fn remove_transaction(
+        &mut self,
+        transaction_id: u64,
+        user_id: UserId,
+    ) -> Result<Transaction, IggyError> {
+        if self
+            .transactions
+            .get(&transaction_id)
+            .is_none_or(|transaction| transaction.user_id != user_id)
+        {
+            return Err(IggyError::TransactionNotFound(transaction_id));
+        }
+
+        Ok(self
+            .transactions
+            .remove(&transaction_id)
+            .expect("Transaction should exist"))
+    }
+
+    /// Removes the transaction to be committed and tracks it until its markers are written,
+    /// which is completed by calling `finish_commit`.
+    pub fn start_commit(
+        &mut self,
+        transaction_id: u64,
+        user_id: UserId,
+    ) -> Result<Transaction, IggyError> {
+        let transaction = self.remove_transaction(transaction_id, user_id)?;
+        self.committing.insert(transaction_id);
+        Ok(transaction)
+    }
+
+    /// Tracks the committed transaction, whose markers are still missing in any of its partitions.
+    pub fn track_commit(&mut self, transaction_id: u64) {
+        self.committing.insert(transaction_id);
+    }
+
+    pub fn finish_commit(&mut self, transaction_id: u64) {
+        self.committing.remove(&transaction_id);
+    }
+
+    /// Returns the ID of the oldest transaction, which is either open or being committed.
+    /// The markers of this and the following transactions are not pruned, as the IDs of the pruned ones
+    /// are treated as completed when recovering the committed transactions on startup.
+    pub fn oldest_transaction_id(&self) -> Option<u64> {
+        self.transactions
+            .keys()
+            .chain(self.committing.iter())
+            .min()
+            .copied()
+    }
+
+    /// Removes the transactions begun by the client, e.g. when it disconnects.
+    pub fn remove_client_transactions(&mut self, client_id: u32) -> Vec<Transaction> {
+        self.remove_transactions(|transaction| transaction.client_id == client_id)
+    }
+
+    /// Removes the transactions, which have not been completed within the given timeout.
+    pub fn remove_expired_transactions(&mut self, timeout: IggyDuration) -> Vec<Transaction> {
+        let now = IggyTimestamp::now().as_micros();
+        let timeout = timeout.as_micros();
+        self.remove_transactions(|transaction| transaction.created_at.as_micros() + timeout <= now)
+    }
+
+    fn remove_transactions(
+        &mut self,
+        predicate: impl Fn(&Transaction) -> bool,
+    ) -> Vec<Transaction> {
+        let ids = self
+            .transactions
+            .values()
+            .filter(|transaction| predicate(transaction))
+            .map(|transaction| transaction.id)
+            .collect::<Vec<_>>();
+        ids.into_iter()
+            .filter_map(|id| self.transactions.remove(&id))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transaction_should_be_removed_only_by_its_owner() {
+        let mut manager = TransactionManager::default();
+        let transaction_id = manager.begin_transaction(1, 10);
+
+        assert!(manager.remove_transaction(transaction_id, 2).is_err());
+        let transaction = manager.remove_transaction(transaction_id, 1).unwrap();
+        assert_eq!(transaction.id, transaction_id);
+        assert!(manager.remove_transaction(transaction_id, 1).is_err());
+    }
+
+    #[test]
+    fn committing_transaction_should_be_the_oldest_until_finished() {
+        let mut manager = TransactionManager::default();
+        let first_id = manager.begin_transaction(1, 10);
+        let second_id = manager.begin_transaction(1, 10);
+
+        manager.start_commit(first_id, 1).unwrap();
+        assert_eq!(manager.oldest_transaction_id(), Some(first_id));
+
+        manager.finish_commit(first_id);
+        assert_eq!(manager.oldest_transaction_id(), Some(second_id));
+
+        manager.remove_transaction(second_id, 1).unwrap();
+        assert_eq!(manager.oldest_transaction_id(), None);
+    }
+
+    #[test]
+    fn client_transactions_should_be_removed() {
+        let mut manager = TransactionManager::default();
+        let first_id = manager.begin_transaction(1, 10);
+        let second_id = manager.begin_transaction(1, 20);
+
+        let removed = manager.remove_client_transactions(10);
+
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].id, first_id);
+        assert!(second_id > first_id);
+        assert!(
+            manager
+                .add_partition(
+                    second_id,
+                    1,
+                    TransactionPartition {
+                        stream_id: 1,
+                        topic_id: 1,
+                        partition_id: 1,
+                    },
+                )
+                .is_ok()
+        );
+    }
+}
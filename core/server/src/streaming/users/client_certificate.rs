@@ -0,0 +1,144 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::client_auth::ClientCertificateIdentity;
+use iggy_common::IggyError;
+use openssl::nid::Nid;
+use openssl::x509::X509;
+use rustls::RootCertStore;
+use rustls::pki_types::CertificateDer;
+use rustls::pki_types::pem::PemObject;
+use rustls::server::WebPkiClientVerifier;
+use rustls::server::danger::ClientCertVerifier;
+use std::sync::Arc;
+use tracing::error;
+
+/// Creates the verifier which requires every client to present a certificate signed by one of the CAs from the bundle.
+pub fn load_client_verifier(ca_file: &str) -> Result<Arc<dyn ClientCertVerifier>, IggyError> {
+    let certificates = CertificateDer::pem_file_iter(ca_file)
+        .map_err(|error| {
+            error!("Cannot read the client CA file: {ca_file}. {error}");
+            IggyError::InvalidTlsCertificatePath
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| {
+            error!("Cannot parse the client CA file: {ca_file}. {error}");
+            IggyError::InvalidTlsCertificate
+        })?;
+
+    let mut roots = RootCertStore::empty();
+    for certificate in certificates {
+        roots.add(certificate).map_err(|error| {
+            error!("Cannot add the client CA certificate from file: {ca_file}. {error}");
+            IggyError::FailedToAddCertificate
+        })?;
+    }
+
+    WebPkiClientVerifier::builder_with_provider(
+        Arc::new(roots),
+        Arc::new(rustls::crypto::ring::default_provider()),
+    )
+    .build()
+    .map_err(|error| {
+        error!("Cannot create the client certificate verifier. {error}");
+        IggyError::InvalidTlsCertificate
+    })
+}
+
+/// Returns the username mapped from the (already verified) client certificate, if the certificate contains the identity.
+pub fn get_username(
+    certificate: &CertificateDer<'_>,
+    identity: ClientCertificateIdentity,
+) -> Option<String> {
+    let certificate = X509::from_der(certificate).ok()?;
+    let username = match identity {
+        ClientCertificateIdentity::CommonName => certificate
+            .subject_name()
+            .entries_by_nid(Nid::COMMONNAME)
+            .next()
+            .and_then(|entry| entry.data().as_utf8().ok())
+            .map(|common_name| common_name.to_string()),
+        ClientCertificateIdentity::SanDns
+        | ClientCertificateIdentity::SanEmail
+        | ClientCertificateIdentity::SanUri => {
+            certificate.subject_alt_names()?.iter().find_map(|name| {
+                match identity {
+                    ClientCertificateIdentity::SanDns => name.dnsname(),
+                    ClientCertificateIdentity::SanEmail => name.email(),
+                    _ => name.uri(),
+                }
+                .map(|name| name.to_string())
+            })
+        }
+    };
+    username.filter(|username| !username.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rcgen::{CertificateParams, DnType, KeyPair, SanType};
+
+    fn generate_certificate(common_name: &str, sans: Vec<SanType>) -> CertificateDer<'static> {
+        let mut params = CertificateParams::default();
+        params
+            .distinguished_name
+            .push(DnType::CommonName, common_name);
+        params.subject_alt_names = sans;
+        let key_pair = KeyPair::generate().unwrap();
+        params.self_signed(&key_pair).unwrap().der().clone()
+    }
+
+    #[test]
+    fn should_map_common_name_to_username() {
+        let certificate = generate_certificate("alice", vec![]);
+        let username = get_username(&certificate, ClientCertificateIdentity::CommonName);
+        assert_eq!(username.as_deref(), Some("alice"));
+        assert!(get_username(&certificate, ClientCertificateIdentity::SanDns).is_none());
+    }
+
+    #[test]
+    fn should_map_subject_alternative_names_to_username() {
+        let certificate = generate_certificate(
+            "client",
+            vec![
+                SanType::DnsName("bob".try_into().unwrap()),
+                SanType::Rfc822Name("bob@iggy.rs".try_into().unwrap()),
+                SanType::URI("spiffe://iggy/bob".try_into().unwrap()),
+            ],
+        );
+        assert_eq!(
+            get_username(&certificate, ClientCertificateIdentity::SanDns).as_deref(),
+            Some("bob")
+        );
+        assert_eq!(
+            get_username(&certificate, ClientCertificateIdentity::SanEmail).as_deref(),
+            Some("bob@iggy.rs")
+        );
+        assert_eq!(
+            get_username(&certificate, ClientCertificateIdentity::SanUri).as_deref(),
+            Some("spiffe://iggy/bob")
+        );
+    }
+
+    #[test]
+    fn should_not_map_invalid_certificate() {
+        let certificate = CertificateDer::from(vec![1, 2, 3]);
+        assert!(get_username(&certificate, ClientCertificateIdentity::CommonName).is_none());
+    }
+}
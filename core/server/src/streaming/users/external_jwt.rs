@@ -0,0 +1,510 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::system::{ExternalJwtConfig, ExternalJwtIssuerConfig};
+use ahash::AHashMap;
+use iggy_common::IggyError;
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use serde_json::Value;
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex, RwLock};
+use tracing::{error, info, warn};
+
+/// The key set fetched from the URL is refreshed because of the unknown key ID at most once per this interval.
+const MIN_JWKS_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The maximum time of fetching the key set from the URL, so that the unresponsive issuer doesn't hang the logins.
+const JWKS_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Validates the JSON Web Tokens issued by the external identity providers, using the signing keys
+/// of the configured issuers, and maps the claims of the valid tokens to the iggy usernames.
+/// The claims are mapped only to the existing users, whose permissions and roles are managed by iggy,
+/// mapping the claims directly to the permissions is out of scope.
+/// The validation might fetch the key set of the issuer, so it must not be called while holding the system lock.
+#[derive(Debug)]
+pub struct ExternalJwtValidator {
+    enabled: bool,
+    jwks_cache_ttl: Duration,
+    clock_skew: u64,
+    issuers: AHashMap<String, ExternalIssuer>,
+}
+
+#[derive(Debug)]
+struct ExternalIssuer {
+    config: ExternalJwtIssuerConfig,
+    jwks: RwLock<Option<CachedJwks>>,
+    /// Held while the key set is being refreshed, so that there's only one request to the issuer at a time.
+    refresh_lock: Mutex<()>,
+    http_client: reqwest::Client,
+}
+
+#[derive(Debug)]
+struct CachedJwks {
+    keys: JwkSet,
+    loaded_at: Instant,
+}
+
+/// The identity resolved from the valid external token.
+#[derive(Debug, PartialEq)]
+pub struct ExternalIdentity {
+    pub username: String,
+    pub token_id: String,
+    pub expiry: u64,
+}
+
+impl ExternalJwtValidator {
+    pub fn new(config: &ExternalJwtConfig) -> Self {
+        let http_client = reqwest::Client::builder()
+            .timeout(JWKS_FETCH_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+        ExternalJwtValidator {
+            enabled: config.enabled,
+            jwks_cache_ttl: config.jwks_cache_ttl.get_duration(),
+            clock_skew: config.clock_skew.as_secs() as u64,
+            issuers: config
+                .issuers
+                .iter()
+                .map(|issuer| {
+                    (
+                        issuer.issuer.clone(),
+                        ExternalIssuer {
+                            config: issuer.clone(),
+                            jwks: RwLock::new(None),
+                            refresh_lock: Mutex::new(()),
+                            http_client: http_client.clone(),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns true if the token was issued by one of the configured issuers, without validating it.
+    pub fn is_external_token(&self, token: &str) -> bool {
+        self.enabled
+            && Self::get_unverified_issuer(token)
+                .is_some_and(|issuer| self.issuers.contains_key(&issuer))
+    }
+
+    /// Loads the key sets of all the issuers, so that the misconfigured ones are reported on startup.
+    pub async fn init(&self) {
+        if !self.enabled {
+            info!("External JWT issuers are disabled.");
+            return;
+        }
+
+        for issuer in self.issuers.values() {
+            match issuer.refresh_jwks().await {
+                Ok(()) => info!(
+                    "Loaded JSON Web Key Set for external JWT issuer: {}",
+                    issuer.config.issuer
+                ),
+                Err(error) => error!(
+                    "Failed to load JSON Web Key Set for external JWT issuer: {}. {error}",
+                    issuer.config.issuer
+                ),
+            }
+        }
+    }
+
+    pub async fn validate(&self, token: &str) -> Result<ExternalIdentity, IggyError> {
+        if !self.enabled {
+            return Err(IggyError::FeatureUnavailable);
+        }
+
+        let header =
+            jsonwebtoken::decode_header(token).map_err(|_| IggyError::InvalidAccessToken)?;
+        if matches!(
+            header.alg,
+            Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512
+        ) {
+            warn!("External JWT signed with the symmetric algorithm is not accepted.");
+            return Err(IggyError::InvalidJwtAlgorithm(format!("{:?}", header.alg)));
+        }
+
+        let issuer = Self::get_unverified_issuer(token)
+            .and_then(|issuer| self.issuers.get(&issuer))
+            .ok_or(IggyError::Unauthenticated)?;
+        let key = issuer
+            .get_decoding_key(header.kid.as_deref(), self.jwks_cache_ttl)
+            .await?;
+
+        let mut validation = Validation::new(header.alg);
+        validation.leeway = self.clock_skew;
+        validation.set_issuer(&[&issuer.config.issuer]);
+        if issuer.config.audiences.is_empty() {
+            validation.validate_aud = false;
+        } else {
+            validation.set_audience(&issuer.config.audiences);
+        }
+
+        let claims = jsonwebtoken::decode::<Value>(token, &key, &validation)
+            .map_err(|error| {
+                warn!(
+                    "Invalid external JWT of issuer: {}. {error}",
+                    issuer.config.issuer
+                );
+                IggyError::Unauthenticated
+            })?
+            .claims;
+        let username = map_claims_to_username(&issuer.config, &claims)
+            .ok_or(IggyError::UnmappedAccessTokenClaims)?;
+        let token_id = claims
+            .get("jti")
+            .and_then(Value::as_str)
+            .map(ToOwned::to_owned)
+            .unwrap_or_else(|| blake3::hash(token.as_bytes()).to_hex().to_string());
+        let expiry = claims
+            .get("exp")
+            .and_then(Value::as_u64)
+            .unwrap_or_default();
+        Ok(ExternalIdentity {
+            username,
+            token_id,
+            expiry,
+        })
+    }
+
+    fn get_unverified_issuer(token: &str) -> Option<String> {
+        let header = jsonwebtoken::decode_header(token).ok()?;
+        let mut validation = Validation::new(header.alg);
+        validation.insecure_disable_signature_validation();
+        validation.validate_exp = false;
+        validation.validate_aud = false;
+        validation.required_spec_claims.clear();
+        let claims =
+            jsonwebtoken::decode::<Value>(token, &DecodingKey::from_secret(&[]), &validation)
+                .ok()?
+                .claims;
+        claims.get("iss")?.as_str().map(ToOwned::to_owned)
+    }
+}
+
+impl ExternalIssuer {
+    async fn get_decoding_key(
+        &self,
+        key_id: Option<&str>,
+        jwks_cache_ttl: Duration,
+    ) -> Result<DecodingKey, IggyError> {
+        let (refresh_required, loaded_at) = match self.jwks.read().await.as_ref() {
+            None => (true, None),
+            Some(jwks) => {
+                let elapsed = jwks.loaded_at.elapsed();
+                let unknown_key = key_id.is_some_and(|key_id| jwks.keys.find(key_id).is_none());
+                let refresh_required = self.config.jwks_url.is_some()
+                    && (elapsed >= jwks_cache_ttl
+                        || (unknown_key && elapsed >= MIN_JWKS_REFRESH_INTERVAL));
+                (refresh_required, Some(jwks.loaded_at))
+            }
+        };
+        if refresh_required {
+            let _refresh_guard = self.refresh_lock.lock().await;
+            // The key set might have been already refreshed by the concurrent validation.
+            let refreshed = self.jwks.read().await.as_ref().map(|jwks| jwks.loaded_at) != loaded_at;
+            if !refreshed {
+                match self.refresh_jwks().await {
+                    Ok(()) => {}
+                    // The cached keys are still used, so that the issuer being unavailable doesn't reject all the tokens.
+                    Err(error) if loaded_at.is_some() => warn!(
+                        "Failed to refresh JSON Web Key Set for external JWT issuer: {}, using the cached one. {error}",
+                        self.config.issuer
+                    ),
+                    Err(error) => return Err(error),
+                }
+            }
+        }
+
+        let jwks = self.jwks.read().await;
+        let keys = &jwks.as_ref().ok_or(IggyError::Unauthenticated)?.keys;
+        let jwk = match key_id {
+            Some(key_id) => keys.find(key_id),
+            None if keys.keys.len() == 1 => keys.keys.first(),
+            None => None,
+        }
+        .ok_or_else(|| {
+            warn!(
+                "Signing key: {} of external JWT issuer: {} was not found.",
+                key_id.unwrap_or("none"),
+                self.config.issuer
+            );
+            IggyError::Unauthenticated
+        })?;
+        DecodingKey::from_jwk(jwk).map_err(|_| IggyError::InvalidAccessToken)
+    }
+
+    async fn refresh_jwks(&self) -> Result<(), IggyError> {
+        let keys = match (&self.config.jwks_file, &self.config.jwks_url) {
+            (Some(path), _) => {
+                let content = tokio::fs::read_to_string(path)
+                    .await
+                    .map_err(|_| IggyError::CannotLoadJwks(path.to_owned()))?;
+                serde_json::from_str::<JwkSet>(&content)
+                    .map_err(|_| IggyError::CannotLoadJwks(path.to_owned()))?
+            }
+            (None, Some(url)) => self
+                .http_client
+                .get(url)
+                .send()
+                .await
+                .and_then(|response| response.error_for_status())
+                .map_err(|_| IggyError::CannotLoadJwks(url.to_owned()))?
+                .json::<JwkSet>()
+                .await
+                .map_err(|_| IggyError::CannotLoadJwks(url.to_owned()))?,
+            (None, None) => return Err(IggyError::CannotLoadJwks(self.config.issuer.clone())),
+        };
+        self.jwks.write().await.replace(CachedJwks {
+            keys,
+            loaded_at: Instant::now(),
+        });
+        Ok(())
+    }
+}
+
+/// Maps the claims to the username by the first matching claim rule, or by the value of the user claim.
+fn map_claims_to_username(config: &ExternalJwtIssuerConfig, claims: &Value) -> Option<String> {
+    let rule = config
+        .claim_rules
+        .iter()
+        .find(|rule| match get_claim(claims, &rule.claim) {
+            Some(Value::Array(values)) => values
+                .iter()
+                .any(|value| value.as_str() == Some(rule.value.as_str())),
+            Some(Value::String(value)) => value == &rule.value,
+            Some(value) => rule
+                .value
+                .parse::<Value>()
+                .is_ok_and(|rule_value| &rule_value == value),
+            None => false,
+        });
+    if let Some(rule) = rule {
+        return Some(rule.username.clone());
+    }
+
+    config
+        .user_claim
+        .as_deref()
+        .and_then(|claim| get_claim(claims, claim))
+        .and_then(Value::as_str)
+        .map(ToOwned::to_owned)
+}
+
+/// Returns the claim by its name, the nested claims are separated with dots, e.g. `realm_access.roles`.
+fn get_claim<'a>(claims: &'a Value, name: &str) -> Option<&'a Value> {
+    name.split('.')
+        .try_fold(claims, |value, segment| value.get(segment))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::ExternalJwtClaimRuleConfig;
+    use base64::Engine;
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    use iggy_common::{IggyDuration, IggyTimestamp};
+    use jsonwebtoken::{EncodingKey, Header};
+    use ring::rand::SystemRandom;
+    use ring::signature::{ECDSA_P256_SHA256_FIXED_SIGNING, EcdsaKeyPair, KeyPair};
+    use serde_json::json;
+    use tempfile::NamedTempFile;
+
+    const ISSUER: &str = "https://idp.example.com";
+    const KEY_ID: &str = "key-1";
+
+    fn issuer_config() -> ExternalJwtIssuerConfig {
+        ExternalJwtIssuerConfig {
+            issuer: ISSUER.to_string(),
+            audiences: vec!["iggy".to_string()],
+            jwks_file: Some("jwks.json".to_string()),
+            jwks_url: None,
+            user_claim: Some("preferred_username".to_string()),
+            claim_rules: vec![ExternalJwtClaimRuleConfig {
+                claim: "realm_access.roles".to_string(),
+                value: "iggy-admin".to_string(),
+                username: "iggy".to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn claims_should_be_mapped_by_matching_rule_before_user_claim() {
+        let config = issuer_config();
+        let claims = json!({
+            "preferred_username": "alice",
+            "realm_access": { "roles": ["viewer", "iggy-admin"] }
+        });
+
+        assert_eq!(
+            map_claims_to_username(&config, &claims),
+            Some("iggy".to_string())
+        );
+    }
+
+    #[test]
+    fn claims_should_be_mapped_by_user_claim_given_no_matching_rule() {
+        let config = issuer_config();
+        let claims = json!({
+            "preferred_username": "alice",
+            "realm_access": { "roles": ["viewer"] }
+        });
+
+        assert_eq!(
+            map_claims_to_username(&config, &claims),
+            Some("alice".to_string())
+        );
+    }
+
+    #[test]
+    fn claims_should_not_be_mapped_given_no_matching_rule_nor_user_claim() {
+        let config = issuer_config();
+        let claims = json!({ "sub": "12345" });
+
+        assert_eq!(map_claims_to_username(&config, &claims), None);
+    }
+
+    #[tokio::test]
+    async fn token_signed_with_issuer_key_should_be_validated_and_mapped_to_username() {
+        let (encoding_key, jwks_file) = create_signing_key();
+        let validator = create_validator(&jwks_file);
+
+        let token = sign_token(&encoding_key, "iggy", "alice");
+        let identity = validator.validate(&token).await.unwrap();
+
+        assert_eq!(identity.username, "alice");
+        assert_eq!(identity.token_id, "token-1");
+        assert!(validator.is_external_token(&token));
+    }
+
+    #[tokio::test]
+    async fn token_with_invalid_audience_should_not_be_validated() {
+        let (encoding_key, jwks_file) = create_signing_key();
+        let validator = create_validator(&jwks_file);
+
+        let token = sign_token(&encoding_key, "other", "alice");
+        let error = validator.validate(&token).await.unwrap_err();
+
+        assert_eq!(error.as_code(), IggyError::Unauthenticated.as_code());
+    }
+
+    #[tokio::test]
+    async fn token_signed_with_symmetric_algorithm_should_not_be_validated() {
+        let (_, jwks_file) = create_signing_key();
+        let validator = create_validator(&jwks_file);
+
+        let token = jsonwebtoken::encode(
+            &Header::default(),
+            &claims("iggy", "alice"),
+            &EncodingKey::from_secret(b"secret"),
+        )
+        .unwrap();
+        let error = validator.validate(&token).await.unwrap_err();
+
+        assert_eq!(
+            error.as_code(),
+            IggyError::InvalidJwtAlgorithm(String::new()).as_code()
+        );
+    }
+
+    #[tokio::test]
+    async fn cached_keys_should_be_used_given_failed_refresh() {
+        let (encoding_key, jwks_file) = create_signing_key();
+        let mut issuer = issuer_config();
+        issuer.jwks_file = None;
+        // Nothing listens on the port, so the refresh fails right away.
+        issuer.jwks_url = Some("http://127.0.0.1:1/jwks.json".to_string());
+        let validator = ExternalJwtValidator::new(&ExternalJwtConfig {
+            enabled: true,
+            jwks_cache_ttl: IggyDuration::new_from_secs(60),
+            clock_skew: IggyDuration::new_from_secs(5),
+            issuers: vec![issuer],
+        });
+        let keys =
+            serde_json::from_str::<JwkSet>(&std::fs::read_to_string(jwks_file.path()).unwrap())
+                .unwrap();
+        validator.issuers[ISSUER]
+            .jwks
+            .write()
+            .await
+            .replace(CachedJwks {
+                keys,
+                loaded_at: Instant::now()
+                    .checked_sub(Duration::from_secs(120))
+                    .unwrap(),
+            });
+
+        let token = sign_token(&encoding_key, "iggy", "alice");
+        let identity = validator.validate(&token).await.unwrap();
+
+        assert_eq!(identity.username, "alice");
+    }
+
+    fn create_signing_key() -> (EncodingKey, NamedTempFile) {
+        let random = SystemRandom::new();
+        let pkcs8 =
+            EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, &random).unwrap();
+        let key_pair =
+            EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, pkcs8.as_ref(), &random)
+                .unwrap();
+        // The uncompressed public key consists of the 0x04 prefix followed by the X and Y coordinates.
+        let public_key = key_pair.public_key().as_ref();
+        let jwks = json!({
+            "keys": [{
+                "kty": "EC",
+                "crv": "P-256",
+                "kid": KEY_ID,
+                "x": URL_SAFE_NO_PAD.encode(&public_key[1..33]),
+                "y": URL_SAFE_NO_PAD.encode(&public_key[33..65]),
+            }]
+        });
+        let jwks_file = NamedTempFile::new().unwrap();
+        std::fs::write(jwks_file.path(), jwks.to_string()).unwrap();
+        (EncodingKey::from_ec_der(pkcs8.as_ref()), jwks_file)
+    }
+
+    fn create_validator(jwks_file: &NamedTempFile) -> ExternalJwtValidator {
+        let mut issuer = issuer_config();
+        issuer.jwks_file = Some(jwks_file.path().to_string_lossy().to_string());
+        ExternalJwtValidator::new(&ExternalJwtConfig {
+            enabled: true,
+            jwks_cache_ttl: IggyDuration::new_from_secs(60),
+            clock_skew: IggyDuration::new_from_secs(5),
+            issuers: vec![issuer],
+        })
+    }
+
+    fn sign_token(encoding_key: &EncodingKey, audience: &str, username: &str) -> String {
+        let mut header = Header::new(Algorithm::ES256);
+        header.kid = Some(KEY_ID.to_string());
+        jsonwebtoken::encode(&header, &claims(audience, username), encoding_key).unwrap()
+    }
+
+    fn claims(audience: &str, username: &str) -> Value {
+        let now = IggyTimestamp::now().to_secs();
+        json!({
+            "jti": "token-1",
+            "iss": ISSUER,
+            "aud": audience,
+            "sub": "12345",
+            "preferred_username": username,
+            "iat": now,
+            "exp": now + 60,
+        })
+    }
+}
@@ -16,6 +16,8 @@
  * under the License.
  */
 
+pub mod client_certificate;
+pub mod external_jwt;
 pub mod permissioner;
 pub mod permissioner_rules;
 pub mod user;
@@ -19,7 +19,7 @@
 use crate::streaming::users::user::User;
 use ahash::{AHashMap, AHashSet};
 use iggy_common::UserId;
-use iggy_common::{GlobalPermissions, Permissions, StreamPermissions};
+use iggy_common::{GlobalPermissions, Permissions, Role, StreamPermissions};
 
 #[derive(Debug, Default)]
 pub struct Permissioner {
@@ -29,12 +29,17 @@ pub struct Permissioner {
     pub(super) users_that_can_send_messages_to_all_streams: AHashSet<UserId>,
     pub(super) users_that_can_poll_messages_from_specific_streams: AHashSet<(UserId, u32)>,
     pub(super) users_that_can_send_messages_to_specific_streams: AHashSet<(UserId, u32)>,
+    pub(super) roles: AHashMap<String, Role>,
+    pub(super) users_roles: AHashMap<UserId, Vec<String>>,
+    pub(super) streams_names: AHashMap<u32, String>,
+    pub(super) topics_names: AHashMap<(u32, u32), String>,
 }
 
 impl Permissioner {
     pub fn init(&mut self, users: &[&User]) {
         for user in users {
             self.init_permissions_for_user(user.id, user.permissions.clone());
+            self.update_roles_for_user(user.id, user.roles.clone());
         }
     }
 
@@ -98,4 +103,47 @@ impl Permissioner {
         self.users_that_can_send_messages_to_specific_streams
             .retain(|(id, _)| *id != user_id);
     }
+
+    pub fn update_roles_for_user(&mut self, user_id: UserId, roles: Vec<String>) {
+        if roles.is_empty() {
+            self.users_roles.remove(&user_id);
+            return;
+        }
+
+        self.users_roles.insert(user_id, roles);
+    }
+
+    pub fn delete_roles_for_user(&mut self, user_id: UserId) {
+        self.users_roles.remove(&user_id);
+    }
+
+    pub fn set_role(&mut self, role: Role) {
+        self.roles.insert(role.name.clone(), role);
+    }
+
+    pub fn delete_role(&mut self, name: &str) {
+        self.roles.remove(name);
+        for roles in self.users_roles.values_mut() {
+            roles.retain(|role| role != name);
+        }
+        self.users_roles.retain(|_, roles| !roles.is_empty());
+    }
+
+    pub fn set_stream_name(&mut self, stream_id: u32, name: &str) {
+        self.streams_names.insert(stream_id, name.to_owned());
+    }
+
+    pub fn delete_stream_name(&mut self, stream_id: u32) {
+        self.streams_names.remove(&stream_id);
+        self.topics_names.retain(|(id, _), _| *id != stream_id);
+    }
+
+    pub fn set_topic_name(&mut self, stream_id: u32, topic_id: u32, name: &str) {
+        self.topics_names
+            .insert((stream_id, topic_id), name.to_owned());
+    }
+
+    pub fn delete_topic_name(&mut self, stream_id: u32, topic_id: u32) {
+        self.topics_names.remove(&(stream_id, topic_id));
+    }
 }
@@ -17,7 +17,7 @@
  */
 
 use crate::streaming::users::permissioner::Permissioner;
-use iggy_common::IggyError;
+use iggy_common::{IggyError, RoleAction};
 
 impl Permissioner {
     pub fn poll_messages(
@@ -26,6 +26,12 @@ impl Permissioner {
         stream_id: u32,
         topic_id: u32,
     ) -> Result<(), IggyError> {
+        if let Some(result) =
+            self.check_roles(user_id, RoleAction::PollMessages, stream_id, Some(topic_id))
+        {
+            return result;
+        }
+
         if self
             .users_that_can_poll_messages_from_all_streams
             .contains(&user_id)
@@ -86,6 +92,12 @@ impl Permissioner {
         stream_id: u32,
         topic_id: u32,
     ) -> Result<(), IggyError> {
+        if let Some(result) =
+            self.check_roles(user_id, RoleAction::SendMessages, stream_id, Some(topic_id))
+        {
+            return result;
+        }
+
         if self
             .users_that_can_send_messages_to_all_streams
             .contains(&user_id)
@@ -37,4 +37,14 @@ impl Permissioner {
     ) -> Result<(), IggyError> {
         self.update_topic(user_id, stream_id, topic_id)
     }
+
+    pub fn move_partition(&self, user_id: u32) -> Result<(), IggyError> {
+        if let Some(global_permissions) = self.users_permissions.get(&user_id)
+            && global_permissions.manage_servers
+        {
+            return Ok(());
+        }
+
+        Err(IggyError::Unauthorized)
+    }
 }
@@ -0,0 +1,74 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::users::permissioner::Permissioner;
+use iggy_common::{IggyError, RoleAction, RuleEffect};
+
+impl Permissioner {
+    /// Evaluates the rules of the roles assigned to the user for the action on the stream or its topic.
+    /// Any matching deny rule rejects the action, even if it's allowed by the other roles or the user's permissions.
+    /// Returns `None` if none of the rules matches, thus the user's permissions should be checked.
+    pub(super) fn check_roles(
+        &self,
+        user_id: u32,
+        action: RoleAction,
+        stream_id: u32,
+        topic_id: Option<u32>,
+    ) -> Option<Result<(), IggyError>> {
+        let stream = self.streams_names.get(&stream_id)?;
+        let topic = match topic_id {
+            Some(topic_id) => Some(self.topics_names.get(&(stream_id, topic_id))?.as_str()),
+            None => None,
+        };
+        self.check_roles_by_names(user_id, action, stream, topic)
+    }
+
+    /// Evaluates the rules of the roles assigned to the user for the action on the stream or its topic with the given names,
+    /// which might not exist yet, e.g. when the stream is being created.
+    pub(super) fn check_roles_by_names(
+        &self,
+        user_id: u32,
+        action: RoleAction,
+        stream: &str,
+        topic: Option<&str>,
+    ) -> Option<Result<(), IggyError>> {
+        let roles = self.users_roles.get(&user_id)?;
+        let mut allowed = false;
+        for rule in roles
+            .iter()
+            .filter_map(|role| self.roles.get(role))
+            .flat_map(|role| &role.rules)
+        {
+            if !rule.matches(action, stream, topic) {
+                continue;
+            }
+
+            match rule.effect {
+                RuleEffect::Deny => return Some(Err(IggyError::Unauthorized)),
+                RuleEffect::Allow => allowed = true,
+            }
+        }
+
+        allowed.then_some(Ok(()))
+    }
+
+    /// Returns `true` if the user has any roles assigned, so the accessible resources can be listed based on their rules.
+    pub(super) fn has_roles(&self, user_id: u32) -> bool {
+        self.users_roles.contains_key(&user_id)
+    }
+}
@@ -17,10 +17,14 @@
  */
 
 use crate::streaming::users::permissioner::Permissioner;
-use iggy_common::IggyError;
+use iggy_common::{IggyError, RoleAction};
 
 impl Permissioner {
     pub fn get_stream(&self, user_id: u32, stream_id: u32) -> Result<(), IggyError> {
+        if let Some(result) = self.check_roles(user_id, RoleAction::ReadStream, stream_id, None) {
+            return result;
+        }
+
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_streams || global_permissions.read_streams {
                 return Ok(());
@@ -37,6 +41,8 @@ impl Permissioner {
         Err(IggyError::Unauthorized)
     }
 
+    /// Checks if the user can list the streams, which should be then filtered with `get_stream`,
+    /// as some of them might be denied by the roles, or the roles might allow only some of them.
     pub fn get_streams(&self, user_id: u32) -> Result<(), IggyError> {
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_streams || global_permissions.read_streams {
@@ -44,10 +50,20 @@ impl Permissioner {
             }
         }
 
+        if self.has_roles(user_id) {
+            return Ok(());
+        }
+
         Err(IggyError::Unauthorized)
     }
 
-    pub fn create_stream(&self, user_id: u32) -> Result<(), IggyError> {
+    pub fn create_stream(&self, user_id: u32, name: &str) -> Result<(), IggyError> {
+        if let Some(result) =
+            self.check_roles_by_names(user_id, RoleAction::ManageStream, name, None)
+        {
+            return result;
+        }
+
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_streams {
                 return Ok(());
@@ -70,6 +86,10 @@ impl Permissioner {
     }
 
     fn manage_stream(&self, user_id: u32, stream_id: u32) -> Result<(), IggyError> {
+        if let Some(result) = self.check_roles(user_id, RoleAction::ManageStream, stream_id, None) {
+            return result;
+        }
+
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_streams {
                 return Ok(());
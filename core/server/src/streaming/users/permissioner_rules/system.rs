@@ -32,6 +32,24 @@ impl Permissioner {
         self.get_server_info(user_id)
     }
 
+    pub fn get_audit_log(&self, user_id: u32) -> Result<(), IggyError> {
+        self.get_server_info(user_id)
+    }
+
+    pub fn export_metadata(
+        &self,
+        user_id: u32,
+        include_password_hashes: bool,
+    ) -> Result<(), IggyError> {
+        self.get_server_info(user_id)?;
+        self.get_streams(user_id)?;
+        if include_password_hashes {
+            self.create_user(user_id)
+        } else {
+            self.get_users(user_id)
+        }
+    }
+
     fn get_server_info(&self, user_id: u32) -> Result<(), IggyError> {
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_servers || global_permissions.read_servers {
@@ -17,10 +17,16 @@
  */
 
 use crate::streaming::users::permissioner::Permissioner;
-use iggy_common::IggyError;
+use iggy_common::{IggyError, RoleAction};
 
 impl Permissioner {
     pub fn get_topic(&self, user_id: u32, stream_id: u32, topic_id: u32) -> Result<(), IggyError> {
+        if let Some(result) =
+            self.check_roles(user_id, RoleAction::ReadTopic, stream_id, Some(topic_id))
+        {
+            return result;
+        }
+
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.read_streams
                 || global_permissions.manage_streams
@@ -49,7 +55,13 @@ impl Permissioner {
         Err(IggyError::Unauthorized)
     }
 
+    /// Checks if the user can list the topics in the stream, which should be then filtered with `get_topic`,
+    /// as some of them might be denied by the roles, or the roles might allow only some of them.
     pub fn get_topics(&self, user_id: u32, stream_id: u32) -> Result<(), IggyError> {
+        if let Some(result) = self.check_roles(user_id, RoleAction::ReadTopic, stream_id, None) {
+            return result;
+        }
+
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.read_streams
                 || global_permissions.manage_streams
@@ -75,10 +87,18 @@ impl Permissioner {
             }
         }
 
+        if self.has_roles(user_id) {
+            return Ok(());
+        }
+
         Err(IggyError::Unauthorized)
     }
 
     pub fn create_topic(&self, user_id: u32, stream_id: u32) -> Result<(), IggyError> {
+        if let Some(result) = self.check_roles(user_id, RoleAction::ManageTopic, stream_id, None) {
+            return result;
+        }
+
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_streams || global_permissions.manage_topics {
                 return Ok(());
@@ -123,6 +143,12 @@ impl Permissioner {
     }
 
     fn manage_topic(&self, user_id: u32, stream_id: u32, topic_id: u32) -> Result<(), IggyError> {
+        if let Some(result) =
+            self.check_roles(user_id, RoleAction::ManageTopic, stream_id, Some(topic_id))
+        {
+            return result;
+        }
+
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_streams || global_permissions.manage_topics {
                 return Ok(());
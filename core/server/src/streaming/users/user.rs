@@ -21,7 +21,7 @@ use dashmap::DashMap;
 use iggy_common::IggyTimestamp;
 use iggy_common::UserStatus;
 use iggy_common::defaults::*;
-use iggy_common::{Permissions, UserId};
+use iggy_common::{Permissions, UserId, UserQuotas};
 use std::sync::Arc;
 
 #[derive(Debug)]
@@ -32,6 +32,8 @@ pub struct User {
     pub password: String,
     pub created_at: IggyTimestamp,
     pub permissions: Option<Permissions>,
+    pub quotas: Option<UserQuotas>,
+    pub roles: Vec<String>,
     pub personal_access_tokens: DashMap<Arc<String>, PersonalAccessToken>,
 }
 
@@ -44,6 +46,8 @@ impl Default for User {
             password: "secret".to_string(),
             created_at: IggyTimestamp::now(),
             permissions: None,
+            quotas: None,
+            roles: Vec::new(),
             personal_access_tokens: DashMap::new(),
         }
     }
@@ -87,6 +91,8 @@ impl User {
             created_at: IggyTimestamp::now(),
             status,
             permissions,
+            quotas: None,
+            roles: Vec::new(),
             personal_access_tokens: DashMap::new(),
         }
     }
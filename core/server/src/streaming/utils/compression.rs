@@ -0,0 +1,200 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use bytes::{BufMut, BytesMut};
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use iggy_common::{CompressionAlgorithm, HeaderKind, IggyError};
+use std::io::{Read, Write};
+use tracing::error;
+
+/// The reserved user header key, which is appended to the user headers of the compressed message.
+/// Its value holds the code of the compression algorithm, so that each message can be decompressed
+/// on its own, even if the compression algorithm of the topic has been changed in the meantime.
+pub const COMPRESSION_HEADER_KEY: &str = "iggy-compression";
+
+/// The size of the serialized compression user header.
+pub const COMPRESSION_HEADER_SIZE: usize = 4 + COMPRESSION_HEADER_KEY.len() + 1 + 4 + 1;
+
+/// Compresses the data using the provided algorithm. The level is used only by zstd.
+pub fn compress(
+    algorithm: CompressionAlgorithm,
+    zstd_level: i32,
+    data: &[u8],
+) -> Result<Vec<u8>, IggyError> {
+    let compressed = match algorithm {
+        CompressionAlgorithm::None => Ok(data.to_vec()),
+        CompressionAlgorithm::Gzip => {
+            let mut encoder =
+                GzEncoder::new(Vec::with_capacity(data.len()), Compression::default());
+            encoder.write_all(data).and_then(|_| encoder.finish())
+        }
+        CompressionAlgorithm::Zstd => zstd::bulk::compress(data, zstd_level),
+        CompressionAlgorithm::Lz4 => Ok(lz4_flex::compress_prepend_size(data)),
+        CompressionAlgorithm::Snappy => snap::raw::Encoder::new()
+            .compress_vec(data)
+            .map_err(std::io::Error::other),
+    };
+
+    compressed.map_err(|error| {
+        error!("Cannot compress data using {algorithm} algorithm. Error: {error}");
+        IggyError::CannotCompressData
+    })
+}
+
+/// Decompresses the data previously compressed using the provided algorithm. The decompressed data
+/// can't exceed the given maximum size, so that a malformed payload can't exhaust the memory.
+pub fn decompress(
+    algorithm: CompressionAlgorithm,
+    data: &[u8],
+    max_size: usize,
+) -> Result<Vec<u8>, IggyError> {
+    let decompressed = match algorithm {
+        CompressionAlgorithm::None => Ok(data.to_vec()),
+        CompressionAlgorithm::Gzip => {
+            let mut decompressed = Vec::with_capacity((data.len() * 2).min(max_size));
+            GzDecoder::new(data)
+                .take(max_size as u64 + 1)
+                .read_to_end(&mut decompressed)
+                .map(|_| decompressed)
+        }
+        CompressionAlgorithm::Zstd => zstd::bulk::decompress(data, max_size),
+        CompressionAlgorithm::Lz4 => match data.get(..4) {
+            Some(size) if u32::from_le_bytes(size.try_into().unwrap()) as usize > max_size => {
+                Err(std::io::Error::other("decompressed size exceeds the limit"))
+            }
+            _ => lz4_flex::decompress_size_prepended(data).map_err(std::io::Error::other),
+        },
+        CompressionAlgorithm::Snappy => snap::raw::decompress_len(data)
+            .and_then(|size| {
+                if size > max_size {
+                    return Err(snap::Error::TooBig {
+                        given: size as u64,
+                        max: max_size as u64,
+                    });
+                }
+                snap::raw::Decoder::new().decompress_vec(data)
+            })
+            .map_err(std::io::Error::other),
+    }
+    .and_then(|decompressed| {
+        if decompressed.len() > max_size {
+            return Err(std::io::Error::other("decompressed size exceeds the limit"));
+        }
+        Ok(decompressed)
+    });
+
+    decompressed.map_err(|error| {
+        error!("Cannot decompress data using {algorithm} algorithm. Error: {error}");
+        IggyError::CannotDecompressData
+    })
+}
+
+/// Appends the serialized compression user header to the provided buffer.
+pub fn write_compression_header(algorithm: CompressionAlgorithm, buffer: &mut BytesMut) {
+    buffer.put_u32_le(COMPRESSION_HEADER_KEY.len() as u32);
+    buffer.put_slice(COMPRESSION_HEADER_KEY.as_bytes());
+    buffer.put_u8(HeaderKind::Uint8.as_code());
+    buffer.put_u32_le(1);
+    buffer.put_u8(algorithm.as_code());
+}
+
+/// Checks whether the serialized user headers end with the compression user header.
+/// If so, returns the compression algorithm and the remaining user headers.
+pub fn split_compression_header(user_headers: &[u8]) -> Option<(CompressionAlgorithm, &[u8])> {
+    if user_headers.len() < COMPRESSION_HEADER_SIZE {
+        return None;
+    }
+
+    let (remaining, header) = user_headers.split_at(user_headers.len() - COMPRESSION_HEADER_SIZE);
+    let key_end = 4 + COMPRESSION_HEADER_KEY.len();
+    if header[..4] != (COMPRESSION_HEADER_KEY.len() as u32).to_le_bytes()
+        || &header[4..key_end] != COMPRESSION_HEADER_KEY.as_bytes()
+        || header[key_end] != HeaderKind::Uint8.as_code()
+        || header[key_end + 1..key_end + 5] != 1u32.to_le_bytes()
+    {
+        return None;
+    }
+
+    CompressionAlgorithm::from_code(header[key_end + 5])
+        .ok()
+        .map(|algorithm| (algorithm, remaining))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALGORITHMS: [CompressionAlgorithm; 4] = [
+        CompressionAlgorithm::Gzip,
+        CompressionAlgorithm::Zstd,
+        CompressionAlgorithm::Lz4,
+        CompressionAlgorithm::Snappy,
+    ];
+
+    #[test]
+    fn data_should_be_compressed_and_decompressed_using_each_algorithm() {
+        let data = "iggy ".repeat(1000).into_bytes();
+        for algorithm in ALGORITHMS {
+            let compressed = compress(algorithm, 3, &data).unwrap();
+            assert!(compressed.len() < data.len(), "{algorithm} should compress");
+            let decompressed = decompress(algorithm, &compressed, data.len()).unwrap();
+            assert_eq!(decompressed, data, "{algorithm} should decompress");
+        }
+    }
+
+    #[test]
+    fn invalid_data_should_not_be_decompressed() {
+        let data = b"definitely not compressed";
+        for algorithm in ALGORITHMS {
+            assert!(decompress(algorithm, data, 1000).is_err(), "{algorithm}");
+        }
+    }
+
+    #[test]
+    fn data_exceeding_max_size_should_not_be_decompressed() {
+        let data = "iggy ".repeat(1000).into_bytes();
+        for algorithm in ALGORITHMS {
+            let compressed = compress(algorithm, 3, &data).unwrap();
+            assert!(
+                decompress(algorithm, &compressed, data.len() - 1).is_err(),
+                "{algorithm}"
+            );
+        }
+    }
+
+    #[test]
+    fn compression_header_should_be_split_from_user_headers() {
+        let mut user_headers = BytesMut::from(&b"user headers"[..]);
+        write_compression_header(CompressionAlgorithm::Zstd, &mut user_headers);
+        assert_eq!(
+            user_headers.len(),
+            b"user headers".len() + COMPRESSION_HEADER_SIZE
+        );
+
+        let (algorithm, remaining) = split_compression_header(&user_headers).unwrap();
+        assert_eq!(algorithm, CompressionAlgorithm::Zstd);
+        assert_eq!(remaining, b"user headers");
+    }
+
+    #[test]
+    fn user_headers_without_compression_header_should_not_be_split() {
+        assert!(split_compression_header(b"").is_none());
+        assert!(split_compression_header(&[0; COMPRESSION_HEADER_SIZE]).is_none());
+    }
+}
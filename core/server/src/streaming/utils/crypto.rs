@@ -16,7 +16,7 @@
  * under the License.
  */
 
-use bcrypt::{hash, verify};
+use bcrypt::{HashParts, hash, verify};
 
 pub fn hash_password(password: &str) -> String {
     hash(password, 4).unwrap()
@@ -25,3 +25,7 @@ pub fn hash_password(password: &str) -> String {
 pub fn verify_password(password: &str, hash: &str) -> bool {
     verify(password, hash).unwrap_or(false)
 }
+
+pub fn is_password_hash(hash: &str) -> bool {
+    hash.parse::<HashParts>().is_ok()
+}
@@ -16,7 +16,8 @@
  * under the License.
  */
 
-use std::path::Path;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 use tokio::fs::{File, OpenOptions, remove_file};
 
 pub async fn open(path: &str) -> Result<File, std::io::Error> {
@@ -46,3 +47,121 @@ pub async fn rename(old_path: &str, new_path: &str) -> Result<(), std::io::Error
 pub async fn exists(path: &str) -> Result<bool, std::io::Error> {
     tokio::fs::try_exists(path).await
 }
+
+pub fn available_space(path: &str) -> Result<u64, std::io::Error> {
+    let stats = nix::sys::statvfs::statvfs(Path::new(path))?;
+    Ok(stats.blocks_available() as u64 * stats.fragment_size() as u64)
+}
+
+/// Copies the files from the source directory (recursively) to the target directory, and removes the ones
+/// no longer present in the source. Files with one of the append-only extensions are copied only when they
+/// are missing in the target directory, differ in size or were modified after being copied. All the other
+/// files might be rewritten in place without changing their size, thus they are always copied.
+/// Returns the number of copied bytes.
+pub async fn sync_directory(
+    source: &str,
+    target: &str,
+    append_only_extensions: &[&str],
+) -> Result<u64, std::io::Error> {
+    let mut copied_bytes = 0;
+    let mut directories = vec![(PathBuf::from(source), PathBuf::from(target))];
+    while let Some((source_directory, target_directory)) = directories.pop() {
+        tokio::fs::create_dir_all(&target_directory).await?;
+        let mut names = HashSet::new();
+        let mut entries = tokio::fs::read_dir(&source_directory).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let source_path = entry.path();
+            let target_path = target_directory.join(entry.file_name());
+            names.insert(entry.file_name());
+            let metadata = entry.metadata().await?;
+            if metadata.is_dir() {
+                directories.push((source_path, target_path));
+                continue;
+            }
+
+            let is_append_only = source_path
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .is_some_and(|extension| append_only_extensions.contains(&extension));
+            let is_synced = match tokio::fs::metadata(&target_path).await {
+                Ok(_) if !is_append_only => false,
+                Ok(target_metadata) => {
+                    target_metadata.len() == metadata.len()
+                        && target_metadata.modified()? >= metadata.modified()?
+                }
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => false,
+                Err(error) => return Err(error),
+            };
+            if !is_synced {
+                copied_bytes += tokio::fs::copy(&source_path, &target_path).await?;
+            }
+        }
+
+        let mut entries = tokio::fs::read_dir(&target_directory).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            if names.contains(&entry.file_name()) {
+                continue;
+            }
+
+            if entry.file_type().await?.is_dir() {
+                tokio::fs::remove_dir_all(entry.path()).await?;
+            } else {
+                remove_file(entry.path()).await?;
+            }
+        }
+    }
+
+    Ok(copied_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn sync_directory_should_copy_rewritten_files_of_the_same_size() {
+        let source = tempfile::TempDir::new().unwrap();
+        let target = tempfile::TempDir::new().unwrap();
+        let source_path = source.path().to_str().unwrap();
+        let target_path = target.path().to_str().unwrap();
+        tokio::fs::write(source.path().join("offset"), b"1111")
+            .await
+            .unwrap();
+        tokio::fs::write(source.path().join("0.log"), b"1111")
+            .await
+            .unwrap();
+        sync_directory(source_path, target_path, &["log"])
+            .await
+            .unwrap();
+
+        tokio::fs::write(source.path().join("offset"), b"2222")
+            .await
+            .unwrap();
+        let copied_bytes = sync_directory(source_path, target_path, &["log"])
+            .await
+            .unwrap();
+
+        assert_eq!(copied_bytes, 4);
+        let offset = tokio::fs::read(target.path().join("offset")).await.unwrap();
+        assert_eq!(offset, b"2222");
+    }
+
+    #[tokio::test]
+    async fn sync_directory_should_remove_files_missing_in_source() {
+        let source = tempfile::TempDir::new().unwrap();
+        let target = tempfile::TempDir::new().unwrap();
+        tokio::fs::write(target.path().join("0.log"), b"1111")
+            .await
+            .unwrap();
+
+        sync_directory(
+            source.path().to_str().unwrap(),
+            target.path().to_str().unwrap(),
+            &["log"],
+        )
+        .await
+        .unwrap();
+
+        assert!(!target.path().join("0.log").exists());
+    }
+}
@@ -16,15 +16,20 @@
  * under the License.
  */
 
+use crate::audit::AuditEvent;
 use crate::binary::command::ServerCommandHandler;
 use crate::binary::{command, sender::SenderKind};
 use crate::server_error::ConnectionError;
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::diagnostics::metrics::RequestMetrics;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use crate::tcp::connection_handler::command::ServerCommand;
-use iggy_common::IggyError;
+use iggy_common::{CORRELATION_ID_FLAG, IggyError};
 use std::io::ErrorKind;
 use std::sync::Arc;
+use std::time::Instant;
+use tokio::task::JoinSet;
 use tracing::{debug, error, info};
 
 const INITIAL_BYTES_LENGTH: usize = 4;
@@ -36,7 +41,22 @@ pub(crate) async fn handle_connection(
 ) -> Result<(), ConnectionError> {
     let mut length_buffer = [0u8; INITIAL_BYTES_LENGTH];
     let mut code_buffer = [0u8; INITIAL_BYTES_LENGTH];
+    let mut correlation_id_buffer = [0u8; INITIAL_BYTES_LENGTH];
+    let request_metrics = system.read().await.metrics.request_metrics();
+    // The pipelined requests handled concurrently are aborted once the connection is closed.
+    let mut pipelined_requests = JoinSet::new();
     loop {
+        while let Some(result) = pipelined_requests.try_join_next() {
+            match result {
+                Ok(Ok(())) => {}
+                Ok(Err(error)) => return Err(error),
+                Err(error) => {
+                    error!("Failed to handle pipelined request, session: {session}. {error}")
+                }
+            }
+        }
+
+        sender.set_correlation_id(None);
         let read_length = match sender.read(&mut length_buffer).await {
             Ok(read_length) => read_length,
             Err(error) => {
@@ -56,34 +76,113 @@ pub(crate) async fn handle_connection(
             continue;
         }
 
-        let length = u32::from_le_bytes(length_buffer);
+        let mut length = u32::from_le_bytes(length_buffer);
         sender.read(&mut code_buffer).await?;
-        let code = u32::from_le_bytes(code_buffer);
+        let mut code = u32::from_le_bytes(code_buffer);
+        let mut correlation_id = None;
+        if code & CORRELATION_ID_FLAG != 0 {
+            // The pipelined request carries the correlation ID echoed before the response.
+            code &= !CORRELATION_ID_FLAG;
+            sender.read(&mut correlation_id_buffer).await?;
+            let Some(payload_length) = length
+                .checked_sub(INITIAL_BYTES_LENGTH as u32)
+                .filter(|length| *length >= INITIAL_BYTES_LENGTH as u32)
+            else {
+                // The rest of the malformed request can't be skipped, so the connection is closed.
+                let error = IggyError::CommandLengthError(format!(
+                    "The pipelined TCP request length: {length} doesn't include the code and the correlation ID."
+                ));
+                sender.send_error_response(error.clone()).await?;
+                return Err(ConnectionError::from(error));
+            };
+            length = payload_length;
+            correlation_id = Some(u32::from_le_bytes(correlation_id_buffer));
+            debug!("Received a pipelined TCP request with correlation ID: {correlation_id:?}");
+        }
+        sender.set_correlation_id(correlation_id);
         debug!("Received a TCP request, length: {length}, code: {code}");
         let command = ServerCommand::from_code_and_reader(code, sender, length - 4).await?;
         debug!("Received a TCP command: {command}, payload size: {length}");
-        match command.handle(sender, length, &session, &system).await {
-            Ok(_) => {
-                debug!(
-                    "Command was handled successfully, session: {session}. TCP response was sent."
-                );
-            }
-            Err(error) => {
-                error!("Command was not handled successfully, session: {session}, error: {error}.");
-                if let IggyError::ClientNotFound(_) = error {
-                    sender.send_error_response(error).await?;
-                    debug!("TCP error response was sent to: {session}.");
-                    error!("Session: {session} will be deleted.");
-                    return Err(ConnectionError::from(IggyError::ClientNotFound(
-                        session.client_id,
-                    )));
-                } else {
-                    sender.send_error_response(error).await?;
-                    debug!("TCP error response was sent to: {session}.");
-                }
+        if let Some(correlation_id) = correlation_id
+            && command.can_be_handled_concurrently()
+            && let Some(mut pipelined_sender) = sender.get_tcp_pipelined_sender(correlation_id)
+        {
+            let session = session.clone();
+            let system = system.clone();
+            let request_metrics = request_metrics.clone();
+            pipelined_requests.spawn(async move {
+                handle_command(
+                    command,
+                    code,
+                    length,
+                    &mut pipelined_sender,
+                    &session,
+                    &system,
+                    &request_metrics,
+                )
+                .await
+            });
+            continue;
+        }
+
+        handle_command(
+            command,
+            code,
+            length,
+            sender,
+            &session,
+            &system,
+            &request_metrics,
+        )
+        .await?;
+    }
+}
+
+async fn handle_command(
+    command: ServerCommand,
+    code: u32,
+    length: u32,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+    request_metrics: &RequestMetrics,
+) -> Result<(), ConnectionError> {
+    let audit_target = command.audit_target();
+    let user_id = session.get_user_id();
+    let started_at = Instant::now();
+    let result = command.handle(sender, length, session, system).await;
+    request_metrics.record(code, started_at.elapsed());
+    if let Some(target) = audit_target {
+        let event = AuditEvent {
+            user_id,
+            address: session.ip_address,
+            transport: Transport::Tcp.to_string(),
+            code,
+            target,
+            error_code: result.as_ref().err().map(IggyError::as_code),
+        };
+        system.read().await.audit.record(event).await;
+    }
+    match result {
+        Ok(_) => {
+            debug!("Command was handled successfully, session: {session}. TCP response was sent.");
+        }
+        Err(error) => {
+            error!("Command was not handled successfully, session: {session}, error: {error}.");
+            if let IggyError::ClientNotFound(_) = error {
+                sender.send_error_response(error).await?;
+                debug!("TCP error response was sent to: {session}.");
+                error!("Session: {session} will be deleted.");
+                return Err(ConnectionError::from(IggyError::ClientNotFound(
+                    session.client_id,
+                )));
+            } else {
+                sender.send_error_response(error).await?;
+                debug!("TCP error response was sent to: {session}.");
             }
         }
     }
+    Ok(())
 }
 
 pub(crate) fn handle_error(error: ConnectionError) {
@@ -18,14 +18,24 @@
 
 use iggy_common::IggyError;
 use std::io::IoSlice;
+use std::sync::Arc;
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::sync::Mutex;
 use tracing::debug;
 
 const STATUS_OK: &[u8] = &[0; 4];
 
+/// The write half of the connection, shared with the tasks handling the pipelined requests concurrently.
+/// Each response is written as a whole while holding the lock, so that the responses are never interleaved.
+pub(crate) type SharedWriter = Arc<Mutex<Box<dyn AsyncWrite + Send + Unpin>>>;
+
+pub(crate) fn shared_writer(writer: impl AsyncWrite + Send + Unpin + 'static) -> SharedWriter {
+    Arc::new(Mutex::new(Box::new(writer)))
+}
+
 pub(crate) async fn read<T>(stream: &mut T, buffer: &mut [u8]) -> Result<usize, IggyError>
 where
-    T: AsyncRead + AsyncWrite + Unpin,
+    T: AsyncRead + Unpin,
 {
     match stream.read_exact(buffer).await {
         Ok(0) => Err(IggyError::ConnectionClosed),
@@ -40,48 +50,59 @@ where
     }
 }
 
-pub(crate) async fn send_empty_ok_response<T>(stream: &mut T) -> Result<(), IggyError>
+pub(crate) async fn send_empty_ok_response<T>(
+    stream: &mut T,
+    correlation_id: Option<u32>,
+) -> Result<(), IggyError>
 where
-    T: AsyncRead + AsyncWrite + Unpin,
+    T: AsyncWrite + Unpin,
 {
-    send_ok_response(stream, &[]).await
+    send_ok_response(stream, correlation_id, &[]).await
 }
 
-pub(crate) async fn send_ok_response<T>(stream: &mut T, payload: &[u8]) -> Result<(), IggyError>
+pub(crate) async fn send_ok_response<T>(
+    stream: &mut T,
+    correlation_id: Option<u32>,
+    payload: &[u8],
+) -> Result<(), IggyError>
 where
-    T: AsyncRead + AsyncWrite + Unpin,
+    T: AsyncWrite + Unpin,
 {
-    send_response(stream, STATUS_OK, payload).await
+    send_response(stream, correlation_id, STATUS_OK, payload).await
 }
 
 pub(crate) async fn send_ok_response_vectored<T>(
     stream: &mut T,
+    correlation_id: Option<u32>,
     length: &[u8],
     slices: Vec<IoSlice<'_>>,
 ) -> Result<(), IggyError>
 where
-    T: AsyncRead + AsyncWrite + Unpin,
+    T: AsyncWrite + Unpin,
 {
-    send_response_vectored(stream, STATUS_OK, length, slices).await
+    send_response_vectored(stream, correlation_id, STATUS_OK, length, slices).await
 }
 
 pub(crate) async fn send_error_response<T>(
     stream: &mut T,
+    correlation_id: Option<u32>,
     error: IggyError,
 ) -> Result<(), IggyError>
 where
-    T: AsyncRead + AsyncWrite + Unpin,
+    T: AsyncWrite + Unpin,
 {
-    send_response(stream, &error.as_code().to_le_bytes(), &[]).await
+    send_response(stream, correlation_id, &error.as_code().to_le_bytes(), &[]).await
 }
 
+/// Sends the response, preceded by the correlation ID if the request was pipelined.
 pub(crate) async fn send_response<T>(
     stream: &mut T,
+    correlation_id: Option<u32>,
     status: &[u8],
     payload: &[u8],
 ) -> Result<(), IggyError>
 where
-    T: AsyncRead + AsyncWrite + Unpin,
+    T: AsyncWrite + Unpin,
 {
     debug!(
         "Sending response of len: {} with status: {:?}...",
@@ -89,8 +110,14 @@ where
         status
     );
     let length = (payload.len() as u32).to_le_bytes();
+    let correlation_id = correlation_id.map(u32::to_le_bytes);
+    let correlation_id = correlation_id.as_ref().map_or(&[][..], |id| &id[..]);
     stream
-        .write_all(&[status, &length, payload].as_slice().concat())
+        .write_all(
+            &[correlation_id, status, &length, payload]
+                .as_slice()
+                .concat(),
+        )
         .await
         .map_err(|_| IggyError::TcpError)?;
     debug!("Sent response with status: {:?}", status);
@@ -99,19 +126,26 @@ where
 
 pub(crate) async fn send_response_vectored<T>(
     stream: &mut T,
+    correlation_id: Option<u32>,
     status: &[u8],
     length: &[u8],
     mut slices: Vec<IoSlice<'_>>,
 ) -> Result<(), IggyError>
 where
-    T: AsyncReadExt + AsyncWriteExt + Unpin,
+    T: AsyncWrite + Unpin,
 {
     debug!(
         "Sending vectored response of len: {} with status: {:?}...",
         slices.len(),
         status
     );
-    let prefix = [IoSlice::new(status), IoSlice::new(length)];
+    let correlation_id = correlation_id.map(u32::to_le_bytes);
+    let correlation_id = correlation_id.as_ref().map_or(&[][..], |id| &id[..]);
+    let prefix = [
+        IoSlice::new(correlation_id),
+        IoSlice::new(status),
+        IoSlice::new(length),
+    ];
     slices.splice(0..0, prefix);
     let mut slice_refs = slices.as_mut_slice();
     while !slice_refs.is_empty() {
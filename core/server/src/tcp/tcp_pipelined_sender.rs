@@ -0,0 +1,74 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::sender::Sender;
+use crate::server_error::ServerError;
+use crate::tcp::sender;
+use crate::tcp::sender::SharedWriter;
+use iggy_common::IggyError;
+use tracing::error;
+
+/// Sends the response to the pipelined request handled concurrently with the other requests on the connection.
+/// The request is read by the connection handler beforehand, so reading is not supported.
+pub struct TcpPipelinedSender {
+    pub(crate) writer: SharedWriter,
+    pub(crate) correlation_id: Option<u32>,
+}
+
+impl Sender for TcpPipelinedSender {
+    async fn read(&mut self, _buffer: &mut [u8]) -> Result<usize, IggyError> {
+        error!("Cannot read from the sender of the pipelined request.");
+        Err(IggyError::InvalidCommand)
+    }
+
+    async fn send_empty_ok_response(&mut self) -> Result<(), IggyError> {
+        sender::send_empty_ok_response(&mut *self.writer.lock().await, self.correlation_id).await
+    }
+
+    async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError> {
+        sender::send_ok_response(&mut *self.writer.lock().await, self.correlation_id, payload).await
+    }
+
+    async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
+        sender::send_error_response(&mut *self.writer.lock().await, self.correlation_id, error)
+            .await
+    }
+
+    fn set_correlation_id(&mut self, correlation_id: Option<u32>) {
+        self.correlation_id = correlation_id;
+    }
+
+    async fn shutdown(&mut self) -> Result<(), ServerError> {
+        // The connection is shut down by its handler.
+        Ok(())
+    }
+
+    async fn send_ok_response_vectored(
+        &mut self,
+        length: &[u8],
+        slices: Vec<std::io::IoSlice<'_>>,
+    ) -> Result<(), IggyError> {
+        sender::send_ok_response_vectored(
+            &mut *self.writer.lock().await,
+            self.correlation_id,
+            length,
+            slices,
+        )
+        .await
+    }
+}
@@ -17,16 +17,28 @@
  */
 
 use crate::binary::sender::SenderKind;
+use crate::configs::client_auth::ClientCertificateIdentity;
 use crate::configs::tcp::TcpTlsConfig;
 use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::users::client_certificate;
 use crate::tcp::connection_handler::{handle_connection, handle_error};
+use openssl::pkcs12::Pkcs12;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer};
 use std::net::SocketAddr;
-use tokio::net::TcpSocket;
+use std::sync::Arc;
+use tokio::net::{TcpSocket, TcpStream};
 use tokio::sync::oneshot;
 use tokio_native_tls::native_tls;
 use tokio_native_tls::native_tls::Identity;
-use tracing::{error, info};
+use tracing::{error, info, warn};
+
+#[derive(Clone)]
+enum TlsAcceptor {
+    NativeTls(tokio_native_tls::TlsAcceptor),
+    Rustls(tokio_rustls::TlsAcceptor, ClientCertificateIdentity),
+}
 
 pub(crate) async fn start(
     address: &str,
@@ -42,16 +54,21 @@ pub(crate) async fn start(
             panic!("Unable to read certificate file.");
         }
 
-        let identity = Identity::from_pkcs12(&certificate.unwrap(), &config.password);
-        if identity.is_err() {
-            panic!("Unable to create identity from certificate.");
-        }
+        let acceptor = match config.client_auth.enabled {
+            true => create_mtls_acceptor(&certificate.unwrap(), &config),
+            false => {
+                let identity = Identity::from_pkcs12(&certificate.unwrap(), &config.password);
+                if identity.is_err() {
+                    panic!("Unable to create identity from certificate.");
+                }
 
-        let acceptor = tokio_native_tls::TlsAcceptor::from(
-            native_tls::TlsAcceptor::builder(identity.unwrap())
-                .build()
-                .unwrap(),
-        );
+                TlsAcceptor::NativeTls(tokio_native_tls::TlsAcceptor::from(
+                    native_tls::TlsAcceptor::builder(identity.unwrap())
+                        .build()
+                        .unwrap(),
+                ))
+            }
+        };
 
         let addr = address.parse();
         if addr.is_err() {
@@ -89,10 +106,30 @@ pub(crate) async fn start(
 
                     let client_id = session.client_id;
                     let acceptor = acceptor.clone();
-                    let stream = acceptor.accept(stream).await.unwrap();
                     let system = system.clone();
-                    let mut sender = SenderKind::get_tcp_tls_sender(stream);
                     tokio::spawn(async move {
+                        let mut sender = match accept(&acceptor, stream).await {
+                            Ok((sender, client_certificate)) => {
+                                if let Some((certificate, identity)) = client_certificate {
+                                    authenticate_with_certificate(
+                                        &system,
+                                        &session,
+                                        &certificate,
+                                        identity,
+                                    )
+                                    .await;
+                                }
+                                sender
+                            }
+                            Err(error) => {
+                                error!(
+                                    "Unable to complete TLS handshake for client: {client_id}, address: {address}. {error}"
+                                );
+                                system.read().await.delete_client(client_id).await;
+                                return;
+                            }
+                        };
+
                         if let Err(error) =
                             handle_connection(session, &mut sender, system.clone()).await
                         {
@@ -119,3 +156,95 @@ pub(crate) async fn start(
         Err(_) => panic!("Failed to get the local address for TCP TLS listener."),
     }
 }
+
+type ClientCertificate = (CertificateDer<'static>, ClientCertificateIdentity);
+
+async fn accept(
+    acceptor: &TlsAcceptor,
+    stream: TcpStream,
+) -> Result<(SenderKind, Option<ClientCertificate>), std::io::Error> {
+    match acceptor {
+        TlsAcceptor::NativeTls(acceptor) => {
+            let stream = acceptor
+                .accept(stream)
+                .await
+                .map_err(std::io::Error::other)?;
+            Ok((SenderKind::get_tcp_tls_sender(stream), None))
+        }
+        TlsAcceptor::Rustls(acceptor, identity) => {
+            let stream = acceptor.accept(stream).await?;
+            let certificate = stream
+                .get_ref()
+                .1
+                .peer_certificates()
+                .and_then(|certificates| certificates.first())
+                .map(|certificate| (certificate.clone().into_owned(), *identity));
+            Ok((SenderKind::get_tcp_mtls_sender(stream), certificate))
+        }
+    }
+}
+
+async fn authenticate_with_certificate(
+    system: &SharedSystem,
+    session: &Session,
+    certificate: &CertificateDer<'_>,
+    identity: ClientCertificateIdentity,
+) {
+    if let Err(error) = system
+        .read()
+        .await
+        .login_with_client_certificate(certificate, identity, session)
+        .await
+    {
+        warn!(
+            "Unable to authenticate client: {} with certificate. {error}",
+            session.client_id
+        );
+    }
+}
+
+fn create_mtls_acceptor(certificate: &[u8], config: &TcpTlsConfig) -> TlsAcceptor {
+    let pkcs12 = Pkcs12::from_der(certificate)
+        .and_then(|pkcs12| pkcs12.parse2(&config.password))
+        .unwrap_or_else(|error| panic!("Unable to parse PKCS12 certificate. {error}"));
+    let (Some(server_certificate), Some(key)) = (pkcs12.cert, pkcs12.pkey) else {
+        panic!("PKCS12 certificate does not contain the certificate and the private key.");
+    };
+
+    let mut certificates = vec![CertificateDer::from(
+        server_certificate
+            .to_der()
+            .expect("Unable to encode server certificate."),
+    )];
+    if let Some(chain) = pkcs12.ca {
+        for ca_certificate in chain {
+            certificates.push(CertificateDer::from(
+                ca_certificate
+                    .to_der()
+                    .expect("Unable to encode CA certificate."),
+            ));
+        }
+    }
+    let key = PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(
+        key.private_key_to_pkcs8()
+            .expect("Unable to encode private key."),
+    ));
+
+    let verifier = client_certificate::load_client_verifier(&config.client_auth.ca_file)
+        .unwrap_or_else(|error| panic!("Unable to load client CA certificates. {error}"));
+    let server_config = rustls::ServerConfig::builder_with_provider(Arc::new(
+        rustls::crypto::ring::default_provider(),
+    ))
+    .with_safe_default_protocol_versions()
+    .and_then(|builder| {
+        builder
+            .with_client_cert_verifier(verifier)
+            .with_single_cert(certificates, key)
+    })
+    .unwrap_or_else(|error| panic!("Unable to create TLS server config. {error}"));
+
+    TlsAcceptor::Rustls(
+        tokio_rustls::TlsAcceptor::from(Arc::new(server_config)),
+        config.client_auth.identity,
+    )
+}
@@ -18,37 +18,49 @@
 
 use crate::binary::sender::Sender;
 use crate::tcp::COMPONENT;
+use crate::tcp::sender::SharedWriter;
 use crate::{server_error::ServerError, tcp::sender};
 use error_set::ErrContext;
 use iggy_common::IggyError;
-use tokio::io::AsyncWriteExt;
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt, ReadHalf};
 use tokio::net::TcpStream;
 use tokio_native_tls::TlsStream;
 
-#[derive(Debug)]
-pub struct TcpTlsSender {
-    pub(crate) stream: TlsStream<TcpStream>,
+pub struct TcpTlsSender<S = TlsStream<TcpStream>> {
+    pub(crate) reader: ReadHalf<S>,
+    pub(crate) writer: SharedWriter,
+    pub(crate) correlation_id: Option<u32>,
 }
 
-impl Sender for TcpTlsSender {
+impl<S> Sender for TcpTlsSender<S>
+where
+    S: AsyncRead + AsyncWrite + Unpin + Send,
+{
     async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError> {
-        sender::read(&mut self.stream, buffer).await
+        sender::read(&mut self.reader, buffer).await
     }
 
     async fn send_empty_ok_response(&mut self) -> Result<(), IggyError> {
-        sender::send_empty_ok_response(&mut self.stream).await
+        sender::send_empty_ok_response(&mut *self.writer.lock().await, self.correlation_id).await
     }
 
     async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError> {
-        sender::send_ok_response(&mut self.stream, payload).await
+        sender::send_ok_response(&mut *self.writer.lock().await, self.correlation_id, payload).await
     }
 
     async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
-        sender::send_error_response(&mut self.stream, error).await
+        sender::send_error_response(&mut *self.writer.lock().await, self.correlation_id, error)
+            .await
+    }
+
+    fn set_correlation_id(&mut self, correlation_id: Option<u32>) {
+        self.correlation_id = correlation_id;
     }
 
     async fn shutdown(&mut self) -> Result<(), ServerError> {
-        self.stream
+        self.writer
+            .lock()
+            .await
             .shutdown()
             .await
             .with_error_context(|error| {
@@ -62,6 +74,12 @@ impl Sender for TcpTlsSender {
         length: &[u8],
         slices: Vec<std::io::IoSlice<'_>>,
     ) -> Result<(), IggyError> {
-        sender::send_ok_response_vectored(&mut self.stream, length, slices).await
+        sender::send_ok_response_vectored(
+            &mut *self.writer.lock().await,
+            self.correlation_id,
+            length,
+            slices,
+        )
+        .await
     }
 }
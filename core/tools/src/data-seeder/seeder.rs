@@ -53,6 +53,7 @@ async fn create_topics(client: &IggyClient) -> Result<(), IggyError> {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                TopicOptions::default(),
             )
             .await?;
 
@@ -66,6 +67,7 @@ async fn create_topics(client: &IggyClient) -> Result<(), IggyError> {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                TopicOptions::default(),
             )
             .await?;
 
@@ -79,6 +81,7 @@ async fn create_topics(client: &IggyClient) -> Result<(), IggyError> {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                TopicOptions::default(),
             )
             .await?;
 
@@ -92,6 +95,7 @@ async fn create_topics(client: &IggyClient) -> Result<(), IggyError> {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                TopicOptions::default(),
             )
             .await?;
 
@@ -105,6 +109,7 @@ async fn create_topics(client: &IggyClient) -> Result<(), IggyError> {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                TopicOptions::default(),
             )
             .await?;
     }